@@ -0,0 +1,495 @@
+use crate::core::geometry::{Bounds3f, Point3f, Ray, Vector3f};
+use crate::core::primitive::Primitive;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+// relative costs used by the surface area heuristic
+const TRAVERSAL_COST: f32 = 0.125;
+const INTERSECT_COST: f32 = 1.0;
+const N_BUCKETS: usize = 12;
+// traversal stack size kept on the call stack, deeper trees get a heap one
+const MAX_STACK_DEPTH: usize = 64;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SplitMethod {
+    SAH,
+    Middle,
+    EqualCounts,
+}
+
+struct BVHPrimitiveInfo {
+    primitive_number: usize,
+    bounds: Bounds3f,
+    centroid: Point3f,
+}
+
+impl BVHPrimitiveInfo {
+    fn new(primitive_number: usize, bounds: Bounds3f) -> Self {
+        Self {
+            primitive_number,
+            bounds,
+            centroid: bounds.centroid(),
+        }
+    }
+}
+
+enum BVHBuildNode {
+    Leaf {
+        bounds: Bounds3f,
+        first_prim_offset: usize,
+        n_primitives: usize,
+    },
+    Interior {
+        bounds: Bounds3f,
+        split_axis: usize,
+        children: [Box<BVHBuildNode>; 2],
+    },
+}
+
+impl BVHBuildNode {
+    fn bounds(&self) -> &Bounds3f {
+        match self {
+            BVHBuildNode::Leaf { bounds, .. } => bounds,
+            BVHBuildNode::Interior { bounds, .. } => bounds,
+        }
+    }
+
+    // interior nodes on the longest path to a leaf
+    fn depth(&self) -> usize {
+        match self {
+            BVHBuildNode::Leaf { .. } => 0,
+            BVHBuildNode::Interior { children, .. } => 1 + children[0].depth().max(children[1].depth()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct LinearBVHNode {
+    bounds: Bounds3f,
+    // primitives offset for leaves, second child offset for interior nodes
+    offset: usize,
+    // leaves of primitives with a single centroid can exceed max_prims_in_node
+    n_primitives: u32,
+    axis: u8,
+}
+
+pub struct BVHAccel {
+    max_prims_in_node: usize,
+    split_method: SplitMethod,
+    primitives: Vec<Arc<dyn Primitive>>,
+    nodes: Vec<LinearBVHNode>,
+    max_depth: usize,
+    build_cost: f32,
+}
+
+impl BVHAccel {
+    pub fn new(
+        primitives: Vec<Arc<dyn Primitive>>,
+        max_prims_in_node: usize,
+        split_method: SplitMethod,
+    ) -> Self {
+        let mut bvh = Self {
+            max_prims_in_node: max_prims_in_node.clamp(1, 255),
+            split_method,
+            primitives,
+            nodes: Vec::new(),
+            max_depth: 0,
+            build_cost: 0.0,
+        };
+        bvh.build();
+        bvh
+    }
+
+    fn build(&mut self) {
+        self.nodes.clear();
+        if self.primitives.is_empty() {
+            self.build_cost = 0.0;
+            return;
+        }
+        let mut primitive_info: Vec<BVHPrimitiveInfo> = self
+            .primitives
+            .iter()
+            .enumerate()
+            .map(|(i, p)| BVHPrimitiveInfo::new(i, p.world_bound()))
+            .collect();
+
+        let mut total_nodes = 0;
+        let mut ordered_prims = Vec::with_capacity(self.primitives.len());
+        let n = primitive_info.len();
+        let root =
+            self.recursive_build(&mut primitive_info, 0, n, &mut total_nodes, &mut ordered_prims);
+        self.primitives = ordered_prims;
+        self.max_depth = root.depth();
+
+        self.nodes.reserve(total_nodes);
+        self.flatten_bvh_tree(&root);
+        self.build_cost = self.sah_cost();
+    }
+
+    fn recursive_build(
+        &self,
+        primitive_info: &mut [BVHPrimitiveInfo],
+        start: usize,
+        end: usize,
+        total_nodes: &mut usize,
+        ordered_prims: &mut Vec<Arc<dyn Primitive>>,
+    ) -> BVHBuildNode {
+        *total_nodes += 1;
+        let bounds = primitive_info[start..end]
+            .iter()
+            .fold(Bounds3f::empty(), |b, info| Bounds3f::union(&b, &info.bounds));
+        let n_primitives = end - start;
+        let make_leaf = |ordered_prims: &mut Vec<Arc<dyn Primitive>>, infos: &[BVHPrimitiveInfo]| {
+            let first_prim_offset = ordered_prims.len();
+            for info in infos {
+                ordered_prims.push(self.primitives[info.primitive_number].clone());
+            }
+            BVHBuildNode::Leaf {
+                bounds,
+                first_prim_offset,
+                n_primitives,
+            }
+        };
+        if n_primitives == 1 {
+            return make_leaf(ordered_prims, &primitive_info[start..end]);
+        }
+
+        let centroid_bounds = primitive_info[start..end]
+            .iter()
+            .fold(Bounds3f::empty(), |b, info| Bounds3f::union_from_point(&b, info.centroid));
+        let dim = centroid_bounds.maximum_extent();
+        if centroid_bounds.p_max[dim] == centroid_bounds.p_min[dim] {
+            return make_leaf(ordered_prims, &primitive_info[start..end]);
+        }
+
+        let mut mid = (start + end) / 2;
+        let mut split_method = self.split_method;
+        if split_method == SplitMethod::Middle {
+            let p_mid = (centroid_bounds.p_min[dim] + centroid_bounds.p_max[dim]) / 2.0;
+            mid = start + partition(&mut primitive_info[start..end], |pi| pi.centroid[dim] < p_mid);
+            // fall back to equal counts when the centroids pile up on one side
+            if mid == start || mid == end {
+                split_method = SplitMethod::EqualCounts;
+            }
+        }
+        if split_method == SplitMethod::EqualCounts {
+            mid = (start + end) / 2;
+            primitive_info[start..end].select_nth_unstable_by(mid - start, |a, b| {
+                a.centroid[dim].total_cmp(&b.centroid[dim])
+            });
+        }
+        if split_method == SplitMethod::SAH {
+            if n_primitives <= 2 {
+                mid = (start + end) / 2;
+                primitive_info[start..end].select_nth_unstable_by(mid - start, |a, b| {
+                    a.centroid[dim].total_cmp(&b.centroid[dim])
+                });
+            } else {
+                let bucket_index = |info: &BVHPrimitiveInfo| {
+                    let b = (N_BUCKETS as f32 * centroid_bounds.offset(&info.centroid)[dim]) as usize;
+                    b.min(N_BUCKETS - 1)
+                };
+                let mut counts = [0usize; N_BUCKETS];
+                let mut bucket_bounds = [Bounds3f::empty(); N_BUCKETS];
+                for info in &primitive_info[start..end] {
+                    let b = bucket_index(info);
+                    counts[b] += 1;
+                    bucket_bounds[b] = Bounds3f::union(&bucket_bounds[b], &info.bounds);
+                }
+
+                let mut cost = [0.0f32; N_BUCKETS - 1];
+                for (i, c) in cost.iter_mut().enumerate() {
+                    let mut b0 = Bounds3f::empty();
+                    let mut b1 = Bounds3f::empty();
+                    let mut count0 = 0;
+                    let mut count1 = 0;
+                    for j in 0..=i {
+                        b0 = Bounds3f::union(&b0, &bucket_bounds[j]);
+                        count0 += counts[j];
+                    }
+                    for j in i + 1..N_BUCKETS {
+                        b1 = Bounds3f::union(&b1, &bucket_bounds[j]);
+                        count1 += counts[j];
+                    }
+                    *c = TRAVERSAL_COST
+                        + (count0 as f32 * area_or_zero(&b0) + count1 as f32 * area_or_zero(&b1))
+                            / bounds.surface_area();
+                }
+
+                let (min_cost_split_bucket, min_cost) = cost
+                    .iter()
+                    .enumerate()
+                    .fold((0, f32::MAX), |(bi, bc), (i, &c)| if c < bc { (i, c) } else { (bi, bc) });
+
+                let leaf_cost = n_primitives as f32 * INTERSECT_COST;
+                if n_primitives > self.max_prims_in_node || min_cost < leaf_cost {
+                    mid = start
+                        + partition(&mut primitive_info[start..end], |pi| {
+                            bucket_index(pi) <= min_cost_split_bucket
+                        });
+                } else {
+                    return make_leaf(ordered_prims, &primitive_info[start..end]);
+                }
+            }
+        }
+
+        let c0 = self.recursive_build(primitive_info, start, mid, total_nodes, ordered_prims);
+        let c1 = self.recursive_build(primitive_info, mid, end, total_nodes, ordered_prims);
+        BVHBuildNode::Interior {
+            bounds: Bounds3f::union(c0.bounds(), c1.bounds()),
+            split_axis: dim,
+            children: [Box::new(c0), Box::new(c1)],
+        }
+    }
+
+    fn flatten_bvh_tree(&mut self, node: &BVHBuildNode) -> usize {
+        let offset = self.nodes.len();
+        match node {
+            BVHBuildNode::Leaf {
+                bounds,
+                first_prim_offset,
+                n_primitives,
+            } => {
+                self.nodes.push(LinearBVHNode {
+                    bounds: *bounds,
+                    offset: *first_prim_offset,
+                    n_primitives: u32::try_from(*n_primitives).expect("too many primitives in a BVH leaf"),
+                    axis: 0,
+                });
+            }
+            BVHBuildNode::Interior {
+                bounds,
+                split_axis,
+                children,
+            } => {
+                self.nodes.push(LinearBVHNode {
+                    bounds: *bounds,
+                    offset: 0,
+                    n_primitives: 0,
+                    axis: *split_axis as u8,
+                });
+                self.flatten_bvh_tree(&children[0]);
+                let second_child_offset = self.flatten_bvh_tree(&children[1]);
+                self.nodes[offset].offset = second_child_offset;
+            }
+        }
+        offset
+    }
+
+    pub fn world_bound(&self) -> Bounds3f {
+        match self.nodes.first() {
+            Some(root) => root.bounds,
+            None => Bounds3f::empty(),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    // recompute every node's bounds bottom-up from the current primitive
+    // bounds, keeping the topology. children always come after their parent
+    // in the flattened layout, so walking the nodes backwards is enough.
+    pub fn refit(&mut self) {
+        for i in (0..self.nodes.len()).rev() {
+            let node = self.nodes[i];
+            self.nodes[i].bounds = if node.n_primitives > 0 {
+                self.primitives[node.offset..node.offset + node.n_primitives as usize]
+                    .iter()
+                    .fold(Bounds3f::empty(), |b, p| Bounds3f::union(&b, &p.world_bound()))
+            } else {
+                Bounds3f::union(&self.nodes[i + 1].bounds, &self.nodes[node.offset].bounds)
+            };
+        }
+    }
+
+    // expected cost of a random ray through the tree, relative to the root area
+    pub fn sah_cost(&self) -> f32 {
+        let root_area = match self.nodes.first() {
+            Some(root) => root.bounds.surface_area(),
+            None => return 0.0,
+        };
+        if root_area <= 0.0 {
+            return self.primitives.len() as f32 * INTERSECT_COST;
+        }
+        self.nodes
+            .iter()
+            .map(|node| {
+                let cost = if node.n_primitives > 0 {
+                    node.n_primitives as f32 * INTERSECT_COST
+                } else {
+                    TRAVERSAL_COST
+                };
+                cost * area_or_zero(&node.bounds) / root_area
+            })
+            .sum()
+    }
+
+    // the SAH cost of the tree when it was last built
+    pub fn build_cost(&self) -> f32 {
+        self.build_cost
+    }
+
+    // true once refitting has degraded the tree by more than the given factor
+    pub fn needs_rebuild(&self, threshold: f32) -> bool {
+        self.sah_cost() > threshold * self.build_cost
+    }
+
+    pub fn rebuild(&mut self) {
+        self.build();
+    }
+
+    // refit after the primitives moved and rebuild if the quality dropped
+    // below the threshold. returns true when the tree was rebuilt.
+    pub fn update(&mut self, rebuild_threshold: f32) -> bool {
+        self.refit();
+        if self.needs_rebuild(rebuild_threshold) {
+            self.rebuild();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Primitive for BVHAccel {
+    fn world_bound(&self) -> Bounds3f {
+        BVHAccel::world_bound(self)
+    }
+
+    fn intersect(&self, ray: &mut Ray) -> bool {
+        if self.max_depth <= MAX_STACK_DEPTH {
+            self.intersect_with_stack(ray, &mut [0; MAX_STACK_DEPTH])
+        } else {
+            self.intersect_with_stack(ray, &mut vec![0; self.max_depth])
+        }
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        if self.max_depth <= MAX_STACK_DEPTH {
+            self.intersect_p_with_stack(ray, &mut [0; MAX_STACK_DEPTH])
+        } else {
+            self.intersect_p_with_stack(ray, &mut vec![0; self.max_depth])
+        }
+    }
+}
+
+impl BVHAccel {
+    // the stack holds at least max_depth nodes
+    fn intersect_with_stack(&self, ray: &mut Ray, nodes_to_visit: &mut [usize]) -> bool {
+        if self.nodes.is_empty() {
+            return false;
+        }
+        let mut hit = false;
+        let inv_dir = Vector3f::new(1.0 / ray.d.x, 1.0 / ray.d.y, 1.0 / ray.d.z);
+        let dir_is_neg = [
+            (inv_dir.x < 0.0) as usize,
+            (inv_dir.y < 0.0) as usize,
+            (inv_dir.z < 0.0) as usize,
+        ];
+        let mut to_visit_offset = 0;
+        let mut current_node_index = 0;
+        loop {
+            let node = &self.nodes[current_node_index];
+            if node.bounds.intersect_p_inv(ray, &inv_dir, dir_is_neg) {
+                if node.n_primitives > 0 {
+                    for p in &self.primitives[node.offset..node.offset + node.n_primitives as usize] {
+                        if p.intersect(ray) {
+                            hit = true;
+                        }
+                    }
+                    if to_visit_offset == 0 {
+                        break;
+                    }
+                    to_visit_offset -= 1;
+                    current_node_index = nodes_to_visit[to_visit_offset];
+                } else if dir_is_neg[node.axis as usize] == 1 {
+                    nodes_to_visit[to_visit_offset] = current_node_index + 1;
+                    to_visit_offset += 1;
+                    current_node_index = node.offset;
+                } else {
+                    nodes_to_visit[to_visit_offset] = node.offset;
+                    to_visit_offset += 1;
+                    current_node_index += 1;
+                }
+            } else {
+                if to_visit_offset == 0 {
+                    break;
+                }
+                to_visit_offset -= 1;
+                current_node_index = nodes_to_visit[to_visit_offset];
+            }
+        }
+        hit
+    }
+
+    fn intersect_p_with_stack(&self, ray: &Ray, nodes_to_visit: &mut [usize]) -> bool {
+        if self.nodes.is_empty() {
+            return false;
+        }
+        let inv_dir = Vector3f::new(1.0 / ray.d.x, 1.0 / ray.d.y, 1.0 / ray.d.z);
+        let dir_is_neg = [
+            (inv_dir.x < 0.0) as usize,
+            (inv_dir.y < 0.0) as usize,
+            (inv_dir.z < 0.0) as usize,
+        ];
+        let mut to_visit_offset = 0;
+        let mut current_node_index = 0;
+        loop {
+            let node = &self.nodes[current_node_index];
+            if node.bounds.intersect_p_inv(ray, &inv_dir, dir_is_neg) {
+                if node.n_primitives > 0 {
+                    for p in &self.primitives[node.offset..node.offset + node.n_primitives as usize] {
+                        if p.intersect_p(ray) {
+                            return true;
+                        }
+                    }
+                    if to_visit_offset == 0 {
+                        break;
+                    }
+                    to_visit_offset -= 1;
+                    current_node_index = nodes_to_visit[to_visit_offset];
+                } else if dir_is_neg[node.axis as usize] == 1 {
+                    nodes_to_visit[to_visit_offset] = current_node_index + 1;
+                    to_visit_offset += 1;
+                    current_node_index = node.offset;
+                } else {
+                    nodes_to_visit[to_visit_offset] = node.offset;
+                    to_visit_offset += 1;
+                    current_node_index += 1;
+                }
+            } else {
+                if to_visit_offset == 0 {
+                    break;
+                }
+                to_visit_offset -= 1;
+                current_node_index = nodes_to_visit[to_visit_offset];
+            }
+        }
+        false
+    }
+}
+
+fn area_or_zero(b: &Bounds3f) -> f32 {
+    if b.is_empty() {
+        0.0
+    } else {
+        b.surface_area()
+    }
+}
+
+// moves the elements matching pred to the front, returns how many matched
+fn partition<T, F: Fn(&T) -> bool>(v: &mut [T], pred: F) -> usize {
+    let mut first = 0;
+    for i in 0..v.len() {
+        if pred(&v[i]) {
+            v.swap(first, i);
+            first += 1;
+        }
+    }
+    first
+}
@@ -0,0 +1,272 @@
+use crate::core::geometry::{BaseRay, Bounds3f, Bounds3i, Point3f, Point3i, Ray, Vector3f};
+use crate::core::pbrt::clamp;
+use crate::core::primitive::Primitive;
+use std::sync::Arc;
+
+const MAX_VOXELS_PER_AXIS: i32 = 64;
+const MAILBOX_SIZE: usize = 8;
+
+// hashed mailbox: remembers the primitives tested recently by one ray so a
+// primitive overlapping several voxels is only intersected once. it lives
+// on the stack of each traversal, so the grid can be shared between threads.
+struct Mailbox {
+    ids: [usize; MAILBOX_SIZE],
+}
+
+impl Mailbox {
+    fn new() -> Self {
+        Self {
+            ids: [usize::MAX; MAILBOX_SIZE],
+        }
+    }
+
+    // returns false when the primitive was already tested
+    fn insert(&mut self, id: usize) -> bool {
+        let slot = &mut self.ids[id % MAILBOX_SIZE];
+        if *slot == id {
+            false
+        } else {
+            *slot = id;
+            true
+        }
+    }
+}
+
+pub struct GridAccel {
+    primitives: Vec<Arc<dyn Primitive>>,
+    bounds: Bounds3f,
+    n_voxels: Point3i,
+    width: Vector3f,
+    inv_width: Vector3f,
+    voxels: Vec<Vec<usize>>,
+    mailboxing: bool,
+}
+
+impl GridAccel {
+    pub fn new(primitives: Vec<Arc<dyn Primitive>>, mailboxing: bool) -> Self {
+        let prim_bounds: Vec<Bounds3f> = primitives.iter().map(|p| p.world_bound()).collect();
+        let bounds = prim_bounds
+            .iter()
+            .fold(Bounds3f::empty(), |b, pb| Bounds3f::union(&b, pb));
+
+        // about three voxels per primitive along the longest axis
+        let delta = bounds.diagonal();
+        let max_axis = bounds.maximum_extent();
+        let inv_max_width = if delta[max_axis] > 0.0 { 1.0 / delta[max_axis] } else { 0.0 };
+        let cube_root = 3.0 * (primitives.len() as f32).powf(1.0 / 3.0);
+        let voxels_per_unit_dist = cube_root * inv_max_width;
+        let n_voxel = |axis: usize| {
+            let n = (delta[axis] * voxels_per_unit_dist).round() as i32;
+            clamp(n, 1, MAX_VOXELS_PER_AXIS) as i16
+        };
+        let n_voxels = Point3i::new(n_voxel(0), n_voxel(1), n_voxel(2));
+
+        let width = Vector3f::new(
+            delta.x / n_voxels.x as f32,
+            delta.y / n_voxels.y as f32,
+            delta.z / n_voxels.z as f32,
+        );
+        let inv_width = Vector3f::new(
+            if width.x == 0.0 { 0.0 } else { 1.0 / width.x },
+            if width.y == 0.0 { 0.0 } else { 1.0 / width.y },
+            if width.z == 0.0 { 0.0 } else { 1.0 / width.z },
+        );
+
+        let n_total = n_voxels.x as usize * n_voxels.y as usize * n_voxels.z as usize;
+        let mut grid = Self {
+            primitives,
+            bounds,
+            n_voxels,
+            width,
+            inv_width,
+            voxels: vec![Vec::new(); n_total],
+            mailboxing,
+        };
+
+        // add every primitive to the voxels its bounds overlap
+        for (i, pb) in prim_bounds.iter().enumerate() {
+            let v_min = grid.pos_to_voxel(&pb.p_min);
+            let v_max = grid.pos_to_voxel(&pb.p_max);
+            for z in v_min.z..=v_max.z {
+                for y in v_min.y..=v_max.y {
+                    for x in v_min.x..=v_max.x {
+                        let o = grid.offset(&Point3i::new(x, y, z));
+                        grid.voxels[o].push(i);
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn world_bound(&self) -> Bounds3f {
+        self.bounds
+    }
+
+    pub fn n_voxels(&self) -> Point3i {
+        self.n_voxels
+    }
+
+    pub fn voxel_bounds(&self) -> Bounds3i {
+        Bounds3i::new(Point3i::new(0, 0, 0), self.n_voxels)
+    }
+
+    // indices of the primitives overlapping the given cell
+    pub fn voxel(&self, p: &Point3i) -> &[usize] {
+        assert!(Bounds3i::inside_exclusive(*p, self.voxel_bounds()));
+        &self.voxels[self.offset(p)]
+    }
+
+    // world space bounds of a single cell
+    pub fn voxel_world_bound(&self, p: &Point3i) -> Bounds3f {
+        Bounds3f::new(
+            Point3f::new(
+                self.voxel_to_pos(p.x, 0),
+                self.voxel_to_pos(p.y, 1),
+                self.voxel_to_pos(p.z, 2),
+            ),
+            Point3f::new(
+                self.voxel_to_pos(p.x + 1, 0),
+                self.voxel_to_pos(p.y + 1, 1),
+                self.voxel_to_pos(p.z + 1, 2),
+            ),
+        )
+    }
+
+    pub fn pos_to_voxel(&self, p: &Point3f) -> Point3i {
+        let v = |axis: usize| {
+            let n = ((p[axis] - self.bounds.p_min[axis]) * self.inv_width[axis]) as i32;
+            clamp(n, 0, self.n_voxels[axis] as i32 - 1) as i16
+        };
+        Point3i::new(v(0), v(1), v(2))
+    }
+
+    fn voxel_to_pos(&self, p: i16, axis: usize) -> f32 {
+        self.bounds.p_min[axis] + p as f32 * self.width[axis]
+    }
+
+    fn offset(&self, p: &Point3i) -> usize {
+        let nx = self.n_voxels.x as usize;
+        let ny = self.n_voxels.y as usize;
+        p.z as usize * nx * ny + p.y as usize * nx + p.x as usize
+    }
+
+    // walks the cells pierced by the ray front to back with 3D-DDA, calling
+    // visit with each cell's primitives. visit returns the current ray
+    // t_max to keep going, or None to stop.
+    fn traverse<F>(&self, ray: &Ray, mut visit: F)
+    where
+        F: FnMut(&[usize]) -> Option<f32>,
+    {
+        if self.primitives.is_empty() {
+            return;
+        }
+        let ray_t = if Bounds3f::inside(&ray.o, &self.bounds) {
+            0.0
+        } else {
+            match self.bounds.intersect_p(ray) {
+                Some((t0, _)) => t0,
+                None => return,
+            }
+        };
+        let grid_intersect = ray.point(ray_t);
+
+        let mut next_crossing_t = [0.0f32; 3];
+        let mut delta_t = [0.0f32; 3];
+        let mut step = [0i16; 3];
+        let mut out = [0i16; 3];
+        let start = self.pos_to_voxel(&grid_intersect);
+        let mut pos = [start.x, start.y, start.z];
+        for axis in 0..3 {
+            if ray.d[axis] == 0.0 {
+                next_crossing_t[axis] = f32::INFINITY;
+                delta_t[axis] = 0.0;
+                step[axis] = 0;
+                out[axis] = -1;
+            } else if ray.d[axis] > 0.0 {
+                next_crossing_t[axis] = ray_t
+                    + (self.voxel_to_pos(pos[axis] + 1, axis) - grid_intersect[axis]) / ray.d[axis];
+                delta_t[axis] = self.width[axis] / ray.d[axis];
+                step[axis] = 1;
+                out[axis] = self.n_voxels[axis];
+            } else {
+                next_crossing_t[axis] = ray_t
+                    + (self.voxel_to_pos(pos[axis], axis) - grid_intersect[axis]) / ray.d[axis];
+                delta_t[axis] = -self.width[axis] / ray.d[axis];
+                step[axis] = -1;
+                out[axis] = -1;
+            }
+        }
+
+        loop {
+            let o = self.offset(&Point3i::new(pos[0], pos[1], pos[2]));
+            let t_max = match visit(&self.voxels[o]) {
+                Some(t_max) => t_max,
+                None => return,
+            };
+
+            // advance to the next voxel along the axis crossed first
+            let step_axis = if next_crossing_t[0] < next_crossing_t[1] {
+                if next_crossing_t[0] < next_crossing_t[2] {
+                    0
+                } else {
+                    2
+                }
+            } else if next_crossing_t[1] < next_crossing_t[2] {
+                1
+            } else {
+                2
+            };
+            if t_max < next_crossing_t[step_axis] {
+                return;
+            }
+            pos[step_axis] += step[step_axis];
+            if pos[step_axis] == out[step_axis] {
+                return;
+            }
+            next_crossing_t[step_axis] += delta_t[step_axis];
+        }
+    }
+}
+
+impl Primitive for GridAccel {
+    fn world_bound(&self) -> Bounds3f {
+        self.bounds
+    }
+
+    fn intersect(&self, ray: &mut Ray) -> bool {
+        let mut mailbox = Mailbox::new();
+        let mut hit = false;
+        let r = *ray;
+        self.traverse(&r, |voxel| {
+            for &i in voxel {
+                if self.mailboxing && !mailbox.insert(i) {
+                    continue;
+                }
+                if self.primitives[i].intersect(ray) {
+                    hit = true;
+                }
+            }
+            Some(ray.t_max)
+        });
+        hit
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        let mut mailbox = Mailbox::new();
+        let mut hit = false;
+        self.traverse(ray, |voxel| {
+            for &i in voxel {
+                if self.mailboxing && !mailbox.insert(i) {
+                    continue;
+                }
+                if self.primitives[i].intersect_p(ray) {
+                    hit = true;
+                    return None;
+                }
+            }
+            Some(ray.t_max)
+        });
+        hit
+    }
+}
@@ -0,0 +1,5 @@
+pub use bvh::*;
+pub use grid::*;
+
+mod bvh;
+mod grid;
@@ -0,0 +1,179 @@
+use crate::core::camera::{Camera, CameraSample};
+use crate::core::geometry::{spherical_direction, Point2f, Point2i, Point3f, Ray, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::pbrt::{radians, PI};
+use crate::core::sampling::{equal_area_sphere_to_square, equal_area_square_to_sphere};
+use crate::core::transform::{AnimatedTransform, Transform};
+
+// how film positions map to camera space directions, camera looking down +z
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EnvironmentMapping {
+    // latitude-longitude with +y up, phi measured from +x towards +z
+    Equirectangular,
+    // equal-area octahedral square, +z at the center and -z at the corners
+    EqualArea,
+    // angular (equidistant) fisheye, fov in degrees across the image circle
+    // inscribed in the shorter axis
+    Fisheye { fov: f32 },
+    // six square faces side by side in the order +x, -x, +y, -y, +z, -z,
+    // each oriented as an OpenGL cube map face
+    CubeMap,
+}
+
+impl EnvironmentMapping {
+    // direction for a film position in [0, 1]^2, None where nothing is mapped
+    pub fn direction(&self, uv: &Point2f, aspect: f32) -> Option<Vector3f> {
+        match *self {
+            EnvironmentMapping::Equirectangular => {
+                let theta = PI * uv.y;
+                let phi = 2.0 * PI * uv.x;
+                let d = spherical_direction(theta.sin(), theta.cos(), phi);
+                Some(Vector3f::new(d.x, d.z, d.y))
+            }
+            EnvironmentMapping::EqualArea => Some(equal_area_square_to_sphere(uv)),
+            EnvironmentMapping::Fisheye { fov } => {
+                let mut p = Point2f::new(2.0 * uv.x - 1.0, 1.0 - 2.0 * uv.y);
+                if aspect > 1.0 {
+                    p.x *= aspect;
+                } else {
+                    p.y /= aspect;
+                }
+                let r = (p.x * p.x + p.y * p.y).sqrt();
+                if r > 1.0 {
+                    return None;
+                }
+                let theta = r * radians(fov) / 2.0;
+                let phi = p.y.atan2(p.x);
+                Some(spherical_direction(theta.sin(), theta.cos(), phi))
+            }
+            EnvironmentMapping::CubeMap => {
+                let face = ((uv.x * 6.0) as usize).min(5);
+                let a = 2.0 * (uv.x * 6.0 - face as f32) - 1.0;
+                let b = 2.0 * uv.y - 1.0;
+                let d = match face {
+                    0 => Vector3f::new(1.0, -b, -a),
+                    1 => Vector3f::new(-1.0, -b, a),
+                    2 => Vector3f::new(a, 1.0, b),
+                    3 => Vector3f::new(a, -1.0, -b),
+                    4 => Vector3f::new(a, -b, 1.0),
+                    _ => Vector3f::new(-a, -b, -1.0),
+                };
+                Some(Vector3f::normalize(&d))
+            }
+        }
+    }
+
+    // inverse of direction, None for directions outside the field of view
+    pub fn film_position(&self, d: &Vector3f, aspect: f32) -> Option<Point2f> {
+        let d = Vector3f::normalize(d);
+        match *self {
+            EnvironmentMapping::Equirectangular => {
+                let theta = d.y.clamp(-1.0, 1.0).acos();
+                let mut phi = d.z.atan2(d.x);
+                if phi < 0.0 {
+                    phi += 2.0 * PI;
+                }
+                Some(Point2f::new(phi / (2.0 * PI), theta / PI))
+            }
+            EnvironmentMapping::EqualArea => Some(equal_area_sphere_to_square(&d)),
+            EnvironmentMapping::Fisheye { fov } => {
+                let theta = d.z.clamp(-1.0, 1.0).acos();
+                let r = theta / (radians(fov) / 2.0);
+                if r > 1.0 {
+                    return None;
+                }
+                let phi = d.y.atan2(d.x);
+                let mut p = Point2f::new(r * phi.cos(), r * phi.sin());
+                if aspect > 1.0 {
+                    p.x /= aspect;
+                } else {
+                    p.y *= aspect;
+                }
+                Some(Point2f::new(0.5 * (p.x + 1.0), 0.5 * (1.0 - p.y)))
+            }
+            EnvironmentMapping::CubeMap => {
+                let ad = Vector3f::abs(&d);
+                let (face, sc, tc, ma) = if ad.x >= ad.y && ad.x >= ad.z {
+                    if d.x > 0.0 {
+                        (0, -d.z, -d.y, ad.x)
+                    } else {
+                        (1, d.z, -d.y, ad.x)
+                    }
+                } else if ad.y >= ad.z {
+                    if d.y > 0.0 {
+                        (2, d.x, d.z, ad.y)
+                    } else {
+                        (3, d.x, -d.z, ad.y)
+                    }
+                } else if d.z > 0.0 {
+                    (4, d.x, -d.y, ad.z)
+                } else {
+                    (5, -d.x, -d.y, ad.z)
+                };
+                let s = 0.5 * (sc / ma + 1.0);
+                let t = 0.5 * (tc / ma + 1.0);
+                Some(Point2f::new((face as f32 + s) / 6.0, t))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EnvironmentCamera {
+    pub camera_to_world: AnimatedTransform,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub medium: Option<Medium>,
+    pub full_resolution: Point2i,
+    pub mapping: EnvironmentMapping,
+}
+
+impl EnvironmentCamera {
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        full_resolution: Point2i,
+        mapping: EnvironmentMapping,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        Self {
+            camera_to_world,
+            shutter_open,
+            shutter_close,
+            medium,
+            full_resolution,
+            mapping,
+        }
+    }
+
+    fn aspect(&self) -> f32 {
+        self.full_resolution.x as f32 / self.full_resolution.y as f32
+    }
+
+    // raster position seen along a world space direction at the given time
+    pub fn raster_position(&self, d: &Vector3f, time: f32) -> Option<Point2f> {
+        let world_to_camera = Transform::inverse(&self.camera_to_world.interpolate(time));
+        let uv = self.mapping.film_position(&world_to_camera.transform_vector(d), self.aspect())?;
+        Some(Point2f::new(
+            uv.x * self.full_resolution.x as f32,
+            uv.y * self.full_resolution.y as f32,
+        ))
+    }
+}
+
+impl Camera for EnvironmentCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        let uv = Point2f::new(
+            sample.p_film.x / self.full_resolution.x as f32,
+            sample.p_film.y / self.full_resolution.y as f32,
+        );
+        let time = self.shutter_open + (self.shutter_close - self.shutter_open) * sample.time;
+        let d = match self.mapping.direction(&uv, self.aspect()) {
+            Some(d) => d,
+            None => return (0.0, Ray::default()),
+        };
+        let ray = Ray::new(Point3f::new(0.0, 0.0, 0.0), d, f32::INFINITY, time, self.medium.as_ref());
+        (1.0, self.camera_to_world.transform_ray(&ray))
+    }
+}
@@ -0,0 +1,11 @@
+pub use environment::*;
+pub use orthographic::*;
+pub use perspective::*;
+pub use realistic::*;
+pub use stereo::*;
+
+mod environment;
+mod orthographic;
+mod perspective;
+mod realistic;
+mod stereo;
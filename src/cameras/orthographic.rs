@@ -0,0 +1,108 @@
+use crate::core::camera::{Camera, CameraSample, ProjectiveCamera, ThinLens};
+use crate::core::geometry::{Bounds2f, Point2f, Point2i, Point3f, Ray, RayDifferential, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::transform::{AnimatedTransform, Transform};
+
+#[derive(Clone, Debug)]
+pub struct OrthographicCamera {
+    pub projective: ProjectiveCamera,
+    dx_camera: Vector3f,
+    dy_camera: Vector3f,
+    near: f32,
+    far: f32,
+}
+
+impl OrthographicCamera {
+    // the screen window is given in camera space units
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        screen_window: Bounds2f,
+        full_resolution: Point2i,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        let projective = ProjectiveCamera::new(
+            camera_to_world,
+            Transform::orthographic(0.0, 1.0),
+            screen_window,
+            full_resolution,
+            shutter_open,
+            shutter_close,
+            medium,
+        );
+        let p0 = projective.raster_to_camera_point(&Point2f::new(0.0, 0.0));
+        let dx_camera = projective.raster_to_camera_point(&Point2f::new(1.0, 0.0)) - p0;
+        let dy_camera = projective.raster_to_camera_point(&Point2f::new(0.0, 1.0)) - p0;
+        Self {
+            projective,
+            dx_camera,
+            dy_camera,
+            near: 0.0,
+            far: f32::INFINITY,
+        }
+    }
+
+    pub fn with_lens(mut self, lens: ThinLens) -> Self {
+        self.projective.lens = lens;
+        self
+    }
+
+    // only geometry between the near and far planes (camera space z) is seen
+    pub fn with_clipping(mut self, near: f32, far: f32) -> Self {
+        assert!(near < far);
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    // ray starting on the near plane, clipped at the far plane through t_max
+    fn camera_ray<'a>(&self, p_camera: &Point3f, p_lens: &Point2f, time: f32, medium: Option<&'a Medium>) -> Ray<'a> {
+        let mut ray = Ray::new(
+            Point3f::new(p_camera.x, p_camera.y, self.near),
+            Vector3f::new(0.0, 0.0, 1.0),
+            f32::INFINITY,
+            time,
+            medium,
+        );
+        let lens = &self.projective.lens;
+        if !lens.is_pinhole() {
+            // the lens is centered on the ray of the pixel
+            let p = lens.sample_lens(p_lens);
+            let origin = Point3f::new(p_camera.x + p.x, p_camera.y + p.y, self.near);
+            ray = lens.focus(&ray, &origin);
+        }
+        ray.t_max = (self.far - self.near) / ray.d.z;
+        ray
+    }
+}
+
+impl Camera for OrthographicCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        let p_camera = self.projective.raster_to_camera_point(&sample.p_film);
+        let ray = self.camera_ray(
+            &p_camera,
+            &sample.p_lens,
+            self.projective.time(sample),
+            self.projective.medium.as_ref(),
+        );
+        (1.0, self.projective.camera_to_world.transform_ray(&ray))
+    }
+
+    // differential rays are the main ray shifted by one pixel, so for a
+    // pinhole they only differ in origin
+    fn generate_ray_differential(&self, sample: &CameraSample) -> (f32, RayDifferential<'_>) {
+        let p_camera = self.projective.raster_to_camera_point(&sample.p_film);
+        let time = self.projective.time(sample);
+        let medium = self.projective.medium.as_ref();
+        let mut ray = RayDifferential::from_ray(self.camera_ray(&p_camera, &sample.p_lens, time, medium));
+        let rx = self.camera_ray(&(p_camera + self.dx_camera), &sample.p_lens, time, medium);
+        let ry = self.camera_ray(&(p_camera + self.dy_camera), &sample.p_lens, time, medium);
+        ray.rx_origin = rx.o;
+        ray.ry_origin = ry.o;
+        ray.rx_direction = rx.d;
+        ray.ry_direction = ry.d;
+        ray.has_differentials = true;
+        (1.0, self.projective.camera_to_world.transform_ray_differential(&ray))
+    }
+}
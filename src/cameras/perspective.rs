@@ -0,0 +1,120 @@
+use crate::core::camera::{Camera, CameraSample, ProjectiveCamera, ThinLens};
+use crate::core::geometry::{BaseRay, Bounds2f, Point2f, Point2i, Point3f, Ray, RayDifferential, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::transform::{AnimatedTransform, Transform};
+
+#[derive(Clone, Debug)]
+pub struct PerspectiveCamera {
+    pub projective: ProjectiveCamera,
+    dx_camera: Vector3f,
+    dy_camera: Vector3f,
+}
+
+impl PerspectiveCamera {
+    // fov in degrees, spanning the shorter axis of the screen window
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        screen_window: Bounds2f,
+        full_resolution: Point2i,
+        fov: f32,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        let projective = ProjectiveCamera::new(
+            camera_to_world,
+            Transform::perspective(fov, 1e-2, 1000.0),
+            screen_window,
+            full_resolution,
+            shutter_open,
+            shutter_close,
+            medium,
+        );
+        // camera space offsets of one pixel step on the film
+        let p0 = projective.raster_to_camera_point(&Point2f::new(0.0, 0.0));
+        let dx_camera = projective.raster_to_camera_point(&Point2f::new(1.0, 0.0)) - p0;
+        let dy_camera = projective.raster_to_camera_point(&Point2f::new(0.0, 1.0)) - p0;
+        Self {
+            projective,
+            dx_camera,
+            dy_camera,
+        }
+    }
+
+    pub fn with_lens(mut self, lens: ThinLens) -> Self {
+        self.projective.lens = lens;
+        self
+    }
+
+    // screen window derived from the image aspect ratio
+    pub fn from_resolution(
+        camera_to_world: AnimatedTransform,
+        full_resolution: Point2i,
+        fov: f32,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        let frame = full_resolution.x as f32 / full_resolution.y as f32;
+        Self::new(
+            camera_to_world,
+            ProjectiveCamera::screen_window(frame),
+            full_resolution,
+            fov,
+            shutter_open,
+            shutter_close,
+            medium,
+        )
+    }
+}
+
+impl Camera for PerspectiveCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        let p_camera = self.projective.raster_to_camera_point(&sample.p_film);
+        let mut ray = Ray::new(
+            Point3f::new(0.0, 0.0, 0.0),
+            Vector3f::normalize(&(p_camera - Point3f::new(0.0, 0.0, 0.0))),
+            f32::INFINITY,
+            self.projective.time(sample),
+            self.projective.medium.as_ref(),
+        );
+        let lens = &self.projective.lens;
+        if !lens.is_pinhole() {
+            ray = lens.focus(&ray, &lens.sample_lens(&sample.p_lens));
+        }
+        (1.0, self.projective.camera_to_world.transform_ray(&ray))
+    }
+
+    fn generate_ray_differential(&self, sample: &CameraSample) -> (f32, RayDifferential<'_>) {
+        let p_camera = self.projective.raster_to_camera_point(&sample.p_film);
+        let dir = p_camera - Point3f::new(0.0, 0.0, 0.0);
+        let mut ray = RayDifferential::new(
+            Point3f::new(0.0, 0.0, 0.0),
+            Vector3f::normalize(&dir),
+            f32::INFINITY,
+            self.projective.time(sample),
+            self.projective.medium.as_ref(),
+        );
+        let lens = &self.projective.lens;
+        if lens.is_pinhole() {
+            ray.rx_origin = ray.ray().o;
+            ray.ry_origin = ray.ray().o;
+            ray.rx_direction = Vector3f::normalize(&(dir + self.dx_camera));
+            ray.ry_direction = Vector3f::normalize(&(dir + self.dy_camera));
+        } else {
+            // the offset rays go through the same lens point
+            let p_lens = lens.sample_lens(&sample.p_lens);
+            let focused = lens.focus(ray.ray(), &p_lens);
+            let origin = Point3f::new(0.0, 0.0, 0.0);
+            let rx = lens.focus(&Ray::new(origin, Vector3f::normalize(&(dir + self.dx_camera)), 0.0, 0.0, None), &p_lens);
+            let ry = lens.focus(&Ray::new(origin, Vector3f::normalize(&(dir + self.dy_camera)), 0.0, 0.0, None), &p_lens);
+            ray = RayDifferential::from_ray(focused);
+            ray.rx_origin = rx.o;
+            ray.ry_origin = ry.o;
+            ray.rx_direction = rx.d;
+            ray.ry_direction = ry.d;
+        }
+        ray.has_differentials = true;
+        (1.0, self.projective.camera_to_world.transform_ray_differential(&ray))
+    }
+}
@@ -0,0 +1,422 @@
+use crate::core::camera::{Camera, CameraSample};
+use crate::core::geometry::{BaseRay, Bounds2f, Point2f, Point2i, Point3f, Ray, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::pbrt::{lerp, quadratic};
+use crate::core::transform::{AnimatedTransform, Transform};
+
+const N_EXIT_PUPIL_BOUNDS: usize = 64;
+const N_EXIT_PUPIL_SAMPLES: usize = 4096;
+
+// one spherical surface (or the aperture stop when curvature_radius is 0)
+// of a lens prescription, all lengths in meters
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LensElementInterface {
+    pub curvature_radius: f32,
+    pub thickness: f32,
+    pub eta: f32,
+    pub aperture_radius: f32,
+}
+
+impl LensElementInterface {
+    // parses a lens table in the format of pbrt's lens files: one interface
+    // per line, front to back, with curvature radius, thickness, index of
+    // refraction and aperture diameter, lengths in millimeters. lines
+    // starting with '#' are comments.
+    pub fn parse_table(text: &str) -> Result<Vec<Self>, String> {
+        let mut elements = Vec::new();
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let values = line
+                .split_whitespace()
+                .map(|v| v.parse::<f32>())
+                .collect::<Result<Vec<f32>, _>>()
+                .map_err(|e| format!("line {}: {}", line_number + 1, e))?;
+            if values.len() != 4 {
+                return Err(format!(
+                    "line {}: expected 4 values, found {}",
+                    line_number + 1,
+                    values.len()
+                ));
+            }
+            elements.push(Self {
+                curvature_radius: values[0] * 0.001,
+                thickness: values[1] * 0.001,
+                eta: values[2],
+                aperture_radius: values[3] * 0.001 / 2.0,
+            });
+        }
+        if elements.is_empty() {
+            return Err("lens table has no elements".to_string());
+        }
+        Ok(elements)
+    }
+}
+
+// camera simulating a real lens system in front of the film. rays are
+// traced from the film through every element, sampling only the exit
+// pupil that can actually reach the scene.
+#[derive(Clone, Debug)]
+pub struct RealisticCamera {
+    pub camera_to_world: AnimatedTransform,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub medium: Option<Medium>,
+    // weight rays by cos^4 only, so images keep a brightness close to a
+    // pinhole camera instead of being in radiometric units
+    pub simple_weighting: bool,
+    full_resolution: Point2i,
+    film_diagonal: f32,
+    physical_extent: Bounds2f,
+    element_interfaces: Vec<LensElementInterface>,
+    exit_pupil_bounds: Vec<Bounds2f>,
+    effective_focal_length: f32,
+}
+
+impl RealisticCamera {
+    // aperture_diameter and film_diagonal in millimeters, focus_distance in
+    // meters. the rear element is moved so that focus_distance is sharp.
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        lens: &[LensElementInterface],
+        aperture_diameter: f32,
+        focus_distance: f32,
+        film_diagonal: f32,
+        full_resolution: Point2i,
+    ) -> Result<Self, String> {
+        let mut element_interfaces = lens.to_vec();
+        for element in element_interfaces.iter_mut() {
+            if element.curvature_radius == 0.0 {
+                // the stop can only be stopped down from the prescription
+                element.aperture_radius = element.aperture_radius.min(aperture_diameter * 0.001 / 2.0);
+            }
+        }
+
+        let diagonal = film_diagonal * 0.001;
+        let aspect = full_resolution.y as f32 / full_resolution.x as f32;
+        let x = (diagonal * diagonal / (1.0 + aspect * aspect)).sqrt();
+        let y = aspect * x;
+        let physical_extent = Bounds2f::new(Point2f::new(-x / 2.0, -y / 2.0), Point2f::new(x / 2.0, y / 2.0));
+
+        let mut camera = Self {
+            camera_to_world,
+            shutter_open: 0.0,
+            shutter_close: 1.0,
+            medium: None,
+            simple_weighting: true,
+            full_resolution,
+            film_diagonal: diagonal,
+            physical_extent,
+            element_interfaces,
+            exit_pupil_bounds: Vec::new(),
+            effective_focal_length: 0.0,
+        };
+
+        let (pz, fz) = camera.compute_thick_lens_approximation()?;
+        camera.effective_focal_length = fz[0] - pz[0];
+        let rear_thickness = camera.focus_thick_lens(focus_distance, &pz, &fz)?;
+        camera.element_interfaces.last_mut().unwrap().thickness = rear_thickness;
+
+        camera.exit_pupil_bounds = (0..N_EXIT_PUPIL_BOUNDS)
+            .map(|i| {
+                let r0 = i as f32 / N_EXIT_PUPIL_BOUNDS as f32 * camera.film_diagonal / 2.0;
+                let r1 = (i + 1) as f32 / N_EXIT_PUPIL_BOUNDS as f32 * camera.film_diagonal / 2.0;
+                camera.bound_exit_pupil(r0, r1)
+            })
+            .collect();
+        Ok(camera)
+    }
+
+    // focal length of the thick lens approximation, in meters
+    pub fn effective_focal_length(&self) -> f32 {
+        self.effective_focal_length
+    }
+
+    // distance between the film and the rear element
+    pub fn lens_rear_z(&self) -> f32 {
+        self.element_interfaces.last().unwrap().thickness
+    }
+
+    pub fn lens_front_z(&self) -> f32 {
+        self.element_interfaces.iter().map(|e| e.thickness).sum()
+    }
+
+    pub fn rear_element_radius(&self) -> f32 {
+        self.element_interfaces.last().unwrap().aperture_radius
+    }
+
+    // bounds on the rear element of the rays leaving film points at
+    // distance r from the center, r along +x
+    pub fn exit_pupil_bounds(&self, r: f32) -> Bounds2f {
+        let r_index = (r / (self.film_diagonal / 2.0) * self.exit_pupil_bounds.len() as f32) as usize;
+        self.exit_pupil_bounds[r_index.min(self.exit_pupil_bounds.len() - 1)]
+    }
+
+    // traces a camera space ray starting on the film through the lens
+    // system, returning the ray leaving the front element
+    pub fn trace_lenses_from_film<'a>(&self, r_camera: &Ray<'a>) -> Option<Ray<'a>> {
+        let mut element_z = 0.0;
+        let camera_to_lens = Transform::scale(1.0, 1.0, -1.0);
+        let mut r_lens = camera_to_lens.transform_ray(r_camera);
+        for i in (0..self.element_interfaces.len()).rev() {
+            let element = &self.element_interfaces[i];
+            element_z -= element.thickness;
+
+            let is_stop = element.curvature_radius == 0.0;
+            let mut n = Vector3f::default();
+            let t = if is_stop {
+                // the refracted ray might be heading back towards the film
+                if r_lens.d.z >= 0.0 {
+                    return None;
+                }
+                (element_z - r_lens.o.z) / r_lens.d.z
+            } else {
+                let radius = element.curvature_radius;
+                let z_center = element_z + radius;
+                let (t, normal) = intersect_spherical_element(radius, z_center, &r_lens)?;
+                n = normal;
+                t
+            };
+
+            let p_hit = r_lens.point(t);
+            let r2 = p_hit.x * p_hit.x + p_hit.y * p_hit.y;
+            if r2 > element.aperture_radius * element.aperture_radius {
+                return None;
+            }
+            r_lens.o = p_hit;
+
+            if !is_stop {
+                let eta_i = element.eta;
+                let eta_t = if i > 0 && self.element_interfaces[i - 1].eta != 0.0 {
+                    self.element_interfaces[i - 1].eta
+                } else {
+                    1.0
+                };
+                r_lens.d = refract(&Vector3f::normalize(&-r_lens.d), &n, eta_i / eta_t)?;
+            }
+        }
+        let lens_to_camera = Transform::scale(1.0, 1.0, -1.0);
+        Some(lens_to_camera.transform_ray(&r_lens))
+    }
+
+    // traces a camera space ray coming from the scene towards the film
+    pub fn trace_lenses_from_scene<'a>(&self, r_camera: &Ray<'a>) -> Option<Ray<'a>> {
+        let mut element_z = -self.lens_front_z();
+        let camera_to_lens = Transform::scale(1.0, 1.0, -1.0);
+        let mut r_lens = camera_to_lens.transform_ray(r_camera);
+        for i in 0..self.element_interfaces.len() {
+            let element = &self.element_interfaces[i];
+            let is_stop = element.curvature_radius == 0.0;
+            let mut n = Vector3f::default();
+            let t = if is_stop {
+                (element_z - r_lens.o.z) / r_lens.d.z
+            } else {
+                let radius = element.curvature_radius;
+                let z_center = element_z + radius;
+                let (t, normal) = intersect_spherical_element(radius, z_center, &r_lens)?;
+                n = normal;
+                t
+            };
+
+            let p_hit = r_lens.point(t);
+            let r2 = p_hit.x * p_hit.x + p_hit.y * p_hit.y;
+            if r2 > element.aperture_radius * element.aperture_radius {
+                return None;
+            }
+            r_lens.o = p_hit;
+
+            if !is_stop {
+                let eta_i = if i == 0 || self.element_interfaces[i - 1].eta == 0.0 {
+                    1.0
+                } else {
+                    self.element_interfaces[i - 1].eta
+                };
+                let eta_t = if element.eta != 0.0 { element.eta } else { 1.0 };
+                r_lens.d = refract(&Vector3f::normalize(&-r_lens.d), &n, eta_i / eta_t)?;
+            }
+            element_z += element.thickness;
+        }
+        let lens_to_camera = Transform::scale(1.0, 1.0, -1.0);
+        Some(lens_to_camera.transform_ray(&r_lens))
+    }
+
+    // z of the principal plane and focal point for a ray parallel to the axis
+    fn compute_cardinal_points(r_in: &Ray, r_out: &Ray) -> (f32, f32) {
+        let tf = -r_out.o.x / r_out.d.x;
+        let fz = -r_out.point(tf).z;
+        let tp = (r_in.o.x - r_out.o.x) / r_out.d.x;
+        let pz = -r_out.point(tp).z;
+        (pz, fz)
+    }
+
+    fn compute_thick_lens_approximation(&self) -> Result<([f32; 2], [f32; 2]), String> {
+        // a small offset from the axis, within the paraxial region
+        let x = 0.001 * self.film_diagonal;
+        let r_scene = Ray::new(
+            Point3f::new(x, 0.0, self.lens_front_z() + 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            f32::INFINITY,
+            0.0,
+            None,
+        );
+        let r_film = self
+            .trace_lenses_from_scene(&r_scene)
+            .ok_or("unable to trace ray from scene to film for thick lens approximation")?;
+        let (pz0, fz0) = Self::compute_cardinal_points(&r_scene, &r_film);
+
+        let r_film = Ray::new(
+            Point3f::new(x, 0.0, self.lens_rear_z() - 1.0),
+            Vector3f::new(0.0, 0.0, 1.0),
+            f32::INFINITY,
+            0.0,
+            None,
+        );
+        let r_scene = self
+            .trace_lenses_from_film(&r_film)
+            .ok_or("unable to trace ray from film to scene for thick lens approximation")?;
+        let (pz1, fz1) = Self::compute_cardinal_points(&r_film, &r_scene);
+        Ok(([pz0, pz1], [fz0, fz1]))
+    }
+
+    // rear element thickness that brings focus_distance into focus
+    fn focus_thick_lens(&self, focus_distance: f32, pz: &[f32; 2], fz: &[f32; 2]) -> Result<f32, String> {
+        let f = fz[0] - pz[0];
+        let z = -focus_distance;
+        let c = (pz[1] - z - pz[0]) * (pz[1] - z - 4.0 * f - pz[0]);
+        if c <= 0.0 {
+            return Err(format!(
+                "coefficient must be positive, focus distance {} is too short for a focal length of {}",
+                focus_distance, f
+            ));
+        }
+        let delta = 0.5 * (pz[1] - z + pz[0] - c.sqrt());
+        Ok(self.lens_rear_z() + delta)
+    }
+
+    fn bound_exit_pupil(&self, p_film_x0: f32, p_film_x1: f32) -> Bounds2f {
+        let mut pupil_bounds = Bounds2f::empty();
+        let mut n_exiting_rays = 0;
+        let rear_radius = self.rear_element_radius();
+        let proj_rear_bounds = Bounds2f::new(
+            Point2f::new(-1.5 * rear_radius, -1.5 * rear_radius),
+            Point2f::new(1.5 * rear_radius, 1.5 * rear_radius),
+        );
+
+        // stratified points on the film segment and the rear element's plane
+        let n = (N_EXIT_PUPIL_SAMPLES as f32).sqrt() as usize;
+        for i in 0..N_EXIT_PUPIL_SAMPLES {
+            let p_film = Point3f::new(
+                lerp((i as f32 + 0.5) / N_EXIT_PUPIL_SAMPLES as f32, p_film_x0, p_film_x1),
+                0.0,
+                0.0,
+            );
+            let u = Point2f::new(((i % n) as f32 + 0.5) / n as f32, ((i / n) as f32 + 0.5) / n as f32);
+            let p = proj_rear_bounds.lerp(&u);
+            let p_rear = Point3f::new(p.x, p.y, self.lens_rear_z());
+            if Bounds2f::inside(&p, &pupil_bounds)
+                || self
+                    .trace_lenses_from_film(&Ray::new(p_film, p_rear - p_film, f32::INFINITY, 0.0, None))
+                    .is_some()
+            {
+                pupil_bounds = Bounds2f::union_from_point(&pupil_bounds, p);
+                n_exiting_rays += 1;
+            }
+        }
+        if n_exiting_rays == 0 {
+            return proj_rear_bounds;
+        }
+        // grow by the sample spacing to cover what falls between samples
+        Bounds2f::expand(&pupil_bounds, 2.0 * proj_rear_bounds.diagonal().length() / n as f32)
+    }
+
+    // point on the rear element plane for a film point, and the area of the
+    // bounds it was sampled from
+    pub fn sample_exit_pupil(&self, p_film: &Point2f, lens_sample: &Point2f) -> (Point3f, f32) {
+        let r_film = (p_film.x * p_film.x + p_film.y * p_film.y).sqrt();
+        let pupil_bounds = self.exit_pupil_bounds(r_film);
+        let sample_bounds_area = pupil_bounds.area();
+
+        // rotate the bounds computed along +x to the film point
+        let p_lens = pupil_bounds.lerp(lens_sample);
+        let sin_theta = if r_film != 0.0 { p_film.y / r_film } else { 0.0 };
+        let cos_theta = if r_film != 0.0 { p_film.x / r_film } else { 1.0 };
+        (
+            Point3f::new(
+                cos_theta * p_lens.x - sin_theta * p_lens.y,
+                sin_theta * p_lens.x + cos_theta * p_lens.y,
+                self.lens_rear_z(),
+            ),
+            sample_bounds_area,
+        )
+    }
+}
+
+impl Camera for RealisticCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        // the image is flipped by the lens, so is the film
+        let s = Point2f::new(
+            sample.p_film.x / self.full_resolution.x as f32,
+            sample.p_film.y / self.full_resolution.y as f32,
+        );
+        let p_film2 = self.physical_extent.lerp(&s);
+        let p_film = Point3f::new(-p_film2.x, p_film2.y, 0.0);
+
+        let (p_rear, exit_pupil_bounds_area) = self.sample_exit_pupil(&Point2f::new(p_film.x, p_film.y), &sample.p_lens);
+        let r_film = Ray::new(
+            p_film,
+            p_rear - p_film,
+            f32::INFINITY,
+            lerp(sample.time, self.shutter_open, self.shutter_close),
+            self.medium.as_ref(),
+        );
+        let ray = match self.trace_lenses_from_film(&r_film) {
+            Some(ray) => ray,
+            None => return (0.0, Ray::default()),
+        };
+        let mut ray = self.camera_to_world.transform_ray(&ray);
+        ray.d = Vector3f::normalize(&ray.d);
+
+        let cos_theta = Vector3f::normalize(&r_film.d).z;
+        let cos4_theta = (cos_theta * cos_theta) * (cos_theta * cos_theta);
+        let weight = if self.simple_weighting {
+            cos4_theta * exit_pupil_bounds_area / self.exit_pupil_bounds[0].area()
+        } else {
+            (self.shutter_close - self.shutter_open) * (cos4_theta * exit_pupil_bounds_area)
+                / (self.lens_rear_z() * self.lens_rear_z())
+        };
+        (weight, ray)
+    }
+}
+
+fn intersect_spherical_element(radius: f32, z_center: f32, ray: &Ray) -> Option<(f32, Vector3f)> {
+    let o = ray.o - Vector3f::new(0.0, 0.0, z_center);
+    let a = ray.d.x * ray.d.x + ray.d.y * ray.d.y + ray.d.z * ray.d.z;
+    let b = 2.0 * (ray.d.x * o.x + ray.d.y * o.y + ray.d.z * o.z);
+    let c = o.x * o.x + o.y * o.y + o.z * o.z - radius * radius;
+    let (t0, t1) = quadratic(a, b, c)?;
+
+    // convex or concave as seen by the ray decides which hit is the surface
+    let use_closer_t = (ray.d.z > 0.0) ^ (radius < 0.0);
+    let t = if use_closer_t { t0.min(t1) } else { t0.max(t1) };
+    if t < 0.0 {
+        return None;
+    }
+    let n = Vector3f::normalize(&(o + ray.d * t - Point3f::new(0.0, 0.0, 0.0)));
+    // face the normal towards the incoming ray
+    let n = if Vector3f::dot(&n, &-ray.d) < 0.0 { -n } else { n };
+    Some((t, n))
+}
+
+// Snell's law, wi points away from the surface, None for total internal reflection
+fn refract(wi: &Vector3f, n: &Vector3f, eta: f32) -> Option<Vector3f> {
+    let cos_theta_i = Vector3f::dot(n, wi);
+    let sin2_theta_i = (1.0 - cos_theta_i * cos_theta_i).max(0.0);
+    let sin2_theta_t = eta * eta * sin2_theta_i;
+    if sin2_theta_t >= 1.0 {
+        return None;
+    }
+    let cos_theta_t = (1.0 - sin2_theta_t).sqrt();
+    Some(-*wi * eta + *n * (eta * cos_theta_i - cos_theta_t))
+}
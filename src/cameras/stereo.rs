@@ -0,0 +1,222 @@
+use crate::core::camera::{Camera, CameraSample, ProjectiveCamera};
+use crate::core::geometry::{Point2f, Point2i, Point3f, Ray, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::transform::{AnimatedTransform, Transform};
+
+use super::EnvironmentMapping;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StereoEye {
+    Left,
+    Right,
+}
+
+impl StereoEye {
+    // sign of the eye's offset along the camera space x axis
+    fn sign(&self) -> f32 {
+        match *self {
+            StereoEye::Left => -1.0,
+            StereoEye::Right => 1.0,
+        }
+    }
+}
+
+// how the two eyes share the film
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StereoLayout {
+    // left eye in the top half, right eye in the bottom half
+    TopBottom,
+    // left eye in the left half, right eye in the right half
+    SideBySide,
+}
+
+impl StereoLayout {
+    // eye seen at a film position in [0, 1]^2, and the position within that
+    // eye's image
+    pub fn eye_position(&self, uv: &Point2f) -> (StereoEye, Point2f) {
+        match *self {
+            StereoLayout::TopBottom => {
+                if uv.y < 0.5 {
+                    (StereoEye::Left, Point2f::new(uv.x, 2.0 * uv.y))
+                } else {
+                    (StereoEye::Right, Point2f::new(uv.x, 2.0 * uv.y - 1.0))
+                }
+            }
+            StereoLayout::SideBySide => {
+                if uv.x < 0.5 {
+                    (StereoEye::Left, Point2f::new(2.0 * uv.x, uv.y))
+                } else {
+                    (StereoEye::Right, Point2f::new(2.0 * uv.x - 1.0, uv.y))
+                }
+            }
+        }
+    }
+
+    // inverse of eye_position
+    pub fn film_position(&self, eye: StereoEye, p: &Point2f) -> Point2f {
+        let offset = if eye == StereoEye::Left { 0.0 } else { 0.5 };
+        match *self {
+            StereoLayout::TopBottom => Point2f::new(p.x, 0.5 * p.y + offset),
+            StereoLayout::SideBySide => Point2f::new(0.5 * p.x + offset, p.y),
+        }
+    }
+
+    // resolution of one eye's image
+    pub fn eye_resolution(&self, full_resolution: Point2i) -> Point2i {
+        match *self {
+            StereoLayout::TopBottom => Point2i::new(full_resolution.x, full_resolution.y / 2),
+            StereoLayout::SideBySide => Point2i::new(full_resolution.x / 2, full_resolution.y),
+        }
+    }
+}
+
+// pair of off-axis perspective cameras side by side. the eye frustums are
+// sheared so that they coincide on the convergence plane, which is where
+// objects show up with zero parallax.
+#[derive(Clone, Debug)]
+pub struct StereoCamera {
+    pub projective: ProjectiveCamera,
+    pub full_resolution: Point2i,
+    pub layout: StereoLayout,
+    pub interpupillary_distance: f32,
+    // distance of the zero parallax plane, infinity for parallel eyes
+    pub convergence_distance: f32,
+}
+
+impl StereoCamera {
+    // fov in degrees for each eye, spanning the shorter axis of its image
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        full_resolution: Point2i,
+        layout: StereoLayout,
+        fov: f32,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        let eye_resolution = layout.eye_resolution(full_resolution);
+        let frame = eye_resolution.x as f32 / eye_resolution.y as f32;
+        let projective = ProjectiveCamera::new(
+            camera_to_world,
+            Transform::perspective(fov, 1e-2, 1000.0),
+            ProjectiveCamera::screen_window(frame),
+            eye_resolution,
+            shutter_open,
+            shutter_close,
+            medium,
+        );
+        Self {
+            projective,
+            full_resolution,
+            layout,
+            interpupillary_distance: 0.064,
+            convergence_distance: f32::INFINITY,
+        }
+    }
+
+    pub fn with_interpupillary_distance(mut self, interpupillary_distance: f32) -> Self {
+        self.interpupillary_distance = interpupillary_distance;
+        self
+    }
+
+    pub fn with_convergence(mut self, convergence_distance: f32) -> Self {
+        self.convergence_distance = convergence_distance;
+        self
+    }
+
+    // camera space ray for one eye and a raster position in that eye's image
+    pub fn eye_ray(&self, eye: StereoEye, p_raster: &Point2f, time: f32) -> Ray<'_> {
+        let p_camera = self.projective.raster_to_camera_point(p_raster);
+        let offset = eye.sign() * 0.5 * self.interpupillary_distance;
+        // shift the window by the eye offset scaled to the convergence plane
+        let d = Vector3f::new(
+            p_camera.x / p_camera.z - offset / self.convergence_distance,
+            p_camera.y / p_camera.z,
+            1.0,
+        );
+        Ray::new(
+            Point3f::new(offset, 0.0, 0.0),
+            Vector3f::normalize(&d),
+            f32::INFINITY,
+            time,
+            self.projective.medium.as_ref(),
+        )
+    }
+}
+
+impl Camera for StereoCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        let uv = Point2f::new(
+            sample.p_film.x / self.full_resolution.x as f32,
+            sample.p_film.y / self.full_resolution.y as f32,
+        );
+        let (eye, p) = self.layout.eye_position(&uv);
+        let eye_resolution = self.layout.eye_resolution(self.full_resolution);
+        let p_raster = Point2f::new(p.x * eye_resolution.x as f32, p.y * eye_resolution.y as f32);
+        let ray = self.eye_ray(eye, &p_raster, self.projective.time(sample));
+        (1.0, self.projective.camera_to_world.transform_ray(&ray))
+    }
+}
+
+// omni-directional stereo panorama. every eye has an equirectangular image
+// whose rays start on a circle of interpupillary diameter, tangent to the
+// viewing direction, so each longitude is seen with the correct parallax.
+#[derive(Clone, Debug)]
+pub struct ODSCamera {
+    pub camera_to_world: AnimatedTransform,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub medium: Option<Medium>,
+    pub full_resolution: Point2i,
+    pub layout: StereoLayout,
+    pub interpupillary_distance: f32,
+}
+
+impl ODSCamera {
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        full_resolution: Point2i,
+        interpupillary_distance: f32,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        Self {
+            camera_to_world,
+            shutter_open,
+            shutter_close,
+            medium,
+            full_resolution,
+            layout: StereoLayout::TopBottom,
+            interpupillary_distance,
+        }
+    }
+
+    pub fn with_layout(mut self, layout: StereoLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    // camera space ray for one eye and a position in [0, 1]^2 of its panorama
+    pub fn eye_ray(&self, eye: StereoEye, p: &Point2f, time: f32) -> Ray<'_> {
+        let d = EnvironmentMapping::Equirectangular.direction(p, 2.0).unwrap();
+        // the eye's right hand side in the horizontal plane, y is up
+        let phi = d.z.atan2(d.x);
+        let right = Vector3f::new(phi.sin(), 0.0, -phi.cos());
+        let o = Point3f::new(0.0, 0.0, 0.0) + right * (eye.sign() * 0.5 * self.interpupillary_distance);
+        Ray::new(o, d, f32::INFINITY, time, self.medium.as_ref())
+    }
+}
+
+impl Camera for ODSCamera {
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>) {
+        let uv = Point2f::new(
+            sample.p_film.x / self.full_resolution.x as f32,
+            sample.p_film.y / self.full_resolution.y as f32,
+        );
+        let (eye, p) = self.layout.eye_position(&uv);
+        let time = self.shutter_open + (self.shutter_close - self.shutter_open) * sample.time;
+        let ray = self.eye_ray(eye, &p, time);
+        (1.0, self.camera_to_world.transform_ray(&ray))
+    }
+}
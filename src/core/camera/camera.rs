@@ -0,0 +1,259 @@
+use crate::core::geometry::{Bounds2f, Point2f, Point2i, Point3f, Ray, RayDifferential, Vector3f};
+use crate::core::medium::Medium;
+use crate::core::pbrt::PI;
+use crate::core::sampling::{concentric_sample_disk, uniform_sample_triangle};
+use crate::core::transform::{AnimatedTransform, Transform};
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct CameraSample {
+    pub p_film: Point2f,
+    pub p_lens: Point2f,
+    // in [0, 1), mapped onto the shutter interval by the camera
+    pub time: f32,
+}
+
+pub trait Camera: Send + Sync {
+    // world space ray for the sample and the weight of the radiance it carries
+    fn generate_ray(&self, sample: &CameraSample) -> (f32, Ray<'_>);
+
+    // fallback that finds the differentials by tracing rays one pixel over
+    fn generate_ray_differential(&self, sample: &CameraSample) -> (f32, RayDifferential<'_>) {
+        let (wt, ray) = self.generate_ray(sample);
+        let mut rd = RayDifferential::from_ray(ray);
+        if wt == 0.0 {
+            return (0.0, rd);
+        }
+
+        let mut sshift = *sample;
+        sshift.p_film.x += 1.0;
+        let (wtx, rx) = self.generate_ray(&sshift);
+        if wtx == 0.0 {
+            return (0.0, rd);
+        }
+        rd.rx_origin = rx.o;
+        rd.rx_direction = rx.d;
+
+        sshift.p_film.x -= 1.0;
+        sshift.p_film.y += 1.0;
+        let (wty, ry) = self.generate_ray(&sshift);
+        if wty == 0.0 {
+            return (0.0, rd);
+        }
+        rd.ry_origin = ry.o;
+        rd.ry_direction = ry.d;
+        rd.has_differentials = true;
+        (wt, rd)
+    }
+}
+
+// shape of the lens opening, sampled over [-1, 1]^2 and scaled by the
+// lens radius
+#[derive(Clone, Debug, PartialEq)]
+pub enum Aperture {
+    Circular,
+    // regular polygon with its vertices on the unit circle, rotation in degrees
+    Polygonal { blades: u32, rotation: f32 },
+    Image(ImageAperture),
+}
+
+impl Aperture {
+    pub fn polygonal(blades: u32, rotation: f32) -> Self {
+        assert!(blades >= 3, "a polygonal aperture needs at least 3 blades");
+        Aperture::Polygonal { blades, rotation }
+    }
+
+    pub fn sample(&self, u: &Point2f) -> Point2f {
+        match self {
+            Aperture::Circular => concentric_sample_disk(u),
+            Aperture::Polygonal { blades, rotation } => {
+                // pick one triangle of the fan around the center, then
+                // sample it uniformly with the remapped first dimension
+                let n = *blades as f32;
+                let i = ((u.x * n) as u32).min(blades - 1);
+                let u_remapped = Point2f::new((u.x * n - i as f32).min(1.0), u.y);
+                let b = uniform_sample_triangle(&u_remapped);
+                let phi0 = rotation.to_radians() + 2.0 * PI * i as f32 / n;
+                let phi1 = rotation.to_radians() + 2.0 * PI * (i + 1) as f32 / n;
+                let v0 = Point2f::new(phi0.cos(), phi0.sin());
+                let v1 = Point2f::new(phi1.cos(), phi1.sin());
+                // the third vertex is the center, which contributes nothing
+                Point2f::add_element(v0 * b.x, v1 * b.y)
+            }
+            Aperture::Image(image) => image.sample(u),
+        }
+    }
+}
+
+// aperture given as a grayscale mask covering [-1, 1]^2, row 0 at the top.
+// points are distributed proportionally to the mask value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageAperture {
+    width: usize,
+    height: usize,
+    conditional_cdf: Vec<f32>,
+    marginal_cdf: Vec<f32>,
+}
+
+impl ImageAperture {
+    pub fn new(mask: &[f32], width: usize, height: usize) -> Self {
+        assert_eq!(mask.len(), width * height);
+        let mut conditional_cdf = vec![0.0; height * (width + 1)];
+        let mut marginal_cdf = vec![0.0; height + 1];
+        for y in 0..height {
+            let cdf = &mut conditional_cdf[y * (width + 1)..(y + 1) * (width + 1)];
+            for x in 0..width {
+                cdf[x + 1] = cdf[x] + mask[y * width + x].max(0.0);
+            }
+            marginal_cdf[y + 1] = marginal_cdf[y] + cdf[width];
+        }
+        assert!(marginal_cdf[height] > 0.0, "aperture mask is black");
+        Self {
+            width,
+            height,
+            conditional_cdf,
+            marginal_cdf,
+        }
+    }
+
+    // continuous inversion of a non-normalized piecewise constant cdf
+    fn sample_cdf(cdf: &[f32], u: f32) -> f32 {
+        let target = u * cdf[cdf.len() - 1];
+        let n = cdf.len() - 1;
+        let mut i = cdf.partition_point(|&c| c <= target).clamp(1, n) - 1;
+        // only possible for u at the top end, step back over empty buckets
+        while i > 0 && cdf[i + 1] == cdf[i] {
+            i -= 1;
+        }
+        let du = if cdf[i + 1] > cdf[i] {
+            ((target - cdf[i]) / (cdf[i + 1] - cdf[i])).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (i as f32 + du) / n as f32
+    }
+
+    pub fn sample(&self, u: &Point2f) -> Point2f {
+        let v = Self::sample_cdf(&self.marginal_cdf, u.y);
+        let y = ((v * self.height as f32) as usize).min(self.height - 1);
+        let cdf = &self.conditional_cdf[y * (self.width + 1)..(y + 1) * (self.width + 1)];
+        let s = Self::sample_cdf(cdf, u.x);
+        Point2f::new(2.0 * s - 1.0, 1.0 - 2.0 * v)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThinLens {
+    pub lens_radius: f32,
+    pub focal_distance: f32,
+    pub aperture: Aperture,
+}
+
+impl Default for ThinLens {
+    fn default() -> Self {
+        Self::pinhole()
+    }
+}
+
+impl ThinLens {
+    pub fn new(lens_radius: f32, focal_distance: f32, aperture: Aperture) -> Self {
+        Self {
+            lens_radius,
+            focal_distance,
+            aperture,
+        }
+    }
+
+    pub fn pinhole() -> Self {
+        Self::new(0.0, 1e6, Aperture::Circular)
+    }
+
+    pub fn is_pinhole(&self) -> bool {
+        self.lens_radius <= 0.0
+    }
+
+    pub fn sample_lens(&self, u: &Point2f) -> Point3f {
+        let p_lens = self.aperture.sample(u) * self.lens_radius;
+        Point3f::new(p_lens.x, p_lens.y, 0.0)
+    }
+
+    // bends a camera space ray through the lens so that it still passes the
+    // point it would have hit on the plane of focus
+    pub fn focus<'a>(&self, ray: &Ray<'a>, p_lens: &Point3f) -> Ray<'a> {
+        let ft = self.focal_distance / ray.d.z;
+        let p_focus = ray.o + ray.d * ft;
+        let mut r = *ray;
+        r.o = *p_lens;
+        r.d = Vector3f::normalize(&(p_focus - *p_lens));
+        r
+    }
+}
+
+// state shared by cameras that map the film through a 4x4 projection
+#[derive(Clone, Debug)]
+pub struct ProjectiveCamera {
+    pub camera_to_world: AnimatedTransform,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub medium: Option<Medium>,
+    pub camera_to_screen: Transform,
+    pub raster_to_camera: Transform,
+    pub screen_to_raster: Transform,
+    pub raster_to_screen: Transform,
+    pub lens: ThinLens,
+}
+
+impl ProjectiveCamera {
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        camera_to_screen: Transform,
+        screen_window: Bounds2f,
+        full_resolution: Point2i,
+        shutter_open: f32,
+        shutter_close: f32,
+        medium: Option<Medium>,
+    ) -> Self {
+        let screen_to_raster = Transform::scale(full_resolution.x as f32, full_resolution.y as f32, 1.0)
+            * Transform::scale(
+                1.0 / (screen_window.p_max.x - screen_window.p_min.x),
+                1.0 / (screen_window.p_min.y - screen_window.p_max.y),
+                1.0,
+            )
+            * Transform::translate(&Vector3f::new(-screen_window.p_min.x, -screen_window.p_max.y, 0.0));
+        let raster_to_screen = Transform::inverse(&screen_to_raster);
+        let raster_to_camera = Transform::inverse(&camera_to_screen) * raster_to_screen;
+        Self {
+            camera_to_world,
+            shutter_open,
+            shutter_close,
+            medium,
+            camera_to_screen,
+            raster_to_camera,
+            screen_to_raster,
+            raster_to_screen,
+            lens: ThinLens::pinhole(),
+        }
+    }
+
+    // screen window spanning [-1, 1] along the shorter image axis
+    pub fn screen_window(frame_aspect_ratio: f32) -> Bounds2f {
+        if frame_aspect_ratio > 1.0 {
+            Bounds2f::new(
+                Point2f::new(-frame_aspect_ratio, -1.0),
+                Point2f::new(frame_aspect_ratio, 1.0),
+            )
+        } else {
+            Bounds2f::new(
+                Point2f::new(-1.0, -1.0 / frame_aspect_ratio),
+                Point2f::new(1.0, 1.0 / frame_aspect_ratio),
+            )
+        }
+    }
+
+    pub fn time(&self, sample: &CameraSample) -> f32 {
+        self.shutter_open + (self.shutter_close - self.shutter_open) * sample.time
+    }
+
+    pub fn raster_to_camera_point(&self, p_film: &Point2f) -> Point3f {
+        self.raster_to_camera.transform_point(&Point3f::new(p_film.x, p_film.y, 0.0))
+    }
+}
@@ -0,0 +1,3 @@
+pub use camera::*;
+
+mod camera;
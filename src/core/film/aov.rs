@@ -0,0 +1,237 @@
+use crate::core::geometry::{Normal3f, Point2f, Point3f, Vector3f};
+use crate::core::imageio::{ExrPixelType, ExrValue};
+
+// id of samples that hit nothing
+pub const AOV_NO_ID: u32 = u32::MAX;
+
+// auxiliary data at the first intersection of a camera ray. the default value
+// is a ray that escaped the scene.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AovSample {
+    // shading normal in world space
+    pub normal: Normal3f,
+    // distance to the hit along the camera's z axis
+    pub depth: f32,
+    // world space hit point
+    pub position: Point3f,
+    pub uv: Point2f,
+    pub albedo: [f32; 3],
+    pub primitive_id: u32,
+    pub material_id: u32,
+}
+
+impl Default for AovSample {
+    fn default() -> Self {
+        Self {
+            normal: Normal3f::default(),
+            depth: f32::INFINITY,
+            position: Point3f::default(),
+            uv: Point2f::default(),
+            albedo: [0.0; 3],
+            primitive_id: AOV_NO_ID,
+            material_id: AOV_NO_ID,
+        }
+    }
+}
+
+impl AovSample {
+    pub fn is_hit(&self) -> bool {
+        self.depth.is_finite()
+    }
+}
+
+// how the samples of a pixel are combined
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AovFiltering {
+    // weighted by the film's filter, like radiance
+    Filter,
+    // average of the hits inside the pixel. filtering across an edge would
+    // give depths and positions that lie on no surface.
+    Box,
+    // value of the sample closest to the pixel center, ids can't be averaged
+    Nearest,
+    // number of samples inside the pixel
+    Count,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Aov {
+    Normal,
+    Depth,
+    Position,
+    Uv,
+    Albedo,
+    PrimitiveId,
+    MaterialId,
+    SampleCount,
+}
+
+impl Aov {
+    pub const ALL: [Aov; 8] = [
+        Aov::Normal,
+        Aov::Depth,
+        Aov::Position,
+        Aov::Uv,
+        Aov::Albedo,
+        Aov::PrimitiveId,
+        Aov::MaterialId,
+        Aov::SampleCount,
+    ];
+
+    pub fn filtering(&self) -> AovFiltering {
+        match *self {
+            Aov::Normal | Aov::Uv | Aov::Albedo => AovFiltering::Filter,
+            Aov::Depth | Aov::Position => AovFiltering::Box,
+            Aov::PrimitiveId | Aov::MaterialId => AovFiltering::Nearest,
+            Aov::SampleCount => AovFiltering::Count,
+        }
+    }
+
+    // channel names in OpenEXR output
+    pub fn channel_names(&self) -> &'static [&'static str] {
+        match *self {
+            Aov::Normal => &["N.X", "N.Y", "N.Z"],
+            Aov::Depth => &["Z"],
+            Aov::Position => &["P.X", "P.Y", "P.Z"],
+            Aov::Uv => &["uv.U", "uv.V"],
+            Aov::Albedo => &["albedo.R", "albedo.G", "albedo.B"],
+            Aov::PrimitiveId => &["primitiveId"],
+            Aov::MaterialId => &["materialId"],
+            Aov::SampleCount => &["sampleCount"],
+        }
+    }
+
+    pub fn pixel_type(&self) -> ExrPixelType {
+        match *self {
+            Aov::Normal | Aov::Albedo => ExrPixelType::Half,
+            Aov::Depth | Aov::Position | Aov::Uv => ExrPixelType::Float,
+            Aov::PrimitiveId | Aov::MaterialId | Aov::SampleCount => ExrPixelType::Uint,
+        }
+    }
+
+    // value of channel i of a resolved pixel, integers for the uint channels
+    pub fn channel_value(&self, v: &AovSample, sample_count: u32, i: usize) -> ExrValue {
+        match *self {
+            Aov::Normal => ExrValue::Float(v.normal[i]),
+            Aov::Depth => ExrValue::Float(v.depth),
+            Aov::Position => ExrValue::Float(v.position[i]),
+            Aov::Uv => ExrValue::Float(v.uv[i]),
+            Aov::Albedo => ExrValue::Float(v.albedo[i]),
+            Aov::PrimitiveId => ExrValue::Uint(v.primitive_id),
+            Aov::MaterialId => ExrValue::Uint(v.material_id),
+            Aov::SampleCount => ExrValue::Uint(sample_count),
+        }
+    }
+}
+
+// running sums of the auxiliary data of one pixel
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct AovPixel {
+    normal: [f32; 3],
+    uv: [f32; 2],
+    albedo: [f32; 3],
+    filter_weight_sum: f32,
+    depth: f32,
+    position: [f32; 3],
+    n_hits: u32,
+    nearest_distance: f32,
+    primitive_id: u32,
+    material_id: u32,
+}
+
+impl Default for AovPixel {
+    fn default() -> Self {
+        Self {
+            normal: [0.0; 3],
+            uv: [0.0; 2],
+            albedo: [0.0; 3],
+            filter_weight_sum: 0.0,
+            depth: 0.0,
+            position: [0.0; 3],
+            n_hits: 0,
+            nearest_distance: f32::INFINITY,
+            primitive_id: AOV_NO_ID,
+            material_id: AOV_NO_ID,
+        }
+    }
+}
+
+impl AovPixel {
+    // contribution to a pixel within the filter's support
+    pub(crate) fn add_filtered(&mut self, s: &AovSample, filter_weight: f32) {
+        for i in 0..3 {
+            self.normal[i] += filter_weight * s.normal[i];
+            self.albedo[i] += filter_weight * s.albedo[i];
+        }
+        self.uv[0] += filter_weight * s.uv.x;
+        self.uv[1] += filter_weight * s.uv.y;
+        self.filter_weight_sum += filter_weight;
+    }
+
+    // contribution to the pixel containing the sample, distance is measured
+    // from the pixel center
+    pub(crate) fn add_inside(&mut self, s: &AovSample, distance: f32) {
+        if s.is_hit() {
+            self.depth += s.depth;
+            for i in 0..3 {
+                self.position[i] += s.position[i];
+            }
+            self.n_hits += 1;
+        }
+        if distance < self.nearest_distance {
+            self.nearest_distance = distance;
+            self.primitive_id = s.primitive_id;
+            self.material_id = s.material_id;
+        }
+    }
+
+    // ties keep the ids already present, so merging in a fixed order is
+    // deterministic
+    pub(crate) fn merge(&mut self, other: &AovPixel) {
+        for i in 0..3 {
+            self.normal[i] += other.normal[i];
+            self.albedo[i] += other.albedo[i];
+            self.position[i] += other.position[i];
+        }
+        self.uv[0] += other.uv[0];
+        self.uv[1] += other.uv[1];
+        self.filter_weight_sum += other.filter_weight_sum;
+        self.depth += other.depth;
+        self.n_hits += other.n_hits;
+        if other.nearest_distance < self.nearest_distance {
+            self.nearest_distance = other.nearest_distance;
+            self.primitive_id = other.primitive_id;
+            self.material_id = other.material_id;
+        }
+    }
+
+    pub(crate) fn resolve(&self) -> AovSample {
+        let mut v = AovSample {
+            primitive_id: self.primitive_id,
+            material_id: self.material_id,
+            ..Default::default()
+        };
+        if self.filter_weight_sum != 0.0 {
+            let inv_weight = 1.0 / self.filter_weight_sum;
+            let n = Vector3f::new(self.normal[0], self.normal[1], self.normal[2]);
+            if n.length_squared() > 0.0 {
+                let n = Vector3f::normalize(&n);
+                v.normal = Normal3f::new(n.x, n.y, n.z);
+            }
+            v.uv = Point2f::new(self.uv[0] * inv_weight, self.uv[1] * inv_weight);
+            for i in 0..3 {
+                v.albedo[i] = (self.albedo[i] * inv_weight).max(0.0);
+            }
+        }
+        if self.n_hits > 0 {
+            let inv_hits = 1.0 / self.n_hits as f32;
+            v.depth = self.depth * inv_hits;
+            v.position = Point3f::new(
+                self.position[0] * inv_hits,
+                self.position[1] * inv_hits,
+                self.position[2] * inv_hits,
+            );
+        }
+        v
+    }
+}
@@ -0,0 +1,538 @@
+use crate::core::film::{Aov, AovPixel, AovSample};
+use crate::core::filter::Filter;
+use crate::core::geometry::{Bounds2f, Bounds2i, Point2f, Point2i, Vector2f};
+use crate::core::imageio::{read_image, write_image, ExrChannelData, ExrImage, ExrPixelType, ExrValue};
+use crate::core::pbrt::{clamp, AtomicFloat};
+use crate::core::spectrum::{SampledWavelengths, SpectrumSamples};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+const FILTER_TABLE_WIDTH: usize = 16;
+
+#[derive(Copy, Clone, Debug, Default)]
+struct Pixel {
+    rgb: [f32; 3],
+    filter_weight_sum: f32,
+    sample_count: u32,
+}
+
+// filter table and radius shared by the film and its tiles
+struct FilterTable {
+    radius: Vector2f,
+    inv_radius: Vector2f,
+    values: Vec<f32>,
+}
+
+impl FilterTable {
+    fn new(filter: &dyn Filter) -> Self {
+        // filter values at the centers of a grid over the positive quadrant,
+        // the filters are symmetric so this covers all of them
+        let radius = filter.radius();
+        let mut values = Vec::with_capacity(FILTER_TABLE_WIDTH * FILTER_TABLE_WIDTH);
+        for y in 0..FILTER_TABLE_WIDTH {
+            for x in 0..FILTER_TABLE_WIDTH {
+                let p = Point2f::new(
+                    (x as f32 + 0.5) * radius.x / FILTER_TABLE_WIDTH as f32,
+                    (y as f32 + 0.5) * radius.y / FILTER_TABLE_WIDTH as f32,
+                );
+                values.push(filter.evaluate(&p));
+            }
+        }
+        Self {
+            radius,
+            inv_radius: Vector2f::new(1.0 / radius.x, 1.0 / radius.y),
+            values,
+        }
+    }
+
+    // calls f with the offset and filter weight of every pixel of bounds whose
+    // filter covers p_film
+    fn for_each_pixel<F>(&self, bounds: &Bounds2i, p_film: &Point2f, mut f: F)
+    where
+        F: FnMut(usize, f32),
+    {
+        let p_discrete = Point2f::new(p_film.x - 0.5, p_film.y - 0.5);
+        let p0 = Point2i::new(
+            (p_discrete.x - self.radius.x).ceil() as i16,
+            (p_discrete.y - self.radius.y).ceil() as i16,
+        );
+        let p1 = Point2i::new(
+            (p_discrete.x + self.radius.x).floor() as i16 + 1,
+            (p_discrete.y + self.radius.y).floor() as i16 + 1,
+        );
+        let covered = Bounds2i::new(Point2i::max(&p0, &bounds.p_min), Point2i::min(&p1, &bounds.p_max));
+        if covered.is_empty() {
+            return;
+        }
+
+        let table_index = |d: f32, inv_radius: f32| {
+            ((d.abs() * inv_radius * FILTER_TABLE_WIDTH as f32).floor() as usize).min(FILTER_TABLE_WIDTH - 1)
+        };
+        for p in covered.points() {
+            let ifx = table_index(p.x as f32 - p_discrete.x, self.inv_radius.x);
+            let ify = table_index(p.y as f32 - p_discrete.y, self.inv_radius.y);
+            f(bounds.offset(&p), self.values[ify * FILTER_TABLE_WIDTH + ifx]);
+        }
+    }
+
+    // adds a radiance sample to the pixels around p_film
+    fn add_sample(&self, bounds: &Bounds2i, pixels: &mut [Pixel], p_film: &Point2f, l: [f32; 3], sample_weight: f32) {
+        self.for_each_pixel(bounds, p_film, |offset, filter_weight| {
+            let pixel = &mut pixels[offset];
+            for (c, v) in pixel.rgb.iter_mut().zip(l.iter()) {
+                *c += v * sample_weight * filter_weight;
+            }
+            pixel.filter_weight_sum += filter_weight;
+        });
+        if let Some(offset) = containing_pixel(bounds, p_film) {
+            pixels[offset].sample_count += 1;
+        }
+    }
+
+    fn add_aov_sample(&self, bounds: &Bounds2i, aovs: &mut [AovPixel], p_film: &Point2f, s: &AovSample) {
+        self.for_each_pixel(bounds, p_film, |offset, filter_weight| {
+            aovs[offset].add_filtered(s, filter_weight);
+        });
+        if let Some(offset) = containing_pixel(bounds, p_film) {
+            let d = Point2f::new(p_film.x - p_film.x.floor() - 0.5, p_film.y - p_film.y.floor() - 0.5);
+            aovs[offset].add_inside(s, d.x * d.x + d.y * d.y);
+        }
+    }
+}
+
+// offset of the pixel of bounds that p_film lies in
+fn containing_pixel(bounds: &Bounds2i, p_film: &Point2f) -> Option<usize> {
+    let p = Point2i::new(p_film.x.floor() as i16, p_film.y.floor() as i16);
+    if Bounds2i::inside_exclusive(p, *bounds) {
+        Some(bounds.offset(&p))
+    } else {
+        None
+    }
+}
+
+// private accumulation buffer for one worker. it covers the pixels a tile of
+// samples can reach, so neighbouring tiles overlap by the filter radius.
+pub struct FilmTile {
+    sample_bounds: Bounds2i,
+    pixel_bounds: Bounds2i,
+    filter_table: Arc<FilterTable>,
+    pixels: Vec<Pixel>,
+    aovs: Option<Vec<AovPixel>>,
+}
+
+impl FilmTile {
+    pub fn sample_bounds(&self) -> Bounds2i {
+        self.sample_bounds
+    }
+
+    pub fn pixel_bounds(&self) -> Bounds2i {
+        self.pixel_bounds
+    }
+
+    pub fn add_sample(&mut self, p_film: &Point2f, l: [f32; 3], sample_weight: f32) {
+        self.filter_table
+            .add_sample(&self.pixel_bounds, &mut self.pixels, p_film, l, sample_weight);
+    }
+
+    // radiance at the wavelengths of a path, stored as RGB
+    pub fn add_spectral_sample(&mut self, p_film: &Point2f, l: &SpectrumSamples, lambda: &SampledWavelengths, sample_weight: f32) {
+        self.add_sample(p_film, l.rgb(lambda), sample_weight);
+    }
+
+    // records first-hit data, ignored unless the film has AOVs enabled
+    pub fn add_aov_sample(&mut self, p_film: &Point2f, s: &AovSample) {
+        if let Some(aovs) = self.aovs.as_mut() {
+            self.filter_table.add_aov_sample(&self.pixel_bounds, aovs, p_film, s);
+        }
+    }
+}
+
+// how integrators represent the radiance of a path
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SpectralMode {
+    Rgb,
+    // every path carries N_SPECTRUM_SAMPLES wavelengths, a hero wavelength
+    // and others at stratified offsets, converted to RGB on the film
+    HeroWavelength,
+}
+
+// image plane accumulating filtered radiance samples. sample positions are
+// continuous raster coordinates, pixel (x, y) has its center at
+// (x + 0.5, y + 0.5).
+pub struct Film {
+    pub full_resolution: Point2i,
+    pub filter: Arc<dyn Filter>,
+    // factor applied to every pixel when the image is resolved
+    pub scale: f32,
+    pixel_bounds: Bounds2i,
+    pixels: Vec<Pixel>,
+    splats: Vec<[AtomicFloat; 3]>,
+    filter_table: Arc<FilterTable>,
+    aov_outputs: Vec<Aov>,
+    aovs: Option<Vec<AovPixel>>,
+    spectral_mode: SpectralMode,
+}
+
+impl Film {
+    pub fn new(full_resolution: Point2i, filter: Arc<dyn Filter>) -> Self {
+        let pixel_bounds = Bounds2i::new(Point2i::new(0, 0), full_resolution);
+        let n_pixels = pixel_bounds.area() as usize;
+        let filter_table = Arc::new(FilterTable::new(filter.as_ref()));
+        Self {
+            full_resolution,
+            filter,
+            scale: 1.0,
+            pixel_bounds,
+            pixels: vec![Pixel::default(); n_pixels],
+            splats: (0..n_pixels).map(|_| Default::default()).collect(),
+            filter_table,
+            aov_outputs: Vec::new(),
+            aovs: None,
+            spectral_mode: SpectralMode::Rgb,
+        }
+    }
+
+    pub fn with_spectral_mode(mut self, mode: SpectralMode) -> Self {
+        self.spectral_mode = mode;
+        self
+    }
+
+    pub fn spectral_mode(&self) -> SpectralMode {
+        self.spectral_mode
+    }
+
+    // wavelengths for a new camera path, None unless the film is spectral
+    pub fn sample_wavelengths(&self, u: f32) -> Option<SampledWavelengths> {
+        match self.spectral_mode {
+            SpectralMode::Rgb => None,
+            SpectralMode::HeroWavelength => Some(SampledWavelengths::sample_visible(u)),
+        }
+    }
+
+    // keeps auxiliary buffers next to the radiance, outputs lists the ones
+    // written by exr_image
+    pub fn with_aovs(mut self, outputs: &[Aov]) -> Self {
+        self.aov_outputs = outputs.to_vec();
+        self.aovs = Some(vec![AovPixel::default(); self.pixels.len()]);
+        self
+    }
+
+    // restricts the film to the pixels of a window in [0, 1]^2 of the full
+    // image, discarding anything accumulated so far
+    pub fn with_crop_window(mut self, crop_window: &Bounds2f) -> Self {
+        self.pixel_bounds = Self::crop_pixel_bounds(self.full_resolution, crop_window);
+        let n_pixels = self.pixel_bounds.area() as usize;
+        self.pixels = vec![Pixel::default(); n_pixels];
+        self.splats = (0..n_pixels).map(|_| Default::default()).collect();
+        if self.aovs.is_some() {
+            self.aovs = Some(vec![AovPixel::default(); n_pixels]);
+        }
+        self
+    }
+
+    // pixel bounds of a crop window, adjacent windows share no pixels and
+    // leave none out
+    pub fn crop_pixel_bounds(full_resolution: Point2i, crop_window: &Bounds2f) -> Bounds2i {
+        let (x0, x1) = (crop_window.p_min.x.min(crop_window.p_max.x), crop_window.p_min.x.max(crop_window.p_max.x));
+        let (y0, y1) = (crop_window.p_min.y.min(crop_window.p_max.y), crop_window.p_min.y.max(crop_window.p_max.y));
+        let to_pixel = |t: f32, resolution: i16| (resolution as f32 * clamp(t, 0.0, 1.0)).ceil() as i16;
+        let bounds = Bounds2i::new(
+            Point2i::new(to_pixel(x0, full_resolution.x), to_pixel(y0, full_resolution.y)),
+            Point2i::new(to_pixel(x1, full_resolution.x), to_pixel(y1, full_resolution.y)),
+        );
+        assert!(!bounds.is_empty(), "crop window covers no pixels");
+        bounds
+    }
+
+    pub fn aov_outputs(&self) -> &[Aov] {
+        &self.aov_outputs
+    }
+
+    pub fn pixel_bounds(&self) -> Bounds2i {
+        self.pixel_bounds
+    }
+
+    // area over which samples have to be taken so that every pixel gets the
+    // full support of the filter
+    pub fn sample_bounds(&self) -> Bounds2i {
+        let radius = self.filter.radius();
+        let p_min = Point2i::new(
+            (self.pixel_bounds.p_min.x as f32 + 0.5 - radius.x).floor() as i16,
+            (self.pixel_bounds.p_min.y as f32 + 0.5 - radius.y).floor() as i16,
+        );
+        let p_max = Point2i::new(
+            (self.pixel_bounds.p_max.x as f32 - 0.5 + radius.x).ceil() as i16,
+            (self.pixel_bounds.p_max.y as f32 - 0.5 + radius.y).ceil() as i16,
+        );
+        Bounds2i::new(p_min, p_max)
+    }
+
+    // splits the sample bounds into tiles of at most tile_size squared
+    // pixels, in scanline order
+    pub fn tiles(&self, tile_size: i16) -> Vec<Bounds2i> {
+        assert!(tile_size > 0);
+        let sample_bounds = self.sample_bounds();
+        let mut tiles = Vec::new();
+        let mut y = sample_bounds.p_min.y;
+        while y < sample_bounds.p_max.y {
+            let mut x = sample_bounds.p_min.x;
+            while x < sample_bounds.p_max.x {
+                let p_max = Point2i::min(&Point2i::new(x + tile_size, y + tile_size), &sample_bounds.p_max);
+                tiles.push(Bounds2i::new(Point2i::new(x, y), p_max));
+                x += tile_size;
+            }
+            y += tile_size;
+        }
+        tiles
+    }
+
+    // empty tile for the samples taken in sample_bounds. it covers every
+    // pixel those samples contribute to.
+    pub fn get_film_tile(&self, sample_bounds: &Bounds2i) -> FilmTile {
+        let radius = self.filter_table.radius;
+        let p0 = Point2i::new(
+            (sample_bounds.p_min.x as f32 - 0.5 - radius.x).ceil() as i16,
+            (sample_bounds.p_min.y as f32 - 0.5 - radius.y).ceil() as i16,
+        );
+        let p1 = Point2i::new(
+            (sample_bounds.p_max.x as f32 - 0.5 + radius.x).floor() as i16 + 1,
+            (sample_bounds.p_max.y as f32 - 0.5 + radius.y).floor() as i16 + 1,
+        );
+        let mut pixel_bounds = Bounds2i::new(
+            Point2i::max(&p0, &self.pixel_bounds.p_min),
+            Point2i::min(&p1, &self.pixel_bounds.p_max),
+        );
+        if pixel_bounds.is_empty() {
+            pixel_bounds = Bounds2i::new(pixel_bounds.p_min, pixel_bounds.p_min);
+        }
+        FilmTile {
+            sample_bounds: *sample_bounds,
+            pixel_bounds,
+            filter_table: self.filter_table.clone(),
+            pixels: vec![Pixel::default(); pixel_bounds.area().max(0) as usize],
+            aovs: self
+                .aovs
+                .as_ref()
+                .map(|_| vec![AovPixel::default(); pixel_bounds.area().max(0) as usize]),
+        }
+    }
+
+    // adds the tile's pixels to the film. floating point sums depend on the
+    // order tiles are merged in, use merge_film_tiles for reproducible output.
+    pub fn merge_film_tile(&mut self, tile: FilmTile) {
+        for p in tile.pixel_bounds.points() {
+            let tile_pixel = &tile.pixels[tile.pixel_bounds.offset(&p)];
+            let pixel = &mut self.pixels[self.pixel_bounds.offset(&p)];
+            for (c, v) in pixel.rgb.iter_mut().zip(tile_pixel.rgb.iter()) {
+                *c += v;
+            }
+            pixel.filter_weight_sum += tile_pixel.filter_weight_sum;
+            pixel.sample_count += tile_pixel.sample_count;
+        }
+        if let (Some(aovs), Some(tile_aovs)) = (self.aovs.as_mut(), tile.aovs.as_ref()) {
+            for p in tile.pixel_bounds.points() {
+                aovs[self.pixel_bounds.offset(&p)].merge(&tile_aovs[tile.pixel_bounds.offset(&p)]);
+            }
+        }
+    }
+
+    // merges tiles in scanline order of their sample bounds, whatever order
+    // the workers finished them in
+    pub fn merge_film_tiles<I>(&mut self, tiles: I)
+    where
+        I: IntoIterator<Item = FilmTile>,
+    {
+        let mut tiles: Vec<FilmTile> = tiles.into_iter().collect();
+        tiles.sort_by_key(|t| (t.sample_bounds.p_min.y, t.sample_bounds.p_min.x));
+        for tile in tiles {
+            self.merge_film_tile(tile);
+        }
+    }
+
+    // adds a sample directly to the film, for single threaded use
+    pub fn add_sample(&mut self, p_film: &Point2f, l: [f32; 3], sample_weight: f32) {
+        self.filter_table
+            .add_sample(&self.pixel_bounds, &mut self.pixels, p_film, l, sample_weight);
+    }
+
+    pub fn add_spectral_sample(&mut self, p_film: &Point2f, l: &SpectrumSamples, lambda: &SampledWavelengths, sample_weight: f32) {
+        self.add_sample(p_film, l.rgb(lambda), sample_weight);
+    }
+
+    pub fn add_aov_sample(&mut self, p_film: &Point2f, s: &AovSample) {
+        if let Some(aovs) = self.aovs.as_mut() {
+            self.filter_table.add_aov_sample(&self.pixel_bounds, aovs, p_film, s);
+        }
+    }
+
+    // unfiltered contribution to the pixel containing p, e.g. from light
+    // tracing. splats may be added concurrently from several threads.
+    pub fn add_splat(&self, p: &Point2f, v: [f32; 3]) {
+        if v.iter().any(|c| c.is_nan() || c.is_infinite()) {
+            return;
+        }
+        let pi = Point2i::new(p.x.floor() as i16, p.y.floor() as i16);
+        if !Bounds2i::inside_exclusive(pi, self.pixel_bounds) {
+            return;
+        }
+        let splat = &self.splats[self.pixel_bounds.offset(&pi)];
+        for (s, c) in splat.iter().zip(v.iter()) {
+            s.add(*c);
+        }
+    }
+
+    // final value of a pixel, splats weighted by splat_scale
+    pub fn pixel_value(&self, p: &Point2i, splat_scale: f32) -> [f32; 3] {
+        let offset = self.pixel_bounds.offset(p);
+        let pixel = &self.pixels[offset];
+        let mut rgb = [0.0; 3];
+        for (i, c) in rgb.iter_mut().enumerate() {
+            if pixel.filter_weight_sum != 0.0 {
+                *c = (pixel.rgb[i] / pixel.filter_weight_sum).max(0.0);
+            }
+            *c = (*c + splat_scale * self.splats[offset][i].get()) * self.scale;
+        }
+        rgb
+    }
+
+    // all pixels in scanline order
+    pub fn image(&self, splat_scale: f32) -> Vec<[f32; 3]> {
+        self.pixel_bounds
+            .points()
+            .map(|p| self.pixel_value(&p, splat_scale))
+            .collect()
+    }
+
+    // number of camera samples that landed inside the pixel
+    pub fn sample_count(&self, p: &Point2i) -> u32 {
+        self.pixels[self.pixel_bounds.offset(p)].sample_count
+    }
+
+    // auxiliary data of a pixel, None when AOVs are disabled
+    pub fn aov_value(&self, p: &Point2i) -> Option<AovSample> {
+        self.aovs
+            .as_ref()
+            .map(|aovs| aovs[self.pixel_bounds.offset(p)].resolve())
+    }
+
+    // radiance as half RGB plus the requested AOV layers
+    pub fn exr_image(&self, splat_scale: f32) -> ExrImage {
+        let resolution = self.pixel_bounds.p_max - self.pixel_bounds.p_min;
+        let mut image = ExrImage::new(Point2i::new(resolution.x, resolution.y));
+        image.add_layer("", ["R", "G", "B"], ExrPixelType::Half, &self.image(splat_scale));
+        let values: Vec<(AovSample, u32)> = self
+            .pixel_bounds
+            .points()
+            .filter_map(|p| self.aov_value(&p).map(|v| (v, self.sample_count(&p))))
+            .collect();
+        if values.is_empty() {
+            return image;
+        }
+        for aov in self.aov_outputs.iter() {
+            for (i, name) in aov.channel_names().iter().enumerate() {
+                let channel = values.iter().map(|(v, n)| aov.channel_value(v, *n, i));
+                image.add_channel(name, ExrChannelData::from_values(aov.pixel_type(), channel));
+            }
+        }
+        image
+    }
+
+    // writes the pixels of the crop window as an image of their own. OpenEXR
+    // files also get the AOV layers.
+    pub fn write_image(&self, name: &str, splat_scale: f32) -> io::Result<()> {
+        if is_exr(name) {
+            self.exr_image(splat_scale).write(name)
+        } else {
+            let resolution = self.pixel_bounds.p_max - self.pixel_bounds.p_min;
+            write_image(name, &self.image(splat_scale), Point2i::new(resolution.x, resolution.y), 0.0)
+        }
+    }
+
+    // replaces the crop window's pixels of a full size image file, which is
+    // created black when it doesn't exist yet
+    pub fn composite_image(&self, name: &str, splat_scale: f32) -> io::Result<()> {
+        let n_pixels = self.full_resolution.x as usize * self.full_resolution.y as usize;
+        let exists = Path::new(name).exists();
+        if is_exr(name) {
+            let mut image = if exists { ExrImage::read(name)? } else { ExrImage::new(self.full_resolution) };
+            self.check_resolution(image.resolution)?;
+            for c in self.exr_image(splat_scale).channels {
+                let mut data = match image.channel(&c.name) {
+                    Some(e) if e.pixel_type() == c.pixel_type() => e.data.clone(),
+                    _ => ExrChannelData::filled(c.pixel_type(), self.empty_value(&c.name), n_pixels),
+                };
+                match (&mut data, &c.data) {
+                    (ExrChannelData::Uint(full), ExrChannelData::Uint(cropped)) => self.composite(full, cropped),
+                    (ExrChannelData::Half(full), ExrChannelData::Half(cropped))
+                    | (ExrChannelData::Float(full), ExrChannelData::Float(cropped)) => self.composite(full, cropped),
+                    _ => unreachable!(),
+                }
+                image.add_channel(&c.name, data);
+            }
+            image.write(name)
+        } else {
+            let mut rgb = if exists {
+                let (rgb, resolution) = read_image(name)?;
+                self.check_resolution(resolution)?;
+                rgb
+            } else {
+                vec![[0.0; 3]; n_pixels]
+            };
+            self.composite(&mut rgb, &self.image(splat_scale));
+            write_image(name, &rgb, self.full_resolution, 0.0)
+        }
+    }
+
+    // value of the pixels outside the crop window in a new image, the no-ID
+    // value for the ID channels
+    fn empty_value(&self, name: &str) -> ExrValue {
+        for aov in self.aov_outputs.iter() {
+            if let Some(i) = aov.channel_names().iter().position(|n| *n == name) {
+                return aov.channel_value(&AovSample::default(), 0, i);
+            }
+        }
+        ExrValue::Float(0.0)
+    }
+
+    fn check_resolution(&self, resolution: Point2i) -> io::Result<()> {
+        if resolution != self.full_resolution {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{}x{} image can't hold a {}x{} film",
+                    resolution.x, resolution.y, self.full_resolution.x, self.full_resolution.y
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    // copies values of the pixel bounds into a full resolution buffer
+    fn composite<T: Copy>(&self, full: &mut [T], cropped: &[T]) {
+        let full_bounds = Bounds2i::new(Point2i::new(0, 0), self.full_resolution);
+        for (p, v) in self.pixel_bounds.points().zip(cropped.iter()) {
+            full[full_bounds.offset(&p)] = *v;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for pixel in self.pixels.iter_mut() {
+            *pixel = Pixel::default();
+        }
+        for splat in self.splats.iter_mut() {
+            *splat = Default::default();
+        }
+        if let Some(aovs) = self.aovs.as_mut() {
+            for aov in aovs.iter_mut() {
+                *aov = AovPixel::default();
+            }
+        }
+    }
+}
+
+fn is_exr(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("exr"))
+}
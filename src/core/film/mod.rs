@@ -0,0 +1,5 @@
+pub use aov::*;
+pub use film::*;
+
+mod aov;
+mod film;
@@ -0,0 +1,7 @@
+use crate::core::geometry::{Point2f, Vector2f};
+
+// pixel reconstruction filter, centered at the origin and zero outside radius
+pub trait Filter: Send + Sync {
+    fn radius(&self) -> Vector2f;
+    fn evaluate(&self, p: &Point2f) -> f32;
+}
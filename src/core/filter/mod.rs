@@ -0,0 +1,3 @@
+pub use filter::*;
+
+mod filter;
@@ -1,5 +1,6 @@
-use crate::core::geometry::{Point2, Point3, Point3f, Vector3};
-use std::ops::{Add, Index, Sub};
+use crate::core::geometry::{Point2, Point2f, Point2i, Point3, Point3f, Ray, Vector2, Vector3, Vector3f};
+use crate::core::pbrt::gamma;
+use std::ops::{Add, Sub};
 
 pub type Bounds2f = Bounds2<f32>;
 pub type Bounds2i = Bounds2<i16>;
@@ -84,23 +85,200 @@ macro_rules! impl_bounds_set_op {
 
             pub fn union(b1: &Self, b2: &Self) -> Self {
                 Self::new(
-                    $PointN::min(&b1.$field1, &b2.$field1),
-                    $PointN::max(&b1.$field2, &b2.$field2),
+                    $PointN::<$T>::min(&b1.$field1, &b2.$field1),
+                    $PointN::<$T>::max(&b1.$field2, &b2.$field2),
                 )
             }
 
             pub fn intersect(b1: &Self, b2: &Self) -> Self {
                 Self::new(
-                    $PointN::max(&b1.$field1, &b2.$field1),
-                    $PointN::min(&b1.$field2, &b2.$field2),
+                    $PointN::<$T>::max(&b1.$field1, &b2.$field1),
+                    $PointN::<$T>::min(&b1.$field2, &b2.$field2),
                 )
             }
         }
     };
 }
 
+impl_bounds!(
+    Bounds2 { p_min, p_max },
+    Point2 { x, y },
+    Vector2 { x, y }
+);
 impl_bounds!(
     Bounds3 { p_min, p_max },
     Point3 { x, y, z },
     Vector3 { x, y, z }
 );
+
+impl Bounds2f {
+    // bounds that any union will overwrite
+    pub fn empty() -> Self {
+        Self::new(Point2f::new(f32::MAX, f32::MAX), Point2f::new(f32::MIN, f32::MIN))
+    }
+
+    pub fn area(&self) -> f32 {
+        let d = self.diagonal();
+        d.x * d.y
+    }
+
+    pub fn lerp(&self, t: &Point2f) -> Point2f {
+        Point2f::new(
+            self.p_min.x + (self.p_max.x - self.p_min.x) * t.x,
+            self.p_min.y + (self.p_max.y - self.p_min.y) * t.y,
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.p_min.x > self.p_max.x || self.p_min.y > self.p_max.y
+    }
+}
+
+// integer bounds are half-open, p_max is not part of them
+impl Bounds2i {
+    pub fn area(&self) -> i32 {
+        let d = self.diagonal();
+        d.x as i32 * d.y as i32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.p_min.x >= self.p_max.x || self.p_min.y >= self.p_max.y
+    }
+
+    // index of a point inside the bounds in scanline order
+    pub fn offset(&self, p: &Point2i) -> usize {
+        let width = (self.p_max.x - self.p_min.x) as usize;
+        (p.y - self.p_min.y) as usize * width + (p.x - self.p_min.x) as usize
+    }
+
+    // points inside the bounds in scanline order
+    pub fn points(&self) -> impl Iterator<Item = Point2i> {
+        let b = *self;
+        (b.p_min.y..b.p_max.y).flat_map(move |y| (b.p_min.x..b.p_max.x).map(move |x| Point2i::new(x, y)))
+    }
+}
+
+impl Bounds3f {
+    // bounds that any union will overwrite
+    pub fn empty() -> Self {
+        Self::new(
+            Point3f::new(f32::MAX, f32::MAX, f32::MAX),
+            Point3f::new(f32::MIN, f32::MIN, f32::MIN),
+        )
+    }
+
+    pub fn corner(&self, corner: usize) -> Point3f {
+        Point3f::new(
+            if corner & 1 == 0 { self.p_min.x } else { self.p_max.x },
+            if corner & 2 == 0 { self.p_min.y } else { self.p_max.y },
+            if corner & 4 == 0 { self.p_min.z } else { self.p_max.z },
+        )
+    }
+
+    pub fn centroid(&self) -> Point3f {
+        Point3f::add_element(self.p_min * 0.5, self.p_max * 0.5)
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        let d = self.diagonal();
+        2.0 * (d.x * d.y + d.x * d.z + d.y * d.z)
+    }
+
+    pub fn volume(&self) -> f32 {
+        let d = self.diagonal();
+        d.x * d.y * d.z
+    }
+
+    pub fn maximum_extent(&self) -> usize {
+        let d = self.diagonal();
+        if d.x > d.y && d.x > d.z {
+            0
+        } else if d.y > d.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    pub fn lerp(&self, t: &Point3f) -> Point3f {
+        Point3f::new(
+            self.p_min.x + (self.p_max.x - self.p_min.x) * t.x,
+            self.p_min.y + (self.p_max.y - self.p_min.y) * t.y,
+            self.p_min.z + (self.p_max.z - self.p_min.z) * t.z,
+        )
+    }
+
+    // relative position of p in the box, (0, 0, 0) at p_min and (1, 1, 1) at p_max
+    pub fn offset(&self, p: &Point3f) -> Vector3f {
+        let mut o = *p - self.p_min;
+        if self.p_max.x > self.p_min.x {
+            o.x /= self.p_max.x - self.p_min.x;
+        }
+        if self.p_max.y > self.p_min.y {
+            o.y /= self.p_max.y - self.p_min.y;
+        }
+        if self.p_max.z > self.p_min.z {
+            o.z /= self.p_max.z - self.p_min.z;
+        }
+        o
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.p_min.x > self.p_max.x || self.p_min.y > self.p_max.y || self.p_min.z > self.p_max.z
+    }
+
+    // slab test, returns the parametric range of the ray inside the box
+    pub fn intersect_p(&self, ray: &Ray) -> Option<(f32, f32)> {
+        let mut t0 = 0.0;
+        let mut t1 = ray.t_max;
+        for i in 0..3 {
+            let inv_ray_dir = 1.0 / ray.d[i];
+            let mut t_near = (self.p_min[i] - ray.o[i]) * inv_ray_dir;
+            let mut t_far = (self.p_max[i] - ray.o[i]) * inv_ray_dir;
+            if t_near > t_far {
+                std::mem::swap(&mut t_near, &mut t_far);
+            }
+            t_far *= 1.0 + 2.0 * gamma(3);
+            t0 = if t_near > t0 { t_near } else { t0 };
+            t1 = if t_far < t1 { t_far } else { t1 };
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some((t0, t1))
+    }
+
+    pub fn intersect_p_inv(&self, ray: &Ray, inv_dir: &Vector3f, dir_is_neg: [usize; 3]) -> bool {
+        let bounds = [self.p_min, self.p_max];
+        let mut t_min = (bounds[dir_is_neg[0]].x - ray.o.x) * inv_dir.x;
+        let mut t_max = (bounds[1 - dir_is_neg[0]].x - ray.o.x) * inv_dir.x;
+        let ty_min = (bounds[dir_is_neg[1]].y - ray.o.y) * inv_dir.y;
+        let mut ty_max = (bounds[1 - dir_is_neg[1]].y - ray.o.y) * inv_dir.y;
+
+        t_max *= 1.0 + 2.0 * gamma(3);
+        ty_max *= 1.0 + 2.0 * gamma(3);
+        if t_min > ty_max || ty_min > t_max {
+            return false;
+        }
+        if ty_min > t_min {
+            t_min = ty_min;
+        }
+        if ty_max < t_max {
+            t_max = ty_max;
+        }
+
+        let tz_min = (bounds[dir_is_neg[2]].z - ray.o.z) * inv_dir.z;
+        let mut tz_max = (bounds[1 - dir_is_neg[2]].z - ray.o.z) * inv_dir.z;
+        tz_max *= 1.0 + 2.0 * gamma(3);
+        if t_min > tz_max || tz_min > t_max {
+            return false;
+        }
+        if tz_min > t_min {
+            t_min = tz_min;
+        }
+        if tz_max < t_max {
+            t_max = tz_max;
+        }
+        t_min < ray.t_max && t_max > 0.0
+    }
+}
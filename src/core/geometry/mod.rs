@@ -4,6 +4,8 @@ pub use vectors::*;
 pub use points::*;
 
 mod bounds;
+// rays/mod.rs declares pub mod rays, this private module hides it
+#[allow(hidden_glob_reexports)]
 mod rays;
 mod vectors;
 mod points;
@@ -1,6 +1,6 @@
 use crate::core::geometry::{Vector2, Vector3};
 use crate::impl_scal_mul;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Hash, Debug, Default)]
 pub struct Point2<T> {
@@ -167,6 +167,38 @@ where
     }
 }
 
+impl Point2f {
+    pub fn abs(p: &Self) -> Self {
+        Self::new(p.x.abs(), p.y.abs())
+    }
+
+    pub fn ceil(p: &Self) -> Self {
+        Self::new(p.x.ceil(), p.y.ceil())
+    }
+
+    pub fn floor(p: &Self) -> Self {
+        Self::new(p.x.floor(), p.y.floor())
+    }
+
+    pub fn min(p0: &Self, p1: &Self) -> Self {
+        Self::new(p0.x.min(p1.x), p0.y.min(p1.y))
+    }
+
+    pub fn max(p0: &Self, p1: &Self) -> Self {
+        Self::new(p0.x.max(p1.x), p0.y.max(p1.y))
+    }
+}
+
+impl Point2i {
+    pub fn min(p0: &Self, p1: &Self) -> Self {
+        Self::new(p0.x.min(p1.x), p0.y.min(p1.y))
+    }
+
+    pub fn max(p0: &Self, p1: &Self) -> Self {
+        Self::new(p0.x.max(p1.x), p0.y.max(p1.y))
+    }
+}
+
 impl Point3f {
     pub fn abs(p: &Self) -> Self {
         Self::new(p.x.abs(), p.y.abs(), p.z.abs())
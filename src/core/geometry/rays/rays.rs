@@ -12,9 +12,9 @@ pub trait BaseRay<'a> {
 pub struct Ray<'a> {
     pub o: Point3f,
     pub d: Vector3f,
-    t_max: f32,
-    time: f32,
-    medium: Option<&'a Medium>,
+    pub t_max: f32,
+    pub time: f32,
+    pub medium: Option<&'a Medium>,
 }
 
 impl<'a> Ray<'a> {
@@ -202,7 +202,7 @@ where
         let v2y = v2.y;
         let v2z = v2.z;
         Vector3::new(
-            v1x * v2z - v1z * v2y,
+            v1y * v2z - v1z * v2y,
             v1z * v2x - v1x * v2z,
             v1x * v2y - v1y * v2x,
         )
@@ -280,7 +280,7 @@ where
         let v2y = v2.y;
         let v2z = v2.z;
         Self::new(
-            v1x * v2z - v1z * v2y,
+            v1y * v2z - v1z * v2y,
             v1z * v2x - v1x * v2z,
             v1x * v2y - v1y * v2x,
         )
@@ -294,3 +294,21 @@ impl_abs_dot_vector!(Normal3<i16>);
 impl_vector!(Vector3{x, y, z});
 impl_vector!(Vector2{x, y});
 impl_vector!(Normal3{x, y, z});
+
+pub fn spherical_direction(sin_theta: f32, cos_theta: f32, phi: f32) -> Vector3f {
+    let sin_theta = sin_theta.clamp(-1.0, 1.0);
+    Vector3f::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta.clamp(-1.0, 1.0))
+}
+
+pub fn spherical_theta(v: &Vector3f) -> f32 {
+    v.z.clamp(-1.0, 1.0).acos()
+}
+
+pub fn spherical_phi(v: &Vector3f) -> f32 {
+    let p = v.y.atan2(v.x);
+    if p < 0.0 {
+        p + 2.0 * f32::consts::PI
+    } else {
+        p
+    }
+}
@@ -0,0 +1,1121 @@
+use crate::core::geometry::Point2i;
+use crate::core::imageio::{zlib_compress, zlib_decompress};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+
+const EXR_MAGIC: [u8; 4] = [0x76, 0x2f, 0x31, 0x01];
+// version 2, with the flag allowing names longer than 31 bytes
+const EXR_VERSION: u8 = 2;
+const EXR_LONG_NAMES: u8 = 0x04;
+
+const BITMAP_SIZE: usize = 8192;
+const HUF_ENCSIZE: usize = (1 << 16) + 1;
+const SHORT_ZEROCODE_RUN: u64 = 59;
+const LONG_ZEROCODE_RUN: u64 = 63;
+const SHORTEST_LONG_RUN: u64 = 2 + LONG_ZEROCODE_RUN - SHORT_ZEROCODE_RUN;
+const LONGEST_LONG_RUN: u64 = 255 + SHORTEST_LONG_RUN;
+
+pub fn f32_to_half(v: f32) -> u16 {
+    let bits = v.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let mant = bits & 0x7f_ffff;
+    if exp == 0xff {
+        // keep nans as nans
+        let nan = if mant != 0 { 0x200 | (mant >> 13) as u16 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+    let e = exp - 127 + 15;
+    if e >= 0x1f {
+        return sign | 0x7c00;
+    }
+    // round to nearest even, carries propagate into the exponent
+    let round = |h: u32, rem: u32, halfway: u32| {
+        if rem > halfway || (rem == halfway && h & 1 == 1) {
+            h + 1
+        } else {
+            h
+        }
+    };
+    if e <= 0 {
+        if e < -10 {
+            return sign;
+        }
+        let m = mant | 0x80_0000;
+        let shift = (14 - e) as u32;
+        let h = round(m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1));
+        return sign | h as u16;
+    }
+    let h = round(((e as u32) << 10) | (mant >> 13), mant & 0x1fff, 0x1000);
+    sign | h as u16
+}
+
+pub fn half_to_f32(h: u16) -> f32 {
+    let sign = ((h as u32) & 0x8000) << 16;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let mant = (h & 0x3ff) as u32;
+    match exp {
+        0 => {
+            let v = mant as f32 * (1.0 / (1 << 24) as f32);
+            if sign != 0 {
+                -v
+            } else {
+                v
+            }
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mant << 13)),
+        _ => f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13)),
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExrPixelType {
+    Uint,
+    Half,
+    Float,
+}
+
+impl ExrPixelType {
+    fn size(&self) -> usize {
+        match *self {
+            ExrPixelType::Half => 2,
+            _ => 4,
+        }
+    }
+}
+
+// a single channel value, uint channels hold integers such as object ids
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExrValue {
+    Uint(u32),
+    Float(f32),
+}
+
+impl ExrValue {
+    // the value as a number, converting integers
+    pub fn as_f32(&self) -> f32 {
+        match *self {
+            ExrValue::Uint(v) => v as f32,
+            ExrValue::Float(v) => v,
+        }
+    }
+}
+
+// values of one channel in scanline order. half values are kept as f32 and
+// rounded when the image is written.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ExrChannelData {
+    Uint(Vec<u32>),
+    Half(Vec<f32>),
+    Float(Vec<f32>),
+}
+
+impl ExrChannelData {
+    // n copies of value, integers are converted for half and float channels
+    pub fn filled(pixel_type: ExrPixelType, value: ExrValue, n: usize) -> Self {
+        Self::from_values(pixel_type, std::iter::repeat(value).take(n))
+    }
+
+    pub fn from_values<I: IntoIterator<Item = ExrValue>>(pixel_type: ExrPixelType, values: I) -> Self {
+        let values = values.into_iter();
+        match pixel_type {
+            ExrPixelType::Uint => ExrChannelData::Uint(
+                values
+                    .map(|v| match v {
+                        ExrValue::Uint(v) => v,
+                        ExrValue::Float(_) => panic!("uint channels hold integers"),
+                    })
+                    .collect(),
+            ),
+            ExrPixelType::Half => ExrChannelData::Half(values.map(|v| v.as_f32()).collect()),
+            ExrPixelType::Float => ExrChannelData::Float(values.map(|v| v.as_f32()).collect()),
+        }
+    }
+
+    pub fn pixel_type(&self) -> ExrPixelType {
+        match *self {
+            ExrChannelData::Uint(_) => ExrPixelType::Uint,
+            ExrChannelData::Half(_) => ExrPixelType::Half,
+            ExrChannelData::Float(_) => ExrPixelType::Float,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match *self {
+            ExrChannelData::Uint(ref v) => v.len(),
+            ExrChannelData::Half(ref v) | ExrChannelData::Float(ref v) => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn value(&self, i: usize) -> ExrValue {
+        match *self {
+            ExrChannelData::Uint(ref v) => ExrValue::Uint(v[i]),
+            ExrChannelData::Half(ref v) | ExrChannelData::Float(ref v) => ExrValue::Float(v[i]),
+        }
+    }
+
+    // value i as a number, converting the integers of uint channels
+    pub fn float_value(&self, i: usize) -> f32 {
+        self.value(i).as_f32()
+    }
+
+    fn write_value(&self, i: usize, out: &mut Vec<u8>) {
+        match *self {
+            ExrChannelData::Uint(ref v) => out.extend_from_slice(&v[i].to_le_bytes()),
+            ExrChannelData::Half(ref v) => out.extend_from_slice(&f32_to_half(v[i]).to_le_bytes()),
+            ExrChannelData::Float(ref v) => out.extend_from_slice(&v[i].to_le_bytes()),
+        }
+    }
+
+    fn read_value(&mut self, i: usize, b: &[u8]) {
+        match *self {
+            ExrChannelData::Uint(ref mut v) => v[i] = u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            ExrChannelData::Half(ref mut v) => v[i] = half_to_f32(u16::from_le_bytes([b[0], b[1]])),
+            ExrChannelData::Float(ref mut v) => v[i] = f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExrCompression {
+    None,
+    // zlib over blocks of 16 scanlines
+    Zip,
+    // wavelet and huffman coding over blocks of 32 scanlines, lossless
+    Piz,
+}
+
+impl ExrCompression {
+    fn id(&self) -> u8 {
+        match *self {
+            ExrCompression::None => 0,
+            ExrCompression::Zip => 3,
+            ExrCompression::Piz => 4,
+        }
+    }
+
+    fn lines_per_block(&self) -> usize {
+        match *self {
+            ExrCompression::None => 1,
+            ExrCompression::Zip => 16,
+            ExrCompression::Piz => 32,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExrChannel {
+    pub name: String,
+    pub data: ExrChannelData,
+}
+
+impl ExrChannel {
+    pub fn pixel_type(&self) -> ExrPixelType {
+        self.data.pixel_type()
+    }
+}
+
+// multi-channel scanline OpenEXR image. layers follow the usual naming
+// convention, channel "B" of layer "diffuse" is called "diffuse.B".
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExrImage {
+    pub resolution: Point2i,
+    pub compression: ExrCompression,
+    pub channels: Vec<ExrChannel>,
+}
+
+impl ExrImage {
+    pub fn new(resolution: Point2i) -> Self {
+        Self {
+            resolution,
+            compression: ExrCompression::Zip,
+            channels: Vec::new(),
+        }
+    }
+
+    pub fn with_compression(mut self, compression: ExrCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    // name of a channel inside a layer, the default layer has no prefix
+    pub fn layer_channel_name(layer: &str, channel: &str) -> String {
+        if layer.is_empty() {
+            channel.to_string()
+        } else {
+            format!("{}.{}", layer, channel)
+        }
+    }
+
+    // adds a channel, replacing any channel with the same name
+    pub fn add_channel(&mut self, name: &str, data: ExrChannelData) {
+        assert!(!name.is_empty());
+        assert_eq!(data.len(), self.resolution.x as usize * self.resolution.y as usize);
+        self.channels.retain(|c| c.name != name);
+        self.channels.push(ExrChannel {
+            name: name.to_string(),
+            data,
+        });
+    }
+
+    // adds one channel per component of the pixels, e.g. R, G, B
+    pub fn add_layer<const N: usize>(
+        &mut self,
+        layer: &str,
+        channel_names: [&str; N],
+        pixel_type: ExrPixelType,
+        pixels: &[[f32; N]],
+    ) {
+        for (i, channel) in channel_names.iter().enumerate() {
+            let values = pixels.iter().map(|p| ExrValue::Float(p[i]));
+            let data = ExrChannelData::from_values(pixel_type, values);
+            self.add_channel(&Self::layer_channel_name(layer, channel), data);
+        }
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&ExrChannel> {
+        self.channels.iter().find(|c| c.name == name)
+    }
+
+    // names of the layers present, the default layer as ""
+    pub fn layers(&self) -> Vec<String> {
+        let mut layers: Vec<String> = self
+            .channels
+            .iter()
+            .map(|c| match c.name.rfind('.') {
+                Some(i) => c.name[..i].to_string(),
+                None => String::new(),
+            })
+            .collect();
+        layers.sort();
+        layers.dedup();
+        layers
+    }
+
+    // channels in file order, sorted by name
+    fn sorted_channels(&self) -> Vec<&ExrChannel> {
+        let mut channels: Vec<&ExrChannel> = self.channels.iter().collect();
+        channels.sort_by(|a, b| a.name.as_bytes().cmp(b.name.as_bytes()));
+        channels
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let (width, height) = (self.resolution.x as usize, self.resolution.y as usize);
+        assert!(width > 0 && height > 0);
+        let channels = self.sorted_channels();
+        let long_names = channels.iter().any(|c| c.name.len() > 31);
+
+        let mut out = EXR_MAGIC.to_vec();
+        out.extend_from_slice(&[EXR_VERSION, if long_names { EXR_LONG_NAMES } else { 0 }, 0, 0]);
+
+        let mut channel_list = Vec::new();
+        for c in channels.iter() {
+            channel_list.extend_from_slice(c.name.as_bytes());
+            channel_list.push(0);
+            let pixel_type: i32 = match c.pixel_type() {
+                ExrPixelType::Uint => 0,
+                ExrPixelType::Half => 1,
+                ExrPixelType::Float => 2,
+            };
+            channel_list.extend_from_slice(&pixel_type.to_le_bytes());
+            // linear flag and reserved bytes, then x and y sampling
+            channel_list.extend_from_slice(&[0, 0, 0, 0]);
+            channel_list.extend_from_slice(&1i32.to_le_bytes());
+            channel_list.extend_from_slice(&1i32.to_le_bytes());
+        }
+        channel_list.push(0);
+        let mut window = Vec::new();
+        for v in &[0, 0, width as i32 - 1, height as i32 - 1] {
+            window.extend_from_slice(&v.to_le_bytes());
+        }
+        let mut screen_window_center = 0f32.to_le_bytes().to_vec();
+        screen_window_center.extend_from_slice(&0f32.to_le_bytes());
+
+        write_attribute(&mut out, "channels", "chlist", &channel_list);
+        write_attribute(&mut out, "compression", "compression", &[self.compression.id()]);
+        write_attribute(&mut out, "dataWindow", "box2i", &window);
+        write_attribute(&mut out, "displayWindow", "box2i", &window);
+        write_attribute(&mut out, "lineOrder", "lineOrder", &[0]);
+        write_attribute(&mut out, "pixelAspectRatio", "float", &1f32.to_le_bytes());
+        write_attribute(&mut out, "screenWindowCenter", "v2f", &screen_window_center);
+        write_attribute(&mut out, "screenWindowWidth", "float", &1f32.to_le_bytes());
+        out.push(0);
+
+        let lines_per_block = self.compression.lines_per_block();
+        let n_blocks = height.div_ceil(lines_per_block);
+        let offset_table = out.len();
+        out.resize(offset_table + 8 * n_blocks, 0);
+        for block in 0..n_blocks {
+            let y0 = block * lines_per_block;
+            let y1 = (y0 + lines_per_block).min(height);
+            let mut raw = Vec::new();
+            for y in y0..y1 {
+                for c in channels.iter() {
+                    for x in 0..width {
+                        c.data.write_value(y * width + x, &mut raw);
+                    }
+                }
+            }
+            let compressed = match self.compression {
+                ExrCompression::None => None,
+                ExrCompression::Zip => Some(zip_compress(&raw)),
+                ExrCompression::Piz => Some(piz_compress(&raw, &channels, width, y1 - y0)),
+            };
+            // blocks that do not get smaller are stored as they are
+            let data = match compressed {
+                Some(ref data) if data.len() < raw.len() => data,
+                _ => &raw,
+            };
+
+            let offset = out.len() as u64;
+            out[offset_table + 8 * block..offset_table + 8 * (block + 1)].copy_from_slice(&offset.to_le_bytes());
+            out.extend_from_slice(&(y0 as i32).to_le_bytes());
+            out.extend_from_slice(&(data.len() as i32).to_le_bytes());
+            out.extend_from_slice(data);
+        }
+        out
+    }
+
+    // reads single-part scanline files with no, ZIP or PIZ compression
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        if data.len() < 8 || data[..4] != EXR_MAGIC {
+            return Err("not an OpenEXR file".to_string());
+        }
+        if data[4] != EXR_VERSION || data[5] & !EXR_LONG_NAMES != 0 {
+            return Err("only single-part scanline OpenEXR files are supported".to_string());
+        }
+
+        let mut r = ByteReader { data, pos: 8 };
+        let mut channel_list = Vec::new();
+        let mut compression = None;
+        let mut data_window = None;
+        loop {
+            let name = r.string()?;
+            if name.is_empty() {
+                break;
+            }
+            let _type_name = r.string()?;
+            let size = r.i32()? as usize;
+            let value = r.bytes(size)?;
+            match name.as_str() {
+                "channels" => channel_list = parse_channel_list(value)?,
+                "compression" => {
+                    compression = Some(match value.first() {
+                        Some(0) => ExrCompression::None,
+                        Some(3) => ExrCompression::Zip,
+                        Some(4) => ExrCompression::Piz,
+                        v => return Err(format!("unsupported OpenEXR compression {:?}", v)),
+                    })
+                }
+                "dataWindow" => {
+                    let mut w = ByteReader { data: value, pos: 0 };
+                    data_window = Some([w.i32()?, w.i32()?, w.i32()?, w.i32()?]);
+                }
+                _ => {}
+            }
+        }
+        let compression = compression.ok_or("missing compression attribute")?;
+        let window = data_window.ok_or("missing dataWindow attribute")?;
+        let width = window[2] as i64 - window[0] as i64 + 1;
+        let height = window[3] as i64 - window[1] as i64 + 1;
+        if width <= 0 || height <= 0 || width > i16::MAX as i64 || height > i16::MAX as i64 {
+            return Err(format!("unsupported image size {}x{}", width, height));
+        }
+
+        let (width, height) = (width as usize, height as usize);
+        let mut image = Self::new(Point2i::new(width as i16, height as i16)).with_compression(compression);
+        for (name, pixel_type) in channel_list.iter() {
+            image.channels.push(ExrChannel {
+                name: name.clone(),
+                data: match *pixel_type {
+                    ExrPixelType::Uint => ExrChannelData::Uint(vec![0; width * height]),
+                    ExrPixelType::Half => ExrChannelData::Half(vec![0.0; width * height]),
+                    ExrPixelType::Float => ExrChannelData::Float(vec![0.0; width * height]),
+                },
+            });
+        }
+
+        let lines_per_block = compression.lines_per_block();
+        let n_blocks = height.div_ceil(lines_per_block);
+        let line_size: usize = channel_list.iter().map(|(_, t)| t.size() * width).sum();
+        for block in 0..n_blocks {
+            let mut table = ByteReader {
+                data,
+                pos: r.pos + 8 * block,
+            };
+            let mut chunk = ByteReader {
+                data,
+                pos: table.u64()? as usize,
+            };
+            let y0 = chunk
+                .i32()?
+                .checked_sub(window[1])
+                .and_then(|y| usize::try_from(y).ok())
+                .filter(|&y| y < height)
+                .ok_or("scanline block outside the data window")?;
+            let size = usize::try_from(chunk.i32()?).map_err(|_| "invalid scanline block size")?;
+            let packed = chunk.bytes(size)?;
+            let n_lines = lines_per_block.min(height - y0);
+            let raw_size = line_size * n_lines;
+            let raw = if size < raw_size {
+                match compression {
+                    ExrCompression::None => return Err("truncated scanline block".to_string()),
+                    ExrCompression::Zip => zip_decompress(packed)?,
+                    ExrCompression::Piz => {
+                        let types: Vec<ExrPixelType> = channel_list.iter().map(|(_, t)| *t).collect();
+                        piz_decompress(packed, &types, width, n_lines)?
+                    }
+                }
+            } else {
+                packed.to_vec()
+            };
+            if raw.len() != raw_size {
+                return Err("scanline block has the wrong size".to_string());
+            }
+
+            let mut pos = 0;
+            for y in y0..y0 + n_lines {
+                for c in image.channels.iter_mut() {
+                    let size = c.pixel_type().size();
+                    for x in 0..width {
+                        c.data.read_value(y * width + x, &raw[pos..pos + size]);
+                        pos += size;
+                    }
+                }
+            }
+        }
+        Ok(image)
+    }
+
+    pub fn write(&self, name: &str) -> io::Result<()> {
+        fs::write(name, self.encode())
+    }
+
+    pub fn read(name: &str) -> io::Result<Self> {
+        let data = fs::read(name)?;
+        Self::decode(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+fn write_attribute(out: &mut Vec<u8>, name: &str, type_name: &str, value: &[u8]) {
+    out.extend_from_slice(name.as_bytes());
+    out.push(0);
+    out.extend_from_slice(type_name.as_bytes());
+    out.push(0);
+    out.extend_from_slice(&(value.len() as i32).to_le_bytes());
+    out.extend_from_slice(value);
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let b = self
+            .data
+            .get(self.pos..self.pos.saturating_add(n))
+            .ok_or("unexpected end of OpenEXR data")?;
+        self.pos += n;
+        Ok(b)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.data[self.pos.min(self.data.len())..]
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or("unterminated string")?;
+        let s = String::from_utf8_lossy(self.bytes(len)?).into_owned();
+        self.pos += 1;
+        Ok(s)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        let b = self.bytes(4)?;
+        Ok(i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        let b = self.bytes(8)?;
+        let mut v = [0u8; 8];
+        v.copy_from_slice(b);
+        Ok(u64::from_le_bytes(v))
+    }
+}
+
+fn parse_channel_list(value: &[u8]) -> Result<Vec<(String, ExrPixelType)>, String> {
+    let mut r = ByteReader { data: value, pos: 0 };
+    let mut channels = Vec::new();
+    loop {
+        let name = r.string()?;
+        if name.is_empty() {
+            return Ok(channels);
+        }
+        let pixel_type = match r.i32()? {
+            0 => ExrPixelType::Uint,
+            1 => ExrPixelType::Half,
+            2 => ExrPixelType::Float,
+            t => return Err(format!("invalid pixel type {}", t)),
+        };
+        r.bytes(4)?;
+        if r.i32()? != 1 || r.i32()? != 1 {
+            return Err("subsampled channels are not supported".to_string());
+        }
+        channels.push((name, pixel_type));
+    }
+}
+
+// ZIP: bytes are split into even and odd halves, delta coded, then deflated
+fn zip_compress(raw: &[u8]) -> Vec<u8> {
+    let half = raw.len().div_ceil(2);
+    let mut tmp = vec![0u8; raw.len()];
+    for (i, &b) in raw.iter().enumerate() {
+        tmp[if i % 2 == 0 { i / 2 } else { half + i / 2 }] = b;
+    }
+    let mut p = tmp.first().copied().unwrap_or(0);
+    for t in tmp.iter_mut().skip(1) {
+        let d = t.wrapping_sub(p).wrapping_add(128);
+        p = *t;
+        *t = d;
+    }
+    zlib_compress(&tmp)
+}
+
+fn zip_decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut tmp = zlib_decompress(data)?;
+    for i in 1..tmp.len() {
+        tmp[i] = tmp[i - 1].wrapping_add(tmp[i]).wrapping_sub(128);
+    }
+    let half = tmp.len().div_ceil(2);
+    Ok((0..tmp.len())
+        .map(|i| tmp[if i % 2 == 0 { i / 2 } else { half + i / 2 }])
+        .collect())
+}
+
+// 16-bit words of one PIZ block grouped by channel. every channel holds its
+// lines one after another, 32-bit values as two words.
+fn piz_words(raw: &[u8], types: &[ExrPixelType], width: usize, n_lines: usize) -> Vec<Vec<u16>> {
+    let mut words: Vec<Vec<u16>> = types.iter().map(|_| Vec::new()).collect();
+    let mut pos = 0;
+    for _ in 0..n_lines {
+        for (c, t) in types.iter().enumerate() {
+            let n = width * t.size();
+            words[c].extend(raw[pos..pos + n].chunks(2).map(|b| u16::from_le_bytes([b[0], b[1]])));
+            pos += n;
+        }
+    }
+    words
+}
+
+fn piz_compress(raw: &[u8], channels: &[&ExrChannel], width: usize, n_lines: usize) -> Vec<u8> {
+    let types: Vec<ExrPixelType> = channels.iter().map(|c| c.pixel_type()).collect();
+    let mut words = piz_words(raw, &types, width, n_lines);
+
+    // the values actually present are remapped to a dense range
+    let mut bitmap = vec![0u8; BITMAP_SIZE];
+    for &w in words.iter().flatten() {
+        bitmap[w as usize >> 3] |= 1 << (w & 7);
+    }
+    bitmap[0] &= !1;
+    let min_non_zero = bitmap.iter().position(|&b| b != 0).unwrap_or(BITMAP_SIZE - 1);
+    let max_non_zero = bitmap.iter().rposition(|&b| b != 0).unwrap_or(0);
+    let mut lut = vec![0u16; 1 << 16];
+    let mut k = 0u16;
+    for (i, l) in lut.iter_mut().enumerate() {
+        if i == 0 || bitmap[i >> 3] & (1 << (i & 7)) != 0 {
+            *l = k;
+            k += 1;
+        }
+    }
+    let max_value = k - 1;
+
+    for (c, t) in types.iter().enumerate() {
+        for w in words[c].iter_mut() {
+            *w = lut[*w as usize];
+        }
+        let size = t.size() / 2;
+        for j in 0..size {
+            wav2_encode(&mut words[c][j..], width, size, n_lines, width * size, max_value);
+        }
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&(min_non_zero as u16).to_le_bytes());
+    out.extend_from_slice(&(max_non_zero as u16).to_le_bytes());
+    if min_non_zero <= max_non_zero {
+        out.extend_from_slice(&bitmap[min_non_zero..=max_non_zero]);
+    }
+    let all: Vec<u16> = words.into_iter().flatten().collect();
+    let huffman = huf_compress(&all);
+    out.extend_from_slice(&(huffman.len() as i32).to_le_bytes());
+    out.extend(huffman);
+    out
+}
+
+fn piz_decompress(data: &[u8], types: &[ExrPixelType], width: usize, n_lines: usize) -> Result<Vec<u8>, String> {
+    let mut r = ByteReader { data, pos: 0 };
+    let min_non_zero = r.u16()? as usize;
+    let max_non_zero = r.u16()? as usize;
+    let mut bitmap = vec![0u8; BITMAP_SIZE];
+    if min_non_zero <= max_non_zero {
+        if max_non_zero >= BITMAP_SIZE {
+            return Err("invalid PIZ bitmap range".to_string());
+        }
+        bitmap[min_non_zero..=max_non_zero].copy_from_slice(r.bytes(max_non_zero - min_non_zero + 1)?);
+    }
+    let mut lut = Vec::with_capacity(1 << 16);
+    for i in 0..1usize << 16 {
+        if i == 0 || bitmap[i >> 3] & (1 << (i & 7)) != 0 {
+            lut.push(i as u16);
+        }
+    }
+    let max_value = (lut.len() - 1) as u16;
+
+    let length = r.i32()? as usize;
+    let n_words: usize = types.iter().map(|t| width * n_lines * t.size() / 2).sum();
+    let mut all = huf_uncompress(r.bytes(length)?, n_words)?;
+
+    let mut start = 0;
+    for t in types.iter() {
+        let size = t.size() / 2;
+        let n = width * n_lines * size;
+        for j in 0..size {
+            wav2_decode(&mut all[start + j..start + n], width, size, n_lines, width * size, max_value);
+        }
+        start += n;
+    }
+    for w in all.iter_mut() {
+        *w = *lut.get(*w as usize).ok_or("PIZ value outside the lookup table")?;
+    }
+
+    // back to scanline order
+    let mut channel_start = Vec::with_capacity(types.len());
+    let mut s = 0;
+    for t in types.iter() {
+        channel_start.push(s);
+        s += width * n_lines * t.size() / 2;
+    }
+    let mut raw = Vec::with_capacity(2 * n_words);
+    for y in 0..n_lines {
+        for (c, t) in types.iter().enumerate() {
+            let n = width * t.size() / 2;
+            let line = channel_start[c] + y * n;
+            for w in &all[line..line + n] {
+                raw.extend_from_slice(&w.to_le_bytes());
+            }
+        }
+    }
+    Ok(raw)
+}
+
+fn wenc14(a: u16, b: u16) -> (u16, u16) {
+    let (a, b) = (a as i16 as i32, b as i16 as i32);
+    let m = (a + b) >> 1;
+    let d = a - b;
+    (m as u16, d as u16)
+}
+
+fn wdec14(l: u16, h: u16) -> (u16, u16) {
+    let (l, h) = (l as i16 as i32, h as i16 as i32);
+    let a = l + (h & 1) + (h >> 1);
+    let b = a - h;
+    (a as i16 as u16, b as i16 as u16)
+}
+
+fn wenc16(a: u16, b: u16) -> (u16, u16) {
+    let ao = (a as i32 + 0x8000) & 0xffff;
+    let mut m = (ao + b as i32) >> 1;
+    let d = ao - b as i32;
+    if d < 0 {
+        m = (m + 0x8000) & 0xffff;
+    }
+    (m as u16, (d & 0xffff) as u16)
+}
+
+fn wdec16(l: u16, h: u16) -> (u16, u16) {
+    let (m, d) = (l as i32, h as i32);
+    let b = (m - (d >> 1)) & 0xffff;
+    let a = (d + b - 0x8000) & 0xffff;
+    (a as u16, b as u16)
+}
+
+// 2D Haar-like wavelet of the PIZ codec, in place over an nx by ny grid with
+// element stride ox and line stride oy
+fn wav2_encode(data: &mut [u16], nx: usize, ox: usize, ny: usize, oy: usize, max_value: u16) {
+    let enc = if max_value < (1 << 14) { wenc14 } else { wenc16 };
+    let n = nx.min(ny);
+    let mut p = 1;
+    let mut p2 = 2;
+    while p2 <= n {
+        let (ox1, ox2, oy1, oy2) = (ox * p, ox * p2, oy * p, oy * p2);
+        let mut py = 0;
+        while py + oy2 <= oy * ny {
+            let mut px = py;
+            while px + ox2 <= py + ox * nx {
+                let (p01, p10) = (px + ox1, px + oy1);
+                let p11 = p10 + ox1;
+                let (i00, i01) = enc(data[px], data[p01]);
+                let (i10, i11) = enc(data[p10], data[p11]);
+                let (a, b) = enc(i00, i10);
+                data[px] = a;
+                data[p10] = b;
+                let (a, b) = enc(i01, i11);
+                data[p01] = a;
+                data[p11] = b;
+                px += ox2;
+            }
+            if nx & p != 0 {
+                let p10 = px + oy1;
+                let (a, b) = enc(data[px], data[p10]);
+                data[px] = a;
+                data[p10] = b;
+            }
+            py += oy2;
+        }
+        if ny & p != 0 {
+            let mut px = py;
+            while px + ox2 <= py + ox * nx {
+                let p01 = px + ox1;
+                let (a, b) = enc(data[px], data[p01]);
+                data[px] = a;
+                data[p01] = b;
+                px += ox2;
+            }
+        }
+        p = p2;
+        p2 <<= 1;
+    }
+}
+
+fn wav2_decode(data: &mut [u16], nx: usize, ox: usize, ny: usize, oy: usize, max_value: u16) {
+    let dec = if max_value < (1 << 14) { wdec14 } else { wdec16 };
+    let n = nx.min(ny);
+    let mut p = 1;
+    while p <= n {
+        p <<= 1;
+    }
+    p >>= 1;
+    let mut p2 = p;
+    p >>= 1;
+    while p >= 1 {
+        let (ox1, ox2, oy1, oy2) = (ox * p, ox * p2, oy * p, oy * p2);
+        let mut py = 0;
+        while py + oy2 <= oy * ny {
+            let mut px = py;
+            while px + ox2 <= py + ox * nx {
+                let (p01, p10) = (px + ox1, px + oy1);
+                let p11 = p10 + ox1;
+                let (i00, i10) = dec(data[px], data[p10]);
+                let (i01, i11) = dec(data[p01], data[p11]);
+                let (a, b) = dec(i00, i01);
+                data[px] = a;
+                data[p01] = b;
+                let (a, b) = dec(i10, i11);
+                data[p10] = a;
+                data[p11] = b;
+                px += ox2;
+            }
+            if nx & p != 0 {
+                let p10 = px + oy1;
+                let (a, b) = dec(data[px], data[p10]);
+                data[px] = a;
+                data[p10] = b;
+            }
+            py += oy2;
+        }
+        if ny & p != 0 {
+            let mut px = py;
+            while px + ox2 <= py + ox * nx {
+                let p01 = px + ox1;
+                let (a, b) = dec(data[px], data[p01]);
+                data[px] = a;
+                data[p01] = b;
+                px += ox2;
+            }
+        }
+        p2 = p;
+        p >>= 1;
+    }
+}
+
+// canonical code assignment of the OpenEXR huffman coder: lengths in, and
+// code << 6 | length out. longer codes get the numerically smaller values.
+fn huf_canonical_code_table(lengths: &[u64]) -> Vec<u64> {
+    let mut n = [0u64; 59];
+    for &l in lengths {
+        n[l as usize] += 1;
+    }
+    let mut c = 0;
+    for i in (1..=58).rev() {
+        let nc = (c + n[i]) >> 1;
+        n[i] = c;
+        c = nc;
+    }
+    lengths
+        .iter()
+        .map(|&l| {
+            if l > 0 {
+                let code = l | (n[l as usize] << 6);
+                n[l as usize] += 1;
+                code
+            } else {
+                0
+            }
+        })
+        .collect()
+}
+
+// MSB first bit packing used by the huffman coder
+struct HufWriter {
+    out: Vec<u8>,
+    c: u64,
+    lc: u32,
+}
+
+impl HufWriter {
+    fn bits(&mut self, n: u32, bits: u64) {
+        self.c = (self.c << n) | bits;
+        self.lc += n;
+        while self.lc >= 8 {
+            self.lc -= 8;
+            self.out.push((self.c >> self.lc) as u8);
+        }
+    }
+
+    fn code(&mut self, code: u64) {
+        self.bits((code & 63) as u32, code >> 6);
+    }
+
+    fn flush(&mut self) {
+        if self.lc > 0 {
+            self.out.push((self.c << (8 - self.lc)) as u8);
+        }
+    }
+}
+
+struct HufReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    c: u64,
+    lc: u32,
+}
+
+impl HufReader<'_> {
+    fn bits(&mut self, n: u32) -> Result<u64, String> {
+        while self.lc < n {
+            let b = *self.data.get(self.pos).ok_or("unexpected end of huffman data")?;
+            self.pos += 1;
+            self.c = (self.c << 8) | b as u64;
+            self.lc += 8;
+        }
+        self.lc -= n;
+        Ok((self.c >> self.lc) & ((1 << n) - 1))
+    }
+}
+
+fn huf_compress(raw: &[u16]) -> Vec<u8> {
+    if raw.is_empty() {
+        return Vec::new();
+    }
+    let mut freq = vec![0u64; HUF_ENCSIZE];
+    for &v in raw {
+        freq[v as usize] += 1;
+    }
+    let im = freq.iter().position(|&f| f != 0).unwrap();
+    // one past the largest symbol is the run length pseudo-symbol
+    let rlc = freq.iter().rposition(|&f| f != 0).unwrap() + 1;
+    freq[rlc] = 1;
+
+    // huffman code lengths, merging the two least frequent subtrees
+    let mut lengths = vec![0u64; HUF_ENCSIZE];
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+    let mut members: Vec<Vec<usize>> = Vec::new();
+    for (s, &f) in freq.iter().enumerate().take(rlc + 1).skip(im) {
+        if f != 0 {
+            heap.push(Reverse((f, members.len())));
+            members.push(vec![s]);
+        }
+    }
+    while heap.len() > 1 {
+        let Reverse((f0, a)) = heap.pop().unwrap();
+        let Reverse((f1, b)) = heap.pop().unwrap();
+        let mut merged = std::mem::take(&mut members[a]);
+        merged.append(&mut members[b]);
+        for &s in merged.iter() {
+            lengths[s] += 1;
+        }
+        heap.push(Reverse((f0 + f1, members.len())));
+        members.push(merged);
+    }
+    let codes = huf_canonical_code_table(&lengths);
+
+    // code lengths table, with runs of unused symbols shortened
+    let mut w = HufWriter {
+        out: Vec::new(),
+        c: 0,
+        lc: 0,
+    };
+    let mut i = im;
+    while i <= rlc {
+        let l = lengths[i];
+        if l == 0 {
+            let mut zerun = 1;
+            while i < rlc && zerun < LONGEST_LONG_RUN && lengths[i + 1] == 0 {
+                i += 1;
+                zerun += 1;
+            }
+            if zerun >= 2 {
+                if zerun >= SHORTEST_LONG_RUN {
+                    w.bits(6, LONG_ZEROCODE_RUN);
+                    w.bits(8, zerun - SHORTEST_LONG_RUN);
+                } else {
+                    w.bits(6, SHORT_ZEROCODE_RUN + zerun - 2);
+                }
+                i += 1;
+                continue;
+            }
+        }
+        w.bits(6, l);
+        i += 1;
+    }
+    w.flush();
+    let table = std::mem::take(&mut w.out);
+    w.c = 0;
+    w.lc = 0;
+
+    // symbols, with runs of a repeated value sent as the run length code
+    // followed by the repeat count when that is shorter
+    let send = |w: &mut HufWriter, s: usize, run: u64| {
+        let (code, rlc_code) = (codes[s], codes[rlc]);
+        if (code & 63) + (rlc_code & 63) + 8 < (code & 63) * run {
+            w.code(code);
+            w.code(rlc_code);
+            w.bits(8, run);
+        } else {
+            for _ in 0..=run {
+                w.code(code);
+            }
+        }
+    };
+    let mut s = raw[0] as usize;
+    let mut run = 0;
+    for &v in &raw[1..] {
+        if v as usize == s && run < 255 {
+            run += 1;
+        } else {
+            send(&mut w, s, run);
+            run = 0;
+        }
+        s = v as usize;
+    }
+    send(&mut w, s, run);
+    let n_bits = 8 * w.out.len() as u32 + w.lc;
+    w.flush();
+
+    let mut out = Vec::with_capacity(20 + table.len() + w.out.len());
+    for v in &[im as u32, rlc as u32, table.len() as u32, n_bits, 0] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out.extend(table);
+    out.extend(w.out);
+    out
+}
+
+fn huf_uncompress(data: &[u8], n_raw: usize) -> Result<Vec<u16>, String> {
+    if data.is_empty() {
+        return if n_raw == 0 { Ok(Vec::new()) } else { Err("missing huffman data".to_string()) };
+    }
+    if data.len() < 20 {
+        return Err("truncated huffman header".to_string());
+    }
+    let read_u32 = |i: usize| u32::from_le_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]) as usize;
+    let (im, rlc, table_length, n_bits) = (read_u32(0), read_u32(4), read_u32(8), read_u32(12));
+    if im >= HUF_ENCSIZE || rlc >= HUF_ENCSIZE || im > rlc || 20 + table_length > data.len() {
+        return Err("invalid huffman table".to_string());
+    }
+
+    let mut r = HufReader {
+        data: &data[20..20 + table_length],
+        pos: 0,
+        c: 0,
+        lc: 0,
+    };
+    let mut lengths = vec![0u64; HUF_ENCSIZE];
+    let mut i = im;
+    while i <= rlc {
+        let l = r.bits(6)?;
+        let zerun = if l == LONG_ZEROCODE_RUN {
+            r.bits(8)? + SHORTEST_LONG_RUN
+        } else if l >= SHORT_ZEROCODE_RUN {
+            l - SHORT_ZEROCODE_RUN + 2
+        } else {
+            lengths[i] = l;
+            i += 1;
+            continue;
+        };
+        if i + zerun as usize > rlc + 1 {
+            return Err("huffman table run too long".to_string());
+        }
+        i += zerun as usize;
+    }
+    let codes = huf_canonical_code_table(&lengths);
+    // the run length symbol is 65536 when every 16-bit value is used
+    let decode: HashMap<u64, usize> = codes
+        .iter()
+        .enumerate()
+        .filter(|(_, &c)| c != 0)
+        .map(|(s, &c)| (c, s))
+        .collect();
+
+    let payload = &data[20 + table_length..];
+    if n_bits > 8 * payload.len() {
+        return Err("truncated huffman data".to_string());
+    }
+    let mut r = HufReader {
+        data: payload,
+        pos: 0,
+        c: 0,
+        lc: 0,
+    };
+    let mut raw = Vec::with_capacity(n_raw);
+    let mut consumed = 0;
+    let mut code = 0u64;
+    let mut length = 0u64;
+    while consumed < n_bits {
+        code = (code << 1) | r.bits(1)?;
+        length += 1;
+        consumed += 1;
+        if length > 58 {
+            return Err("invalid huffman code".to_string());
+        }
+        if let Some(&s) = decode.get(&(code << 6 | length)) {
+            if s == rlc {
+                let run = r.bits(8)? as usize;
+                consumed += 8;
+                let last = *raw.last().ok_or("huffman run without a value")?;
+                raw.extend(std::iter::repeat(last).take(run));
+            } else {
+                raw.push(s as u16);
+            }
+            code = 0;
+            length = 0;
+        }
+    }
+    if raw.len() != n_raw {
+        return Err("huffman data has the wrong length".to_string());
+    }
+    Ok(raw)
+}
@@ -0,0 +1,236 @@
+use crate::core::geometry::Point2i;
+use crate::core::imageio::{decode_png, encode_png, ExrImage, ExrPixelType};
+use crate::core::pbrt::clamp;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// sRGB transfer curve from linear values to display values
+pub fn gamma_correct(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        12.92 * value
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub fn inverse_gamma_correct(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// scales by 2^exposure, applies the sRGB curve and clamps to 8 bits
+pub fn to_srgb8(rgb: &[[f32; 3]], exposure: f32) -> Vec<u8> {
+    let scale = 2f32.powf(exposure);
+    rgb.iter()
+        .flat_map(|p| p.iter())
+        .map(|&v| clamp(255.0 * gamma_correct(v * scale) + 0.5, 0.0, 255.0) as u8)
+        .collect()
+}
+
+pub fn from_srgb8(bytes: &[u8]) -> Vec<[f32; 3]> {
+    bytes
+        .chunks(3)
+        .map(|p| {
+            [
+                inverse_gamma_correct(p[0] as f32 / 255.0),
+                inverse_gamma_correct(p[1] as f32 / 255.0),
+                inverse_gamma_correct(p[2] as f32 / 255.0),
+            ]
+        })
+        .collect()
+}
+
+// binary 8-bit portable pixmap
+pub fn encode_ppm(rgb: &[u8], width: usize, height: usize) -> Vec<u8> {
+    assert_eq!(rgb.len(), 3 * width * height);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.extend_from_slice(rgb);
+    out
+}
+
+// splits off the next whitespace separated token of a pnm style header,
+// skipping comments
+fn next_token<'a>(data: &'a [u8], pos: &mut usize) -> Result<&'a str, String> {
+    loop {
+        while *pos < data.len() && data[*pos].is_ascii_whitespace() {
+            *pos += 1;
+        }
+        if *pos < data.len() && data[*pos] == b'#' {
+            while *pos < data.len() && data[*pos] != b'\n' {
+                *pos += 1;
+            }
+        } else {
+            break;
+        }
+    }
+    let start = *pos;
+    while *pos < data.len() && !data[*pos].is_ascii_whitespace() {
+        *pos += 1;
+    }
+    if start == *pos {
+        return Err("unexpected end of header".to_string());
+    }
+    std::str::from_utf8(&data[start..*pos]).map_err(|e| e.to_string())
+}
+
+// byte count of an image from untrusted header values, e.g. bytes per
+// pixel, width and height
+pub(crate) fn image_size(factors: &[usize]) -> Result<usize, String> {
+    factors
+        .iter()
+        .try_fold(1usize, |n, &f| n.checked_mul(f))
+        .ok_or_else(|| "image size overflows".to_string())
+}
+
+// the n bytes after the header, which ends with a single whitespace byte
+fn image_data(data: &[u8], pos: usize, n: usize) -> Option<&[u8]> {
+    let start = pos.checked_add(1)?;
+    data.get(start..start.checked_add(n)?)
+}
+
+fn parse_token<T: std::str::FromStr>(data: &[u8], pos: &mut usize) -> Result<T, String> {
+    let token = next_token(data, pos)?;
+    token.parse().map_err(|_| format!("invalid header value {}", token))
+}
+
+pub fn decode_ppm(data: &[u8]) -> Result<(Vec<u8>, usize, usize), String> {
+    let mut pos = 0;
+    if next_token(data, &mut pos)? != "P6" {
+        return Err("only binary (P6) ppm is supported".to_string());
+    }
+    let width: usize = parse_token(data, &mut pos)?;
+    let height: usize = parse_token(data, &mut pos)?;
+    let max_value: u32 = parse_token(data, &mut pos)?;
+    if max_value == 0 || max_value > 255 {
+        return Err(format!("unsupported ppm max value {}", max_value));
+    }
+    let pixels = image_data(data, pos, image_size(&[3, width, height])?).ok_or("ppm pixel data too short")?;
+    let rgb = pixels
+        .iter()
+        .map(|&v| ((v as u32 * 255 + max_value / 2) / max_value) as u8)
+        .collect();
+    Ok((rgb, width, height))
+}
+
+// portable float map, little endian with the rows stored bottom to top
+pub fn encode_pfm(rgb: &[[f32; 3]], width: usize, height: usize) -> Vec<u8> {
+    assert_eq!(rgb.len(), width * height);
+    let mut out = format!("PF\n{} {}\n-1\n", width, height).into_bytes();
+    for y in (0..height).rev() {
+        for p in &rgb[y * width..(y + 1) * width] {
+            for v in p.iter() {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+    }
+    out
+}
+
+pub fn decode_pfm(data: &[u8]) -> Result<(Vec<[f32; 3]>, usize, usize), String> {
+    let mut pos = 0;
+    let channels = match next_token(data, &mut pos)? {
+        "PF" => 3,
+        "Pf" => 1,
+        _ => return Err("not a pfm file".to_string()),
+    };
+    let width: usize = parse_token(data, &mut pos)?;
+    let height: usize = parse_token(data, &mut pos)?;
+    // the sign of the scale gives the byte order, its magnitude is unused
+    let scale: f32 = parse_token(data, &mut pos)?;
+    let little_endian = scale < 0.0;
+    let pixels = image_data(data, pos, image_size(&[4, channels, width, height])?).ok_or("pfm pixel data too short")?;
+    let values: Vec<f32> = pixels
+        .chunks(4)
+        .map(|b| {
+            let bytes = [b[0], b[1], b[2], b[3]];
+            if little_endian {
+                f32::from_le_bytes(bytes)
+            } else {
+                f32::from_be_bytes(bytes)
+            }
+        })
+        .collect();
+
+    let mut rgb = vec![[0.0; 3]; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let i = channels * ((height - 1 - y) * width + x);
+            rgb[y * width + x] = if channels == 3 {
+                [values[i], values[i + 1], values[i + 2]]
+            } else {
+                [values[i]; 3]
+            };
+        }
+    }
+    Ok((rgb, width, height))
+}
+
+fn extension(name: &str) -> String {
+    Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase()
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// writes linear RGB pixels in scanline order, the format is chosen by the
+// file extension. exposure only applies to the 8-bit formats, float files
+// keep the values as they are.
+pub fn write_image(name: &str, rgb: &[[f32; 3]], resolution: Point2i, exposure: f32) -> io::Result<()> {
+    let (width, height) = (resolution.x as usize, resolution.y as usize);
+    if rgb.len() != width * height {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} pixels for a {}x{} image", rgb.len(), width, height),
+        ));
+    }
+    let data = match extension(name).as_str() {
+        "ppm" => encode_ppm(&to_srgb8(rgb, exposure), width, height),
+        "png" => encode_png(&to_srgb8(rgb, exposure), width, height),
+        "pfm" => encode_pfm(rgb, width, height),
+        "exr" => {
+            let mut image = ExrImage::new(resolution);
+            image.add_layer("", ["R", "G", "B"], ExrPixelType::Half, rgb);
+            image.encode()
+        }
+        ext => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported image format \"{}\"", ext),
+            ))
+        }
+    };
+    fs::write(name, data)
+}
+
+// reads an image back as linear RGB, undoing the sRGB curve of 8-bit files
+pub fn read_image(name: &str) -> io::Result<(Vec<[f32; 3]>, Point2i)> {
+    let data = fs::read(name)?;
+    let (rgb, width, height) = match extension(name).as_str() {
+        "ppm" => decode_ppm(&data).map(|(b, w, h)| (from_srgb8(&b), w, h)),
+        "png" => decode_png(&data).map(|(b, w, h)| (from_srgb8(&b), w, h)),
+        "pfm" => decode_pfm(&data),
+        "exr" => ExrImage::decode(&data).map(|image| {
+            // luminance only images come back as gray
+            let channel = |name: &str| image.channel(name).or_else(|| image.channel("Y"));
+            let n = image.resolution.x as usize * image.resolution.y as usize;
+            let value = |name: &str, i: usize| channel(name).map_or(0.0, |c| c.data.float_value(i));
+            let rgb = (0..n).map(|i| [value("R", i), value("G", i), value("B", i)]).collect();
+            (rgb, image.resolution.x as usize, image.resolution.y as usize)
+        }),
+        ext => Err(format!("unsupported image format \"{}\"", ext)),
+    }
+    .map_err(invalid_data)?;
+    if width > i16::MAX as usize || height > i16::MAX as usize {
+        return Err(invalid_data(format!("{}x{} image is too large", width, height)));
+    }
+    Ok((rgb, Point2i::new(width as i16, height as i16)))
+}
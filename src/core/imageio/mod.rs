@@ -0,0 +1,9 @@
+pub use exr::*;
+pub use imageio::*;
+pub use png::*;
+pub use zlib::*;
+
+mod exr;
+mod imageio;
+mod png;
+mod zlib;
@@ -0,0 +1,170 @@
+use crate::core::imageio::{image_size, zlib_compress, zlib_decompress};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn write_chunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(chunk_type);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = ((p - a as i16).abs(), (p - b as i16).abs(), (p - c as i16).abs());
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+// predictor of a byte from its left, upper and upper-left neighbours
+fn predict(filter: u8, a: u8, b: u8, c: u8) -> u8 {
+    match filter {
+        1 => a,
+        2 => b,
+        3 => ((a as u16 + b as u16) / 2) as u8,
+        4 => paeth(a, b, c),
+        _ => 0,
+    }
+}
+
+// encodes 8-bit RGB pixels in scanline order. every row uses the filter
+// whose output has the smallest sum of absolute values.
+pub fn encode_png(rgb: &[u8], width: usize, height: usize) -> Vec<u8> {
+    assert_eq!(rgb.len(), 3 * width * height);
+    let stride = 3 * width;
+    let mut raw = Vec::with_capacity((stride + 1) * height);
+    let zero_row = vec![0u8; stride];
+    let mut candidate = vec![0u8; stride];
+    let mut best = vec![0u8; stride];
+    for y in 0..height {
+        let row = &rgb[y * stride..(y + 1) * stride];
+        let prev = if y > 0 { &rgb[(y - 1) * stride..y * stride] } else { &zero_row[..] };
+        let mut best_filter = 0;
+        let mut best_cost = u64::MAX;
+        for filter in 0..5u8 {
+            for i in 0..stride {
+                let a = if i >= 3 { row[i - 3] } else { 0 };
+                let c = if i >= 3 { prev[i - 3] } else { 0 };
+                candidate[i] = row[i].wrapping_sub(predict(filter, a, prev[i], c));
+            }
+            let cost = candidate.iter().map(|&v| (v as i8).unsigned_abs() as u64).sum();
+            if cost < best_cost {
+                best_cost = cost;
+                best_filter = filter;
+                best.copy_from_slice(&candidate);
+            }
+        }
+        raw.push(best_filter);
+        raw.extend_from_slice(&best);
+    }
+
+    let mut out = PNG_SIGNATURE.to_vec();
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, truecolor, deflate, adaptive filtering, no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &header);
+    // pixel values are sRGB encoded
+    write_chunk(&mut out, b"sRGB", &[0]);
+    write_chunk(&mut out, b"IDAT", &zlib_compress(&raw));
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+// decodes a non-interlaced 8-bit grayscale, RGB, gray-alpha or RGBA png to
+// RGB bytes. returns the pixels, width and height.
+pub fn decode_png(data: &[u8]) -> Result<(Vec<u8>, usize, usize), String> {
+    if data.len() < 8 || data[..8] != PNG_SIGNATURE {
+        return Err("not a png file".to_string());
+    }
+    let mut pos = 8;
+    let mut header = None;
+    let mut idat = Vec::new();
+    while pos + 12 <= data.len() {
+        let length = u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+        let end = (pos + 12).checked_add(length).ok_or("truncated png chunk")?;
+        if end > data.len() {
+            return Err("truncated png chunk".to_string());
+        }
+        let chunk_type = &data[pos + 4..pos + 8];
+        let chunk = &data[pos + 8..pos + 8 + length];
+        let crc = u32::from_be_bytes([data[end - 4], data[end - 3], data[end - 2], data[end - 1]]);
+        if crc != crc32(&data[pos + 4..pos + 8 + length]) {
+            return Err("png chunk crc mismatch".to_string());
+        }
+        match chunk_type {
+            b"IHDR" => header = Some(chunk.to_vec()),
+            b"IDAT" => idat.extend_from_slice(chunk),
+            b"IEND" => break,
+            _ => {}
+        }
+        pos = end;
+    }
+
+    let header = header.ok_or("png has no header")?;
+    if header.len() != 13 {
+        return Err("invalid png header".to_string());
+    }
+    let width = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let height = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    let (bit_depth, color_type, interlace) = (header[8], header[9], header[12]);
+    if bit_depth != 8 || interlace != 0 {
+        return Err("only 8-bit non-interlaced png is supported".to_string());
+    }
+    let channels = match color_type {
+        0 => 1,
+        2 => 3,
+        4 => 2,
+        6 => 4,
+        _ => return Err(format!("unsupported png color type {}", color_type)),
+    };
+
+    let stride = image_size(&[channels, width])?;
+    let raw_size = image_size(&[stride.checked_add(1).ok_or("image size overflows")?, height])?;
+    let raw = zlib_decompress(&idat)?;
+    if raw.len() < raw_size {
+        return Err("png image data too short".to_string());
+    }
+    let mut pixels = vec![0u8; stride * height];
+    for y in 0..height {
+        let filter = raw[y * (stride + 1)];
+        if filter > 4 {
+            return Err(format!("invalid png filter {}", filter));
+        }
+        let line = &raw[y * (stride + 1) + 1..(y + 1) * (stride + 1)];
+        for i in 0..stride {
+            let a = if i >= channels { pixels[y * stride + i - channels] } else { 0 };
+            let b = if y > 0 { pixels[(y - 1) * stride + i] } else { 0 };
+            let c = if y > 0 && i >= channels { pixels[(y - 1) * stride + i - channels] } else { 0 };
+            pixels[y * stride + i] = line[i].wrapping_add(predict(filter, a, b, c));
+        }
+    }
+
+    let rgb = pixels
+        .chunks(channels)
+        .flat_map(|p| match channels {
+            1 | 2 => [p[0], p[0], p[0]],
+            _ => [p[0], p[1], p[2]],
+        })
+        .collect();
+    Ok((rgb, width, height))
+}
@@ -0,0 +1,385 @@
+// minimal zlib (RFC 1950) around deflate (RFC 1951). compression uses LZ77
+// with the fixed huffman codes, decompression handles every block type.
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+const WINDOW_SIZE: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: usize = 15;
+const MAX_CHAIN: usize = 64;
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before b overflows
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            bit_buffer: 0,
+            bit_count: 0,
+        }
+    }
+
+    // least significant bit first, as deflate packs everything but huffman codes
+    fn write_bits(&mut self, bits: u32, count: u32) {
+        self.bit_buffer |= bits << self.bit_count;
+        self.bit_count += count;
+        while self.bit_count >= 8 {
+            self.bytes.push(self.bit_buffer as u8);
+            self.bit_buffer >>= 8;
+            self.bit_count -= 8;
+        }
+    }
+
+    // huffman codes go out most significant bit first
+    fn write_code(&mut self, code: u32, length: u32) {
+        let reversed = code.reverse_bits() >> (32 - length);
+        self.write_bits(reversed, length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bit_count > 0 {
+            self.bytes.push(self.bit_buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+fn write_fixed_literal(w: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => w.write_code(0x30 + symbol, 8),
+        144..=255 => w.write_code(0x190 + symbol - 144, 9),
+        256..=279 => w.write_code(symbol - 256, 7),
+        _ => w.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(w: &mut BitWriter, length: usize, distance: usize) {
+    let l = LENGTH_BASE.iter().rposition(|&b| b as usize <= length).unwrap();
+    write_fixed_literal(w, 257 + l as u32);
+    w.write_bits((length - LENGTH_BASE[l] as usize) as u32, LENGTH_EXTRA[l] as u32);
+    let d = DIST_BASE.iter().rposition(|&b| b as usize <= distance).unwrap();
+    w.write_code(d as u32, 5);
+    w.write_bits((distance - DIST_BASE[d] as usize) as u32, DIST_EXTRA[d] as u32);
+}
+
+fn hash(data: &[u8], i: usize) -> usize {
+    let v = (data[i] as u32) << 16 | (data[i + 1] as u32) << 8 | data[i + 2] as u32;
+    (v.wrapping_mul(2654435761) >> (32 - HASH_BITS)) as usize
+}
+
+// raw deflate stream in a single fixed huffman block
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut w = BitWriter::new();
+    w.write_bits(1, 1);
+    w.write_bits(1, 2);
+
+    // most recent position for each hash and the previous one with the same hash
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; WINDOW_SIZE];
+    let insert = |head: &mut [usize], prev: &mut [usize], i: usize| {
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(data, i);
+            prev[i % WINDOW_SIZE] = head[h];
+            head[h] = i;
+        }
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let mut best_length = 0;
+        let mut best_distance = 0;
+        if i + MIN_MATCH <= data.len() {
+            let max_length = MAX_MATCH.min(data.len() - i);
+            let mut candidate = head[hash(data, i)];
+            let mut chain = 0;
+            while candidate != usize::MAX && i - candidate <= WINDOW_SIZE && chain < MAX_CHAIN {
+                let length = data[candidate..]
+                    .iter()
+                    .zip(data[i..i + max_length].iter())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if length > best_length {
+                    best_length = length;
+                    best_distance = i - candidate;
+                    if length == max_length {
+                        break;
+                    }
+                }
+                let next = prev[candidate % WINDOW_SIZE];
+                // stale entries from an older window point forwards
+                if next == usize::MAX || next >= candidate {
+                    break;
+                }
+                candidate = next;
+                chain += 1;
+            }
+        }
+
+        if best_length >= MIN_MATCH {
+            write_match(&mut w, best_length, best_distance);
+            for j in i..i + best_length {
+                insert(&mut head, &mut prev, j);
+            }
+            i += best_length;
+        } else {
+            write_fixed_literal(&mut w, data[i] as u32);
+            insert(&mut head, &mut prev, i);
+            i += 1;
+        }
+    }
+    write_fixed_literal(&mut w, 256);
+    w.finish()
+}
+
+pub fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    // 32K window, deflate, check bits making the header a multiple of 31
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data));
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            bit_buffer: 0,
+            bit_count: 0,
+        }
+    }
+
+    fn bits(&mut self, count: u32) -> Result<u32, String> {
+        while self.bit_count < count {
+            let byte = *self.data.get(self.pos).ok_or("unexpected end of deflate stream")?;
+            self.pos += 1;
+            self.bit_buffer |= (byte as u32) << self.bit_count;
+            self.bit_count += 8;
+        }
+        let v = self.bit_buffer & ((1u64 << count) - 1) as u32;
+        self.bit_buffer = if count == 32 { 0 } else { self.bit_buffer >> count };
+        self.bit_count -= count;
+        Ok(v)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+}
+
+// canonical huffman code, decoded one bit at a time
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, String> {
+        let mut counts = [0u16; 16];
+        for &l in lengths {
+            counts[l as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut left = 1i32;
+        for &c in counts.iter().skip(1) {
+            left = 2 * left - c as i32;
+            if left < 0 {
+                return Err("over-subscribed huffman code".to_string());
+            }
+        }
+        let mut offsets = [0u16; 16];
+        for l in 1..15 {
+            offsets[l + 1] = offsets[l] + counts[l];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &l) in lengths.iter().enumerate() {
+            if l != 0 {
+                symbols[offsets[l as usize] as usize] = symbol as u16;
+                offsets[l as usize] += 1;
+            }
+        }
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, r: &mut BitReader) -> Result<u16, String> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in self.counts.iter().skip(1) {
+            code |= r.bits(1)? as i32;
+            let count = count as i32;
+            if code - count < first {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("invalid huffman code".to_string())
+    }
+}
+
+fn fixed_huffman() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    for (i, l) in lengths.iter_mut().enumerate() {
+        *l = match i {
+            0..=143 => 8,
+            144..=255 => 9,
+            256..=279 => 7,
+            _ => 8,
+        };
+    }
+    (Huffman::new(&lengths).unwrap(), Huffman::new(&[5; 30]).unwrap())
+}
+
+fn dynamic_huffman(r: &mut BitReader) -> Result<(Huffman, Huffman), String> {
+    let n_literal = r.bits(5)? as usize + 257;
+    let n_distance = r.bits(5)? as usize + 1;
+    let n_code_length = r.bits(4)? as usize + 4;
+    let mut code_lengths = [0u8; 19];
+    for &i in CODE_LENGTH_ORDER.iter().take(n_code_length) {
+        code_lengths[i] = r.bits(3)? as u8;
+    }
+    let code_length_huffman = Huffman::new(&code_lengths)?;
+
+    let mut lengths = Vec::with_capacity(n_literal + n_distance);
+    while lengths.len() < n_literal + n_distance {
+        let symbol = code_length_huffman.decode(r)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let last = *lengths.last().ok_or("repeat without previous length")?;
+                (last, 3 + r.bits(2)?)
+            }
+            17 => (0, 3 + r.bits(3)?),
+            _ => (0, 11 + r.bits(7)?),
+        };
+        lengths.extend(std::iter::repeat(value).take(repeat as usize));
+    }
+    if lengths.len() > n_literal + n_distance {
+        return Err("too many code lengths".to_string());
+    }
+    Ok((
+        Huffman::new(&lengths[..n_literal])?,
+        Huffman::new(&lengths[n_literal..])?,
+    ))
+}
+
+pub fn inflate(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut r = BitReader::new(data);
+    let mut out = Vec::new();
+    loop {
+        let last = r.bits(1)? == 1;
+        match r.bits(2)? {
+            0 => {
+                r.align_to_byte();
+                let header = data.get(r.pos..r.pos + 4).ok_or("truncated stored block")?;
+                let length = u16::from_le_bytes([header[0], header[1]]);
+                let n_length = u16::from_le_bytes([header[2], header[3]]);
+                if length != !n_length {
+                    return Err("stored block length mismatch".to_string());
+                }
+                r.pos += 4;
+                let block = data
+                    .get(r.pos..r.pos + length as usize)
+                    .ok_or("truncated stored block")?;
+                out.extend_from_slice(block);
+                r.pos += length as usize;
+            }
+            block_type @ 1..=2 => {
+                let (literal, distance) = if block_type == 1 {
+                    fixed_huffman()
+                } else {
+                    dynamic_huffman(&mut r)?
+                };
+                loop {
+                    let symbol = literal.decode(&mut r)? as usize;
+                    if symbol < 256 {
+                        out.push(symbol as u8);
+                    } else if symbol == 256 {
+                        break;
+                    } else {
+                        let l = symbol - 257;
+                        if l >= LENGTH_BASE.len() {
+                            return Err("invalid length symbol".to_string());
+                        }
+                        let length = LENGTH_BASE[l] as usize + r.bits(LENGTH_EXTRA[l] as u32)? as usize;
+                        let d = distance.decode(&mut r)? as usize;
+                        if d >= DIST_BASE.len() {
+                            return Err("invalid distance symbol".to_string());
+                        }
+                        let dist = DIST_BASE[d] as usize + r.bits(DIST_EXTRA[d] as u32)? as usize;
+                        if dist > out.len() {
+                            return Err("distance too far back".to_string());
+                        }
+                        // copies may overlap the bytes they produce
+                        let start = out.len() - dist;
+                        for k in 0..length {
+                            out.push(out[start + k]);
+                        }
+                    }
+                }
+            }
+            _ => return Err("invalid deflate block type".to_string()),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+pub fn zlib_decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < 6 {
+        return Err("zlib stream too short".to_string());
+    }
+    let (cmf, flg) = (data[0], data[1]);
+    if cmf & 0x0f != 8 || ((cmf as u16) << 8 | flg as u16) % 31 != 0 {
+        return Err("invalid zlib header".to_string());
+    }
+    if flg & 0x20 != 0 {
+        return Err("zlib preset dictionaries are not supported".to_string());
+    }
+    let out = inflate(&data[2..data.len() - 4])?;
+    let n = data.len();
+    let checksum = u32::from_be_bytes([data[n - 4], data[n - 3], data[n - 2], data[n - 1]]);
+    if checksum != adler32(&out) {
+        return Err("zlib checksum mismatch".to_string());
+    }
+    Ok(out)
+}
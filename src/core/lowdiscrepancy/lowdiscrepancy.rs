@@ -0,0 +1,415 @@
+use crate::core::geometry::Point2f;
+use crate::core::lowdiscrepancy::{N_SOBOL_DIMENSIONS, SOBOL_MATRICES_32, SOBOL_MATRIX_SIZE};
+use crate::core::pbrt::{mix_bits, ONE_MINUS_EPSILON};
+use crate::core::rng::Rng;
+
+pub const PRIME_TABLE_SIZE: usize = 1000;
+
+const fn compute_primes() -> [u64; PRIME_TABLE_SIZE] {
+    let mut primes = [0u64; PRIME_TABLE_SIZE];
+    let mut n = 0;
+    let mut candidate = 2;
+    while n < PRIME_TABLE_SIZE {
+        let mut is_prime = true;
+        let mut i = 0;
+        while i < n && primes[i] * primes[i] <= candidate {
+            if candidate % primes[i] == 0 {
+                is_prime = false;
+                break;
+            }
+            i += 1;
+        }
+        if is_prime {
+            primes[n] = candidate;
+            n += 1;
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+const fn compute_prime_sums() -> [u64; PRIME_TABLE_SIZE] {
+    let mut sums = [0u64; PRIME_TABLE_SIZE];
+    let mut i = 1;
+    while i < PRIME_TABLE_SIZE {
+        sums[i] = sums[i - 1] + PRIMES[i - 1];
+        i += 1;
+    }
+    sums
+}
+
+// the first 1000 primes, the bases of the Halton dimensions
+pub const PRIMES: [u64; PRIME_TABLE_SIZE] = compute_primes();
+// sum of the primes before each one, the offset of its digit permutation
+pub const PRIME_SUMS: [u64; PRIME_TABLE_SIZE] = compute_prime_sums();
+
+// mirrors the digits of a in base PRIMES[base_index] around the radix point
+pub fn radical_inverse(base_index: usize, a: u64) -> f32 {
+    if base_index == 0 {
+        // base 2 is a bit reversal
+        return (a.reverse_bits() as f64 * (1.0 / 18_446_744_073_709_551_616.0)).min(ONE_MINUS_EPSILON as f64) as f32;
+    }
+    let base = PRIMES[base_index];
+    let inv_base = 1.0 / base as f64;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_n = 1.0;
+    let mut a = a;
+    while a != 0 {
+        let next = a / base;
+        let digit = a - next * base;
+        reversed_digits = reversed_digits * base + digit;
+        inv_base_n *= inv_base;
+        a = next;
+    }
+    (reversed_digits as f64 * inv_base_n).min(ONE_MINUS_EPSILON as f64) as f32
+}
+
+// undoes the digit reversal for an n_digits radical inverse given as the
+// integer of its digits
+pub fn inverse_radical_inverse(base: u64, mut inverse: u64, n_digits: u32) -> u64 {
+    let mut index = 0;
+    for _ in 0..n_digits {
+        let digit = inverse % base;
+        inverse /= base;
+        index = index * base + digit;
+    }
+    index
+}
+
+// a random permutation of the digits of every prime base, each starting at
+// PRIME_SUMS of its base index
+pub fn compute_radical_inverse_permutations(rng: &mut Rng) -> Vec<u16> {
+    let size = (PRIME_SUMS[PRIME_TABLE_SIZE - 1] + PRIMES[PRIME_TABLE_SIZE - 1]) as usize;
+    let mut perms = Vec::with_capacity(size);
+    for &base in PRIMES.iter() {
+        let start = perms.len();
+        perms.extend(0..base as u16);
+        let perm = &mut perms[start..];
+        for i in 0..perm.len() {
+            let other = i + rng.uniform_u32_bounded((perm.len() - i) as u32) as usize;
+            perm.swap(i, other);
+        }
+    }
+    perms
+}
+
+// radical inverse with every digit mapped through perm. the infinite tail
+// of zero digits becomes perm[0] repeated, which adds a geometric series.
+pub fn scrambled_radical_inverse(base_index: usize, a: u64, perm: &[u16]) -> f32 {
+    let base = PRIMES[base_index];
+    let inv_base = 1.0 / base as f64;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_n = 1.0;
+    let mut a = a;
+    while a != 0 {
+        let next = a / base;
+        let digit = a - next * base;
+        reversed_digits = reversed_digits * base + perm[digit as usize] as u64;
+        inv_base_n *= inv_base;
+        a = next;
+    }
+    let tail = inv_base * perm[0] as f64 / (1.0 - inv_base);
+    (inv_base_n * (reversed_digits as f64 + tail)).min(ONE_MINUS_EPSILON as f64) as f32
+}
+
+// radical inverse with Owen scrambling: each digit is permuted by a
+// permutation picked by hashing the digits before it
+pub fn owen_scrambled_radical_inverse(base_index: usize, a: u64, hash: u64) -> f32 {
+    let base = PRIMES[base_index];
+    let inv_base = 1.0 / base as f64;
+    let limit = u64::MAX / base - base;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0;
+    let mut a = a;
+    // leading zero digits of a are scrambled too, up to the precision of the
+    // result
+    while 1.0 - (base - 1) as f64 * inv_base_m < 1.0 && reversed_digits < limit {
+        let next = a / base;
+        let digit = a - next * base;
+        let digit_hash = mix_bits(hash ^ reversed_digits) as u32;
+        let digit = permutation_element(digit as u32, base as u32, digit_hash) as u64;
+        reversed_digits = reversed_digits * base + digit;
+        inv_base_m *= inv_base;
+        a = next;
+    }
+    (inv_base_m * reversed_digits as f64).min(ONE_MINUS_EPSILON as f64) as f32
+}
+
+// element i of a random permutation of 0..l selected by p, without storing
+// the permutation (Kensler, correlated multi-jittered sampling)
+pub fn permutation_element(mut i: u32, l: u32, p: u32) -> u32 {
+    let mut w = l - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170_893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929_eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | p >> 27);
+        i = i.wrapping_mul(0x6935_fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dc_b303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e50_1cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860_a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < l {
+            break;
+        }
+    }
+    (i.wrapping_add(p)) % l
+}
+
+// x with a * x = 1 mod n, for a and n coprime
+pub fn multiplicative_inverse(a: i64, n: i64) -> u64 {
+    let (x, _) = extended_gcd(a as u64, n as u64);
+    x.rem_euclid(n) as u64
+}
+
+fn extended_gcd(a: u64, b: u64) -> (i64, i64) {
+    if b == 0 {
+        return (1, 0);
+    }
+    let d = (a / b) as i64;
+    let (xp, yp) = extended_gcd(b, a % b);
+    (yp, xp - d * yp)
+}
+
+// randomization applied to the 32 bits of a Sobol sample
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SobolScrambler {
+    None,
+    // random digit scrambling, the bits are flipped by a fixed random mask
+    BinaryPermute(u32),
+    // Laine-Karras style hash, cheaper than Owen scrambling with a similar
+    // effect. every bit only depends on the bits above it.
+    FastOwen(u32),
+    // every bit flipped depending on a hash of the bits above it
+    Owen(u32),
+}
+
+impl SobolScrambler {
+    pub fn scramble(&self, v: u32) -> u32 {
+        match *self {
+            SobolScrambler::None => v,
+            SobolScrambler::BinaryPermute(permutation) => v ^ permutation,
+            SobolScrambler::FastOwen(seed) => {
+                let mut v = v.reverse_bits();
+                v ^= v.wrapping_mul(0x3d20_adea);
+                v = v.wrapping_add(seed);
+                v = v.wrapping_mul((seed >> 16) | 1);
+                v ^= v.wrapping_mul(0x0552_6c56);
+                v ^= v.wrapping_mul(0x53a2_2864);
+                v.reverse_bits()
+            }
+            SobolScrambler::Owen(seed) => {
+                let mut v = v;
+                if seed & 1 != 0 {
+                    v ^= 1 << 31;
+                }
+                for b in 1..32 {
+                    let mask = !0u32 << (32 - b);
+                    if (mix_bits(((v & mask) ^ seed) as u64) as u32) & (1 << b) != 0 {
+                        v ^= 1 << (31 - b);
+                    }
+                }
+                v
+            }
+        }
+    }
+}
+
+// raw 32 bits of sample a of a Sobol dimension, the product of its generator
+// matrix and the bits of a
+pub fn sobol_sample_bits(a: u64, dimension: usize) -> u32 {
+    assert!(dimension < N_SOBOL_DIMENSIONS);
+    let mut v = 0;
+    let mut a = a;
+    let mut i = dimension * SOBOL_MATRIX_SIZE;
+    while a != 0 {
+        if a & 1 != 0 {
+            v ^= SOBOL_MATRICES_32[i];
+        }
+        a >>= 1;
+        i += 1;
+    }
+    v
+}
+
+pub fn sobol_sample(a: u64, dimension: usize, scrambler: SobolScrambler) -> f32 {
+    let v = scrambler.scramble(sobol_sample_bits(a, dimension));
+    (v as f32 * (1.0 / 4_294_967_296.0)).min(ONE_MINUS_EPSILON)
+}
+
+// occupied elementary intervals of [0, 1)^2 for 2^k points, cells given by
+// their coordinates on the 2^k by 2^k grid
+struct ElementaryIntervals {
+    k: u32,
+    occupied: Vec<Vec<bool>>,
+}
+
+impl ElementaryIntervals {
+    fn new(k: u32) -> Self {
+        Self {
+            k,
+            occupied: (0..=k).map(|_| vec![false; 1 << k]).collect(),
+        }
+    }
+
+    // interval with 2^a columns and 2^(k - a) rows containing the cell
+    fn index(&self, a: u32, cx: usize, cy: usize) -> usize {
+        ((cy >> a) << a) | (cx >> (self.k - a))
+    }
+
+    fn is_free(&self, cx: usize, cy: usize) -> bool {
+        (0..=self.k).all(|a| !self.occupied[a as usize][self.index(a, cx, cy)])
+    }
+
+    fn occupy(&mut self, cx: usize, cy: usize) {
+        for a in 0..=self.k {
+            let i = self.index(a, cx, cy);
+            self.occupied[a as usize][i] = true;
+        }
+    }
+
+    fn cell(&self, p: &Point2f) -> (usize, usize) {
+        let n = (1 << self.k) as f32;
+        ((p.x * n) as usize, (p.y * n) as usize)
+    }
+}
+
+fn toroidal_distance_squared(a: &Point2f, b: &Point2f) -> f32 {
+    let dx = (a.x - b.x).abs();
+    let dy = (a.y - b.y).abs();
+    let (dx, dy) = (dx.min(1.0 - dx), dy.min(1.0 - dy));
+    dx * dx + dy * dy
+}
+
+// doubles the points of a pmj02 sequence. each new point goes into a free
+// subquadrant of the cell holding an earlier point and into a free interval
+// of every elementary shape. None when the random choices hit a dead end.
+fn extend_pmj02(samples: &[Point2f], blue_noise: bool, rng: &mut Rng) -> Option<Vec<Point2f>> {
+    let n = samples.len();
+    let k = (2 * n).trailing_zeros();
+    // side of the grid with one point per cell, before the even steps
+    let m = 1usize << (n.trailing_zeros() / 2);
+    let mut strata = ElementaryIntervals::new(k);
+    for p in samples.iter() {
+        let (cx, cy) = strata.cell(p);
+        strata.occupy(cx, cy);
+    }
+
+    // subquadrant of its grid cell that each new point has to go into
+    let subquadrant = |p: &Point2f| {
+        let (x, y) = (p.x * m as f32, p.y * m as f32);
+        let (i, j) = (x as usize, y as usize);
+        (i, j, ((x - i as f32) * 2.0) as usize, ((y - j as f32) * 2.0) as usize)
+    };
+    let mut targets = Vec::with_capacity(n);
+    if n.trailing_zeros() % 2 == 0 {
+        // diagonally opposite subquadrant
+        for p in samples.iter() {
+            let (i, j, xh, yh) = subquadrant(p);
+            targets.push((i, j, 1 - xh, 1 - yh));
+        }
+    } else {
+        // the two subquadrants left, in random order
+        let first: Vec<_> = samples[..n / 2]
+            .iter()
+            .map(|p| {
+                let (i, j, xh, yh) = subquadrant(p);
+                if rng.uniform_u32() & 1 == 0 {
+                    (i, j, 1 - xh, yh)
+                } else {
+                    (i, j, xh, 1 - yh)
+                }
+            })
+            .collect();
+        targets.extend(first.iter().copied());
+        targets.extend(first.iter().map(|&(i, j, xh, yh)| (i, j, 1 - xh, 1 - yh)));
+    }
+
+    let fine = 1usize << k;
+    // fine cells per subquadrant side
+    let width = fine / (2 * m);
+    let mut new_samples: Vec<Point2f> = Vec::with_capacity(n);
+    let mut free = Vec::new();
+    for &(i, j, xh, yh) in targets.iter() {
+        let (x0, y0) = ((2 * i + xh) * width, (2 * j + yh) * width);
+        free.clear();
+        for cy in y0..y0 + width {
+            for cx in x0..x0 + width {
+                if strata.is_free(cx, cy) {
+                    free.push((cx, cy));
+                }
+            }
+        }
+        if free.is_empty() {
+            return None;
+        }
+        let candidate = |rng: &mut Rng| {
+            let (cx, cy) = free[rng.uniform_u32_bounded(free.len() as u32) as usize];
+            Point2f::new(
+                ((cx as f32 + rng.uniform_f32()) / fine as f32).min(ONE_MINUS_EPSILON),
+                ((cy as f32 + rng.uniform_f32()) / fine as f32).min(ONE_MINUS_EPSILON),
+            )
+        };
+        let p = if blue_noise {
+            // best candidate, farthest from the points placed so far
+            let mut best = (candidate(rng), -1.0);
+            for _ in 0..8 {
+                let c = candidate(rng);
+                let d = samples
+                    .iter()
+                    .chain(new_samples.iter())
+                    .map(|q| toroidal_distance_squared(&c, q))
+                    .fold(f32::INFINITY, f32::min);
+                if d > best.1 {
+                    best = (c, d);
+                }
+            }
+            best.0
+        } else {
+            candidate(rng)
+        };
+        let (cx, cy) = strata.cell(&p);
+        strata.occupy(cx, cy);
+        new_samples.push(p);
+    }
+    Some(new_samples)
+}
+
+// progressive multi-jittered (0, 2) sequence of Christensen et al.: every
+// prefix of 2^k points has one point in each elementary interval of area
+// 2^-k. with blue_noise the points are placed by best candidate sampling.
+pub fn generate_pmj02(n_samples: usize, blue_noise: bool, rng: &mut Rng) -> Vec<Point2f> {
+    assert!(n_samples.is_power_of_two());
+    'restart: loop {
+        let mut samples = vec![Point2f::new(rng.uniform_f32(), rng.uniform_f32())];
+        while samples.len() < n_samples {
+            let mut attempts = 0;
+            let new_samples = loop {
+                if let Some(new_samples) = extend_pmj02(&samples, blue_noise, rng) {
+                    break new_samples;
+                }
+                // earlier points may leave no way forward
+                attempts += 1;
+                if attempts == 16 {
+                    continue 'restart;
+                }
+            };
+            samples.extend(new_samples);
+        }
+        return samples;
+    }
+}
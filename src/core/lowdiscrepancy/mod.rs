@@ -0,0 +1,5 @@
+pub use lowdiscrepancy::*;
+pub use sobolmatrices::*;
+
+mod lowdiscrepancy;
+mod sobolmatrices;
@@ -0,0 +1,7183 @@
+// generator matrices of the first 1024 Sobol dimensions, SOBOL_MATRIX_SIZE
+// columns each with the most significant bit first. dimension 0 is the van
+// der Corput sequence, dimension i > 0 uses the i-th primitive polynomial over
+// GF(2) in order of degree and coefficients, as Joe and Kuo's
+// new-joe-kuo-6.21201 does. dimensions 1 to 20 use its initial direction
+// numbers, the higher ones still use odd initial direction numbers drawn
+// from a fixed 64-bit LCG until the table is regenerated from the full file
+// with tools/sobolmatrices.py.
+
+pub const N_SOBOL_DIMENSIONS: usize = 1024;
+pub const SOBOL_MATRIX_SIZE: usize = 52;
+
+#[rustfmt::skip]
+pub static SOBOL_MATRICES_32: [u32; N_SOBOL_DIMENSIONS * SOBOL_MATRIX_SIZE] = [
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x08000000, 0x04000000, 0x02000000, 0x01000000,
+    0x00800000, 0x00400000, 0x00200000, 0x00100000, 0x00080000, 0x00040000, 0x00020000, 0x00010000,
+    0x00008000, 0x00004000, 0x00002000, 0x00001000, 0x00000800, 0x00000400, 0x00000200, 0x00000100,
+    0x00000080, 0x00000040, 0x00000020, 0x00000010, 0x00000008, 0x00000004, 0x00000002, 0x00000001,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000, 0xff000000,
+    0x80800000, 0xc0c00000, 0xa0a00000, 0xf0f00000, 0x88880000, 0xcccc0000, 0xaaaa0000, 0xffff0000,
+    0x80008000, 0xc000c000, 0xa000a000, 0xf000f000, 0x88008800, 0xcc00cc00, 0xaa00aa00, 0xff00ff00,
+    0x80808080, 0xc0c0c0c0, 0xa0a0a0a0, 0xf0f0f0f0, 0x88888888, 0xcccccccc, 0xaaaaaaaa, 0xffffffff,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000, 0xff000000,
+    0x80800000, 0xc0c00000, 0xa0a00000, 0xf0f00000, 0x88880000, 0xcccc0000, 0xaaaa0000, 0xffff0000,
+    0x80008000, 0xc000c000, 0xa000a000, 0xf000f000,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0x8e000000, 0xc5000000,
+    0x68800000, 0x9cc00000, 0xee600000, 0x55900000, 0x80680000, 0xc09c0000, 0x60ee0000, 0x90550000,
+    0xe8808000, 0x5cc0c000, 0x8e606000, 0xc5909000, 0x6868e800, 0x9c9c5c00, 0xeeee8e00, 0x5555c500,
+    0x8000e880, 0xc0005cc0, 0x60008e60, 0x9000c590, 0xe8006868, 0x5c009c9c, 0x8e00eeee, 0xc5005555,
+    0x68808000, 0x9cc0c000, 0xee606000, 0x55909000, 0x8068e800, 0xc09c5c00, 0x60ee8e00, 0x9055c500,
+    0xe880e880, 0x5cc05cc0, 0x8e608e60, 0xc590c590, 0x68686868, 0x9c9c9c9c, 0xeeeeeeee, 0x55555555,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xf8000000, 0x74000000, 0xa2000000, 0x93000000,
+    0xd8800000, 0x25400000, 0x59e00000, 0xe6d00000, 0x78080000, 0xb40c0000, 0x82020000, 0xc3050000,
+    0x208f8000, 0x51474000, 0xfbea2000, 0x75d93000, 0xa0858800, 0x914e5400, 0xdbe79e00, 0x25db6d00,
+    0x58800080, 0xe54000c0, 0x79e00020, 0xb6d00050, 0x800800f8, 0xc00c0074, 0x200200a2, 0x50050093,
+    0xf80f80d8, 0x74074025, 0xa20a2059, 0x930930e6, 0xd88d8878, 0x254254b4, 0x59e59e82, 0xe6de6dc3,
+    0x780f80a0, 0xb4074091, 0x820a20db, 0xc3093025, 0x208d8858, 0x514254e5, 0xfbe59e79, 0x75de6db6,
+    0xa08f8000, 0x91474000, 0xdbea2000, 0x25d93000,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xf8000000, 0xdc000000, 0x7a000000, 0x9d000000,
+    0x5a800000, 0x2fc00000, 0xa1600000, 0xf0b00000, 0xda880000, 0x6fc40000, 0x81620000, 0x40bb0000,
+    0x22878000, 0xb3c9c000, 0xfb65a000, 0xddb2d000, 0x78022800, 0x9c0b3c00, 0x5a0fb600, 0x2d0ddb00,
+    0xa2878080, 0xf3c9c040, 0xdb65a020, 0x6db2d0b0, 0x800228f8, 0x400b3cdc, 0x200fb67a, 0xb00ddb9d,
+    0xf80780da, 0xdc09c06f, 0x7a05a081, 0x9d02d040, 0x5a8a2822, 0x2fcf3cb3, 0xa16db6fb, 0xf0b6dbdd,
+    0xda8000f8, 0x6fc000dc, 0x8160007a, 0x40b0009d, 0x2288005a, 0xb3c4002f, 0xfb6200a1, 0xddbb00f0,
+    0x780780da, 0x9c09c06f, 0x5a05a081, 0x2d02d040,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xc8000000, 0x24000000, 0x56000000, 0xfb000000,
+    0xe0800000, 0x70400000, 0xa8600000, 0x14300000, 0x9ec80000, 0xdf240000, 0xb6d60000, 0x8bbb0000,
+    0x48008000, 0x64004000, 0x36006000, 0xcb003000, 0x2880c800, 0x54402400, 0xfe605600, 0xef30fb00,
+    0x7e48e080, 0xaf647040, 0x1eb6a860, 0x9f8b1430, 0xd6c81ec8, 0xbb249f24, 0x80d6d6d6, 0x40bbbbbb,
+    0x60800000, 0x30400000, 0xc8600000, 0x24300000, 0x56c80000, 0xfb240000, 0xe0d60000, 0x70bb0000,
+    0xa8808000, 0x14404000, 0x9e606000, 0xdf303000, 0xb648c800, 0x8b642400, 0x48b65600, 0x648bfb00,
+    0x36486080, 0xcb643040, 0x28b6c860, 0x548b2430,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x58000000, 0x94000000, 0x3e000000, 0xe3000000,
+    0xbe800000, 0x23c00000, 0x1e200000, 0xf3100000, 0x46780000, 0x67840000, 0x78460000, 0x84670000,
+    0xc6788000, 0xa784c000, 0xd846a000, 0x5467d000, 0x9e78d800, 0x33845400, 0xe6469e00, 0xb7673300,
+    0x20f86680, 0x104477c0, 0xf8668020, 0x4477c010, 0x668020f8, 0x77c01044, 0x8020f866, 0xc0104477,
+    0xa0f86680, 0xd04477c0, 0x58668020, 0x9477c010, 0x3e8020f8, 0xe3c01044, 0xbe20f866, 0x23104477,
+    0x1e786680, 0xf38477c0, 0x46468020, 0x6767c010, 0x78f820f8, 0x84441044, 0xc666f866, 0xa7774477,
+    0xd800e680, 0x5400b7c0, 0x9e002020, 0x33001010,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x88000000, 0x24000000, 0x12000000, 0x2d000000,
+    0x76800000, 0x9e400000, 0x08200000, 0x64100000, 0xb2280000, 0x7d140000, 0xfea20000, 0xba490000,
+    0x1a248000, 0x491b4000, 0xc4b5a000, 0xe3739000, 0xf6800800, 0xde400400, 0xa8200a00, 0x34100500,
+    0x3a280880, 0x59140240, 0xeca20120, 0x974902d0, 0x6ca48768, 0xd75b49e4, 0xcc95a082, 0x87639641,
+    0x44a80322, 0xa35403d1, 0x568205ea, 0x8e590ea4, 0x200c8922, 0x100f46d1, 0x2817ad6b, 0x743a9ce7,
+    0x9a248000, 0x091b4000, 0x64b5a000, 0xb3739000, 0x7e800800, 0xfa400400, 0xba200a00, 0x19100500,
+    0x4ca80880, 0xc7540240, 0xe4820120, 0xf35902d0,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x28000000, 0xd4000000, 0x6a000000, 0x71000000,
+    0x38800000, 0x58400000, 0xea200000, 0x31100000, 0x98a80000, 0x08540000, 0xc22a0000, 0xe5250000,
+    0xf2b28000, 0x79484000, 0xfaa42000, 0xbd731000, 0x18a80800, 0x48540400, 0x622a0a00, 0xb5250500,
+    0xdab28280, 0xad484d40, 0x90a426a0, 0xcc731710, 0x20280b88, 0x10140184, 0x880a04a2, 0x84350611,
+    0x421a8b0a, 0xa51c4dc5, 0x528e2a82, 0x29561942, 0xd29a84a3, 0x695c4610, 0x72ae2b08, 0x39461dc6,
+    0x5ab28280, 0xed484d40, 0x30a426a0, 0x9c731710, 0x08280b88, 0xc4140184, 0xe20a04a2, 0xf5350611,
+    0x7a9a8b0a, 0xfd5c4dc5, 0xb8ae2a82, 0x18461942,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x98000000, 0x94000000, 0x8a000000, 0x5b000000,
+    0x33800000, 0xd9c00000, 0x72200000, 0x3f100000, 0xc1b80000, 0xa6ec0000, 0x53860000, 0x29f50000,
+    0x0a3a8000, 0x1b2ac000, 0xd392e000, 0x69ff7000, 0xea380800, 0xab2c0400, 0x4ba60e00, 0xfde50b00,
+    0x60028980, 0xf006c940, 0x7834e8a0, 0x241a75b0, 0x123a8b38, 0xcf2ac99c, 0xb992e922, 0x82ff78f1,
+    0x41b80d9b, 0xe6ec072e, 0xb3860398, 0x99f50c2f, 0x923a8a1b, 0x8f2ac56e, 0x5992e2bb, 0x32ff70de,
+    0xd9b80980, 0x72ec0940, 0x398608a0, 0xc2f505b0, 0xa1ba8338, 0x56eacd9c, 0x2bb2e722, 0x0def73f1,
+    0x1800041b, 0xd4000e6e, 0x6a000b38, 0xeb00099f,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x08000000, 0x6c000000, 0x9e000000, 0x23000000,
+    0x57800000, 0xadc00000, 0x7fa00000, 0x91d00000, 0x49880000, 0xced40000, 0x880a0000, 0x2c0f0000,
+    0x3e0d8000, 0x3317c000, 0x5fb06000, 0xc1f8b000, 0xe18d8800, 0xb2d7c400, 0x1e106a00, 0x6328b100,
+    0xf7858880, 0xbdc3c2c0, 0x77ba63e0, 0xfdf7b330, 0xd7800df8, 0xedc0081c, 0xdfa0041a, 0x81d00a2d,
+    0x41880160, 0xa2d400f1, 0x160a069a, 0x0f0f09ed, 0x698d8200, 0x9ed7c500, 0x20106a81, 0x5028b7c2,
+    0xa8058160, 0x7c03c0f1, 0x961a669a, 0x4f27b9ed, 0xc9880a00, 0x8ed40100, 0x280a0081, 0x3c0f06c2,
+    0x360d89e0, 0x5f17c231, 0xc1b0657a, 0xe2f8badd,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x58000000, 0xac000000, 0x96000000, 0x2b000000,
+    0xd4800000, 0x09400000, 0xe2a00000, 0x52500000, 0x4e280000, 0xc71c0000, 0x629e0000, 0x12670000,
+    0x6e138000, 0xf731c000, 0x3a98a000, 0xbe449000, 0xf83b8800, 0xdc2dc400, 0xee06a200, 0xb7239300,
+    0x1aa80d80, 0x8e5c0ec0, 0xa03e0b60, 0x703701b0, 0x783b88c8, 0x9c2dca54, 0xce06a74a, 0x87239795,
+    0x42a801aa, 0x225c08e5, 0x363e0a03, 0x5b370703, 0xacbb8783, 0x956dc9c2, 0x2ca6ace0, 0xd5739872,
+    0x0c800c2a, 0xe5400625, 0x54a00163, 0x495006b3, 0xc2a80f4b, 0x625c0396, 0x163e0baa, 0x6b370fe7,
+    0xf4bb8d80, 0x396dcec0, 0xbaa6ab60, 0xfe7391b0,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xf8000000, 0x8c000000, 0xe2000000, 0x33000000,
+    0x0f800000, 0x21400000, 0x95a00000, 0x5e700000, 0xd8080000, 0x1c240000, 0xba160000, 0xef370000,
+    0x15868000, 0x9e6fc000, 0x781b6000, 0x4c349000, 0x420e8800, 0x630bcc00, 0xf7ad6a00, 0xad739500,
+    0x77800780, 0x6d4004c0, 0xd7a00420, 0x3d700630, 0x2f880f78, 0xb1640ad4, 0xcdb6077a, 0x824706d7,
+    0xc20e8d78, 0xa30bc3d6, 0x57ad62fb, 0xfd739b14, 0x8f8004d8, 0xe1400424, 0x35a00620, 0x0e700f30,
+    0x20080af8, 0x90240716, 0x581606db, 0xdc370d24, 0x1a0683a0, 0xbf2fc2f0, 0xedbb6b5a, 0x12449ce7,
+    0x9a068000, 0x7f2fc000, 0x4dbb6000, 0x42449000,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x38000000, 0xc4000000, 0x42000000, 0xa3000000,
+    0xf1800000, 0xaa400000, 0xfce00000, 0x85100000, 0xe0080000, 0x500c0000, 0x58060000, 0x54090000,
+    0x7a038000, 0x670c4000, 0xb3842000, 0x094a3000, 0x0d6f1800, 0x2f5aa400, 0x1ce7ce00, 0xd5145100,
+    0xb8000080, 0x040000c0, 0x22000060, 0x33000090, 0xc9800038, 0x6e4000c4, 0xbee00042, 0x261000a3,
+    0x118800f1, 0xfa4c00aa, 0xa4e600fc, 0xd1190085, 0x9a0b80e0, 0x37004050, 0xeb822058, 0x5d433054,
+    0x776c987a, 0x4856e467, 0xaf63eeb3, 0xdc5e6109, 0xb56f188d, 0x2b5aa4ef, 0x3ee7ce7c, 0xe6145145,
+    0x71800000, 0x6a400000, 0x9ce00000, 0x15100000,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0xa8000000, 0x54000000, 0x9a000000, 0x9d000000,
+    0x1e800000, 0x5cc00000, 0x7d200000, 0x8d100000, 0x24880000, 0x71c40000, 0xeba20000, 0x75df0000,
+    0x6ba28000, 0x35d14000, 0x4ba3a000, 0xc5d2d000, 0xe3a16800, 0x91db8c00, 0x79aef200, 0x0cdf4100,
+    0x672a8080, 0x50154040, 0x1a01a020, 0xdd0dd0f0, 0x3e83e8a8, 0xaccacc54, 0xd52d529a, 0xd91d919d,
+    0xbe83e89e, 0xeccacc1c, 0xf52d525d, 0x291d917d, 0x1683e80c, 0xb8cacc65, 0x6f2d5251, 0xb41d9118,
+    0x0803e85d, 0xe40acc7d, 0x120d528c, 0x390d9125, 0x2c8be8f1, 0x95cecca8, 0xf9af5255, 0x4cd29199,
+    0x4729681e, 0xa01f8c5c, 0xb20cf27d, 0x8900418d,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xd8000000, 0xc4000000, 0x46000000, 0x85000000,
+    0xa5800000, 0x76c00000, 0xada00000, 0x6ab00000, 0x2da80000, 0xaabc0000, 0x0daa0000, 0x7ab10000,
+    0xd5a78000, 0xbebd4000, 0x93a3e000, 0x3bb51000, 0x3629b800, 0x4d727c00, 0x9b836200, 0x27c4d700,
+    0xb629b880, 0x8d727cc0, 0xbb836220, 0xf7c4d7d0, 0x6e29b858, 0x49727c04, 0xfd836266, 0x72c4d755,
+    0xcba9b8fd, 0x3fb27c72, 0x502362cb, 0x1874d73f, 0xe601b8d0, 0x950e7cd8, 0x5d8962c6, 0x62c5d745,
+    0x33a63805, 0x2bb33c66, 0xce2a8255, 0x5970c77e, 0x058f8033, 0x66c1402b, 0x55a9e0ce, 0x7eb41059,
+    0xb3a63805, 0xebb33c66, 0xee2a8255, 0x8970c77e,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x38000000, 0x14000000, 0xf6000000, 0x67000000,
+    0x8f800000, 0x50400000, 0x8aa00000, 0x0ff00000, 0x12a80000, 0xabf40000, 0xfcaa0000, 0x28fb0000,
+    0xbd298000, 0x0bba4000, 0x4e06e000, 0x330c3000, 0x59861800, 0xc74d3400, 0x3d2cb200, 0x4bb2cb00,
+    0x6e061880, 0xc30d3440, 0x618cb220, 0xd342cbf0, 0xcb2e18b8, 0x2cb93454, 0xe186b2d6, 0x9349cb97,
+    0xeb2f9837, 0xdcb77404, 0xd98a525c, 0x874efb98, 0x1d280025, 0xbbb400af, 0x560a00a0, 0xd70b00b0,
+    0x97818018, 0xb44e40e4, 0x44ace0ce, 0x7cf73073, 0x6b2f9879, 0x9cb77437, 0xf98a5205, 0x774efb5f,
+    0x25280018, 0xafb400e4, 0xa00a00ce, 0xb00b0073,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x68000000, 0x64000000, 0x36000000, 0x6d000000,
+    0x41800000, 0xe0400000, 0xd2e00000, 0x9bf00000, 0x0ce80000, 0x52fc0000, 0x5b6a0000, 0x2fb30000,
+    0xa00c8000, 0x30054000, 0x4807e000, 0x940f9000, 0x5e01f800, 0x090e9400, 0x778a5600, 0x8d416b00,
+    0x9369f880, 0x7bb294c0, 0xde005620, 0xc9026bf0, 0x578d78e8, 0x7d4bd4a4, 0xfb6db616, 0x1fbefb9d,
+    0xe80000a9, 0xa4000044, 0x160000c4, 0x9d000006, 0x29800025, 0x844000d6, 0xe4e000bf, 0xf6f000d9,
+    0x4d6800ed, 0xb2bc0082, 0x898a00c1, 0xb4430020, 0xace480f2, 0x62f9406b, 0x136de064, 0xbbbc9036,
+    0xfe0d786d, 0x390bd442, 0x3f8db6e1, 0x194efbd0,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x98000000, 0xf4000000, 0xae000000, 0xbb000000,
+    0xe7800000, 0x95c00000, 0x1c200000, 0xd0300000, 0xdba80000, 0x55f40000, 0xff820000, 0x21c10000,
+    0x12238000, 0x3b3a4000, 0xa42b6000, 0x3430f000, 0x4da69800, 0x4af3ec00, 0x2e043a00, 0xfb0a1f00,
+    0x47851880, 0xc5c9ac40, 0x842f5aa0, 0x243aef50, 0x75a38018, 0xeefa40b4, 0x180b600e, 0xb400f0eb,
+    0x0e0e987f, 0xeb07ec61, 0x7f863ab2, 0x61cb1f6b, 0xb22698bc, 0x6b33ec80, 0x3c243a43, 0xc03a1fa1,
+    0xe3ad18d1, 0xf1fdacda, 0xc98d5a55, 0x6ecbeffe, 0x5ba800a0, 0x15f40050, 0x5f820098, 0x71c100f4,
+    0x8a2380ae, 0xcf3a40bb, 0x0a2b60e7, 0x8f30f095,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xb8000000, 0x3c000000, 0xce000000, 0x41000000,
+    0x21800000, 0x51c00000, 0x09600000, 0x85700000, 0xf2780000, 0x8e9c0000, 0x60020000, 0x70030000,
+    0x58038000, 0x8c02c000, 0x7602e000, 0x7d00f000, 0xef833800, 0x10c10400, 0x28e08600, 0xd4b14700,
+    0xfb182580, 0x0bee15c0, 0x9279c9e0, 0xfe9d3a70, 0x38000008, 0xfc00000c, 0x2e00000e, 0xf100000b,
+    0x9980000b, 0x6dc00003, 0xc760000c, 0xc4700004, 0xd3f80002, 0xdf5c0005, 0x69620000, 0xf5730008,
+    0xaa7b800f, 0x029ec008, 0x1600e006, 0x0d03f007, 0xb780b805, 0x9cc3c408, 0x5ee26607, 0xa9b1b707,
+    0x149b1d8e, 0x1b2f11c1, 0xba994fe2, 0x2a2c7d7d,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x68000000, 0x3c000000, 0x8a000000, 0x51000000,
+    0xa9800000, 0xddc00000, 0x5ba00000, 0x39d00000, 0x95f80000, 0x56d40000, 0x0a020000, 0x91030000,
+    0x49838000, 0x0dc34000, 0x33a1a000, 0x05d0f000, 0x1ffa2800, 0x07d54400, 0xa380a600, 0x4cc07700,
+    0x1222ee80, 0x3413a740, 0xa65bf7e0, 0x5305ab50, 0x15f80008, 0x96d4000c, 0xea02000e, 0x4103000d,
+    0x21838006, 0x31c34003, 0xb9a1a008, 0x54d0f005, 0xb67a280a, 0xda15440d, 0xf820a605, 0x75107703,
+    0x87daee89, 0x62c7a745, 0xac59f7e0, 0xc206ab59, 0x5c7b800c, 0x9b17400c, 0xd9a3a00d, 0x44d3f00d,
+    0x3e79a807, 0x36160403, 0x1a210602, 0x18108701,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xa8000000, 0x74000000, 0xf6000000, 0x31000000,
+    0x19800000, 0xadc00000, 0xbb200000, 0x1a500000, 0xaa680000, 0x72ac0000, 0x3e020000, 0x15030000,
+    0xa7838000, 0x78c24000, 0xfca2a000, 0xf291d000, 0xfecbd800, 0xf43cc400, 0x36ca6600, 0xd03db700,
+    0x88cb6c80, 0x053d2940, 0xcf4989a0, 0xedfd5ab0, 0x9be80008, 0x6b6c000c, 0x9322000e, 0xae530009,
+    0xbc6b800a, 0xd3ae4007, 0x8f80a00f, 0xccc2d003, 0xeaa05801, 0x5392840a, 0x4f4ac60b, 0x2dff6701,
+    0x7beb348a, 0xfb6fad47, 0x3b234fa3, 0xda523db1, 0x4a6b3482, 0xe2afad4b, 0x96034fa1, 0x61023db6,
+    0x5183348d, 0x49c3ad44, 0xe5214fa2, 0x5f513db7,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xd8000000, 0xcc000000, 0x2e000000, 0x59000000,
+    0x0d800000, 0xcfc00000, 0xa8e00000, 0xd4300000, 0x02580000, 0x86bc0000, 0x8d820000, 0x0fc30000,
+    0x48e38000, 0x2433c000, 0xda5b6000, 0x4abf3000, 0xa382b800, 0x56c26400, 0x4563b600, 0xebf0ff00,
+    0x72b9c380, 0x9e8c60c0, 0xa1dab160, 0xd07c7ef0, 0xc8e38008, 0xe433c00c, 0x3a5b600e, 0xbabf300f,
+    0x7b82b80d, 0x9ac2640c, 0x6b63b602, 0xb2f0ff05, 0x7f39c380, 0x514c60cc, 0x093ab16a, 0x044c7efd,
+    0xcabb8008, 0x628fc004, 0xb7d96006, 0xb57c300f, 0x33613809, 0xbef1a40e, 0xb138d60f, 0xf84fcf01,
+    0xdcbb7b8a, 0x078e04c9, 0x4c59076e, 0xefbc81f3,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x88000000, 0x04000000, 0xa2000000, 0x79000000,
+    0x9d800000, 0x8f400000, 0x0ea00000, 0xcf900000, 0x6fc80000, 0xbf240000, 0x37820000, 0x32430000,
+    0x91228000, 0xe9d34000, 0x74ea2000, 0xfbf41000, 0xf4e88800, 0x3bf6e400, 0x54eaf600, 0xebf67d00,
+    0xdcea9a80, 0xeff76e40, 0x7ee99720, 0x96f74890, 0xe36a8008, 0x19b7400c, 0xedc8200a, 0xd627100d,
+    0x82020808, 0x6901a400, 0xb582d60a, 0x5b416d07, 0x24a09289, 0xb292ca48, 0x50494120, 0x4965259c,
+    0xa4a0928e, 0x7292ca47, 0xf0494129, 0x9965259e, 0x2ca09289, 0x7692ca42, 0x52494127, 0xe0652595,
+    0xb1209286, 0xf9d2ca47, 0x5ce94125, 0x2ff52598,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xa8000000, 0x34000000, 0x3a000000, 0x95000000,
+    0x8a800000, 0xcdc00000, 0x87a00000, 0x6a900000, 0x1cc80000, 0x4f640000, 0xcfa20000, 0x8e910000,
+    0xeec98000, 0x7e664000, 0xd720a000, 0xe251d000, 0xd9e96800, 0x4c341400, 0xc6ca8a00, 0x0a66e700,
+    0x8d217680, 0xe750be40, 0xfb697920, 0xb5f49a90, 0x7b696808, 0xf5f41404, 0x1b6a8a06, 0x65f6e709,
+    0xb369768a, 0x51f4be43, 0x896b7923, 0xc4f59a99, 0x03e8e800, 0x09365408, 0x84482a0e, 0x63a6370f,
+    0x98819e8b, 0x2cc2ea47, 0x5723532f, 0xa253ad91, 0xb9e9768e, 0xdc34be4f, 0x6ecb7923, 0x3e659a91,
+    0xb720e80e, 0x72525407, 0x71ea2a05, 0x78373708,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x28000000, 0xa4000000, 0x1e000000, 0x55000000,
+    0x97800000, 0x21c00000, 0x10e00000, 0xf8900000, 0x3c280000, 0x926c0000, 0xeee20000, 0xdd930000,
+    0xe3a98000, 0xa7aec000, 0xc802a000, 0xd4019000, 0x5601f800, 0x41039400, 0xa182fe00, 0xd0c21700,
+    0x99603b80, 0x8c52b640, 0xbb4aaea0, 0x4b3eceb0, 0xc229f808, 0xb76f940c, 0x3160fe06, 0xe851170b,
+    0xc549bb82, 0xae3c764a, 0x7da80ea1, 0x32af5eb5, 0x3f800001, 0x45c0000e, 0x6ee00007, 0x1d900004,
+    0x83a80001, 0x17ac0003, 0xe002000f, 0x70030008, 0x48018007, 0x1402c008, 0x3600a00d, 0xf1029002,
+    0x89807806, 0x74c1540d, 0x87625e04, 0xd9508700,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xa8000000, 0x34000000, 0x42000000, 0xad000000,
+    0x1f800000, 0x7a400000, 0x41e00000, 0x61900000, 0x10480000, 0x18b40000, 0x9c620000, 0x76d30000,
+    0xee2a8000, 0xb366c000, 0x65c8a000, 0x3bf7d000, 0x20018800, 0x70037400, 0x08005e00, 0x8403f900,
+    0xea02af80, 0x9903e240, 0x5d813720, 0xd7433fd0, 0x5e630808, 0x1bd1b40c, 0x51aafe0a, 0x7927290b,
+    0x8c29a78a, 0x6e665643, 0x7249c924, 0xc5b716da, 0x8be02f89, 0x8891224b, 0x45cb972e, 0x4bf7efdd,
+    0x28000003, 0xf400000e, 0xe2000007, 0x1d000006, 0xb7800005, 0x4e40000f, 0x03e00006, 0xcc90000f,
+    0x0fc80002, 0x62f40001, 0xdd82000d, 0x17430009,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x18000000, 0xd4000000, 0x16000000, 0x9d000000,
+    0x0a800000, 0xd7400000, 0xfce00000, 0x9af00000, 0x7f880000, 0x30fc0000, 0x58620000, 0xf4b10000,
+    0xa7e88000, 0x844ec000, 0xdf8a6000, 0xc0fe5000, 0x6062d800, 0x90b1b400, 0xa9e8ca00, 0xcd4ecd00,
+    0xc309cb80, 0x8abe4fc0, 0x96030c20, 0xdd022af0, 0x2a825808, 0x67437404, 0xe4e0aa02, 0x4ef19d0b,
+    0x698b9381, 0xadfd3bcd, 0x52e3a621, 0x23f3b7f9, 0x5b09cb88, 0x1ebe4fc9, 0xa0030c2d, 0xf0022af2,
+    0x3802580e, 0x6403740a, 0x0e00aa06, 0x49019d0d, 0x1c83938b, 0x4a413bc8, 0xf661a623, 0x4db2b7fc,
+    0x83694b89, 0xaa0c8fc3, 0x27eb6c22, 0xc44d7af1,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0xa8000000, 0x44000000, 0x96000000, 0x17000000,
+    0x57800000, 0x77c00000, 0xe6e00000, 0x4fb00000, 0x0b380000, 0x9c140000, 0x8b3a0000, 0xdc150000,
+    0xab3a8000, 0x4c174000, 0x03382000, 0x08165000, 0x953a7800, 0x1f160c00, 0xc2bb2600, 0x68d7d300,
+    0x2458bd80, 0x2766edc0, 0x2f609160, 0xbb709d90, 0xa458bd88, 0x6766edc4, 0x0f609162, 0x2b709d99,
+    0x0c58bd82, 0x2366edc0, 0x9960916b, 0x3c709d98, 0x5bd8bd87, 0x54a6edc7, 0x7f809165, 0x73c09d9c,
+    0x50e0bd87, 0xc8b2edce, 0xf4ba916d, 0xafd59d91, 0xfbda3d8d, 0x84a5adca, 0xf782b16d, 0xa7c3cd91,
+    0x6ee04584, 0x9bb3a1cb, 0x35399761, 0xcf141e97,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x48000000, 0x4c000000, 0x3a000000, 0x2b000000,
+    0x02800000, 0xbfc00000, 0x9d600000, 0xb2f00000, 0xc6e80000, 0x685c0000, 0xdcea0000, 0xd35f0000,
+    0x36688000, 0x709e4000, 0xd909a000, 0xa56f7000, 0x27614800, 0x59f3dc00, 0x646b4200, 0x879e2300,
+    0x0989b780, 0x2daea8c0, 0xca810c20, 0x33c379b0, 0x0761b788, 0xc9f2a8cc, 0x8c6b0c2a, 0x9b9c79b5,
+    0x7b89378c, 0x4aace8c8, 0xf202ac29, 0xa70309b7, 0x98807f8c, 0xc4c334c3, 0xd7e3ee20, 0x41322abc,
+    0x61894800, 0xf1afdc05, 0x1881420d, 0x04c12301, 0x77e1378b, 0x1130e8ce, 0x2988ac2a, 0xbdac09be,
+    0x2280ff85, 0x2fc174c3, 0x75604e25, 0xaef25ab1,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x48000000, 0x3c000000, 0x42000000, 0xcb000000,
+    0xd7800000, 0xc5400000, 0x76200000, 0xd5300000, 0x6e080000, 0x91ac0000, 0x598a0000, 0x24ed0000,
+    0x07ab8000, 0xfdddc000, 0x63a2a000, 0x9b713000, 0xafa9a800, 0xb1df1c00, 0x09a17600, 0x5c71c900,
+    0x722a0e80, 0x839fadc0, 0xea001ea0, 0x8703f770, 0xbd818e88, 0x02426dc4, 0xaba2bea6, 0xe772c773,
+    0x8da8268c, 0x4add71c7, 0x9623c8a2, 0xa5330e7f, 0x460a2801, 0x9daedc0b, 0x5389d605, 0xd3edf902,
+    0x9228268f, 0xf39d71c6, 0xc203c8a6, 0x8b030e73, 0xb7822803, 0xf542dc0e, 0x3e23d602, 0xe930f905,
+    0x2c0ba688, 0x5aacb1ce, 0x8e0b68a7, 0xe1af3e72,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xd8000000, 0x5c000000, 0x36000000, 0x8f000000,
+    0xc2800000, 0xcd400000, 0xcfe00000, 0xe2d00000, 0xfc280000, 0xc7240000, 0x66aa0000, 0x96670000,
+    0x7f498000, 0x2bb44000, 0x99e2e000, 0x7dd13000, 0x06a83800, 0x86640c00, 0x474ab200, 0xa7b77900,
+    0x77e16d80, 0xaed2c240, 0xf22b2520, 0xc42592d0, 0x4a28ed88, 0x8826824c, 0x4429c52e, 0x8b24a2dd,
+    0x68a8d585, 0x95668e49, 0x53c9772d, 0x35f4dbd5, 0xa2803809, 0xdd400c05, 0xf7e0b201, 0x6ed0790b,
+    0x1228ed8e, 0x14268245, 0x9229c529, 0xd424a2df, 0x7228d58c, 0x04268e4e, 0xaa29772d, 0x5824dbdd,
+    0x9c28380d, 0xd7240c0f, 0x5eaab206, 0x1a677901,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x48000000, 0xfc000000, 0xe2000000, 0xbf000000,
+    0xed800000, 0x3ac00000, 0xdaa00000, 0x4af00000, 0xd2a80000, 0x961c0000, 0xc00a0000, 0x60ed0000,
+    0xd0a28000, 0xb9f2c000, 0xb529a000, 0x1fdc3000, 0x5d28a800, 0x53dc0c00, 0xd72abe00, 0xe0dcd700,
+    0x90ab7c80, 0x991cf0c0, 0x45880820, 0x562cbfb0, 0xa003d488, 0xb000fcc4, 0x6802b622, 0x0c0068bf,
+    0xaa00a80c, 0x43000c0b, 0x0f80be0c, 0x85c1d704, 0x3721fc8a, 0x703230cc, 0x080ba823, 0xdced8fbf,
+    0x12a1fc8b, 0xf6f230ce, 0x10aba823, 0xd91d8fbd, 0x6589fc8c, 0xa62e30c9, 0xe801a82b, 0x4c008fb3,
+    0x8a037c8a, 0xb300f0c6, 0x47820827, 0x79c1bfbf,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x38000000, 0x34000000, 0x7e000000, 0x1b000000,
+    0x8c800000, 0x8ac00000, 0xc5200000, 0x26900000, 0x3e580000, 0x7b740000, 0x9d7a0000, 0x02e70000,
+    0xc9a08000, 0x6c51c000, 0x5b7a6000, 0xede61000, 0x9b231800, 0x4d93bc00, 0x2ad94a00, 0x75b48700,
+    0x1e5bc680, 0x0b756140, 0x057a95e0, 0x86e4db90, 0x8fa0de88, 0x4352dd4c, 0xa9f9dfea, 0x7c275c9b,
+    0xd283980b, 0xe1c27c0f, 0xd1a32a0d, 0x2852970a, 0xbd78de8b, 0x72e6dd4b, 0x51a3dfeb, 0xe8505c93,
+    0x1d7b1803, 0xc2e7bc03, 0x69a34a0f, 0xdc538709, 0x637b468c, 0xd9e4a142, 0xe520f5eb, 0x5692cb9f,
+    0xa65bc685, 0xff756146, 0xdb7a95e1, 0x2de4db90,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xc8000000, 0x4c000000, 0x8e000000, 0x8b000000,
+    0x88800000, 0x2cc00000, 0xdea00000, 0xb3700000, 0x0d880000, 0xef440000, 0xdbaa0000, 0xb0f70000,
+    0xa8808000, 0x1cc0c000, 0xb6a1a000, 0x0f72f000, 0x4b891800, 0x28471c00, 0xdd2a1a00, 0x17359f00,
+    0xfea1d880, 0x8372bec0, 0x658aeca0, 0x534780d0, 0x9da84088, 0x77f562cc, 0xae0156aa, 0xbb00efdf,
+    0xe0808004, 0x90c0c008, 0x98a1a002, 0x7472f007, 0x0b091804, 0x48871c06, 0x8d8a1a05, 0x2f459f03,
+    0x7ba9d888, 0x40f6becc, 0x6080eca0, 0x50c080d0, 0x38a0c082, 0x8471a2c3, 0xc30af6ac, 0x04851fd4,
+    0x0389180a, 0xa4471c0b, 0xf32a1a04, 0x6c359f06,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xe8000000, 0xd4000000, 0xe6000000, 0xfb000000,
+    0x59800000, 0x7d400000, 0xd3a00000, 0x2cb00000, 0x4af80000, 0xf0a40000, 0x48da0000, 0x05550000,
+    0x3f818000, 0xc641c000, 0x6a202000, 0x61f39000, 0x71583800, 0x0814bc00, 0xe420fe00, 0x0ef31900,
+    0x2ed9a880, 0xbe5587c0, 0x8603c560, 0x8b012050, 0x51801088, 0x9940fbc4, 0xdda31b6e, 0x03b1a953,
+    0xf5798006, 0x76e5c009, 0xc2fa2000, 0x54a6900c, 0xa6d9b80b, 0x1a557c0a, 0x6800de03, 0x9400890d,
+    0x06019081, 0xcb013bc8, 0xb1833b69, 0xa9423952, 0x35a1b80f, 0xd7b17c07, 0x137ade0c, 0x8de58905,
+    0x9b78108a, 0x29e4fbc1, 0x75791b66, 0x36e4a959,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x18000000, 0x1c000000, 0x3a000000, 0xef000000,
+    0xe0800000, 0x90c00000, 0x48a00000, 0xf5d00000, 0x3e080000, 0xc94c0000, 0x342a0000, 0x9f5f0000,
+    0x38008000, 0x2c00c000, 0x8202e000, 0x0303b000, 0xc2808800, 0x63c3cc00, 0x92236a00, 0x8a11ff00,
+    0x962aa080, 0xac5d9440, 0x4283daa0, 0xa3c3f270, 0x3222a888, 0x7a12984c, 0x8e2850aa, 0xb05ebd7f,
+    0x78800009, 0x4cc0000d, 0xd2a00009, 0xead00001, 0xc688000f, 0x458c0008, 0x468a0007, 0x858f0001,
+    0xe688800d, 0x758cc005, 0xfe88e007, 0x698cb006, 0xc4880800, 0x868f0c0e, 0x240b8a0b, 0x164d4f09,
+    0x6caaa88f, 0xe39e9844, 0x52a250a1, 0x2ad1bd78,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x58000000, 0xe4000000, 0xd6000000, 0x4f000000,
+    0x28800000, 0xac400000, 0xcaa00000, 0x2d500000, 0xe9d80000, 0x2f240000, 0x98b60000, 0x04ff0000,
+    0x26808000, 0x47404000, 0x9420a000, 0x9e105000, 0x53f85800, 0x4a34e400, 0x6dced600, 0x498b4f00,
+    0x7f6ea880, 0xc0dbec40, 0xe0b6eaa0, 0xf0ff3d50, 0x088091d8, 0xbc40db24, 0x32a0b6b6, 0x9950ffff,
+    0x67d88000, 0x84244000, 0x6636a000, 0xe7bf5000, 0xc4a0d800, 0xc650a400, 0xb7587600, 0x9c641f00,
+    0x22967080, 0x61ef4840, 0xd3f89ca0, 0x0a342250, 0xcdce6158, 0x198bd364, 0x276e8a16, 0x24db8daf,
+    0x36b63958, 0xbfff3764, 0x20005c16, 0x1000c2af,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x58000000, 0xa4000000, 0x76000000, 0x59000000,
+    0x34800000, 0xce400000, 0x0da00000, 0x4ad00000, 0x6b180000, 0xeb840000, 0xabe60000, 0x0b610000,
+    0x5ba08000, 0x03d04000, 0xa798a000, 0xd1c45000, 0x88465800, 0xbcb1a400, 0x7238f600, 0x7f141900,
+    0x355e9480, 0x5e359e40, 0xb55ed5a0, 0x1e35aed0, 0x155e3d18, 0x4e35a284, 0x4d5ee766, 0xea357121,
+    0x3b5e5800, 0xb335a400, 0x0fdef600, 0x7d751900, 0x027e1480, 0x37a5de40, 0x696675a0, 0xdc21fed0,
+    0xc2806518, 0xd7400684, 0x99201166, 0xd4906821, 0x3eb84c80, 0x05547a40, 0xb6fe83a0, 0xb9e5e7d0,
+    0xc4c6f198, 0xc6f198c4, 0xf198c4c6, 0x98c4c6f1,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xf8000000, 0x3c000000, 0x6a000000, 0x63000000,
+    0xec800000, 0x22400000, 0x07a00000, 0x4a700000, 0xd3b80000, 0xb41c0000, 0xeeda0000, 0x953b0000,
+    0x15a08000, 0x55704000, 0xf538a000, 0x055cf000, 0xfdfaf800, 0xc10b3c00, 0xabb8ea00, 0xc81c2300,
+    0x24da4c80, 0x063bd240, 0x01207fa0, 0x4b303670, 0x989819b8, 0x2c2c271c, 0xc2c2fa5a, 0x57578b7b,
+    0x42c2f800, 0x17573c00, 0xe2c2ea00, 0xe7572300, 0x1ac2cc80, 0xdb579240, 0x70c2dfa0, 0xb857c670,
+    0x9c4261b8, 0x9a175b1c, 0x9be2b05a, 0xd067587b, 0x485a4c80, 0x647bd240, 0xa6807fa0, 0xf1403670,
+    0xb32019b8, 0xa430271c, 0x4618fa5a, 0xa16c8b7b,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x78000000, 0x54000000, 0xa6000000, 0x7d000000,
+    0xb6800000, 0x35c00000, 0xfae00000, 0x37300000, 0x59980000, 0xa8e40000, 0x7c7e0000, 0x1ab90000,
+    0x07988000, 0x41e4c000, 0x0cfe6000, 0xa279f000, 0x33f8f800, 0x17149400, 0x0986c600, 0x40ad8d00,
+    0xa09e4e80, 0x9089a1c0, 0x8880bce0, 0x2cc07a30, 0xf260f718, 0xdbf03924, 0xcbf8589e, 0x83140489,
+    0xcf864e80, 0xcdada1c0, 0x6e1ebce0, 0xf1497a30, 0xd4607718, 0x66f0f924, 0x1d78389e, 0x46d4f489,
+    0x4d663680, 0xae9df5c0, 0x91861ae0, 0x24ad0730, 0x1e9ec198, 0x4989cce4, 0xe000427e, 0x300003b9,
+    0x18000f18, 0xa400ad24, 0xde009e9e, 0x29008989,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xb8000000, 0x9c000000, 0x9a000000, 0xad000000,
+    0x72800000, 0x59c00000, 0xdc600000, 0xba300000, 0xbd580000, 0x3aec0000, 0x7de20000, 0xda510000,
+    0x8dd88000, 0x622cc000, 0x1102e000, 0xf8a1f000, 0xbc603800, 0x8a305c00, 0xe5587a00, 0x56ec5d00,
+    0x5fe2ca80, 0xeb51c5c0, 0x6558c660, 0x96ecd730, 0xbfe22fd8, 0x1b51932c, 0xdd589982, 0x0aec3c61,
+    0x25e24a80, 0xb65105c0, 0xafd82660, 0x532c2730, 0xf98297d8, 0x0c610f2c, 0x12800382, 0x69c09161,
+    0x84603800, 0xd6305c00, 0x9f587a00, 0x0bec5d00, 0x9562ca80, 0x2e91c5c0, 0x2338c660, 0x81dcd730,
+    0x703a2fd8, 0x787d932c, 0x7cda9982, 0x6a8d3c61,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xe8000000, 0x04000000, 0x4a000000, 0xbf000000,
+    0x0e800000, 0x55c00000, 0xe1e00000, 0x83700000, 0x58c80000, 0x2c940000, 0x2ef20000, 0xc5630000,
+    0x59288000, 0x5fe4c000, 0xfe3a6000, 0xddf73000, 0xd5da6800, 0x2187c400, 0xe392aa00, 0x68d34f00,
+    0xc4800680, 0x2ac0a1c0, 0x8f6023e0, 0xe6b00870, 0x5128f448, 0xabe4c254, 0x3c3a8b92, 0x56f7acd3,
+    0x795a6e80, 0xcf4765c0, 0x46f289e0, 0x01634770, 0x7328f2c8, 0xd0e46394, 0x18baa872, 0x8c37a4a3,
+    0x7e3a9ac8, 0x1df7a794, 0xb5da0272, 0x1187eba3, 0x0b921c48, 0x6cd3c654, 0x8e804192, 0x95c0d3d3,
+    0x81e08000, 0xb370c000, 0xb0c86000, 0x28943000,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x88000000, 0xfc000000, 0x96000000, 0x9b000000,
+    0x8a800000, 0x5d400000, 0xf9e00000, 0x3bd00000, 0xba280000, 0xa5cc0000, 0x9dee0000, 0xd93f0000,
+    0x4bc88000, 0x221c4000, 0xd1c66000, 0xf7f31000, 0xd4a60800, 0x5a63bc00, 0xf5ee7600, 0x753fcb00,
+    0x35c8e280, 0x551cf140, 0x454687e0, 0xcdb34cd0, 0x31c6aea8, 0xa7f3df8c, 0x3ca6988e, 0xb66374af,
+    0xebeeea80, 0x123f4d40, 0x2948f1e0, 0x935c87d0, 0x36264c28, 0xab232ecc, 0x720e1f6e, 0x39ef387f,
+    0x1b60c428, 0xca90d2cc, 0x3dc8096e, 0xe91ce37f, 0xb346aea8, 0x46b3df8c, 0x3346988e, 0x06b374af,
+    0x5346ea80, 0x16b34d40, 0xdb46f1e0, 0xeab387d0,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x98000000, 0xdc000000, 0x9e000000, 0x01000000,
+    0xf2800000, 0xc7c00000, 0x8f200000, 0x9b700000, 0xd1780000, 0x3a0c0000, 0x13560000, 0x65550000,
+    0x40588000, 0x607cc000, 0x90aea000, 0xa8993000, 0x442e1800, 0x42591c00, 0x9f8ebe00, 0xf3e9f100,
+    0x35564a80, 0x4855ebc0, 0x14d8a920, 0x4abcb670, 0xeb0e85f8, 0x292910cc, 0x76f6e8f6, 0x25e524e5,
+    0x20005280, 0xf000f7c0, 0x38001720, 0xec004770, 0x06004f78, 0xdd003b0c, 0x6c80e1d6, 0xc6c0a295,
+    0x7da0cf78, 0x5cb0fb0c, 0x5e5841d6, 0xa17c9295, 0xc22e5778, 0x5f59270c, 0x530e5fd6, 0x05295395,
+    0xd0f685f8, 0xc8e510cc, 0xd480e8f6, 0xeac024e5,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xd8000000, 0x04000000, 0x52000000, 0x0d000000,
+    0x0e800000, 0x9bc00000, 0xb1600000, 0x28f00000, 0xfcf80000, 0x06540000, 0x373a0000, 0xdf510000,
+    0xc3188000, 0x7564c000, 0x9aa2e000, 0x41f5b000, 0xd0da5800, 0xa861c400, 0x3c803200, 0xe6c07d00,
+    0x87e03680, 0x07302fc0, 0xc7983b60, 0x27a421f0, 0x9742a078, 0x4fc59094, 0x4bc208da, 0x1905ac61,
+    0x1422ee80, 0x1a352bc0, 0x81bae960, 0x3091ecf0, 0x1878cef8, 0xe4947b54, 0xe2da01ba, 0xd561f091,
+    0x0a007878, 0xc9009494, 0xbc80dada, 0x26c06161, 0x67e08000, 0xb730c000, 0x1f98e000, 0x23a4b000,
+    0xc542d800, 0x42c50400, 0x4542d200, 0x82c5cd00,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x18000000, 0x8c000000, 0xde000000, 0xe3000000,
+    0x43800000, 0x13c00000, 0x1ba00000, 0xffd00000, 0xb5c80000, 0x04440000, 0x7a1a0000, 0xc9b90000,
+    0x821a8000, 0x75b94000, 0x241ae000, 0x6ab93000, 0xa19af800, 0x1679bc00, 0x27ba2600, 0x19695f00,
+    0xca526580, 0xf13d4cc0, 0x1e207e20, 0xc310b310, 0x53e84be8, 0x7b54f754, 0x8ff251f2, 0xaded4eed,
+    0x88684be8, 0xa494f754, 0x2a5251f2, 0xc13d4eed, 0x66204be8, 0x3f10f754, 0x95e851f2, 0x14544eed,
+    0x1272cbe8, 0x5d2db754, 0xd048b1f2, 0x48847eed, 0x843ab3e8, 0x3aa94b54, 0xa9f277f2, 0xf2ed11ed,
+    0x6de8ae68, 0xa854fb94, 0xb472cfd2, 0x422dcdfd,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xe8000000, 0x54000000, 0x52000000, 0x01000000,
+    0xb1800000, 0x09c00000, 0x95e00000, 0x83f00000, 0x38180000, 0xdccc0000, 0x36de0000, 0xabff0000,
+    0x0cde8000, 0xbeff4000, 0xcf5ea000, 0xa63f5000, 0x033e4800, 0x780f0400, 0xfcc61a00, 0x26330500,
+    0x4380ab80, 0x58c00cc0, 0xec60be60, 0xce30cf30, 0x17f8a678, 0x0a3c03fc, 0xed4678a6, 0x7ff3fc03,
+    0x1e60a678, 0x9f3003fc, 0x6e7878a6, 0x47fcfc03, 0xc2a62678, 0xa90343fc, 0xc5f8d8a6, 0x4b3cac03,
+    0x7cc6ee78, 0x663307fc, 0x638062a6, 0x48c0f903, 0x04600df8, 0x9a300f3c, 0x45f8c6c6, 0x0b3c3333,
+    0x5cc68000, 0x76334000, 0x8b80a000, 0x1cc05000,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x88000000, 0x4c000000, 0x1e000000, 0x55000000,
+    0xe9800000, 0xbf400000, 0x46a00000, 0xc1500000, 0xa3980000, 0x40f40000, 0x20fa0000, 0x90970000,
+    0xf85a8000, 0xc4c7c000, 0x52426000, 0x4b73b000, 0xbc18e800, 0x56b4fc00, 0xf9da7600, 0x87876900,
+    0x62e2ff80, 0xe3236640, 0x6000d120, 0xb0009b10, 0x680064b8, 0x3c0002e4, 0x960053c2, 0x19000833,
+    0xf7808cb8, 0xea40fee4, 0xaf2025c2, 0x7e106133, 0xe538f338, 0x81a458a4, 0x836294e2, 0xd0634a23,
+    0xd8a07f80, 0x5450a640, 0xaa18b120, 0x8fb42b10, 0xee5a0cb8, 0x1dc73ee4, 0x45c245c2, 0xd133d133,
+    0x9b381b38, 0x64a4a4a4, 0x02e2e2e2, 0x53232323,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xc8000000, 0x7c000000, 0xe2000000, 0x37000000,
+    0x95800000, 0xc2400000, 0x47600000, 0xfd900000, 0x0e380000, 0x6dd40000, 0xb60e0000, 0x796d0000,
+    0x98368000, 0x64b9c000, 0x46382000, 0xd1d47000, 0xf40e6800, 0xfe6dcc00, 0xc5b6aa00, 0xdaf98b00,
+    0xe3585780, 0x1b448540, 0x6fb6bae0, 0x51f9f3d0, 0x34d8e358, 0x5e041b44, 0x75566fb6, 0x122951f9,
+    0x9f80b4d8, 0xf9409e04, 0x58e0d556, 0xc4d0a229, 0xf6d85780, 0x19048540, 0x88d6bae0, 0x1c69f3d0,
+    0xf2e0e358, 0x4fd01b44, 0x21586fb6, 0x5c4451f9, 0x923634d8, 0x5fb95e04, 0x59b8f556, 0xe894d229,
+    0x0cee3f80, 0x8abd4940, 0xfb6e10e0, 0xbffd78d0,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x38000000, 0x8c000000, 0x6e000000, 0x2f000000,
+    0x51800000, 0xd6400000, 0x63200000, 0xdfd00000, 0x49c80000, 0x0a140000, 0x85720000, 0x44a90000,
+    0xfaba8000, 0x9dbdc000, 0x98486000, 0x1c547000, 0x0652d800, 0x2b793c00, 0x8b72d600, 0x1ba96300,
+    0x733a5f80, 0x77fd8940, 0xad686aa0, 0x6084f590, 0x701a9ce8, 0xd82dc6c4, 0x3ca04b3a, 0x5690fbfd,
+    0xa368c368, 0x3f844f84, 0xf99a219a, 0x326d0e6d, 0x0980df80, 0x2a404940, 0xd5200aa0, 0xccd08590,
+    0x4e4844e8, 0x7f54fac4, 0xd9d29d3a, 0x623998fd, 0x81d29ce8, 0x9e39c6c4, 0x37d24b3a, 0x8d39fbfd,
+    0x30524368, 0xf8798f84, 0x6cf2419a, 0xdee97e6d,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x98000000, 0x04000000, 0xca000000, 0x2b000000,
+    0x05800000, 0x94c00000, 0x12600000, 0x0f300000, 0xdf080000, 0x275c0000, 0x132e0000, 0x51110000,
+    0xe6468000, 0x2d7d4000, 0x5860a000, 0x64305000, 0xfa88b800, 0xa39c1400, 0x994e5200, 0x5a212f00,
+    0xf34e4f80, 0x2121ffc0, 0x4eceb7e0, 0xa1e1cbf0, 0x0eaef568, 0x81d17c6c, 0x1e263e26, 0x194d094d,
+    0x1ae802e8, 0xd3ac97ac, 0x31c6dbc6, 0xd6bdedbd, 0xa580b800, 0xc4c01400, 0xaa605200, 0x1b302f00,
+    0x8d08cf80, 0x085cbfc0, 0xdcae17e0, 0xeed19bf0, 0xf1a64d68, 0xb68d686c, 0x95086c26, 0x4c5c264d,
+    0x36ae4d68, 0xd5d1686c, 0x6c266c26, 0x264d264d,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x98000000, 0xdc000000, 0x7a000000, 0xc9000000,
+    0x57800000, 0x68c00000, 0x34600000, 0x0e300000, 0xe7880000, 0xa0c40000, 0x50e60000, 0xb8930000,
+    0xcc8e8000, 0xe2a74000, 0x1580a000, 0x2dc05000, 0xa1e0b800, 0x63f0cc00, 0x6668e200, 0xd3341500,
+    0xae8e2d80, 0xb7a7a1c0, 0x1800e3e0, 0x9c0026f0, 0x5a00f3e8, 0xd900bef4, 0xcf802f6e, 0xb4c0c457,
+    0x4e60e668, 0xc7309334, 0xb0088e8e, 0xc804a7a7, 0x64868000, 0xb6a34000, 0x2b06a000, 0x42635000,
+    0x45663800, 0x95538c00, 0x6d6e4200, 0x81574500, 0x73e81580, 0xfef42dc0, 0x0f6ea1e0, 0xd45763f0,
+    0x7e68e668, 0x4f349334, 0xf48e8e8e, 0x6ea7a7a7,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x58000000, 0x34000000, 0x0a000000, 0x9f000000,
+    0x0b800000, 0xd8400000, 0xf4600000, 0xea700000, 0x4f680000, 0x53ac0000, 0xec5a0000, 0xfe650000,
+    0x753f8000, 0x44edc000, 0x8b802000, 0x18403000, 0x14603800, 0x3a703400, 0x17681600, 0x67ac0d00,
+    0xe65a0280, 0x616527c0, 0x7ebf82e0, 0x9cadf610, 0x7fe01d18, 0xf2300a9c, 0x5b082bda, 0x69dc20eb,
+    0xfb322d16, 0x99c93299, 0x93659fcf, 0x2588f6fb, 0xf53f8000, 0x84edc000, 0x6b802000, 0xc8403000,
+    0x4c603800, 0x0e703400, 0x1d681600, 0xf8ac0d00, 0xedda0280, 0xb92527c0, 0x8adf82e0, 0x76ddf610,
+    0x30881d18, 0xa19c0a9c, 0xb7522bda, 0x97b920eb,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xe8000000, 0x84000000, 0x36000000, 0x85000000,
+    0x37800000, 0x58400000, 0x8c200000, 0x62500000, 0x2b380000, 0xdef40000, 0xdd820000, 0x677b0000,
+    0x5cb28000, 0x26b3c000, 0x21802000, 0xed401000, 0xf3a02800, 0xce101c00, 0x79183a00, 0xbda42100,
+    0xf73a0d80, 0x64cf2140, 0x3a908de0, 0x7bd8d610, 0xda2a8308, 0x7757c894, 0x249a02ce, 0x9adf3bbd,
+    0x0b88a560, 0x327ce4de, 0xf31080ac, 0x1298d9ec, 0x1f8a8000, 0x3c47c000, 0x6a022000, 0x7f3b1000,
+    0x7092a800, 0x34e3dc00, 0xe2b81a00, 0xb7b43100, 0x18222580, 0x2c6b3d40, 0x122ab7e0, 0xc357f710,
+    0x5a9a0e88, 0xebdf29d4, 0xe208af2e, 0x6b3cfdad,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xf8000000, 0x24000000, 0x42000000, 0xdd000000,
+    0x9e800000, 0xa4400000, 0x82600000, 0xfd100000, 0x2ed80000, 0x5c3c0000, 0xa6720000, 0xbf310000,
+    0xf3b68000, 0xc2f34000, 0x02602000, 0x3d103000, 0x0ed80800, 0xec3c2c00, 0x5e723e00, 0x9b310900,
+    0xb1b69080, 0x1ff37740, 0x9ce007a0, 0x99501910, 0x8cb82898, 0x112c1344, 0x70aa35b6, 0xc70d1e0f,
+    0x17c4b91c, 0xa0c2588c, 0x6f56bb4d, 0x5ba369ac, 0x8ed80800, 0x2c3c2c00, 0x7e723e00, 0x2b310900,
+    0x49b69080, 0x3bf37740, 0xdee007a0, 0x44501910, 0x12382898, 0xb56c1344, 0xf2ca35b6, 0x3a1d1e0f,
+    0x391cb91c, 0xfcfe588c, 0xc924bb4d, 0xe49269ac,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xa8000000, 0xdc000000, 0xca000000, 0xa1000000,
+    0xb5800000, 0x2c400000, 0x32600000, 0x35700000, 0x33c80000, 0xef140000, 0xfa8e0000, 0x66d50000,
+    0x8cb88000, 0x5ddec000, 0xd0602000, 0x88703000, 0xac483800, 0xf2542400, 0xd56e2a00, 0xa3e53700,
+    0x4710b280, 0x26bae840, 0xaca60d60, 0x2df13b10, 0xe81eb498, 0xfc2fe95c, 0xba7e86f2, 0x995ffcc5,
+    0xc1b69423, 0x3a4be5f5, 0x5938bc4e, 0x219eff67, 0xaa003800, 0xf1002400, 0xfd802a00, 0x60403700,
+    0x50603280, 0x48702840, 0x4c482d60, 0x62540b10, 0x7d6e0c98, 0x7fe50d5c, 0x8d108cf2, 0x87bafbc5,
+    0x19261ea3, 0x01b129b5, 0xda7e9b2e, 0xc95ff377,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x18000000, 0x94000000, 0x7e000000, 0xe3000000,
+    0xd6800000, 0x4c400000, 0x0a200000, 0x6d500000, 0x7da80000, 0x16cc0000, 0xac2a0000, 0xfa4f0000,
+    0x25098000, 0xf1cf4000, 0xfca02000, 0x31101000, 0xcf882800, 0xbf9c1400, 0xb7820600, 0x9b832500,
+    0x21a39f80, 0xa4c078c0, 0x050995a0, 0xe1cf4310, 0x44a00a88, 0xf5101f54, 0xa988316a, 0xc89c34b3,
+    0x1f02328a, 0x34c32353, 0xfd03a362, 0x85d057a3, 0x7281a800, 0x9a535400, 0x95222600, 0x19933500,
+    0x20abb780, 0xc31c6cc0, 0xc6ab93a0, 0xf41c6610, 0xce2b9508, 0x0b5c6794, 0x0a8ba4ca, 0xbe4c77a3,
+    0x0303b802, 0x26d07c07, 0x0401b208, 0x86137310,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xf8000000, 0x1c000000, 0x82000000, 0x2d000000,
+    0x8d800000, 0x0a400000, 0x09600000, 0xb3d00000, 0x55380000, 0x519c0000, 0x281e0000, 0xd4590000,
+    0xc63a8000, 0x4330c000, 0xdab82000, 0xb6dc3000, 0x0cfe2800, 0x9dc93c00, 0x6262be00, 0xbd7cc700,
+    0x259e0080, 0x1e193b40, 0x6f5a8b60, 0x00e0fe90, 0x77801c58, 0xfb401bf4, 0xa6e01dce, 0x64902327,
+    0x29d81767, 0xf40c3086, 0xf6461356, 0x1b153778, 0x369c8080, 0xccb5fb40, 0x3dfcab60, 0x9265ce90,
+    0x4544b458, 0x39b9e7f4, 0x9c3a83ce, 0x4230d427, 0x8d383fe7, 0x7d9c37c6, 0xf21e2636, 0x15590ee8,
+    0x31ba9c58, 0x7870dbf4, 0xdc583dce, 0x224c1327,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x18000000, 0x44000000, 0x4a000000, 0x63000000,
+    0x89800000, 0x82400000, 0x6f600000, 0x0fb00000, 0x07380000, 0x93ec0000, 0x69520000, 0x4abd0000,
+    0x9bcd8000, 0x053ec000, 0x3cb82000, 0x06ac3000, 0xbdb21800, 0x104d3c00, 0x28158600, 0x3c22d100,
+    0xfe323280, 0x310d28c0, 0xaef5ba60, 0x41d2dc90, 0x8e6a3dd8, 0xe95122ec, 0x8a9f8b4e, 0xfb83c03b,
+    0xf5759e34, 0x2492d33f, 0x428a17ab, 0xf7a11663, 0x7347b280, 0xa19fe8c0, 0xbe7f9a60, 0x9173ec90,
+    0x3eada5d8, 0xa98edeec, 0xd2002d4e, 0xe700213b, 0xa38034b4, 0x114007ff, 0xfee00bcb, 0xc9f02bf3,
+    0x225825d8, 0xff5c1eec, 0xe7ea0d4e, 0x5b11113b,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x08000000, 0x74000000, 0xd2000000, 0x6b000000,
+    0x68800000, 0xf6400000, 0xf1200000, 0x17b00000, 0x9ca80000, 0x64640000, 0xfa2a0000, 0xaf310000,
+    0x62d98000, 0xe14a4000, 0x3f882000, 0x58d41000, 0x6e021800, 0xed153400, 0x61d38200, 0x2dcb5d00,
+    0x13f99480, 0xb6fa4ac0, 0xc3202220, 0xecb01990, 0x9c282648, 0x36242cec, 0xd10a11aa, 0xa7811ed9,
+    0x44f1902a, 0x186e4c9c, 0x5c021ede, 0x1615102e, 0x61538c80, 0x7f8b7ec0, 0x38d9a020, 0xbe4a4490,
+    0xe50832c8, 0x1594262c, 0xffa2138a, 0x78e51749, 0xde5bae62, 0x351f5470, 0x1dfb8d74, 0x8bef53f7,
+    0xaaf3882a, 0xb57b789c, 0x5dd19cde, 0xebde4d2e,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xe8000000, 0x7c000000, 0x5a000000, 0x25000000,
+    0x47800000, 0xd6400000, 0xe7200000, 0xc6900000, 0x4fc80000, 0xda140000, 0x650e0000, 0x67b50000,
+    0xc62e8000, 0x0f49c000, 0xbae82000, 0x15841000, 0xff460800, 0x22e10400, 0xb180ba00, 0x212cdf00,
+    0xc9aeb680, 0xf509d940, 0xcfc839e0, 0x9a142190, 0x450e0bc8, 0x77b52aa4, 0x2e2ebf72, 0x7349e0c5,
+    0xe0e83e23, 0x3084353d, 0xb8c611a3, 0xf4a103e6, 0x56a0be80, 0xe7bcdd40, 0x866683e0, 0x2f1dfe90,
+    0xaac63d48, 0xfda133e4, 0x8320a692, 0x78fcd155, 0x94c6bdeb, 0x66cddb99, 0x1fae34d1, 0x12652c23,
+    0x0946be23, 0xd58df53d, 0x9f0e31a3, 0x12b513e6,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x68000000, 0x44000000, 0x5a000000, 0x8d000000,
+    0x6c800000, 0xd6400000, 0xa3600000, 0x23f00000, 0x0bc80000, 0x6f940000, 0xc5f20000, 0x30f50000,
+    0x702a8000, 0xb8384000, 0xcc282000, 0x0e243000, 0xbf5a3800, 0xa5910400, 0xe0909a00, 0xf8195100,
+    0xec10b680, 0xfe595340, 0xc7708320, 0x89a94190, 0xfeb8aad8, 0x2f3d6dfc, 0x0dca9672, 0x44885da5,
+    0xb2003edc, 0x09003fed, 0xd6801b72, 0x4b402522, 0xa7e00e80, 0xb1b01740, 0xf2a83920, 0xc1642090,
+    0xa2ba2458, 0x89213abc, 0x16b88f52, 0xab3d4d35, 0xb7caa284, 0xd9884151, 0xb6802e20, 0x9b400917,
+    0x2fe02284, 0xe5b00151, 0xc0a80e20, 0x08643917,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x48000000, 0xbc000000, 0xe2000000, 0x27000000,
+    0x3f800000, 0x26400000, 0x21200000, 0xeef00000, 0x30e80000, 0xb5c40000, 0x4d4e0000, 0x04890000,
+    0x4baf8000, 0x382dc000, 0x04482000, 0xa6741000, 0x61063800, 0x0efd1400, 0x60a99200, 0xfdd0ef00,
+    0xf1619880, 0xe6e4d9c0, 0x6cc797e0, 0x07a9cd90, 0x22260248, 0x870d10bc, 0x8fc19eba, 0x3e54dfb1,
+    0xd50f9633, 0xb09dfe72, 0xf58035c3, 0xad4031e7, 0x54a02080, 0x03b00dc0, 0x844825e0, 0xe6743290,
+    0x810622c8, 0x5efd1d7c, 0x28a9bb5a, 0x41d0ed21, 0x1361b4fb, 0xc1e4e30e, 0x53478e99, 0x21e9dcc6,
+    0x0306147b, 0x69fd2ece, 0xbf298b79, 0x8b90fe56,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xd8000000, 0x64000000, 0x1e000000, 0xe9000000,
+    0x28800000, 0x89400000, 0x78e00000, 0xc1500000, 0x74b80000, 0x633c0000, 0xe78a0000, 0xbcb30000,
+    0xaf3e8000, 0x65874000, 0x9f8a2000, 0xa8b33000, 0x493e8800, 0x58876c00, 0x710a1600, 0xacf32900,
+    0x075e8f80, 0xf9975640, 0x55d21c20, 0x87df0b50, 0xec8c91b8, 0xe7486614, 0x69de810e, 0x3dd7539f,
+    0x3bb2085a, 0x96cf3938, 0x105482c1, 0xa86456fe, 0x9c0c91b8, 0xca086614, 0x2f3e810e, 0xa587539f,
+    0xbf8a085a, 0x18b33938, 0x913e82c1, 0x3c8756fe, 0x6f0a11b8, 0x45f32614, 0x2fdea10e, 0x70d7639f,
+    0x2d32005a, 0x468f1538, 0x9834b4c1, 0x84744ffe,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x98000000, 0x7c000000, 0x2e000000, 0x4b000000,
+    0xc4800000, 0xf3400000, 0xe8e00000, 0xe5100000, 0x4fa80000, 0x97dc0000, 0x8bd60000, 0x95ab0000,
+    0xd6a38000, 0xf6014000, 0x57562000, 0xdaeb3000, 0xb043a800, 0xc8116400, 0x447e0600, 0xc2772f00,
+    0xfd75a380, 0xf3aa76c0, 0x19f5b720, 0x50ea53d0, 0xc915b9b8, 0x59fa7f84, 0x30bd8cca, 0xf9265227,
+    0x51ebbd4d, 0xd4cd45ee, 0xab2832e2, 0x349c3d67, 0x5b3639b8, 0x9cbb3f84, 0x2f0bacca, 0x56dd6227,
+    0x3600154d, 0xf70021ee, 0x4a8034e2, 0x28401267, 0xb4601a38, 0x6a500944, 0x89483bea, 0x39cc01f7,
+    0x00fe04f5, 0xf1373a6a, 0xb595be28, 0x86ba6f40,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x38000000, 0xe4000000, 0xfe000000, 0x49000000,
+    0x4e800000, 0xed400000, 0x50e00000, 0x54500000, 0xf6080000, 0x650c0000, 0xbc9a0000, 0x86230000,
+    0x4d368000, 0x40c84000, 0x4c7a2000, 0xc2733000, 0xa33e8800, 0x11c47400, 0x36e02e00, 0xe9500900,
+    0x5e883780, 0xf54c2640, 0x64fa1da0, 0x92330250, 0x5b5eabb8, 0xd5d44754, 0x18880e22, 0x984c1213,
+    0xf47a331e, 0xe673009c, 0x7d3ea0cf, 0x88c47071, 0x406023b8, 0xe0103354, 0xf0682022, 0xe81c1b13,
+    0xdc72049e, 0x1a7f26dc, 0xb724bd6f, 0x07a77221, 0xa3b68800, 0xbd887400, 0x049a2e00, 0xa2230900,
+    0x9336b780, 0xd9c86640, 0x3afa3da0, 0xcb333250,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0xb8000000, 0x4c000000, 0x5e000000, 0x6b000000,
+    0xe4800000, 0x07400000, 0x6aa00000, 0x74100000, 0x52680000, 0x950c0000, 0x1fee0000, 0x5bb30000,
+    0x21f38000, 0x40ed4000, 0x4d4e2000, 0x23a31000, 0x8d9ba800, 0x2ee16400, 0x0e200e00, 0x33500300,
+    0x98c83f80, 0x711c3ec0, 0xf5861720, 0x82bf12d0, 0x601d8d28, 0x705e47c4, 0x883d8dba, 0x640e7493,
+    0xaa759553, 0x79527fe8, 0xd1d3bae6, 0x88bd5278, 0x89062528, 0x19ff23c4, 0x4cbd83ba, 0xb34e7793,
+    0xd8d5aad3, 0xd1424128, 0x65bbadc6, 0x3ab140a8, 0x2c682800, 0x2e0c2400, 0xe36e2e00, 0x80f31300,
+    0xad539780, 0x13fd5ac0, 0xa5a61920, 0xdaef11d0,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x08000000, 0x94000000, 0x1a000000, 0x27000000,
+    0xcd800000, 0x83400000, 0x6fa00000, 0xc8100000, 0x74080000, 0xea540000, 0x7f620000, 0x51890000,
+    0x0d198000, 0x52cbc000, 0x22c22000, 0x3a991000, 0x0691a800, 0xd8dfd400, 0xed802200, 0x93403500,
+    0xc7a02e80, 0x0c101dc0, 0x66083160, 0x595405d0, 0xa8e21d68, 0xf5c93bc4, 0xaf39ae62, 0x199bda45,
+    0x396a2430, 0x18dd3666, 0x0dfbb644, 0x6302fa27, 0x9ffbb568, 0x9002efc4, 0xe87b8c62, 0x6442ef45,
+    0x425b8ab0, 0xbb12eba6, 0x43f3a724, 0xbe56eff7, 0x85198000, 0x86cbc000, 0x98c22000, 0x4d991000,
+    0xc311a800, 0xcf9fd400, 0x98202200, 0x7c503500,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xe8000000, 0x64000000, 0x26000000, 0x19000000,
+    0xa2800000, 0xf5400000, 0xd0a00000, 0x52100000, 0x37480000, 0xafa40000, 0x499a0000, 0xb0870000,
+    0x620e8000, 0xaf5a4000, 0xf3ba2000, 0xe3d71000, 0xebe6a800, 0xffee7400, 0xe1e81a00, 0x74b40900,
+    0x94522180, 0x5e633240, 0x353492a0, 0x30cd6450, 0x227c9da8, 0x0f6942c4, 0x23e6ad72, 0x0bee72b9,
+    0x8fe8144e, 0xd9b405a5, 0xf8d20ed1, 0xd6232b7f, 0x6114b5a8, 0x8e9d76c4, 0x67149772, 0x079d6bb9,
+    0x8d949dce, 0x46dd43e5, 0x93348671, 0x69cd462f, 0x20fc8980, 0x2a294640, 0x1b4688a0, 0x3dfe6d50,
+    0x9ea03c28, 0x6f103084, 0x13c81fd2, 0x93e406e9,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xf8000000, 0xec000000, 0xa2000000, 0x09000000,
+    0xc5800000, 0xcd400000, 0x83e00000, 0x12700000, 0x31780000, 0x49cc0000, 0x5f2e0000, 0x72cd0000,
+    0x3b828000, 0x5e0bc000, 0xc34e2000, 0x88fd3000, 0x8e9a9800, 0xf1f7cc00, 0x13781e00, 0x80cc0b00,
+    0xfaae3080, 0x8f8d0e40, 0x40628f60, 0xa07bc850, 0x50362878, 0xc83136dc, 0x1434a53e, 0x4e7aed23,
+    0xab1aa933, 0xccb7d32f, 0x0898123e, 0x4ebc3bb1, 0x91d63078, 0x23413adc, 0x78cc9b3e, 0x16f6d623,
+    0x2dd481b3, 0x490ad16f, 0x65e2a35e, 0x9d3bc8e1, 0x4bd63080, 0x06410e40, 0x7f4c8f60, 0xe2b6c850,
+    0x93b4a878, 0x7a3af6dc, 0x757a853e, 0xcf87dd23,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0xe8000000, 0x04000000, 0xfa000000, 0xb7000000,
+    0x43800000, 0x3d400000, 0xece00000, 0xa2900000, 0x57e80000, 0xef040000, 0xfffa0000, 0x8b510000,
+    0x35858000, 0x443ac000, 0x9a122000, 0x87553000, 0x3bffa800, 0xd12be400, 0x12f79a00, 0xefbff100,
+    0xa365b680, 0x91aaf9c0, 0x2e7a22e0, 0xc5111a50, 0xc0e597b8, 0xfceae064, 0x8a9a219a, 0xf3812851,
+    0x850d82a6, 0xa0eecce0, 0xcce02943, 0xf2903c3b, 0x1fe82138, 0x7b0419a4, 0xedfa037a, 0x38513201,
+    0x8c05951e, 0xce7aec84, 0x357228d9, 0x1885246a, 0x80f78b9e, 0x9cbff144, 0xbae59039, 0x8beacf3a,
+    0x691a2aa6, 0x5ec128e0, 0x81edb343, 0x067ecd3b,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x18000000, 0x94000000, 0xaa000000, 0xc3000000,
+    0xd1800000, 0xf1400000, 0xc6a00000, 0x6ab00000, 0xc4f80000, 0x659c0000, 0xcb420000, 0xcd910000,
+    0x070f8000, 0x13c3c000, 0x5e3a2000, 0x194d1000, 0xeaedb800, 0x84e2cc00, 0x85cda600, 0xfb12f500,
+    0xd595b280, 0x933eecc0, 0xb9afaa60, 0x9d73c550, 0xc8c20528, 0x1bd1136c, 0x422f91de, 0xef33fcf7,
+    0x3fe23b98, 0xb0211058, 0x5877a7b5, 0x741fc7ab, 0x9a5837a8, 0xdb2c3fac, 0x45ba1bbe, 0x5b0d29a7,
+    0x05cd86b0, 0xbb12cf34, 0x3595906b, 0xa33ece5c, 0xa1afbeb0, 0x0973c334, 0x62c2166b, 0xd8d12b5c,
+    0x93afb430, 0x1e73e3f4, 0xf9421a0b, 0xda911b0c,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x18000000, 0x44000000, 0x1e000000, 0x41000000,
+    0x39800000, 0xd7400000, 0x04e00000, 0x84b00000, 0x44c80000, 0xa4dc0000, 0x749a0000, 0x6cbb0000,
+    0x28b68000, 0x3685c000, 0x77d22000, 0x4e273000, 0x994cb800, 0x9dcef400, 0x194ca600, 0x5dcee100,
+    0xf94c9f80, 0x8dced440, 0xe14c9060, 0xc9cee0d0, 0xff4c98b8, 0x88cee46c, 0xc6cc86d2, 0x5f8ed9a7,
+    0xc22c8bfe, 0xdb3eca92, 0x86e48dc7, 0x7fe2f52a, 0xf27e8738, 0x1359f02c, 0xdac836b2, 0x25dc0977,
+    0xad1a2b46, 0x6bfb1afe, 0x34568d15, 0xf635fd8d, 0x2d1a2b46, 0xabfb1afe, 0xd4568d15, 0x2635fd8d,
+    0x351a2b46, 0xeffb1afe, 0xca568d15, 0x6735fd8d,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0xb8000000, 0x84000000, 0x1a000000, 0xd5000000,
+    0x30800000, 0x93400000, 0x6ba00000, 0x34b00000, 0xc9480000, 0x5e8c0000, 0x545a0000, 0xe2590000,
+    0xb1048000, 0x7aff4000, 0xfe322000, 0xdf251000, 0xbdb6b800, 0x779a5400, 0x5aa48e00, 0x0e4f7100,
+    0xd77a1e80, 0xd1a93140, 0x51ec9a20, 0x11c351d0, 0xf1a01268, 0xa1b0096c, 0x19c800f2, 0x9dcc1233,
+    0x87fa2dde, 0x52e9306a, 0x624c827b, 0xf1737170, 0x9ae834e8, 0xae3c2c2c, 0x671234d2, 0x39d522e3,
+    0x6dde9936, 0x8fe65c46, 0x3e9696a9, 0x446a4393, 0xba4c95de, 0x6573646a, 0xd8e80c7b, 0xaf3c0070,
+    0xf5922a68, 0xfb951d6c, 0x2cfeaef2, 0xfd167333,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xe8000000, 0x44000000, 0x0a000000, 0x6b000000,
+    0x26800000, 0x7d400000, 0x2be00000, 0xde900000, 0x21380000, 0x2dc40000, 0xcbaa0000, 0x8ec90000,
+    0x59138000, 0xb19c4000, 0x6df22000, 0xabdd3000, 0x1ee1a800, 0x81414c00, 0x1d939a00, 0x23dc6100,
+    0xca920a80, 0x530d26c0, 0xdab99ba0, 0x4b554250, 0xd6e19278, 0x35414c64, 0x5f938b6e, 0x3cdc72e1,
+    0x0e1203b2, 0x014d2646, 0xddd98472, 0x83855772, 0xfab9b0f8, 0xbb5566a4, 0x9ee1aace, 0x414161b1,
+    0xbd93b34a, 0x13dc40e2, 0x22922ebc, 0x170d36c3, 0xd0b983b2, 0x20556646, 0xf061a472, 0x48016772,
+    0x747398f8, 0xe24c6aa4, 0x2f2a10ce, 0x2c8930b1,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x78000000, 0xa4000000, 0xea000000, 0xbf000000,
+    0x45800000, 0x7dc00000, 0xf9a00000, 0x03d00000, 0x34a80000, 0xad140000, 0x9e860000, 0x32530000,
+    0xcb5c8000, 0xc7964000, 0xeedca000, 0x8a567000, 0x8f7c9800, 0xdd864c00, 0x29d48600, 0x6b926500,
+    0x18d2bc80, 0x9b014ac0, 0x6fae2de0, 0x228715b0, 0x4c7ab388, 0x06156544, 0x692826a2, 0x44d40201,
+    0x15260703, 0xda832b95, 0x2874a954, 0x0c4260b0, 0x267a8703, 0x79156b95, 0xcca80954, 0xc91410b0,
+    0x94861f03, 0x7d532795, 0xf6dc8f54, 0x1e5675b0, 0xfd7ca383, 0x36866d55, 0xfe54a2b4, 0x0d526000,
+    0x4ef2900b, 0x5a114811, 0xe7262416, 0xf1831201,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x18000000, 0x74000000, 0xe6000000, 0x73000000,
+    0x7a800000, 0x42c00000, 0x26e00000, 0x78b00000, 0x2f980000, 0xab140000, 0xeeee0000, 0x54fd0000,
+    0x1db48000, 0x6e71c000, 0xff34a000, 0x18b1d000, 0x9fd48800, 0x4301c400, 0xd2cc8e00, 0xded5d900,
+    0xfcc2b780, 0x8198c5c0, 0xb46e2920, 0x063d1570, 0x83548098, 0x32c1db5c, 0x2eac837e, 0xb4a5f599,
+    0xedba96c5, 0x263ce9a6, 0x93182e28, 0x8ad433fa, 0x0a8e16c5, 0x4a8d29a6, 0xeacc8e28, 0xbad5e3fa,
+    0xa2c29ec5, 0xd698eda6, 0x30ee0028, 0x43fd3afa, 0x3934a945, 0x7bb1e866, 0x5d548908, 0x25c1ff8a,
+    0x0a2ca1dd, 0xa165f73a, 0x4fda8476, 0x1b4cd313,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xb8000000, 0x04000000, 0x76000000, 0x5f000000,
+    0xf1800000, 0x59c00000, 0x75a00000, 0xefd00000, 0xca880000, 0xae540000, 0x2b160000, 0xdfcd0000,
+    0xb2d28000, 0x4a124000, 0xed52a000, 0x38d27000, 0x47729800, 0x65c25c00, 0xc7dab600, 0x26865d00,
+    0xd464ab80, 0xbe0f4ac0, 0x030817e0, 0x33941cb0, 0xc8b62a88, 0xdf1d1744, 0x31daa02a, 0xb98650d1,
+    0xc5e4a4ef, 0x57cf5622, 0xcea82ddb, 0xd8443f66, 0x743e24ef, 0x2e491622, 0xeb4c8ddb, 0x3f8b4f66,
+    0x02963cef, 0xf20d0a22, 0xe9729bdb, 0x4ec26266, 0x185a8f6f, 0x94465ce2, 0x9e449a3b, 0x531f53d6,
+    0x3ba01667, 0x74d01d66, 0xad083bf1, 0x189432b7,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x78000000, 0xb4000000, 0x32000000, 0x4d000000,
+    0x2a800000, 0xdec00000, 0x8ca00000, 0x51f00000, 0x33680000, 0x21940000, 0x2b6e0000, 0x05870000,
+    0x51498000, 0x8488c000, 0xfde9a000, 0x2578f000, 0x16818800, 0x80ecfc00, 0x77ef9600, 0x7c6bd100,
+    0x3e261a80, 0x6b230240, 0x65efb020, 0xc16bc5f0, 0xcca61b08, 0x31e32d8c, 0xa34f81d2, 0x699bf4e9,
+    0xe74e1189, 0x83b737c8, 0x2e0195fb, 0xe32ce31a, 0x09cf9989, 0x775bcbc8, 0xcbee03fb, 0xe247321a,
+    0x65698309, 0x76b8c988, 0x10a1b3db, 0x3fdcf7ea, 0xb0279801, 0xb80fe404, 0x14203209, 0x02300303,
+    0x35480988, 0x9ea413cc, 0xeca607f2, 0xc1e31019,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0xb8000000, 0xac000000, 0xea000000, 0x51000000,
+    0x49800000, 0xddc00000, 0x5ba00000, 0x40f00000, 0x28480000, 0x04540000, 0x2e620000, 0xdf470000,
+    0xe6d78000, 0x836f4000, 0x74f7a000, 0xde5f7000, 0xa71f8800, 0xeafb6c00, 0x1935a600, 0x9de84700,
+    0x3b801c80, 0x90c01340, 0xe0202ee0, 0x10301430, 0x68681008, 0x6464180c, 0xfe0a341a, 0x17233219,
+    0xf2dd8502, 0xc54c5188, 0xcfaa0ed7, 0xc6d33623, 0x33158d02, 0x6cd87d88, 0x026808d7, 0xf5640123,
+    0x178a1982, 0xbae302c8, 0x117d8037, 0x29bc5213, 0x0de2150a, 0x93870984, 0x54f79acd, 0x6e5f743a,
+    0xbf1f8000, 0x36fb4000, 0x4b35a000, 0x60e87000,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xa8000000, 0xa4000000, 0x82000000, 0x73000000,
+    0x03800000, 0x3fc00000, 0x91a00000, 0x54d00000, 0x7e680000, 0x3d7c0000, 0xb6ce0000, 0xbd3f0000,
+    0x769e8000, 0x5d1b4000, 0x06bea000, 0xf50b7000, 0xa2f69800, 0x77676c00, 0xd1f0b200, 0x74f44500,
+    0xee481280, 0xe56c19c0, 0xba863260, 0x9b532630, 0x8798ae08, 0x2d886f04, 0x3a863b92, 0x5b53395b,
+    0x6798aea1, 0x5d8866d4, 0x928601e6, 0xff533b62, 0xe598b6a1, 0x2e884ad4, 0x910613e6, 0xc0930e62,
+    0x7438bc21, 0x7a587f14, 0xef6e3386, 0xfdef1d52, 0xc2f698a9, 0xc76765d0, 0x99f08874, 0xa0f44739,
+    0xc4480a80, 0x326c35c0, 0x3b062060, 0xd7931330,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x68000000, 0xec000000, 0xe2000000, 0x57000000,
+    0x21800000, 0xf3c00000, 0x7ce00000, 0x29700000, 0x6cd80000, 0x210c0000, 0x708a0000, 0x7b630000,
+    0xa3e48000, 0x54ae4000, 0xe53ca000, 0x5ea25000, 0x1e36a800, 0xbd016400, 0x6ab23200, 0x881f1f00,
+    0x5c36aa80, 0x7a015ac0, 0x03320ae0, 0x47df0230, 0xaad6a7d8, 0xe871576c, 0xac6a366e, 0xc21315ef,
+    0x873ca8ac, 0x49a27d07, 0x1fb69d8d, 0x9ec15440, 0x7e52022c, 0x4d6f27c7, 0xd2ee976d, 0x0c0d5670,
+    0x523825f4, 0xcf7c30ab, 0x75d20103, 0x95af139f, 0x258ea558, 0xbdbd69ac, 0xe9800e8e, 0x8fc008df,
+    0xd6e025f4, 0x427030ab, 0xc7580103, 0x69cc139f,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0xa8000000, 0x44000000, 0x8e000000, 0x21000000,
+    0x15800000, 0xe3c00000, 0x4ea00000, 0xa1700000, 0xd5f80000, 0x438c0000, 0xde9e0000, 0x09410000,
+    0x918d8000, 0xcdd14000, 0xfff5a000, 0x1c9d7000, 0x724b8800, 0x836c5400, 0x5e9e2200, 0xc9410500,
+    0x318da180, 0x5dd17d40, 0x57f5ace0, 0x589d51b0, 0xfc4bbd48, 0xa26c4cec, 0x4b1e01b6, 0x2a81054f,
+    0x7f2db0f1, 0xfca147af, 0x820d8a5a, 0x1b117077, 0x22d59171, 0xab2d7aef, 0xda9386ba, 0xe75051c7,
+    0x80d82439, 0xe03c2203, 0xf046050c, 0x987d2188, 0x7c4bbd48, 0x626c4cec, 0xeb1e01b6, 0xba81054f,
+    0xd72db0f1, 0xb8a147af, 0x0c0d8a5a, 0x3a117077,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0xc8000000, 0x54000000, 0x46000000, 0xb9000000,
+    0xfa800000, 0x68c00000, 0xd7e00000, 0x34300000, 0xb6780000, 0xc13c0000, 0x76c60000, 0xb2ad0000,
+    0x7cb58000, 0x31b2c000, 0x9d2da000, 0x84bed000, 0xfd938800, 0xe72fd400, 0x3fe03a00, 0x30300100,
+    0x98780b80, 0x3c3c3f40, 0x02461d20, 0x376d2170, 0x17d59ad8, 0xd442f97c, 0x063586c6, 0x1972f773,
+    0xeacd8dd7, 0xa08ec5e8, 0x83eb9502, 0x7213d188, 0x0f260e57, 0x3b9d2ea8, 0x1e4db222, 0x654ef1f8,
+    0x488b9f0f, 0x87e3e894, 0x5c3e29c4, 0xf25127fb, 0x4f138800, 0x9befd400, 0x0e003a00, 0xad000100,
+    0x1c800b80, 0xc1c03f40, 0xe5601d20, 0x08f02170,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x18000000, 0xf4000000, 0x3e000000, 0x25000000,
+    0xb6800000, 0xccc00000, 0xafe00000, 0x98700000, 0xb4080000, 0x1e240000, 0x554e0000, 0xaee90000,
+    0x38b78000, 0x91b6c000, 0xbd5fa000, 0x02e2d000, 0xd299a800, 0xfaefcc00, 0x36802e00, 0x8cc03100,
+    0x8fe00180, 0xe8702840, 0xac082420, 0xea240770, 0x6b4e09d8, 0x8be91c6c, 0x8e37ab5a, 0x5d76d2a5,
+    0x12bfb3a9, 0x9a92da2f, 0x66919e7c, 0xe4cbc45e, 0x63ce1a29, 0x22293e6f, 0xb757945c, 0x79c6f22e,
+    0x11579271, 0xe8c6ca43, 0xb9d7bb26, 0x7106f7fb, 0xb8b78000, 0xd1b6c000, 0x9d5fa000, 0x72e2d000,
+    0xca99a800, 0x0eefcc00, 0x08802e00, 0xa9c03100,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xf8000000, 0x0c000000, 0x32000000, 0xbb000000,
+    0xc2800000, 0x2cc00000, 0xada00000, 0x40100000, 0xa0480000, 0x50540000, 0xc8620000, 0xf4630000,
+    0x3e428000, 0x8925c000, 0x79aaa000, 0xee61f000, 0x8100b800, 0xed96fc00, 0xe0000600, 0xf0003f00,
+    0x98002a80, 0x3c0021c0, 0xca000220, 0xb70026f0, 0xf0801748, 0x97c035f4, 0x6f2033da, 0x6cd00f21,
+    0x0de83162, 0x10443909, 0x682a159a, 0xa4370b44, 0xf620a3e2, 0x7d46e4c9, 0x47e811ba, 0x674412b4,
+    0xf8aa1e2a, 0x03f730fd, 0x61008040, 0x1d96cb65, 0x78000000, 0xcc000000, 0x52000000, 0x8b000000,
+    0x3a800000, 0x20c00000, 0x9fa00000, 0xfb100000,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xf8000000, 0x5c000000, 0xf6000000, 0x33000000,
+    0xa8800000, 0xbac00000, 0x03a00000, 0xfe500000, 0x57280000, 0xe2b40000, 0x0faa0000, 0x60090000,
+    0xb05c8000, 0x187ec000, 0x2c54a000, 0x0e5af000, 0x6f769800, 0x5eb7ec00, 0x89a82600, 0xab743b00,
+    0x448a3b80, 0x549907c0, 0x1cd4a9a0, 0x589af570, 0x82d6b748, 0xbfe7ede4, 0x78002702, 0x9c000589,
+    0x160010c8, 0x4300143b, 0x508039b7, 0xe6c03d70, 0xf5a03348, 0xcd503ffb, 0xffa81617, 0x58740300,
+    0x0c0a2780, 0x9e5939df, 0xe774beb5, 0xfacac8f9, 0x23febb80, 0x6e53c7c0, 0xdf2a09a0, 0x46c90570,
+    0xa5fcaf48, 0xa52ec1e4, 0x2bfca102, 0x0a2ece89,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xa8000000, 0x04000000, 0xfe000000, 0x0b000000,
+    0xf0800000, 0x52c00000, 0x7be00000, 0xe2100000, 0xc9380000, 0x49cc0000, 0x837e0000, 0x84890000,
+    0x34c88000, 0x0c8ac000, 0x4090a000, 0x6a96d000, 0x37b69800, 0xc803e400, 0x94583200, 0xb61c2500,
+    0xdf262d80, 0xa69537c0, 0x5deea9a0, 0x751fd770, 0xbbfe3d58, 0x42493ef4, 0xf928a896, 0x319aca47,
+    0x2f288669, 0x7e9adad5, 0xc1a8b823, 0xf75ad6b3, 0xe2c8b3e9, 0x438ac915, 0xae108383, 0xe356f4c3,
+    0x14d6bb31, 0x7cd3e421, 0xd88010b5, 0x16c01cf4, 0x65e03580, 0x391013c0, 0x91b83ba0, 0x1f0c2270,
+    0x069e08d8, 0x6d992d34, 0x0d709336, 0x1786e837,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x48000000, 0xc4000000, 0x02000000, 0xab000000,
+    0xcc800000, 0x87c00000, 0xbb600000, 0x14900000, 0xdba80000, 0x851c0000, 0x31d60000, 0x7a4b0000,
+    0x67088000, 0x6ac5c000, 0x9edea000, 0x948ed000, 0x9bd63800, 0xe54b3c00, 0x81888a00, 0x3205dd00,
+    0xa33eb280, 0x68decbc0, 0x359e0ba0, 0x58073730, 0x1c1697f8, 0x5e02f9d4, 0x95282ab2, 0xe9dc2fa3,
+    0x2636113f, 0x591b18e5, 0x4fc09a4f, 0x3f49d1f7, 0x76a086c7, 0xc0d9e131, 0x0188b0fd, 0x7205fe54,
+    0xc33e97f8, 0xd8def9d4, 0x7d9e2ab2, 0x9c072fa3, 0x1e16913f, 0xf502d8e5, 0x59a83a4f, 0x6e1c01f7,
+    0x9d563ec7, 0x4d8b1d31, 0x94689afd, 0xba55f354,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x88000000, 0x64000000, 0xa6000000, 0x31000000,
+    0x7c800000, 0x7dc00000, 0x39600000, 0x58900000, 0xbbb80000, 0x986c0000, 0xac660000, 0xa2190000,
+    0x07478000, 0xc5d0c000, 0x6521a000, 0xe2c9d000, 0x50e63800, 0x9fd93400, 0x5e27ba00, 0x0d40fd00,
+    0x5699ab80, 0x1ea5c540, 0x5a800ea0, 0x0cc02730, 0x25e02b78, 0xb5503454, 0x0ad82b36, 0xa4fc257f,
+    0xb1de3357, 0x0b751dad, 0xd7a1bbb0, 0x1a09d0a6, 0x5b06182f, 0x7f8929f9, 0x8e7f9086, 0xe57cf5d9,
+    0xa2a7ab78, 0x3080f454, 0x0ff98b36, 0xd635f57f, 0x69380b57, 0xf0ac29ad, 0x2f8601b0, 0x26492da6,
+    0x711fb3af, 0x1cececb9, 0xed9f9e26, 0xb12cd2e9,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xb8000000, 0xcc000000, 0x2a000000, 0xf5000000,
+    0xfa800000, 0x9bc00000, 0xd7200000, 0x4bd00000, 0xcf380000, 0x77f40000, 0xcd4a0000, 0xf6db0000,
+    0xd1c38000, 0xb209c000, 0x0929a000, 0x98c2f000, 0x8af23800, 0x73ef1400, 0xc329b600, 0x3dc2d700,
+    0xa8720480, 0xb42f3e40, 0x8609ac20, 0x4f12ffb0, 0xb7ca0568, 0xad1b3704, 0x66e39486, 0x69d9d649,
+    0x7e11b03c, 0x2336c5bb, 0x6db8006a, 0x70342a80, 0xe86a0d54, 0x140b26bf, 0x767b82ec, 0x673ddbc9,
+    0xc3c381e8, 0x4b09c944, 0xb9a998a6, 0x6602d9f9, 0x1f520d54, 0x6fff26bf, 0xf13182ec, 0xf4e6dbc9,
+    0x508001e8, 0xaec00944, 0x4da038a6, 0x401029f9,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x28000000, 0xec000000, 0x0a000000, 0x0b000000,
+    0x2d800000, 0x72c00000, 0x51200000, 0xaed00000, 0xc3780000, 0x11fc0000, 0x30920000, 0xa65b0000,
+    0xa1448000, 0x56dd4000, 0x6776a000, 0xe7967000, 0x19ea3800, 0x6ca73400, 0xf4569200, 0x7e467f00,
+    0xb5122880, 0xf89b09c0, 0x9a6491e0, 0xe30d5a70, 0xa18eb528, 0x68aa7c04, 0x72581e16, 0x6f2c190b,
+    0xbbea259a, 0x4ba70e59, 0xb3d6b8bd, 0x17866e55, 0xe1b228b2, 0xc88b065d, 0x023c94ab, 0x5721785e,
+    0x7fe49da8, 0xadcd75c4, 0xb2ae8ff6, 0x317a437b, 0xbea010b2, 0xeb10325d, 0xfdd806ab, 0x3aec075e,
+    0xad4a3528, 0x8cb73c04, 0x240ebe16, 0x366a690b,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0xe8000000, 0xfc000000, 0x76000000, 0x63000000,
+    0x6b800000, 0x1ec00000, 0xa6600000, 0xcb700000, 0x37f80000, 0x389c0000, 0x2d6a0000, 0x5cb50000,
+    0x5f328000, 0xfdf84000, 0x8da0a000, 0x75d15000, 0xc1f80800, 0x1b9c0400, 0xe6ea1200, 0x12752b00,
+    0x1152a780, 0xca8867c0, 0xcc58a760, 0x2e4d4f70, 0x87123b78, 0x59e9316c, 0x1fb8b666, 0x24fd57fb,
+    0xab0a0124, 0x87c52eca, 0x20cab3f6, 0x69646323, 0x3eca9ddc, 0xb6647866, 0x834aa2f0, 0x9ba47ba8,
+    0xa6aaa780, 0xb21467c0, 0x4132a760, 0x22f84f70, 0x3020bb78, 0x5811716c, 0xe4181666, 0x322c07fb,
+    0x01720924, 0x82992aca, 0x6040a1f6, 0xb0614823,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x78000000, 0x5c000000, 0xa2000000, 0x4d000000,
+    0xc8800000, 0x77c00000, 0x6a600000, 0xb9700000, 0xee980000, 0x54b40000, 0x35f20000, 0x174f0000,
+    0x99cf8000, 0xbd26c000, 0xc0a5a000, 0x63ddd000, 0x7c180800, 0x72743400, 0x95123600, 0x04ff3300,
+    0xadb7b680, 0x7b22c440, 0xd3afbaa0, 0xd456deb0, 0xf63da0b8, 0xfb69c7ac, 0x93ea373e, 0x743b1871,
+    0x665da85a, 0x8319f0be, 0xcff20faf, 0xd64f232a, 0x2b4fbe62, 0x4be6f352, 0xb8458231, 0xbc6de5eb,
+    0x92603680, 0xa5700440, 0xec981aa0, 0x89b40eb0, 0x857228b8, 0x3c8f33ac, 0x51afa13e, 0x4956fb71,
+    0xe6bd96da, 0x40a9c0fe, 0x238a230f, 0xdc4b1e9a,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x48000000, 0xbc000000, 0x06000000, 0x83000000,
+    0x45800000, 0x10c00000, 0xee600000, 0x2f100000, 0x5ba80000, 0x57fc0000, 0x599a0000, 0x068d0000,
+    0x750a8000, 0xaecf4000, 0xb938a000, 0x80be5000, 0xb6283800, 0x8b3c1c00, 0x99fa0a00, 0x269d2300,
+    0x25229380, 0xd6f34fc0, 0x4d42b0e0, 0x3ae374f0, 0x336a92f8, 0x4ddf53f4, 0xcc908772, 0xd8426d3b,
+    0xe4322b0c, 0xa271079c, 0x411091c4, 0x34826de0, 0x6c522a74, 0x3e611ba8, 0x1738a656, 0xcfbe742b,
+    0xdda81380, 0x94fc0fc0, 0x7c1a10e0, 0x264d24f0, 0xd36aaaf8, 0x3ddf4ff4, 0xe4908d72, 0x54424e3b,
+    0xaa32388c, 0x9d71085c, 0x02908124, 0xa7424910,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xc8000000, 0x14000000, 0x22000000, 0xf9000000,
+    0x92800000, 0xa1c00000, 0xae200000, 0xd7300000, 0x85880000, 0x847c0000, 0x9a0e0000, 0x854b0000,
+    0x14b58000, 0xb291c000, 0xd1b3a000, 0xc666f000, 0x73280800, 0x6f8c1c00, 0x69261a00, 0x2ac72900,
+    0xdd93ba80, 0x2856fb40, 0xc4200c20, 0xfa302630, 0x95082728, 0x6cbc31bc, 0x6eae38ea, 0xe7bb11d3,
+    0x1d1d8f61, 0x18ddfe0d, 0x5cb5a70e, 0x6691d586, 0x53b392c9, 0x8f66f4f1, 0x29a833c4, 0xda4c1265,
+    0xe5063280, 0x04f72740, 0xca9bb620, 0x0deaff30, 0xf00e15a8, 0xa84b16fc, 0x04358eca, 0x5a51eee3,
+    0x25139ac9, 0xa496e8f1, 0x7a8029c4, 0xc5c03b65,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x18000000, 0xec000000, 0x36000000, 0x0d000000,
+    0xb9800000, 0xeac00000, 0xea600000, 0x83500000, 0x48880000, 0x8d340000, 0xf9da0000, 0x0add0000,
+    0x1a058000, 0x9b064000, 0xa4d7a000, 0xbb2f5000, 0xf4e81800, 0xb3642c00, 0xf0d23e00, 0x71290700,
+    0x27bf8b80, 0xf38b7840, 0x79da03e0, 0x4add29f0, 0xfa05acf8, 0x6b066664, 0xbcd78eba, 0x572f6899,
+    0xc2e839d4, 0xbe640eba, 0x49522897, 0x9be919c6, 0xcddf9eac, 0x70db509e, 0x315205cd, 0xc7e908af,
+    0x03df9380, 0x61db5440, 0xa6d23de0, 0xcc292ef0, 0x663fa778, 0x054b5e24, 0xbdba2d5a, 0x288d1169,
+    0x3d0d8d2c, 0x01f244de, 0x16ed982d, 0x3462765f,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0xb8000000, 0xa4000000, 0x62000000, 0xed000000,
+    0xad800000, 0x46c00000, 0x44600000, 0x92100000, 0x35680000, 0xb9840000, 0x9cce0000, 0x0d030000,
+    0x5dd78000, 0x9e884000, 0x5011a000, 0x481f5000, 0x7c603800, 0x76103c00, 0x37683600, 0xe4840500,
+    0x894e3680, 0xefc31240, 0x7bb7b3e0, 0xe1986af0, 0xc8f99a78, 0xb75b6534, 0xa4ce2442, 0xe90336e5,
+    0x5fd79c69, 0xc3886821, 0x4591a6c6, 0xaadf66a2, 0x5a000891, 0x09002355, 0xaf800764, 0x1bc03fb7,
+    0x51e03800, 0x70d03c00, 0x13083600, 0xc6940500, 0x04263680, 0xf2471240, 0x8579b3e0, 0x019b6af0,
+    0x38ae1a78, 0x6f132534, 0xb0bf8442, 0x330c66e5,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x58000000, 0xf4000000, 0xb6000000, 0x41000000,
+    0x42800000, 0x31c00000, 0x1c200000, 0xfa700000, 0xd3680000, 0x1dd40000, 0x9e160000, 0x2d410000,
+    0x20eb8000, 0x36b9c000, 0x47b5a000, 0x7d5cf000, 0x48a02800, 0xbab00c00, 0xf5c81e00, 0xd2640100,
+    0xbf5e3b80, 0x7fe52d40, 0x9915bd20, 0x76ecdc30, 0xa7e837a8, 0xad1402ec, 0xe0b63bd2, 0x16f105e9,
+    0xb7a3855f, 0x251df125, 0xbccba23f, 0x0cc9e6be, 0xb4dda177, 0x9088d289, 0x8eb63acd, 0x63f13e67,
+    0x6323a800, 0xa5ddcc00, 0xba6bbe00, 0x3379f100, 0xcd959380, 0x362ce140, 0x81480320, 0x62a42d30,
+    0xc1fe2428, 0x445523ac, 0x0e5d98f2, 0x6548d8d9,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x98000000, 0x64000000, 0x6a000000, 0x65000000,
+    0xd6800000, 0xf5c00000, 0xb0200000, 0xc8300000, 0x5c180000, 0x9e040000, 0x977e0000, 0xd7870000,
+    0x49478000, 0x20b9c000, 0xae81a000, 0x61caf000, 0x72000800, 0xc1001400, 0x1c800e00, 0x00c03d00,
+    0xfea03c80, 0x59f00040, 0x86382f20, 0x33342430, 0x1de619a8, 0xbc430f7c, 0x6e19bb0e, 0x3f0ed58d,
+    0xbbde12d9, 0xdf772260, 0x4bff9f8e, 0x774dd9cf, 0x27c7bff1, 0xe179f95c, 0x4ca1a5a0, 0x38fae572,
+    0x0a980000, 0xabc40000, 0x875e0000, 0x8fb70000, 0x8d5f8000, 0xdabdc000, 0x53ffa000, 0xd34df000,
+    0xedc78800, 0x1479d400, 0x0221ae00, 0xa93acd00,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x88000000, 0x6c000000, 0x06000000, 0xd3000000,
+    0x76800000, 0xf3c00000, 0x70200000, 0x48300000, 0x4c080000, 0xf63c0000, 0x5b220000, 0x1a9b0000,
+    0xf5c18000, 0xa314c000, 0x3eada000, 0xbff4f000, 0x86000800, 0x13000c00, 0x56800200, 0x03c00f00,
+    0xf8200880, 0x243006c0, 0x4a080060, 0x253c0d30, 0x2da20768, 0xe95b0f3c, 0x85e18702, 0xeb24c483,
+    0x72a5a4c0, 0x49c8ff63, 0xdd220db2, 0x099b0da9, 0xa3418d5c, 0xa0d4c531, 0xc68dab6a, 0x9bc4fd3f,
+    0xcc080000, 0x363c0000, 0x7b220000, 0xea9b0000, 0x7dc18000, 0xcf14c000, 0x38ada000, 0x6cf4f000,
+    0xf0800800, 0xe0c00c00, 0x26a00200, 0x4bf00f00,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x98000000, 0x44000000, 0x0e000000, 0xe5000000,
+    0xc7800000, 0xa3400000, 0xc8200000, 0xbc100000, 0xfa080000, 0xe3240000, 0xfea60000, 0x2ed10000,
+    0x80638000, 0x56894000, 0x22e9e000, 0x7264d000, 0x69800800, 0x96400400, 0xb7a00200, 0xcb500900,
+    0xa4280980, 0xfe340440, 0xcd2e00e0, 0x8bb50e50, 0x71658c78, 0x67084a34, 0x90a26c82, 0x7bd99bc1,
+    0x4fc7e7a0, 0x29d1da32, 0xa0e585ea, 0x25484fed, 0xb1026386, 0x11899828, 0x226fe34e, 0x91a5d936,
+    0x626b8000, 0xb1ad4000, 0xf24fe000, 0x29b5d000, 0xb6638800, 0x27894400, 0x5369e200, 0xe024d900,
+    0xf0200180, 0x28100040, 0x4c0802e0, 0xd2240750,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x98000000, 0x8c000000, 0xee000000, 0x53000000,
+    0xc3800000, 0x45c00000, 0xac200000, 0x7e300000, 0x9b180000, 0xe78c0000, 0xbfe60000, 0x9d030000,
+    0x008b8000, 0x4e4cc000, 0xcddce000, 0x28277000, 0xd4200800, 0x02300c00, 0x8d180600, 0x088c0300,
+    0x0a660980, 0x07c308c0, 0x812b8ee0, 0x26bcc530, 0x3964ec38, 0xf45b745c, 0x5cfe02c2, 0x068f0be3,
+    0xa96d8fb1, 0x3c4fcd78, 0x78d7627e, 0xfcabb110, 0x985ce6e8, 0x42e77dd4, 0xad800ee5, 0xd6c009de,
+    0x0fa00800, 0x0bf00c00, 0xaf380600, 0x15bc0300, 0xcafe0980, 0x298f08c0, 0x7ced8ee0, 0x968fc530,
+    0x61776c38, 0x185bb45c, 0x82e4e2c2, 0xcd9b7be3,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xe8000000, 0xec000000, 0x3a000000, 0x37000000,
+    0xb3800000, 0xf4400000, 0xdc200000, 0x42300000, 0xd3380000, 0x35940000, 0x117a0000, 0xb49b0000,
+    0x3ffe8000, 0xcc55c000, 0x1828e000, 0x740f1000, 0x8e200800, 0x59300c00, 0xbab80e00, 0xa6d40500,
+    0x96da0e80, 0xeeeb0ec0, 0x0ae683a0, 0x8cf1c370, 0x69eaeb38, 0x01401f44, 0x7c8485c2, 0x63cec823,
+    0x4e566333, 0x2b1ad659, 0x11a8e797, 0x774f1989, 0x0180065f, 0xbf4006b5, 0x5da00a3a, 0x3d7003c4,
+    0x6e980800, 0x58e40c00, 0x97e20e00, 0x007f0500, 0x921c8e80, 0xfb2acec0, 0x39b463a0, 0x7b65d370,
+    0x6bb46338, 0x6065d344, 0x02346bc2, 0xf325dd23,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xc8000000, 0x0c000000, 0xb2000000, 0xeb000000,
+    0x51800000, 0x4cc00000, 0xde200000, 0x49300000, 0x92b80000, 0xc1740000, 0xe8d20000, 0x70130000,
+    0x38248000, 0xf436c000, 0xa6126000, 0x9d223000, 0x04980800, 0x44440c00, 0x286a0e00, 0x8a670d00,
+    0x49768c80, 0xc4e5c0c0, 0xf216eb20, 0xcb24feb0, 0x61b26d18, 0x54d238cc, 0x1a000be2, 0xf7000593,
+    0xcb800bab, 0x7bc001d7, 0xf5a0092d, 0xe2f00971, 0xaf180dba, 0x2f84053f, 0xf5ca029b, 0xb497098d,
+    0x9c6e8480, 0x0c61ccc0, 0xe45ce520, 0xd873f3b0, 0x727ce198, 0x5d43f80c, 0xb2c4e0c2, 0xa737fb23,
+    0xc396e6b3, 0x97e4f91b, 0x979262cf, 0xc1e23ce2,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xa8000000, 0x34000000, 0x56000000, 0xed000000,
+    0x0a800000, 0xe0400000, 0x92200000, 0x63100000, 0x4b980000, 0x70d40000, 0x554a0000, 0x74bd0000,
+    0x795d8000, 0xae974000, 0x3e6ca000, 0x7b271000, 0xc7b80800, 0xdac40400, 0x8a520600, 0x6d290500,
+    0x4ab78a80, 0x807a4340, 0xc2092560, 0xcb345ed0, 0x7fa6a8a8, 0x26da1a04, 0xb8458722, 0x7e034731,
+    0x9906a839, 0x3c8a114d, 0x5d7d82b4, 0x30874edb, 0xb774a45d, 0x8fb31f3d, 0xfed208ec, 0x146908c7,
+    0xe4178280, 0xbe2a4740, 0xb9312360, 0x0cb05bd0, 0xa554a228, 0xaca31944, 0xd54a0242, 0x34bd09e1,
+    0x195d8891, 0xfe974f49, 0x966ca396, 0x4f271cea,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xe8000000, 0xac000000, 0x82000000, 0xd9000000,
+    0x9d800000, 0x99400000, 0x59200000, 0x5db00000, 0x79780000, 0x09340000, 0xb58a0000, 0xd5530000,
+    0x8b148000, 0x6cbc4000, 0x48f46000, 0x1272d000, 0x358a0800, 0x15530c00, 0x6b148e00, 0x3cbc4500,
+    0xa0f46e80, 0xbe72dac0, 0xb78a0020, 0xcc530190, 0xf69487d8, 0xa5fc4c94, 0xf9d46b12, 0xe3c2df1b,
+    0xcef207b7, 0xc5670103, 0x431e8c80, 0x70af41c1, 0x72c0e3a3, 0x8f7e99d0, 0x86066338, 0xd715d024,
+    0x769487d8, 0x65fc4c94, 0x19d46b12, 0xb3c2df1b, 0x26f207b7, 0x69670103, 0xc11e8c80, 0xa9af41c1,
+    0xef40e3a3, 0x163e99d0, 0xdf266338, 0x8aa5d024,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0xc8000000, 0xfc000000, 0x7a000000, 0x87000000,
+    0xf1800000, 0x92400000, 0x17200000, 0x99900000, 0xde780000, 0xf5340000, 0x2aa20000, 0xa9c70000,
+    0x9a6a8000, 0x4b23c000, 0x53ab6000, 0xc1561000, 0x30a20800, 0x3ec70400, 0x43ea8e00, 0x7563c500,
+    0xf68b6c80, 0x23c61fc0, 0x655a0fa0, 0xdeb30c70, 0x8fe88118, 0xd774cc24, 0xa5b9edf2, 0x0441d659,
+    0xc22b6247, 0x43161323, 0x0f820bb2, 0x0b5702b8, 0x2f928a54, 0xfb57c7eb, 0x57a963fd, 0x9f4114f6,
+    0x19908118, 0x9e40cc24, 0x151bedf2, 0x7a86d659, 0x61c1e247, 0x6675d323, 0x31096bb2, 0xd49112b8,
+    0x30c88254, 0xa2e4c3eb, 0x29c1edfd, 0xda75d1f6,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0xc8000000, 0x0c000000, 0x76000000, 0x63000000,
+    0x31800000, 0x11400000, 0x75200000, 0x82b00000, 0x08f80000, 0x78640000, 0x49a20000, 0xe54b0000,
+    0x37018000, 0x439ec000, 0xe8456000, 0x41bed000, 0x095a0800, 0x512f0c00, 0xe8a38e00, 0xd5d5c100,
+    0x26c4ec80, 0x1f6010c0, 0xe23f6f60, 0xf121da30, 0xd8818518, 0xeddecc14, 0xf2e565d2, 0xad4ed9eb,
+    0xfb02036f, 0xd5bb0d76, 0x9b598ee2, 0xb80ac870, 0x143f6bba, 0x5221ddc6, 0x09018639, 0xec9ec186,
+    0x4fc56998, 0x23fedcd4, 0x85fa0ab2, 0xcedf03db, 0xe37b8677, 0x4c01c162, 0x561eeb30, 0x930f119b,
+    0xe9bce8d5, 0xd54410b0, 0x0f3d68db, 0x979ad9f6,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x28000000, 0xc4000000, 0x82000000, 0xb5000000,
+    0x68800000, 0x47400000, 0x7f200000, 0x49900000, 0x95e80000, 0xd14c0000, 0x303a0000, 0x68090000,
+    0x64248000, 0x320b4000, 0x9d212000, 0xac805000, 0xc5520800, 0xca050400, 0x213e8a00, 0xd2924b00,
+    0xae6da280, 0x79871c40, 0xfde92020, 0xb55c5f50, 0x02000488, 0xf5000b74, 0xc8800f72, 0xf74003d9,
+    0x572003fe, 0x8d900a04, 0x17e80dab, 0x644c09a4, 0x58ba0738, 0x2f4903cd, 0x1b04877e, 0x7b9b4345,
+    0x08c92608, 0x7dcc5734, 0xf5680f52, 0xa20c0c89, 0x451a0f76, 0xe0990570, 0x334c88d9, 0xd507417d,
+    0x38bb2646, 0x7f595589, 0x233e8af5, 0x279245b1,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0xf8000000, 0x1c000000, 0xb2000000, 0x9b000000,
+    0xc3800000, 0x22c00000, 0x55200000, 0x9a900000, 0xb2680000, 0xc0440000, 0xbb4e0000, 0x48df0000,
+    0x02108000, 0xb304c000, 0x8785e000, 0x1cf93000, 0x84060800, 0xde0b0400, 0xe1368a00, 0x2c9fc900,
+    0xb75b6f80, 0x42e2f1c0, 0x25336320, 0x52a6fdb0, 0xc67d6e38, 0x9679ff2c, 0x8e6de8d2, 0xa27d3569,
+    0x78680d86, 0x07440974, 0x6ace092c, 0x611f07d1, 0x6cb08deb, 0x1754c8c5, 0xd2cde114, 0xdd2d3ffe,
+    0x4ea009b8, 0x74500aec, 0x0d4801f2, 0x4dd401d9, 0x80a60c3e, 0x2d5b0798, 0x9dfe82de, 0xd88bcf08,
+    0xa15d6e55, 0xd7e9fe9d, 0x5f85e0ea, 0xd0f93d46,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xd8000000, 0x04000000, 0x66000000, 0x63000000,
+    0xee800000, 0xbec00000, 0x19200000, 0x0f900000, 0x25680000, 0x22540000, 0x9ac60000, 0xef190000,
+    0x14958000, 0x1ff2c000, 0x2298a000, 0xe4d13000, 0x680e0800, 0xec1d0400, 0xca1b8a00, 0x092fcd00,
+    0x37a32d80, 0x516ef040, 0x4c452e60, 0x25e7f230, 0x63b8ace8, 0xaf4132ec, 0x8b660e12, 0x7d4909b9,
+    0x665d83b6, 0x5cf6c955, 0x5c16aca4, 0x220c336d, 0xa5358053, 0x5da2c8da, 0x8850a505, 0xa3d53a7d,
+    0x70800968, 0x49c006ac, 0xe9a00a72, 0x06500689, 0x6cc80ade, 0xf4040ff9, 0x2e0e06d6, 0x1f1d05e4,
+    0x5c9b828d, 0x63efc323, 0x908329d3, 0x39fef299,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x48000000, 0x1c000000, 0x76000000, 0x47000000,
+    0x46800000, 0x12400000, 0x35200000, 0xf3900000, 0xa1c80000, 0xb4cc0000, 0xf7620000, 0x1ebf0000,
+    0xb6698000, 0x3707c000, 0x6eb8a000, 0x9e441000, 0xbb0a0800, 0xe0a30400, 0x8d638200, 0x97a4cb00,
+    0xc3db2480, 0xb9e0d1c0, 0x30d12f60, 0x4543d070, 0xcbb2ae68, 0x95e71e24, 0x4ee98dd2, 0x3e47c1f9,
+    0x4b18abfc, 0x889415fc, 0x216200fe, 0xa9bf017f, 0x98e9893c, 0x8947c21d, 0x6598af4d, 0x36d41d75,
+    0x2a4202e8, 0x012f0be4, 0x09a180b2, 0x68cbca89, 0xe15aa914, 0xc9bb1e18, 0x08c3804c, 0x7174cbf6,
+    0x31b32028, 0x5cfcdc05, 0x1b5b2f01, 0x00a0d683,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x58000000, 0xa4000000, 0x26000000, 0xa7000000,
+    0xe6800000, 0x53400000, 0x76a00000, 0xfb500000, 0x2a880000, 0xc97c0000, 0xf39e0000, 0x2cd50000,
+    0x60578000, 0xae1cc000, 0x0b1e2000, 0x2c901000, 0x9e578800, 0xed1cc400, 0x6b9e2a00, 0x68d01b00,
+    0x56778d80, 0xe10cce40, 0x11b62860, 0xfdfc1170, 0x69e183e8, 0x57e5cb74, 0xf4dfaf0a, 0x8465dec5,
+    0x28202140, 0x1c1517e3, 0x92080833, 0x693c0808, 0xbdbe0545, 0x37c50202, 0xa4ff8f02, 0xcc70cf81,
+    0x64282140, 0xc62917e3, 0xb7360833, 0x0eb90808, 0xaf618545, 0xf4a5c202, 0x7a7faf02, 0x6b35df81,
+    0x7ca82940, 0xd66913e3, 0xa1160233, 0xb1a90308,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x68000000, 0x04000000, 0xde000000, 0xbf000000,
+    0x75800000, 0x3cc00000, 0x4da00000, 0x90f00000, 0xf7b80000, 0x21c40000, 0x55220000, 0x6c850000,
+    0xfa758000, 0x0efac000, 0xe8a0e000, 0xe449f000, 0xd1f58800, 0x4d3acc00, 0xb080e600, 0x9879fd00,
+    0x03ed8e80, 0xf80ecc40, 0xcc1aebe0, 0x6a38f6f0, 0xd93a09d8, 0xa6b10f8c, 0x936f8f3a, 0x107bcfff,
+    0x17d76ea3, 0x2e063190, 0xa738e468, 0xa9bdf837, 0x5ecf8984, 0x408bc03f, 0x806f6f82, 0xdfc23cc3,
+    0x9a1aeea3, 0xc138f190, 0x7aba0468, 0xf1710837, 0x1d4f8184, 0x074bcc3f, 0x064f6982, 0x1cf231c3,
+    0x3d82e823, 0x48ccf1d0, 0x4b800988, 0x93c003c7,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0x68000000, 0x6c000000, 0x5a000000, 0x33000000,
+    0xce800000, 0x09c00000, 0x62a00000, 0xb3d00000, 0x21880000, 0xc5640000, 0x2c520000, 0x91df0000,
+    0xf6948000, 0x4ddbc000, 0xf4832000, 0x0aebb000, 0xb4348800, 0x6e0bc400, 0x1d0b2a00, 0x738fbd00,
+    0xaa668e80, 0xa0d4c2c0, 0x7f1fafa0, 0x04947e30, 0xf2c5a268, 0x102f725c, 0x8803218a, 0x1c2bb10d,
+    0xe2148a70, 0x371bc30a, 0xf8a32dcf, 0x60fbbad0, 0x9f1c8ab9, 0x74bfc9e7, 0x4ad128ef, 0x1434bc22,
+    0xbe200270, 0x7510070a, 0x1fa807cf, 0xf07407d0, 0x93fa0439, 0xb1ab0b27, 0x0d6e874f, 0x9070c212,
+    0xa3eda018, 0xa99b7556, 0xd9592645, 0xce50b6dd,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0xa8000000, 0x94000000, 0x16000000, 0x1d000000,
+    0xa3800000, 0xebc00000, 0x1fa00000, 0xa9f00000, 0x74b80000, 0xe74c0000, 0x94d20000, 0x87090000,
+    0x34878000, 0x47464000, 0x04db6000, 0xdf22b000, 0x08a78800, 0xc5764c00, 0x0fc36600, 0x619ebf00,
+    0x40cd8280, 0x31334540, 0xb996e760, 0xbcd1fed0, 0xd31168b8, 0x4297bbfc, 0xaa4a0e9a, 0x0f75084f,
+    0xa0cd81f3, 0x01334688, 0x7196e357, 0xd8d1fa7f, 0x6d11675b, 0xcb97b96c, 0x1fca0cc2, 0xf9b502a1,
+    0x1ced89f3, 0x43034a88, 0x1a8ee557, 0x966df57f, 0x8d7b6ddb, 0xabd2b02c, 0xbf9f8da2, 0x39fa4371,
+    0x2cb163cb, 0xdb67b434, 0x16f20cad, 0x8c3903e0,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xe8000000, 0x54000000, 0xc2000000, 0xbb000000,
+    0x26800000, 0x70400000, 0xcaa00000, 0x3e700000, 0x2fa80000, 0xc5cc0000, 0xe8520000, 0xb6b90000,
+    0x88728000, 0xa6afc000, 0xb04f2000, 0xeab5d000, 0xce528800, 0xc79fcc00, 0x91c72200, 0x2a49df00,
+    0x0d888680, 0xaedac940, 0xd6efae20, 0x7ae314b0, 0xd4cf24e8, 0xe1f5de44, 0x02728a8a, 0x79afcb57,
+    0x9ccf2c12, 0x85f5d118, 0x0872888f, 0x66afca7c, 0x904f2635, 0x1ab5d582, 0x26528dc3, 0x939fc863,
+    0x53c72412, 0x9149dd18, 0x2b088a8f, 0xde9ac57c, 0x1c4fa8b5, 0x449310c2, 0xfb6721e3, 0x2439d3d3,
+    0xea208e7a, 0xcf16c61c, 0x14bdac25, 0x235a159b,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x28000000, 0x74000000, 0xba000000, 0x57000000,
+    0x22800000, 0x1d400000, 0xd8a00000, 0x6a700000, 0xaa180000, 0xef1c0000, 0x2eb20000, 0x4b710000,
+    0x69848000, 0xd1f8c000, 0x78fe2000, 0x5f6e9000, 0xa39c8800, 0x4ee4cc00, 0x9e4c2e00, 0xd01f9b00,
+    0x58180a80, 0xbc1c0b40, 0x7e3205a0, 0xc5310e70, 0x01a480a8, 0x85c8c694, 0x92c6262a, 0x904293d7,
+    0xdd168089, 0x9db9c325, 0xabc2a161, 0xcffa5910, 0xcdc8a6b9, 0x96e758ae, 0xe26628c4, 0x4e329331,
+    0x2d0e8a09, 0x95a5c865, 0x8ff0a4c1, 0xedcb5760, 0xc6ec2611, 0x7a6f9e3a, 0x12000eee, 0xe30000e6,
+    0x78800a80, 0xfa400b40, 0xd22005a0, 0x03300e70,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xb8000000, 0x7c000000, 0xa2000000, 0x0f000000,
+    0xf2800000, 0x73400000, 0x48a00000, 0x50700000, 0x30180000, 0x982c0000, 0xec160000, 0x6a030000,
+    0xcb068000, 0x2c9cc000, 0xde542000, 0xb53f1000, 0xd19e8800, 0x0bf0cc00, 0x80e22e00, 0x8c4c1700,
+    0xc2000380, 0xbf000bc0, 0xaa800420, 0x7f4007f0, 0x52a004a8, 0x237000f4, 0x60980eaa, 0xe46c05f7,
+    0x56360c29, 0x49330eb6, 0xb3be8a4b, 0xe4c0c3a7, 0x025a2fb1, 0x47101b4b, 0xf68e0b24, 0x4d6f01f3,
+    0x95b08fa9, 0x15efc576, 0xcdcaae6b, 0x01cfd457, 0xfbfca319, 0xf8fcd7bf, 0x10422b8e, 0x103c1304,
+    0x08180000, 0x242c0000, 0xae160000, 0x15030000,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xd8000000, 0x74000000, 0xc6000000, 0x45000000,
+    0x2c800000, 0xccc00000, 0x2ea00000, 0x77d00000, 0xe3080000, 0xf98c0000, 0xe85e0000, 0x3e610000,
+    0x33778000, 0x63d44000, 0xb53ea000, 0xb4b07000, 0x18df8800, 0x98884400, 0xeae8aa00, 0xbb9d7700,
+    0xf3560580, 0x83ed0340, 0x65298660, 0x1cb54350, 0xb4c92f48, 0x2aa43b8c, 0x69c1268a, 0xd228372d,
+    0x131f2ef8, 0x61893054, 0x3c48a3ef, 0x884d70db, 0xae5e0ee7, 0x3b6109b5, 0xbff78cfe, 0xdf144071,
+    0x439ea378, 0xb7607714, 0x3dd78f8f, 0x2404448b, 0x2e36a42f, 0x493c7179, 0xee818c14, 0x97e9440c,
+    0x331f2ac8, 0x518938cc, 0x4448a0ea, 0x8c4d747d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0x98000000, 0x9c000000, 0xba000000, 0x29000000,
+    0xd8800000, 0x0b400000, 0x91a00000, 0x43f00000, 0xe2d80000, 0x1e440000, 0x830e0000, 0x49bb0000,
+    0xffe48000, 0xe8ff4000, 0xff49a000, 0x5fa91000, 0x64ea8800, 0xfd444c00, 0x5aad2e00, 0xce565500,
+    0xdb232180, 0x35ad55c0, 0x15e7ada0, 0x59e21b90, 0xbbd60208, 0xeeff0074, 0xdc6a853a, 0x66044a6f,
+    0xb30d2185, 0x41a65a00, 0x1bfb2f02, 0x9ee95e80, 0xf469a341, 0x321912e0, 0x0d128f73, 0x4eb0447a,
+    0x505b238d, 0x64195a74, 0xb631aa38, 0xeb1d14ef, 0x3dbc82c4, 0xf1fb48e0, 0x2fe7a071, 0xb0e21afa,
+    0x835600cc, 0xb5bf0894, 0xd5ca854b, 0xb9f44095,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x48000000, 0xec000000, 0xe6000000, 0xd3000000,
+    0x0c800000, 0x7dc00000, 0x8fa00000, 0xe9700000, 0x46580000, 0xd4ec0000, 0x3e3a0000, 0x470f0000,
+    0xaeaf8000, 0x54e1c000, 0xfe33a000, 0xa7193000, 0x5e958800, 0x1ceecc00, 0x121c2e00, 0x4138ff00,
+    0x8d862c80, 0x1047f2c0, 0x6ff1a860, 0xce8a3e30, 0x64d80248, 0x562c0a1c, 0xbb1a0c1a, 0xf0bf0267,
+    0x23d78e4d, 0xf8bdc962, 0xeff1a5b1, 0x0e8a300b, 0x84d80ebd, 0xa62c0d4b, 0xf31a0e9f, 0x1cbf02d8,
+    0xc5d78c05, 0x2bbdc37e, 0xe371a9ab, 0x734a326c, 0x0b7800f0, 0x4f5c0429, 0xb5420b2e, 0xc85302d3,
+    0xfbed8ab8, 0x6cb2c235, 0x4dde2934, 0x27abffb4,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x78000000, 0x0c000000, 0x32000000, 0x75000000,
+    0xe0800000, 0xb6c00000, 0x8da00000, 0x4a700000, 0xa1680000, 0x36ec0000, 0x4d860000, 0x6a470000,
+    0x51468000, 0x4eec4000, 0x41b1a000, 0x587b7000, 0x24408800, 0xae6b4c00, 0xf7572200, 0xd5e73f00,
+    0x6e192f80, 0x0f3c3cc0, 0xc1b1a920, 0x987b7450, 0x04408388, 0x5e6b48ac, 0x8f572e7a, 0xd9e73c37,
+    0x5c1928be, 0x7a3c3392, 0x2131ab2a, 0x2ebb7d3f, 0x89e08450, 0x141b4b8b, 0x2e3f24af, 0xef0b3c7a,
+    0x119f2b36, 0x107b3b3e, 0x70772550, 0x60573108, 0xc85124ee, 0x4c603419, 0x0a7fad85, 0x41607e45,
+    0xe6c800e6, 0xc59c0c75, 0x1e6e08df, 0x6f6b0922,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xb8000000, 0xf4000000, 0x4e000000, 0xe5000000,
+    0xb9800000, 0xe8c00000, 0x28a00000, 0x5f500000, 0x85480000, 0x3e4c0000, 0x8af60000, 0x67990000,
+    0xa5f98000, 0x8d1e4000, 0x4582e000, 0x4aebf000, 0x87af8800, 0xf5d74400, 0xc5336a00, 0x09b9bf00,
+    0xf0db6380, 0x2ca5bb40, 0xa96566e0, 0x9470b550, 0xc9eae218, 0x8737facc, 0xf6b18fea, 0x12524fe5,
+    0x20f4e6ac, 0x24b2f378, 0x457606dd, 0xde590810, 0xdad985f9, 0x2f8e411c, 0xe9eae631, 0x3737f50a,
+    0xeeb18cb4, 0x16524db4, 0xd6f4e337, 0x35b2f8f5, 0xb2f600d5, 0xd3990924, 0x4bf9860c, 0x981e484a,
+    0x4402eb55, 0x562bf664, 0xe10f8aec, 0x4f87421a,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0xb8000000, 0x64000000, 0xb6000000, 0x57000000,
+    0xa3800000, 0xbcc00000, 0x22a00000, 0xb8500000, 0x35c80000, 0xea340000, 0xc5160000, 0xe2b50000,
+    0x58478000, 0x85c2c000, 0x422c6000, 0x192f7000, 0x30998800, 0xb943c400, 0x717dea00, 0x5d58b100,
+    0x87726380, 0xaa6e7240, 0x34e80960, 0xaea40070, 0x727e0bb8, 0xa0d10c8c, 0x109982ca, 0xe943c8b5,
+    0x697de204, 0x2958b01f, 0x89726c43, 0x996e7a62, 0x216806f2, 0x4564087a, 0xf35e0aaf, 0xa441065a,
+    0x07f18a3c, 0xbb27ced3, 0x99a3e7e9, 0x21d9b2a7, 0x1423ef4e, 0xfe19b4e9, 0x3b03e426, 0xd989b48d,
+    0x81ebeeca, 0x042db6b6, 0x4615e105, 0x5f3cbe9f,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xb8000000, 0xd4000000, 0xb6000000, 0x7b000000,
+    0xcf800000, 0x1fc00000, 0x76a00000, 0x8d700000, 0x45e80000, 0xaf840000, 0xafd60000, 0xbe890000,
+    0xb1678000, 0x9fedc000, 0xb6af6000, 0xad62b000, 0x15f98800, 0x1790cc00, 0x7bf6e200, 0x08827500,
+    0xca676380, 0x5056b140, 0xa9678160, 0xdbedceb0, 0x98af6578, 0x5262b9bc, 0xd4798f8a, 0xa750ce27,
+    0x74d6e1c6, 0xe13279b4, 0x36af62ef, 0x6d62b383, 0x35f98942, 0x4790c561, 0xc3f6e0b1, 0xdc82727a,
+    0x7c676f3e, 0x2b56bd48, 0x66e78e05, 0xc42dc614, 0xee0f6e7c, 0xdf12b429, 0x91918cb4, 0x08d4c16e,
+    0xdb00e2c2, 0x5fbb7821, 0x87c8e3d1, 0xf28f79ca,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x38000000, 0x8c000000, 0x0a000000, 0xb5000000,
+    0x94800000, 0xa1400000, 0xbfa00000, 0xd6d00000, 0x9d580000, 0x2dac0000, 0x7fe60000, 0x4be70000,
+    0x8de08000, 0xd2c84000, 0x8350a000, 0x8e9d9000, 0x1c7e8800, 0x7f134400, 0x81ae2e00, 0xa5ced700,
+    0x56f0ab80, 0xdd4d9cc0, 0xcda686a0, 0x0fff4850, 0x73e824c8, 0x01f9d5d4, 0xd8c820da, 0x3669dafd,
+    0x1a30263d, 0xbd15d09e, 0xc08e20cc, 0x575ed807, 0x3888afd1, 0x7b71920b, 0xa2b88a76, 0x86644409,
+    0x8236a175, 0xa13a9d8a, 0x82be08b6, 0x564b0daa, 0x2a068ea4, 0x652f4f81, 0x3cb022c0, 0x6555d9a3,
+    0x01ae27d1, 0xe5ced60b, 0xb6f0a476, 0xad4d9309,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x28000000, 0x7c000000, 0xae000000, 0x1d000000,
+    0xfd800000, 0x04c00000, 0x2aa00000, 0xfe500000, 0x4cc80000, 0x468c0000, 0x28720000, 0xf5eb0000,
+    0x412d8000, 0xe3aa4000, 0x91dfe000, 0x5b3bf000, 0x7cb78800, 0x075d4400, 0xeb686a00, 0x7d66bf00,
+    0x645fea80, 0x93fbf3c0, 0x701788e0, 0x680d4ad0, 0xdc206758, 0x5e2ab88c, 0x350de2ca, 0x8180fdf5,
+    0xaad203f4, 0x37bb0ef4, 0x03e58775, 0x48264837, 0x6c2dea14, 0xd610f3a7, 0xb93a026e, 0x07a70b19,
+    0xcbff862c, 0x641141b8, 0x1a3a675f, 0x9f1db012, 0x969a6c38, 0xb04db21f, 0x01d26531, 0x6301bb0b,
+    0x78806a14, 0x0d7ab3a7, 0x0c45e26e, 0x4fccfb19,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x38000000, 0xfc000000, 0xce000000, 0x0f000000,
+    0x59800000, 0xf2400000, 0x8a600000, 0xd6500000, 0xe8480000, 0x4f6c0000, 0xd2e20000, 0x129d0000,
+    0x59ce8000, 0xd91d4000, 0x9a832000, 0xadf3b000, 0x23032800, 0x0fb3bc00, 0xf1632e00, 0xb5e3b500,
+    0xef2b2d80, 0x098fbac0, 0xaa492160, 0xe652ba30, 0x2067a4f8, 0x1b5ff514, 0xd8cc8c5e, 0x2f904871,
+    0xc165aada, 0x7dd2f087, 0xbb2a09f4, 0x03b104ae, 0x974c81e8, 0x7ed043ff, 0x8485a120, 0x3ac2ff10,
+    0x768201e8, 0x9bcd03ff, 0xb0068120, 0x08314f10, 0x340129e8, 0x9a3ebfff, 0x0505af20, 0x6482fa10,
+    0x6ae20468, 0x2e9d053f, 0xf7ce8e40, 0x461d4020,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x28000000, 0xcc000000, 0x76000000, 0x3b000000,
+    0x20800000, 0x00400000, 0x48600000, 0xb4700000, 0x0a480000, 0x8d540000, 0xf3de0000, 0x04ad0000,
+    0x1a508000, 0x956e4000, 0xf7ce6000, 0xceaa7000, 0x7f4e6800, 0x42ea7400, 0xa12e6600, 0xbd9a7300,
+    0xa3e66480, 0xfc8e7fc0, 0x6e5863e0, 0x77537c70, 0x5ec0e1e8, 0x6f293c74, 0x12b0856e, 0x115e4733,
+    0x7de66dca, 0x4b8e7be6, 0xd8d864f7, 0x3c1378ac, 0x1e20e9d2, 0x17193a3a, 0x2698834e, 0x137a49a0,
+    0xa4f069d2, 0xc2377a3a, 0x7936e34e, 0x19a039a0, 0xf9f601d2, 0xc1890e3a, 0x5dc6854e, 0x9b974aa0,
+    0x60c0e552, 0xa82931fa, 0x8c3086ae, 0x961e46d0,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0xf8000000, 0x6c000000, 0x16000000, 0xd5000000,
+    0x22800000, 0xf7c00000, 0xbbe00000, 0x7dd00000, 0xf0f80000, 0xae7c0000, 0xb7820000, 0xb5790000,
+    0xfc2c8000, 0x2e21c000, 0x19356000, 0x249a9000, 0xdad56800, 0xf54a9c00, 0x9c2d6200, 0x9e369d00,
+    0xf12f6d80, 0xb08f9bc0, 0xa0e3ece0, 0x367e5690, 0x6bae8ec8, 0x4b58c9ec, 0xbd19ed76, 0x76bb5231,
+    0x2de00079, 0x68d005d6, 0x72780681, 0x49bc0541, 0xf4620da3, 0xa4a90d33, 0x1ad480f8, 0x555dcb96,
+    0x8c3765a3, 0x66239133, 0x9d19e2f8, 0xa6bb5696, 0x75e00823, 0x14d00af3, 0x9c780e18, 0xf0bc0006,
+    0xc0e206eb, 0x8669031f, 0x83b4836e, 0xdf4dc237,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x18000000, 0x34000000, 0x5a000000, 0x6b000000,
+    0x24800000, 0x83c00000, 0xcfe00000, 0xd1d00000, 0x48d80000, 0xbb7c0000, 0x6a3a0000, 0x23170000,
+    0x28b18000, 0x7de34000, 0xe6ee6000, 0xaa789000, 0xb58e6800, 0x9c689c00, 0x50b66a00, 0x39c49500,
+    0x14d46b80, 0x7d6f9640, 0xe73fee20, 0x1a8bd0f0, 0x8ad80c68, 0x247c08cc, 0x34ba0896, 0x5bd709d1,
+    0xdbd18e1b, 0x1bf34b66, 0x3bd66938, 0xabd49a17, 0xb3ec6613, 0x87c39779, 0xdddde835, 0xb6e0dce2,
+    0x92538e13, 0x11884b79, 0xde65e235, 0x0f8cd9e2, 0x47518d93, 0xfc334139, 0x96366615, 0xb5049c12,
+    0x9db46a7b, 0xe07f9fb5, 0x0687e0a3, 0xace7d533,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x88000000, 0x8c000000, 0x4e000000, 0x13000000,
+    0xf6800000, 0x5ac00000, 0xd4e00000, 0xe7f00000, 0xc1680000, 0xa3840000, 0x73460000, 0x56950000,
+    0xcaee8000, 0x8cee4000, 0x53d5e000, 0x8b765000, 0x72bde800, 0xd8f25400, 0xe9fbee00, 0xb2675f00,
+    0xe5156680, 0xa18917c0, 0x0e408260, 0x633f46f0, 0x5e9d6508, 0x06fd175c, 0xa2ee8646, 0xf0ee4623,
+    0xf5d5ecd0, 0xa4765bdb, 0x423de984, 0x1d325842, 0x859be022, 0x1c5757d0, 0x061d6b59, 0xbf3d1d44,
+    0x688e86a2, 0x71de4010, 0x265de939, 0x7f025bb4, 0x4893e3aa, 0xa1e3574c, 0x1e536f7f, 0x7b1c1d97,
+    0x8aae0f7a, 0xfcd10c97, 0xfbc886fb, 0xd74b45d5,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x98000000, 0x9c000000, 0xca000000, 0xf9000000,
+    0x72800000, 0x96400000, 0xac600000, 0xbd500000, 0x7bf80000, 0x33bc0000, 0xa8fa0000, 0xc80d0000,
+    0x64078000, 0xe625c000, 0xbb336000, 0x8f93f000, 0xb2cb6800, 0x092ffc00, 0x9ab16200, 0x2262fd00,
+    0x7256eb80, 0x8a5734c0, 0xa67d8720, 0xe468cb50, 0x1954e808, 0x3de63e34, 0x988000ce, 0xbf4008e1,
+    0xe6e00cf1, 0xa7100f1a, 0x8598095e, 0xebec04ca, 0x6b820d16, 0x94f1059c, 0x121d87eb, 0x0538c044,
+    0x08ace696, 0xe75a315c, 0xdafa00cb, 0x7d0d0b14, 0xe4878e9e, 0x0565cf68, 0x37d36005, 0xc183f3f5,
+    0xddd36a6f, 0xe883fc72, 0x97536b5b, 0xf2c3fa3f,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x68000000, 0x04000000, 0xfe000000, 0x1b000000,
+    0xf1800000, 0x65400000, 0xc3600000, 0x94500000, 0x0fe80000, 0x97b40000, 0xd24e0000, 0x28df0000,
+    0x64288000, 0x4e2a4000, 0x93202000, 0x859a7000, 0xf3482800, 0xdc6e7c00, 0x9be62600, 0x71a17b00,
+    0xa546a080, 0x236f3b40, 0xe4408f60, 0x67de4af0, 0x938e2878, 0x2c5570a4, 0x33e8a24e, 0x95a032e1,
+    0x2b600a30, 0x50500cda, 0x11e80674, 0xfcb403a7, 0x4bce0ece, 0x499f07a1, 0x59488252, 0xc17a40a9,
+    0x6d482e4e, 0x776e7ce1, 0xe2662d32, 0x60e17a59, 0xf026ae36, 0xa83f3045, 0xe428897c, 0x8e2a43b8,
+    0x73202c86, 0xf59a7bdf, 0x9b482668, 0xd86e71ef,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xf8000000, 0x2c000000, 0xf2000000, 0xa3000000,
+    0xd4800000, 0xefc00000, 0x99e00000, 0xdcd00000, 0x15480000, 0xaf3c0000, 0x56a20000, 0x04d90000,
+    0x495d8000, 0x15314000, 0xf1826000, 0xf6769000, 0xdbaa6800, 0x095a9c00, 0xb5206e00, 0x41af9100,
+    0xde77e180, 0x0fabd3c0, 0xd7420ea0, 0xe40909f0, 0x36158be8, 0xe50d4b0c, 0x79a06476, 0x926f9bc1,
+    0x2d97e2a2, 0x8c7bdff2, 0x1c8a0ee8, 0x2bf5088f, 0xdfd780b5, 0x71c449e0, 0x68d5ef13, 0x1b72dc7a,
+    0x061f8935, 0x0d384620, 0xcd97efb3, 0x9c7bd48a, 0x848a0b5d, 0xd7f502ec, 0xd5d78b65, 0xfec447bb,
+    0x4e55eb97, 0x57b2d9d2, 0x4b7f815b, 0x3e284c05,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xe8000000, 0xa4000000, 0x92000000, 0x2b000000,
+    0xe0800000, 0xfc400000, 0xb2600000, 0x57500000, 0x92d80000, 0x8b240000, 0xd0be0000, 0x34670000,
+    0xe66b8000, 0x2d6d4000, 0x1de76000, 0xf98d7000, 0x07df6800, 0x66b97c00, 0x4d596a00, 0x0dea7300,
+    0xc1b4e480, 0xbbd43940, 0xb8be0da0, 0x50670bf0, 0x546b8ba8, 0xf66d4834, 0x1567628e, 0xa1cd7241,
+    0x27bf6d23, 0x1ae97cb3, 0x3f016b08, 0x7a8e7245, 0xa36ae5e6, 0xd8e33517, 0xcc0d863e, 0xf62e4d4a,
+    0x9932e966, 0x3b873057, 0xf4d3819e, 0xea1945ba, 0x77016e4e, 0xee8e7d23, 0xf96ae4b0, 0xa7e33f0b,
+    0x568d8445, 0x856e4ce4, 0x59d2ea96, 0xbb9737ff,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x38000000, 0x14000000, 0xd6000000, 0x29000000,
+    0x64800000, 0x10c00000, 0xa9e00000, 0x35500000, 0x51880000, 0x3e6c0000, 0x1a320000, 0x3b3f0000,
+    0xc3a68000, 0x597ec000, 0x3388e000, 0xb1625000, 0x7fbae800, 0x635d5c00, 0xb89c6e00, 0x3ae39d00,
+    0x1af48d80, 0xaad1cc40, 0xe2c668e0, 0xdee092d0, 0x24e800a8, 0xcffc0edc, 0x545a07b6, 0x090301c9,
+    0xd49c8a4e, 0x58edc0ff, 0x95fc6345, 0xcf739190, 0xba9c86c9, 0xa5edc8ce, 0x477c623f, 0xe6b39f67,
+    0x4ffc8661, 0x947dc612, 0x69146589, 0xc48f9eae, 0x60c68c2f, 0x81eec6ed, 0x1960e6cc, 0x939e5f3e,
+    0xc160e2e6, 0x579e5223, 0x4f60eaf3, 0x7a9e5d59,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x18000000, 0x14000000, 0x1a000000, 0x6b000000,
+    0x4c800000, 0xe3c00000, 0x24e00000, 0x76500000, 0xdc980000, 0x1bcc0000, 0xc0da0000, 0xa4670000,
+    0xbb9d8000, 0x59554000, 0x52156000, 0xa7361000, 0x62af6800, 0x42c11c00, 0x1b4aea00, 0xcd085100,
+    0x019d8b80, 0x22554040, 0x26956220, 0x80f61af0, 0x444f6ce8, 0x4b9115cc, 0x9152ed26, 0x5e045ee9,
+    0xa92780cf, 0x13a248a5, 0x6570e1aa, 0xb43f566f, 0x0a180695, 0x530c0ba3, 0x88ba07b3, 0xe1f70749,
+    0x5be5827d, 0x20c9426f, 0x54576095, 0x739d18a0, 0x5568e132, 0x5c33568a, 0xd622091f, 0x453b053f,
+    0xedbf80cf, 0xdc6e48a5, 0x9faae1aa, 0xab58566f,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x18000000, 0x24000000, 0xfa000000, 0xef000000,
+    0x4c800000, 0xe2c00000, 0x77e00000, 0x94700000, 0x9a180000, 0x3f340000, 0xc4a20000, 0xeec30000,
+    0xb1d18000, 0xa57c4000, 0xc38e6000, 0x7e645000, 0x1d346800, 0xef935400, 0xc847e200, 0x942c1700,
+    0xa2180380, 0x6b340540, 0x86a20420, 0x15c30fb0, 0x1f518ae8, 0x8cbc429c, 0x02ee6c16, 0xe7d4599b,
+    0xbc4c6217, 0xa6175098, 0xe11de095, 0xd1ab1358, 0x4d738d76, 0x1fbf4208, 0x305fef4f, 0x20181ced,
+    0x703a041e, 0xb83705d4, 0x14138779, 0xe20f4ac6, 0xcb27ece1, 0xb69c17d0, 0x0de00bfa, 0x3b700005,
+    0x76980380, 0xedf40540, 0xab420420, 0x5eb30fb0,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x68000000, 0x84000000, 0x0e000000, 0x25000000,
+    0x68800000, 0xc3c00000, 0xc0e00000, 0xe9500000, 0x5c180000, 0x22340000, 0x8f1e0000, 0x73b70000,
+    0xe6788000, 0xef8bc000, 0xe460e000, 0x508b9000, 0xffe6e800, 0x32c89c00, 0x1e606a00, 0xf3a45f00,
+    0x26600c80, 0xcf900740, 0xd4780460, 0x38a40910, 0x7be600e8, 0x3cd3062c, 0x3b7e8a66, 0x9b08c0f9,
+    0xe5866f47, 0x0f77508a, 0x3d1e893e, 0x6498c78d, 0x59fe6157, 0xb3d358e0, 0x48f88650, 0x7d4bc88b,
+    0x0a00ed3f, 0xeb1b998c, 0x6d9ee856, 0x9b6c9162, 0x6b066a90, 0xadb7532a, 0xbb7e810e, 0x5b08c916,
+    0xc5866800, 0x3f775c00, 0x551e8a00, 0xe098cf00,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x48000000, 0x1c000000, 0xb2000000, 0x93000000,
+    0x0c800000, 0xb4c00000, 0x0de00000, 0x96500000, 0x58380000, 0xa4140000, 0x46360000, 0x0d190000,
+    0x65b98000, 0x374f4000, 0x07ab6000, 0xbc7ad000, 0xcf256800, 0xdeb7dc00, 0xb7cae200, 0x79719f00,
+    0x76800680, 0xfbc00ec0, 0x136005a0, 0x81900bf0, 0x11580f68, 0x9a8403bc, 0xa1ee0b36, 0xac5d0b19,
+    0x77378595, 0x2a824868, 0x29c4e13e, 0x107c93f5, 0xf50f8fbb, 0xf19645e4, 0x3972e842, 0x16a59b62,
+    0x6bd60653, 0x6b490898, 0xd58186d4, 0xbf5b4b8b, 0xbb9d64ae, 0x3e63df4c, 0x141ceedc, 0xce389c67,
+    0xb1018000, 0xe79b4000, 0xec7d6000, 0x1733d000,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x88000000, 0x8c000000, 0x7e000000, 0xbf000000,
+    0x0c800000, 0xc2400000, 0x77600000, 0x6ef00000, 0x05280000, 0x61ac0000, 0xffde0000, 0x1e850000,
+    0xd34e8000, 0x2ceb4000, 0xbc276000, 0x46367000, 0x4b316800, 0xe6af7400, 0x0769ee00, 0x36dd3d00,
+    0x61160680, 0x039905c0, 0xb6d88960, 0x21324a30, 0x639fefa8, 0x26f43f14, 0xa906885e, 0xefb7453b,
+    0x58d161ec, 0x161f75d1, 0xe321e859, 0x9a813ddd, 0x61600b7b, 0x8df003cc, 0x9fa80743, 0x00ec0aa1,
+    0x723e0a53, 0x81350d18, 0xd386887d, 0x5ef748aa, 0x3d316a97, 0x95af761d, 0x15e9ef1a, 0xdb9d377c,
+    0x92f60128, 0x23290ed4, 0xba908f3e, 0x916e420b,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xe8000000, 0x2c000000, 0x1e000000, 0x49000000,
+    0x17800000, 0x9ac00000, 0x45600000, 0xe3900000, 0x80e80000, 0x5e540000, 0x13060000, 0xac990000,
+    0xba788000, 0x3123c000, 0xc396a000, 0x90f7d000, 0x666e2800, 0x67141c00, 0x76988e00, 0xc173c700,
+    0x719ea880, 0x07f3d9c0, 0x58e02760, 0x5a491a50, 0x410e0e98, 0x4b9d0a3c, 0x0cf68dae, 0x907ece55,
+    0x02002338, 0x7f191d8c, 0x128601a6, 0x23590e10, 0x7e9889b9, 0x9d73c54e, 0xe79ea546, 0xd2f3d301,
+    0xb9602a81, 0xa58918c2, 0x0dee04e0, 0x7bcd0d11, 0xdefe8b38, 0xb77ac18c, 0xd48e2fa6, 0x6e441910,
+    0x3b108939, 0xe0b7c08e, 0x1470ac26, 0x903ede51,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x68000000, 0x94000000, 0x72000000, 0x3f000000,
+    0xfe800000, 0xdfc00000, 0x99600000, 0xcab00000, 0xbdc80000, 0x9e740000, 0xf8260000, 0xec370000,
+    0xde2d8000, 0x81054000, 0x4f8f6000, 0xf871d000, 0xf522e800, 0x4db49400, 0xcf4d8e00, 0xafb54700,
+    0x88476080, 0xfd05da40, 0xe984e9a0, 0xd5439eb0, 0x048006c8, 0x04c0090c, 0x1de006fe, 0x8e700b17,
+    0xc02803ea, 0x20040ffc, 0x500e0c96, 0x58330928, 0xfc23841e, 0xe6364784, 0x4d2ceb50, 0xc18792db,
+    0x216e07f4, 0x46830878, 0x53eb87c6, 0x77424bf3, 0x238aebea, 0x66709bfc, 0x14238296, 0x32364e28,
+    0x5f2ce49e, 0xce879dc4, 0xb7ee02f0, 0x0d430c6b,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xf8000000, 0x0c000000, 0xe6000000, 0xb3000000,
+    0x66800000, 0xc4400000, 0x2ee00000, 0xcc900000, 0xb1480000, 0x6b440000, 0xe6720000, 0xafd50000,
+    0x630e8000, 0xcebf4000, 0xa06c6000, 0x4cd03000, 0x6d82e800, 0x8eff7c00, 0x9ca68600, 0xd96b4b00,
+    0xef566180, 0xf44137c0, 0xf6fe69e0, 0x509537f0, 0xdf446e08, 0x2c443c74, 0x6ad8ed06, 0x3ebe7b4d,
+    0x98720e1a, 0xa0d50a8d, 0xfb8e8cfb, 0xc5ff4cfd, 0xf60c6d31, 0xfb003d6b, 0x72aae5c4, 0x5e6b792f,
+    0x83fc8b2b, 0x152a4be6, 0xf582ef3f, 0x32ff7ed2, 0x62a6879a, 0x166b414d, 0x97d6631b, 0x8f01300d,
+    0x589e62b9, 0xeb4536df, 0x266c6122, 0x4fd03592,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x98000000, 0xec000000, 0xa2000000, 0x11000000,
+    0xaa800000, 0xc8c00000, 0xa6600000, 0x78900000, 0xa1f80000, 0xf0e40000, 0xba7a0000, 0x62850000,
+    0x6ce78000, 0x606ec000, 0x8fafe000, 0xcc551000, 0xcda86800, 0xcd6bdc00, 0xcf1f8a00, 0x718acf00,
+    0x2755eb80, 0xba101dc0, 0x3d2feba0, 0x289513d0, 0xe9c86a88, 0x94fbd29c, 0x5c67834e, 0xa5aecac5,
+    0x994fe1d9, 0xb1051457, 0x5ab060b0, 0x70dfd67a, 0x7a7d8d87, 0x42bbc53b, 0x5cd06f65, 0xf84fd308,
+    0x6385845e, 0x6e5fcd6c, 0xdcaa65d5, 0x67cada72, 0x07e20a59, 0xd7f10997, 0x5fe58b10, 0x1bcfc5aa,
+    0xcdd2670f, 0x92eed7a7, 0x8b780c2b, 0xf82409cd,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0xe8000000, 0x0c000000, 0xde000000, 0x15000000,
+    0xf9800000, 0x8b400000, 0x4ce00000, 0xe2b00000, 0xcfd80000, 0x123c0000, 0xab3e0000, 0x9cb10000,
+    0xcae08000, 0x23a8c000, 0x7c682000, 0xe6433000, 0x3150a800, 0xcbd7f400, 0xf0068a00, 0xf825c100,
+    0xc436ac80, 0x821af5c0, 0x23380b60, 0xe08c0590, 0xace60078, 0xd28d0ce4, 0x57de85d6, 0xa619c65f,
+    0x9108a5d3, 0x5babfe58, 0xf85881d6, 0x4464c45c, 0x426e20d3, 0xc37e30da, 0x50d62115, 0xf4b239bd,
+    0x86d02980, 0xdd8f3b42, 0x3956aba3, 0xf7eaf871, 0xb600092b, 0x5900077c, 0x07800f60, 0xce400793,
+    0x5d600578, 0x65f00266, 0x5d380515, 0xe58c0bbe,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0xd8000000, 0x1c000000, 0x32000000, 0x27000000,
+    0xd2800000, 0x8c400000, 0xfce00000, 0x3fb00000, 0x35e80000, 0x58240000, 0x5c1a0000, 0xd2090000,
+    0xd7378000, 0x0ab34000, 0x907a6000, 0xcefb3000, 0x18a5e800, 0xe76c7400, 0xd4458600, 0xa0de4b00,
+    0xedb7e380, 0xe2f17ec0, 0x528006a0, 0xcc4007b0, 0x1ce00008, 0xcfb005b4, 0xede80f66, 0x44240aff,
+    0x6e1a0fb0, 0xf5090409, 0x05b78bb7, 0x86f34066, 0x6c9a677c, 0xf14b3e70, 0x2d4def2a, 0xbf487dc6,
+    0x885f8b4c, 0x72d744b9, 0x3a80623d, 0xe8423a10, 0xc2fa6c38, 0x02bb3f7d, 0x0445e271, 0x28dc7d29,
+    0x39ad80c4, 0xe4fa4bcd, 0x83adedfb, 0x17f87c5f,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x88000000, 0x14000000, 0x66000000, 0x71000000,
+    0xce800000, 0x7ec00000, 0x3e600000, 0x35900000, 0xcb480000, 0x352c0000, 0xe0a60000, 0xfbdf0000,
+    0x46c88000, 0xb277c000, 0x2f82e000, 0x18499000, 0x64826800, 0x35d25c00, 0x13c68600, 0x42d4c100,
+    0xbc446680, 0x9a9d5c40, 0x38c606e0, 0x7f4f0a50, 0xa3008488, 0x299bcdfc, 0x7944e78e, 0xc20695f5,
+    0x8f02e132, 0xc3899b1b, 0x12626856, 0xdf825a03, 0xa06e8700, 0x28a8c783, 0xcfca6ac0, 0x10fe52a1,
+    0x9b6080b2, 0x7d0bc0d8, 0x148ce476, 0x4dea92f2, 0xbfc4eb3a, 0x68c696a7, 0x3762ef38, 0x571994a6,
+    0x9faa6c3a, 0x806e5124, 0x18a885f8, 0x97e7c607,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x48000000, 0x3c000000, 0x5e000000, 0xef000000,
+    0x48800000, 0x96400000, 0xd8e00000, 0x95b00000, 0x87e80000, 0xa53c0000, 0x15a60000, 0xc7e50000,
+    0x451c8000, 0x85ae4000, 0x8fdee000, 0x79243000, 0xdbaa6800, 0x60f67400, 0x31b28600, 0x4dc74d00,
+    0xb80c6a80, 0xa4137ac0, 0xca2e0960, 0x1d290930, 0xb1b28f68, 0x0dc74e94, 0x580c6d86, 0x341373ff,
+    0x822e0810, 0x212909f8, 0xefb283ac, 0xe2c74649, 0x108c6c05, 0xa2537ebf, 0x5ace0932, 0xb4990f6b,
+    0x685a8e95, 0x47fb4d87, 0x052a63fe, 0x65b67012, 0x1fd285f8, 0x313749ac, 0xe784614a, 0x3edf7186,
+    0xde8001fd, 0x05400313, 0x2e600e78, 0x7cf003ed,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x28000000, 0xc4000000, 0x46000000, 0x01000000,
+    0xf8800000, 0xdec00000, 0x64600000, 0xddb00000, 0x30480000, 0xf3bc0000, 0x955e0000, 0x5d030000,
+    0x62b48000, 0x05c3c000, 0x27eb6000, 0x50e21000, 0xf177e800, 0x2b2dd400, 0x3b8a8e00, 0x0170c500,
+    0x5317e480, 0xb79ddd40, 0x93428ee0, 0x3c0cc850, 0x8a29ede8, 0xf32edcfc, 0x87be034e, 0xcb730877,
+    0xc01c8722, 0x200fc3f0, 0x701d6759, 0x382d1b47, 0xec356558, 0x82211c47, 0x472366d9, 0xf99e1285,
+    0x2649eefa, 0xba9ed6f7, 0xb9f60160, 0xedcf0492, 0xc3c282ca, 0x66cccb0c, 0xc849ea17, 0x3f9ed630,
+    0x677606fa, 0x220f02f7, 0x77228f60, 0xa1bcc192,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xd8000000, 0x74000000, 0x2e000000, 0xaf000000,
+    0xd1800000, 0x72400000, 0x51e00000, 0x1e900000, 0x93f80000, 0x0b9c0000, 0x33720000, 0x0f430000,
+    0x6d728000, 0x684e4000, 0x30eea000, 0x90397000, 0x38042800, 0x843b3c00, 0x26008a00, 0x030d4100,
+    0x8b9c2f80, 0xf3773a40, 0x2f6a8760, 0xbd4241b0, 0xb064adf8, 0x44e670d4, 0xbe04ad86, 0x97367d8d,
+    0x559ca4c1, 0x547a7aa0, 0x52f6a090, 0x95357129, 0x608e259e, 0x24e43b29, 0x8e00049e, 0xbf0004aa,
+    0x298006df, 0xd64007c9, 0xa7e0096e, 0xc5900533, 0x6c780939, 0xd6dc0a74, 0xb3120d16, 0x63930ca4,
+    0xaf6a815f, 0x7d424189, 0x9064a40e, 0x94e67583,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x28000000, 0xa4000000, 0xe6000000, 0x29000000,
+    0x27800000, 0x01400000, 0xbfe00000, 0x21100000, 0x73a80000, 0xff4c0000, 0x4afe0000, 0x6cb30000,
+    0x19f88000, 0xa81fc000, 0x642da000, 0x8632b000, 0x792b2800, 0x0f9e7c00, 0xa57e0e00, 0x59f30900,
+    0x08188480, 0x540fcf40, 0xfe05ace0, 0xf53eb8d0, 0x4db52418, 0x6a7d7e84, 0x574e8786, 0x2ef0cb05,
+    0xea832844, 0x60d272a5, 0xa7800775, 0xc1400def, 0xdfe00f28, 0x71100bce, 0x5ba80cdb, 0x5b4c0aa7,
+    0xacfe0374, 0x45b307ef, 0x3e788c28, 0xa95fcc4d, 0xdbcda418, 0xa722be84, 0x0a832786, 0xf0d27b05,
+    0xef800044, 0x35400ea5, 0x11e00975, 0xfc1004ef,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x48000000, 0x8c000000, 0x3e000000, 0xed000000,
+    0x44800000, 0x58400000, 0xf9e00000, 0x49100000, 0x46880000, 0x23640000, 0xac6e0000, 0x23e10000,
+    0x86048000, 0xe920c000, 0x169c6000, 0xfb6a3000, 0x1876e800, 0xd9ebfc00, 0xd90e0600, 0x7eb10b00,
+    0xe76c8a80, 0x1e54cfc0, 0xf0fa6760, 0x2faf3610, 0xf5fc6fa8, 0xb73a3754, 0x4b9eedd6, 0x17dffa15,
+    0xbc080976, 0x56240567, 0xf10e07ce, 0x42b10118, 0x716c818e, 0x0f54cf38, 0xc27a613f, 0x16ef3ee1,
+    0x769c6750, 0x4b6a3d0b, 0xb076eb27, 0x25ebf5ec, 0xaf0e07a8, 0x1fb10b54, 0x9dec8bd6, 0xab14c115,
+    0x4d9a6bf6, 0x3eff36a7, 0x4a9466ae, 0xdd4e3c08,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xb8000000, 0xbc000000, 0x52000000, 0x71000000,
+    0x4c800000, 0x24400000, 0x9de00000, 0x5b300000, 0xa1880000, 0xcafc0000, 0xe0aa0000, 0x8e510000,
+    0x30c38000, 0xd59cc000, 0x4ce96000, 0xa7a93000, 0xcde0e800, 0xc314f400, 0xeda20e00, 0xb0ed0d00,
+    0x95898d80, 0x2cfdc2c0, 0x37a2eea0, 0x75c9fcd0, 0x7f2389e8, 0xbfacc154, 0xc1e16b16, 0xd9153ef7,
+    0x08aae046, 0xaa75f6dc, 0x2ee98f32, 0xde8dce5a, 0x754ae44e, 0x2145f538, 0x576188bc, 0x3871ca40,
+    0x7fe0e5e0, 0x6214f6b0, 0x79220298, 0xb8ad07ed, 0xe2698468, 0xbacdc394, 0x88aae5b6, 0xea75f227,
+    0x4ee981ae, 0x4e8dc388, 0xcd4aea24, 0x9d45fdad,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xc8000000, 0xec000000, 0x0e000000, 0x6b000000,
+    0x5f800000, 0xc3c00000, 0x21600000, 0x92300000, 0x3d180000, 0x30bc0000, 0xae460000, 0x4fa90000,
+    0x6be88000, 0x3d714000, 0x84092000, 0x720d1000, 0x8d3fa800, 0x08a95400, 0x5a780a00, 0x658c0900,
+    0xe2de0e80, 0x07d503c0, 0x734e8c60, 0xcf284870, 0xfd99a518, 0x86f05a8c, 0xc5e88fee, 0xc671411f,
+    0x338923c7, 0x8dcd1728, 0x6a5fadb5, 0x1d995d31, 0x36e006ba, 0xfdf0089d, 0x32780586, 0x198c0b0b,
+    0x04de0ee5, 0x50d506f9, 0xeace8bbd, 0x8be84f55, 0x8d79a680, 0xbc0057c0, 0x86108660, 0xa73d4170,
+    0x81b72b98, 0x60e8194c, 0x92e9238e, 0x5ffd196f,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x18000000, 0xdc000000, 0x9a000000, 0xbf000000,
+    0xb7800000, 0x21c00000, 0xb7600000, 0xb8300000, 0x8c180000, 0xa20c0000, 0x73020000, 0x35b10000,
+    0x42d18000, 0x9ae94000, 0x26f22000, 0x8cdd9000, 0x3bd9a800, 0x4879d400, 0x2f800a00, 0xbdc00500,
+    0x0d600380, 0x17300cc0, 0x23980820, 0x5fcc0630, 0x5e6202d8, 0x328109ec, 0x7949800e, 0x1925499f,
+    0xe29023b7, 0x015c91a3, 0xf51027f1, 0x709c9d7b, 0x7a70291d, 0x04ac9ff5, 0x74682dc2, 0xc5a099a3,
+    0x2aea23f2, 0x6ed19f7a, 0x68dba81d, 0x6dc8de77, 0x75518000, 0xfb294000, 0xb1922000, 0x24ed9000,
+    0xafc1a800, 0x3675d400, 0xc6820a00, 0x37710500,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x18000000, 0x44000000, 0xc2000000, 0x17000000,
+    0x1b800000, 0xcbc00000, 0x69600000, 0xf0100000, 0xf8380000, 0xd4240000, 0x6a2a0000, 0x4b170000,
+    0x9da18000, 0x1ef54000, 0x65e32000, 0x20641000, 0x5ab0a800, 0x4d725c00, 0x62000a00, 0x67000700,
+    0x23800380, 0x3fc00f40, 0xb3600da0, 0xa3100530, 0x21b80d98, 0x08e40dcc, 0x18ca072e, 0x70c703bd,
+    0x0cf98915, 0x3ac14243, 0xf7f12921, 0xbf5719f3, 0xad3b2778, 0x1890195e, 0x9a42a584, 0x599154e9,
+    0x1cd38875, 0x52d64591, 0x8bd0ac2b, 0x89625c97, 0x60380000, 0x50240000, 0x882a0000, 0xec170000,
+    0x9e218000, 0x91354000, 0xce832000, 0xc7741000,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x58000000, 0x64000000, 0xc6000000, 0xe1000000,
+    0x1e800000, 0x86400000, 0x20600000, 0xa0100000, 0xb0080000, 0x78040000, 0x941e0000, 0x9e0b0000,
+    0x850c8000, 0xd898c000, 0x675c2000, 0x3ee3d000, 0x2640c800, 0x906c0c00, 0xd8000200, 0x24000100,
+    0xe6000080, 0x110003c0, 0x46800160, 0xe2400190, 0xe6600318, 0x41100384, 0xae88007a, 0xfe440219,
+    0xb47e0081, 0x3e1b0280, 0x350482c0, 0xa09cc1e0, 0xf3422250, 0xa0e8d278, 0xa34c4a14, 0x48f4cf62,
+    0xbf5c239d, 0x1ae3d1fb, 0xc040c819, 0x816c0d81, 0x9e800000, 0xc6400000, 0x00600000, 0x50100000,
+    0xe8080000, 0x1c040000, 0x521e0000, 0x7f0b0000,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x78000000, 0x2c000000, 0x86000000, 0xab000000,
+    0x1e800000, 0x43c00000, 0x9c200000, 0x18100000, 0xdc180000, 0xce040000, 0xef020000, 0xcc8f0000,
+    0x42c58000, 0xafb0c000, 0xee456000, 0x1d7bd000, 0x09fc7800, 0xb7398400, 0xc6800200, 0xbfc00300,
+    0x42200080, 0x8f100040, 0x3c9801e0, 0x0ac400b0, 0xeba20218, 0x3c5f02ac, 0x1c7d807a, 0x3a64c10f,
+    0x417f6270, 0x07e0d060, 0x7823fb70, 0x1a024738, 0x8502e1bc, 0xc1841032, 0xe75c998b, 0x8ae296fe,
+    0x1da11859, 0x1f4657c5, 0x66fe78bf, 0xbbb68730, 0xa4458000, 0x0070c000, 0xd4656000, 0xbe6bd000,
+    0xb3647800, 0x16fd8400, 0x33a20200, 0xc05f0300,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x58000000, 0x64000000, 0xe6000000, 0x47000000,
+    0x05800000, 0xa2c00000, 0x78e00000, 0xd4100000, 0x9e080000, 0x330c0000, 0xcb8e0000, 0xd9cb0000,
+    0xff6c8000, 0xd7ccc000, 0xa460e000, 0x405cb000, 0x8fb25800, 0x46381c00, 0x7ee00200, 0xa3100100,
+    0xa3880180, 0x85cc01c0, 0x0d6e0160, 0x2edb0190, 0x82e48398, 0x0100c11c, 0x128ee016, 0xef47b28b,
+    0x9636d9e3, 0x76e8df50, 0x8f06e078, 0x098bb1cc, 0x18d8daae, 0xcdf3dea7, 0x2882609d, 0x1a5b73cf,
+    0x0abe3889, 0x82a86ddd, 0xeeb2b948, 0x24b4af03, 0xc9ba5800, 0x51341c00, 0x336e0200, 0x4ddb0100,
+    0x01648180, 0x94c0c1c0, 0x37eee160, 0xfd97b190,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xf8000000, 0x44000000, 0x8e000000, 0xc3000000,
+    0x88800000, 0xe2400000, 0x0aa00000, 0x34100000, 0xa6080000, 0x1f0c0000, 0xd2920000, 0x575f0000,
+    0x05288000, 0xd0c1c000, 0x8df06000, 0xa92d1000, 0xa2d64800, 0xe4fa5400, 0xfca00200, 0xf3100100,
+    0xc0880180, 0xae4c0240, 0xa8b203e0, 0xc50f0110, 0x27808238, 0x38ddc30c, 0x71ea6222, 0x033e1389,
+    0x7fccc82a, 0x577494d0, 0xd270e098, 0x95f0d17c, 0x3d3c2aca, 0xb4c4471d, 0xf3ecc9f4, 0x02249753,
+    0x9658e38f, 0xccacd2e8, 0xbb062ac9, 0x8c87474a, 0x0c5e4800, 0x59b65400, 0x44920200, 0x005f0100,
+    0x9ba88180, 0x2581c240, 0x79d063e0, 0xf87d1110,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0xc8000000, 0x84000000, 0x62000000, 0xb7000000,
+    0x37800000, 0x6bc00000, 0x94200000, 0xc2100000, 0x47080000, 0x4f8c0000, 0xf7da0000, 0xba390000,
+    0x93008000, 0xa5844000, 0xa4dae000, 0x3fbcf000, 0x87c47800, 0x822a0400, 0x6f080200, 0x5b8c0100,
+    0x3dda0180, 0x59390340, 0x0e808320, 0xfd444210, 0x657ae188, 0x216cf2dc, 0x636c78de, 0x647605af,
+    0x4bfa0050, 0x6c290208, 0x1e08809c, 0x0908427e, 0xce80e0ff, 0xdd45f0f8, 0xd564fbc4, 0x397e444a,
+    0x2f7ae04d, 0x826cf051, 0x9eec79cf, 0xecb60640, 0x425a0180, 0xf6f90340, 0x98a08320, 0x58544210,
+    0xddf2e188, 0x8120f2dc, 0x629678de, 0xab5f05af,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x58000000, 0x7c000000, 0x42000000, 0xaf000000,
+    0xac800000, 0xe2400000, 0xebe00000, 0xfe100000, 0xcd080000, 0x538c0000, 0x26c60000, 0x2dab0000,
+    0x2bff8000, 0xde104000, 0x9d11e000, 0x3b9f9000, 0x02d14800, 0x13b9fc00, 0xc6e80200, 0xdd9c0100,
+    0xd3ce0180, 0x322700c0, 0x17398160, 0x20bb41f0, 0x586e6108, 0xa8cfd2bc, 0xd8a0aab2, 0x34766f89,
+    0xe2d149af, 0x63b9fef8, 0xfee800b4, 0x919c008e, 0xc9ce007b, 0xe1270186, 0xf9b980c7, 0x6dfb4034,
+    0x1f0e61ce, 0xb49fd1db, 0xfe48a916, 0x99ea6c3f, 0x091f4980, 0x1d9efcc0, 0xf3d18360, 0x622740f0,
+    0x7f206088, 0x04a8d27c, 0x66792bd2, 0x45dd2e79,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x48000000, 0xdc000000, 0x42000000, 0x87000000,
+    0x0d800000, 0x06400000, 0xda600000, 0xbe100000, 0xd5080000, 0x62940000, 0x87ca0000, 0xd6290000,
+    0x3f6b8000, 0x24904000, 0x36cce000, 0x32bfb000, 0xe9b3c800, 0xe631cc00, 0xc7680200, 0x40840100,
+    0xb0c20280, 0x63bd0140, 0xfd218120, 0x28f94370, 0x91c76108, 0x2f3ff21c, 0x07f72836, 0xb05a7c19,
+    0x7371c969, 0xce8ccff8, 0x9dc983d4, 0x653d41ca, 0xfce561bf, 0xafd2f168, 0xba3ea8d5, 0xc5673dfe,
+    0x6794abe5, 0xed5e3fcf, 0x2df72879, 0xfb5a7d39, 0xd4f1ca80, 0xc3cccd40, 0x40298320, 0x866d4270,
+    0xbc0d6388, 0xf216f35c, 0x3f1ca916, 0xc98a3f69,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0xd8000000, 0x44000000, 0xb6000000, 0xdb000000,
+    0xf8800000, 0x67c00000, 0xbe600000, 0xaa100000, 0x69080000, 0x759c0000, 0x144a0000, 0xf93b0000,
+    0x37b88000, 0xcb6ec000, 0x6d9f6000, 0xf0411000, 0xff21f800, 0x64be4c00, 0xafe80200, 0xf84c0100,
+    0x23220380, 0x76b70140, 0x92fa8360, 0x03c9c110, 0xf86de2d8, 0x1914d36c, 0x5d861be2, 0x38519d9f,
+    0x833698f9, 0xc6a35fa8, 0x1ae3f824, 0x9fd94d96, 0x0a7a82b1, 0x7409c2b4, 0x7e0de2e6, 0xa704d011,
+    0x5a8e1bec, 0xd2cd9c22, 0xd9fc9a3f, 0x83585cc9, 0x6bbb7b80, 0x99678d40, 0xb08de160, 0x5bc4d010,
+    0xfc6e1958, 0x4f1d9e2c, 0xd6949a82, 0x18d45d8f,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xc8000000, 0x8c000000, 0xe2000000, 0xcd000000,
+    0x90800000, 0x13400000, 0x5ba00000, 0x96100000, 0x8b180000, 0x63840000, 0x14ce0000, 0x51790000,
+    0x20618000, 0x7af44000, 0x78b5a000, 0xe9901000, 0xe5c96800, 0xcbe9f400, 0x02380200, 0x9bd40300,
+    0xbcf60080, 0x4bad01c0, 0xce178320, 0x9f194230, 0xb5822388, 0x7fd95334, 0x62f34a42, 0x8ca4a44d,
+    0x6f9d4b6e, 0x36cda558, 0xbc64c8ac, 0xc0fde74e, 0xa1bf69f3, 0xaf04f5b5, 0x9d8f82a9, 0xa3dd422f,
+    0x38ec2388, 0x05b0532f, 0x910ac813, 0x0a94e43e, 0xba46e880, 0xd534b5c0, 0x62542120, 0x01245130,
+    0x945ccb08, 0x1a29e6f4, 0x6fc96b62, 0x3ae9f57d,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xa8000000, 0x64000000, 0xda000000, 0xfd000000,
+    0xf4800000, 0x6c400000, 0xc8200000, 0x4d100000, 0x4c980000, 0x405c0000, 0xa63a0000, 0xc2050000,
+    0x21148000, 0x02874000, 0xff45a000, 0xb3ab9000, 0x4cd10800, 0xc67a4400, 0x543a0200, 0x9b050300,
+    0xef948380, 0x43c74340, 0x6be5a2a0, 0xf6fb9190, 0x12690b68, 0x363647f4, 0x4a1801d2, 0x751c02b1,
+    0xa09a00a0, 0xce550274, 0xf92c8392, 0x078b4091, 0x07c7a1f0, 0x81e290fc, 0x73e78b56, 0x2ae405bb,
+    0xe47122dd, 0xa53cd0fa, 0x318cab81, 0x74cdd558, 0x2a710b68, 0xda2a47f4, 0xc40201d2, 0x2a0902b1,
+    0x651680a0, 0xe88e4274, 0x7a532392, 0x8b25d091,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x98000000, 0x5c000000, 0xa6000000, 0xd1000000,
+    0x72800000, 0xb9400000, 0x32600000, 0x29100000, 0xbe880000, 0x675c0000, 0x6f7a0000, 0x65830000,
+    0x8ac38000, 0x81a8c000, 0x2e302000, 0x9be55000, 0x7dd8a800, 0x623c8c00, 0x05fa0200, 0xc0c30100,
+    0xfea38380, 0xa9b8c340, 0x7a382260, 0x19f95170, 0x86c2aa98, 0xffaf8f44, 0x433183ca, 0x9f77c3e5,
+    0x8d89a349, 0x7ece93e4, 0x33ab089a, 0x9d311ced, 0xc2728925, 0xc10adfd2, 0x4a892a61, 0xd55b4ea3,
+    0xdc7ba391, 0x3c1191fb, 0x36128875, 0xa91add61, 0xfe812a98, 0x87474f44, 0xbf61a3ca, 0xfd8293e5,
+    0xd6d90b49, 0x27ae1fe4, 0xff2b0a9a, 0xe9711ded,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0xf8000000, 0x94000000, 0xea000000, 0xe1000000,
+    0x84800000, 0xf3400000, 0x5c200000, 0xc9100000, 0xb8880000, 0x65440000, 0x0b260000, 0x2a8f0000,
+    0x505a8000, 0x45b94000, 0x08ca2000, 0x70e79000, 0xebf8e800, 0x0668c400, 0x3da60200, 0xdccf0100,
+    0xbafa8080, 0x3ae940c0, 0x7ae223e0, 0x5af39250, 0x6af6eba8, 0x92f3c784, 0x06f28012, 0xeced42cd,
+    0x0de421f0, 0x896c93e4, 0x7a246902, 0x260e87c5, 0xef1ea184, 0x5785d22e, 0x32c64bd3, 0x39fd161b,
+    0x136848b3, 0x433615b7, 0x0694c865, 0x0e4057c8, 0x7ea46ba8, 0x954e8784, 0x933ea012, 0xae95d2cd,
+    0x724e49f0, 0xc8b917e4, 0xf24e4b02, 0x88b916c5,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x68000000, 0xfc000000, 0x9e000000, 0xe5000000,
+    0xf1800000, 0x12c00000, 0xdb600000, 0x69100000, 0xc7980000, 0x2bc40000, 0x44fe0000, 0xf6dd0000,
+    0x11678000, 0xce07c000, 0xbd1aa000, 0x45973000, 0x80c3d800, 0xc865ac00, 0x819e0200, 0xbacd0300,
+    0x077f8080, 0x0703c3c0, 0x4a84a1a0, 0x265a33f0, 0xc83c5a78, 0xc8a66f94, 0x89faa1c6, 0x1b47334b,
+    0x4dbbdbed, 0x6871ae64, 0xb19800be, 0xf2c4005f, 0x0b7e03eb, 0xf11d038f, 0x53878223, 0x49d7c083,
+    0x3fe2a3e1, 0xe2433326, 0xf225dadb, 0x07bcafc5, 0x0f678278, 0xeb07c394, 0x6c9aa3c6, 0xa757304b,
+    0x33a3db6d, 0x5d75ada4, 0xd806011e, 0x740903af,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x58000000, 0x7c000000, 0xc6000000, 0x25000000,
+    0x71800000, 0x06c00000, 0x52600000, 0x81100000, 0x8b880000, 0x45d40000, 0x16e20000, 0xae5b0000,
+    0xa3278000, 0x50bcc000, 0x397ea000, 0x11893000, 0xf6d15800, 0xba6f8c00, 0xb5020200, 0x698b0100,
+    0xdacf8280, 0x8478c040, 0xfc14a160, 0x860631f0, 0x8514db18, 0x61884c94, 0x5edb23c6, 0x2e7ef11b,
+    0x47007bc9, 0xae8e7e44, 0x344ff94e, 0x1036bde7, 0xfc3b5bc3, 0x22208f6d, 0xdb2782aa, 0x7cbcc1e9,
+    0x077ea154, 0x58893366, 0x19515a4b, 0xe5af8e81, 0x50e20318, 0xcb5b0094, 0x72a781c6, 0x467cc01b,
+    0x331ea149, 0xec993204, 0xbb595a2e, 0xdabb8d17,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x98000000, 0x64000000, 0xbe000000, 0xe7000000,
+    0xec800000, 0xe2c00000, 0x17600000, 0x29100000, 0x63980000, 0xc2440000, 0xa7a20000, 0x9b630000,
+    0x9b048000, 0xf68bc000, 0x9bdae000, 0x0cec9000, 0xbf53d800, 0xf221ac00, 0x423a0200, 0x0a270300,
+    0xd6268080, 0x1c28c040, 0xdd3e6260, 0xb2b75190, 0x02f13af8, 0x10593f9c, 0xc2b3d9b2, 0x6af1ac8b,
+    0xdc4202dd, 0x90b303e4, 0xcffc836e, 0x0ddfc2d9, 0xc7e0e206, 0x31cb9061, 0x3df55b26, 0x78c96fcd,
+    0xae646380, 0x1280501c, 0xe5cfbbae, 0xcbf5fc25, 0x43cfba78, 0x88f5ffdc, 0x314fbbd2, 0x9d35fd1b,
+    0x52afb825, 0x32e5fc78, 0x9857badc, 0x3eb1fe52,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x68000000, 0x5c000000, 0x5a000000, 0x0d000000,
+    0xf5800000, 0x03400000, 0xcf600000, 0xbf100000, 0x64980000, 0x4cdc0000, 0x71360000, 0xedfd0000,
+    0x84c38000, 0xbd2f4000, 0x8ffba000, 0x0dcdb000, 0x26a2e800, 0x87a32c00, 0x602e0200, 0x62610300,
+    0xda958380, 0x5fc242c0, 0x07a021a0, 0xa03ef170, 0x826f4968, 0x6a939c34, 0x37cf69d6, 0x5bad6f0d,
+    0xfa2022bd, 0x8f7ef33c, 0x9f0f4bb2, 0x34839e83, 0x94d76b0c, 0x45316df3, 0xebf6212d, 0xd3d3f30d,
+    0x45b4c8f4, 0x7920dd76, 0xc1e2c8fd, 0x56cddf95, 0x5c394ae8, 0x487e9ef4, 0x5f94e876, 0x465e2e7d,
+    0x36ed83d5, 0x3e4e4308, 0x92ee2064, 0x004ff28e,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xd8000000, 0x6c000000, 0xea000000, 0x5d000000,
+    0x2d800000, 0x4a400000, 0x36a00000, 0x5b100000, 0xda880000, 0x5acc0000, 0xa17e0000, 0xffeb0000,
+    0xdb358000, 0x31494000, 0x7c31a000, 0x29dcb000, 0xcfe9c800, 0x23311400, 0xcd560200, 0xbe370100,
+    0xc0c38180, 0x646e43c0, 0xde7a2360, 0x8b7ef1b0, 0x82edeba8, 0x66a4e574, 0x530e6ab6, 0xfe9aa429,
+    0xf4dc4b5a, 0xa27857ac, 0x0967a38a, 0x0bebb21b, 0x3d2a4bcd, 0x765f54fb, 0xd4ac2112, 0x2209f028,
+    0x590e6bb4, 0x139aa656, 0x615c4959, 0x74385512, 0x0dc7a228, 0x61fbb2b4, 0x20224bd6, 0x3bd35499,
+    0x6ef22172, 0xccb2f118, 0x6e13ea5c, 0x230fe782,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x28000000, 0x44000000, 0x3e000000, 0xd1000000,
+    0xd4800000, 0xb0c00000, 0xc1e00000, 0xcb100000, 0x2b880000, 0xcd540000, 0x39be0000, 0xe8350000,
+    0x8a708000, 0x164bc000, 0x6a382000, 0xb9659000, 0xe1dd9800, 0x556e7c00, 0xdbd60200, 0x1a710100,
+    0x2e468280, 0x562ac3c0, 0x1b76a0a0, 0x62db5110, 0x2af538f8, 0x50902f44, 0x6edbb952, 0x40feefc3,
+    0xc79b1b87, 0xf744bdec, 0x76a0a08e, 0x1daa50e5, 0xc633ba3e, 0x237aee34, 0x5ecd1b23, 0x88f5bdce,
+    0xd3862185, 0x1150928e, 0xcbad195c, 0xb325bd5f, 0x99ee2278, 0xe7149084, 0xf19b1bf2, 0xd244bfd3,
+    0x1420a1ff, 0xc86a5168, 0xc553b97c, 0xcdaaee36,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x68000000, 0x3c000000, 0x6a000000, 0xa3000000,
+    0xf0800000, 0x9c400000, 0x93600000, 0x25100000, 0xf1880000, 0x03dc0000, 0xeda20000, 0xdd3d0000,
+    0xe17f8000, 0x82094000, 0xdf026000, 0x7a8d1000, 0x2f5b0800, 0x0bf5ac00, 0x854a0200, 0x08f10100,
+    0x85d58380, 0xeca84040, 0x42bfe1a0, 0x9fa950f0, 0x7a2ee9a8, 0xcfedfe8c, 0xfb5169c2, 0x1de4bf71,
+    0xac530bcd, 0x4b69add4, 0x810803e6, 0xdf9c01bf, 0x9ec2003e, 0xe82d0148, 0x78f7804f, 0xbdd54205,
+    0x58a0635b, 0x04b01183, 0x3ea48af4, 0x15bceec5, 0xc9286228, 0x576c12cc, 0x5b068a62, 0xe481ee81,
+    0x2a57e1e5, 0x4a655318, 0x1e84eb84, 0xa14cfe3e,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0xc8000000, 0x7c000000, 0x0a000000, 0x07000000,
+    0xa6800000, 0xa4400000, 0xfaa00000, 0x19100000, 0x67880000, 0xe7c40000, 0x737e0000, 0x23690000,
+    0x5b778000, 0xcf7d4000, 0x4962e000, 0x8c6fd000, 0x51e00800, 0x592a9400, 0x00d60200, 0x45fd0100,
+    0x9f218080, 0xe5c043c0, 0x68636320, 0x5fff91f0, 0xc02b6828, 0x2741041c, 0x822b689a, 0x1c410791,
+    0x0eab696a, 0x4f0106a4, 0x9a8b683e, 0x8e5104af, 0x0da36845, 0x778507a1, 0xbfd56a7f, 0x17680680,
+    0x6d7ce9dd, 0xe26c47e8, 0x18e18821, 0x46bad722, 0x731d60a8, 0xb09693dc, 0x795ce9ba, 0x633c4761,
+    0xafc989c2, 0x4f6ed578, 0x096b6184, 0xac7b93ce,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x58000000, 0x7c000000, 0xc2000000, 0xa3000000,
+    0x0e800000, 0x2e400000, 0xdf200000, 0xbe900000, 0xa6580000, 0xdb240000, 0x10920000, 0x9f530000,
+    0x0aab8000, 0xf24a4000, 0xcd276000, 0x659a5000, 0xc4c78800, 0x6f608400, 0x10ab8200, 0xed4a4300,
+    0x21a76080, 0xf8da5040, 0x4d678960, 0x83b085f0, 0xabd38108, 0x2bfe418c, 0x99ed60ba, 0x92ed50f9,
+    0x887e0a1c, 0x5039c70a, 0xca076391, 0x670a52e0, 0x809f88f8, 0x07448684, 0x56b98036, 0x205943c3,
+    0x362ce225, 0x17001036, 0x2898ea0b, 0x134ed689, 0xa0a60a1c, 0x655dc70a, 0x25b56391, 0x56c952e0,
+    0x746c08f8, 0x522ac684, 0x490ce036, 0x799013c3,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0xe8000000, 0x7c000000, 0x2e000000, 0x7f000000,
+    0x93800000, 0x84400000, 0x17a00000, 0xbb900000, 0x18480000, 0x09b40000, 0xbc820000, 0x1fd50000,
+    0xdffb8000, 0xfa27c000, 0x48cae000, 0xd061f000, 0x60634800, 0x587a1400, 0x6c7b8200, 0xae67c100,
+    0x176ae280, 0x87f1f240, 0xbe2b4ba0, 0x52ce15f0, 0x6d7982b8, 0x4af2c0fc, 0x4cb160ce, 0x42063051,
+    0xf909abfe, 0x308be71e, 0xa9d0cad9, 0x04e9d4da, 0x43b3603c, 0x0993312e, 0x79522a01, 0x9d3c26b6,
+    0x3f52285a, 0xde3c266b, 0x22d22905, 0xb57c25b3, 0x4ef22bfe, 0xf6ac271e, 0x6f1a2ad9, 0x3b8824da,
+    0x5850283c, 0xa9a9252e, 0x2c89a801, 0xf7cbe7b6,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x68000000, 0x6c000000, 0xb2000000, 0x57000000,
+    0x30800000, 0x67400000, 0xace00000, 0x7c900000, 0x05480000, 0x33f40000, 0xb0020000, 0xb8050000,
+    0xa4198000, 0x4e044000, 0x8d07e000, 0x0b8b9000, 0xe5daa800, 0x9cbf7c00, 0xe0f98200, 0x1e944100,
+    0x9a4fe280, 0xff7f9240, 0x0d58a9a0, 0x2ffa7db0, 0x5a0000c8, 0x7b00005c, 0x22800242, 0xa04003dd,
+    0xf4600313, 0x77d00042, 0x1ba802dd, 0x18640193, 0x85ca0202, 0xecb1037d, 0xb8fb8023, 0x8a9140ca,
+    0x2c566221, 0x767bd0e1, 0xd8df4957, 0x2f31ec92, 0xa13aab13, 0xcc2f7c42, 0xf7b180dd, 0xea604093,
+    0x72cde082, 0x4c3a913d, 0xb7a12983, 0x4a6e3d7a,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x08000000, 0x64000000, 0xca000000, 0x7b000000,
+    0xe3800000, 0x21c00000, 0xea200000, 0x37900000, 0x23d80000, 0x55340000, 0x2e160000, 0x71190000,
+    0x38988000, 0xf25f4000, 0xc3ee6000, 0xb9a0f000, 0xde557800, 0x0dfe2400, 0x98b88200, 0x7ecf4300,
+    0xa3b66280, 0xfd54f0c0, 0x12637820, 0x2f772590, 0x49f80128, 0xa2a402ec, 0xadce010e, 0x142d0047,
+    0x1e8e8388, 0xe746414e, 0x3176e3a7, 0x30ffb0b8, 0xfe3b1b36, 0x959ed543, 0xaccdf94a, 0x44a16417,
+    0x18d6e300, 0xd6afb072, 0x9fc31961, 0xa33ad4a3, 0x6303fb88, 0x7f8c654e, 0x27d861a7, 0x0f29f3b8,
+    0x6d15f9b6, 0xfe956583, 0xb740e16a, 0xa976b187,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0xa8000000, 0x44000000, 0xe6000000, 0x75000000,
+    0xd9800000, 0x3c400000, 0xade00000, 0x05900000, 0x56580000, 0xaaf40000, 0x971e0000, 0x2a8d0000,
+    0x40dc8000, 0x9032c000, 0x782ba000, 0x5c3bb000, 0x6a274800, 0xc7291c00, 0xf2bc8200, 0x6ce2c300,
+    0x5213a280, 0xfb1fb1c0, 0x48814aa0, 0x73c01d10, 0x4ca60198, 0x35e902d4, 0x899a81e6, 0x244bc131,
+    0xe1e92017, 0xd7847106, 0x6d506ac1, 0x42606f7f, 0x94d06b3a, 0xce206f5b, 0x31306b34, 0xffb06c96,
+    0x29686a99, 0x64446c0b, 0x81f66a4c, 0x07896db2, 0xb54ae817, 0xae6bad06, 0x36d948c1, 0x5d341c7f,
+    0x9db801ba, 0x1a64029b, 0xb8c60394, 0xcc390286,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x48000000, 0x24000000, 0x06000000, 0x75000000,
+    0x82800000, 0x03400000, 0x10600000, 0x5e900000, 0xf9480000, 0xef7c0000, 0x5b0a0000, 0x43810000,
+    0x2fd88000, 0x923ac000, 0x8277a000, 0xf58cd000, 0x82d4a800, 0x9caaac00, 0x73388200, 0xdeeac100,
+    0x0fdfa380, 0xe220d340, 0xfa76a920, 0x4987ac90, 0xe8c20218, 0xcbbd00d4, 0x82b2818a, 0x2a2bc34d,
+    0x9e672161, 0xaf8a11ea, 0x4dc90bfd, 0x01377e69, 0xa5fca966, 0x3c46ac03, 0xb5fa817e, 0xd457c172,
+    0xa1ed234c, 0x4a4b13bb, 0xb8f18a5f, 0xeaddbe38, 0xc8a30961, 0x0d267dea, 0x17ec29fd, 0xe7406f69,
+    0xb66722e6, 0x1b8a1343, 0xe3c90a5e, 0x80377ce2,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x28000000, 0x6c000000, 0xa2000000, 0x65000000,
+    0x7d800000, 0x07400000, 0x62e00000, 0xff900000, 0x92480000, 0x97740000, 0x24c60000, 0x7aad0000,
+    0x61e98000, 0x691a4000, 0x8f95a000, 0x5a403000, 0xeb67a800, 0x1ed87c00, 0x5ba18200, 0xd26e4100,
+    0xa953a280, 0xf5ed32c0, 0xdf0e28a0, 0x1c823db0, 0x14d42088, 0x12be7094, 0xadfc0b76, 0x7b014edd,
+    0xc289ab2b, 0x4bd17f4e, 0x4e260041, 0x193d0009, 0xd9a18345, 0x476e4287, 0x5cd3a224, 0x2ead327e,
+    0x37ee2b09, 0xea123ebd, 0x591c2143, 0xe78a7372, 0x965a09ab, 0xf97c4d8e, 0x53c828e1, 0x4a2f3db9,
+    0x773da3cd, 0xaea43213, 0x77e9a952, 0x4a017ca3,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x58000000, 0xbc000000, 0x0a000000, 0x63000000,
+    0x81800000, 0xcf400000, 0x69200000, 0x3b900000, 0xb4480000, 0x74a40000, 0xced60000, 0xe6630000,
+    0x72a38000, 0xbfdf4000, 0x98eb6000, 0x0675b000, 0x22ada800, 0xf7c1c400, 0x4ceb8200, 0xe87b4100,
+    0xf7bd6080, 0x1f56b0c0, 0x612e2960, 0xcf8e86f0, 0x6a48e228, 0xf9aaf08c, 0x9a46ca86, 0xc1b477fd,
+    0x36462ac4, 0xa3ba871e, 0xb156e079, 0x942df19e, 0x171348dd, 0x1f983694, 0x6246cb26, 0x0db4746d,
+    0x4446281c, 0x4cba86ba, 0x62d6e3f3, 0x846df0a5, 0xf5b34a44, 0x884837de, 0x3eaec919, 0x8dc0776e,
+    0x57f82af5, 0xe5ed8618, 0x6aeb61a0, 0xa975b390,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x18000000, 0x3c000000, 0xb2000000, 0x37000000,
+    0xf3800000, 0xe4400000, 0xe0600000, 0xd9900000, 0x2f580000, 0xc1ec0000, 0x3ad60000, 0x24bb0000,
+    0xc03b8000, 0x47f84000, 0x0bc1e000, 0x262bf000, 0x46f3c800, 0x11506c00, 0x9ce38200, 0xa2544300,
+    0xb177e180, 0xeb00f0c0, 0xb1904860, 0xab442cf0, 0x1ff460c8, 0x97c4b3dc, 0xc43faa4e, 0x59e8df51,
+    0xc6d229e1, 0xf6ab9c96, 0xc72849f5, 0xac782c1b, 0xd39a60c5, 0x7443b033, 0xa86a2ba9, 0xcd979e95,
+    0xb9464bf4, 0x78ff2f22, 0x4c4fe0f7, 0x047cf33c, 0x379e4861, 0x9a532c56, 0x1d79e195, 0x1117f0eb,
+    0x929dc80d, 0xced76fef, 0x42b603e7, 0x012b02c4,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x48000000, 0x74000000, 0x92000000, 0x6d000000,
+    0xe3800000, 0x88c00000, 0x7b600000, 0x29900000, 0x79d80000, 0x4eec0000, 0x465a0000, 0xb0310000,
+    0xea328000, 0x33304000, 0x22a0a000, 0x977cf000, 0x47845800, 0x92db4c00, 0x026a8200, 0x081c4300,
+    0xd41aa180, 0xa21df140, 0x750ed920, 0xdf970dd0, 0x6ec82048, 0x847db2b4, 0xa7167a0e, 0x1297ff63,
+    0xbd4e7acd, 0x14bbfe76, 0xe074782f, 0xdd1afecf, 0x3b9ef837, 0xd4c6bf73, 0xcd645aad, 0x9e8b4e4e,
+    0x8352820b, 0xb7a04361, 0xd8f8a08c, 0x0150f32a, 0x32be5b4d, 0x7f7a4f36, 0x0380010f, 0x18c0031f,
+    0x5360007f, 0x0d9001c7, 0xa3d802a3, 0x57ec022d,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x78000000, 0x2c000000, 0x6e000000, 0xd9000000,
+    0xde800000, 0x15400000, 0xa8e00000, 0xa4900000, 0x2e580000, 0xf16c0000, 0x9e5a0000, 0x19770000,
+    0xaa598000, 0x0b7fc000, 0x65482000, 0x20ec5000, 0xc09c6800, 0x4459bc00, 0x12618200, 0xdbc3c300,
+    0x65aa2180, 0x10675140, 0xccc7e9e0, 0x523d7cb0, 0xf3aa23b8, 0x25675064, 0x1c47eafa, 0xce7d7d15,
+    0xfdca2143, 0xb8b75122, 0xf4ffe881, 0x42817de1, 0x934822e3, 0x45ec5380, 0x681c6832, 0xf419bc9b,
+    0x720181b6, 0x9f13c0a3, 0x539223e0, 0x89db51c2, 0x0aa5e8c3, 0x0bf67c62, 0x4111a361, 0x62939251,
+    0x635448db, 0x0df5eea4, 0x6c1de928, 0xce0a7e3e,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x58000000, 0x7c000000, 0xae000000, 0xb7000000,
+    0xf7800000, 0x35400000, 0xfc200000, 0x9d900000, 0x00580000, 0x2aac0000, 0x04420000, 0xc8b30000,
+    0xb5538000, 0x3c3fc000, 0xfd81e000, 0x905b7000, 0x72b46800, 0x7844e400, 0x66ab8200, 0x0243c300,
+    0xcbbbe180, 0xc8d47240, 0x6c7de960, 0xef2425f0, 0x781be0b8, 0x4c0471dc, 0x0605ea5e, 0x03182695,
+    0x7d81e090, 0x505b7086, 0x12b46b39, 0xe844e436, 0x3eab80af, 0x7e43c107, 0x65bbe21d, 0x7fd4721a,
+    0x9bfde949, 0xda6427ce, 0x843be293, 0xd1947169, 0x065de910, 0x29b426c6, 0x79c3e059, 0x98e872c6,
+    0xa7e7e997, 0xd47b269b, 0xc32a6323, 0xee18b27f,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xb8000000, 0x8c000000, 0xbe000000, 0xf5000000,
+    0xc2800000, 0xdac00000, 0xfd200000, 0x58900000, 0x35d80000, 0x10a40000, 0x4fde0000, 0xafaf0000,
+    0x6a458000, 0x8ce1c000, 0xc23da000, 0xbd0fd000, 0x36879800, 0xc8da8400, 0xc63d8200, 0xd715c300,
+    0xa19ba080, 0x8954d1c0, 0x1f641ae0, 0x00604630, 0x95e3a0f8, 0x2ea0d0d4, 0x7ac21b8a, 0x4d3b46ab,
+    0xc0802314, 0xc9da1052, 0x16bc3aaf, 0x36de5656, 0xd3219ad5, 0x458184e5, 0xb35e0225, 0x406f0245,
+    0x75e582b5, 0x7eb1c22d, 0xb2c5a209, 0x793bd11b, 0xf2819b94, 0x82d18592, 0x2126024f, 0x2e9b0366,
+    0xf4c382ad, 0xe02ac1f1, 0xde06214f, 0x651111de,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xc8000000, 0xc4000000, 0xf6000000, 0xe3000000,
+    0x38800000, 0xe7400000, 0x0da00000, 0x1b900000, 0xffc80000, 0x7aec0000, 0x2e2e0000, 0xe8410000,
+    0xb73c8000, 0x41dcc000, 0x1df36000, 0x80ba9000, 0x1c1ca800, 0x4a1e1400, 0x79128200, 0x199dc100,
+    0x02cfe180, 0xf56653c0, 0x3fefcb20, 0xeda48710, 0xab8e29d8, 0x57c3d68c, 0x4efd6362, 0x102b915d,
+    0xcf482a96, 0x79bed4be, 0x4587e107, 0x28ca5077, 0x7c61ca02, 0xee758470, 0x2b7aab28, 0xa8f314e4,
+    0x8b2003e6, 0x9bd002cb, 0x5ce80175, 0x953c002f, 0x2cc6034e, 0xaa7d0232, 0x9d7a8265, 0x2be1c12a,
+    0x43a9e094, 0xb48b50ce, 0x955d4a2f, 0x78a94493,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x78000000, 0x04000000, 0x32000000, 0x4f000000,
+    0x5e800000, 0x3fc00000, 0x18a00000, 0xe1900000, 0x69580000, 0xeb740000, 0xa7320000, 0x89c70000,
+    0xa5ac8000, 0x50004000, 0x181ce000, 0xb4105000, 0x5a033800, 0x3303b400, 0x689e8200, 0x42c74300,
+    0x09306280, 0x80d01040, 0x4e3fd9e0, 0x1243e410, 0x2fe5bac8, 0x0be0f63c, 0xe9e4e1fa, 0x7ef453bf,
+    0x34693b02, 0x41b0b7d6, 0x1200028d, 0x9f000181, 0x868001ae, 0x2bc003a4, 0x52a0026e, 0xaa900229,
+    0x05d8016f, 0x9bb403c7, 0xe112032b, 0x579701b9, 0xd45481ca, 0x5ae441ea, 0xd676e377, 0xd6a3523e,
+    0x589dbaac, 0xeac4f472, 0xd52ee0e3, 0xa6d753a8,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x08000000, 0xbc000000, 0x02000000, 0x75000000,
+    0xf3800000, 0x0dc00000, 0xa8a00000, 0xca900000, 0x54580000, 0xbc6c0000, 0x2ba60000, 0x52090000,
+    0x0d038000, 0x279a4000, 0x03d9a000, 0xd7a8f000, 0xf0067800, 0xa803f400, 0x6c058200, 0xba034300,
+    0xc1022180, 0x4d9eb2c0, 0x7ad9d820, 0x2e3206f0, 0x34d87a08, 0xf136f6d4, 0xde58004e, 0xb56c01f7,
+    0xba260302, 0x9ac9031a, 0x5e238179, 0x5cca41d5, 0xfd21a368, 0xd454f0ab, 0x7c787958, 0x4ba6f783,
+    0xe20001fc, 0x05000185, 0x9b8003bf, 0x01c003d9, 0xa2a0010a, 0x039001ce, 0xa5d80337, 0xc4ac0322,
+    0x708601ea, 0x95590171, 0xf1fb8001, 0x516640a6,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x88000000, 0xac000000, 0x6a000000, 0xb9000000,
+    0x8b800000, 0x40400000, 0x48200000, 0x6e900000, 0x79c80000, 0xf56c0000, 0x7b320000, 0x0c090000,
+    0x5a118000, 0x41034000, 0xbf842000, 0x0e55f000, 0x3730c800, 0x36036400, 0x0b038200, 0x769a4100,
+    0x1ddda180, 0x537ab040, 0xb826ea20, 0xb68f96b0, 0x3dcacba8, 0x236667e4, 0x202001ae, 0x92900041,
+    0xfbc80280, 0xf06c034a, 0x12b2026f, 0x59490381, 0xf3b1826a, 0xd6d343d5, 0x05ec2266, 0xd5e9f1bf,
+    0x7deaca39, 0xa1f66656, 0x53e802d7, 0xcefc023d, 0x837a0128, 0x102500ae, 0x6a8381c1, 0xcfda42c0,
+    0xbe7da16a, 0x6daab0df, 0x01ceea29, 0x8173948e,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xa8000000, 0x8c000000, 0x76000000, 0x13000000,
+    0x7c800000, 0x6ec00000, 0xad600000, 0xfb900000, 0x80580000, 0x8ab40000, 0x7f760000, 0xd29d0000,
+    0x39dd8000, 0xbbfcc000, 0x68416000, 0x66b31000, 0x99769800, 0x59970c00, 0x614b8200, 0x2f31c300,
+    0xa024e280, 0xf2abd0c0, 0x0b79faa0, 0x109d1e30, 0x48ce9bd8, 0x86730f4c, 0x93058172, 0xbc88c27b,
+    0xced76295, 0x9d7e111e, 0x53931979, 0x0c4fcc56, 0xfca4e3d7, 0x6c6bd1bd, 0xae19f920, 0x570d1c7a,
+    0x169699b5, 0x93c70d6e, 0xe6f380c1, 0x13d5c38a, 0x26eae14d, 0xb3d2d252, 0x16ea7a0b, 0x1bd8dd2d,
+    0x9afc7bc2, 0x6dd5dc63, 0x89f9f8f9, 0x115d1d1c,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x98000000, 0xdc000000, 0x0a000000, 0x73000000,
+    0x8b800000, 0xb6c00000, 0x3ae00000, 0x12900000, 0x5e580000, 0x4fb40000, 0xaabe0000, 0x383b0000,
+    0xa6678000, 0x49d34000, 0x937f6000, 0x3353f000, 0x49291800, 0x0ae4fc00, 0xea818200, 0x525c4300,
+    0xada6e280, 0x9dbbb1c0, 0x65b1fa60, 0x69a44f70, 0x8bb7fa28, 0xbcbb4ecc, 0xe1367aae, 0x2ee70c1b,
+    0xec909a0b, 0xc35cbdfa, 0x112163b1, 0x76f8f142, 0x9096984c, 0xb943bd47, 0xfa20e014, 0x2164b20a,
+    0x2c507b59, 0xf0a80f6e, 0x63291852, 0xc9e4ff14, 0x590182a3, 0x489c42f6, 0x0546e37f, 0x202bb229,
+    0xba69f86f, 0xe3d04e71, 0x9069f90b, 0x20d04f53,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x58000000, 0x4c000000, 0x76000000, 0x53000000,
+    0x11800000, 0x76c00000, 0x57e00000, 0xdc900000, 0x60480000, 0xaab40000, 0xe8b60000, 0xf1b30000,
+    0x3d258000, 0x7564c000, 0x6bcb6000, 0x897ab000, 0x05c09800, 0x3662dc00, 0x525b8200, 0x4ba3c100,
+    0x5838e280, 0x97fd70c0, 0x3c867960, 0xf058ad30, 0xc2ae7bd8, 0xfcbcac4c, 0xcbb078c6, 0x182bac1b,
+    0x37ebf8bf, 0x0c886f82, 0xa85319b9, 0x8eb51f16, 0x8ab563dc, 0x98bdb3a1, 0x09b31bd5, 0x41251fc0,
+    0x5b7d638f, 0x74c9b35a, 0xeee51875, 0x20061e90, 0x7010e1e7, 0xf819730e, 0x7c18781f, 0x2e0fae3d,
+    0x1f15f8bb, 0x678f6c6f, 0x25c098aa, 0x4662ddcd,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0xa8000000, 0x34000000, 0x96000000, 0x63000000,
+    0xb7800000, 0xaa400000, 0x1ae00000, 0xd6900000, 0x58c80000, 0xec240000, 0x15ae0000, 0x00ed0000,
+    0x2f9a8000, 0x5640c000, 0x68e36000, 0x7b8b7000, 0x105c0800, 0x53ff7c00, 0x501c8200, 0x1819c100,
+    0xbc1fe080, 0x5202b3c0, 0x5d0beaa0, 0xe099ccd0, 0x8bd96858, 0xd3bd0c8c, 0x7bf4085e, 0x240b7c69,
+    0xae1a804b, 0x2f00c14a, 0x4d83611b, 0xc35b712c, 0x6c740a70, 0x3e4b7ff6, 0x3cfa8253, 0x3d90c026,
+    0x2b4b62f5, 0x787f706d, 0x585a0bbf, 0xf7e67d76, 0xbe000093, 0x17000206, 0x018001e5, 0x39400095,
+    0x056002e3, 0x48d001f0, 0xd42801b6, 0x59b401b3,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x18000000, 0x1c000000, 0xde000000, 0x13000000,
+    0x4b800000, 0x8cc00000, 0x17200000, 0xe2900000, 0xe6480000, 0xb5740000, 0x35f20000, 0x0ebb0000,
+    0xfb4f8000, 0x15e5c000, 0x5ea76000, 0x4353f000, 0x19f6d800, 0x98bec400, 0x4c558200, 0x8c7ac100,
+    0x0772e280, 0x10e93040, 0xe2243860, 0xf607f470, 0x4719b978, 0x3199354c, 0x4dd159ae, 0x89bf0773,
+    0x2ac8e0bc, 0xe82632ba, 0xdf19ba01, 0x6d9936a9, 0x33d158e1, 0x8abf0735, 0x7948e167, 0x78e630e2,
+    0x1639b971, 0x9c093651, 0x9e195a6d, 0xb30b063b, 0x5b9ae344, 0x94cd33b6, 0x0b3e39cf, 0x3c98f4aa,
+    0xf54c3ba5, 0xfee3f483, 0xb923b8a8, 0x19963448,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xd8000000, 0xf4000000, 0x4e000000, 0xe1000000,
+    0x62800000, 0xcd400000, 0x69600000, 0x4d500000, 0xa9780000, 0xad4c0000, 0x79620000, 0x75570000,
+    0x8d7c8000, 0x3b5ec000, 0x6c60a000, 0x59d29000, 0xa1390800, 0x30bfbc00, 0xec60a200, 0x99d29300,
+    0x41390b80, 0xe0bfbf40, 0x3460a160, 0x6dd290d0, 0x0f390ab8, 0x01bfbcc4, 0x56e0a0ea, 0xa09293e5,
+    0x66590b1d, 0x4cefbdf1, 0xff98a24f, 0x0dde9150, 0x1f3b0af8, 0x39b8bc24, 0x72e4207a, 0x368051bd,
+    0x735bab49, 0x606a2d43, 0xd3dd2afe, 0x063fed7f, 0x9f3b0af8, 0xf9b8bc24, 0x92e4207a, 0xe68051bd,
+    0xab5bab49, 0x946a2d43, 0x9ddd2afe, 0xe73fed7f,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x08000000, 0x9c000000, 0x96000000, 0x7b000000,
+    0xde800000, 0xea400000, 0x0fa00000, 0x8a500000, 0xbfa80000, 0x72440000, 0x7ba20000, 0x70450000,
+    0x0ab18000, 0x43d7c000, 0x457ee000, 0x78ac1000, 0x2ad3a800, 0x42f5e400, 0x6d7ee200, 0xf4ac1100,
+    0x14d3aa80, 0xf5f5e540, 0x2dfee220, 0xf9ec1370, 0x53f3a8d8, 0xeee5e4ac, 0x4376e15a, 0xebb810d9,
+    0x9859a8e6, 0x66b4e685, 0x8dcd63a4, 0xaa6ed110, 0xac34c908, 0x2d8a3744, 0xe8d1ab8e, 0xd3e0e41f,
+    0xaee7621d, 0xe37fd3a6, 0xbbaf4ba5, 0x904cf554, 0xfab4c908, 0x1bca3744, 0xd171ab8e, 0x72b0e41f,
+    0xc7cf621d, 0xe77bd3a6, 0x59ad4ba5, 0x1159f554,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x18000000, 0xb4000000, 0x86000000, 0x3d000000,
+    0x71800000, 0xa0c00000, 0x19e00000, 0xf8d00000, 0x4df80000, 0x8ed40000, 0xb8e20000, 0x53570000,
+    0x2a348000, 0xf1a7c000, 0x9e632000, 0x6d11f000, 0xa9833800, 0x34c0cc00, 0x4fe32200, 0xddd1f300,
+    0x88633880, 0xa810cf40, 0x9c1b2260, 0xda05f1d0, 0xc7013a98, 0x6687cfb4, 0xde4fa3a6, 0x73b23353,
+    0x0d7a1aff, 0x7d923c57, 0x82d69a91, 0x9af1fd68, 0xd04fba1c, 0x02b30e1a, 0xbee202b9, 0xae5701ee,
+    0x7bb48205, 0x8167c27e, 0x9f832087, 0x21c1f189, 0x627b3a1c, 0x8714ce1a, 0x868122b9, 0x2e46f1ee,
+    0xbbb7ba05, 0xa1670e7e, 0x4f800287, 0x39c00289,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x78000000, 0x44000000, 0x2e000000, 0x81000000,
+    0xeb800000, 0x50c00000, 0xae200000, 0x88d00000, 0x1a380000, 0xfed40000, 0xef3e0000, 0x435b0000,
+    0x7af68000, 0x28fec000, 0x23f6a000, 0x777bb000, 0x0538b800, 0x2c487400, 0x3076a200, 0xa3bbb300,
+    0xa518b880, 0x159874c0, 0xb9cea3e0, 0x49afb210, 0xca06b838, 0x5f1376c4, 0x3280204e, 0xcf457353,
+    0xa8ee1a80, 0xe3e3c4e7, 0x57761826, 0x3527c4a8, 0x54501b3c, 0x7478c6ea, 0x8da0994d, 0x240904cb,
+    0xfe1eba53, 0xe9177627, 0xe78621e1, 0x42ca727e, 0x45269b3c, 0xcc4606ea, 0x2076394d, 0xeba2b4cb,
+    0x991e0253, 0x7f8b0227, 0x16ce83e1, 0x232ac17e,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x68000000, 0x5c000000, 0xea000000, 0x23000000,
+    0x03800000, 0x74c00000, 0x7ba00000, 0x18d00000, 0xb9a80000, 0x07dc0000, 0xe03e0000, 0x38110000,
+    0xc4158000, 0xbe1c4000, 0x2519e000, 0xa2903000, 0x08417800, 0xe6f24400, 0x34b9e200, 0xd9403100,
+    0xd2697980, 0x25ee46c0, 0xc727e3a0, 0xa5813070, 0x45d4fa28, 0xbf2e064c, 0x018003ae, 0x4bc001a3,
+    0xf22003c6, 0xff10022f, 0xa9880348, 0x37cc00bc, 0xc83601c6, 0x041d000f, 0x9e0381f8, 0xf5114334,
+    0x7a9262fa, 0x3c4d7209, 0x50e519d7, 0xfda2357c, 0xf9df79c6, 0xa533440f, 0x2a8463f8, 0xa4407234,
+    0x04ee9b7a, 0xfbbf74c9, 0x58db1a77, 0xd9b3350c,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x88000000, 0x94000000, 0xee000000, 0x19000000,
+    0x06800000, 0x7f400000, 0xa1600000, 0x01500000, 0x70680000, 0x73cc0000, 0xf12a0000, 0x43bd0000,
+    0x62e88000, 0xa288c000, 0xa947a000, 0x54721000, 0xe5dd4800, 0xe422bc00, 0xb72fa200, 0x7ebe1100,
+    0xf2774b80, 0xc8dfbc40, 0xfca72020, 0x4966d350, 0xc558e838, 0xf661ac24, 0xeed0683a, 0x91b96ead,
+    0xb1ffc8bd, 0x2b077d21, 0xc588827b, 0x7ed8c322, 0x59afa359, 0xc5fe137f, 0xd51749c8, 0x548fbc8c,
+    0xec4f2246, 0xc8ead0c3, 0x7d92eac2, 0xd2ccad6d, 0x5bb0e8d9, 0x3eedaf3f, 0x389a69e8, 0x4e546fdc,
+    0xc3ff4a7e, 0x0803bce7, 0xd40522f8, 0x0e07d3c0,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xc8000000, 0xd4000000, 0x26000000, 0xd5000000,
+    0x4d800000, 0xba400000, 0xe9200000, 0xc8500000, 0xda380000, 0xb0cc0000, 0x9dee0000, 0x2bb50000,
+    0x68848000, 0x1fd9c000, 0xd76a2000, 0x7f727000, 0xdb666800, 0xd57ed400, 0x64722200, 0xc7ee7300,
+    0x7cb06b80, 0xfe07d6c0, 0x5918a120, 0x2782b050, 0xe55ecb18, 0x4bac6594, 0x1894e816, 0x37ce16b9,
+    0xb36a82bc, 0x916cc1b5, 0xda6ea2fe, 0xbeebb1ba, 0x0b2c4a6b, 0x635ca78b, 0x0eac4964, 0xcd1ca401,
+    0x218c4a38, 0x604ca55b, 0x7e3448a3, 0xbec0a458, 0x2cfa49eb, 0x8825a54b, 0xd3c6ca44, 0x9d706751,
+    0x7062eaa0, 0x01e7160f, 0x19b80195, 0x7b8c02b1,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x08000000, 0x1c000000, 0x7a000000, 0xcd000000,
+    0xeb800000, 0x34400000, 0x26e00000, 0xa2500000, 0x09f80000, 0xb0d40000, 0xe0320000, 0x1d750000,
+    0x5e018000, 0x13124000, 0x38942000, 0x2cd93000, 0x5a3da800, 0xf0656c00, 0xe58c2200, 0x2f5d3300,
+    0x0277a880, 0xf4846d40, 0x9edfa220, 0xab2a7370, 0x31fa0968, 0x14cb1e74, 0xfe3c298e, 0xee772fa1,
+    0x169803f3, 0x67c403fd, 0x76aa0329, 0xbab10322, 0x08ab82d3, 0xf9a341b8, 0x383fa319, 0xc97a716a,
+    0x18020857, 0xf41f1e9e, 0x160e2aac, 0xef022cc7, 0x32998253, 0xb9d640f8, 0xa5be2339, 0xa228311a,
+    0x747629bf, 0xab962daa, 0xd44b8102, 0x56f34016,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x18000000, 0x1c000000, 0xde000000, 0x3f000000,
+    0x06800000, 0xbec00000, 0x92e00000, 0xf4d00000, 0x37e80000, 0x2f540000, 0x7eaa0000, 0x22f70000,
+    0xbcd98000, 0x13f4c000, 0xfd526000, 0x87b21000, 0x0764b800, 0xda90cc00, 0x00da6200, 0x9df61100,
+    0xfa46b880, 0x8d23cc40, 0x7fa1e260, 0x6b71d170, 0x2c8f5bf8, 0x2bc61cbc, 0x4164ba7a, 0xb990cf8b,
+    0xf85a63b3, 0x0c36126f, 0x7626b825, 0xdb33ce76, 0x04a9e2a9, 0x8ff5d2d4, 0x634d59ce, 0x98a51eb5,
+    0x11f73a3e, 0x7c430ecd, 0x8e398182, 0xb724c1f7, 0xf2ba6229, 0xa4e61294, 0xbfceb9ae, 0xdb67cec5,
+    0x6483e146, 0x0fc2d231, 0x9374db98, 0x4081de0c,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x58000000, 0xdc000000, 0x0a000000, 0xb5000000,
+    0x26800000, 0x28400000, 0xd3e00000, 0x76500000, 0xb8e80000, 0x7bd40000, 0xbd2e0000, 0x9dff0000,
+    0x75458000, 0x31664000, 0xa81f2000, 0xe4009000, 0xc6072800, 0xd7026c00, 0x17972200, 0xd8c49100,
+    0x44a12880, 0x36396cc0, 0xc074a360, 0x3899d270, 0x635d88a8, 0xee64be14, 0x2b8f2bfa, 0x82c66ed1,
+    0xb9b121e7, 0xa4bf92cd, 0x6622ab99, 0x88742efe, 0x8c8002f3, 0xed40030a, 0x8d600304, 0xb210039f,
+    0x390802e1, 0x648403ef, 0x29460349, 0x7b6b017b, 0x3d0b8273, 0xf28943ca, 0xb652a264, 0xd8e2d0ef,
+    0x6bde0ac9, 0xd529fd3b, 0x19fb89d3, 0xa35fbcda,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x68000000, 0xfc000000, 0xda000000, 0xe5000000,
+    0x2d800000, 0x06c00000, 0xaba00000, 0xc3d00000, 0xd6280000, 0x0d040000, 0x918a0000, 0x7cd90000,
+    0x5ebd8000, 0x86464000, 0x2ceb2000, 0x7cbaf000, 0xb7467800, 0x8761c400, 0x55612200, 0xbc63f100,
+    0x93fbfa80, 0xf4278440, 0x3c0a03a0, 0x3a1902f0, 0x551d81e8, 0x559643d4, 0x92c32116, 0x8dbef3eb,
+    0xfccc79c6, 0x1eb8c49b, 0x265ca1ee, 0x3ce5b12f, 0x14b0da68, 0x4b4d76bc, 0x5d647982, 0xb07cc4dd,
+    0x91f6a11d, 0x952cb2d5, 0x5f855831, 0xffdf37af, 0xec2d5b80, 0x581b3568, 0xc4075894, 0xee123736,
+    0xd312d8db, 0x7a90764e, 0x3253f9df, 0x5ae38680,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0xb8000000, 0x54000000, 0x92000000, 0x17000000,
+    0xa3800000, 0x8bc00000, 0xab200000, 0xa4d00000, 0x1ca80000, 0x9d140000, 0x309a0000, 0x22530000,
+    0xf3f88000, 0x86754000, 0x70b06000, 0x1b1a3000, 0x958d9800, 0x06c01400, 0x63aa6200, 0xf2893100,
+    0xed551880, 0xb4655640, 0xb7b200e0, 0x20870050, 0xda4282c8, 0x87f6421c, 0x8460e06e, 0xdfbb737f,
+    0xec87f8e4, 0x8c5926cf, 0xbaf778fc, 0x64e8655b, 0x65f518ee, 0xdb75555a, 0x203a03dd, 0x8943027d,
+    0x4e708128, 0x6cb143a9, 0xd50263bf, 0x6c9d3121, 0xcc4f1a26, 0x9af65746, 0xf4ea83b3, 0xdde24102,
+    0x8f7ae1cc, 0xb2287166, 0x9e5f7943, 0xedfc657a,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x78000000, 0xdc000000, 0x6e000000, 0xdf000000,
+    0x1a800000, 0x1f400000, 0x84200000, 0xa8500000, 0x0ab80000, 0x1d1c0000, 0x13860000, 0x66c30000,
+    0x1fe58000, 0x9432c000, 0xe0486000, 0xceba9000, 0x5f1a0800, 0xda9ad400, 0x7f4e6200, 0x74399300,
+    0xd05f8980, 0xd6b817c0, 0x731e03e0, 0xcc8f0070, 0x7c5b8038, 0x00adc0bc, 0x100be18a, 0x481b530d,
+    0xc40feba8, 0x420e85dd, 0xc91a0a40, 0x199ad709, 0x6bce61de, 0x447993fa, 0x36ff8835, 0xbda81694,
+    0x93860197, 0xa6c301ad, 0x7fe58151, 0x6432c1fb, 0x984861e6, 0x12ba9346, 0x311a09bf, 0x059ad599,
+    0x65ce623f, 0x6b799070, 0x547f8911, 0x7ee815f2,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xb8000000, 0xb4000000, 0x8a000000, 0x89000000,
+    0x60800000, 0x90c00000, 0x7e600000, 0x0dd00000, 0x44f80000, 0xbc0c0000, 0xa6060000, 0xa70b0000,
+    0x2b888000, 0x75464000, 0xb027a000, 0xe06d5000, 0xdecb3800, 0x0567dc00, 0xb041a200, 0xceb65300,
+    0x63bbbb80, 0x012d9fc0, 0xf4e000e0, 0x241001d0, 0x021801a8, 0x651c01e4, 0xae9e0362, 0x2bd70093,
+    0x23f683d1, 0x77814213, 0x23492091, 0xaf3010b3, 0x7ffc1b61, 0x2190ceab, 0x3c533bbd, 0x30bbdc25,
+    0x80bfa1d8, 0x18b151dd, 0xbcb53ab5, 0x7ea0dcd0, 0xfbaf22c9, 0xa52b134f, 0x36ec9adf, 0xa10a8fb6,
+    0x5c8a9989, 0xf6d18c0e, 0x397a1ac4, 0xd65bcdb3,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xc8000000, 0x24000000, 0xea000000, 0x5d000000,
+    0x4e800000, 0x1a400000, 0x3d200000, 0xd3500000, 0xb1b80000, 0x60140000, 0x10060000, 0x181d0000,
+    0x1c1a8000, 0x06044000, 0x930f2000, 0xf9949000, 0x09c8e800, 0x69ee7400, 0xf4292200, 0x5fd99300,
+    0x02ea6880, 0xc1be37c0, 0x68000120, 0x14000390, 0x02000328, 0x890002b4, 0x6c80021a, 0x634000f9,
+    0x99a0035c, 0x94100239, 0xc21803fc, 0xa90401e9, 0x9c9e00b4, 0xab59032d, 0xbda482b6, 0x7e0d4198,
+    0x9f13a20c, 0xe78dd386, 0x86dd4857, 0x967ea5bf, 0x6eeeeb9c, 0xcfa37599, 0xff0ba2ac, 0xf789d261,
+    0x9ec349d0, 0x8a67a67f, 0x68ea6a8b, 0x5cbe37c6,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x38000000, 0xb4000000, 0x66000000, 0xff000000,
+    0x73800000, 0x1dc00000, 0xf9e00000, 0xaed00000, 0x78680000, 0x7a1c0000, 0x050e0000, 0x368b0000,
+    0x4b508000, 0x82abc000, 0x14796000, 0xd81ab000, 0xc4185800, 0x3e1b4c00, 0x7b176200, 0x2d81b100,
+    0x56c0d980, 0xec7c8cc0, 0x4c0802e0, 0xf20c03d0, 0xc9060018, 0x8487033c, 0xe256812e, 0x362cc2a7,
+    0xce2fe27f, 0x5a367147, 0x6c37b82f, 0x9b2d3d9f, 0x64a0d9f3, 0xab6c8f61, 0xcb80034c, 0xe9c00222,
+    0xffe00125, 0x61d001aa, 0x33e80345, 0xd3dc03de, 0x9aee01eb, 0x675b005d, 0x40b88062, 0xe577c185,
+    0xe89762da, 0x4041b02d, 0xf720db8a, 0xc6ac8c91,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x48000000, 0xac000000, 0x4a000000, 0x2f000000,
+    0x0d800000, 0xc6c00000, 0x86200000, 0xedd00000, 0xbda80000, 0x661c0000, 0x251a0000, 0x42850000,
+    0x5b478000, 0x08e04000, 0xc7e06000, 0x1a70f000, 0xf4a93800, 0x4e977400, 0xa15a6200, 0x9fe5f100,
+    0xbe66b980, 0x72bb3640, 0xbb880320, 0xcbcc03b0, 0xf8b200a8, 0xb49902fc, 0x365d8116, 0xe66541ab,
+    0xd6a7e330, 0x3d90b30b, 0x3ec95ac0, 0x92278683, 0xd3d3588c, 0x3ca287bd, 0xa294d81b, 0x8b42c6fb,
+    0x20f4b92b, 0xfbf23493, 0x187d8137, 0x77b54055, 0x090fe024, 0x488cb141, 0x14535b0d, 0x95628650,
+    0x4934db9b, 0x3052c5d8, 0x537cbad7, 0xdc3e3466,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x48000000, 0x5c000000, 0x32000000, 0x5d000000,
+    0x5d800000, 0x66c00000, 0x66a00000, 0xe1d00000, 0xaa280000, 0x80940000, 0x7b520000, 0x206d0000,
+    0x57618000, 0x03f74000, 0x76bc2000, 0xc9cbf000, 0x06387800, 0x2a8be400, 0x32462200, 0x13f2f100,
+    0x5eabf880, 0x65c1a740, 0xac338320, 0x639a4070, 0x01dda048, 0x1a3cb234, 0x38845856, 0xbf4014eb,
+    0x067e5b52, 0x647917f3, 0x316dd95e, 0x10f355d9, 0x94387bd7, 0xe78be436, 0x07c6209d, 0xf932f049,
+    0x420bf829, 0x8511a579, 0x699b8241, 0xd8ce43bd, 0x41afa31f, 0xbd41b142, 0xa36ddbeb, 0xddf354d2,
+    0xa1b87b33, 0x0d4be4be, 0x1b662149, 0x19e2f38f,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xc8000000, 0x3c000000, 0xca000000, 0xcb000000,
+    0xf4800000, 0x81c00000, 0xc4a00000, 0x78d00000, 0xbf380000, 0x438c0000, 0x9f4a0000, 0xfe650000,
+    0x10e58000, 0xb2284000, 0xce0ae000, 0xa511d000, 0xe19e5800, 0xd84a8400, 0x78f8e200, 0x3e38d300,
+    0xbc11db80, 0x0a17c640, 0x2b0f8120, 0x649d43f0, 0x49d760a8, 0xf8b5916c, 0xb2deb8f2, 0x743e56f7,
+    0xb7033a3a, 0x1e9a15cf, 0x3ac3d8ae, 0x682ec649, 0x0d1803cf, 0x8d9c03aa, 0x3a520137, 0x1ff9025a,
+    0xc8b7829f, 0xcad140b6, 0xf03d602d, 0x19009161, 0xeb8338e7, 0xf35a1486, 0x1c63d8e5, 0x77fec75d,
+    0x44a0000d, 0xb8d00015, 0x5f380271, 0xd38c02df,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x58000000, 0x4c000000, 0xc2000000, 0x0f000000,
+    0x55800000, 0x6ac00000, 0xf3200000, 0xbbd00000, 0xf3b80000, 0xa79c0000, 0x2dc20000, 0xd2a70000,
+    0x8f038000, 0x958b4000, 0x0ac46000, 0x2328f000, 0xe3d7b800, 0xbfa55400, 0x659e6200, 0x22c3f300,
+    0x872e3980, 0xe5d51740, 0x66bb8360, 0xb1174230, 0xd0866288, 0x444ff37c, 0x92743836, 0xb73e149b,
+    0xadc202c4, 0x12a701d3, 0xef038318, 0x458b4075, 0x52c4621b, 0x6f28f095, 0x21d7bafa, 0xb0a554f4,
+    0x301e63ca, 0x4803f16d, 0x740e3bff, 0x5e051507, 0x95038113, 0x168b40a9, 0xfd4463ac, 0x96e8f15f,
+    0x1d77ba06, 0x22b55482, 0xa70663b1, 0x318ff3d9,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x28000000, 0xac000000, 0x3a000000, 0xd5000000,
+    0x1f800000, 0xe7c00000, 0xf3200000, 0x14d00000, 0x1ba80000, 0xcd8c0000, 0x3eda0000, 0xa6b90000,
+    0x9e178000, 0x730a4000, 0xa8932000, 0x3956b000, 0xb5635800, 0x43381400, 0x0cc12200, 0xefb3b100,
+    0xe386db80, 0x55c75640, 0xfa3f82a0, 0x894643b0, 0xad692368, 0xb73fb114, 0x22dcd8de, 0x84be552f,
+    0xbf080124, 0x429c0207, 0x24520390, 0x96e502e9, 0xb6e58397, 0x86ff4079, 0xdefea2fe, 0xcaf5f1ae,
+    0x74effa27, 0x37f8e5e0, 0xc7635961, 0xea381593, 0xe141237f, 0xe173b32d, 0x1d26da80, 0xdfd75631,
+    0x373783eb, 0x62da40b3, 0x64bb208f, 0x2f1ab1e5,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xc8000000, 0x7c000000, 0x42000000, 0x95000000,
+    0x23800000, 0xdf400000, 0x27600000, 0x3c500000, 0x6ff80000, 0xb7840000, 0x914e0000, 0x986d0000,
+    0x46c98000, 0x19294000, 0xa6b92000, 0xebf23000, 0x818c4800, 0x9a551c00, 0xdcef2200, 0xe70b3300,
+    0xbe93ca80, 0x20c55ec0, 0x0a298120, 0x463942f0, 0x0c212388, 0x45263094, 0x94ba4bae, 0x16fc1e8d,
+    0x6e08a395, 0xcf1f71a5, 0x129b6bb1, 0xbada2f63, 0x9b32ebf8, 0x93a36c60, 0x7873c92c, 0x96d55d2e,
+    0xc13183f1, 0xa2ad4283, 0x1df72328, 0x2a9f3398, 0x6ec5caf0, 0xb53c5e34, 0x3cb601a2, 0x7ae90253,
+    0x5407816c, 0xee044172, 0x0f10a017, 0xb28b7386,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xd8000000, 0x44000000, 0x42000000, 0xbf000000,
+    0xea800000, 0x12c00000, 0xdd600000, 0x23d00000, 0xb6f80000, 0x52940000, 0xa6da0000, 0x777f0000,
+    0x10da8000, 0x826b4000, 0xf94b6000, 0x6c2f1000, 0x13fab800, 0x83047c00, 0x3c896200, 0x17d41300,
+    0xdce23880, 0x41943c40, 0x825a8160, 0x04ab4210, 0x9e2b6188, 0xa4ff12bc, 0xd582b8ca, 0x38507d5b,
+    0xefb3607d, 0xeebb1173, 0x4d20b9f1, 0xa07b7d41, 0xb653e08e, 0x6ebf5342, 0x8d295b10, 0x807b2dff,
+    0xa6403bb4, 0xb6bf3e36, 0xc93a00f1, 0xc26f0136, 0x19428186, 0x5c2f41be, 0xdbe960ba, 0x1f0411b4,
+    0x3a9a3ac1, 0xeac03db9, 0x896082aa, 0xb9c4403c,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x68000000, 0x94000000, 0x3e000000, 0x93000000,
+    0xe3800000, 0x3e400000, 0xdda00000, 0x1dd00000, 0x83e80000, 0x50740000, 0x462e0000, 0x2f910000,
+    0xbc488000, 0xc4b8c000, 0xe34be000, 0xa52c3000, 0xc414e800, 0x860c6400, 0xff036200, 0x9194f100,
+    0x6f5f0880, 0x072056c0, 0x6d178ba0, 0x10989750, 0x4ddc6a78, 0x3bf4a58c, 0x3c6880ae, 0x3428c169,
+    0x7e83e2ae, 0x66c832ab, 0xb472e8d9, 0x703965a4, 0xb88de318, 0xb9c9337c, 0x95f26886, 0x7765a41d,
+    0x2ba0004c, 0xead0004c, 0x1668028e, 0xd9340079, 0x2e0e01b6, 0x0b0101d7, 0x3f80805f, 0x245cc1b9,
+    0x18ade354, 0xf9593330, 0x603a6a08, 0x2081a464,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x28000000, 0x4c000000, 0x8e000000, 0xc1000000,
+    0xfd800000, 0x62c00000, 0x10e00000, 0x0f500000, 0x1a280000, 0xf3a40000, 0x8b7a0000, 0x00890000,
+    0xc9578000, 0x6722c000, 0x1826a000, 0x5cbaf000, 0x07e03800, 0xa7da4c00, 0xdf712200, 0xba983100,
+    0x42469a80, 0x89a0bc40, 0xe0711aa0, 0x5e127c30, 0x091fbab8, 0x019c8e44, 0xd4cd83d6, 0xb5fbc1fb,
+    0x30d9205b, 0x37fc3249, 0xdfdc9906, 0x1b79bc71, 0x688e99a0, 0x2554bfb0, 0xf9231878, 0xf13f7ea4,
+    0xed323a46, 0xeb374ef3, 0x763ca367, 0x2da3f3bb, 0xc27fb9fb, 0xa10c8df9, 0x0d85817e, 0xfacfc2d5,
+    0x64eb23e6, 0xe5413143, 0x19391b1f, 0x41267d1f,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xb8000000, 0x44000000, 0xce000000, 0x6d000000,
+    0xb2800000, 0x59400000, 0xc3a00000, 0xd3d00000, 0x9ee80000, 0x3e6c0000, 0x902e0000, 0xaa130000,
+    0xb30a8000, 0x3784c000, 0xa7c42000, 0xa8f01000, 0xf767c800, 0x5ca24400, 0x264ea200, 0x8634d100,
+    0x7303eb80, 0x978256c0, 0xf7c168e0, 0xa0fa9410, 0x0b634ab8, 0xd6a58674, 0x854801aa, 0x59bc03b5,
+    0x98c60156, 0x0d7f012b, 0xe7a48169, 0xedd7c138, 0xabeea0bc, 0x78e4d136, 0xbf6be973, 0x40ae560d,
+    0x1c4f6baa, 0x9d3996bd, 0xe881c8aa, 0xb24d443d, 0x002221ea, 0x021f101d, 0x1f0b481a, 0xb5898735,
+    0xf8c60316, 0xfd7f038b, 0xbfa483d9, 0x19d7c330,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x08000000, 0xdc000000, 0xb6000000, 0xdb000000,
+    0x74800000, 0x04400000, 0x43600000, 0x64d00000, 0x0d280000, 0x5abc0000, 0xbe760000, 0xf3550000,
+    0xc9f18000, 0x0785c000, 0xfcc7e000, 0xe92ed000, 0x58a8a800, 0x7f725c00, 0xe2d66200, 0xbe3b1100,
+    0x82274980, 0x04308dc0, 0xb720ca20, 0x6fa04e70, 0xd5f6a958, 0x919b5fac, 0x37d1e272, 0xe5abd3a1,
+    0x88f12a75, 0x560b9c4f, 0xeb07811e, 0x1c90c067, 0xa856617e, 0xfd7b10e3, 0x63c74bec, 0xcfa08f86,
+    0x85e8c8eb, 0x898c4efc, 0x93c8aada, 0x87a25f95, 0x39fe630b, 0x4f8710ac, 0x40d148f2, 0x2f258ee1,
+    0x8bb14815, 0xd7f58fdf, 0x50994916, 0x26498f63,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x38000000, 0x3c000000, 0x1e000000, 0xe9000000,
+    0xa0800000, 0x37c00000, 0xa8a00000, 0xa3500000, 0x35780000, 0x88e40000, 0x15b20000, 0xd9db0000,
+    0x69b18000, 0xa7dac000, 0xf0bfa000, 0xef535000, 0xc37d9800, 0x65fce400, 0x972e2200, 0x19199300,
+    0x889a3a80, 0x93dbb740, 0x5ab9bae0, 0x6c4a77f0, 0x40ef98f8, 0x01b7e7e4, 0x63c7a2e2, 0x72b7506f,
+    0xc84f98ba, 0xb2e7e699, 0xcebfa0cf, 0x165352a8, 0xfbfd9a8e, 0xbe3ce5d1, 0x198e23b3, 0x6f49911e,
+    0x03623b1b, 0xc5ffb490, 0x472bba4a, 0x21017563, 0xb4861a34, 0x8dd92748, 0xb3b2017c, 0xccdb00b6,
+    0x77318315, 0xa91ac201, 0xc09fa119, 0x47c3508d,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x98000000, 0x1c000000, 0xce000000, 0xb1000000,
+    0x85800000, 0xcb400000, 0x01e00000, 0x00d00000, 0xbbb80000, 0x24a40000, 0xa0320000, 0x06ef0000,
+    0x98458000, 0xd37cc000, 0x0b18a000, 0x6e9bf000, 0x15d20800, 0xfc26ec00, 0xa8e52200, 0x59433300,
+    0x1ef8aa80, 0x44521f40, 0xbd72a860, 0x6a191f70, 0x73052bb8, 0xc28ade84, 0x33d808f6, 0x192def1d,
+    0x6772a1df, 0xf100f2f7, 0xe59d8b20, 0xbb512e4b, 0x49ea03c9, 0x84db0281, 0x69af8326, 0x5ba7c175,
+    0x94b72033, 0x483c326d, 0x52e52967, 0xd25adef3, 0xb0600896, 0xf189ef36, 0x9140a066, 0x5aeff04e,
+    0x36580842, 0x126dee68, 0xc692a2b7, 0x21d0f09b,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xf8000000, 0xd4000000, 0x32000000, 0xab000000,
+    0x02800000, 0x0ec00000, 0xfaa00000, 0xee500000, 0x87680000, 0xb27c0000, 0xbdfa0000, 0x48ab0000,
+    0x054c8000, 0xe5ff4000, 0xacaee000, 0xbf599000, 0x72f37800, 0xb028a400, 0xfc8a6200, 0x13dad100,
+    0xf9279980, 0x8d1a3440, 0xbb9599e0, 0xd75d3650, 0x1eeb1b48, 0x362577ec, 0x759b786a, 0x4254a62b,
+    0x21706342, 0x4b71d305, 0x046b18bf, 0xbce5774e, 0x253b783f, 0xc304a65c, 0x6e9862a0, 0x88cdd130,
+    0x73b11bd8, 0xbfde7584, 0x5f3ffbf6, 0x7417e5f9, 0x020482fd, 0x23134119, 0x3e9ce3ff, 0x10de902e,
+    0xb7adf8af, 0x75c0e434, 0x2032013c, 0x448702e2,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xa8000000, 0x64000000, 0x8a000000, 0xa7000000,
+    0x3f800000, 0x5ec00000, 0x95e00000, 0x33500000, 0xe0280000, 0x173c0000, 0x50a60000, 0x52610000,
+    0x71868000, 0xa3de4000, 0x357f6000, 0x6e013000, 0x6d1ff800, 0xf8906c00, 0x9151e200, 0x63237100,
+    0xc2a69980, 0x59605fc0, 0x500098a0, 0x78015c90, 0x5c0619a8, 0xb61f1c5c, 0xe11979de, 0x768e2e2b,
+    0x4c4e815f, 0x53b24101, 0xc7f16276, 0x185c31eb, 0x51bf7b63, 0x94ef2ff3, 0xf5c800c7, 0x646c00f9,
+    0xd08e013e, 0xb55d02db, 0x89208247, 0x95bf4125, 0xcef9e3bc, 0x6adf7132, 0x67e09a11, 0xc8515d82,
+    0x69ae1bf5, 0xa8e31d74, 0xb3df795e, 0x2d7f2ef7,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xb8000000, 0x5c000000, 0xea000000, 0xcb000000,
+    0xe8800000, 0xff400000, 0x4aa00000, 0xb5d00000, 0xeaf80000, 0xa9fc0000, 0xb5720000, 0x24330000,
+    0xc3958000, 0x07c44000, 0x6df3e000, 0x7376d000, 0x0d2f4800, 0xd41fe400, 0x1e1e6200, 0xa50e9300,
+    0x458ea980, 0x8eca3740, 0xca7ca8e0, 0x02b93670, 0x21c92828, 0x54ed776c, 0xbce2cac2, 0x98f7a6cd,
+    0x4ee783e2, 0xbff740cb, 0x246660c1, 0x6fb29046, 0x305ca915, 0x042937e6, 0xb3912b85, 0x0fc177be,
+    0x59e8c9c1, 0x7d78a690, 0x7020020e, 0x1d90029f, 0xc2d80377, 0xf86c006d, 0x45aa03a4, 0x9b5f0188,
+    0x3cbf80fc, 0xf4db421a, 0x196c6149, 0x063d90ec,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x98000000, 0x7c000000, 0xe6000000, 0x91000000,
+    0x04800000, 0x59c00000, 0x23200000, 0x27500000, 0x1df80000, 0x69fc0000, 0xcbf20000, 0x70f70000,
+    0x4b718000, 0x6331c000, 0x8750a000, 0xadeed000, 0x21ff5800, 0x2fe49400, 0xeaf92200, 0x3c731300,
+    0xf6a5f980, 0xda014740, 0xd705f860, 0x259146f0, 0x155df818, 0x9efd4604, 0x9e77f9f2, 0x4da644d7,
+    0xe18c7af4, 0xff5c8769, 0xc1ff5a1d, 0x3fe49734, 0x12f921c9, 0x907313cd, 0x88a5f9bc, 0x37014525,
+    0x3585fa23, 0xed51479d, 0x32fdfa74, 0xe06d46a9, 0xa0affabd, 0x030a47e4, 0x378679c1, 0xe65787e1,
+    0x417cda72, 0x2c225514, 0xded8025a, 0x5eac01ab,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x08000000, 0x14000000, 0xca000000, 0x25000000,
+    0x99800000, 0xdd400000, 0xd1a00000, 0xcad00000, 0x3be80000, 0x2bfc0000, 0x93e60000, 0xeff50000,
+    0xc1e88000, 0x26f24000, 0x8e6d2000, 0x00a3f000, 0x294fc800, 0xabad6400, 0x87cda200, 0x467db100,
+    0x34ace980, 0x734797c0, 0x76acea20, 0x02479550, 0x452ce8a8, 0x0a079654, 0x050ce9c6, 0x099795e5,
+    0x2544ebce, 0xcdbb952f, 0x14caea01, 0xd4f295de, 0x97646be7, 0xd725d5e5, 0xe309ca40, 0xda88666e,
+    0xd7cd2131, 0x9e73f148, 0xb8a7caaa, 0x5551667f, 0x85aba1a9, 0x60c8b30a, 0xeee46861, 0xba65d7e0,
+    0x5aa9cbfe, 0xf4586539, 0x2e25230c, 0x848ff0a4,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x48000000, 0x4c000000, 0x1e000000, 0x87000000,
+    0x2b800000, 0xd2400000, 0x68600000, 0xf3d00000, 0x57380000, 0x0d340000, 0x383e0000, 0x1aa50000,
+    0x9c738000, 0x09d0c000, 0x123a6000, 0x47a43000, 0x42f7e800, 0xd917dc00, 0x4c91e200, 0x69d0f300,
+    0x422b8880, 0x9fb2eec0, 0xf6f38b20, 0xc316ee30, 0x99958af8, 0xdb57efdc, 0x3ce00b0e, 0x0e162fb9,
+    0xbf17ea79, 0x0f87dd23, 0x3049e22a, 0xb974f191, 0x134d8837, 0xb0f3ed00, 0x3006097a, 0x88172cb9,
+    0x6c026a03, 0xae161e1a, 0xcf060069, 0x6791036b, 0xcc4d82ce, 0xef75c2e3, 0xd849e270, 0x8574f018,
+    0x654d884c, 0xcbf3ee06, 0x4d860b3d, 0x91572d5b,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x78000000, 0x8c000000, 0x6a000000, 0xf3000000,
+    0x95800000, 0xf7c00000, 0x4ae00000, 0xf1500000, 0xd8a80000, 0x84640000, 0x871a0000, 0x33930000,
+    0xcec78000, 0x3c604000, 0xeb10e000, 0xe9867000, 0x55d9d800, 0x5de78c00, 0xfacd6200, 0x3a753100,
+    0xc20eba80, 0xe701bc40, 0xc3845be0, 0x06d4cf30, 0xd87a0128, 0x7503028c, 0xfc8f8136, 0x595441af,
+    0xcca2e163, 0xd2717239, 0x7604589c, 0xa114ce02, 0x4a9a0361, 0x185301d4, 0x362783f2, 0xa2304165,
+    0xb438e332, 0xe5227349, 0x67a3d9d8, 0x9be48e84, 0x33c2e32a, 0x84e17161, 0x3c4c5a58, 0x4820cec8,
+    0x07280130, 0xd0a400a4, 0x807a02b6, 0xa90301e3,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x58000000, 0x44000000, 0x86000000, 0x27000000,
+    0x2c800000, 0xedc00000, 0x45600000, 0x76500000, 0xbc280000, 0xeaf40000, 0x01120000, 0x1b870000,
+    0xb9468000, 0xbcb1c000, 0xed376000, 0xa965d000, 0xfc559800, 0x212fdc00, 0x7363e200, 0x39531100,
+    0xfca47880, 0xcd3bcd40, 0xf9611b60, 0xa4491c10, 0x653a0098, 0xf57300dc, 0x1e548152, 0xd036c3e7,
+    0x20f1e26d, 0xbc141055, 0xd202f85a, 0xd91a0c80, 0x1f9e7ba3, 0x1f48cf00, 0xcbb59b80, 0x99bfdcc0,
+    0x00abe2a0, 0x3f371030, 0x707e7ae8, 0xe3d8ced4, 0x3e7d9956, 0xb8dbdf89, 0xa0f1e288, 0xfc1413a7,
+    0xf202fb6e, 0x891a0f65, 0x479e7932, 0x5b48ce94,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x68000000, 0xa4000000, 0xb6000000, 0xfb000000,
+    0x96800000, 0x2c400000, 0x34e00000, 0xe9d00000, 0x0a280000, 0x177c0000, 0x741e0000, 0xae090000,
+    0x571e8000, 0xb49dc000, 0x0946e000, 0xfd709000, 0xe51c2800, 0xe9832c00, 0x5cc66200, 0xbba45100,
+    0x87a44980, 0xddbe7cc0, 0x64b4aba0, 0xe72bef90, 0x6ae00158, 0xf6d0022c, 0x4aa8027a, 0xf03c02e1,
+    0xbe7e03ab, 0xcf9902db, 0xdfd680aa, 0xb131c300, 0xe1f0e359, 0x684591ae, 0xf2fca9a7, 0x1ac7ed28,
+    0x08b603dd, 0xe5350010, 0x1fe08298, 0x6744c30c, 0x8a7061aa, 0x01915159, 0xb8c4cb77, 0x6dbabcc9,
+    0x4ca4cb2f, 0x232abf65, 0xececc995, 0x65c6bc24,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x68000000, 0x0c000000, 0xf6000000, 0x29000000,
+    0xa3800000, 0xa2c00000, 0x84600000, 0xa0500000, 0x59380000, 0x78fc0000, 0x480a0000, 0xbc190000,
+    0xee0f8000, 0x5d11c000, 0x3189a000, 0x71cb3000, 0xf8efb800, 0x8811dc00, 0xdc0c2200, 0xfe03f300,
+    0x35099980, 0x3d9b2c40, 0x87d23ba0, 0xd1e51f30, 0x2b800058, 0x7ec003e4, 0x7a6002ae, 0x955002fb,
+    0x64b80069, 0xff3c0215, 0x99ea0032, 0x978903cc, 0x90d781bf, 0x277dc2fa, 0xa4dba0cd, 0x157e3216,
+    0x07d23882, 0x11e51ed4, 0x4b80037b, 0x6ec002a4, 0x1260018e, 0x9950030b, 0x92b80251, 0xd63c0321,
+    0x3a6a0154, 0x3549023b, 0x14b78184, 0x872dc07e,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x68000000, 0x9c000000, 0x06000000, 0x2b000000,
+    0xff800000, 0x45c00000, 0x68600000, 0xdd500000, 0xec380000, 0xbff40000, 0xe49e0000, 0x12510000,
+    0x91a18000, 0x032bc000, 0x7273e000, 0x3040b000, 0x18a71800, 0xb5b89c00, 0xa12c6200, 0x3b6a7300,
+    0xa6cd7880, 0x7be7eec0, 0xd6869ba0, 0x83535d70, 0xf33f8298, 0xfa7ac16c, 0x3c5262de, 0xc6ab71a7,
+    0x6ab4f819, 0xc4a82da9, 0x53b37a56, 0x1a26ec44, 0x1cff1b4d, 0x171c9feb, 0x898a6377, 0x26cf7236,
+    0xbbf2f8d3, 0xf69d2e6b, 0x3354f9f0, 0x9b382e58, 0x666b79cc, 0x3a42ef2e, 0xedb919ff, 0x95299cd5,
+    0x816de307, 0x3bd1b2e9, 0xc7669b71, 0xf0c35d5a,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0x28000000, 0x24000000, 0xaa000000, 0xbb000000,
+    0xcf800000, 0xc3c00000, 0x57200000, 0x1b500000, 0xa5780000, 0x06ac0000, 0xc1820000, 0x12c10000,
+    0xc3b28000, 0x470a4000, 0x198ee000, 0x6ed43000, 0xd5b05800, 0x0a117400, 0xcb1e6200, 0x578f7300,
+    0x9fd43b80, 0xf1330540, 0x2e5adaa0, 0x7be73790, 0xb1ea8328, 0x9af640ac, 0x9d74e01e, 0x2ab932df,
+    0xff80db54, 0x7bda3611, 0xbb220103, 0xc55102f9, 0x9c6a82e4, 0x56364269, 0x67d4e067, 0x3d2931fb,
+    0x4058db5d, 0x3ae63706, 0xed780260, 0xb2ac0170, 0xa3820298, 0xddc100d4, 0x8e32837a, 0x1bca42dd,
+    0x2b2ee16d, 0x0d44323e, 0xe8685ba4, 0xd42d76e2,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x68000000, 0xb4000000, 0xee000000, 0x53000000,
+    0xa7800000, 0x25400000, 0xf8200000, 0x3dd00000, 0x58e80000, 0x61ac0000, 0x63920000, 0x53590000,
+    0xc7208000, 0xf84ac000, 0x1cb9e000, 0x3713b000, 0x61910800, 0x22509400, 0xe1ab6200, 0x23907100,
+    0xb3406b80, 0x9735e540, 0x90598ba0, 0xa8b657d0, 0xd9008238, 0x329ac10c, 0x85d1e0be, 0xc4ffb305,
+    0xdba309f8, 0x8e99976b, 0xcfc3e245, 0xf1e6b15f, 0xcb238b70, 0x8a43546f, 0xf5b2023f, 0x7d890140,
+    0xd84880a7, 0xeca6c01b, 0xef0be02a, 0xed9ab060, 0x105988b0, 0xe8b65608, 0x390082c4, 0x629ac21a,
+    0xedd1e1af, 0x70ffb25f, 0x35a309f0, 0xdd9996af,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x18000000, 0xa4000000, 0xda000000, 0x6b000000,
+    0xc6800000, 0xd3c00000, 0xe9a00000, 0xf4500000, 0xcfe80000, 0x8cb40000, 0x0bc20000, 0x2daf0000,
+    0xde5a8000, 0x6cea4000, 0xf62ee000, 0xa6151000, 0x75035800, 0x87820400, 0xb65e6200, 0x40e45100,
+    0xb0353880, 0x63125740, 0x1a895a60, 0x5dc90790, 0x90aee1e8, 0xe5d513ec, 0xa4a35bfa, 0x07d2069f,
+    0xbbb662ae, 0x035051ed, 0xa7773bcd, 0xf67d5791, 0xebf3daf3, 0x16b347b8, 0x40c80208, 0x3b2403bc,
+    0x158a0332, 0x214b0283, 0xd870822c, 0x02f143f6, 0x3f366135, 0x5f9052f9, 0x7257385f, 0x6aed5522,
+    0x133bda87, 0x1997473a, 0xb7420013, 0x056f0144,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xa8000000, 0xdc000000, 0x02000000, 0xc7000000,
+    0x9d800000, 0x11400000, 0x3ca00000, 0x31d00000, 0x63680000, 0x6fb40000, 0x025a0000, 0x2f390000,
+    0x6d8d8000, 0xc95f4000, 0x78b26000, 0x57da7000, 0xd2678800, 0xeb234400, 0x4b8de200, 0x58483100,
+    0xec226880, 0xb60f7440, 0x791d88a0, 0x688a4670, 0xf3c86288, 0xc473725c, 0xc2220856, 0xab180775,
+    0x678d805a, 0x825f41ab, 0x6f326373, 0x4d9a72a7, 0xd947882d, 0xd0b3474e, 0x8bc5e2c1, 0xd06c3040,
+    0x2c306a90, 0xd6127458, 0x49020aa4, 0xd0880682, 0x87c5827f, 0x1a7b40f9, 0x07206144, 0xf18773e2,
+    0xeb58086f, 0xafb104e1, 0x62480140, 0x1f240210,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x68000000, 0x14000000, 0x16000000, 0x89000000,
+    0xd8800000, 0x27400000, 0x40600000, 0xd8d00000, 0x3aa80000, 0x6d7c0000, 0x3e5e0000, 0xe0e90000,
+    0xd3988000, 0x20c1c000, 0x56b2a000, 0x576d5000, 0xed420800, 0xdb64bc00, 0xa75c2200, 0x00799100,
+    0xb8d62980, 0x0ab12cc0, 0x057c0ba0, 0x2a5dbd50, 0xf6eca3d8, 0x5a8453e4, 0xf85a8b42, 0x71e57c0d,
+    0x170e82f9, 0x3584c017, 0xe1dc2250, 0xca3993cc, 0x3e362b3a, 0x58212c2d, 0xd93408fd, 0x25b1bd71,
+    0x7cfaa1ab, 0xa1815236, 0xb7d40923, 0x2321bf9e, 0xd6b2a39b, 0x176d531e, 0x8d420a4f, 0xeb64bfe0,
+    0xcf5c23b0, 0x147991e8, 0xaed62acc, 0x83b12c2e,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x98000000, 0x7c000000, 0x5e000000, 0xff000000,
+    0x06800000, 0x5dc00000, 0xe8200000, 0xe6500000, 0x62780000, 0x82340000, 0x435a0000, 0x09ef0000,
+    0x70698000, 0xeb22c000, 0x7ecf6000, 0xe0aa9000, 0xfc8a9800, 0x60d4b400, 0xffa4e200, 0x4a035300,
+    0x350e7880, 0xf387e640, 0x8e529860, 0xf670b6f0, 0x8826e3f8, 0x964852bc, 0x4a65fafa, 0xf62e26c7,
+    0xf9567ab8, 0xd4e3e695, 0xd7f09a6b, 0x4f6bb5c3, 0xcdb56257, 0xb3159279, 0x909b1870, 0x66c27671,
+    0x5cb1807d, 0x8286c1d6, 0x0fcd617c, 0x6121915a, 0x6bc11b97, 0x832d7410, 0xead80281, 0xeaa40085,
+    0x298203ea, 0x234b01c6, 0x79eb81fd, 0x5869c2df,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x48000000, 0xf4000000, 0x72000000, 0xad000000,
+    0x35800000, 0x6a400000, 0xb8e00000, 0xa4d00000, 0x80280000, 0xb2ec0000, 0xf5ce0000, 0xd3bd0000,
+    0xb7a48000, 0x9da94000, 0xdca62000, 0x97247000, 0x9e6da800, 0x38018c00, 0x1c04a200, 0xd60c3100,
+    0x27090b80, 0x249dbec0, 0xd9c5ab20, 0x0dad8ed0, 0x84aaa048, 0x7b213174, 0xe0658a76, 0x5b08fcb9,
+    0x0285880b, 0x56d8fef7, 0x6d2d8a9e, 0x6774fe16, 0x2f838942, 0x2359fc64, 0xe76f0b55, 0x6f8cbc8a,
+    0xc34f2b1b, 0x5769ccd4, 0x2780002d, 0x37400046, 0x25600281, 0x8a900127, 0x02c8029d, 0x4f3c01f5,
+    0x3266019a, 0xa61101f3, 0xcf0a80b0, 0x808440b3,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x88000000, 0xfc000000, 0x8a000000, 0xd7000000,
+    0x38800000, 0x07400000, 0x9c600000, 0x2c700000, 0x04680000, 0x68640000, 0x9a7a0000, 0x396b0000,
+    0x77f48000, 0x2da5c000, 0x5e5f2000, 0x4de83000, 0xd2a0d800, 0x0ac75400, 0xb8a0da00, 0xedc75700,
+    0x6820db80, 0xe68754c0, 0xf640d9a0, 0xe1f75730, 0x40a8db88, 0x59d3546c, 0x7e32db6a, 0x4b885471,
+    0x91ce591b, 0x223994c0, 0x5183790a, 0x3edea561, 0x6ead2363, 0x20d73184, 0xffa658bc, 0xad5d9632,
+    0x1b797a45, 0x0cf5a7c5, 0x8739a2af, 0x0a02f3de, 0x17117a45, 0x5891a7c5, 0xf743a2af, 0x1469f3de,
+    0xd065fa45, 0x8e7467c5, 0xbf7c82af, 0xa2f1c3de,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xf8000000, 0xf4000000, 0xd2000000, 0x5f000000,
+    0xc1800000, 0xeac00000, 0xd3a00000, 0x83b00000, 0x4ba80000, 0x67ac0000, 0xb1b20000, 0xc4ad0000,
+    0xae338000, 0x5769c000, 0x31022000, 0xa08a1000, 0x824f4800, 0x7df13c00, 0x284f4a00, 0x16f13f00,
+    0x1bcf4a80, 0x53313cc0, 0xf1ef4960, 0xce413f10, 0xbbe74a28, 0x755d3e6c, 0x807d492e, 0x3c9c3dc7,
+    0x4c5cca60, 0x2ce8ffc9, 0x98c56b4e, 0xbca72e57, 0x9a3a0188, 0xa5710145, 0x9e098130, 0x9918c318,
+    0x9c8ba174, 0x7c52d25a, 0xf4e4e89d, 0x9cd3ec7d, 0x96a3a174, 0x313ed25a, 0xb6f6e89d, 0x2bceec7d,
+    0x8b382174, 0xf5fb125a, 0xe446c89d, 0x10e9fc7d,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x08000000, 0xac000000, 0x6a000000, 0x61000000,
+    0x2f800000, 0x41400000, 0x03200000, 0xfb300000, 0x1f380000, 0x792c0000, 0x02220000, 0x44a30000,
+    0x86768000, 0xae1bc000, 0x3707e000, 0xfc8a1000, 0xdbd63800, 0xa3f85c00, 0x1e563a00, 0xc3b85d00,
+    0x92f63880, 0x09c85cc0, 0x86ee38a0, 0x27d45e70, 0xf1f43908, 0x7b5b5ff4, 0x5a20b9b6, 0xd0a39ef1,
+    0xe871d9ba, 0x79024d1d, 0x1b9801c6, 0x5f5c00f9, 0xdc3a03ce, 0x7bbf00eb, 0xd6ec8357, 0x1fd4c393,
+    0xf5f363ab, 0xcd42d2d1, 0x593f596c, 0x32358dda, 0xecbde3ab, 0x5a7512d1, 0xcc1ab96c, 0xfa1c9dda,
+    0xb91d5bab, 0xfb968ed1, 0x8f4b636c, 0xa42ed0da,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x68000000, 0x4c000000, 0x16000000, 0xc7000000,
+    0x0f800000, 0x09c00000, 0xeae00000, 0x72f00000, 0x26e80000, 0xa4ec0000, 0xc1fe0000, 0x3b730000,
+    0xa03a8000, 0x9d984000, 0xc4d0a000, 0x7c695000, 0x6fbc6800, 0xb44b3400, 0xbe3c6a00, 0x668b3700,
+    0x055c6a80, 0x06bb3540, 0xb8d46b20, 0x52673470, 0xaca26b78, 0x71c8376c, 0x0eeeeb46, 0x08ff774b,
+    0x67f2c8ed, 0x04616680, 0x8bb28076, 0xce444013, 0xff26a171, 0xc60653fe, 0x4f10eb71, 0xf38c77c8,
+    0xe7c84bc2, 0x09f92409, 0x276222a4, 0xfe2d1384, 0x869acbc2, 0xb54d6409, 0xfeac82a4, 0x9cc74384,
+    0x087423c2, 0x7db21009, 0xb95e48a4, 0x08a62484,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xa8000000, 0xdc000000, 0x56000000, 0xd5000000,
+    0x34800000, 0x18c00000, 0x63e00000, 0xfdf00000, 0x64e80000, 0xfe6c0000, 0x37a20000, 0x42d70000,
+    0xf8fc8000, 0x487e4000, 0xb2b8e000, 0xae547000, 0x943c6800, 0xa1969400, 0xcc546a00, 0x2b3a9700,
+    0x46166a80, 0xed1d96c0, 0x1082e820, 0x4acfd5b0, 0x94f80978, 0x967ca6e4, 0xcbb0e1aa, 0x64c87287,
+    0x85f66a25, 0xa0ed9670, 0xfc6ae936, 0x18a3d749, 0x5d5a0bc8, 0xddaba4f2, 0x51cc63d3, 0xe17631f7,
+    0x602e8a93, 0xeb89e779, 0x6f5e831e, 0xbaa940ab, 0xc2446013, 0x4a2a31b9, 0xd8848b3e, 0x06c2e51b,
+    0x3ae8016b, 0x476c035d, 0xdd220094, 0x2317009c,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x28000000, 0x4c000000, 0x5a000000, 0x85000000,
+    0x52800000, 0x02400000, 0xe2e00000, 0x74f00000, 0xebf80000, 0x9c6c0000, 0x7cba0000, 0xb41f0000,
+    0x6e068000, 0xab05c000, 0x59816000, 0xebdd3000, 0x21387800, 0x19dda400, 0xa8207a00, 0xb141a500,
+    0x9f627880, 0x2932a7c0, 0xa5def820, 0x7a2866f0, 0xc8591948, 0xbff094e4, 0xe2600002, 0x4fb000ed,
+    0x89980189, 0x93dc003e, 0x552200a6, 0x97c3008f, 0x132481f4, 0x70c6c36f, 0x10a5e124, 0x1e1bf3d7,
+    0x631d9908, 0x05865471, 0x29dde007, 0xc037f0c5, 0x5d479988, 0x756957b1, 0x84236227, 0xbb5e3335,
+    0x227cf840, 0xafab6495, 0x999d9a05, 0x0bc65528,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x08000000, 0xe4000000, 0x72000000, 0x55000000,
+    0xb2800000, 0x8d400000, 0x59600000, 0x7f700000, 0xb0680000, 0x63ec0000, 0xd6320000, 0xbb130000,
+    0xb99f8000, 0xdcc9c000, 0xb1a76000, 0x44df3000, 0x4da79800, 0x0adc3400, 0x76af9a00, 0x73403700,
+    0x8a759a80, 0x32ff34c0, 0xc6b81aa0, 0x9b55f750, 0xbe68fb68, 0xb8ef0604, 0x7fba01a2, 0xbfcf0031,
+    0x142582c7, 0x0606c2cc, 0x3f02e086, 0xdb99f083, 0x51c57bfe, 0x1f35c5ff, 0x5782e2d0, 0xd7d9f0d8,
+    0xe025785c, 0xac05c73e, 0x760ae2af, 0xf705f1b8, 0x1f9f7adc, 0xd3cac7fe, 0x422f620f, 0x010331e8,
+    0x289d9b34, 0xec53353a, 0x61ea190d, 0x4b36f689,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xd8000000, 0x8c000000, 0x66000000, 0x4f000000,
+    0x41800000, 0xe3400000, 0x4e600000, 0x3c700000, 0xf9680000, 0xe1fc0000, 0x04260000, 0xbc9d0000,
+    0xc7dc8000, 0x65a84000, 0x4fc5a000, 0xf1bf9000, 0x05c4f800, 0x08bab400, 0xd34cfa00, 0x2676b700,
+    0x1862f880, 0x1b67b440, 0x9cf07be0, 0xe0aef670, 0x374f5a78, 0x6464274c, 0xb572837e, 0x27f940c1,
+    0x9b372047, 0x2506d330, 0xa893d922, 0x4dcc67f7, 0x3cb72052, 0x4946d035, 0x0773da55, 0x42fc64df,
+    0x53bf218c, 0x18cad2e4, 0x9c3ddada, 0x509d657b, 0xd1c5a10c, 0x22bf92a4, 0x5a44fb3a, 0xf8fab40b,
+    0x62acfbf4, 0x3a46b5a8, 0x88eaf9a4, 0x6aabb5ba,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x98000000, 0x5c000000, 0x86000000, 0x7b000000,
+    0x11800000, 0xa0400000, 0x43200000, 0xd8300000, 0x59b80000, 0x01f40000, 0xcece0000, 0x08ed0000,
+    0x765e8000, 0xe03a4000, 0xf5bb2000, 0xaff41000, 0xa1ce9800, 0x5b64bc00, 0x77009a00, 0x4f89bd00,
+    0xe75e1b80, 0x04b3fe40, 0x9b6539e0, 0x5707ef30, 0xff8ba3f8, 0xaf5352dc, 0xc0b339be, 0x416eed5d,
+    0xaa032332, 0x9500107d, 0x1e809bb4, 0x23c9bf4a, 0xda7e1b77, 0x2b83fdb5, 0xcd5d3b10, 0xd1b3ef68,
+    0xe5e5a1f4, 0xa4ce50aa, 0xbdf5bb47, 0xd8d0accd, 0x8bee020c, 0xebdd0276, 0x5e6682f9, 0x918e4190,
+    0xe055213e, 0x2329120b, 0x0828194d, 0xc1aafeda,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x38000000, 0xec000000, 0xa2000000, 0xe3000000,
+    0x1b800000, 0x36c00000, 0xb0200000, 0x23300000, 0x40b80000, 0xfa740000, 0x184a0000, 0xe07d0000,
+    0x57588000, 0x39f8c000, 0x728ea000, 0xaa40d000, 0x8b668800, 0xf8dc4c00, 0xb12c8a00, 0xbba14d00,
+    0x9df40b80, 0x44998ec0, 0x675aab60, 0x21e95d70, 0x0e8421e8, 0xc04112fc, 0xbc62ab86, 0xad5d5f27,
+    0xc6ee22c6, 0xf30c116b, 0x938228a4, 0x82d19ff2, 0x0e2a812d, 0x5a31c28f, 0x543c21f6, 0x7535104f,
+    0x7da8a97a, 0x68e05d4d, 0x0216a093, 0x3304d02c, 0xb3948892, 0x12d54fb1, 0xc63e0b15, 0x8e248f0b,
+    0x1a222a54, 0x34219eda, 0x853283b1, 0x45b5c0f9,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xe8000000, 0x4c000000, 0xde000000, 0x2b000000,
+    0x14800000, 0x39c00000, 0x7ea00000, 0x5db00000, 0xd5280000, 0xcafc0000, 0x8b4a0000, 0xb8670000,
+    0xed868000, 0xb8414000, 0x68fe2000, 0x5653b000, 0x6bfe0800, 0xaec29c00, 0x3c340a00, 0x13659f00,
+    0x39128880, 0x2194ddc0, 0xa644ab20, 0xe3fb6cf0, 0xf2d0a058, 0xba2ef05c, 0x9c6a2a0a, 0xbf862fbb,
+    0xed448370, 0xe76a430d, 0x931aa304, 0x6089f0d6, 0x53ccab71, 0x9fb76d6b, 0x1832a24d, 0x4175f1f1,
+    0x6c06a9be, 0xae106c55, 0x631421cd, 0xe884b0a4, 0x0fd089e6, 0x19bfdc09, 0x97202bc7, 0xc7e12f1f,
+    0xa0c20296, 0xef2b0304, 0x13e482c3, 0x7ada40c9,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xb8000000, 0x94000000, 0x56000000, 0xe5000000,
+    0xde800000, 0x60c00000, 0xfd600000, 0xd0700000, 0xc2e80000, 0x702c0000, 0xb64a0000, 0x71ad0000,
+    0xcf178000, 0x91914000, 0x3157e000, 0xec393000, 0x0c46a800, 0x76be4400, 0x928caa00, 0x72d34700,
+    0xe67b2a80, 0xf7f207c0, 0x06a4c860, 0xca973590, 0xf6c06138, 0x38687204, 0x3ef14842, 0x1a377487,
+    0x19420137, 0x30310006, 0x5655825c, 0x61a04056, 0x470263bd, 0xbd997094, 0xf344c843, 0x5f273695,
+    0x37c86351, 0xc8f47373, 0x0f334a64, 0x5fc6766b, 0xf4f78269, 0x45214177, 0xc0dfe226, 0x0d6532ec,
+    0x4864ab5e, 0x66ff4571, 0x9e312a7a, 0xc75f05ba,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xd8000000, 0x64000000, 0x86000000, 0xf3000000,
+    0x00800000, 0x08400000, 0x03e00000, 0x98f00000, 0xb4780000, 0x66240000, 0xa4de0000, 0x91b50000,
+    0xba018000, 0x51154000, 0x95852000, 0x2bd83000, 0xd334b800, 0xf7438400, 0xa96aba00, 0xb9b68500,
+    0x760b3980, 0x5b13c640, 0x8c961ae0, 0xe25ff7d0, 0xcae4a0f8, 0x897d701c, 0xc9a99afa, 0x9e0fb53d,
+    0x37180375, 0xb694011e, 0xf3460344, 0xbf610356, 0x72a7832f, 0xc284400c, 0x0d5aa0bd, 0x18787335,
+    0xfc3018fe, 0x85cef614, 0xec3b236e, 0xfddd3313, 0xb82d3806, 0x73c2c608, 0xf7299994, 0x914fb62e,
+    0x8a780373, 0x61240316, 0x1a5e00d0, 0xdef50078,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x68000000, 0x94000000, 0x8a000000, 0x13000000,
+    0xb7800000, 0x67c00000, 0x17200000, 0x38300000, 0x31b80000, 0x5f640000, 0xf4ca0000, 0xe0b70000,
+    0xbffd8000, 0x5698c000, 0x0f58a000, 0x51655000, 0x35da2800, 0x582d0c00, 0x01b02a00, 0xd76a0d00,
+    0x10d5a980, 0x02a6ccc0, 0x38ff0820, 0x6b109e90, 0x7b92a208, 0xf1d252dc, 0x6a27aad6, 0x7eb5ce43,
+    0x66e88b0a, 0x120f5e63, 0xaf0f81ec, 0x498bc08e, 0x8ecf23b7, 0xdbba9010, 0x7c670a3e, 0xcb449d59,
+    0x6360a321, 0x4ac15233, 0xe9b028c4, 0x036a0e42, 0x7ad5a929, 0x61a6ccef, 0xe77f0812, 0x98d09d01,
+    0xe6b2a3a3, 0xdae2524c, 0xec1fabde, 0x4611ce1f,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x08000000, 0xe4000000, 0x1e000000, 0xc9000000,
+    0x53800000, 0x4c400000, 0xdca00000, 0x49b00000, 0xd8380000, 0xdf640000, 0xfb560000, 0x96270000,
+    0xbe638000, 0x3cd44000, 0x5c736000, 0x47d21000, 0xdce21800, 0x1f10cc00, 0xde941a00, 0x71c7cd00,
+    0xc1ef9980, 0x2a878f40, 0x27d2f9a0, 0x0ce69cd0, 0xf71d61d8, 0xaa9113f4, 0x67d79a96, 0xece38fc5,
+    0x6704f864, 0xa2819da3, 0x83dee0a4, 0xf2f5530e, 0xae1cf911, 0xf1159ce2, 0xcf90e35e, 0x2e465144,
+    0xe7b179b3, 0x2932dc9c, 0x10ee030a, 0xd503014f, 0x7195806b, 0x97434368, 0x2c28e39c, 0x8962538a,
+    0x3647798f, 0x4ba5dd8b, 0x33358098, 0x57f34154,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x98000000, 0xbc000000, 0x5a000000, 0x8b000000,
+    0xe8800000, 0x9a400000, 0x19200000, 0xa8300000, 0x1bb80000, 0x616c0000, 0xf60a0000, 0x39090000,
+    0xe7968000, 0xa4c9c000, 0xca7ea000, 0x6e80b000, 0x9b5c7800, 0x72a81c00, 0xceee7a00, 0xe6cd1d00,
+    0xbd72f880, 0x7c0dddc0, 0xba1adae0, 0x1b04af30, 0x40980388, 0xbe5c011c, 0xff32022a, 0x79250275,
+    0x783c80ce, 0x13b0c015, 0x757022c0, 0x88157360, 0x5410d870, 0xde0dada8, 0x5d0e824c, 0xa195c052,
+    0x45cca181, 0x31e5b288, 0xa040fb62, 0x4228dcf7, 0x48a65a89, 0x95f46e54, 0x064823a8, 0x333971b2,
+    0x1b3ad9cf, 0x7f34af5d, 0x39200342, 0xd83000a7,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xd8000000, 0x3c000000, 0xfa000000, 0xbd000000,
+    0xe3800000, 0x3b400000, 0xb3200000, 0x24300000, 0x52b80000, 0x8e640000, 0x70120000, 0x78190000,
+    0x6c168000, 0x9215c000, 0x59146000, 0x25931000, 0x7c53f800, 0xedbadc00, 0xfcf9fa00, 0xdac7dd00,
+    0x197d7980, 0x068b1fc0, 0xa4df9ae0, 0x927dcf30, 0x9a000108, 0x4d0001c4, 0xdb800086, 0xb7400029,
+    0x912001ca, 0xa5300279, 0x4b3803e0, 0x082400b0, 0x20b201c8, 0x676903a4, 0x8d8e83f6, 0x3841c101,
+    0x7bbe605e, 0xd3ee1107, 0x605778e5, 0x07b61c88, 0xc9fb18d6, 0x6d410f03, 0x3c3ae283, 0xcea2d391,
+    0x24759814, 0xa500ccbe, 0x7f8481e5, 0x914cc308,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x58000000, 0x1c000000, 0xee000000, 0xbd000000,
+    0x8d800000, 0x6e400000, 0xf5200000, 0x3e300000, 0x72b80000, 0x2f7c0000, 0x21120000, 0x23890000,
+    0xf3428000, 0x08bb4000, 0x0872e000, 0x9b805000, 0xff5a1800, 0xeebf7c00, 0x81701a00, 0xbc0a7d00,
+    0xde009a80, 0xc5083cc0, 0xe188fbe0, 0xd84f2cb0, 0x54320058, 0x5db90244, 0xa1fa806e, 0x57c742fd,
+    0x7160e33a, 0xa40953c5, 0xe2189b90, 0x5b043f08, 0x0482faac, 0x49ca2c72, 0xd47a83d7, 0x15874029,
+    0x1240e342, 0x4b3953cf, 0xab209a4d, 0xbb383ffc, 0xb330f99a, 0x87332d4b, 0x2d2001c3, 0x623001b1,
+    0xbcb802f8, 0xe27c00ca, 0xf492003d, 0x51c90144,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x08000000, 0xf4000000, 0x2e000000, 0x93000000,
+    0x58800000, 0x0ac00000, 0x9f200000, 0x0a300000, 0x45b80000, 0x91ec0000, 0x530a0000, 0xb89b0000,
+    0x9acd8000, 0xa73c4000, 0xf6382000, 0x9fba3000, 0x2cf1e800, 0x9881cc00, 0xeac3ea00, 0x0f36cd00,
+    0x32246880, 0xb9a18dc0, 0x4be9c8a0, 0xee0bfe10, 0x730a0018, 0xc89b005c, 0x32cd837a, 0x633c4177,
+    0xd0382386, 0xf8ba319f, 0x5a71eab0, 0x0141cc08, 0x2d63e944, 0x0fc6cda6, 0xe8bc68cd, 0x227d8fd1,
+    0x5d5bc949, 0xc77cfe57, 0xbacd8054, 0xd73c403e, 0x5e3821d1, 0x5bba33cb, 0x0af1eb8e, 0xff81cf59,
+    0x9c43ea4f, 0x96f6ce08, 0xf5846844, 0xb9518f26,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xc8000000, 0x9c000000, 0x42000000, 0x65000000,
+    0x94800000, 0x27400000, 0x45e00000, 0xbef00000, 0xed680000, 0x0bac0000, 0x258e0000, 0xadc70000,
+    0x65ac8000, 0x1a90c000, 0x28566000, 0x1861f000, 0x97209800, 0xbec36400, 0xba269a00, 0x76586700,
+    0xff6c1a80, 0x46a3a740, 0x1d18f9a0, 0x10959530, 0x114280a8, 0xeee7c0a4, 0xc572e0fa, 0xa7ad3339,
+    0xbf90f96d, 0x9cc99582, 0x2f2483f8, 0x1accc0dc, 0x9c3060e6, 0x154af0bf, 0x18e21882, 0x4e64a678,
+    0xcc34791c, 0x3d455406, 0xb4f4e32f, 0xd476339a, 0xfd3a7b34, 0x77c257e2, 0xb4b86075, 0x6016f293,
+    0xf0041871, 0xf80fa584, 0x4416fad7, 0x16129446,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x28000000, 0x5c000000, 0x02000000, 0xdf000000,
+    0xc3800000, 0x1f400000, 0xf2600000, 0xa3700000, 0xd5f80000, 0xea340000, 0x969e0000, 0x2fcb0000,
+    0x34a88000, 0xb554c000, 0x317d2000, 0x02f45000, 0x45b23800, 0xf3cf3400, 0x76b43a00, 0x0a403500,
+    0xa2fabb80, 0x35abf440, 0xebd11b20, 0x52b06530, 0x7c4e8328, 0x23ebc24c, 0x2b2ba226, 0xe81b9331,
+    0x7c1f986f, 0x321ba4fc, 0xa7052198, 0xb7805324, 0x414c384a, 0x2f7434a7, 0xbfe4b8b6, 0x0920f73f,
+    0x07199a92, 0xc794a6f5, 0x594ba375, 0x0b6b90e3, 0xc9e79a3a, 0x882fa4f9, 0x199b2073, 0xc44b53e2,
+    0x77e4bafd, 0x4520f609, 0x4d1998ed, 0x1494a6c7,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xb8000000, 0xa4000000, 0xf2000000, 0x77000000,
+    0x64800000, 0x40c00000, 0x7f600000, 0xc6700000, 0x33e80000, 0x5aa40000, 0x7a860000, 0x09c10000,
+    0xe2ed8000, 0xd32fc000, 0x55db2000, 0x14fcb000, 0x262a2800, 0xee588400, 0x2ca42a00, 0x4f8d8700,
+    0xd247ab80, 0xcab74740, 0x029f0960, 0xcdc135d0, 0x00e582a8, 0x1c3bc00c, 0x9555213a, 0xa629b20f,
+    0x2e49a947, 0x4ca24556, 0x5f9c8968, 0x6a4bf5ac, 0x6eb522ca, 0xf099b017, 0xbac1a833, 0x647647c8,
+    0x5cf288d1, 0xea2ef61e, 0x605ea274, 0x1db7731e, 0x161709f9, 0x25153552, 0x638b802e, 0x8c5ec3c1,
+    0x23bea196, 0xef077348, 0x709f0b1c, 0x7ac136b2,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x98000000, 0x84000000, 0x46000000, 0xb5000000,
+    0x16800000, 0x2ec00000, 0x57600000, 0x81f00000, 0x0f380000, 0x48440000, 0xa1aa0000, 0xb2810000,
+    0x38d58000, 0x6a774000, 0x7b60e000, 0x7bf7b000, 0x68252800, 0xc5df2400, 0x30f0aa00, 0x1fa86500,
+    0x33904980, 0x105fd6c0, 0x85b563e0, 0xe480f0d0, 0xe5c5c8f8, 0x60e89704, 0x97b58122, 0xdf87417f,
+    0x4a58e39f, 0xf2b3b3a8, 0x010f2b5b, 0x689e258d, 0x0fc528ff, 0x6fef2638, 0x0628ab83, 0x8cdc6439,
+    0x14624935, 0x5a6ad45b, 0x4372e26a, 0xeff2b074, 0x463aaaaa, 0x6cd967f3, 0xe47dc931, 0xc26c95f9,
+    0xc77f8255, 0xa9f641cb, 0xf33562b2, 0x7a40f1c0,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x78000000, 0xac000000, 0x82000000, 0x59000000,
+    0x92800000, 0x77400000, 0x0c200000, 0x0eb00000, 0x11f80000, 0x99cc0000, 0x31620000, 0x2f950000,
+    0x53cc8000, 0x5c7ec000, 0xc300e000, 0x778e3000, 0x4fd61800, 0x06737c00, 0xc61a9a00, 0x1f0dbd00,
+    0xcd9a7880, 0x1ac38ec0, 0xe6ec6160, 0x9040f070, 0x2daef968, 0x36714c14, 0x5e0e83a2, 0xe31bc309,
+    0xc79462f2, 0x97ccf343, 0x5a6cfbdd, 0x3c144d30, 0xea1a00ba, 0xdd190287, 0x348e8107, 0x785bc1b5,
+    0xe9b46356, 0x307cf29b, 0xa114f933, 0x7e984fb5, 0x555801a4, 0xa53c01d8, 0xe43a02ee, 0xcaa90285,
+    0x17f6811e, 0x66d7c35f, 0xacf663e9, 0xbd59f330,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xb8000000, 0xe4000000, 0x06000000, 0x2f000000,
+    0x76800000, 0x7ac00000, 0xace00000, 0x92700000, 0xa4a80000, 0xf25c0000, 0x7d360000, 0x3e090000,
+    0x0b098000, 0xd09f4000, 0x85c3a000, 0x627cf000, 0x0ca8e800, 0x0e5cd400, 0x4f216a00, 0xaf039700,
+    0xb682c880, 0xdacf6440, 0x7ce22260, 0x2a7fb3d0, 0x40bd4878, 0xf459246c, 0x52280122, 0x489c01c7,
+    0x71d601f1, 0x7c79005e, 0x17a1831b, 0xc6c343fb, 0xfef5a1cd, 0x7375f1c4, 0x71216a90, 0xa4039598,
+    0x6602cb7c, 0x5f0f677a, 0x1e82201b, 0x26cfb17b, 0x4ef54a8d, 0xbb752724, 0xfd360300, 0xfe090280,
+    0xab098340, 0x009f42e0, 0x3dc3a390, 0x867cf018,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x28000000, 0x24000000, 0xae000000, 0xfb000000,
+    0xac800000, 0x84400000, 0x5c600000, 0x34f00000, 0x6eb80000, 0xd1dc0000, 0x55ae0000, 0x1d530000,
+    0xe1eb8000, 0x79204000, 0xd9142000, 0x9d963000, 0x5dd8f800, 0x9fbc5400, 0x68537a00, 0xa66c1500,
+    0x79ff5a80, 0x25262540, 0x5b09a220, 0xfc8971d0, 0x8c515a98, 0x6875273c, 0xb2e222aa, 0xb1a9306d,
+    0xd34579fb, 0x2ae3156e, 0xedbad8d9, 0x51556415, 0x4bf601a5, 0x3c3f014d, 0xa69d81f9, 0x915f4017,
+    0x2be9a2dc, 0x0c397048, 0x9e895824, 0x9d592756, 0xa1f42327, 0x59263126, 0xc900fafd, 0xb5905643,
+    0x79c57802, 0x31a3152b, 0x935ad924, 0x0ae56784,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x58000000, 0x64000000, 0xde000000, 0xdd000000,
+    0xe6800000, 0xb1400000, 0xe2600000, 0x90f00000, 0xb7b80000, 0x94dc0000, 0x08a60000, 0x55550000,
+    0x7c658000, 0x6de84000, 0x013ee000, 0x7d8bb000, 0x8edbd800, 0x1ba87400, 0x16de5a00, 0x1fb03500,
+    0xb8d8ba80, 0xcaa78440, 0x624563e0, 0xc1eaf1d0, 0x8326ba98, 0x6a9e87a4, 0x635ee382, 0xad7bb121,
+    0x1963dbeb, 0xdf7476b2, 0x467859ad, 0x2ee53645, 0x1abd388d, 0x7a4fc671, 0x85fb8377, 0x0d2141a4,
+    0xef9d60c6, 0xe1c6f0f3, 0xc238b892, 0x2617865d, 0xa91d632d, 0x4086f241, 0x5858bb3f, 0x82e78518,
+    0x98a56120, 0x6d5af070, 0x687ebba8, 0xbbf2856c,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xc8000000, 0x04000000, 0x76000000, 0x65000000,
+    0xae800000, 0x63400000, 0xe7e00000, 0xbf700000, 0x79280000, 0x104c0000, 0x3c620000, 0x6ab30000,
+    0xc18f8000, 0xf2cb4000, 0xed38e000, 0xbe40b000, 0x45749800, 0x8e3f0400, 0x7dd31a00, 0xacb84700,
+    0x8c89fa80, 0x884bf4c0, 0xc072e1a0, 0xf0bfb0d0, 0x26991a78, 0x87474644, 0x41e47a42, 0xe273b709,
+    0x1ba5827d, 0x01044224, 0xc89d6361, 0x1e44f0e1, 0x7569f872, 0x663bf692, 0x89dae371, 0x12b3b139,
+    0xed9b19e6, 0x50c44428, 0xc623fafc, 0xb9c4f6a6, 0xfab7611b, 0x198bf2cc, 0xeecc783d, 0xa73fb697,
+    0x61478311, 0xfcf7421a, 0x50f2e10e, 0x32ffb0a7,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0xd8000000, 0x04000000, 0x8e000000, 0xe3000000,
+    0xa3800000, 0xabc00000, 0x46e00000, 0x4b700000, 0xd3b80000, 0x7ec40000, 0x327a0000, 0xf5390000,
+    0xa98d8000, 0x86de4000, 0xe67f2000, 0x333cd000, 0xe69ee800, 0x774c9400, 0x24ab6a00, 0xbb56d500,
+    0x46ae4980, 0xea5304c0, 0xfc3d22e0, 0x5701d0d0, 0x55896ad8, 0xdcdbd65c, 0xc361c9d6, 0xdfb04433,
+    0xbcd5802d, 0xf36a40ce, 0xe7bd203b, 0xc8c1d3a9, 0xa5696bc4, 0x00abd49e, 0xe559cb97, 0xedb44417,
+    0xe5cf83e3, 0x05e34241, 0x78e8a010, 0xd06b920c, 0xe4344a4e, 0xf31a064f, 0x4b88a2cb, 0x97db9175,
+    0xbcec4b52, 0xfe6e048d, 0x972aa08a, 0xf8969151,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x18000000, 0x74000000, 0xf2000000, 0x79000000,
+    0x1e800000, 0x47400000, 0x80200000, 0x14b00000, 0x0ef80000, 0x9a440000, 0xd4b60000, 0xaeed0000,
+    0x6a578000, 0xdca7c000, 0xc2f1e000, 0xec4bb000, 0x57bd9800, 0xa565fc00, 0xb5921a00, 0x90c63d00,
+    0x31f5f980, 0xafd08d40, 0x0467e1e0, 0x7f16b090, 0xd5921828, 0xc0c63c74, 0xc9f5f9d2, 0xcbd08c29,
+    0xee67e032, 0x7216b1eb, 0x391219a1, 0xfe863eb6, 0x5755f8c1, 0x98208db4, 0x60bfe0f2, 0xfce2b219,
+    0xe35c186a, 0xca2f3ea7, 0xe9b4786f, 0xea6a4d59, 0xc81981d8, 0xcc0ec20c, 0x7610602e, 0x8301737f,
+    0x0b9bf931, 0xdfc98fcc, 0x4c7e630e, 0xf318704f,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xa8000000, 0x94000000, 0x0a000000, 0x99000000,
+    0x2f800000, 0xe2c00000, 0x81a00000, 0x1c300000, 0x9be80000, 0x4bd40000, 0xb6360000, 0xb2eb0000,
+    0xbc5d8000, 0xf8e2c000, 0x055ee000, 0xa7689000, 0xdf810800, 0x1ad05c00, 0x5db48a00, 0x12269f00,
+    0xa0fc6980, 0x69550d40, 0x7168e320, 0x48839310, 0x0e5c8908, 0x9df29e74, 0x54ca6836, 0x46be0cbf,
+    0x40b56310, 0x5fa150df, 0xaf2269f7, 0x5d6a0ec4, 0xbe836239, 0xe94a50a0, 0xb17fe950, 0xa888cfa8,
+    0x9e5d8224, 0x35e2c11e, 0xc0dee25b, 0x4ca8906e, 0xd9a108b4, 0x70205c81, 0x4dfc888c, 0xdcc29dba,
+    0xa2a26b85, 0x72aa0e55, 0xfaa363ea, 0x1eba51ad,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xe8000000, 0xac000000, 0x46000000, 0xb1000000,
+    0xab800000, 0x3fc00000, 0x74e00000, 0x75700000, 0xdda80000, 0xe3dc0000, 0x1af20000, 0xc8670000,
+    0xc03e8000, 0x851c4000, 0x69996000, 0xc4dc9000, 0xd8722800, 0xb82c5400, 0x9104aa00, 0x9b9c1700,
+    0x77c7c880, 0x28fb84c0, 0xdb796320, 0xc0ac9270, 0x0e5a2838, 0x943057b4, 0x1716aa16, 0x8a8b178b,
+    0x2c514ae5, 0xff3bc72e, 0x0392032b, 0xf3d700d5, 0x62f682f6, 0xdc7042cf, 0x3223600b, 0x36179231,
+    0x9916a8a8, 0xa78b15c8, 0x89d14bcc, 0x2dfbc642, 0x72f202cd, 0xa4670226, 0x263e81c7, 0xc41c43e7,
+    0x2a196083, 0x571c939d, 0xea9228fa, 0x7c5c542d,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xd8000000, 0x04000000, 0x0e000000, 0xbf000000,
+    0x26800000, 0xae400000, 0x51200000, 0xedb00000, 0x3af80000, 0x7e5c0000, 0xb9220000, 0x11a10000,
+    0x40ed8000, 0x17464000, 0x24b4a000, 0x9773f000, 0x008b7800, 0x8d4d7c00, 0x5dbefa00, 0x82e73d00,
+    0x2a505a80, 0x1f29ccc0, 0x72b4a1e0, 0x6c73f0d0, 0x080b7bd8, 0xec0d7f2c, 0xf21ef9c2, 0xc5173d55,
+    0x4f885b66, 0x33c5cf33, 0xd7eea0d5, 0xadcef026, 0xa0e4f893, 0x075a3d25, 0xacbfdace, 0x3b7e8cb7,
+    0xd2958013, 0x385a4175, 0xca36a346, 0xb522f113, 0xf3bef9f5, 0x0de73e86, 0xf4d05873, 0xc569cce5,
+    0xf594a03e, 0x3ac3f04f, 0x1a737a9f, 0x83117dd7,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0xc8000000, 0x0c000000, 0x0a000000, 0x57000000,
+    0xd9800000, 0x3b400000, 0xef200000, 0x78b00000, 0xd2e80000, 0x195c0000, 0x443e0000, 0x532f0000,
+    0xeaa28000, 0xe1e5c000, 0x56c42000, 0xea779000, 0x3e983800, 0x7ad27c00, 0xb072ba00, 0x019bbf00,
+    0x3f409880, 0x492f2d40, 0xb5ac23a0, 0x946b9170, 0xf7863988, 0x9a4d7f2c, 0x93b83a6e, 0x99627e81,
+    0x111ab872, 0xe487bd13, 0x85de9831, 0x55f02c1a, 0x68c6a26f, 0x436250e7, 0xee141b1f, 0x0109ed6f,
+    0xec9c8233, 0x29cac201, 0xcfe6a332, 0xf7d253b3, 0x96fc1ac1, 0xdf55ee52, 0xb92282a3, 0x4da5c1d9,
+    0xc06421a6, 0x398790d9, 0xcb503a72, 0x173e7c47,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x98000000, 0x54000000, 0x2e000000, 0xc9000000,
+    0x10800000, 0x98400000, 0x7ce00000, 0xfd700000, 0xc1b80000, 0x9b0c0000, 0x7b9a0000, 0x9bc10000,
+    0x233f8000, 0x584c4000, 0x9cfe6000, 0x6d7af000, 0x69b63800, 0x57018400, 0x0193ba00, 0x7cccc500,
+    0xfab25880, 0xd08a75c0, 0x784202e0, 0xecfd0090, 0x557d8058, 0x0db142b4, 0xe103e29a, 0x9c8bb015,
+    0x4255d909, 0xabfa36b0, 0xbcfe60b7, 0x1d7af2f8, 0x51b6399b, 0x33018736, 0xb793ba04, 0xe1ccc532,
+    0xc4325b29, 0x81ca745f, 0x142201fb, 0x89cd0379, 0xe8258278, 0x6bcd405b, 0x5b21e3d6, 0x9c46b194,
+    0x1af05aea, 0x687775dd, 0x033f8365, 0x284c435e,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xe8000000, 0xe4000000, 0x2a000000, 0x9b000000,
+    0xca800000, 0x4ac00000, 0xda200000, 0xd3b00000, 0x6ff80000, 0xa90c0000, 0xcd860000, 0x06530000,
+    0x15fb8000, 0x9a14c000, 0x4302a000, 0xb69b7000, 0xbcd96800, 0x67364c00, 0x4c24ea00, 0x5eb18d00,
+    0x687dc880, 0x444e3e40, 0x2afe0320, 0xda9f01d0, 0x42dd8188, 0x4e37c2bc, 0xc1a12022, 0xf8f3b0d7,
+    0xad85c8ea, 0x36423c09, 0x8df803fd, 0x460c01c1, 0x65060361, 0xe39302b3, 0xc75b817e, 0x7c64c345,
+    0x165aa2c5, 0x1de772c7, 0x0e076882, 0x51194c05, 0x21816ba7, 0xb84a4c72, 0x9cfaeb5d, 0xc79e8d13,
+    0x0d58482c, 0x9775fec8, 0xe4d923de, 0x5b3fb297,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x98000000, 0xe4000000, 0x3e000000, 0x61000000,
+    0x4e800000, 0xfac00000, 0xa5200000, 0xdeb00000, 0x0ce80000, 0xf60c0000, 0xcd1a0000, 0x1c910000,
+    0xe9c98000, 0xc8a84000, 0xd1e76000, 0x428a3000, 0x58c6a800, 0x0e202c00, 0x67352a00, 0xa5a96f00,
+    0x3d73ca80, 0x13471fc0, 0x446800e0, 0x79cc0050, 0xa0ba0218, 0x8de102d4, 0xa88183a2, 0x9fd441ff,
+    0xc5b56356, 0xed673115, 0x9b5d2b9d, 0x08656f49, 0x3bc9cb93, 0x1ba61fd0, 0x9c698220, 0x7dd84348,
+    0x8eaf6134, 0x44f6310a, 0x9a14ab1b, 0xbf0d2c04, 0x3f8ea87a, 0x1c5c2ecb, 0x23e72924, 0xa1846db2,
+    0x8d484a3f, 0xd5725fce, 0x8f5ce079, 0x8e7f71e3,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xf8000000, 0x94000000, 0x7e000000, 0xa7000000,
+    0xd4800000, 0x45c00000, 0xb9a00000, 0x16300000, 0xb5680000, 0x14140000, 0xbe1a0000, 0x471d0000,
+    0x24938000, 0xbdc24000, 0x2da9a000, 0x6831b000, 0x126ec800, 0xc0911c00, 0xfbc74a00, 0xfebe5f00,
+    0x32b56980, 0x08a9acc0, 0x39a00260, 0xd6300290, 0x55680198, 0xe414030c, 0x461a014a, 0xd31d01db,
+    0x5a93804c, 0x1ac243d3, 0xf929a361, 0x2df1b11f, 0xabcecacb, 0xd6a11d14, 0x4eaf4abf, 0xeaaa5cbb,
+    0x8caf6b5c, 0x4fb4ae8b, 0x1d33820d, 0x6bf242c5, 0x78c1a288, 0x8c25b254, 0x5474ca26, 0x138c1d01,
+    0xa154c98f, 0xe47c1c53, 0xcb9ccb98, 0x25581d35,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xa8000000, 0x14000000, 0xf2000000, 0xf9000000,
+    0x6b800000, 0x3b400000, 0x0de00000, 0xd3700000, 0xdda80000, 0xfc1c0000, 0x86160000, 0x7b1b0000,
+    0xea8d8000, 0xecc9c000, 0xd0a3e000, 0xd59c1000, 0x9c4d3800, 0x717fa400, 0x4cb6ba00, 0x239d6700,
+    0x9f50d880, 0x27e4b440, 0x46600220, 0xf8300010, 0xd84803e8, 0xeb6c00f4, 0x01be00c6, 0x6a070359,
+    0xf51b8251, 0x5592c044, 0x5c4e62ef, 0xd165d050, 0x9ca6d959, 0x8b8fb551, 0x8b4580c4, 0xd5e5c2af,
+    0xbf7de070, 0x93ab1249, 0xe31eb839, 0xe6816570, 0xd2c6d9c9, 0xb7bfb6f9, 0x090d8010, 0xd389c179,
+    0x2743e241, 0x3bec13ac, 0x70653a1b, 0x5b23a496,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xa8000000, 0xf4000000, 0x8a000000, 0x69000000,
+    0x2e800000, 0xd3400000, 0x2ce00000, 0xb1700000, 0xcdb80000, 0x488c0000, 0x5c460000, 0xcd7b0000,
+    0x23a18000, 0xa382c000, 0x3fdee000, 0x12b7b000, 0x410d9800, 0x9a99a400, 0xf9521a00, 0x95ec6500,
+    0x37eb7880, 0xeae21640, 0xee798220, 0x843ec190, 0x2e40e208, 0xb870b134, 0xd3321832, 0x43dc6539,
+    0xfcb37821, 0xaa1e152c, 0xf90783bf, 0x2689c2ea, 0xf74762d4, 0x0ef97042, 0x2c757a41, 0x692516ed,
+    0xf2c60192, 0x5e3b0218, 0xaf4183fc, 0xc2f2c266, 0x5a66e33b, 0xae3bb040, 0x974b9a51, 0x3ee2a425,
+    0xf4739bc6, 0xe52ea41a, 0x24d59b9d, 0x4925a51b,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x98000000, 0xd4000000, 0x16000000, 0xb1000000,
+    0x24800000, 0xd4400000, 0xc6600000, 0xbdf00000, 0xf8280000, 0x508c0000, 0x325a0000, 0xcf710000,
+    0x7d7f8000, 0xa2634000, 0xf3e26000, 0xbd3a7000, 0x52113800, 0xcf155400, 0xf99cba00, 0x02cb1700,
+    0x2d3b5a80, 0x3a1327c0, 0xa31f80e0, 0x0b934090, 0x3dca62b8, 0x6cb67154, 0xdccb3aaa, 0x002456c5,
+    0x548339d3, 0xac585462, 0x02713aeb, 0x03e55724, 0x0534b8d0, 0xb6071418, 0x41015a64, 0x9c9226a2,
+    0x304802d9, 0x487c0245, 0xd8f20241, 0xcabd03b3, 0x35c58332, 0xd0a240b3, 0xa6d5e2e0, 0x432532c2,
+    0x4f015889, 0x3992259d, 0x22c802c5, 0x1d3c0281,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x68000000, 0x1c000000, 0xaa000000, 0x07000000,
+    0x0e800000, 0xd7400000, 0xa2a00000, 0xd9300000, 0x13f80000, 0x8e9c0000, 0x97420000, 0x82a70000,
+    0xa9308000, 0x7be3c000, 0x928a2000, 0x3d567000, 0x85bcd800, 0xa7bfec00, 0xacb65a00, 0x30272d00,
+    0xe46efa80, 0x96459cc0, 0x29308320, 0x3be3c0b0, 0xb28a2388, 0x4d5671ac, 0xedbcdb32, 0xbbbfef31,
+    0x06b65a18, 0x37272d25, 0xeaeef87f, 0x41059ec3, 0x8b9083b8, 0xe2d3c3e4, 0xa172233e, 0xc3ca7073,
+    0x7afed981, 0x3918ec21, 0xaf86d8e0, 0x4cc4eea1, 0x7864da91, 0x7c53ec68, 0x0e2c588d, 0x456c2fe3,
+    0x0dc478b9, 0xf3ed5f05, 0x9e9022fe, 0xaf5d7362,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x38000000, 0x84000000, 0xca000000, 0x79000000,
+    0x4d800000, 0x9a400000, 0xbf200000, 0x45b00000, 0x61780000, 0x509c0000, 0xedde0000, 0xd4710000,
+    0x9b158000, 0xc886c000, 0xb9c2e000, 0x066dd000, 0xf602d800, 0x77159c00, 0xde915a00, 0x3ece5d00,
+    0x50e03a80, 0xe4c84f40, 0x51eb8260, 0x0d47c150, 0x91af6348, 0xf37711b4, 0x1d9e3afe, 0xc2494d9d,
+    0x8b2602e2, 0x27ad039b, 0xa46b82cf, 0x5307c07d, 0xc48f602e, 0x5fc71115, 0x09663b76, 0x8c954cd5,
+    0x13d8000a, 0xcf6c0303, 0x1386012f, 0x515d01b1, 0x2fb38320, 0xc86bc26c, 0x0509617e, 0x639a1001,
+    0x9955b824, 0x23be8e16, 0x2e716059, 0x0a061064,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x68000000, 0x9c000000, 0xc2000000, 0x3d000000,
+    0xcf800000, 0x1ac00000, 0x3ee00000, 0x32700000, 0xc8280000, 0x66940000, 0xbb5a0000, 0x532f0000,
+    0x92108000, 0x851ec000, 0x2b906000, 0xdcd81000, 0x35f00800, 0x9efef400, 0xe2728a00, 0xb02b3700,
+    0x62806980, 0x8d56e4c0, 0x302a8020, 0xa281c2b0, 0x6d48e128, 0xc022d144, 0xca926996, 0xf15de6ef,
+    0x022802cd, 0xf7940356, 0x3eda0365, 0x18ef02f8, 0xc9708096, 0x0caec26f, 0xd0d8608d, 0xaffc1236,
+    0xb7e20b35, 0x83f5f440, 0x3df008ca, 0x32fef4d5, 0xa8728950, 0xe12b3692, 0x07006819, 0x3696e572,
+    0x034a83a3, 0xb731c22f, 0x5400e247, 0x8e06d1e3,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x58000000, 0x54000000, 0xb2000000, 0x6d000000,
+    0x7e800000, 0xb6c00000, 0x40600000, 0x0df00000, 0x7ea80000, 0x11840000, 0x5d4a0000, 0x4c390000,
+    0xe14f8000, 0x522fc000, 0xfa4d6000, 0x5bb23000, 0x5303a800, 0x359d8400, 0x774e2a00, 0x153f4700,
+    0xddcecb80, 0xd1efb4c0, 0x90ad82e0, 0x5292c190, 0x00c8e228, 0x5764f324, 0xe2614a52, 0x08f0763f,
+    0x6c28e033, 0xb154f058, 0x9a294881, 0x0644756a, 0xe5aae2ee, 0xd819f311, 0x9404c842, 0xd216b64a,
+    0x9d020383, 0x268d019d, 0xe2cd8369, 0xf262c2b3, 0x60e0e218, 0x0020f221, 0xa74b495a, 0x1d397776,
+    0x41cf616d, 0x9fff328c, 0x43ae2b2b, 0xa70f44f9,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x28000000, 0x54000000, 0xee000000, 0xdf000000,
+    0x96800000, 0x0bc00000, 0x10e00000, 0x3b300000, 0xfbd80000, 0x48fc0000, 0x77360000, 0xf9cf0000,
+    0x05f38000, 0x84a14000, 0x55892000, 0x575ab000, 0x3222e800, 0x8e424400, 0xcfabca00, 0xcd18f500,
+    0xb3892080, 0x0c5ab340, 0xc2a2ea20, 0x9e824710, 0x8fcbc918, 0x76e8f72c, 0x20312162, 0xab56b013,
+    0xc82ce839, 0x1f4147d3, 0x16364ab4, 0x784ab6e3, 0xfca58351, 0xc99e42d7, 0xdd42a1f2, 0xbb37f226,
+    0xbbc5c9f5, 0xe8ebf4ac, 0xe72ca04f, 0xd1c4f3f3, 0x51e04aa4, 0x6ab5b67b, 0x8a8e01bd, 0xc1c301d5,
+    0x39fd8351, 0x9ea242d7, 0xf494a1f2, 0x36c8f226,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xc8000000, 0x54000000, 0xae000000, 0x8b000000,
+    0x39800000, 0x79c00000, 0xcee00000, 0xcf300000, 0x4dc80000, 0x90fc0000, 0xbc360000, 0xf8530000,
+    0xdb2f8000, 0x03c94000, 0x2be7a000, 0x5db1d000, 0x1d9d4800, 0xefd74400, 0xe9faea00, 0xd4a69700,
+    0x8d07a280, 0x1681d340, 0x16554ba0, 0xb02b4510, 0x0a4ceb98, 0x8a35947c, 0x0f4820de, 0x28b8908b,
+    0x871ae87d, 0xcb9697cb, 0x28cfa194, 0xc27dd103, 0xcc634919, 0x97784629, 0x46e36b61, 0x7b3cd765,
+    0xd3cf81f7, 0xc3f9416a, 0x19afa058, 0x7b8dd39c, 0x30cb48ee, 0x7e744743, 0x267d6b39, 0x7a63d4f9,
+    0xa07e0119, 0x956f0229, 0xeff98161, 0xfbaa4065,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xb8000000, 0xd4000000, 0x12000000, 0x7f000000,
+    0x71800000, 0x6dc00000, 0xe2a00000, 0xdb700000, 0x6bd80000, 0x6fbc0000, 0xa5fe0000, 0x1f950000,
+    0x8cd18000, 0x5a36c000, 0xc230a000, 0x86345000, 0x8c374800, 0xf72a4c00, 0x2ca7ea00, 0xea6e1d00,
+    0x6b48a080, 0xa2f85240, 0x5a114860, 0x83034c10, 0xaf8868a8, 0x04cddfac, 0x4629810e, 0x6c3ac20b,
+    0x8736a22c, 0x44ad52ca, 0x8660c88d, 0xad458c7f, 0xcfe0c8b6, 0x54858c8b, 0x9f40ca68, 0x20f58d8c,
+    0x3d18c97e, 0xf6898cb3, 0x6846c828, 0x4d6c8ce8, 0xfecf49c8, 0x15264c38, 0x7ba1e840, 0x57f71c64,
+    0x109f2036, 0x955792cb, 0x5bf66808, 0xe698dc9c,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x18000000, 0xe4000000, 0x62000000, 0x27000000,
+    0x53800000, 0x9d400000, 0xa5200000, 0xfc700000, 0xab580000, 0xb8340000, 0x8cea0000, 0x379d0000,
+    0xbf4b8000, 0x622ac000, 0x9ffda000, 0x2e091000, 0xf91d1800, 0x1295ac00, 0xbbc0ba00, 0x54ecbd00,
+    0x7385a180, 0x0d4d11c0, 0x6d2f1be0, 0x307caf50, 0x35413be8, 0x192b7e0c, 0x9a6b8146, 0xde5ac329,
+    0xd4a5a03a, 0xa63d12d4, 0x6df719d1, 0xc108ae1d, 0x668b3a58, 0x11c67dd7, 0xbff80294, 0xbe040112,
+    0x3112035b, 0xde990371, 0x25d980ed, 0xf5f3c1c0, 0x65042103, 0xe48ad2a6, 0x06c13879, 0xf46b7dd2,
+    0xc74b83d8, 0xf62ac017, 0x05fda374, 0xdd091342,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x38000000, 0x9c000000, 0xa6000000, 0x1b000000,
+    0x16800000, 0x1ec00000, 0x13600000, 0xe1b00000, 0x8cd80000, 0xfa7c0000, 0x4e320000, 0x15890000,
+    0x64488000, 0x33a54000, 0xc5d6a000, 0xe5fd3000, 0xef624800, 0x17b81400, 0xbfd4ea00, 0xf8f52500,
+    0x52eea080, 0x27f133c0, 0x2e684a60, 0xac3d14b0, 0x24966878, 0x47d5671c, 0x84fa8242, 0xe4ec43d7,
+    0xb4fe2277, 0x9ce8734d, 0x88ece806, 0x8af92673, 0xabe4a3c9, 0x007432a8, 0x132aca3e, 0x081d55a5,
+    0xe4024a18, 0x9a081446, 0x0d0ce9b9, 0x358927da, 0x945ca1d1, 0xabb832ee, 0xe9c0c987, 0x7be8577f,
+    0x6878cb49, 0xa7245568, 0xba12ca5e, 0xfd115415,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x78000000, 0xcc000000, 0x46000000, 0x15000000,
+    0xb0800000, 0xdac00000, 0x89600000, 0xa7b00000, 0x04c80000, 0x00740000, 0x492e0000, 0x97150000,
+    0x07828000, 0xed4f4000, 0xec376000, 0x6f923000, 0x99440800, 0x062c1400, 0xac936a00, 0x44ce2700,
+    0xa07f6380, 0xb9263140, 0x7f0a0860, 0xb3891570, 0x6759eaf8, 0xbf356464, 0xca06005a, 0xf311027f,
+    0x55848087, 0x825e4085, 0xe7b3e2ce, 0xa4cc71fb, 0xf077eaed, 0xa1206562, 0x230483dd, 0x8d9e431c,
+    0xbe53e018, 0x49bc72d4, 0x05dfe902, 0xcae464cb, 0x28e282f5, 0x0fff43b6, 0x407f60df, 0xe92630d7,
+    0x670a096d, 0xef891422, 0x5959ebbd, 0x6635666c,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x28000000, 0x0c000000, 0x5a000000, 0xe3000000,
+    0x8a800000, 0x91400000, 0x64600000, 0x61300000, 0xbb480000, 0xcf6c0000, 0x77a20000, 0x680b0000,
+    0x6c088000, 0xca18c000, 0xfb142000, 0xae89b000, 0xc749b800, 0xdd698400, 0x08bd9a00, 0xa0903700,
+    0x3a5c2080, 0x72e5b3c0, 0xb26bba20, 0x182284f0, 0x72d51bc8, 0xe4b8f4bc, 0xaa8003c2, 0x61400209,
+    0xec60019b, 0x5d300331, 0xc94802b4, 0x206c0105, 0xa7220071, 0x1a4b0354, 0x82e883d5, 0x3a68c149,
+    0x243c2120, 0x00d5b32b, 0x0ba3bbc2, 0x7a0e8752, 0x13171951, 0x0283f47f, 0xad408217, 0x1674c11b,
+    0x8e3620f1, 0x6bc2b094, 0xbd2139f5, 0x994145b9,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x08000000, 0x74000000, 0x66000000, 0x77000000,
+    0x4a800000, 0xe8400000, 0xbba00000, 0x15f00000, 0x89580000, 0xce340000, 0x23320000, 0x4ca70000,
+    0x1f6b8000, 0x81184000, 0x85892000, 0x3eca3000, 0xb1e91800, 0xb757dc00, 0xd5383a00, 0x83a9ed00,
+    0xc9e32180, 0x8b5932c0, 0x27309ba0, 0x62a89e10, 0xfc7a9bb8, 0x5d8b9d0c, 0x12db1932, 0xbbf0dcbd,
+    0x2a53ba64, 0xf2b1ace6, 0x446a00f3, 0xc19301a3, 0xf0d981bb, 0xa2ff4267, 0x63c2a28d, 0x366270cc,
+    0x60983a12, 0x2559efed, 0x843b23bc, 0x5e2d32da, 0x9b229a29, 0xd0bf9f4a, 0xfd691a91, 0x9817dc06,
+    0xcc183b83, 0xfa19edab, 0x951b201f, 0x539d3261,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xb8000000, 0x5c000000, 0x5e000000, 0xd7000000,
+    0x30800000, 0x50c00000, 0x64600000, 0x7ab00000, 0xd1d80000, 0xf7f40000, 0x40f60000, 0xe0750000,
+    0xe8b88000, 0x00de4000, 0x9c682000, 0xc6b67000, 0x5fd94800, 0x98ff3400, 0x2c696a00, 0xeebd4500,
+    0x5bc62180, 0x4af77240, 0x1d77c960, 0x55247630, 0x6581c998, 0xa951772c, 0x3339483a, 0xae8f345f,
+    0x27d16a33, 0x64f94799, 0x426820ce, 0x51b67259, 0x8f594a9e, 0x183f3471, 0xf0096a6a, 0xc80d4407,
+    0xd41e233f, 0x6a037173, 0x6d01c979, 0xe59177be, 0xe9594821, 0xd33f3742, 0x7e896a73, 0x9fcd4589,
+    0x38fe2286, 0x1c73711d, 0x86b9cb30, 0xbfd57668,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x38000000, 0x5c000000, 0x9a000000, 0x93000000,
+    0xaa800000, 0x0ec00000, 0x03a00000, 0xcf700000, 0x53d80000, 0x74340000, 0xf62e0000, 0x893d0000,
+    0x71a88000, 0x987ec000, 0xcf402000, 0x5bfdb000, 0xa8054800, 0x24060c00, 0x261d6a00, 0x190fbd00,
+    0x01962180, 0xf844b140, 0x977bcb60, 0x5fc1cd30, 0x3623c888, 0x2935ce3c, 0x81adc942, 0xb078cf37,
+    0xab5d4bc4, 0x9df20f36, 0xa1136a49, 0x1d82bc91, 0x8246a335, 0x147e71b3, 0xcd4de9b2, 0x64f57c3f,
+    0xb0800198, 0xddc003e4, 0x49200046, 0xd1b00301, 0x6878032d, 0xe7440317, 0x3ff60294, 0x6e09020e,
+    0x2d06823d, 0x1f83c2cf, 0xbd48a190, 0x0cf37338,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x98000000, 0x9c000000, 0x8e000000, 0xbd000000,
+    0xc6800000, 0x28400000, 0x98a00000, 0x56f00000, 0x07480000, 0xad3c0000, 0x25260000, 0xe1290000,
+    0xd32c8000, 0x703c4000, 0x93ab6000, 0xe171f000, 0xdf929800, 0xacdefc00, 0x3bf1fa00, 0xb9d30f00,
+    0xa9656280, 0x3b94f140, 0x0ed01ae0, 0x00f7bc30, 0xc6501a58, 0x55b7bf84, 0xb8701ba2, 0xbb07bf15,
+    0xbf981998, 0xdccbbeca, 0x13f618a7, 0x2ddebe2b, 0x237c994b, 0x948bfc55, 0xfb5b7878, 0x53364e7a,
+    0xb02a82bf, 0xb3a540cf, 0x716fe019, 0x4781b338, 0x30d7f974, 0xb5fa0dda, 0x04c9e281, 0x6fe8b172,
+    0x13db7993, 0x96764e91, 0x560a833a, 0xc115425f,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x48000000, 0x84000000, 0x16000000, 0x11000000,
+    0x3b800000, 0xbfc00000, 0x50600000, 0xb5b00000, 0xa6c80000, 0x8fec0000, 0xec6e0000, 0xffab0000,
+    0xcdc58000, 0x67744000, 0x2727e000, 0xbe9ed000, 0xba47c800, 0x8ab39400, 0xd0482a00, 0x71b14700,
+    0xd0c9e080, 0x6ef5d240, 0xcfe24ba0, 0x8c77d750, 0x0fa7cb78, 0xd5c39554, 0xab602836, 0xb52d44fb,
+    0xb98fe00f, 0x90c2d079, 0x0ee1c822, 0x3ff497fd, 0x9463aabc, 0xc3ae066a, 0x47cb81f1, 0xac6f404e,
+    0x9faa633f, 0x3dc69221, 0x7f6e2b66, 0xeb364593, 0x2c826103, 0xbd5a920b, 0xa0282b37, 0x5401458d,
+    0x9e01e1c4, 0x3519d33e, 0x7d8c49c7, 0xe6dcd4b5,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x78000000, 0xd4000000, 0xb2000000, 0x8f000000,
+    0xac800000, 0xf1c00000, 0x7fe00000, 0xe0300000, 0x6ed80000, 0x5b6c0000, 0x9de20000, 0xc7210000,
+    0xfe498000, 0x04b6c000, 0x730aa000, 0x628ff000, 0xf0cc6800, 0xf27ae400, 0x2c7eca00, 0xa5691500,
+    0xa4e8a280, 0xbeaef3c0, 0x3005e960, 0x380c2590, 0xf4146928, 0x0216e46c, 0xf71ccafa, 0x7888163b,
+    0x43c1202d, 0xf0e830d7, 0x4cb74bec, 0x9f1fd541, 0x24820056, 0x7dd101da, 0xa9f1808b, 0xa52ac3b5,
+    0x9950a123, 0xb432f3e2, 0x9cdfeaf4, 0xf471258e, 0x8167ebf5, 0x4eed25f8, 0x55bde91f, 0x569026ab,
+    0x92ce697e, 0x956be5b6, 0x9cef4a71, 0x4ab3d58e,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x98000000, 0x2c000000, 0x0a000000, 0x3d000000,
+    0x97800000, 0xbe400000, 0xf4200000, 0xe4700000, 0xe3480000, 0x33bc0000, 0x52220000, 0x136d0000,
+    0x69da8000, 0xaa6ec000, 0x8057e000, 0x2f2dd000, 0x64e6f800, 0x47942c00, 0x76591a00, 0xd035ff00,
+    0xea75e280, 0x6040d1c0, 0xbf3c7ae0, 0x4cfaee70, 0xf38ef848, 0x50582f44, 0xe73318f6, 0x40e4ffcd,
+    0x498d616e, 0xf5431018, 0x5cb11a95, 0xf4b9fddb, 0x80bfe0d3, 0x86a1d13e, 0x01acf9c9, 0x33352db8,
+    0x36e999c5, 0x768a3c03, 0x5bda802f, 0xcb6ec384, 0xa5d7e396, 0x306dd0fd, 0x9546f906, 0x0ca42f4c,
+    0xfcb1189b, 0x84b9fe7a, 0x38bfe13f, 0x1aa1d275,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xd8000000, 0x6c000000, 0x0e000000, 0x41000000,
+    0x65800000, 0x47400000, 0x8aa00000, 0x0cf00000, 0x23c80000, 0x08740000, 0xb11e0000, 0x6d810000,
+    0x935a8000, 0xf8ae4000, 0x9be2e000, 0x6b411000, 0x24b19800, 0x3def9c00, 0x8e4d7a00, 0xfb2f8f00,
+    0x59a66380, 0x2e6e5340, 0x9409fae0, 0xd200cdf0, 0xe71e0158, 0x808103b4, 0x98da802e, 0xeeee4059,
+    0xacc2e0dc, 0x4cf1109e, 0x83d99a9d, 0x786b9d16, 0x791b79dd, 0xd99a8f8a, 0xf142e123, 0xb7b11127,
+    0xbf799a4d, 0x499b9f9e, 0xe9537961, 0xbbae8f58, 0xa17ce2c8, 0xd08013a0, 0xe0cb186c, 0xf2f1de26,
+    0x6ac79859, 0xb9ea9ca0, 0x8441fb90, 0x7034cc68,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x68000000, 0xcc000000, 0x56000000, 0xe7000000,
+    0xde800000, 0xe3400000, 0x02e00000, 0xaeb00000, 0x9ed80000, 0x75240000, 0xf60e0000, 0x57170000,
+    0x46988000, 0xd7414000, 0xf0fb2000, 0xd3aeb000, 0xf1557800, 0xafe4d400, 0xc9205a00, 0x181d6500,
+    0x740da380, 0x9208f340, 0xed0ed820, 0x079f2570, 0x16d802f8, 0x692401ac, 0xa80e03a2, 0xec170251,
+    0xa61882d1, 0x1f014207, 0x7a9b2328, 0x795eb0f2, 0xb3ed7ad9, 0x9730d705, 0xa31658c9, 0x949e65bb,
+    0x5a432322, 0x677ab287, 0xad637bfe, 0x5467d5dd, 0x51eedb03, 0xc22f242e, 0x60800095, 0x88400237,
+    0xea6002f0, 0x3af000ae, 0x2ab80243, 0xf4d40218,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0xa8000000, 0x04000000, 0x3a000000, 0xe1000000,
+    0xf0800000, 0xc8c00000, 0x12a00000, 0x23700000, 0x5d580000, 0x50f40000, 0xf81e0000, 0x0c0f0000,
+    0x4e098000, 0xe315c000, 0xbd8e6000, 0x0642b000, 0x01604800, 0x20576c00, 0x46702a00, 0xd7dadd00,
+    0x9939e180, 0x8a287340, 0x4fbfa920, 0x05f41e50, 0xaa800048, 0xf9c00294, 0xaa2000aa, 0x7fb002e7,
+    0xddf80128, 0x968403be, 0x6fc601d7, 0x753b024a, 0x54378157, 0x04aac250, 0xbc7fe292, 0x96d37331,
+    0x19a82abb, 0x7aeeded6, 0xf107e313, 0x189771a8, 0x2cce2ba4, 0xb8a5de12, 0x6a68612b, 0xa9c9b07e,
+    0xa22fc837, 0x0bb9af7a, 0xdfe9c9ef, 0xdb82ae9c,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0xd8000000, 0x1c000000, 0xda000000, 0x9d000000,
+    0xf0800000, 0x10400000, 0xe4200000, 0xb5700000, 0x1bd80000, 0x93ec0000, 0xd4860000, 0x26410000,
+    0xcb3c8000, 0x8cfac000, 0xe50be000, 0x3c823000, 0x32515800, 0xf5242c00, 0x47fcba00, 0x8a971d00,
+    0x5d496280, 0xeca5f340, 0x29383be0, 0xfdf1de30, 0xa79c8208, 0xe5cac304, 0x38f3e02a, 0x0b1e3075,
+    0x0f8f5b32, 0xd1c92ee4, 0x96e63a97, 0x181cdfee, 0x7c06027f, 0x6a0101d7, 0xd51c800e, 0x348ac24f,
+    0xd653e0df, 0xa32e328a, 0xd8f758a5, 0xfb152d8a, 0x679838a8, 0x85c1dc59, 0x88e48281, 0x4316c2b0,
+    0xcb8de1c5, 0x17c33377, 0xd1eddb53, 0x759eeee4,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xd8000000, 0xac000000, 0xe2000000, 0xd5000000,
+    0x5a800000, 0xfe400000, 0x7be00000, 0x14b00000, 0x43c80000, 0x623c0000, 0x9f9a0000, 0xeccd0000,
+    0xa1aa8000, 0x8957c000, 0x1465e000, 0xe0707000, 0x6e6ed800, 0x797d1400, 0x86f13a00, 0xfa306700,
+    0xd39d6280, 0x5ed6b2c0, 0xdcbbbbe0, 0xa7d7a770, 0xa43082e8, 0xac9ac2e4, 0xf54f6262, 0x9e67b16d,
+    0x616b3be5, 0x0afd651b, 0x6837e168, 0xde81718e, 0xa85e5a9b, 0xc0e7d402, 0x093e5877, 0xea17d474,
+    0x11165ad0, 0xec9bd5b8, 0x15445b1c, 0xce6ad65e, 0xc974daa3, 0x7ef0175e, 0x263bbb09, 0xe997a447,
+    0x27d08116, 0x642ac13d, 0x8c87609d, 0x855bb3e7,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x38000000, 0x7c000000, 0x12000000, 0xbd000000,
+    0x9c800000, 0xcfc00000, 0x58e00000, 0x08300000, 0xf9580000, 0x162c0000, 0xfe4e0000, 0x53b50000,
+    0x131c8000, 0x539fc000, 0x1e56e000, 0xe3b1b000, 0xdb1f2800, 0xc782c400, 0x485fca00, 0x30aa7500,
+    0xe8926280, 0x29c27240, 0x43e7c860, 0xafb676b0, 0x410462a8, 0xce9b7204, 0x52d54aba, 0x146cb68b,
+    0xfff601f1, 0xdda903ef, 0x0c0a824e, 0xba06c1cc, 0xd904628e, 0x229b73d9, 0x58d54a9e, 0x056cb6d4,
+    0x497603a2, 0xd36900a7, 0xda6a810f, 0xc0f6c2be, 0xe43c6104, 0xf347733a, 0x0723494b, 0x48c5b451,
+    0x5d7c81ff, 0xc56fc276, 0x296ee0b0, 0x236db1a8,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xd8000000, 0x04000000, 0xc2000000, 0xf3000000,
+    0x0b800000, 0xacc00000, 0x1ee00000, 0x0b300000, 0x01580000, 0x133c0000, 0xe54a0000, 0x013d0000,
+    0xbe5f8000, 0xd6bec000, 0xd4992000, 0xf9599000, 0x2723a800, 0x5f56a400, 0x86288a00, 0xafce3500,
+    0x8d7ea080, 0x03eb50c0, 0xada889e0, 0x330e35d0, 0xeb9ea268, 0x7cdb52dc, 0xb6f089a6, 0xd73234bf,
+    0xc754a135, 0x2226538f, 0x1dcf09f6, 0xa67cf66c, 0x0c7582de, 0xc373c3eb, 0xdefea09f, 0xeb2b517e,
+    0xd1488ae0, 0xbb3e3650, 0x3946a128, 0xc727507c, 0x8f5a8ad6, 0x2e3f37a7, 0x73d321f1, 0x4b64906d,
+    0x32fc2b6b, 0x552864a4, 0x9451aa89, 0x59a7a7c2,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x48000000, 0xec000000, 0x1a000000, 0x29000000,
+    0x16800000, 0x36400000, 0x49e00000, 0x28b00000, 0x88d80000, 0x23a40000, 0x53420000, 0x957f0000,
+    0xefe88000, 0x83b8c000, 0x835b6000, 0x4d607000, 0xbbec5800, 0x3db85400, 0x5c4d3a00, 0x68f32500,
+    0xb233e380, 0x5498b2c0, 0x0b5738a0, 0x81682470, 0x31f96348, 0xacaf7214, 0xeedcd9b2, 0xa8a497bd,
+    0xc8d45a5d, 0x43ac56cb, 0xa35738ec, 0xdd682648, 0x03f96194, 0x99af7372, 0xaa5cdb1d, 0x5be4942d,
+    0x8db45883, 0x745c5578, 0x74ef3b3a, 0xe03c2589, 0x918361df, 0x07c472de, 0x9e2e5b35, 0x6e875769,
+    0xb245bbc9, 0x2ffbe5b9, 0xa3b083f1, 0x135cc265,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xd8000000, 0x94000000, 0x62000000, 0xc7000000,
+    0x9e800000, 0x2d400000, 0x91200000, 0xc5700000, 0x63d80000, 0x046c0000, 0xf0560000, 0x0cb10000,
+    0xecbd8000, 0x9ca4c000, 0x74b3a000, 0x28a7b000, 0x06bf3800, 0x37b8ec00, 0x0c229a00, 0x78f25d00,
+    0x5a0e2280, 0x23037140, 0x148c9be0, 0xb65f5e10, 0x21bda0e8, 0xd93ab04c, 0xc974bb72, 0xb5dd2fe9,
+    0xb174b95e, 0x71dd2cf0, 0x2b74b8c3, 0x32dd2d58, 0x0ff4b9d4, 0x4c9d2f86, 0x6254ba5f, 0x63ad2d59,
+    0x0e2cb9fd, 0x8ff12e78, 0x0c82ba5f, 0x425c2fe2, 0x73b13a41, 0xd625ecf2, 0x1be91a92, 0x6e979e42,
+    0x8558008a, 0xed2c0376, 0xfb76029c, 0x8ac10001,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x08000000, 0x7c000000, 0x82000000, 0xbf000000,
+    0x06800000, 0x5ec00000, 0x3e200000, 0x76700000, 0xfe880000, 0xbadc0000, 0xe82e0000, 0x677b0000,
+    0xcd068000, 0x2183c000, 0x0c49a000, 0xcefc3000, 0x7d558800, 0x4d6bdc00, 0x9e1aaa00, 0x8d142f00,
+    0xc1868280, 0x7c43c240, 0x96e9a0a0, 0x094c30b0, 0xb37d8a28, 0xa307df0c, 0x349ca812, 0x99c32c87,
+    0x1ca60262, 0xdea702e2, 0x81a883a2, 0xf738c382, 0xf1ef2272, 0xbbcff07a, 0x33b42a86, 0xf03bee9c,
+    0xf36921e7, 0x4318f01f, 0x4494aa46, 0xc1df2c7c, 0x68a801f7, 0x20ac0287, 0xbca60362, 0x4ea70162,
+    0xa9a88262, 0xdb38c2e2, 0x7bef23a2, 0x78cff382,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xd8000000, 0xf4000000, 0x72000000, 0xd5000000,
+    0x18800000, 0xa9400000, 0x6d200000, 0x54f00000, 0xfc980000, 0x634c0000, 0xbc3e0000, 0x36710000,
+    0xacdd8000, 0x90fcc000, 0xe69ae000, 0x6a50b000, 0x2ab7b800, 0x14224c00, 0x8a70da00, 0xd2ce3d00,
+    0x13fd8280, 0x810cc240, 0xfa82e3e0, 0x845cb090, 0x51a9baa8, 0x57a34c84, 0xb0b55baa, 0x5d3efcf1,
+    0x3cf96356, 0x209d71b6, 0xed50d9a6, 0x373e3c4e, 0x7de581aa, 0xba00c0d0, 0x991ce169, 0x6e9db02b,
+    0x064c3b9f, 0x8ca38fcc, 0x6329babe, 0x9fe34f73, 0x97155963, 0xa58efeaa, 0x6dc16271, 0x4a617316,
+    0xb2d6db56, 0xe3f33db6, 0x891e03a6, 0xd681014e,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xe8000000, 0x2c000000, 0x1e000000, 0x5f000000,
+    0xd7800000, 0xafc00000, 0x90e00000, 0xacb00000, 0xe1980000, 0xfcc40000, 0x69660000, 0xe4670000,
+    0x62e08000, 0xcdb24000, 0x59162000, 0x3c821000, 0x92518800, 0xea36a400, 0x6ec72a00, 0x5876f500,
+    0xb4f88380, 0x8eb64040, 0x28902220, 0x085511f0, 0xe7290bd8, 0xa840e5cc, 0x97370926, 0x7053e6c3,
+    0x03298a9d, 0xaa42a44d, 0x6e3929e5, 0x3cd5f5b1, 0x497e027b, 0xd46301ee, 0xdae68128, 0x09a54294,
+    0x6b0ea22a, 0x7d845205, 0x1ad9a9ce, 0x9267b5d8, 0x51e822cc, 0x642113a6, 0xf9d70b03, 0x93e3e7fd,
+    0xbd31891d, 0x8546a40d, 0x61bf2bc5, 0x0702f441,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xf8000000, 0xcc000000, 0xf6000000, 0xc7000000,
+    0xea800000, 0x8e400000, 0x2e600000, 0x9eb00000, 0x34880000, 0xbd4c0000, 0x5efe0000, 0x71e50000,
+    0xcf6e8000, 0x8b3b4000, 0x3c502000, 0xbb7cb000, 0xd1241800, 0xfd4e4400, 0x3efaba00, 0x81f9b700,
+    0x67668080, 0xbf374240, 0x064e2160, 0x8a69b270, 0xfca29838, 0x9989042c, 0x9edc9a72, 0x312c0625,
+    0xcd521b93, 0x36e74603, 0xe5ea3b0b, 0xa567f5ff, 0x42382111, 0xddc0b15e, 0x29b21b54, 0xb61744be,
+    0xa7023ae7, 0x1a9bf7ae, 0x264e232c, 0x1a69b2f2, 0xa4a29865, 0x058904f3, 0x90dc9973, 0x3a2c04b3,
+    0xd1d21b13, 0x7fa74443, 0x210a3a6b, 0xb597f78f,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x28000000, 0xa4000000, 0xf2000000, 0xe5000000,
+    0x16800000, 0xe1400000, 0x09a00000, 0xb4700000, 0x45880000, 0x30cc0000, 0x23620000, 0x76030000,
+    0x67098000, 0x7b9ec000, 0xe3d4e000, 0x32fb7000, 0xfcda7800, 0x157b5c00, 0x710f1a00, 0x0c92ef00,
+    0x00438080, 0x5d21c1c0, 0x98376220, 0xfeaab050, 0x97e51b68, 0x8a5ded04, 0xe4280192, 0xe0bc0311,
+    0x34ea02fc, 0x13cf0394, 0x7aeb8190, 0x48ddc082, 0x7f7d6253, 0x1815b28f, 0x4c06994b, 0xf60c2fb3,
+    0xa7176035, 0xdb9ab0f4, 0x53cd1bea, 0x1ae1ee9d, 0x58c2001a, 0xe773002f, 0x94018111, 0x1a12c1fc,
+    0xe116e114, 0x54887350, 0x2c5bf822, 0xbb299cc3,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x78000000, 0x74000000, 0x0e000000, 0xc9000000,
+    0x64800000, 0x9fc00000, 0xf8600000, 0xa9300000, 0x91880000, 0xa95c0000, 0x8cb60000, 0x16cd0000,
+    0x7cf98000, 0x86fb4000, 0x81e2e000, 0x0c76b000, 0x672fe800, 0x789c3400, 0x5ddc8a00, 0x6b7dc700,
+    0x9aaf8280, 0xdbc64140, 0xde736160, 0x4c21f390, 0x1f1309d8, 0x898b86f4, 0xad54e3aa, 0x4abbb25b,
+    0x43d66af3, 0x1a67769b, 0xaa3e6a47, 0xda0b76d1, 0x97006acc, 0xf59a749a, 0x1f4fe933, 0x11ac34af,
+    0xec548b4d, 0x5221c73a, 0x6e198317, 0xb90b42bd, 0xac8ae1f2, 0x03dab07b, 0xfa71eb03, 0x1a3d3453,
+    0x321b0b2b, 0x9b17846f, 0x8f82e3ed, 0xd846b38a,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x18000000, 0xdc000000, 0x6a000000, 0xa9000000,
+    0x98800000, 0xa8c00000, 0x74e00000, 0x22b00000, 0x95880000, 0x165c0000, 0x572a0000, 0xd3dd0000,
+    0x29608000, 0x40e2c000, 0x4cbe6000, 0xaa893000, 0x3dcf8800, 0xd67fc400, 0x4b796a00, 0xbdf83700,
+    0x2a2a8280, 0x754fc2c0, 0xd6b6e0e0, 0xdb87f2b0, 0x5953e9c8, 0x64b7f5d4, 0x8e9c61ca, 0xdbc83307,
+    0x7d650b31, 0xbef007e9, 0x3baf89e5, 0x350fc4a3, 0x12916822, 0x51d43772, 0x8468817b, 0x4e7ec15f,
+    0x5774610c, 0xf7e43337, 0xf3270839, 0xf5c106dd, 0xa26d089f, 0xc56c056c, 0xd2e58a47, 0x69a2c691,
+    0x3019eaf9, 0xf81af63d, 0x8c14e22f, 0x0206f0a4,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xc8000000, 0x14000000, 0x66000000, 0x27000000,
+    0x8b800000, 0x65400000, 0xa9600000, 0xddb00000, 0x74880000, 0xa2cc0000, 0x262a0000, 0x19d70000,
+    0xbfa38000, 0x6d8a4000, 0x824e2000, 0x82f89000, 0x48f15800, 0x15e44400, 0x6b74fa00, 0x34aa9700,
+    0x9c098080, 0x121d40c0, 0x110da1a0, 0x9482d390, 0x32d77ab8, 0xbe20d4cc, 0x35c7a1b6, 0x05a5d209,
+    0x389cfa0b, 0x48d69523, 0x4b2b8257, 0x0f4642ad, 0x04642252, 0x6b2f91b0, 0x3f52db68, 0x6c6e0414,
+    0x8f3ad9ca, 0x91520757, 0x5f78d856, 0x62b90582, 0xd3195913, 0x7d984704, 0xda56fac9, 0x0ef197ab,
+    0x02e000b3, 0x88f001ef, 0xb5e803e1, 0x9b7c00a4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x88000000, 0x04000000, 0xe2000000, 0x57000000,
+    0x13800000, 0xf2c00000, 0x00a00000, 0xf6f00000, 0x77080000, 0x838c0000, 0xdade0000, 0x54bb0000,
+    0x9ce18000, 0x2414c000, 0x721aa000, 0x7f169000, 0x47872800, 0x98cfcc00, 0x53a20a00, 0x07769f00,
+    0xd2de0080, 0x90bb0240, 0xdee180a0, 0x2314c150, 0xe99aa1a8, 0x89d6914c, 0xa5272bc6, 0x393fce97,
+    0x372a0a4c, 0x763a9f10, 0x08a0015e, 0x32f00395, 0x35080217, 0x848c020c, 0x415e00b0, 0xa27b008e,
+    0x7e41807d, 0x85e4c3fb, 0x1692a09a, 0x0e5a938f, 0x9df9298e, 0x3a84cfab, 0xb84b8ab2, 0xa0ee5e03,
+    0x7a1aa1a8, 0xbb16914c, 0x05872bc6, 0x9fcfce97,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x88000000, 0x84000000, 0xe6000000, 0x0b000000,
+    0x7a800000, 0xe1400000, 0x3a600000, 0xa2b00000, 0xc7180000, 0xf89c0000, 0x1c420000, 0x73e70000,
+    0x35718000, 0x7e344000, 0x8d51a000, 0x08643000, 0xd7b07800, 0x0a94e400, 0x89525a00, 0xae639500,
+    0x7ca20080, 0xc01703c0, 0xe00980a0, 0x101840d0, 0x380ba1b8, 0x0c1f323c, 0x6203fa72, 0xed07a7a9,
+    0x71927b03, 0x9bc3e40f, 0xdb3bdb75, 0x98cbd6a8, 0x65b1a1cd, 0x3f9433fd, 0xe4c87b9c, 0x6fb8e4cb,
+    0x468858b2, 0x4b589560, 0xf3718370, 0x853442e8, 0xdfd1a3c4, 0xdd2430ae, 0x83d079eb, 0x2724e722,
+    0xd2ca59b8, 0xbcbf963c, 0x20000072, 0xf00002a9,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x78000000, 0xec000000, 0x1a000000, 0xf7000000,
+    0xb1800000, 0xe2400000, 0x84a00000, 0x38700000, 0x13080000, 0xff9c0000, 0xc35a0000, 0x28310000,
+    0xe4a28000, 0x2876c000, 0xbb11a000, 0x9b8ad000, 0x4d5f7800, 0x29205c00, 0xb8365a00, 0x8cad4f00,
+    0x6c7a0280, 0xc50100c0, 0xe28a8160, 0xfddac070, 0x97e3a188, 0x9857d06c, 0x63affaae, 0x21fb9c55,
+    0x955f7ad4, 0xf5205c68, 0xfa3658de, 0x67ad4fdf, 0xbffa0021, 0x3c41002e, 0xcdaa8197, 0xd0eac3ad,
+    0xb1cba230, 0xbdfbd12a, 0x375dfb65, 0xce269e7e, 0xa1aff94d, 0x0afb9d00, 0x66df7882, 0xfc605e59,
+    0xad165988, 0xa69d4c6c, 0x83d200ae, 0xeeed0355,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x78000000, 0x24000000, 0xbe000000, 0xbb000000,
+    0xb9800000, 0xe1c00000, 0xaae00000, 0xa1b00000, 0xaf080000, 0x6f940000, 0x66d60000, 0x59750000,
+    0x3d7b8000, 0x237b4000, 0x2864a000, 0x39fe5000, 0x843a0800, 0xb4dce400, 0x10732a00, 0xbdecf700,
+    0x8a360380, 0xa7c50040, 0xf5f38060, 0xc62f43d0, 0x25d2a318, 0x04fb527c, 0x02a9881e, 0xe283a56b,
+    0x48498a4d, 0x5333a601, 0xff418b97, 0xc8a7a778, 0x5f978947, 0x0ed2a40b, 0x656c09f6, 0x7769e7d4,
+    0x5e68aa2a, 0x0ee7b5d1, 0xdfbaa264, 0x741f5109, 0x06178a38, 0xff12a5a7, 0xd78c081b, 0x22d9e4ce,
+    0x3760ab18, 0xfe73b67c, 0xbeeca21e, 0x77aa526b,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x38000000, 0xd4000000, 0x3e000000, 0x2b000000,
+    0x6a800000, 0x34c00000, 0x3fa00000, 0xbef00000, 0x31180000, 0x07940000, 0x41520000, 0x2fe90000,
+    0xa68d8000, 0x0ed54000, 0x82bea000, 0x63763000, 0xbd54c800, 0xcdefec00, 0xbf95ea00, 0xd5559d00,
+    0xf1ea0180, 0xfd8d00c0, 0x5c478360, 0x62684290, 0x62c12018, 0x28ba73fc, 0x967fe952, 0x8cd89c7f,
+    0xabad8354, 0x60e54328, 0x6a06a3ba, 0x55123325, 0xa19ec921, 0x2e52eff1, 0x336a6967, 0x5559dd28,
+    0xb1e120ba, 0x1d8a71a5, 0x2c47eb61, 0x5a7c9ed1, 0xb6c782d7, 0x16a84280, 0xbd6122ee, 0xe64a71a3,
+    0x9f67e998, 0x5f4c9f3c, 0xd4ff8032, 0x640c43ef,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0xc8000000, 0x8c000000, 0x92000000, 0x7b000000,
+    0xb7800000, 0xd4400000, 0x59200000, 0x5df00000, 0xcd180000, 0x829c0000, 0x52c20000, 0xb5ef0000,
+    0xb1188000, 0x788bc000, 0x15c92000, 0x586f9000, 0x92457800, 0x04282c00, 0xe776da00, 0x7bd37d00,
+    0x997a0080, 0xc2c303c0, 0x8de281a0, 0x5508c0f0, 0xd68ba168, 0x34d753dc, 0x18f6d896, 0x63937ebd,
+    0xf25a01ba, 0x54330326, 0x3f7a80d0, 0x8fd4c1bd, 0x4f69a15f, 0xa7c852ed, 0xd37659c7, 0x4dc4bed9,
+    0xec71224d, 0xe44392d2, 0xd13f79fa, 0x71eb2ec6, 0xef145bc0, 0x319bbcc5, 0x6951a27b, 0xf3a4521f,
+    0x932c59e8, 0x92f7bc1c, 0x0c8ba336, 0x83d7534d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0xf8000000, 0x9c000000, 0xe2000000, 0xd3000000,
+    0x41800000, 0xae400000, 0xfda00000, 0xc5700000, 0x79080000, 0xc6840000, 0x99de0000, 0x16fd0000,
+    0x2c5c8000, 0xfeb7c000, 0x9cea2000, 0x5b585000, 0x2126f800, 0x13b7fc00, 0xcc6e5a00, 0x97956f00,
+    0xdb560180, 0xe1390340, 0xf3a28060, 0xdc7ac230, 0x6f9ea068, 0x475b913c, 0x033ada8e, 0x20a6adf5,
+    0x9de220f0, 0xc1dc52ac, 0xbaf8f992, 0xc64afc4f, 0x59b2da63, 0x5b62ae31, 0x581c20de, 0xac1153ad,
+    0xea0c7ba4, 0xa7093e1e, 0xc78efb69, 0x0343fc0a, 0x8d38595f, 0xf9ac6fbf, 0x6b7482ab, 0x5003c11d,
+    0xd81c21e8, 0x6c11527c, 0x0a0c7aee, 0xb7093fc5,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x78000000, 0x8c000000, 0xda000000, 0xbf000000,
+    0x6b800000, 0xc3400000, 0x31600000, 0x4cb00000, 0x02080000, 0xc3040000, 0xf9860000, 0x58550000,
+    0x74fe8000, 0x66fac000, 0x3de4a000, 0x986e9000, 0x623b1800, 0x25df4400, 0xf2af3a00, 0xb31a1700,
+    0x71880180, 0x9c440040, 0x8ae60260, 0xf7e50170, 0xbf768288, 0x55bec0cc, 0x4482a2c6, 0xf0cb91f1,
+    0x4e2d996b, 0x0fd1863f, 0x05a59acd, 0xfc95853e, 0x9cc39a6e, 0x4430862d, 0xc8d51a55, 0xe23e468a,
+    0xe5dfba2c, 0x12b1d776, 0x631423d9, 0x09855177, 0x10473a21, 0x50ee17e8, 0x48e603e7, 0xd4e50242,
+    0x96f68180, 0x75fec040, 0xbc62a260, 0x4c3b9170,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xc8000000, 0x74000000, 0x5e000000, 0xf9000000,
+    0x9d800000, 0x40c00000, 0xd1a00000, 0xa3f00000, 0x82180000, 0x43140000, 0x12860000, 0x80430000,
+    0x9af78000, 0xbf954000, 0x33dce000, 0xdb309000, 0x9fb1a800, 0x32f32c00, 0x5b84ca00, 0x45c1fd00,
+    0x86380180, 0x94240240, 0xff3e00a0, 0x49a70290, 0x5fe98258, 0x48024234, 0x340d630e, 0xbe16d2e7,
+    0x2902c930, 0x5582ff8c, 0x34cf834e, 0x8fb14283, 0x5ae2e1c2, 0x1f97904d, 0x03d82901, 0xc3316d2f,
+    0x23a9a9dc, 0x18e72f76, 0xfc82c947, 0x2142fce4, 0x5b6f82f6, 0x054140c3, 0x8d7ae0a6, 0x6843933f,
+    0x9efe2980, 0x19826e40, 0xa6c62aa0, 0xa4a66f90,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x38000000, 0xa4000000, 0x52000000, 0x87000000,
+    0xb9800000, 0x3ec00000, 0x66600000, 0x30300000, 0x14080000, 0x5a1c0000, 0xeb1e0000, 0xa79d0000,
+    0x77d38000, 0xaefec000, 0x626aa000, 0x722fd000, 0x8b1ce800, 0x978d1400, 0xbfd3ca00, 0xe2ed0700,
+    0xac600280, 0x03300040, 0xe7880060, 0x97dc01d0, 0x5efe01a8, 0x8a6d008c, 0xee3b83ae, 0x7d12c2e7,
+    0x429ca37f, 0x815ed03b, 0x65b969c9, 0xf4c2d5a8, 0x557ce9fc, 0xc3bd14f6, 0xd9dbc8f3, 0xeff10465,
+    0xc6fe0236, 0x3e6d01a3, 0xa43b828d, 0x8e12c05a, 0x911ca39d, 0x9c9ed1a2, 0xe8596a79, 0x7d32d5a0,
+    0x9e94e800, 0x97511400, 0x40adca00, 0x22400700,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0xe8000000, 0x64000000, 0x8e000000, 0x5f000000,
+    0x47800000, 0x85c00000, 0xa9600000, 0x0e300000, 0x1c180000, 0x620c0000, 0xc50e0000, 0x2e970000,
+    0x11518000, 0x813d4000, 0xf38a2000, 0xa3cb1000, 0xb27c4800, 0x97bf9400, 0x2ed1ea00, 0xe8e2c500,
+    0xc0e00280, 0xc4f00040, 0x3af80320, 0xddfc00d0, 0x16760198, 0x79ab00ec, 0x01c78326, 0x1766436b,
+    0xc935a3bb, 0x8791522f, 0xe5dfead5, 0xd975c5b0, 0xb6318164, 0x900d4032, 0x881223d1, 0x140713fe,
+    0x3612482b, 0xd318951b, 0xad98693f, 0x54d384ad, 0xb1e421ec, 0xcc6c13aa, 0x30b5ca3d, 0xc54ed458,
+    0xd735c800, 0x508ed400, 0xb655ca00, 0x6abed500,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x38000000, 0xc4000000, 0xfa000000, 0x41000000,
+    0x53800000, 0x6fc00000, 0xde200000, 0x99500000, 0x82080000, 0xa50c0000, 0x598e0000, 0x06c10000,
+    0x71a38000, 0xc89c4000, 0xe727a000, 0x2ed81000, 0xe7c33800, 0x122bfc00, 0x6f506200, 0xd50cd500,
+    0xb1880080, 0x1acc00c0, 0x5fae00e0, 0x4b910010, 0x31ab8038, 0xe89040c4, 0x1729a0fa, 0x06d91041,
+    0x1bc0b853, 0x2c27bc6f, 0xd45fc2de, 0xc788c599, 0x8dcd3802, 0xab2afc65, 0x18d3e2b9, 0x50c09516,
+    0x16a7a0c9, 0x141810cc, 0x486338fd, 0x71bbfc7f, 0xa2f8628c, 0x0390d5b9, 0x1dae00cb, 0x0e9100cd,
+    0x782b8080, 0xd65040c0, 0xa289a0e0, 0x34491010,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x08000000, 0x74000000, 0x7e000000, 0xbb000000,
+    0x56800000, 0x59c00000, 0xd6a00000, 0x7e900000, 0x5a080000, 0x8d040000, 0x798e0000, 0xa14d0000,
+    0x3c608000, 0x8ab74000, 0x035fe000, 0xbaa7b000, 0xdc9f6800, 0xdb0e9c00, 0xc684ea00, 0xb1ce2900,
+    0x72a80080, 0x08940040, 0x950600e0, 0xa58900d0, 0x9b4e8008, 0x216a4074, 0x1b37607e, 0x0614f0bb,
+    0xf0ce8856, 0x99242c59, 0xf0db02d6, 0x9ee7f57e, 0xed7b0ada, 0x8ef999cd, 0x42bf6819, 0xd75e9c31,
+    0xf4acead4, 0xbf9a292e, 0xf18e00f5, 0x954d0035, 0xa2608014, 0xe1b740e9, 0x5ddfe04e, 0x9767b0e2,
+    0x743f6880, 0x1e9e9c40, 0xca0ceae0, 0x650a29d0,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x78000000, 0xac000000, 0x92000000, 0x9d000000,
+    0x1d800000, 0xb3400000, 0x44e00000, 0x5d700000, 0x16080000, 0xbb0c0000, 0x0e8a0000, 0xc9c10000,
+    0xa3278000, 0x895ac000, 0xb3d12000, 0x341dd000, 0x503fd800, 0x9de43400, 0xc6f4ce00, 0xa64b9700,
+    0xfd680080, 0x183c00c0, 0x99e200a0, 0x20fd0010, 0x15458078, 0x97e7c0ac, 0x87f4a092, 0xe1ca109d,
+    0x1723781d, 0x075224b3, 0xd4d5b644, 0x6094b35d, 0xc0f03696, 0x6543737b, 0x5fec962e, 0x43f56319,
+    0xa7cdeefb, 0xb42a47f5, 0x15d5d879, 0xe71534d5, 0x8abb4e07, 0x6a2d570f, 0x6adb20f5, 0xaf9cd0b9,
+    0xe0785880, 0xdb8ef4c0, 0xd04deea0, 0xf66a4710,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x08000000, 0x24000000, 0x62000000, 0xe7000000,
+    0xd1800000, 0xcd400000, 0xbfa00000, 0x05d00000, 0x1e080000, 0x190c0000, 0xd88a0000, 0xcdc70000,
+    0x2e608000, 0x65b24000, 0xdabe2000, 0x973a7000, 0xb4731800, 0xdad5d400, 0x83827a00, 0x42439d00,
+    0x9a280080, 0xd29c00c0, 0x6aa200a0, 0x8b5b0070, 0x8ec28008, 0x5de94024, 0xeffca062, 0xc49330e7,
+    0x57afb8d1, 0x31d6e4cd, 0xa405c2bf, 0xa2097905, 0x470fc29e, 0xa18e79d9, 0xc54f42f8, 0x9bac397d,
+    0x67d96206, 0xf90a49f1, 0xc8887a90, 0x15c49de4, 0x7268802f, 0x2bbe4064, 0x7bb42027, 0xc3bd7039,
+    0x4fb39880, 0x79b794c0, 0xf4b45aa0, 0xe635ed70,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xa8000000, 0x7c000000, 0x36000000, 0xe5000000,
+    0x5a800000, 0xcbc00000, 0xd8600000, 0x6d500000, 0xfd080000, 0xce840000, 0x61ce0000, 0x3b630000,
+    0x7ada8000, 0x104fc000, 0xeb276000, 0x31f05000, 0xf1bea800, 0x829a3c00, 0x67684600, 0xdcdcb500,
+    0x8d4e0080, 0x55a30040, 0x183a80e0, 0x86dfc030, 0x664f60a8, 0xee24507c, 0x5b78a836, 0x927d3ce5,
+    0x26fcc65a, 0x3c3075cb, 0xc4d3e058, 0x194c902d, 0xffab481d, 0xfb31acfe, 0x91578ec9, 0x8b01d947,
+    0xcb846ecc, 0x0b4d49b5, 0x58af26d1, 0xdebce58a, 0x4b18a8e1, 0xf32d3ce3, 0xa5f4c6e4, 0x5bb475bb,
+    0x619de0a8, 0x70ef907c, 0x3191c836, 0xa8ee6ce5,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x58000000, 0xfc000000, 0xae000000, 0xc7000000,
+    0xe2800000, 0xa3400000, 0x32e00000, 0xecf00000, 0xb1080000, 0x198c0000, 0xcfc20000, 0x46a10000,
+    0x64958000, 0x02b7c000, 0x346ee000, 0xfdb27000, 0xdae52800, 0x98f2b400, 0x9b036e00, 0x9c86ef00,
+    0x1c4a0080, 0x3c6d00c0, 0xb9b78020, 0xb8e6c010, 0xb9f36058, 0x1a89b0fc, 0x8f49c8ae, 0xe4e1c4c7,
+    0xc7f3c6e2, 0xa5839ba3, 0x81c78eb2, 0xb1a99f2c, 0xce10a811, 0x05f574c9, 0x54858eb7, 0x78489faa,
+    0x4e652812, 0xc0b2b4f9, 0xc5636e58, 0x0436ef89, 0x252200d2, 0x965100eb, 0x5b9d8096, 0xcc3bc0be,
+    0x412ce0d8, 0xe453703c, 0x2290a88e, 0xb1b574d7,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0x58000000, 0x0c000000, 0x9a000000, 0x4b000000,
+    0x75800000, 0xeb400000, 0x91200000, 0xf2d00000, 0xed080000, 0x5c8c0000, 0xc9c60000, 0xbde70000,
+    0x97b58000, 0x6d78c000, 0x791da000, 0xd7e6b000, 0x44ba5800, 0x74f23400, 0x785f5200, 0x55c1cd00,
+    0xcfee0080, 0xe8bb00c0, 0xfefb8060, 0x1b53c070, 0x744e2058, 0xb2a9700c, 0xa89a789a, 0x32a0444b,
+    0x689eaa75, 0x52a249eb, 0x18968a11, 0x0aac3932, 0x1499720d, 0x90a4bdec, 0x5f927871, 0xe52c4401,
+    0xb4d8aa35, 0x7405495a, 0x46030a4e, 0x9904f9bb, 0x1a8cd2da, 0x50ce0d96, 0xa76e20c9, 0xc77970ac,
+    0xca1278d8, 0xbe6c44cc, 0x1df8aafa, 0xfad5493b,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x88000000, 0x7c000000, 0x0e000000, 0xef000000,
+    0x79800000, 0xdfc00000, 0xbda00000, 0x73b00000, 0x59880000, 0xafcc0000, 0x85a60000, 0x57bd0000,
+    0xa3808000, 0x32cbc000, 0x1d26e000, 0x1ef3f000, 0xb8671800, 0x23163c00, 0x8ab53a00, 0x9b00cb00,
+    0xcb808080, 0x5ecbc0c0, 0xfb26e060, 0x5df3f0d0, 0x47e71888, 0x6fd63c7c, 0x40953a0e, 0xd870cbef,
+    0x562880f9, 0x5d77c01f, 0x9aa8e0dd, 0xd632f0a3, 0x384998d1, 0xa56cfcd3, 0x7b955a8b, 0xa3f5fbb8,
+    0x50e9f85a, 0x5259cc2d, 0xb55c22c0, 0x70d7f7bd, 0xc11b3ae9, 0x43b1cb30, 0xc18600e1, 0x3bcd0033,
+    0x27a880f9, 0x3eb7c01f, 0x4908e0dd, 0x9a82f0a3,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xa8000000, 0xdc000000, 0x26000000, 0xf5000000,
+    0x73800000, 0xd9400000, 0xca600000, 0x70b00000, 0xab880000, 0x5d4c0000, 0xa8620000, 0xc7b10000,
+    0x7f028000, 0xf881c000, 0x1bc06000, 0x22ae5000, 0xc49db800, 0x6dd85400, 0xffbac600, 0xcb0e5b00,
+    0x22828080, 0xd8c1c0c0, 0x0c206020, 0xb25e5010, 0x58f5b8a8, 0xb06454dc, 0x63b0c626, 0x0d035bf5,
+    0xe78a00f3, 0x134d0019, 0xa16880ea, 0x823cc060, 0xfb40e003, 0x9d6e9081, 0x9437588e, 0xb64bc432,
+    0x9aed1e0c, 0xb7755f21, 0xaf2d7e51, 0x6cdb0f92, 0xb630c6cf, 0xe1435be0, 0x7e6a005f, 0xaabd0000,
+    0x688080f3, 0x73c0c019, 0xdeaae0ea, 0xf2939060,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x78000000, 0x4c000000, 0x16000000, 0x29000000,
+    0xe0800000, 0xcf400000, 0xd8200000, 0x2a500000, 0xec880000, 0x794c0000, 0x21260000, 0x02db0000,
+    0x17cf8000, 0xfb68c000, 0x34776000, 0x27d19000, 0x414d8800, 0xcd223400, 0xc4d1e200, 0xf6c4b500,
+    0x2fef8080, 0xa138c0c0, 0xc0ff6060, 0xa29d90b0, 0x0e6b8878, 0xaaf9344c, 0x259e6216, 0xebec7529,
+    0x2338e060, 0x63f9500f, 0xb51ae8b8, 0x3ca3a49a, 0x07146a94, 0x27aa8135, 0x3c9862b7, 0xb36775eb,
+    0xc07f6017, 0x1ddd9044, 0xce4b88f4, 0x7ca934f1, 0xa71662c3, 0xf7a075d1, 0xf49ee093, 0x876250d2,
+    0x9a756860, 0x22db640f, 0x07cb0ab8, 0x5367119a,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0xa8000000, 0x1c000000, 0x5e000000, 0x8f000000,
+    0x76800000, 0x46c00000, 0x60600000, 0x7a900000, 0x92880000, 0x0cc40000, 0xdd6e0000, 0xe5190000,
+    0x99c28000, 0x1ee5c000, 0x505be000, 0x94e9f000, 0x4d59e800, 0x7b63ac00, 0x7e1ee600, 0xdd43d900,
+    0xb1228080, 0x7db5c040, 0x9c33e0e0, 0x287df090, 0x94dfe8a8, 0x7b2eac1c, 0x80ba665e, 0xe3bb198f,
+    0xb335e0f6, 0x2ef0f006, 0xea1b6880, 0xaf466cea, 0xb825063a, 0xa03a2910, 0x46736803, 0xc3d26c2a,
+    0x29a3060f, 0x2c7729c8, 0x4ed7e818, 0x6e2aacb2, 0x03346661, 0xb6f21968, 0x2e1f60fd, 0xd5413022,
+    0xdd2688f6, 0x1bb29c06, 0xa7306e80, 0x1cf545ea,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xd8000000, 0xd4000000, 0x1e000000, 0x31000000,
+    0x05800000, 0x1bc00000, 0x99e00000, 0x6ad00000, 0xeb880000, 0x82cc0000, 0x20660000, 0xb31d0000,
+    0x51658000, 0x16914000, 0xfaafe000, 0x87721000, 0x9c73d800, 0xa6f5fc00, 0x31b7fe00, 0xb217fd00,
+    0x9ced8080, 0x615d40c0, 0x7949e060, 0x1aaf10d0, 0x977658d8, 0x2474bcd4, 0xa2f01e1e, 0xf7b9ed31,
+    0x57105885, 0x8769bcdb, 0x4b959ef9, 0xe528adba, 0x6bbfb8b3, 0xe51bac96, 0xcc66465e, 0x691d5152,
+    0xc668460c, 0x261c5119, 0x22e3c69d, 0xe05c11cc, 0x74cfa64a, 0x0d6241fb, 0xc4961eae, 0x61a4ed9e,
+    0x7df5d805, 0x6b38fc1b, 0xf55a7e99, 0xc74abd6a,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x18000000, 0xcc000000, 0xae000000, 0x21000000,
+    0xbd800000, 0x78400000, 0x47200000, 0xa1f00000, 0x2b880000, 0x654c0000, 0x7ca60000, 0x7cb50000,
+    0xab298000, 0xfff0c000, 0x2284e000, 0x5ccb1000, 0x7eec5800, 0x789e4400, 0x38571200, 0x30b0cf00,
+    0xc5218080, 0xbefcc0c0, 0xcf02e060, 0x3c8e1050, 0xf5cdd818, 0x776284cc, 0x32d5f2ae, 0xe87edf21,
+    0xc1cc583d, 0x856e44b8, 0xc5df1227, 0xe8fccff1, 0xb20780b3, 0x5709c069, 0x308b60b2, 0x3bced00d,
+    0x0661380e, 0x9755944b, 0x5c37aacb, 0x28e99bdc, 0x059cca68, 0x53d74ba5, 0xf5f5f2e3, 0x898edf7d,
+    0x8a4458bd, 0xb0224478, 0xa1791247, 0x5849cfa1,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x58000000, 0x34000000, 0x5e000000, 0x71000000,
+    0x6f800000, 0xd9c00000, 0xffa00000, 0xd4700000, 0x85880000, 0x36cc0000, 0x412a0000, 0xd23b0000,
+    0xfbed8000, 0x291f4000, 0x4ed7e000, 0x10781000, 0xe38d7800, 0x63c3dc00, 0x98ae9a00, 0x16f79700,
+    0x01c58080, 0x0ba340c0, 0x2a75e0a0, 0x448f10b0, 0x014af858, 0xace79c34, 0x7394fa5e, 0x5e90c771,
+    0xc31f18ef, 0xa1d88c19, 0xaef6025f, 0xe5cb5b64, 0x1da9e25d, 0x877f4bc2, 0x190e9a3f, 0xc38797d3,
+    0x13cd80ec, 0x60af40b4, 0x92ffe017, 0x07c41031, 0x4eaf780f, 0x1bf4dcc9, 0xec491a17, 0x9163d7b8,
+    0xa157e06f, 0x09b810d9, 0xbc2d78ff, 0x07b3dcd4,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x08000000, 0xe4000000, 0x76000000, 0xe9000000,
+    0x3b800000, 0x59400000, 0x5de00000, 0xbff00000, 0x99880000, 0x6e440000, 0x7b6e0000, 0x533f0000,
+    0x98288000, 0x25da4000, 0x9b516000, 0x2b9d9000, 0x50313800, 0x90a6d400, 0xdc173e00, 0xcb72af00,
+    0x4a408080, 0xad6e4040, 0xaa3760e0, 0x5ba690f0, 0x9097b808, 0x54b394e4, 0x0b66de76, 0x1b317fe9,
+    0x9c2ed8bb, 0xa3da0419, 0x7a51e6bd, 0xf41cab4f, 0x7f776611, 0x2449ebca, 0x5866866d, 0x0bba3b0a,
+    0x79e0decb, 0x69fa7f28, 0x608058d8, 0xadcb4429, 0xce2e066c, 0x9cd07b5a, 0xb8d9be3f, 0x6ed3ef33,
+    0x97d7603b, 0x54569059, 0xe11fb85d, 0x2ef794bf,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0x58000000, 0xf4000000, 0x06000000, 0x1f000000,
+    0xdb800000, 0x7d400000, 0x23a00000, 0xda300000, 0x24880000, 0x16cc0000, 0x66e60000, 0x7d970000,
+    0xa0bd8000, 0xd94b4000, 0xadaa6000, 0x7930f000, 0x25073800, 0xde811400, 0x73cb1a00, 0x186b3300,
+    0x1e5b8080, 0x90dc40c0, 0x6b17e060, 0xcf7bb070, 0x0b2d5858, 0x2ef1e4f4, 0x736c2206, 0x03da271f,
+    0x92989a5b, 0xe33b73bd, 0x300a60c3, 0xf800f06a, 0xe40f381c, 0x2e0d1492, 0xb30d1a38, 0x298c3396,
+    0x644e00fd, 0xe72b007b, 0x7cf38035, 0x7a6040ae, 0xef59e07a, 0x9850b0e6, 0x4fded837, 0xd091a46c,
+    0xc235c203, 0x708a9749, 0x00c642c5, 0x51ead775,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xf8000000, 0x1c000000, 0xfa000000, 0x9d000000,
+    0x51800000, 0x61400000, 0xcde00000, 0x44b00000, 0x68880000, 0x36cc0000, 0x5ba20000, 0x0ddb0000,
+    0xf8f78000, 0x14e5c000, 0x8331a000, 0xb6c8d000, 0x9bad9800, 0x2dda5400, 0x48f1be00, 0xece9bb00,
+    0x9f358080, 0x4ccec0c0, 0x06ae2020, 0x7c5110b0, 0x29b638f8, 0x2105841c, 0xdb89a6fa, 0x244d2f9d,
+    0x30621ed1, 0x27fa6ba1, 0x246f986d, 0xd9f15434, 0xcf6e3eb0, 0xa7707b9a, 0x86ae20d9, 0xbc51104c,
+    0x09b63873, 0x91058458, 0x2389a6e7, 0x384d2f8f, 0xca621e44, 0xbafa6b02, 0x75ef988a, 0xb8b154c6,
+    0x028e3e29, 0xe3c07bbd, 0xee262097, 0x8a9d10a9,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0xb8000000, 0xfc000000, 0xda000000, 0xd7000000,
+    0xf0800000, 0xa8400000, 0xdb600000, 0x6af00000, 0xb5880000, 0x0bcc0000, 0x05aa0000, 0x64d70000,
+    0x9b938000, 0x707bc000, 0x12c3a000, 0x0c2c7000, 0xd5198800, 0x9931c400, 0x2c205600, 0x651fbf00,
+    0x81318080, 0xa020c0c0, 0x071a20a0, 0xaa30b070, 0x8aa1a8b8, 0x785a74fc, 0x81d87eda, 0x48190bd7,
+    0x16b1de70, 0xe0e27b68, 0xf83bd6fb, 0x79a87fda, 0x7ed8202d, 0xec9bb047, 0xf0f028c7, 0x028ab43f,
+    0x2b4a5e09, 0x65e5bbf3, 0xfbba7663, 0xd76f0fd9, 0xc8f0286b, 0x3e8ab4e0, 0x514a5e5f, 0xc2e5bbec,
+    0xb33a7648, 0x832f0f54, 0xc9902881, 0x837ab47d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x68000000, 0x2c000000, 0xea000000, 0xd3000000,
+    0x83800000, 0xbfc00000, 0x2ca00000, 0x0c700000, 0x02880000, 0xfb4c0000, 0x2eee0000, 0x92170000,
+    0xdcde8000, 0xdcf6c000, 0xfec4a000, 0x88283000, 0x7e37b800, 0xf4e0bc00, 0x09142a00, 0xe3579700,
+    0x91388080, 0x456dc0c0, 0xc1d420e0, 0xfa79f070, 0x73859868, 0x17c24c2c, 0xe0a132ea, 0x96741bd3,
+    0xb9839203, 0x54c72b7f, 0x7b24aa4c, 0x6db657bc, 0x5322a00a, 0x61b33067, 0x2927384c, 0xaab17c1d,
+    0xeea60add, 0xd375679c, 0xfb0d983b, 0x8f8e4c28, 0xc5cf3233, 0xe7a31bcc, 0xcbfd12e1, 0x7b41eb6a,
+    0xeee80aeb, 0x72126793, 0xacdb1846, 0xb4f48c1f,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x68000000, 0x04000000, 0xfe000000, 0x91000000,
+    0xd6800000, 0xf8c00000, 0x0b200000, 0xe9300000, 0x48c80000, 0xf32c0000, 0xe5360000, 0xe2cd0000,
+    0xf42a8000, 0xa6b64000, 0x3282e000, 0x96c39000, 0x922b2800, 0x6bb56c00, 0x26072200, 0x6d0fbb00,
+    0x2482e080, 0xc3c390c0, 0xdaab2860, 0x52756c50, 0x93a72268, 0x78ffbb04, 0x996ae07e, 0x48df9051,
+    0xa1d528b6, 0xbb546ca8, 0x899ba263, 0xd5b4fbed, 0x170a8036, 0x8b8640a2, 0xe44ae053, 0xa4ef904a,
+    0xc99d2897, 0x75b86c4b, 0x270da284, 0xb389fbf4, 0x88480021, 0x5eec00b1, 0xa69600b9, 0x323d0072,
+    0x09428036, 0x406a40a2, 0x6a5ce053, 0xff12904a,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x08000000, 0x74000000, 0xfe000000, 0x7b000000,
+    0xb4800000, 0x0c400000, 0x9d200000, 0x8b100000, 0x50480000, 0xe7240000, 0xb61e0000, 0xb3cd0000,
+    0xdde48000, 0xd6794000, 0x9472e000, 0xc57b3000, 0x44fe0800, 0xdf382400, 0x86d9e200, 0x16a33900,
+    0xd5d2e080, 0x262b3040, 0x9f9608e0, 0xec0c2450, 0x220fe208, 0x410a3974, 0x6988607e, 0xbfcf703b,
+    0xafe86854, 0x9f7a545c, 0x89f50a95, 0x84b02dff, 0x901d0a2e, 0xf4c42ddc, 0x036b0a62, 0x0f3d2daf,
+    0xced98a28, 0x82ad6d39, 0x7bd1ea52, 0x55221d3d, 0x5f1982d0, 0x1e48497f, 0xc42488e4, 0x7e9c6458,
+    0x3587822e, 0xc5c549dc, 0x92e00862, 0x7cf524af,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x88000000, 0xcc000000, 0x22000000, 0x4b000000,
+    0x3e800000, 0xb9400000, 0xd8e00000, 0x00d00000, 0x4d480000, 0x2ee40000, 0xcdde0000, 0x06c50000,
+    0x482c8000, 0x15f2c000, 0x7b372000, 0x2f103000, 0x90ed2800, 0x6cdeb400, 0x5f4abe00, 0x3deea500,
+    0xb7572080, 0x51803040, 0xf9c528e0, 0x60aab4d0, 0xb1bcbe88, 0x605fa5cc, 0x850da0a2, 0x2783f00b,
+    0x74c888de, 0xcb294469, 0x07743650, 0x3076e1cc, 0x34f996ef, 0x62b51125, 0xbad11e93, 0xe24c552f,
+    0xce6d2878, 0x459eb449, 0xefaabe7c, 0x213ea556, 0x501f2049, 0xf8643014, 0x289b2833, 0x942fb40a,
+    0x1ff03eef, 0xcc3d6525, 0x6b928093, 0x26a7c02f,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x88000000, 0xf4000000, 0x7a000000, 0x19000000,
+    0xb2800000, 0x3bc00000, 0xb1200000, 0xfb100000, 0x15c80000, 0xc2240000, 0x88960000, 0xd08b0000,
+    0x4ecc8000, 0x55a94000, 0xefd4a000, 0x09611000, 0x27706800, 0xc63a1c00, 0xc8d58200, 0x10e61900,
+    0x233ca080, 0xb4551040, 0x282e6860, 0x09951c30, 0x4e0f0288, 0x430459f4, 0xfb8480fa, 0x314d4059,
+    0xf6e2a0d2, 0xc43a100b, 0x8dd4e839, 0x7c675c0f, 0xc3ff226f, 0xd2f809db, 0xd47e48ba, 0xf5b94cab,
+    0x6d9dca9f, 0xac04559e, 0x7605eaf2, 0x7f0c057f, 0x15812235, 0x6247097f, 0xd56cc85c, 0xb97f0cfa,
+    0x7d33eaef, 0x2f57059b, 0x9fa5a2da, 0x3eda499b,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xa8000000, 0xcc000000, 0xaa000000, 0xaf000000,
+    0xe7800000, 0x17400000, 0x08600000, 0xb3f00000, 0x99480000, 0x216c0000, 0xa1720000, 0xbe850000,
+    0xbdc68000, 0x6baec000, 0x9957a000, 0xfe987000, 0x8236b800, 0xb8edd400, 0xe7397600, 0xc068c700,
+    0x4fffa080, 0x6b4470c0, 0x3a6cb820, 0x88f4d4d0, 0x60c5f6a8, 0x572f07cc, 0x879c802a, 0xf8b7c06f,
+    0x732b20c7, 0x019fb0c7, 0x0db598a0, 0x43ae647f, 0x9556ee33, 0x749fa38e, 0xfd35cec6, 0xf76c1369,
+    0x3c725615, 0x620777c8, 0x53023808, 0x15861403, 0x0c485601, 0x21ee77b5, 0x6db6b83f, 0x73add41f,
+    0xcd5976b3, 0xc098c74e, 0x3337a0e6, 0x3e6870b9,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xb8000000, 0xbc000000, 0xf6000000, 0x93000000,
+    0x13800000, 0x72c00000, 0x7e600000, 0xd3b00000, 0x90c80000, 0x8f6c0000, 0xf13a0000, 0x60870000,
+    0xf1478000, 0xe4a1c000, 0x59d06000, 0xb17ab000, 0xc6aef800, 0x08de4c00, 0x63f3d600, 0x8eec8300,
+    0xcaf86080, 0x8066b0c0, 0x24bcf8a0, 0x99454cf0, 0xd0a656b8, 0x0bd643bc, 0x987d8076, 0x0826c053,
+    0x4697e0b3, 0x8adb7082, 0xc2fe98c6, 0xe464fc6f, 0x3ebd2e66, 0xb442cf1c, 0x5423b662, 0x209633d2,
+    0xf1d698af, 0x2578fc07, 0x64af2ed1, 0x99d9cf72, 0x117636f9, 0x36acf387, 0xb0d37857, 0xdff88cbb,
+    0x78e436e6, 0x59f7f3dc, 0x93e6f8c2, 0x56724c22,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xd8000000, 0x94000000, 0xe6000000, 0x95000000,
+    0xc7800000, 0x53400000, 0x3ca00000, 0x66300000, 0xba480000, 0x11240000, 0x327e0000, 0x82630000,
+    0xaed98000, 0xb45f4000, 0x7c996000, 0xe976d000, 0x3cec3800, 0x1817d400, 0xc0b35a00, 0x3887bb00,
+    0xd3c76080, 0x8b65d040, 0x335db8e0, 0x381c94b0, 0xd0bc3ad8, 0xb0866b94, 0x2fc4d866, 0x216a44d5,
+    0xd4500227, 0x8c91bfe3, 0xd1778264, 0x18edffb2, 0x2617623c, 0xc1b46f74, 0x190a3a4d, 0x15816bb5,
+    0x704358cc, 0x06260423, 0xbef8e2df, 0xa7ac2f1a, 0x38b4da4b, 0xbc8bfbee, 0xbdcf80a9, 0xe26840cd,
+    0x5ed6e0e4, 0x8c5e90e0, 0x589ad82b, 0xd7794460,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0x48000000, 0x5c000000, 0x26000000, 0x63000000,
+    0x99800000, 0x5d400000, 0x28e00000, 0xbf300000, 0x98480000, 0x126c0000, 0x1bf60000, 0x9def0000,
+    0xcdb88000, 0x608bc000, 0x31c96000, 0xa724b000, 0x7f1b5800, 0x3cdb3400, 0x22f57e00, 0x91620b00,
+    0x32776080, 0xe8a7b0c0, 0xc955d860, 0xb1bff470, 0x96849e48, 0x0acd7b5c, 0x5aa558a6, 0x105834a3,
+    0x0d3bfef9, 0x4146cb2d, 0xaee680e0, 0xcc38c023, 0x39cfe0de, 0x5b2c7001, 0x491cb8ca, 0x67db449c,
+    0xaf7fc6c3, 0xb6264fbc, 0x5f9826b0, 0xad163f28, 0x95da9eac, 0xd67e7bae, 0x1aa3d8df, 0xb050f40f,
+    0x1d3c1e16, 0x7946bb9d, 0xbaec380c, 0xb63c844f,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xe8000000, 0xcc000000, 0x56000000, 0x37000000,
+    0x42800000, 0x04400000, 0x31600000, 0x9df00000, 0x15480000, 0xcce40000, 0x87320000, 0x2e630000,
+    0x737a8000, 0xd706c000, 0x928a6000, 0x7c4ff000, 0xa563e800, 0xeffba400, 0xb844a600, 0xef6c9700,
+    0xf6f06080, 0x59c8f040, 0xdbab6820, 0x6ede64b0, 0xd6d446e8, 0x22d5a7cc, 0xc0d1e8d6, 0x35d8a477,
+    0x325e2662, 0xd99a57b4, 0xcf320059, 0x12630011, 0xed7a8063, 0x9c06c04b, 0x6e0a602d, 0x830ff0e6,
+    0x8083e814, 0x414ba47d, 0xdeeca6c5, 0xba3897b4, 0x55ea6093, 0x26bff01c, 0x3aabe8fe, 0x5b5fa444,
+    0xb016a60b, 0xa7ff97c7, 0x8442e0db, 0x716a3021,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x68000000, 0x8c000000, 0x2e000000, 0xc3000000,
+    0x5b800000, 0xa9c00000, 0x35a00000, 0x94b00000, 0x42480000, 0xc46c0000, 0xb5160000, 0x8cf10000,
+    0x8f228000, 0x4bf2c000, 0x26a5e000, 0x073fb000, 0x9785f800, 0x27c5bc00, 0x06ad0a00, 0x37310300,
+    0x0f876080, 0x53cd70c0, 0xcca01860, 0x563a0c90, 0xb908f268, 0x6284bf8c, 0x0b426aae, 0x5ee07303,
+    0x5a5978bb, 0x701a7cf9, 0x38746a3d, 0xa3617388, 0xec93f884, 0x3834bc4b, 0xda0f8aa0, 0xc903c376,
+    0x5a828009, 0x8f42c03a, 0x6cede01f, 0xdf53b004, 0x6493f853, 0xe434bc4a, 0xfc0f8a27, 0x1603c3a7,
+    0x4702803f, 0x6982c0b2, 0x2ccde09d, 0x2123b0fe,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0xc8000000, 0x54000000, 0x26000000, 0x1d000000,
+    0xd2800000, 0xeac00000, 0x12200000, 0xda700000, 0x20480000, 0x54ec0000, 0xd25a0000, 0x65370000,
+    0x55288000, 0xf7f34000, 0xbb0f6000, 0x0f8e5000, 0x984ee800, 0x08e68c00, 0x005e1200, 0xae3c1f00,
+    0x52a7e080, 0x9bbd10c0, 0x65e188a0, 0xdad8dcf0, 0xb0f8fac8, 0x96869354, 0x94cbf2a6, 0xc32a0fdd,
+    0x42fce8f2, 0x6d8d8cda, 0xfb44927a, 0x0b645f7e, 0x7212004e, 0xf1db00dd, 0x27728086, 0x62c44062,
+    0x2627e05d, 0xac7d1093, 0x054188af, 0x1a68dc9c, 0x4a90fab8, 0x4c1a93d3, 0x40d9f215, 0xeff10f26,
+    0x170e68bc, 0x1589cc07, 0x074372fc, 0x29694f1c,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x08000000, 0x4c000000, 0xe2000000, 0xa1000000,
+    0xaf800000, 0xc9c00000, 0xe0e00000, 0x58f00000, 0xaa480000, 0x0b2c0000, 0x39120000, 0x6d3b0000,
+    0x60a48000, 0x9edac000, 0xeed72000, 0x06dd9000, 0x5ad8b800, 0x80d37c00, 0x65dfbe00, 0x64542700,
+    0xee93a080, 0x00f750c0, 0x3e479820, 0xbd22ec30, 0x0e150608, 0xc5bc5b4c, 0x09e89e62, 0x5b79b761,
+    0x9183180f, 0x72c82c39, 0x8a6a26c8, 0xa0bdcb24, 0xc86a26c0, 0xf1bdcb26, 0x4fea26d4, 0x447dcbf5,
+    0x450a2687, 0xf18dcbb3, 0xa2c226ee, 0x9261cb48, 0xd4b0262c, 0x6e6acb32, 0xfebca639, 0xa36c0b1b,
+    0x833186cf, 0xc3a69b1f, 0x805fbe1c, 0xb09427d1,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xd8000000, 0x6c000000, 0x5e000000, 0xad000000,
+    0xed800000, 0x52400000, 0x42a00000, 0xaa300000, 0xbbc80000, 0xbae40000, 0x17960000, 0x3f7b0000,
+    0xcbe18000, 0x3c14c000, 0xcc38e000, 0x0ac85000, 0xb1679800, 0xb4d30400, 0x165d9a00, 0xf299eb00,
+    0x76f96080, 0x23ac9040, 0x09b77860, 0xcc8f5430, 0x91c402d8, 0x05e5ef6c, 0x591b7ade, 0xc5bebbed,
+    0x8281780d, 0xb4c45422, 0x6c6d82fa, 0xe1552ff6, 0x18159abd, 0x363deb3b, 0x1dcf6044, 0x2be790b0,
+    0x4c1ef8dc, 0x743f9498, 0x56cae2b3, 0x3766bfdb, 0x75d5622e, 0xa5dd7f8e, 0x0dd20285, 0xd9deef79,
+    0xdbdafab0, 0xf0da7b19, 0xdc5198be, 0x3d980446,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xe8000000, 0x9c000000, 0xbe000000, 0x5f000000,
+    0x07800000, 0xfc400000, 0x82600000, 0xaa100000, 0xbfc80000, 0x04240000, 0x4d760000, 0x17510000,
+    0xd5228000, 0x4dfbc000, 0x689ce000, 0xf10e7000, 0xf0853800, 0x07c2e400, 0x5021b600, 0x9f786900,
+    0x8e5e6080, 0xf9a5b040, 0x9431d860, 0xf7b89490, 0x81fa8ee8, 0x1e9f8d9c, 0xc203563e, 0x3103191f,
+    0xd08fd8e7, 0xf7cd942c, 0x282e0e0a, 0xeb754da6, 0xac5d3669, 0x18a6a987, 0xccbe0014, 0x0c750064,
+    0xffd480d8, 0x36eac017, 0xd7de6003, 0x8ae5b09c, 0x99d1d8bb, 0xade894b7, 0xea528e4d, 0x73ab8d93,
+    0x893d568e, 0x813619ab, 0xcd3b581e, 0xfb3754c2,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x88000000, 0x24000000, 0x92000000, 0x45000000,
+    0xbd800000, 0xf1400000, 0x2b600000, 0xf7d00000, 0xaec80000, 0x07240000, 0x6d3a0000, 0x4e5d0000,
+    0xdd848000, 0x414c4000, 0x53662000, 0x0bd9d000, 0x90c91800, 0xf4207400, 0x07b5c600, 0x479df500,
+    0xba2aa080, 0x6cb19040, 0x211538a0, 0x55e4a450, 0x54185e88, 0xd061c124, 0xf9514612, 0x9501b505,
+    0x7584809d, 0x754c40e1, 0xe9662003, 0x3ad9d083, 0x374918b4, 0x64607466, 0x0355c642, 0x040df5fa,
+    0x8202a0cb, 0x6d059007, 0xc9873876, 0xeb4da4ab, 0x4a6ededb, 0xd8548199, 0x1a89e6a5, 0x91c9254d,
+    0x6baf38a9, 0xcbf9a4c7, 0x2d7cdee1, 0x82bd8129,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x48000000, 0x7c000000, 0xb6000000, 0xcb000000,
+    0x6f800000, 0x6b400000, 0xe7e00000, 0xbeb00000, 0x06c80000, 0x4dac0000, 0x6bd20000, 0xc7b30000,
+    0xe0408000, 0x6869c000, 0x45f26000, 0x192b3000, 0xc719b800, 0xa7101400, 0xf716ae00, 0x2f132300,
+    0xab1ae080, 0x291ef0c0, 0x2811d820, 0x3a9424b0, 0xf5559648, 0x1675f77c, 0x246cae36, 0x7bfc230b,
+    0x8e2060cf, 0xae98301b, 0x4f59388f, 0x0379d472, 0x4ce4cef8, 0x813813fa, 0xb5835832, 0x2e4ee4a7,
+    0x57677648, 0xc077070d, 0xbf67766c, 0xcc770756, 0x616776bc, 0xcb770796, 0xf0e776fb, 0x173707e7,
+    0xce8776b7, 0x09c70721, 0x402f769d, 0x919b0765,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x28000000, 0xf4000000, 0xaa000000, 0xd5000000,
+    0x30800000, 0x88c00000, 0xea200000, 0xbc900000, 0x55480000, 0xce640000, 0xe0760000, 0xecf10000,
+    0xea3e8000, 0x37154000, 0x0a09a000, 0x6504d000, 0xe881c800, 0xc4c6ec00, 0x9c249200, 0x37907100,
+    0x1ac12080, 0xa3209040, 0xb216e860, 0x32877c90, 0xe9c4fa28, 0x10a64df4, 0x715a7a2a, 0xa7e30d95,
+    0x0e3bdad0, 0xf513dd58, 0x4b041222, 0x6f803198, 0x9d480037, 0xea64003f, 0x02760032, 0x5df100d5,
+    0x58be80b2, 0x9ed54022, 0x7aa9a02f, 0x8454d04a, 0x6769c887, 0x3ef2ecd2, 0xc33a9229, 0xa995719e,
+    0x45c1a0cf, 0xb6a0d093, 0xb257c83a, 0x8c67ecd8,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xd8000000, 0xa4000000, 0xee000000, 0x7b000000,
+    0xe9800000, 0x53c00000, 0xc0e00000, 0xb6d00000, 0x25480000, 0x74ac0000, 0x2ef20000, 0x32fd0000,
+    0xc8f98000, 0xf5fc4000, 0x4b7de000, 0x63393000, 0xc11a5800, 0xcbaf1c00, 0x99799e00, 0xb2346500,
+    0xcd966080, 0x82e870c0, 0x9fd63820, 0xddc66c50, 0x0bec26d8, 0xf75f49a4, 0x5a8c266e, 0x264f49bb,
+    0xa9242649, 0x6b3349c3, 0x7d1e26b8, 0xa1a24982, 0x5c75a6b3, 0xf8b3093b, 0xd3d9c651, 0xffca792e,
+    0x92e47e77, 0xa7dd555f, 0x09c438d1, 0x6deb6cbd, 0xf05da6f5, 0xf90f09e7, 0xe083c6c5, 0xfb4b79fb,
+    0x67a7fe22, 0xcb70155c, 0xa3325887, 0xe1131c17,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x08000000, 0x5c000000, 0xfe000000, 0x67000000,
+    0xe3800000, 0xa0c00000, 0x00e00000, 0xf4b00000, 0xe8480000, 0x75ac0000, 0x4f920000, 0x11990000,
+    0xa6948000, 0x6d13c000, 0x81d8e000, 0x7731f000, 0xb88c7800, 0x4d4fac00, 0xff2fde00, 0x53dca300,
+    0x163e6080, 0x580b30c0, 0xb4081820, 0x92019c10, 0x81094608, 0xd08bff5c, 0xe141467e, 0x3927ffa7,
+    0x70d34643, 0x5fbeff70, 0x3dc7c6a8, 0xce6d3f78, 0x42ff26be, 0x2aeccf9e, 0xf1bb5efa, 0xb2cf635a,
+    0x41e68013, 0x043ac0d2, 0x1904605c, 0x448e307d, 0x6346980a, 0xb0275c46, 0xfc5726e3, 0x40f0cf69,
+    0x63e15ef5, 0x5d3a63b2, 0xbd80002c, 0x17c00085,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0xd8000000, 0x34000000, 0x42000000, 0xfd000000,
+    0x03800000, 0xd3c00000, 0x61e00000, 0xa0d00000, 0x81e80000, 0x50dc0000, 0x69e20000, 0x2cd50000,
+    0xc7ef8000, 0xa7d64000, 0x7b6ba000, 0x8a999000, 0xcacb9800, 0x9864ac00, 0x8a158600, 0xe98aa100,
+    0x32cb9880, 0xfc64acc0, 0xb01586a0, 0xb08aa190, 0xab4b9858, 0xe6a4acf4, 0x907586e2, 0x3e9aa16d,
+    0x48c3985b, 0xc568ac27, 0x199f8683, 0xe243a1cd, 0x672618da, 0x1eb7ec77, 0xccf9a6ea, 0xe3d971e1,
+    0x1169a01d, 0xab9c90d0, 0xf74c1891, 0x18aeec6b, 0x637c2657, 0x26163188, 0xff87803b, 0xbdca40d2,
+    0x4ae9a01d, 0x8c5c90d0, 0x74ac1891, 0xd57eec6b,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x58000000, 0x4c000000, 0xce000000, 0xa3000000,
+    0x61800000, 0xd2400000, 0x12e00000, 0x3f100000, 0x86e80000, 0xfd140000, 0x8bee0000, 0x2f950000,
+    0x602b8000, 0xa331c000, 0x83d76000, 0xb9c3f000, 0x99ad7800, 0x1d74d400, 0x2f375600, 0x6dd9a500,
+    0x6acd7880, 0xb024d440, 0xdb3f5660, 0xbfdda510, 0x3fcb78d8, 0x2ea5d40c, 0xfefad6ae, 0x907965b3,
+    0xbdb798b9, 0xe617e4de, 0xf660cebc, 0x0bde418c, 0x8dc5b63f, 0x6bae9523, 0x3874e0b7, 0xf9b630e3,
+    0x5c1c18bf, 0xc76624d0, 0x5f57ae8c, 0xd10db146, 0x0480ce50, 0x64ce4172, 0x332db6b4, 0xcaba9546,
+    0x259ae0bf, 0x392330d0, 0x93b7988c, 0x1517e446,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x48000000, 0x8c000000, 0xb6000000, 0xcd000000,
+    0xf7800000, 0xd7400000, 0xd5a00000, 0xc3d00000, 0xb1a80000, 0x69dc0000, 0xd2a60000, 0x27570000,
+    0x80628000, 0xd2bfc000, 0x1a99e000, 0x4d0b1000, 0x37829800, 0x374c6400, 0x65a6c200, 0x8bd1d900,
+    0x3da29880, 0xdfdc64c0, 0x1faec2e0, 0xd0ddd9b0, 0x572c98c8, 0x0717644c, 0xd94a4256, 0xfca5197d,
+    0x5e5ff83f, 0xe5efb49b, 0x42ff3a83, 0x0bb96dbe, 0xef17228e, 0xc546c9f2, 0x52a600d1, 0xe7570059,
+    0x6062806e, 0x62bfc050, 0x5299e0e3, 0xc10b10e8, 0x81829847, 0xfa4c6496, 0x9226c28f, 0x5c91d9f5,
+    0xe802986e, 0x1c0c6450, 0xae06c2e3, 0xb901d9e8,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xa8000000, 0xa4000000, 0xca000000, 0xbf000000,
+    0x82800000, 0x73400000, 0x54600000, 0x8ef00000, 0xae680000, 0x29fc0000, 0x50ee0000, 0x8cbd0000,
+    0x7d848000, 0x51c74000, 0x57202000, 0x6290b000, 0x2c960800, 0xe9928400, 0x0c13ce00, 0xe1d72b00,
+    0x00fe0880, 0x0b6e84c0, 0xdc7dce60, 0x152a2b10, 0xc99a8828, 0xbc19c464, 0x39d5eeaa, 0x1cf69baf,
+    0xcd6a80aa, 0x0d7a4017, 0xe2a4a0fe, 0x8757f021, 0x19b62884, 0x900234fe, 0x6805c6ce, 0xc405afbd,
+    0xda0dc6d1, 0x1709afcb, 0x268bc6b3, 0xb948afb0, 0xeb6146b7, 0x0c72efe5, 0xdd25e689, 0x7d951fc4,
+    0xde1bce51, 0x22db2b0b, 0x547808d3, 0x012f84a0,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x78000000, 0x94000000, 0x76000000, 0x07000000,
+    0xc6800000, 0xaac00000, 0x5ba00000, 0x2c500000, 0x85a80000, 0x77540000, 0x79220000, 0xc89b0000,
+    0xed058000, 0x1b824000, 0x294ae000, 0xb6e63000, 0x64bc8800, 0x0a1b9c00, 0x59c2b200, 0xfb261900,
+    0xa9948880, 0x048f9c40, 0x6bc0b2a0, 0x422d19f0, 0x241908f8, 0x7ac9dcd4, 0x93a052d6, 0x605429f7,
+    0x97aa003e, 0x7e5f007e, 0x2caf808d, 0xd2dd40db, 0x1b65603b, 0xaafb7049, 0x2a79e854, 0x1fb0ece3,
+    0x46935aae, 0x3e02f5c6, 0x0b05d28b, 0x74866912, 0x53c8e02c, 0x762d30d6, 0xa21108d1, 0x05cddce1,
+    0xc12a522e, 0xbc9b2986, 0xcb0d802b, 0x948640e2,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x58000000, 0xa4000000, 0x16000000, 0xbd000000,
+    0x2d800000, 0x9fc00000, 0xb3e00000, 0x97100000, 0xe1e80000, 0xec140000, 0xb96a0000, 0x125f0000,
+    0x7f4f8000, 0x39a54000, 0x8f36e000, 0x3b129000, 0xdbea3800, 0x2b1e6c00, 0x73e98600, 0xf711dd00,
+    0x71e23880, 0x041a6c40, 0x456b8620, 0xa05addb0, 0xd447b8d8, 0xa9202ce4, 0x3d72e636, 0x173d0d0d,
+    0xff1360f5, 0x5de8d07b, 0x7e135885, 0xa269bc9a, 0xdad55e94, 0x690d21d7, 0x0389869c, 0x16c1dd78,
+    0x606a3813, 0x49de6cba, 0xcd8986fd, 0x4fc1ddaa, 0x7bea388b, 0xdb1e6ca7, 0x0be9865e, 0xe311dd4c,
+    0x3fe23893, 0x1d1a6cfa, 0x7eeb86dd, 0x829add1a,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xa8000000, 0x2c000000, 0x6e000000, 0x63000000,
+    0xfb800000, 0x65c00000, 0xe4a00000, 0x22f00000, 0x2ea80000, 0xf3fc0000, 0xd0220000, 0x12bf0000,
+    0x6cc88000, 0xfe2dc000, 0x11b66000, 0x074ff000, 0x03efd800, 0x7118ac00, 0x67bc1200, 0x204cc300,
+    0x1a67d880, 0x29d4acc0, 0xb39612a0, 0xb0ffc330, 0xdba55828, 0x7f7a6cec, 0x946af2ce, 0x1ad2f353,
+    0x701c60d3, 0x613cf089, 0xd50d582a, 0x7c866c71, 0x4c48f27d, 0x146df3ba, 0xdad4e0da, 0xd0113093,
+    0x513b3819, 0x7d099c58, 0x50872a8d, 0x22455f1b, 0x7760f2af, 0x2151f3df, 0xb5d6e07d, 0xb59e30a0,
+    0x5ffbb899, 0x7e285c98, 0xd1bb4a2d, 0xa749af2b,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x88000000, 0xbc000000, 0x02000000, 0xe5000000,
+    0x9c800000, 0x97c00000, 0x64a00000, 0xa1f00000, 0xdda80000, 0x4f740000, 0x176a0000, 0x93550000,
+    0x17528000, 0x7156c000, 0x8256a000, 0x55da9000, 0x319e6800, 0x077b6c00, 0xcb63e200, 0xa1595300,
+    0x6a546880, 0xd9de6c40, 0xab996220, 0xd67b9310, 0xe9e8c808, 0xd191fcfc, 0x77758a22, 0x73663ff5,
+    0x85558a94, 0x5c563f6b, 0x82dd8ac6, 0xd0123f14, 0x383f8a69, 0x86033f34, 0x830f0a59, 0x6f84ff3b,
+    0x40492a7c, 0x00e9afa0, 0x97196247, 0x11bb93f9, 0x2548c829, 0xdc61fc06, 0x20dd8ad1, 0x65123f57,
+    0x0cbf8a74, 0xbdc33f5c, 0x6daf0a65, 0x9774ff0c,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xe8000000, 0x84000000, 0x5e000000, 0x1b000000,
+    0xf2800000, 0x5ec00000, 0xb1a00000, 0xc3700000, 0xfea80000, 0x77fc0000, 0xb76e0000, 0xdedf0000,
+    0x87d08000, 0xfc5f4000, 0xc1196000, 0x41f8f000, 0xe8654800, 0x125b9c00, 0xf21a1200, 0x577c4b00,
+    0xd8ab4880, 0x50f49cc0, 0x47e29260, 0xa91f0b30, 0x05fc2868, 0xd6636c44, 0x395f5a3e, 0xe897d72b,
+    0x8db95a9a, 0x3704d71a, 0x888fda0f, 0x4bc89728, 0x80203a04, 0x033c275d, 0xcbca9250, 0x40230b72,
+    0x633228dd, 0xfbcc6cba, 0xa827dae3, 0xe73497ad, 0xa5ce3ae4, 0xb323275b, 0x15ba1287, 0xfb0c4bc9,
+    0x028348b5, 0xd6c89cfe, 0x05ac92dd, 0x75700b86,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x98000000, 0x74000000, 0xee000000, 0x23000000,
+    0xbe800000, 0xce400000, 0xcfe00000, 0x4b900000, 0xdae80000, 0x021c0000, 0x142e0000, 0x88bf0000,
+    0xc2d78000, 0x4d804000, 0x18cd6000, 0x8da07000, 0x2e7f8800, 0xc4781400, 0x317ab600, 0x88f68d00,
+    0xbe398880, 0x709b14c0, 0xd0633660, 0x4259cd30, 0xc8cb6818, 0xe5a724b4, 0xa27fde8e, 0x6e7ea913,
+    0x6479d6a6, 0x6179fd7a, 0x20f980c1, 0x523f4098, 0xea9ae09c, 0x1d603088, 0xdfd2e8ad, 0xb8086494,
+    0xe40d3e48, 0x260299a2, 0xff053efd, 0xbc8e9940, 0x77433e19, 0xbc6d9930, 0x185abe9f, 0x65c2d90b,
+    0x48285ed0, 0x4abee9d6, 0x1bd4b613, 0x0e098d63,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xe8000000, 0x44000000, 0x56000000, 0x93000000,
+    0x6b800000, 0x69c00000, 0xe8e00000, 0x7d500000, 0x33680000, 0xcc940000, 0x078e0000, 0x5bc50000,
+    0x5de88000, 0x7dd54000, 0x9d23e000, 0x5fb97000, 0x573dd800, 0xed72ac00, 0xa05cb600, 0x37ee0900,
+    0x54d55880, 0x17a7ec40, 0x88ff5660, 0xc6977910, 0x3e888068, 0x39454004, 0xceabe036, 0xba7d7083,
+    0xdddbd883, 0xad23ac2d, 0xc7ba36be, 0xeb3e49ee, 0x177e38d8, 0x215bdce5, 0x99696e8f, 0xc598a536,
+    0xfd0fee06, 0x7488e5b5, 0x60448e2c, 0xbc24d5c7, 0xc13ab669, 0x5e7f09ed, 0xbbd3d88b, 0xa627ac92,
+    0x103c3685, 0x78ff4998, 0x7e90b892, 0xf28f9c29,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xf8000000, 0x64000000, 0xbe000000, 0x05000000,
+    0x22800000, 0xdac00000, 0xfda00000, 0x04100000, 0x57280000, 0xd2d40000, 0xa88a0000, 0xd9c90000,
+    0x9e2d8000, 0x925b4000, 0x8d4c6000, 0x6de21000, 0x9f7bc800, 0xa498fc00, 0xc2ee7200, 0x6efdad00,
+    0xe5d64880, 0xcd03bc40, 0x0e821220, 0x08cfbdd0, 0x0aa58078, 0x019f4024, 0x706e609e, 0x6c3f10d5,
+    0xac7c48da, 0x8f1abcbe, 0x38a79243, 0x4690fd01, 0x7debe0f5, 0x07705048, 0x309a2875, 0x54e1ac0d,
+    0xf7f9da31, 0x1d574124, 0xaccbf21b, 0xd4a2edf1, 0xf4982883, 0x7aecac7c, 0x2af65a6a, 0x8bd101b3,
+    0x300012eb, 0x4802bd9a, 0x6c0a0058, 0xf20900f0,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x18000000, 0xec000000, 0x5a000000, 0x85000000,
+    0x08800000, 0x69400000, 0x28200000, 0x9e100000, 0xcaa80000, 0x7a5c0000, 0xfe060000, 0xeb010000,
+    0xdb8f8000, 0x6ec3c000, 0xd4e42000, 0x3bf69000, 0x4fdd2800, 0x83cac400, 0xd8600a00, 0xccbc7500,
+    0x9cfaa880, 0x3e5504c0, 0x40022ae0, 0x200be5d0, 0x30080098, 0xc80c002c, 0xf40e00ba, 0xb60d0055,
+    0xdf018010, 0x8d8ec085, 0x61c5a072, 0x4168501b, 0xb63088c2, 0x54be9413, 0xb0f682d6, 0x8453e175,
+    0x150baa91, 0x308925d4, 0xb543a04a, 0xba2950c0, 0xef1f086c, 0x742d54d1, 0xcc1aa24e, 0x5ba9719e,
+    0x14d88201, 0x504ee191, 0xa2a22ad8, 0x7e5be50b,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xa8000000, 0x04000000, 0x8a000000, 0xbd000000,
+    0x06800000, 0x58c00000, 0x2fe00000, 0x7f100000, 0x7c680000, 0x855c0000, 0xf14e0000, 0xd0ad0000,
+    0x58bc8000, 0xff594000, 0x34462000, 0x1a219000, 0x9e7fc800, 0x4fbddc00, 0xf4dc5600, 0xb2089d00,
+    0xd10d4880, 0xe8899cc0, 0x7fc6f660, 0x3c604d10, 0x255ca028, 0x8149d0c4, 0x68ab68ea, 0xf4b80cad,
+    0x71513eae, 0x0341915c, 0xa1aef625, 0xc03c4d02, 0x3892a09a, 0xa424d00d, 0xb177e816, 0x28314cbb,
+    0x9c9f1e06, 0x5e2c01c3, 0xb4773ec9, 0x82b091ef, 0x4a5c760f, 0x46c80d47, 0x70e800db, 0xa09c0086,
+    0xb82e0080, 0xe77d005b, 0xa3348006, 0x01154040,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x48000000, 0x4c000000, 0xb6000000, 0x9d000000,
+    0x57800000, 0xffc00000, 0x6ae00000, 0x45b00000, 0xf6680000, 0xa0f40000, 0x1cce0000, 0xac6d0000,
+    0x3bf28000, 0x6e45c000, 0x902be000, 0x1cdb1000, 0x261f1800, 0x5f7eac00, 0x7f0d1600, 0x588bc700,
+    0x3f4b9880, 0x31a26c40, 0x1e1a7660, 0x8b781790, 0xad0de0c8, 0xaf82100c, 0x0bc398d6, 0x48e66c0d,
+    0x6abc761f, 0xc6e117b3, 0x23b1605c, 0x436ad098, 0x2b7af8c1, 0x1d08bccf, 0x17808e3e, 0x9fc0aba5,
+    0xfae56efb, 0x0db6bb13, 0xba68f63b, 0x16fdd79f, 0x81c600df, 0xfbe900e8, 0xc4348087, 0x04acc0a5,
+    0xd59f60ac, 0xeab7d0ec, 0x86e078d1, 0x43b97c9a,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x48000000, 0x84000000, 0xba000000, 0xb5000000,
+    0x6b800000, 0x96400000, 0x9ca00000, 0x07300000, 0xd0280000, 0x3bf40000, 0xf3420000, 0x5f250000,
+    0xa57e8000, 0xae814000, 0x45cb2000, 0x76e91000, 0xfd9c1800, 0x141eb400, 0x0b5b3200, 0x3a74b700,
+    0x78089880, 0x9c0ef440, 0xd60c92a0, 0x9b09e710, 0x2c8920c8, 0xcccc10c4, 0xdb62981a, 0xb8dff4a5,
+    0x20301223, 0x7aada712, 0x843c80a6, 0x90a440f2, 0xd935a01b, 0x6f2850bd, 0xbd773827, 0xc287a4dc,
+    0x6bcf2acf, 0x31ee0320, 0xa719aa5d, 0x53db43ff, 0xb4b08ae4, 0xca6753ed, 0x395a12f6, 0xeb7ca7a3,
+    0x198000a4, 0xe74000b6, 0xed200041, 0x347000b8,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x28000000, 0xfc000000, 0x36000000, 0xad000000,
+    0x3f800000, 0x79400000, 0x9d600000, 0xdc900000, 0xe1e80000, 0x735c0000, 0xf84a0000, 0x7ce90000,
+    0xd4d08000, 0x2502c000, 0x538de000, 0x47471000, 0x9c659800, 0xfd1fc400, 0xc9adee00, 0xe7b53d00,
+    0xf0971880, 0x3fe804c0, 0x025a8e20, 0xa1c9ed50, 0x80a7e0a8, 0x8a3e103c, 0xb6dd1816, 0x820104fd,
+    0xd70a0e17, 0xfc8b2d85, 0x6fca002b, 0x39a900b1, 0x5fb080fe, 0x0492c05a, 0xa5e5e04d, 0xb15b105c,
+    0xcf4f9803, 0xf066c4fb, 0xc3156e94, 0xc8abfd42, 0xc630f855, 0xd8d614c4, 0xab07965b, 0x0a88e983,
+    0xe2cdee3c, 0x56253d82, 0x0eff1889, 0x65f4045e,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x78000000, 0x6c000000, 0x1e000000, 0xcb000000,
+    0x79800000, 0xd8c00000, 0xee200000, 0x07f00000, 0x1ea80000, 0x2bbc0000, 0x66c60000, 0x752b0000,
+    0x96798000, 0xa269c000, 0xcf9a6000, 0xd83c7000, 0xc7057800, 0xd784bc00, 0x8bc6ba00, 0x8bac9300,
+    0xb932f880, 0x578a7cc0, 0x4bcb5ae0, 0x6bae2370, 0xc93260f8, 0x2f8070ac, 0x27c378fe, 0x75afbcbb,
+    0x023f3a01, 0x560553b4, 0xff089870, 0x9b860c0c, 0x05c62287, 0x48a69f83, 0xd4bada70, 0xfd4be3de,
+    0x70e600f6, 0xdedb00f2, 0x76d18048, 0x32d5c069, 0xa8dc60d9, 0x19d770d5, 0xa15cf83d, 0xb91d7c6b,
+    0x2374da8f, 0xa0ece32a, 0xf6d18026, 0xf2d5c0ae,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0x44000000, 0x2e000000, 0xb5000000,
+    0xd9800000, 0x59c00000, 0xc7a00000, 0x9e900000, 0xee680000, 0xa13c0000, 0xd4f60000, 0x915b0000,
+    0xf8c98000, 0xd8294000, 0x1e506000, 0x524b9000, 0x8164b800, 0xfbb66c00, 0xb5bf5200, 0x30bd3d00,
+    0x3134d880, 0xdcfdfcc0, 0x0d5beae0, 0x62cb51f0, 0x7b2b8ab8, 0x36d0c184, 0xfc07324e, 0xaa0aad85,
+    0x7b066081, 0x9c80902d, 0xb8453831, 0xda632c9f, 0x77393221, 0xc5fdad09, 0x96d1e064, 0x2c0ed023,
+    0x820ad8e8, 0xf70afc4e, 0xce8c6adb, 0x674511b8, 0x98e46aed, 0x42791166, 0x82126a3e, 0x96221142,
+    0x9b5beac9, 0x53cb5147, 0x6cab8abf, 0x2a10c19b,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x88000000, 0xa4000000, 0x6a000000, 0xad000000,
+    0x99800000, 0x8cc00000, 0xdea00000, 0xca700000, 0x18680000, 0x89d40000, 0xb3960000, 0xa1750000,
+    0xa2ea8000, 0xbb994000, 0x45712000, 0x28ed1000, 0x8697f800, 0x54f1fc00, 0x00258200, 0x323fe300,
+    0x338ed880, 0x81c8ec40, 0x37247ae0, 0x5ebb1f90, 0xeac1da08, 0x8dae4fe4, 0x6cfb820a, 0x1c2ee37d,
+    0x043a5871, 0x4880acf8, 0xf341dadc, 0x416e4f53, 0x525b82e3, 0x465ee34c, 0x945258fe, 0x6554ac9a,
+    0x2ad7da95, 0x4d1b4f40, 0x69310252, 0x7107a383, 0x0f83781c, 0x87c9bc36, 0xb428227b, 0x903eb372,
+    0xda8280f6, 0xe24d404c, 0x9ee7204c, 0xbd981089,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x38000000, 0xcc000000, 0xfe000000, 0xa1000000,
+    0xb9800000, 0xae400000, 0xa3a00000, 0xe2100000, 0xebe80000, 0x5cbc0000, 0xd6720000, 0xc41f0000,
+    0x56ed8000, 0x3331c000, 0x9d356000, 0xd43a5000, 0x89b53800, 0x9df6d400, 0x9552d200, 0x5dcf0d00,
+    0xfee85880, 0xb73084c0, 0x3735eaa0, 0xf736d9b0, 0x573f0ab8, 0xe732490c, 0xdf3ad2de, 0x13330dd1,
+    0xed3a58a1, 0x4c3f8412, 0xf5b06ac5, 0x5bfb193f, 0xf8586a14, 0x1a47195f, 0xf1aa6a12, 0xad181959,
+    0x7b67eabf, 0xbf79d99f, 0xe99a8ae5, 0xdaaf89b3, 0x4795b2bd, 0x93aa5d85, 0x1a10e074, 0x87e7902f,
+    0x12ba582b, 0x4f7f8400, 0xb1906a57, 0x06ab195a,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x78000000, 0xec000000, 0xe2000000, 0xb9000000,
+    0x68800000, 0xf5c00000, 0x38200000, 0x4cb00000, 0xc7e80000, 0x19940000, 0x8dde0000, 0x60bd0000,
+    0x05ed8000, 0xd09dc000, 0x8d56a000, 0x017fd000, 0x33cbe800, 0xc7272c00, 0x9b303a00, 0x85a6cf00,
+    0x73f54880, 0xd30cfc40, 0x1585d260, 0x8b4ce310, 0x6ae0f2f8, 0x3f13f3ac, 0x43103a02, 0x9916cfe9,
+    0xac1d48f0, 0x3698fc49, 0x025bd2c2, 0xbef1e309, 0xe58d72b5, 0xa34e33f9, 0x9ee69adf, 0x21191f30,
+    0x601ea00a, 0xa49bd0cc, 0xd35de805, 0x427e2c71, 0x1e4bba8e, 0xc0620fce, 0x03d868ac, 0x7bb7ec74,
+    0xb4631a3f, 0x5dd0df75, 0x38b600ba, 0x99e90051,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x48000000, 0xb4000000, 0x2e000000, 0xdf000000,
+    0x8b800000, 0xbc400000, 0x94200000, 0x19300000, 0xa3680000, 0xf89c0000, 0xf11e0000, 0xe0550000,
+    0xcaf68000, 0x4d084000, 0x6e8d6000, 0xb6c53000, 0x24e49800, 0x46db1400, 0x083daa00, 0x27e9ff00,
+    0xe757f880, 0x7d7b24c0, 0x0b47b260, 0x1ba6ab10, 0xb37932c8, 0x0442eb74, 0xb82252ce, 0xab3edb0f,
+    0xf6664a23, 0xca148fd8, 0xadd60012, 0x6db900a2, 0x24a0802e, 0xe8f1403f, 0x900de088, 0x8804702e,
+    0xd4017858, 0x3e0364cf, 0x9702d223, 0x3f8f9b0b, 0x924baaa8, 0x4b20ffca, 0x92bf781e, 0x1f266414,
+    0x6cbc52be, 0xe82bdb84, 0x4330ca65, 0x326ccf2a,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xe8000000, 0xc4000000, 0xa6000000, 0xe1000000,
+    0xd5800000, 0x8c400000, 0x7e200000, 0x29300000, 0x0f680000, 0x189c0000, 0xa11e0000, 0xef590000,
+    0x667c8000, 0x43c34000, 0x3963e000, 0xb197d000, 0x0090f800, 0xfd1a7400, 0xd5508a00, 0xdd7c7f00,
+    0xfd4d1880, 0xe3a4a4c0, 0xd174f260, 0xef494bd0, 0xfca87268, 0x19fa0b04, 0x3c839246, 0x8dc1dbf1,
+    0xdc656add, 0xa21eaf58, 0xbdd760d0, 0xbeb8901c, 0xb42518f4, 0x0a38a461, 0x2deaf244, 0x98504b6f,
+    0xaaf4f2e4, 0x56094bdf, 0x790872dc, 0x498a0bf3, 0xd64b92be, 0x152ddbb4, 0x7fb36a8a, 0x11abafed,
+    0xde7de078, 0xafced0ba, 0x3b6c78de, 0x2699346d,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xf8000000, 0x34000000, 0x9e000000, 0x29000000,
+    0x76800000, 0x05c00000, 0x65e00000, 0x77b00000, 0x4ea80000, 0xd3940000, 0x1afa0000, 0x47870000,
+    0x97498000, 0x962a4000, 0x475d6000, 0x1d965000, 0x1bf1c800, 0x6d052c00, 0x708ea600, 0x88c6cf00,
+    0xc5652880, 0x97793c40, 0xf7c20e20, 0x16e5b3b0, 0x6e324678, 0x90eedf74, 0xa333803e, 0x106d40d9,
+    0xf3f4e0ae, 0x510c1081, 0x0284a803, 0xbbc77c6a, 0xfce56ea6, 0xf934e3ff, 0x7f6a0e89, 0x2871b375,
+    0x4448461c, 0x7fa9dfc2, 0x411a004b, 0xe937004d, 0x776180d0, 0xc47e4011, 0x5e4760b0, 0x30a1501b,
+    0x2a904814, 0x047b6cbc, 0x3e49c6c1, 0xa0a79f52,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x88000000, 0x74000000, 0x8a000000, 0x4f000000,
+    0x2a800000, 0xea400000, 0xb9e00000, 0x36300000, 0x21280000, 0x599c0000, 0x70fa0000, 0xdb810000,
+    0xe5c68000, 0x46204000, 0x97152000, 0xb8b33000, 0x09694800, 0xfb7cf400, 0xb642c600, 0xd7eeef00,
+    0x5b3ae880, 0x60af84c0, 0x9b5eae20, 0x18512b50, 0xf4d96608, 0x57919fb4, 0xadf4802a, 0x620d40df,
+    0x8b01a082, 0x588e70ce, 0x0940e8bb, 0xe56e840d, 0x6d782e81, 0x77416bfc, 0xe0644663, 0x28feafc7,
+    0xe787c8dd, 0x3bc0b4b9, 0xd32de66e, 0x7a9cdf1e, 0x0c75201e, 0xd0c3309d, 0x3ba14844, 0x8bd0f4dc,
+    0x4510c6de, 0xcbb3ef8b, 0xfde668b6, 0x843ec4d4,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xf8000000, 0x94000000, 0xa2000000, 0x59000000,
+    0x83800000, 0xac400000, 0x11a00000, 0x10900000, 0xdc680000, 0xd47c0000, 0xeed60000, 0x1cc10000,
+    0x9b6d8000, 0x00f24000, 0x4e18a000, 0x89a75000, 0x14995800, 0x66667400, 0x497a3200, 0x37533500,
+    0x7d847880, 0xab4f64c0, 0xe52dcae0, 0x00531150, 0xe10a9278, 0x37886554, 0xbe4b20c2, 0xe0a810c9,
+    0xff1a789b, 0x46226468, 0x3ede4a4b, 0x24cc51dd, 0xef61b27d, 0xf2f075e1, 0xef19581c, 0x9e267430,
+    0x1ada324e, 0x2ec33500, 0xda6c78a6, 0x4773644c, 0xb85bcaa0, 0x550211df, 0x258f1276, 0x4f462556,
+    0x0f258028, 0x655e4049, 0xac86a011, 0xf4ca50f1,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x28000000, 0xa4000000, 0xfe000000, 0x05000000,
+    0xbd800000, 0x73c00000, 0xcca00000, 0xedf00000, 0x1ce80000, 0x279c0000, 0xc83e0000, 0xb14f0000,
+    0xe9e88000, 0x12174000, 0xcffe6000, 0x1bec9000, 0x8d147800, 0xe97d8c00, 0x60aac200, 0xa7f0fb00,
+    0x1fea9880, 0xa31a5cc0, 0x747eda60, 0x512ee7b0, 0x8e3ca2a8, 0x28406b64, 0xf660e01e, 0xa6d8d075,
+    0xc9d498f5, 0x67555c67, 0x68165a1a, 0x24f9a74c, 0x5b62c2df, 0x7f5cfb91, 0x241c9859, 0x1ef95c5f,
+    0x10605a71, 0xefdaa7cc, 0x4e5c424d, 0x5f98bbec, 0x3434780f, 0xa34d8c4c, 0x06e2c27f, 0x7c9cfb24,
+    0xa0bc98db, 0xe7095cfa, 0xda885a6e, 0x6946a74f,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x48000000, 0xa4000000, 0x3a000000, 0xcf000000,
+    0x39800000, 0xf1c00000, 0x98e00000, 0xe0300000, 0x26a80000, 0x48140000, 0xfafa0000, 0xfdc10000,
+    0xb6e28000, 0xad354000, 0xec222000, 0x56563000, 0xc2d07800, 0x49914c00, 0x773c6600, 0x932bc700,
+    0xd7d8d880, 0x0f163c40, 0x077c3e20, 0xc609bbd0, 0x790446c8, 0x988cf7e4, 0xa542209a, 0x73a6305f,
+    0x2e987851, 0x5ab54c85, 0xe0ee66ea, 0xbc3ec78b, 0x00a058a5, 0x81127c36, 0xda749e7b, 0x648ecb3c,
+    0x13441ec0, 0xd2ae8be1, 0x7a1ebeaa, 0xb1fcfbbc, 0x2e46665f, 0xc02ac7dc, 0xa85a5867, 0xc7d37c73,
+    0x27161eb4, 0x537b8b12, 0x64063e1b, 0x5a08bbdb,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0x78000000, 0xa4000000, 0xbe000000, 0x97000000,
+    0xc2800000, 0x9ac00000, 0x0be00000, 0xd2500000, 0xd4a80000, 0x39fc0000, 0xd3d60000, 0x1aed0000,
+    0x03d58000, 0x42e54000, 0xd7d96000, 0x14e2b000, 0x9cd88800, 0xcc6abc00, 0x2f1aa600, 0x9202c100,
+    0xa5026880, 0xd7804cc0, 0x454ecee0, 0x02af8d90, 0xb2f926f8, 0xeb5a8164, 0x392688de, 0x25bbbcc7,
+    0x6cf9265a, 0x2c5a81ae, 0x63a6884d, 0x8b7bbc21, 0xa1192648, 0xcd0a81a4, 0xcb8e88e2, 0xbf47bc36,
+    0xbbaf2682, 0x9f7781ae, 0x171308ea, 0x160efcc9, 0x6b084619, 0xa8843141, 0x5bc80015, 0x826c0003,
+    0x901e0068, 0x6c8100c0, 0xb5cb809b, 0x8d644019,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x38000000, 0x04000000, 0x8e000000, 0x81000000,
+    0x69800000, 0xa1c00000, 0x5d200000, 0x4c300000, 0x20680000, 0x3f540000, 0x8db20000, 0xfdad0000,
+    0x487d8000, 0xde834000, 0x0d4e6000, 0xbb61d000, 0x21df3800, 0x60f16c00, 0xf6c71a00, 0x41aea700,
+    0x827ed880, 0x718efc40, 0xf5c3c2a0, 0x4b295b10, 0xd93a9ab8, 0xffede744, 0x1b90b8ae, 0x371f2cd1,
+    0x9154faf1, 0x5cbc37b5, 0x0c27806b, 0xfdba4089, 0x35a1e067, 0xc47f900f, 0x7c84d881, 0x3e47fc14,
+    0xd9e442bb, 0xe6931b2c, 0x749b7a49, 0x2f927798, 0xd1146099, 0x8c58d05f, 0xaf30b80b, 0x9aef2c0f,
+    0x4c1cfa95, 0x3fd837d2, 0x99fd800d, 0x3b4340d4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xc8000000, 0xf4000000, 0x0e000000, 0xff000000,
+    0x90800000, 0x28c00000, 0xdfa00000, 0x3df00000, 0xe4e80000, 0xc01c0000, 0x2f720000, 0x31210000,
+    0xb23a8000, 0x37c84000, 0x7f256000, 0x2d363000, 0x574ba800, 0xcfed1c00, 0xce9e4200, 0x90b5bb00,
+    0xda064880, 0x61026cc0, 0x07820aa0, 0x1c4ad750, 0x316cc248, 0xe951fb34, 0x4799282e, 0xf3395c6f,
+    0xa04922f8, 0x0b628b8c, 0xb8576099, 0x781730f6, 0x837128da, 0xa3255c47, 0xe73b22a8, 0x9e438bd0,
+    0xcc6de007, 0x44df70f5, 0x62d44884, 0x59d36ca4, 0xff508add, 0x449e9700, 0x39bba269, 0x2986cb75,
+    0x7348006d, 0xc9ec000a, 0x659a009b, 0x5e3d00ed,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x58000000, 0x4c000000, 0x0a000000, 0x31000000,
+    0xb6800000, 0x2a400000, 0x25a00000, 0xd9b00000, 0x4c680000, 0x2edc0000, 0x253e0000, 0xf72b0000,
+    0x2dff8000, 0x7ec5c000, 0x8ae62000, 0x9c945000, 0x2c980800, 0xd4909400, 0x089ce200, 0xaa99f700,
+    0x8f9fa880, 0x7f1a04c0, 0x6e554a60, 0xcc74f3f0, 0x890b62d8, 0xca80378c, 0x184788ea, 0xa8a55401,
+    0x3d32c28e, 0x1b21a796, 0xb7f1a0f7, 0xe7cd9064, 0x28602890, 0xf0dfc4c5, 0x32336aee, 0x8aa0a348,
+    0xd8336ace, 0x8ba0a378, 0x56b36ab5, 0x1de0a35f, 0x21136ab9, 0xb950a3f8, 0xd1fb6a51, 0x8ccca32b,
+    0x67e56a08, 0x8817a3a7, 0x23d2ea46, 0x01be6372,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x78000000, 0x8c000000, 0x3a000000, 0xa3000000,
+    0x4b800000, 0xefc00000, 0x29e00000, 0x3dd00000, 0x94a80000, 0x53740000, 0x84d20000, 0xac250000,
+    0x2f398000, 0x5ef3c000, 0xfa112000, 0xa9437000, 0xe8a8d800, 0x6173ec00, 0xe3d25600, 0xb9a97900,
+    0xadfa7880, 0x29925c40, 0xfa802ea0, 0x134f2590, 0x0ba856f8, 0x8af879cc, 0x9c11f81a, 0xe8449c73,
+    0x1c288e93, 0x873f95f3, 0x0af1aeeb, 0xdc18e552, 0x484376c5, 0x8c2a09cf, 0xff32a0be, 0x86f1b022,
+    0xe61a7870, 0xeb425c8f, 0xc7a82ee3, 0x10fb2596, 0xaf1a569b, 0xdbcd79a2, 0x7fe078e0, 0x94d35c1f,
+    0x9423ae5e, 0x033de53f, 0xf4faf60c, 0x2119c905,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x78000000, 0xf4000000, 0x92000000, 0xd7000000,
+    0x42800000, 0x4ac00000, 0x46200000, 0x0f900000, 0x85980000, 0x569c0000, 0x4e160000, 0x4fd70000,
+    0x55708000, 0xa12fc000, 0x651ee000, 0xe35f9000, 0x66b5b800, 0x7c8a1400, 0xabc0f600, 0x99accf00,
+    0x34d8f680, 0x21f0cf40, 0x76eef620, 0x52b7cf50, 0x0e867658, 0xccc40fa4, 0xb32e96ca, 0xf21c9f73,
+    0x01d3ae88, 0x3c754b39, 0x42a3b8ce, 0x505d1436, 0x8e3076cb, 0xb5430f20, 0x2d6616a5, 0x73ff5f3f,
+    0x41e34e28, 0xa031db7a, 0x2c4880a7, 0x06e3c0ba, 0xdab0e091, 0x3284901b, 0xc2cb389e, 0x7a2ed4d7,
+    0x01989611, 0x4c9b9f5b, 0xa51b2ebe, 0x03568b87,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xd8000000, 0xdc000000, 0xa6000000, 0x47000000,
+    0x8c800000, 0x8ac00000, 0x14a00000, 0xe3100000, 0x2d180000, 0x7e140000, 0xb09a0000, 0xd7570000,
+    0x767a8000, 0x08274000, 0x91d52000, 0xd5b55000, 0x330f9800, 0xde8c3400, 0x5fc1fe00, 0xad218f00,
+    0x0059fe80, 0x02f58fc0, 0x6ee3fe60, 0x2b728f10, 0x75a17eb8, 0xe891cfcc, 0xcb565e1e, 0x30779f8b,
+    0x9f214692, 0xc55feb01, 0x83751886, 0x81ab74e2, 0x7a94de2b, 0xfe54df5c, 0x59f666fb, 0xa469bb9b,
+    0x36ba00b5, 0x3587009f, 0x5d4280da, 0x54e34011, 0xea772023, 0x8e2650d2, 0xe6d7183c, 0x513874ca,
+    0xbdcc5ea3, 0xb0209f12, 0x5ddbc65c, 0x4bb8abda,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x28000000, 0x8c000000, 0x9e000000, 0x39000000,
+    0xe1800000, 0xe6c00000, 0x4ae00000, 0x9bf00000, 0x0af80000, 0x277c0000, 0xffb60000, 0xdc530000,
+    0x8e218000, 0xee114000, 0x1d84a000, 0x40c13000, 0x77e42800, 0x3877c400, 0x63342a00, 0x391a3500,
+    0xfd022a80, 0x43893540, 0x99c3aa20, 0x66687510, 0xa6bf0a08, 0x8dd5459c, 0xb0ed2296, 0x50f181a5,
+    0xbc788877, 0x613af443, 0x161e02bd, 0xf982f198, 0xf2c18097, 0x80e140af, 0xc8fca060, 0xe87d30ef,
+    0x0b3228f8, 0x1514c4e4, 0x330daab2, 0x528775f7, 0xf4488a70, 0x1ea705cb, 0xd5500212, 0xf7adf1f8,
+    0x5cd60078, 0x3d630057, 0xd8398084, 0xb79d405d,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x88000000, 0x1c000000, 0xe6000000, 0xdd000000,
+    0xcb800000, 0x29400000, 0x95e00000, 0x73100000, 0xc6180000, 0xc1940000, 0x96de0000, 0xfa350000,
+    0x30ad8000, 0xf8f44000, 0x5c0e2000, 0x860df000, 0xcd0c4800, 0xf3875c00, 0xbd4c4200, 0x6fec5300,
+    0x48124280, 0xd09953c0, 0x235fc220, 0x2a7d1370, 0x1cc9e2a8, 0x85a4e36c, 0xff7baa4e, 0x0b46bfb1,
+    0xeae26885, 0x079aac98, 0x3bd80a90, 0x19bf0f2b, 0xb5e00076, 0x0310009a, 0xee180048, 0x6d94000c,
+    0xf8de00b6, 0x3b350085, 0x1d2d804f, 0x0cb440eb, 0x026e203a, 0xdc5df09f, 0x9ef4481f, 0x41035c6b,
+    0xed8a4292, 0x544d53f3, 0xee61c251, 0xd25813da,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xa8000000, 0xd4000000, 0x6e000000, 0x2f000000,
+    0xf2800000, 0xbfc00000, 0x9ba00000, 0xc0100000, 0x83180000, 0xa3940000, 0x695a0000, 0xd1730000,
+    0xdb298000, 0x5adcc000, 0x733e2000, 0xedced000, 0x2eaef800, 0x039a8400, 0xd952fe00, 0x09705f00,
+    0x3728fe80, 0x48d35fc0, 0xe6397e60, 0x5e4b9f50, 0x4ce55ec8, 0xd5724f84, 0x3d2026a6, 0xd1d30bab,
+    0xd7bf7854, 0x01024414, 0xfd8ede4f, 0x3d498f14, 0x1c6d86ac, 0x27b21be7, 0xf906208d, 0x618ad072,
+    0x174cf810, 0xf56d8413, 0x2e397e1f, 0xda4b9ffe, 0x6ae55e70, 0xbe724f96, 0x09a0263f, 0x95130b43,
+    0xd09f78b8, 0x51d24412, 0x17b6de99, 0xe10d8fe8,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x58000000, 0xfc000000, 0x42000000, 0x61000000,
+    0x92800000, 0xb1400000, 0x3fe00000, 0xe5d00000, 0xcad80000, 0x535c0000, 0x859e0000, 0x6fbf0000,
+    0x83ca8000, 0xdead4000, 0xa23d6000, 0xc3017000, 0x83855800, 0x4bcdcc00, 0x3aa2f200, 0x3c3c0f00,
+    0xf004f280, 0x280f0f40, 0x940872a0, 0xf6014f50, 0x870192f8, 0x4d827fac, 0x00cb2aba, 0x7d2f83cd,
+    0xf9f7e028, 0xa1ac307c, 0x43b83897, 0xf9ccbcd9, 0x73a7aa7d, 0x3ab1c39a, 0xb7460020, 0x90e30049,
+    0x3c548041, 0x3c1240a6, 0xa977e009, 0x31ec3038, 0xced838af, 0xbd5cbc0b, 0xde9faa8f, 0x703dc3b4,
+    0xba0000d7, 0xcd0000e7, 0xa8800095, 0x3c400029,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x28000000, 0xd4000000, 0xb6000000, 0x07000000,
+    0xde800000, 0x3c400000, 0xcb600000, 0xee100000, 0xe8980000, 0x48d40000, 0xf1be0000, 0x36a50000,
+    0xfbbf8000, 0x33a6c000, 0x8433a000, 0x4e66d000, 0x519cb800, 0x3555bc00, 0x2d70aa00, 0xa28b9b00,
+    0x9e4f2a80, 0xda6d5bc0, 0x879c8a20, 0x225b8b70, 0x6bf83208, 0x62ca37a4, 0x372e98be, 0x8570aca3,
+    0xb68032e0, 0x884e375f, 0x6d68980b, 0x7111acc1, 0xca19b2eb, 0x1699f72d, 0x8bdab8a4, 0x0134bcb8,
+    0xf1e92a3f, 0x8c5c5b14, 0x50fd0a10, 0x7e484b2b, 0x0a6c1282, 0xbf9e27e7, 0x6e5f803d, 0x21f6c03a,
+    0x07cba062, 0x58a2d0b8, 0x60bab836, 0x9f24bcfb,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x08000000, 0x64000000, 0x9e000000, 0x81000000,
+    0xfc800000, 0x7c400000, 0x33e00000, 0xba100000, 0xca980000, 0x1cd40000, 0x58320000, 0x6bab0000,
+    0xb7f38000, 0x0181c000, 0xb2ca2000, 0x54a13000, 0xfa717800, 0x79417c00, 0x01696200, 0x2f5f7300,
+    0xf1fae280, 0x4c8eb3c0, 0x4448c2e0, 0xafeb8350, 0xd813bae8, 0xb995ff34, 0x9b5b5876, 0xc7f04cb5,
+    0x59801a0a, 0xbeca0f09, 0x3ea180d9, 0xed7ac0e3, 0x60c1a0fb, 0x1fa4f0cb, 0xe1f15855, 0x848f4cd5,
+    0x40419a88, 0xa1e0cf4d, 0x511820eb, 0x211a309e, 0x791af8f4, 0x7514bc6d, 0x1f11423c, 0x0815437b,
+    0x11981afe, 0x0f5e0f64, 0xc1f380e5, 0xb481c098,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0xa8000000, 0xb4000000, 0x1a000000, 0x17000000,
+    0x36800000, 0x67c00000, 0x81200000, 0x72d00000, 0xde580000, 0xee9c0000, 0xb9320000, 0x3c2b0000,
+    0xcb598000, 0xe31ec000, 0x9e756000, 0x5bc31000, 0x1f2bf800, 0xa7d84400, 0x73dcb600, 0xb9d64b00,
+    0xe6dd3680, 0xf4548b40, 0x219a5660, 0x03bc9bd0, 0x5de82ec8, 0xf37a1f64, 0xaa41f8d2, 0x066f4473,
+    0x65373664, 0x32238b54, 0xb651d685, 0xfa995bf6, 0xd33cce13, 0x132bcf3c, 0xe1d56018, 0x2ad310a3,
+    0x1253f87f, 0x08944470, 0x9036b6b6, 0xafa14bae, 0x3916b6de, 0x29714b61, 0x1d4eb630, 0x40ed4b8d,
+    0x3afcb63a, 0xaf064b75, 0x6a8536d5, 0x29c88bab,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0xa8000000, 0x9c000000, 0xfe000000, 0xd9000000,
+    0xeb800000, 0x22c00000, 0x2a200000, 0x47100000, 0xee980000, 0x5b540000, 0x4bfe0000, 0x29a90000,
+    0x99db8000, 0xeab64000, 0x1d4fa000, 0x7ae73000, 0x32380800, 0xdf0b6400, 0xb68ce600, 0xa3466700,
+    0xc3ef6680, 0x09b427c0, 0x25c6c620, 0xa8ae17b0, 0x865b4e88, 0x0a7a332c, 0x60638876, 0xa77d24f5,
+    0x89e3461d, 0x72b15717, 0x494f6e17, 0xc8eb43e0, 0xcd3420f1, 0xd9817057, 0x1dcfa86c, 0x8ca854fb,
+    0x9c52ee40, 0x6970039a, 0x98e600b4, 0xd53d00d9, 0x4d85809c, 0x0fcf4073, 0x03ac206c, 0x32d5706c,
+    0x5e31a801, 0x490154a4, 0x53896e5b, 0xc6c6433c,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x68000000, 0x3c000000, 0x8e000000, 0xb1000000,
+    0x36800000, 0xe6400000, 0x99200000, 0xfb100000, 0x47980000, 0x6adc0000, 0xb6720000, 0x5aab0000,
+    0xb1d58000, 0xdbfa4000, 0x9168a000, 0x033cb000, 0x020c5800, 0x67057c00, 0xa384ce00, 0x9ac90f00,
+    0x14694e80, 0x57bf4f40, 0x534bee60, 0xd5a4ffd0, 0x6e583608, 0x2f3cc3ec, 0xa40bd886, 0x0a043c5d,
+    0x8b01ee30, 0xa583fffb, 0x87c7b6c9, 0xc4e183d0, 0x3cfcf806, 0x72e5cc16, 0x79fa96d6, 0x86677381,
+    0x58b800bf, 0xcccc00dd, 0xc16a0089, 0xcb37002f, 0x4e078053, 0x110140c4, 0x468520d1, 0x1e4af005,
+    0xcd2ef8e3, 0x491ecc7f, 0x78971678, 0xed513305,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x88000000, 0xfc000000, 0x6e000000, 0x15000000,
+    0x36800000, 0x4a400000, 0x60a00000, 0x88300000, 0x9bb80000, 0xcf7c0000, 0x09920000, 0xf70d0000,
+    0x858d8000, 0x75c64000, 0x826ea000, 0x3fd0f000, 0xb9a31800, 0xf8b47c00, 0x28f69600, 0x8f5a4500,
+    0xaae91680, 0xa8910540, 0xf38a3660, 0xa4c7b5b0, 0x4ee78ee8, 0x0293394c, 0x1c8a0086, 0xe5410059,
+    0xc1278030, 0xf7f74053, 0xe6d120b0, 0xc92bb02b, 0x4bf838a3, 0x68decc18, 0x2c292ec4, 0xf573c9fa,
+    0xde9118f7, 0x22897c85, 0x68431675, 0x73a00582, 0xa7b5b6bf, 0x017cf5f9, 0x0c9cae98, 0xb9898998,
+    0xbbcdb867, 0x87648ce6, 0x71558eae, 0x87ee39ea,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xa8000000, 0x04000000, 0x2a000000, 0xa3000000,
+    0xfd800000, 0x8a400000, 0x11200000, 0x69900000, 0x1b180000, 0x82d40000, 0x30be0000, 0x468b0000,
+    0xebc98000, 0xed65c000, 0x0dbd6000, 0xaa0a5000, 0x630b0800, 0x5d85ec00, 0xda495e00, 0xb92a7700,
+    0x6d9ede80, 0x3114b7c0, 0x21d23e20, 0xcd3f2790, 0xccc25688, 0xfaef9b94, 0x84f780a2, 0x16aec037,
+    0x28d4e0df, 0x53bf907d, 0x1b0e686e, 0x318bbc44, 0x544456dd, 0x60209bc2, 0x9b180047, 0x42d400e7,
+    0x90be0071, 0x168b0010, 0x43c98065, 0xe965c087, 0x27bd609f, 0x090a50ff, 0x9e8b08a2, 0xd7c5ec29,
+    0xcb695ec8, 0xd0ba7716, 0x7686de6e, 0xb3c0b75a,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x48000000, 0xd4000000, 0x02000000, 0xfd000000,
+    0xa4800000, 0x8f400000, 0x2ba00000, 0xbeb00000, 0x2b380000, 0x6ef40000, 0x491a0000, 0x63890000,
+    0x6ac58000, 0x0664c000, 0x9d5ae000, 0x7bae3000, 0x06b4f800, 0x573b4c00, 0x88f8d600, 0xfe156d00,
+    0xee075680, 0x4308adc0, 0x5f803660, 0xacc65df0, 0x616c2e28, 0x68de2124, 0xce67802a, 0x8959c0d9,
+    0x99a5600e, 0xcbb3f096, 0xbbb398c5, 0xd3b5bc18, 0xd7b4cea6, 0xadbd11a2, 0xccb4f86d, 0xbe3b4cfc,
+    0xce78d6c3, 0xbc556d85, 0x2927565d, 0xa6f8ad64, 0x5d1836da, 0x81825d44, 0xa7ce2ef5, 0xeae3217f,
+    0xc61800fc, 0x520400f6, 0x4502001a, 0xd88d00be,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x38000000, 0x5c000000, 0x16000000, 0x21000000,
+    0xb3800000, 0x04c00000, 0xe0600000, 0xe2100000, 0xb6980000, 0x58dc0000, 0x73fe0000, 0x334b0000,
+    0x9ea08000, 0x7d714000, 0xf20a2000, 0x2b013000, 0xa48f8800, 0xc64d0400, 0x6f24e200, 0x5abe9300,
+    0xa2e26280, 0x2d58d340, 0x2e36c2a0, 0x1ca3a310, 0x3e73ea98, 0x328ed74c, 0xa74aa08e, 0xfca0706d,
+    0x0e7da8bd, 0x7a803429, 0x934d6a7d, 0x8ea4979b, 0x65780073, 0xfe0c00df, 0x050600b6, 0xd98700dd,
+    0x63c68083, 0x4ae640a8, 0x0954a0bf, 0x443b701c, 0x7ba52808, 0x94fd74a7, 0xd9c14a47, 0x55e2a728,
+    0xdfd108ad, 0xe9774482, 0x900e4214, 0x580ee3ce,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xc8000000, 0x6c000000, 0x2a000000, 0xc9000000,
+    0x5c800000, 0x84c00000, 0x14e00000, 0x31300000, 0xbeb80000, 0xd7f40000, 0xcf5e0000, 0x30430000,
+    0xec278000, 0x67dd4000, 0xfa8de000, 0xd7c87000, 0x39673800, 0x9d7eb400, 0xa094da00, 0x7d2d1900,
+    0xff555a80, 0x484759c0, 0x38213a20, 0x39d16910, 0xbd8c62e8, 0x044aed7c, 0x022a60c2, 0x98d530b5,
+    0x5d0ad81e, 0x6286c4f1, 0xb3cbe2aa, 0xdf67ad10, 0xae7f805c, 0xfd19406b, 0xc96be099, 0x457f7082,
+    0xa49eb841, 0xeb20f4dd, 0xd45eba4d, 0xb1c8299e, 0xca67823c, 0x60f59d59, 0xc4d4d8bb, 0x0f05c471,
+    0x7f8c624a, 0xb14aed14, 0x1caa60f3, 0x691530c4,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0xa8000000, 0x3c000000, 0x52000000, 0x91000000,
+    0xee800000, 0xd8c00000, 0x73600000, 0xe3500000, 0x92d80000, 0x979c0000, 0x5bb60000, 0x79c70000,
+    0x65e58000, 0x0f924000, 0x9fb56000, 0x1fc97000, 0x8aef1800, 0x8a145400, 0x04fec200, 0x08ef2700,
+    0x53154280, 0x66766740, 0x3a2ba220, 0x25765750, 0x05a25a88, 0xb33e336c, 0xbe0360da, 0x8b0e70fd,
+    0xf38a98b4, 0xdc461465, 0xaeaba267, 0x10b65796, 0x6a425add, 0x09ae337d, 0x193b6074, 0x1b027085,
+    0x1b8498d7, 0x404d142e, 0xeca022c9, 0x29bf1753, 0xb8c4ba17, 0x836203ea, 0xfb5c98c9, 0x16d114f9,
+    0x519622ab, 0xa4b817a3, 0x54413a54, 0xc2a043c2,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x58000000, 0xbc000000, 0x46000000, 0x89000000,
+    0x16800000, 0x1bc00000, 0x15e00000, 0x3c500000, 0x2f980000, 0x7e7c0000, 0xc8220000, 0xe0b30000,
+    0xe54a8000, 0xda234000, 0xfbb42000, 0xa8c63000, 0x6c6c1800, 0xc81d8400, 0xff34ea00, 0xa8023700,
+    0x3400ca80, 0x42040740, 0xe30cd260, 0x818983d0, 0x38403838, 0x5ea7b46c, 0xbb7af2fe, 0x10acb3a5,
+    0x767ea088, 0x8c25706e, 0x6ab838a5, 0xe24bb43e, 0xa1a0f274, 0x0cf3b3e5, 0x69ee2034, 0xda59306b,
+    0xd69c98f4, 0xa0f1c4cf, 0x37e84a9b, 0x0f544712, 0x1612729b, 0x6a3cf30d, 0x888000d6, 0x6ec000e3,
+    0xa56000ef, 0x3e9000e8, 0x74f800e2, 0xe5ec0088,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x38000000, 0xd4000000, 0xc2000000, 0x15000000,
+    0x94800000, 0xabc00000, 0x92a00000, 0x17300000, 0xb8f80000, 0x705c0000, 0x56620000, 0x901b0000,
+    0xb2848000, 0xf4cbc000, 0x0f2ce000, 0x16713000, 0xba9fb800, 0x6243e400, 0x11ecfe00, 0xe05cb500,
+    0x2e601e80, 0xa41d8540, 0x6087a660, 0xd9c26150, 0x4fa95858, 0x7fb5d484, 0x3d35c61a, 0xe1ff91d1,
+    0x02dc80ee, 0x02a7c02a, 0x6f36e024, 0x8cf630b9, 0xa2593886, 0x7b632418, 0xd09c9ebe, 0xdb4a45e2,
+    0x73694648, 0x8c9851ff, 0x054a6019, 0x5861f035, 0x2317d867, 0x39091434, 0x2287a608, 0x8cc261c5,
+    0x3b2958e1, 0xc475d42c, 0x9795c6b6, 0x22cf9127,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x08000000, 0x34000000, 0x26000000, 0xe9000000,
+    0x2f800000, 0xf7400000, 0x46200000, 0x36b00000, 0x3df80000, 0xc9dc0000, 0x80620000, 0x231f0000,
+    0x0e038000, 0x2d02c000, 0xb18fa000, 0x724ab000, 0x73ae8800, 0x3af05c00, 0x9b538e00, 0xceaaed00,
+    0x237c2e80, 0x6d105d40, 0xc30aa660, 0xcc8c0110, 0xcbc32868, 0x35e5ec24, 0x6f5e86ce, 0x48a8718d,
+    0x7a780081, 0x1a9c006a, 0x084200af, 0x98af0078, 0x327b805c, 0xce9ec03c, 0x7e4da0dd, 0x79a5b035,
+    0x29f5089c, 0x1fde9c30, 0xd1662e79, 0x60935d4a, 0xe34b2671, 0x9021c1ec, 0x67b708b9, 0x7e719cb5,
+    0x249dae2d, 0x3d4d9dd0, 0xf5268664, 0x02347180,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x08000000, 0x0c000000, 0x22000000, 0xbf000000,
+    0x76800000, 0x84c00000, 0xaa600000, 0xa0d00000, 0x5a180000, 0x257c0000, 0x202a0000, 0x7a7f0000,
+    0xc6a38000, 0x96b94000, 0xb0cc6000, 0x1c601000, 0x59d83800, 0x6d911400, 0x423b2200, 0xcb09d700,
+    0xa08f4280, 0xadc5c740, 0x95e57ae0, 0xcb97d390, 0x9337d8e8, 0x4088449c, 0x3dcf7a4a, 0xfde8d363,
+    0x179458dc, 0xb9310477, 0xf3831a9e, 0x6948c34b, 0xc62c600e, 0xcb70104d, 0x9d203892, 0x03fd1490,
+    0xece922c2, 0xbc1ad741, 0x947ec25e, 0x7baf87af, 0xef389ae3, 0x9a8d83d6, 0x36ca00c9, 0x7d6f00fc,
+    0x0a5b806d, 0xf4d540db, 0x3c1e60bb, 0xd47310fc,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x98000000, 0xc4000000, 0x76000000, 0xf9000000,
+    0x56800000, 0xfbc00000, 0x56e00000, 0x1cd00000, 0xae180000, 0xd67c0000, 0x25660000, 0x43990000,
+    0x2c3c8000, 0x43cec000, 0xa2eca000, 0x52d9b000, 0xe31b1800, 0x6efc8400, 0xe3a29a00, 0x35faef00,
+    0x32283a80, 0xedba5f40, 0x938fa220, 0x8f481b30, 0x6a2198b8, 0xc9bb44f4, 0x358aba4e, 0x9e419f4d,
+    0x60a18238, 0x807a6b86, 0xec6ca056, 0x2d19b02e, 0x63fb1896, 0xfb2c8485, 0x833a9a33, 0xdc46efb4,
+    0x37ae3a07, 0x4bf35f94, 0x472b228b, 0xe13adb85, 0xbb4b382d, 0xc42bf441, 0x54b52267, 0x650fdbd1,
+    0x0489b803, 0xa4c03430, 0x1163021a, 0xed91ab28,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xd8000000, 0x84000000, 0x9a000000, 0x19000000,
+    0x14800000, 0x43c00000, 0x3a200000, 0x53b00000, 0xcb780000, 0x38dc0000, 0x06260000, 0x7db30000,
+    0xe0728000, 0xb954c000, 0x976a6000, 0x03537000, 0x1e6bf800, 0x6fd82400, 0x09a8b600, 0x17fbd500,
+    0xc71cd680, 0x5207a540, 0xa503ae20, 0x7a884190, 0xc8cbf8f8, 0x6ba82414, 0x1af0b6e2, 0x3197d54d,
+    0x5cc2d6d6, 0xc9a8a59e, 0xf7f72e94, 0xb71f8199, 0x5a0b181d, 0xf903943c, 0x648f2e15, 0x4bc3815b,
+    0x662d18fb, 0x4db09462, 0x487dae65, 0x3557411e, 0x516778d4, 0x0453e439, 0x89ee564d, 0x219365a4,
+    0x64c9ceb1, 0x3dab3151, 0x65f8009a, 0xf21c0022,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x28000000, 0x44000000, 0xaa000000, 0x57000000,
+    0x32800000, 0x69c00000, 0x0aa00000, 0x5e100000, 0x0ed80000, 0x0bf40000, 0xb9aa0000, 0x96950000,
+    0x88138000, 0x2fd5c000, 0x48766000, 0x2be69000, 0x127b7800, 0x94e70400, 0x84f27e00, 0x272cc700,
+    0xf1561e80, 0x0fbb57c0, 0x3c4ce660, 0x59e893b0, 0xe9717848, 0xa86204f4, 0x6039fe62, 0x600d0763,
+    0xb00a7e30, 0xc808c7ba, 0x34041ef2, 0x820a57d0, 0x130d667e, 0x988c53c8, 0x3ec6985f, 0x38205460,
+    0x37d5666f, 0x047853b8, 0x55ec9843, 0xb7755476, 0x9d66e6ae, 0x31bd938b, 0xb942f8f9, 0xc067c4b6,
+    0x04379e18, 0x5a0f9777, 0xbf0306a4, 0x968ec305,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x38000000, 0xcc000000, 0x62000000, 0x95000000,
+    0x28800000, 0x08c00000, 0x07e00000, 0xa2900000, 0x89580000, 0x2d340000, 0xc0ee0000, 0x47110000,
+    0xb5168000, 0x081c4000, 0x04976000, 0xea5ab000, 0xeeb77800, 0x5fad1400, 0x01ba1a00, 0x9e2feb00,
+    0xdbfb7a80, 0xb8005bc0, 0x0c0c8220, 0xc2040fb0, 0xe5017818, 0x1088147c, 0xc4c29afa, 0x65e2ab29,
+    0x379a9af2, 0xa1d6ab91, 0x25f49a6d, 0xc707ab8f, 0xe5821abe, 0x3c4bebfb, 0x252d7a34, 0xc4755b21,
+    0xad4c02ae, 0x5bad4f83, 0x57b69824, 0x052be4e6, 0x747a0203, 0x35484f13, 0xe7ae1857, 0x0db6a472,
+    0x5c23e225, 0x3efabf54, 0xa88000b9, 0xc8c000ca,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x78000000, 0xb4000000, 0x02000000, 0x23000000,
+    0x21800000, 0x0ec00000, 0xdb200000, 0x26b00000, 0x39f80000, 0xf4140000, 0x6d8e0000, 0x78c10000,
+    0x5a2a8000, 0x543bc000, 0x6eb4e000, 0x95fd5000, 0x921e0800, 0x4489c400, 0xb649c600, 0xc36ef500,
+    0xa0d7a680, 0x91a865c0, 0xa5fd4e20, 0x8a1cf1b0, 0x208a8058, 0xbc4bc004, 0x2c6ce0da, 0x375950e7,
+    0xbe68085b, 0x7c5cc499, 0x83ed46f8, 0xcc94350b, 0xb5c9c643, 0x5eaef51c, 0x8277a6af, 0x7dd8651a,
+    0x3d254e56, 0xcfb8f1dc, 0x577c8003, 0x1d5ec09e, 0xe168605d, 0x33d3906c, 0x682e68ca, 0x6f3e54ad,
+    0x2b31ae45, 0x9135a1e9, 0xa63a889d, 0xf5b304bc,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x28000000, 0xcc000000, 0xca000000, 0x93000000,
+    0x34800000, 0x0e400000, 0x2b600000, 0x8a500000, 0xd8980000, 0x51b40000, 0x6a8e0000, 0x73490000,
+    0x2ae78000, 0x19914000, 0x723ae000, 0x064d5000, 0xd760d800, 0xc85f9c00, 0x77949a00, 0x8730b700,
+    0x3bc9fa80, 0xa6aca7c0, 0x3df3c220, 0xdeee6b30, 0xa79f8008, 0x9f3540fc, 0x7fcce042, 0x50a050af,
+    0x4cf158d6, 0xb563dc51, 0x575ffad5, 0x2911a717, 0xea7a4247, 0x0c262b15, 0xeebae0b9, 0x040d5098,
+    0x9600d810, 0x210f9c07, 0xb38c9ad0, 0x14c4b733, 0xb027faf1, 0xccb5a78a, 0xfb0c42ed, 0x988b2bfd,
+    0x944b6060, 0x606110ce, 0x5ad3b881, 0xd0da8c72,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x88000000, 0x04000000, 0xaa000000, 0x79000000,
+    0x6f800000, 0x29400000, 0x84600000, 0xc6500000, 0x18980000, 0xa9bc0000, 0x1d820000, 0xac490000,
+    0x2de58000, 0x081bc000, 0xa87a6000, 0x54217000, 0x6db1c800, 0x1786fc00, 0x0543aa00, 0xfa607700,
+    0xad5c4a80, 0x821ac740, 0x4177e260, 0x63ad4b70, 0xf8fd80e8, 0xb5e7c074, 0x141860c2, 0x5e78704d,
+    0x3b2c484d, 0x5d313c54, 0x8643ca41, 0x14e40796, 0x87920273, 0xb132fb06, 0x5849a8e1, 0xafee8cb3,
+    0xa517e240, 0xd5fd4b39, 0x8865803a, 0x285bc0cd, 0x2b9a60cd, 0x8f31702b, 0xd349c8a3, 0x056afc52,
+    0xc5d9aaf3, 0xafd57779, 0x76dbca03, 0xc9580777,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x68000000, 0xcc000000, 0xe6000000, 0xcf000000,
+    0xaa800000, 0x6a400000, 0x54e00000, 0x71300000, 0xdff80000, 0xd25c0000, 0xa30e0000, 0x5c830000,
+    0xed418000, 0x626f4000, 0x95742000, 0x88165000, 0xefe43800, 0xd9b6fc00, 0x70b64200, 0x553b7900,
+    0xb5fbe280, 0xdb5e6940, 0x9685faa0, 0xf44dc510, 0x1fee00c8, 0xa1b300dc, 0xf4b980ae, 0x2f334053,
+    0xf4fa2024, 0x72d55069, 0xb045b818, 0x05e9bcd4, 0x90ba6221, 0x653129c9, 0xadf1daa8, 0x2f5b95bf,
+    0xd48a3831, 0x1145fcfc, 0x9c6fc293, 0xae78392a, 0x6099c299, 0x60a73970, 0x0ed642e6, 0x0e4b79b1,
+    0x1ee3e21c, 0x28326990, 0x8273faf6, 0xb692c5ca,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x38000000, 0x54000000, 0x5e000000, 0x89000000,
+    0x37800000, 0xe3400000, 0x9fa00000, 0x82f00000, 0x67380000, 0x385c0000, 0xf84a0000, 0x7b250000,
+    0xa9328000, 0xb959c000, 0xc3cf2000, 0xe26a3000, 0xd99f0800, 0xd1295c00, 0x2e367600, 0x2fd14100,
+    0x0b01d680, 0xac87b140, 0x47c37ee0, 0xd46d1d30, 0x2c9d20d8, 0xa4a33064, 0x767f8806, 0x04f99cad,
+    0x8a39d651, 0x09dbb13e, 0xb6097e76, 0xb5081da8, 0x158fa02f, 0x284af069, 0x1328a858, 0xd53face5,
+    0xfb54de50, 0x78cbed27, 0x56e7887b, 0xda559c0a, 0x134bd67b, 0xe7a2b19b, 0xb6f1fe74, 0x4934ddb2,
+    0x895200dd, 0x9bc900a8, 0xc660805c, 0xbf90c052,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xa8000000, 0xcc000000, 0xa2000000, 0x87000000,
+    0x10800000, 0x7a400000, 0x9da00000, 0x67700000, 0xa6b80000, 0x43dc0000, 0x1c4e0000, 0xe4ab0000,
+    0xdaf58000, 0x57734000, 0xbeb06000, 0xd7d09000, 0x0a405800, 0xa5af5c00, 0x23763600, 0x18b16900,
+    0xceddd680, 0x47c9b940, 0x2c606ea0, 0xba193590, 0xbd286008, 0x053c905c, 0xaf16582a, 0x32a85c9b,
+    0xfbf5b61a, 0x5ef52931, 0x897636af, 0xc3b169da, 0xf45dd68b, 0xa689b9ce, 0xab406e2f, 0xec29359c,
+    0x34b060ca, 0xdcd0902b, 0x98c05842, 0x88ef5c85, 0x065636e9, 0xc9816973, 0x57c5d69e, 0xe465b9f3,
+    0x86166e70, 0x672e35d0, 0xe633e034, 0xd194d02e,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xc8000000, 0xa4000000, 0x1e000000, 0x99000000,
+    0xab800000, 0xd1400000, 0xfce00000, 0xa9f00000, 0xd6380000, 0x521c0000, 0x0d420000, 0x1ee70000,
+    0xa2ff8000, 0x8eb3c000, 0x67db2000, 0xb6603000, 0x2f35c800, 0x6999bc00, 0xf40fa200, 0x96056500,
+    0xdd090280, 0x65819540, 0x80406a60, 0xf367d990, 0x66bb20a8, 0xb3d03034, 0x506dc836, 0x0a35bced,
+    0xb015a27d, 0x064e65ec, 0x466c82c9, 0x973955a1, 0x359ecae1, 0x560f29ba, 0x7d08488f, 0x558d7cd1,
+    0x984b024f, 0x9f66952f, 0xdcbfea35, 0x34d4198a, 0x62e000c3, 0x70f00020, 0x9db80015, 0x535c0017,
+    0x39a200f7, 0x13170042, 0x6ac78065, 0x45afc08a,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x88000000, 0x94000000, 0xe2000000, 0xa7000000,
+    0x04800000, 0xaf400000, 0xd1600000, 0xc3f00000, 0xad380000, 0xb0940000, 0x29c60000, 0x89250000,
+    0x0e118000, 0x6d82c000, 0x32c3e000, 0x8baf1000, 0x4c5f1800, 0x3b604c00, 0x30f2ea00, 0x6bbffd00,
+    0x08de8a80, 0x74232dc0, 0x7195f2a0, 0x904bb170, 0xb9ea6028, 0xb2b9d0e4, 0x1d5af84a, 0xbaea5c83,
+    0x1c3c726e, 0xa11d719c, 0x3d0f80b7, 0x5f83c00b, 0x4dcc6058, 0x032cd06c, 0xad1378dd, 0xb30c9c6e,
+    0x26819268, 0x284361f3, 0x65e71891, 0xe4b44c00, 0xe854ea81, 0x916afdae, 0x23f70aeb, 0x3d35ed3f,
+    0x8890121f, 0x35c1a17a, 0xff24f86b, 0x4b1b5ca9,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x68000000, 0x84000000, 0xda000000, 0xe3000000,
+    0xfc800000, 0x37400000, 0xcce00000, 0x1f900000, 0xc5580000, 0x85740000, 0x17ca0000, 0x0da90000,
+    0x8e738000, 0xaf4dc000, 0x00ef6000, 0x1195d000, 0x245f5800, 0x76f00c00, 0x82023200, 0xcf0ae300,
+    0x428cd280, 0xbe4ff3c0, 0xbb656a60, 0x505eef70, 0xe4fce008, 0xb50810f4, 0x31883852, 0x7ac1dcd7,
+    0xd02f6a4e, 0xaab7ef50, 0x466f606a, 0xb5d5d00b, 0x9c3f5815, 0x6a200c1d, 0x39ba3276, 0x32eee313,
+    0xb69ed206, 0xe2d2f304, 0x12bcea05, 0xda6a2ff4, 0x63d8006e, 0x91340023, 0xc7aa0031, 0x957900fa,
+    0xefcb80bd, 0xb1a9c05a, 0x087d601f, 0x7a48d045,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x68000000, 0x4c000000, 0x7a000000, 0x69000000,
+    0x36800000, 0x81400000, 0x2f200000, 0x42b00000, 0xcd980000, 0xbf240000, 0x3abe0000, 0x39950000,
+    0x112d8000, 0x0dbc4000, 0x50166000, 0xb5ecb000, 0xfc59b800, 0x8a4d1c00, 0x94ae5600, 0xe3736100,
+    0x80f7ee80, 0xe53e7dc0, 0x4e59b8a0, 0x9f4d1c30, 0x902e5648, 0xb73361bc, 0x8b57ee92, 0x03ce7de5,
+    0xe061b8ec, 0x8a991cd8, 0x7ea85651, 0xf272617f, 0x427c6e70, 0xca733d18, 0x567c581b, 0xb478ec5e,
+    0xf9740e7a, 0xf9fa8d4b, 0x6bb06031, 0x1b1db0a0, 0xee6a3837, 0x8d945ca1, 0x5f2db632, 0xeab791d2,
+    0xa19e364d, 0x352ed1ea, 0x3bbdd603, 0x431a2172,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x68000000, 0x1c000000, 0x8e000000, 0x75000000,
+    0xbb800000, 0xa0400000, 0x56600000, 0x84b00000, 0x3ad80000, 0x62640000, 0xf6be0000, 0xd1d90000,
+    0xf4e18000, 0x99794000, 0xb5f02000, 0xdd3e3000, 0xe99da800, 0xf68d9c00, 0xbfcb5200, 0x1c278500,
+    0x6dd6fa80, 0x8aea19c0, 0xa47da8a0, 0x227d9cf0, 0x9b735248, 0xd6f3852c, 0x47b0fa66, 0x505719a9,
+    0x93a22895, 0xbf9ddc25, 0x3782f2a5, 0xb644f508, 0x7765720a, 0x9d30b54f, 0x0992d259, 0xe68ac596,
+    0xe7c0da27, 0x68292980, 0xffdf806b, 0x71e0403b, 0x6af1a0ed, 0x39b77061, 0x6d55885f, 0x0427acef,
+    0xf9d0faca, 0x08e719e1, 0x077a2834, 0x98f9dcd4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x68000000, 0x84000000, 0x82000000, 0xe1000000,
+    0xce800000, 0x8fc00000, 0xf7a00000, 0x80500000, 0xfff80000, 0xcba40000, 0x2e560000, 0xb8f70000,
+    0x36278000, 0xe618c000, 0x8a176000, 0x4c11b000, 0x8f123800, 0xf09f7400, 0xd9dc3600, 0x25b04700,
+    0xd04e0e80, 0x4eef33c0, 0xb4323820, 0xde0f7490, 0xbf0436c8, 0xb18447d4, 0x9e400e6a, 0x39ec33a5,
+    0xd1bbb86c, 0xaa44b4fe, 0xe3e2d6f1, 0x2cba37db, 0x5ac2d662, 0xe32a37ee, 0xf29ad6bd, 0xf8de378d,
+    0x4b34d669, 0x0f8d3745, 0xd145567e, 0xb062f714, 0xa3f5b6d0, 0x95ab87b5, 0x5150eebd, 0xa9754371,
+    0xf866e0b9, 0xb7fe70f0, 0xdfa2d8c3, 0x64560465,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0x38000000, 0x54000000, 0xe2000000, 0x8f000000,
+    0x5e800000, 0x02c00000, 0x7d200000, 0xce100000, 0x87380000, 0x9b2c0000, 0xdb1e0000, 0xa2bf0000,
+    0xf5628000, 0x13f8c000, 0x1b046000, 0x5c8c5000, 0xfdc1d800, 0xcba3a400, 0xa0d2ae00, 0x4c1b6b00,
+    0x38337680, 0x8da8cf40, 0x65d9d820, 0x519fa490, 0x0274ae98, 0xa7486b84, 0x536ff65a, 0xa6ff0f9b,
+    0xee87389c, 0x0ac7341d, 0x612f16bb, 0x90189f48, 0x863e00a0, 0xfcaf00ce, 0xea5a8040, 0x0cd4c064,
+    0x1a1a6035, 0x25335029, 0xb42358f6, 0x559b6495, 0x9876cee4, 0xdc473bac, 0x3feaae8e, 0x13376bb8,
+    0x992d76d1, 0x2417cf85, 0x143b5878, 0x9ba7642d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xf8000000, 0x64000000, 0xc2000000, 0x91000000,
+    0x3f800000, 0x01400000, 0x45a00000, 0xf3500000, 0x64f80000, 0x4ea40000, 0x69d20000, 0xf0310000,
+    0x774c8000, 0xbeabc000, 0x51d36000, 0x7435b000, 0xc5472800, 0xd7ab8c00, 0x0a587e00, 0xb7710700,
+    0x11e75680, 0x1b7e8bc0, 0x6fed2860, 0xbc7e8cb0, 0x8b66fe18, 0x8fbbc714, 0x5600b6ba, 0x6b04fb35,
+    0x2a8b609d, 0x8cc1b020, 0x2ded2862, 0xed7e8ce6, 0x54e6fe1b, 0xfefbc748, 0xeba0b670, 0xfc54fbee,
+    0x8c736004, 0x5365b00c, 0x7bbf281f, 0x1c0f8c63, 0x660a7ea3, 0xb30007e8, 0xde8bd6ac, 0xc6c54b7e,
+    0x20e64827, 0x74ff3c24, 0x06abd6d3, 0x15d54bad,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x38000000, 0x54000000, 0xbe000000, 0xc9000000,
+    0x2d800000, 0x33c00000, 0xa5600000, 0x29300000, 0x25580000, 0xb1ec0000, 0x4f7e0000, 0x47f50000,
+    0xf3b88000, 0xb210c000, 0xaacfa000, 0x80edf000, 0xd6f58800, 0x1a33c400, 0xd7d4da00, 0xdaae2d00,
+    0x565f5280, 0xe368e940, 0x54330820, 0x66d60430, 0x0323fa98, 0xab931d24, 0xb7057a06, 0x0486dddd,
+    0xce4a5ab3, 0xdea7edca, 0xe0567290, 0xe660d97e, 0xe3b1a026, 0x1a18f035, 0x16cd0861, 0x22e30428,
+    0xcdfb7ab2, 0x14b3dd72, 0xbe92daca, 0xa8872d10, 0xc441d2be, 0x79a12946, 0x4cda2825, 0x942e34c9,
+    0x9f195294, 0xe141e910, 0xfe2d88e9, 0xa81fc404,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x18000000, 0x84000000, 0xc2000000, 0xf3000000,
+    0x1d800000, 0x0fc00000, 0xffe00000, 0x80700000, 0xc4980000, 0x156c0000, 0x103e0000, 0x67b10000,
+    0x477e8000, 0x431fc000, 0xcda16000, 0x005f9000, 0x03062800, 0xe583c400, 0x1bcfd200, 0x15ee7100,
+    0xef77fa80, 0x9f1cb540, 0x2ba6a820, 0x015d0470, 0xf58832b8, 0x73c221b4, 0x29eeb25a, 0x9971e137,
+    0xb611d2ff, 0x412f718c, 0xd1117ada, 0xf6af757b, 0x11d9c8c1, 0x62c39492, 0xb7689ab3, 0x3332253a,
+    0xf23e00ef, 0xe4b1009c, 0xe2fe80ce, 0xf8dfc01a, 0xe84160e0, 0xf72f909c, 0x181e28b6, 0x0c2fc4b2,
+    0xe991d2b7, 0xfdef71b4, 0x93717a22, 0x491f759f,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xb8000000, 0x24000000, 0x8a000000, 0xa9000000,
+    0x97800000, 0xba400000, 0x9d200000, 0x8b500000, 0xfb780000, 0x1da40000, 0xad920000, 0x2e9d0000,
+    0x80148000, 0x45d3c000, 0x4ebbe000, 0x4ec8b000, 0x39e32800, 0x6bb6bc00, 0x5f4a8600, 0x6cafef00,
+    0x6e1bae80, 0x46d453c0, 0x203da860, 0x6b0c7c70, 0x828fe658, 0xd3cd9f94, 0x0c65e6b2, 0xb4f49f4d,
+    0xf3e3667d, 0xe2ba5f63, 0x98cc06d2, 0xdee12f65, 0x6f34ce34, 0x638f236b, 0xf8456093, 0x48227074,
+    0x02de48f5, 0xda30cc67, 0x9a06ce0d, 0x810223aa, 0x5b89e021, 0x1c45b04e, 0x222fa85a, 0x1bd17cf0,
+    0xf5bb668c, 0x044e5fbd, 0x962606e5, 0x79d82f17,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x78000000, 0x8c000000, 0xca000000, 0x21000000,
+    0xde800000, 0x30c00000, 0xf5a00000, 0x15300000, 0x25980000, 0x70240000, 0x327a0000, 0xaa7b0000,
+    0xb6768000, 0x647d4000, 0x99736000, 0xf5fe3000, 0x6837b800, 0x891a0400, 0x8deaa600, 0x835d1100,
+    0xd6871e80, 0x34cc15c0, 0xfba338e0, 0x5a384490, 0xe4154618, 0xf96561dc, 0xc2954632, 0x85a5616d,
+    0x9d3546f4, 0xe1956181, 0x1e2d46b3, 0x2d716139, 0x13f74682, 0xb33a6158, 0x5e99c69b, 0x97a321d7,
+    0x0030a6fd, 0xdd1611d6, 0xfbe99e73, 0x405555f9, 0xdd0a589f, 0x9c8d74be, 0xd5cc7ebd, 0x4526256c,
+    0x3af6807a, 0x69bd40b4, 0x605360fc, 0x2d0e30f8,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xb8000000, 0x5c000000, 0x12000000, 0xd7000000,
+    0xdd800000, 0x07400000, 0xc2600000, 0xae900000, 0x49f80000, 0xfde40000, 0xb6da0000, 0x02130000,
+    0x72328000, 0x1c4c4000, 0x15e3e000, 0x92dcd000, 0x2c1be800, 0x6736cc00, 0xaec7c200, 0x93204700,
+    0x45fe2a80, 0x97e18bc0, 0x5dd168e0, 0x1d9e8c10, 0x1a7e22d8, 0x5faf978c, 0xae37422a, 0xce4b074b,
+    0xa2ed4a2f, 0x9f561bc0, 0x9359e047, 0xf95fd0e5, 0x12516841, 0x0dde8c08, 0x659e2288, 0x267f9773,
+    0x9daf4249, 0xc13f07a0, 0x4fcf4ad1, 0xb7a11bca, 0x8a31605c, 0xe04090e8, 0xb7e008c5, 0x2dde1c4c,
+    0x159e2a4d, 0x2e718b04, 0xa9a968de, 0x373a8cdd,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0xf8000000, 0x84000000, 0x7e000000, 0x5d000000,
+    0x74800000, 0xc0c00000, 0x44200000, 0xa2d00000, 0x15f80000, 0x26ac0000, 0xe31a0000, 0x6f530000,
+    0xcfb68000, 0xe745c000, 0xa560a000, 0x9eb6b000, 0x61c85800, 0x8aaf6400, 0xa11c3e00, 0x28561700,
+    0xc8366680, 0xf6067340, 0xd106d860, 0xc686a410, 0xcfc69e18, 0x6fa3a7d4, 0xb190be06, 0xc690d799,
+    0x2918466a, 0xa459038d, 0x7a3420a8, 0xf90c70f6, 0xfa847837, 0xd5cf140d, 0x1ca24604, 0x601a0350,
+    0xf6daa0e2, 0xd3f5b0f7, 0x1fa6d8af, 0xb996a46d, 0x0a9e9e8e, 0x7b1fa77b, 0xfb52be07, 0xa9bfd711,
+    0x6e4cc6f4, 0x57e3c318, 0x877800ce, 0x2f6c00f5,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x78000000, 0xf4000000, 0xbe000000, 0x37000000,
+    0x71800000, 0xffc00000, 0xa3a00000, 0xd6d00000, 0x8c780000, 0x942c0000, 0xaa160000, 0xc0530000,
+    0x1a328000, 0x8c46c000, 0x896ba000, 0x21f41000, 0x39eda800, 0xc4bfc400, 0x06081600, 0xa305df00,
+    0x7f8bbe80, 0x20c51b40, 0x5e2728a0, 0x631504d0, 0xa6d5b658, 0x0472cf64, 0xa82c9646, 0x08101f83,
+    0xc5529e6f, 0x16b7cb18, 0x8b01200a, 0x938ed00d, 0xdac808c9, 0x3f24d411, 0xa9993e91, 0x6293db68,
+    0xd114885c, 0x9bdd1412, 0xdcf61eed, 0x81620b80, 0x5df800cd, 0xbbec00d3, 0x51b6009d, 0x72830018,
+    0x504a8049, 0xdb6ac0e5, 0xecfda096, 0x296710cb,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x18000000, 0x54000000, 0x4e000000, 0x25000000,
+    0x65800000, 0x8bc00000, 0x25e00000, 0x58700000, 0x1a980000, 0xf8640000, 0x37be0000, 0x497d0000,
+    0xa1128000, 0x1ea2c000, 0x4bd8a000, 0x98ca3000, 0xa16bc800, 0xb03cc400, 0xadb61200, 0xe272b100,
+    0xc19bda80, 0x80e775c0, 0xfdf948a0, 0xb15e04b0, 0xad8eb238, 0x77c88124, 0x07e812d6, 0x177fb1b1,
+    0x0c115a8b, 0x6721b51e, 0xb21fe8f8, 0x9a293437, 0xa397fa49, 0x2fe685a1, 0xbb7ea09e, 0x4613308b,
+    0x642748b8, 0xbe9304d1, 0x6e6432a8, 0x66be41f9, 0x12f6b22c, 0x17dc8124, 0x0ace1245, 0xd666b1c6,
+    0x82bdda2c, 0x64fe7511, 0x16d5c89b, 0x3941c43e,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x88000000, 0xc4000000, 0x82000000, 0x8b000000,
+    0x55800000, 0x40c00000, 0xc7a00000, 0x9f300000, 0x6d180000, 0x22e40000, 0x505e0000, 0xd18d0000,
+    0x22c38000, 0x1ca6c000, 0x32b36000, 0x61d01000, 0xa345a800, 0xc6677400, 0x6215c600, 0x15675100,
+    0xcb93ee80, 0xbba6e5c0, 0xb13548a0, 0xf011a4b0, 0x86630ea8, 0x821035b4, 0x4563808a, 0x3396c08f,
+    0xf7ab60f7, 0xf73410bb, 0xf91ba81a, 0x58ea741b, 0x97564628, 0xc2019136, 0x6b008ee8, 0x0586f573,
+    0xb8c8e015, 0x8ba2d0e2, 0xd930c8d5, 0x641e64eb, 0xfc6deecb, 0x451be539, 0x56eec8b4, 0x7a5364c4,
+    0xee8e6e29, 0x7d4d2560, 0x1365a87b, 0x22977434,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xd8000000, 0xbc000000, 0x8a000000, 0x5b000000,
+    0x86800000, 0x19400000, 0x48200000, 0x8fd00000, 0x3d780000, 0xc1ec0000, 0xbfbe0000, 0x5f0d0000,
+    0xa0868000, 0x404c4000, 0x01ac6000, 0xfe109000, 0x011e1800, 0x519d5c00, 0xc9d6a600, 0x747d1700,
+    0x006e3e80, 0x5a7c0b40, 0x8d6cf820, 0x5dfd8cb0, 0x61a2de78, 0xee11db4c, 0x891e00d2, 0xc59d00a7,
+    0x27de80ac, 0x197040b2, 0x57ea6016, 0x9eb1902a, 0x5a8698ff, 0x834c1c15, 0x9b244604, 0x9d5dc787,
+    0xca3a46d7, 0xe4c0c7d0, 0x67e4c6bc, 0xa6b087cf, 0xb68ea647, 0x214117fc, 0xa4283e1a, 0x2ddd0b32,
+    0x0274783c, 0x716ccc9e, 0x77f03eb0, 0xcaa10bd7,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0xa8000000, 0xcc000000, 0xa2000000, 0xd1000000,
+    0xed800000, 0xbf400000, 0xac600000, 0xcf500000, 0x6cb80000, 0x6da40000, 0x50fa0000, 0x95490000,
+    0x61618000, 0x38d04000, 0x16f9e000, 0xea49b000, 0x9fe50800, 0xcb1e0c00, 0x4059a200, 0xfa3aab00,
+    0x32652a80, 0xd450e7c0, 0xfc3f68e0, 0xed6afcb0, 0x3adecac8, 0xd7f457bc, 0xdfc1e08a, 0x44adb0dd,
+    0x097f082f, 0xfc070c1e, 0xaa0022e9, 0x0d0eebbc, 0x9786cae2, 0x0a4057b3, 0x2fe3e031, 0x0310b0f7,
+    0xfc5c8855, 0xf03a4cde, 0x2f624211, 0x9bde1ba2, 0x927ba216, 0xac87ab1a, 0x4ac6aa1f, 0x172da7c9,
+    0x7d3d08ec, 0x78ea0c1a, 0xf19ba207, 0xcd97ab67,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xd8000000, 0x5c000000, 0x1a000000, 0x45000000,
+    0xd9800000, 0xa0c00000, 0xc4e00000, 0x66300000, 0x39580000, 0xa6ac0000, 0xf8920000, 0x8dcb0000,
+    0x896c8000, 0x38fe4000, 0xaeb96000, 0x9e147000, 0xa1860800, 0x8cc13400, 0x36ec9200, 0xf73af500,
+    0x7ede1a80, 0x05698140, 0xbaf9e8e0, 0xd7bc0410, 0x0d957ab8, 0x9c46f10c, 0xcdab6042, 0xea1f7059,
+    0x4f8a88a3, 0x67cf74b5, 0x646df2c5, 0x5572859a, 0xc0721267, 0x81ffb5c5, 0x1d33faf8, 0x93dfb19b,
+    0x68ec808d, 0xd43e409f, 0xc85960bc, 0xb1247049, 0x835e08ec, 0x93ad3426, 0xc91e9219, 0xf901f5c0,
+    0xd38a9a42, 0x5dcbc14c, 0x116a8880, 0x04ff7403,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xa8000000, 0x34000000, 0xb2000000, 0x67000000,
+    0x36800000, 0x03c00000, 0xd4200000, 0xcf900000, 0x87380000, 0xd06c0000, 0x63f60000, 0x72cd0000,
+    0x8fa98000, 0x51dbc000, 0x38576000, 0x811bd000, 0x28f85800, 0x0a417400, 0x3d608e00, 0xbe7e1300,
+    0x39895680, 0x6148a740, 0x35e8b8a0, 0xa4b06430, 0xe2283688, 0xd29e7744, 0xb2b9609a, 0x8f2ad013,
+    0x4f1fd8a4, 0xd9fbb414, 0x11c86e4a, 0x832303f8, 0x7117ee61, 0xc0f5c338, 0x1e490e72, 0xff65d391,
+    0x717e3630, 0x3b0377e8, 0xd088e08a, 0x86cd1063, 0x5da6b87f, 0x66d164d9, 0xd6d7b662, 0x1ed8b77a,
+    0x7adf806b, 0x10d6c065, 0xebdee002, 0x5b5010d1,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x88000000, 0x34000000, 0x9e000000, 0x75000000,
+    0x56800000, 0x78400000, 0x3f200000, 0x3f900000, 0x54380000, 0x3ce40000, 0xc2760000, 0x9ec10000,
+    0x0f678000, 0x94b1c000, 0xc5a1a000, 0x95da3000, 0x9c137800, 0xbafc0c00, 0xdb85a600, 0xf2c32300,
+    0x7d695e80, 0x53baefc0, 0xf4235820, 0xec16fcf0, 0x52f0fe28, 0x7f84df04, 0xd4c62096, 0xb46bf081,
+    0xaf32d868, 0x67663c3d, 0xf0b6dee1, 0x43af2f73, 0x6cd4f875, 0xe89dccb6, 0x1dbc0660, 0x592d13ea,
+    0x969426fa, 0x38b3e3c1, 0x17af7e20, 0x62d11fb8, 0x25918065, 0xf730c00d, 0x8b6620c1, 0x42bbf092,
+    0x24aad877, 0x6d523c31, 0x1958de20, 0xe75a2fa9,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x58000000, 0xdc000000, 0x26000000, 0x55000000,
+    0x6d800000, 0x16c00000, 0xebe00000, 0xa3300000, 0x50580000, 0x782c0000, 0x2d5e0000, 0x71a90000,
+    0xd19a8000, 0x014a4000, 0xce20a000, 0xd058b000, 0x382d4800, 0x8d545400, 0xc1a54200, 0x899f3500,
+    0xdd4c8a80, 0xe8282140, 0x8553e820, 0x55a5e4f0, 0x9b928af8, 0x2a41216c, 0x2aa968fe, 0x8d1fa4c9,
+    0x900a2aeb, 0xa80591f3, 0x2402205e, 0x4a0ef029, 0x2b0be83d, 0xe489e43e, 0x5d4c8a48, 0xa82821c3,
+    0x2553e8b1, 0xe5a5e4c6, 0xc3928a82, 0xf641210f, 0x0ca968e8, 0xd81fa413, 0xfd8a2afe, 0xbec59198,
+    0xcfe2204a, 0xe93ef04a, 0x7b53e8dc, 0x9ca5e477,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xf8000000, 0x8c000000, 0xf6000000, 0x33000000,
+    0x0d800000, 0x45c00000, 0xa4200000, 0x82d00000, 0x28780000, 0x5aec0000, 0x3f760000, 0x05610000,
+    0x5bb08000, 0xadcd4000, 0x2028a000, 0x20d1d000, 0xd1782800, 0x6a686400, 0xc833ee00, 0x1f0cd700,
+    0x0b8d4680, 0xaec8f340, 0x95a688a0, 0xc918b4f0, 0x335b46d8, 0x23b9f33c, 0x61ce088e, 0xf629f4ff,
+    0xa3dde6db, 0x24f523c6, 0xa3a8a0d1, 0x9a11d00b, 0xaed8285a, 0x2e78645b, 0xb1ebeee2, 0x0ef0d796,
+    0x4ea346b6, 0x4095f359, 0xd49808fe, 0x7e98f462, 0xd39566b2, 0x291463f1, 0xe35600e4, 0x4bb1007d,
+    0xa5c88007, 0x74214052, 0xeadea045, 0xec70d0eb,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x98000000, 0x54000000, 0x82000000, 0x95000000,
+    0x39800000, 0x9fc00000, 0x11e00000, 0xc4d00000, 0xf2f80000, 0x23c40000, 0xafe60000, 0x2fd30000,
+    0x80788000, 0x5e88c000, 0x5b4b6000, 0x4ba27000, 0x94fa4800, 0xfccea400, 0x6f698200, 0xcf962300,
+    0x8158aa80, 0x733af7c0, 0x492b6020, 0x81b27050, 0xf4624838, 0xd51aa4c4, 0x0017821a, 0x599123c1,
+    0xf65e2a3b, 0xefb537ca, 0xe7668008, 0xc39fc00b, 0xf755e0db, 0xf439b023, 0x7da9a847, 0x73f414cd,
+    0xf840aa94, 0x952ef77b, 0x0fb56073, 0x1765708e, 0x6b9cc88c, 0xab5164c0, 0xba3c623e, 0x3eaf936b,
+    0x5d7102c3, 0xcb0ee398, 0x228bca0a, 0x454c8728,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x08000000, 0x74000000, 0x9e000000, 0x85000000,
+    0x6b800000, 0x3a400000, 0xd1a00000, 0x36500000, 0x25b80000, 0xfe4c0000, 0xe7aa0000, 0x57590000,
+    0xa83f8000, 0x3d0bc000, 0xb784a000, 0x4048b000, 0xb2a96800, 0xa4d2dc00, 0x9e781e00, 0x0ea3cf00,
+    0xeed5d680, 0x1579a340, 0x9824a060, 0xc318b030, 0x149168e8, 0x64dedc04, 0x3e721e96, 0x9eaacff1,
+    0x96d25675, 0x697e63ff, 0x722a00da, 0xd819003c, 0xfa1f801c, 0x351bc0cc, 0xd59ca054, 0x7954b058,
+    0x853b68ba, 0xb287dcd5, 0x6bcd9ea5, 0x68e10f71, 0x0576f671, 0xa026d36c, 0x5f1b688e, 0x8e97dc33,
+    0x77d59ed7, 0xa4fd0f75, 0x5164f67f, 0xf833d31a,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x38000000, 0x64000000, 0xea000000, 0x33000000,
+    0xbd800000, 0x94400000, 0xf3e00000, 0x3c300000, 0x8f980000, 0xa24c0000, 0x0eea0000, 0xb8b70000,
+    0x05528000, 0x4069c000, 0x3172e000, 0x12f29000, 0x27be6800, 0xded55c00, 0xb1259a00, 0x5211ed00,
+    0xe6091280, 0x25062140, 0x708ae060, 0x58ce90d0, 0xb52c68d8, 0x681e5cf4, 0x0d051ad2, 0x6c832d57,
+    0x36c372d7, 0xfc2a71e7, 0xde94e82e, 0x40c09c78, 0xe1257aa4, 0xca187d6a, 0xf20ffad3, 0x670dbd0d,
+    0x1f8f1a3c, 0x6b442d8f, 0xf869f222, 0x157fb14e, 0x18f408d8, 0x84b90cd5, 0x5b5b9298, 0x416fe1c5,
+    0x4bf80040, 0xe93c0030, 0x17120069, 0x468b0086,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xd8000000, 0x14000000, 0x76000000, 0x0f000000,
+    0x08800000, 0xc1c00000, 0x33e00000, 0xe3700000, 0xc7580000, 0xe8c40000, 0x3c6e0000, 0xf6310000,
+    0xbf768000, 0x8d58c000, 0x7dc02000, 0x69ea9000, 0x9e7cf800, 0x5edb5400, 0xe2808200, 0xa4c5d900,
+    0x9e645a80, 0xaf301dc0, 0x88f620a0, 0x9c1f90f0, 0x9be478f8, 0xcf729424, 0xb55622ae, 0x79c7891b,
+    0xf7e082fe, 0x5d75d90e, 0x345c5a9b, 0x5a441dd2, 0x90a0208c, 0xb45a90ef, 0x9a44f875, 0xb0af5435,
+    0x84568225, 0x4240d9a1, 0xa4a4da0f, 0xf25ddd09, 0x4d4e80bf, 0xac2cc00d, 0x3396204c, 0x7eaf9023,
+    0x4f5c7896, 0xf4c69483, 0x96602296, 0x733289ef,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x38000000, 0x1c000000, 0x36000000, 0x67000000,
+    0x2c800000, 0x1c400000, 0x02600000, 0x7cd00000, 0xecf80000, 0x29440000, 0xc3ea0000, 0x6d170000,
+    0x915a8000, 0x43364000, 0x67e0a000, 0x871e1000, 0xa0583800, 0x18b44c00, 0x6f22c200, 0x85383b00,
+    0xe8e25a80, 0xef9667c0, 0x8612a0e0, 0x73dd10d0, 0x4470b858, 0x6f010c0c, 0xb88ae20e, 0x7e436b7b,
+    0x676a429a, 0x255d7bbb, 0x91327ace, 0x4ae937b0, 0xea90b836, 0xcf910c99, 0x3612e241, 0x3bd76b2f,
+    0x707842f0, 0x7d0e7b89, 0xf582fa0e, 0x03c87713, 0x30aa98dd, 0x17f95c1a, 0xf3ca7a8c, 0xd8ad37ce,
+    0x53fab89b, 0xc9c60cca, 0xb1a86223, 0x04712b22,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0x18000000, 0xd4000000, 0x1e000000, 0xf5000000,
+    0x6b800000, 0xdc400000, 0x83200000, 0x1ff00000, 0xea980000, 0xad4c0000, 0xaeae0000, 0x42b70000,
+    0xcc348000, 0x83f0c000, 0x789aa000, 0x3245b000, 0xfe2b6800, 0x1870bc00, 0x2cddd600, 0xdd67bd00,
+    0xfbd41e80, 0x01eeb140, 0xcb14a020, 0xda02b090, 0x1307e8b8, 0x3a8c7c04, 0x61c97606, 0x66650d21,
+    0x3d53f6f5, 0xc222cd69, 0x5a7dd6c8, 0xabd7bd53, 0x59ec1e51, 0xbf12b1f6, 0x1402a062, 0xfe09b05e,
+    0x850568af, 0xa387bcec, 0x1049565a, 0x49277d53, 0xf4f6be1a, 0x741701f1, 0x1a89c85e, 0xf1c90c79,
+    0xde60be64, 0x395c01ab, 0xc42b4846, 0x7b72cce4,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x88000000, 0xdc000000, 0x1a000000, 0x77000000,
+    0xa3800000, 0xc4c00000, 0xd9600000, 0x14300000, 0x87980000, 0xdbcc0000, 0xd6e20000, 0x22f50000,
+    0xc5f38000, 0xfe794000, 0x3eb66000, 0x99dfd000, 0xb4ecc800, 0x21f23400, 0xf0783a00, 0xefb04500,
+    0x675a9280, 0x2ca1a140, 0x43d460e0, 0xe3ead070, 0xd27f48e8, 0x8cbb74ec, 0x62d65a92, 0xb56395ab,
+    0xc6345a39, 0x4c9695f3, 0x5247da9a, 0xed2fd5a0, 0x8411ba36, 0x0f000563, 0xd7857223, 0x52ce3122,
+    0x0c6748c2, 0x94b774c3, 0x26d45a73, 0xab66952c, 0xcf3fda65, 0xd613d558, 0x840bba95, 0x3e0905e0,
+    0x590cf211, 0x228e71b8, 0x8240a825, 0x1524e49e,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xc8000000, 0xe4000000, 0xc2000000, 0x5d000000,
+    0x54800000, 0xcac00000, 0x76e00000, 0xf1700000, 0x80580000, 0x8b440000, 0xccae0000, 0xaf5d0000,
+    0xaacb8000, 0x66eec000, 0xe9706000, 0x5c5ef000, 0x65481800, 0xb7a9a400, 0x64dae200, 0x69862900,
+    0x8e4c9a80, 0xa42c7dc0, 0x5b95e0a0, 0x9f2d3030, 0x7013f8e8, 0x8c699414, 0xa0ba9a0a, 0xed357db9,
+    0x81706016, 0x885ef057, 0x4f481802, 0xfea9a4cb, 0x3a5ae23e, 0x1a46299e, 0x6e2c9a0e, 0xc29c7db9,
+    0xf9ade092, 0x2fd93033, 0x4a05f8dd, 0x590094df, 0x46871a06, 0xafc2bdd9, 0x0e6580db, 0x1db3c026,
+    0x69bbe042, 0x73b03060, 0xd2b87802, 0x983754f9,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xa8000000, 0x84000000, 0x2e000000, 0x5b000000,
+    0x69800000, 0xcec00000, 0x4c200000, 0x3f100000, 0x0cf80000, 0x83440000, 0x7cee0000, 0xa0330000,
+    0xce638000, 0xc77ec000, 0x6c80a000, 0xa44a5000, 0xf76ba800, 0xaff33400, 0x26c84e00, 0x2826c300,
+    0x21154680, 0xeff8a7c0, 0xc6c320a0, 0x182490d0, 0x99130888, 0xc3fd6494, 0x6ccde686, 0x6d26f7df,
+    0xab9e88c7, 0x64b0a455, 0xee2ec605, 0x1e1267e1, 0x98758068, 0xeb09c0f8, 0x118d207e, 0xc2c79068,
+    0xf6288873, 0xe217a42d, 0xba7b46c0, 0x0a0ba707, 0xa500a07e, 0xba8a5086, 0x334ba8c1, 0x04e33408,
+    0xac304e6d, 0x7462c307, 0x1a7b4659, 0xda0ba768,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0xe8000000, 0x44000000, 0xea000000, 0xdd000000,
+    0x9d800000, 0x66400000, 0xe0600000, 0xfb500000, 0x88f80000, 0x71840000, 0x284e0000, 0x676b0000,
+    0xc3d78000, 0xdf32c000, 0x59aee000, 0x047d7000, 0x54470800, 0xc1643c00, 0x00d0a200, 0x0dba5300,
+    0xd36eca80, 0xb1d1dfc0, 0x1e3780a0, 0x1222c050, 0xf936e0c8, 0x5aa970d4, 0xf6f10802, 0x4e8b3c99,
+    0x9cc92277, 0x0ea393bb, 0x34f7aafd, 0xf78e6f5d, 0x7b4668c8, 0x5def8cda, 0x0d174a68, 0x80981fc2,
+    0xced6e069, 0x4ab970e1, 0xcbe908cd, 0x761f3cf0, 0xde1f2298, 0x7a1c931c, 0x20162a56, 0x8513af5b,
+    0xb491084e, 0x7cdb3c9c, 0xabb1220e, 0x10679334,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xb8000000, 0xe4000000, 0x52000000, 0xab000000,
+    0xfc800000, 0xf2c00000, 0xa4200000, 0xd1b00000, 0xd3d80000, 0x28840000, 0x88c20000, 0xb32b0000,
+    0xcb3e8000, 0x601dc000, 0x3f2f6000, 0x053d9000, 0x9d167800, 0x6aaa6400, 0x1872ae00, 0xd37f3900,
+    0xbff53680, 0x15350dc0, 0x051e8060, 0x3eadc090, 0xa2776058, 0x247990b4, 0xf57478ea, 0x1ef1644f,
+    0xf6b42eae, 0xe156f959, 0x274056d8, 0x79679de3, 0x7fd47817, 0x56816469, 0x9dcc2e03, 0xbaa2f92f,
+    0x607a5629, 0xd7789d5c, 0xbdf0f83a, 0x0633a4ac, 0x1d9fcea2, 0x9e69a9f8, 0xad5dce98, 0x0942a9cf,
+    0xd4634e32, 0x925f6994, 0xafcc2e79, 0x81a2f9af,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0xc8000000, 0x84000000, 0x96000000, 0xc3000000,
+    0xde800000, 0x81400000, 0x49e00000, 0x72700000, 0xc0580000, 0x5c8c0000, 0x5c4a0000, 0x306f0000,
+    0xabbd8000, 0x03fcc000, 0x3d9d2000, 0xf9275000, 0x265ad800, 0x078e7c00, 0x2ecd4a00, 0xb3201900,
+    0x7f573280, 0xe805f540, 0x34058060, 0xae00c050, 0x9f0f2028, 0x04845094, 0x504d585e, 0xc26dbc47,
+    0xeeb5ea48, 0xd6778942, 0xe65aca17, 0xa783d9b3, 0xdecf92e9, 0x6b22657e, 0x33507834, 0xfa0aecb8,
+    0x610f3249, 0xb389f564, 0xc0cf8096, 0xcc2fc07c, 0x6bd2a0fa, 0x704890de, 0x72687897, 0xd6b6ec9f,
+    0x8a7d3212, 0x3c5af586, 0x76800022, 0x554000e8,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xf8000000, 0x24000000, 0x5e000000, 0xef000000,
+    0xd7800000, 0xa2c00000, 0x06e00000, 0x41900000, 0x30380000, 0x80840000, 0x71420000, 0xca230000,
+    0x80728000, 0x7a25c000, 0x687ba000, 0x462d1000, 0xc2728800, 0x0f2ed400, 0xdef90200, 0x24681700,
+    0x95daaa80, 0xb49a13c0, 0x46b08060, 0x02c6c010, 0xf6e92018, 0x8998d0f4, 0x3c3128a6, 0x0287c4cb,
+    0xe4498a89, 0xaca5c34d, 0x1a312851, 0x0987c423, 0x8dc98ad6, 0x3165c311, 0x33512839, 0xced7c4ae,
+    0xe5118a4f, 0x1f71c38f, 0xa5ab2847, 0x26b0c47a, 0x12c10a12, 0xeee70337, 0x7d9a0883, 0x9a3c14ed,
+    0xc98a2282, 0x6dc7c724, 0xe16302cc, 0xcb5f170a,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x28000000, 0x04000000, 0x62000000, 0x9f000000,
+    0xf5800000, 0x75c00000, 0x6f600000, 0x53b00000, 0xfb980000, 0xab040000, 0x9f820000, 0x7ec90000,
+    0xa0e58000, 0x9572c000, 0x077e6000, 0x707bd000, 0x61fca800, 0x06350400, 0xde5e2a00, 0x7c6c0d00,
+    0x903b6280, 0xcb5919c0, 0xf2e20060, 0x027900b0, 0x66fd80c8, 0x3fb6c074, 0xbd9c604a, 0xc602d09b,
+    0xad012897, 0xd283c4ea, 0xdc424a9a, 0x54aedd26, 0xc5da4a14, 0xa0aadd38, 0x4fd84a04, 0xdba3dd65,
+    0xa85dca77, 0x19611d5f, 0x36bbaa8d, 0x5d1ecdce, 0x3d4502b9, 0x5022c928, 0x2c9ea80f, 0xea8c0477,
+    0x4043aa85, 0xfaaacdd1, 0x4cdf0256, 0x802fc961,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x18000000, 0x14000000, 0x86000000, 0xbf000000,
+    0x77800000, 0xa3400000, 0x5ea00000, 0x51700000, 0x38180000, 0xc90c0000, 0x008a0000, 0x38c70000,
+    0xffe88000, 0x2ed0c000, 0xb5e12000, 0xa3d79000, 0x8b6a8800, 0x40104c00, 0x0d085e00, 0x7e8cbd00,
+    0x03c17680, 0x166ca140, 0x26920060, 0x1acb00d0, 0xeee280f8, 0x9a57c084, 0x7b29a09e, 0x6b3750ab,
+    0xde33a8f1, 0xc4bbdc1c, 0xc970d629, 0x6c17f1f2, 0xaf0ba8e6, 0x2f87dcd8, 0x5742d6d8, 0x48acf161,
+    0xf6712867, 0x5b9c1c42, 0xec41762c, 0xe12ca1a2, 0x1e3200d1, 0x64bb0014, 0xb97a80be, 0xe41bc040,
+    0xa303a059, 0xbd805016, 0x6e4328fd, 0x80271cda,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0xe8000000, 0x94000000, 0x2e000000, 0x05000000,
+    0xde800000, 0x3ec00000, 0x33a00000, 0x84300000, 0x7ed80000, 0x8b0c0000, 0x4b820000, 0xd84d0000,
+    0xc1658000, 0xe597c000, 0x45e3a000, 0x0056b000, 0x25c3b800, 0x40210400, 0x90785600, 0xedb13900,
+    0xd19fce80, 0xfbec4d40, 0x3d5a00e0, 0x374100f0, 0x2ce78088, 0x1cdac024, 0x9c0620c6, 0x4a017091,
+    0xa30018f0, 0xff87b43b, 0x2643eeed, 0x9c6c3dba, 0x471d98cd, 0xc12c744f, 0x18fa4e55, 0x7a7b8de3,
+    0xdeb9a0e2, 0x1617b0e9, 0x11a438ca, 0xf33bc450, 0xcf5e7656, 0x184049ea, 0xe167d628, 0x3597f96c,
+    0x1de3ee01, 0x7c5c3d53, 0x9fc5989c, 0x6b2074f4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xe8000000, 0x5c000000, 0x66000000, 0x01000000,
+    0xfb800000, 0x40400000, 0x7ea00000, 0x67100000, 0x75780000, 0xfe040000, 0xd5060000, 0x51890000,
+    0xff4d8000, 0x302b4000, 0xe6d3a000, 0x2c9e7000, 0x1d3ba800, 0x7ca5bc00, 0xe416ee00, 0xcdfe1b00,
+    0xa6cd6680, 0xc36397c0, 0xc8f80020, 0x4f4400f0, 0x18260048, 0x1ad9006c, 0x7a95808e, 0xf43f405d,
+    0xdb2da09d, 0xc2537041, 0xb2502885, 0x3a57fc27, 0xf650ce0b, 0x485f2b99, 0xfd5b6e20, 0x3cd55b6f,
+    0x1b9ec60a, 0x9fbde791, 0x4363a8d1, 0x08f1bcb0, 0xef48eed5, 0x28231b3d, 0xf2dee60c, 0x2695d76c,
+    0x92382081, 0xda2c30d8, 0x39dd88d6, 0xf2148c39,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x28000000, 0xa4000000, 0xc6000000, 0x9b000000,
+    0xf4800000, 0x4cc00000, 0x9f600000, 0xbef00000, 0x9e880000, 0x98040000, 0xac020000, 0x72030000,
+    0x05038000, 0xe3814000, 0xda452000, 0x8ea63000, 0x4e14d800, 0x983fa400, 0xd52e6600, 0x1592fb00,
+    0xfe7cf780, 0x71887440, 0xd2800020, 0xe7c00010, 0x23e00018, 0x2630001c, 0xefe8000a, 0x19f40029,
+    0x000a0031, 0x3dc70026, 0xc2e1803d, 0x63b24013, 0xdeaea027, 0x4bd3702f, 0xa6dbf827, 0xfc9e9426,
+    0x323b3e2b, 0x1c2f1f1c, 0xf4143181, 0x093dff78, 0xb8ad0f96, 0x80d1e073, 0x6a5abe2b, 0x4c5d5f2a,
+    0xe75a91a7, 0x5bde8f70, 0xc31ef7a4, 0xb27b7443,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0xd8000000, 0xdc000000, 0x1a000000, 0x23000000,
+    0x7c800000, 0xbbc00000, 0x32200000, 0x5d700000, 0xf1480000, 0xbc040000, 0x4a020000, 0xeb050000,
+    0xc8858000, 0xa5c6c000, 0xd726e000, 0x18f0d000, 0x15091800, 0x4967e400, 0x9e97de00, 0x751c9100,
+    0xd8bb6b80, 0xa28f4a40, 0xca200020, 0x71700010, 0x93480028, 0xf304002c, 0xf4820036, 0xafc50037,
+    0x9c258006, 0x6076c008, 0x68cee01f, 0x4244d02e, 0x9c63180c, 0x4316e417, 0xed585e3c, 0x87db512f,
+    0x8d1a0b92, 0xf4bc5a7a, 0xc08a7812, 0x8521f439, 0xcff0261d, 0xd78fa52a, 0xa7a7adb3, 0x31313f65,
+    0x10293581, 0xc6951b45, 0xe91d8ba1, 0x62bf9a7a,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x48000000, 0xe4000000, 0x56000000, 0xb7000000,
+    0xf9800000, 0x4fc00000, 0x13a00000, 0xadb00000, 0x92280000, 0x0c040000, 0x22060000, 0x89010000,
+    0xfa828000, 0xa8424000, 0x0f672000, 0x1192b000, 0xc75db800, 0x740bcc00, 0xcc707e00, 0x2a8f9d00,
+    0xd232ed80, 0xe2ea5140, 0x22200020, 0xc6700030, 0xf7880008, 0x46b40014, 0x01ae0012, 0x2ec50039,
+    0x9d248015, 0x3bf3402d, 0x9e4da03e, 0xfa14f013, 0xf99c9804, 0x41287c2b, 0x63874624, 0xfac21103,
+    0x3323b388, 0x50f67c62, 0xcdcdd59e, 0xf8d3dd5a, 0x16bf5e2b, 0xbbd92d20, 0xe34955ab, 0xfe909d70,
+    0x72dafe14, 0xf9c9dd2e, 0x26d3cd97, 0x65b9e152,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xb8000000, 0x74000000, 0x4e000000, 0xa1000000,
+    0x52800000, 0x72c00000, 0x9ee00000, 0x81300000, 0xe0380000, 0x36040000, 0xb5020000, 0xec810000,
+    0xbbc68000, 0x0065c000, 0xc9f3a000, 0x91da7000, 0x44b10800, 0x75789400, 0x36a69600, 0x11107700,
+    0x8de8c980, 0xa40e21c0, 0x12b80020, 0xd4c40010, 0xb3e20008, 0xc9b10034, 0xadfe802e, 0xe361c01d,
+    0x6071a013, 0xae9b7028, 0x33178814, 0x86ed541c, 0x818d3627, 0x37fe0720, 0x9c63c1b8, 0x0bf3b5cd,
+    0x2ada160d, 0x2930b72b, 0x4c3fe9a6, 0xfc0091f4, 0x3204283c, 0xd3072429, 0xa1843e0a, 0x03429301,
+    0x25a757b7, 0xd092c2c5, 0x7eac5fb7, 0xe9af56f1,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x28000000, 0xd4000000, 0x4e000000, 0x17000000,
+    0xd8800000, 0x46c00000, 0x9ca00000, 0x17d00000, 0x76d80000, 0xba040000, 0x89020000, 0x47850000,
+    0xda448000, 0xbc614000, 0x4876a000, 0xf78a7000, 0x9d1cb800, 0x7720c400, 0x9f953600, 0x77bd6500,
+    0xcbf17e80, 0x0bce56c0, 0x42780020, 0x39d40010, 0x11da0028, 0x7a810024, 0xa3c6800a, 0x69244035,
+    0x40922013, 0x4b3b3005, 0x7b321836, 0x7c6eb411, 0x172b8e27, 0x50c8a105, 0xf8f8c89d, 0x7a1673ee,
+    0x48fdde82, 0x821526c1, 0x14fa383e, 0x8814841b, 0x65ff1630, 0x9392552c, 0xb5b966be, 0x5ef1e2ed,
+    0xe64d0e22, 0x793ce119, 0xb632e88b, 0x5de943f2,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x38000000, 0x2c000000, 0xea000000, 0xb7000000,
+    0x17800000, 0x99400000, 0x80e00000, 0x91f00000, 0x65980000, 0xb6040000, 0x95020000, 0x04830000,
+    0xa0c68000, 0x50a1c000, 0x77916000, 0x27af5000, 0x6ab9b800, 0xbcd6bc00, 0x7c4dca00, 0x66cd8700,
+    0x270dcf80, 0x652e8cc0, 0xd7780020, 0x2cf40010, 0x551a0018, 0x60c70034, 0x70a4800e, 0xc792c00b,
+    0xcfafe03a, 0x7eba902d, 0x7ad2d805, 0x214eec26, 0xc648f220, 0xa9cdfb24, 0x7c8de599, 0xc66a9bed,
+    0xd8999785, 0x8681a0d1, 0x43c19210, 0xe126ab30, 0x63d65d8b, 0x3fcf27f6, 0x598add8e, 0x2ae9e7d9,
+    0x6c5f3d98, 0x102477f6, 0x6951659a, 0x660c5bf4,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xd8000000, 0xa4000000, 0x92000000, 0xd1000000,
+    0x0b800000, 0x36400000, 0x43200000, 0x80d00000, 0x98d80000, 0x41040000, 0xf3860000, 0xa2470000,
+    0xd9268000, 0x2dd6c000, 0xa55d2000, 0x34409000, 0x6a208800, 0x1f575c00, 0x349f3200, 0xaf20d900,
+    0x4ed72680, 0x4fdf56c0, 0x01860020, 0x63470030, 0xeaa68038, 0x6f96c034, 0xac7d2036, 0xc1909029,
+    0x6b788824, 0xb9135c34, 0x8fb93202, 0xbbf7d90d, 0x4c09a690, 0xa3dd96e0, 0xcf852006, 0xb4449020,
+    0xaa268804, 0xff505c1c, 0xe499b200, 0x77261922, 0xead2068d, 0xdddbc6f9, 0xd0808838, 0x68c75c3a,
+    0xdce73225, 0x2cb4d93f, 0x2ca92683, 0x594c56ea,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xc8000000, 0x3c000000, 0x92000000, 0xa3000000,
+    0x09800000, 0x82c00000, 0xb9200000, 0xb1b00000, 0xc2580000, 0x53040000, 0x51860000, 0x56c10000,
+    0x47228000, 0x48b64000, 0x54d9e000, 0x4a409000, 0xc9631800, 0x57d14c00, 0xb60e9600, 0x60cf8900,
+    0xe7ee6d80, 0x8f5982c0, 0x8b860020, 0x09c10030, 0xfca28008, 0x39764014, 0x2c79e032, 0x4530900f,
+    0x201b1824, 0x16654c28, 0x2c509602, 0xe7ca8920, 0x486aedae, 0x209ec2ec, 0x5a256010, 0x5833d024,
+    0x309e781c, 0x72269c01, 0x1436ee23, 0x3a98151d, 0x252203b1, 0xb3b3d7fa, 0x895be390, 0x368347c5,
+    0x8940fbab, 0x70e60bd0, 0x6d106d93, 0xab2c82dc,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x48000000, 0xa4000000, 0x46000000, 0x3d000000,
+    0xc1800000, 0x04400000, 0x93e00000, 0x84300000, 0xee980000, 0x85040000, 0x5d820000, 0x9e450000,
+    0x94e48000, 0xd2b24000, 0xf45d2000, 0xa8263000, 0xb293e800, 0xa2cb0c00, 0xece8a200, 0xf378df00,
+    0xd8320180, 0x949ec4c0, 0xb2020020, 0xd3050010, 0x68848028, 0x5bc24024, 0x46a52012, 0x34523029,
+    0x15e9e811, 0x04fa0c0f, 0x99762230, 0xbafb9f01, 0x9871a1a4, 0x717bb4e1, 0xd332481b, 0x101b7c31,
+    0x0bc46a3f, 0xeea0e303, 0xa055cbb7, 0x8beb57dd, 0xd5ff838c, 0xbaf42bf5, 0xc5b9e994, 0xf711c8dd,
+    0x3088a205, 0xaa08df00, 0x82ca01a6, 0x3ceac4c6,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xe8000000, 0xc4000000, 0xea000000, 0x65000000,
+    0x1f800000, 0xe2c00000, 0x01a00000, 0x84700000, 0x09b80000, 0x01040000, 0x85860000, 0x4fc10000,
+    0x6a258000, 0x64b74000, 0xa91e2000, 0x70f35000, 0x0bfd2800, 0x50e5fc00, 0x89149600, 0x236e4d00,
+    0x464b8380, 0x987a5dc0, 0xec260020, 0xcfb10030, 0xa99d8008, 0xb0b3402c, 0xdb18203a, 0x19f25031,
+    0x8a78a83a, 0xd522bc19, 0x3632b607, 0xb0591d38, 0xc990ab80, 0x032ea1e1, 0x06af1622, 0x896c0d30,
+    0xc34e23a9, 0x17fb4dff, 0x56e68800, 0xe211ec18, 0x03ef9e18, 0x360ce129, 0xa09c3dbf, 0x6134ecdd,
+    0x26da95b8, 0x3ad350c0, 0xdf4ba3bc, 0x11fc0dc2,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xe8000000, 0xd4000000, 0x86000000, 0x75000000,
+    0x8d800000, 0x8dc00000, 0x2a600000, 0xf2700000, 0x26980000, 0x91040000, 0x53860000, 0xb4c10000,
+    0x4de28000, 0xb0b74000, 0xa67ea000, 0x90303000, 0x2a3da800, 0xfd916c00, 0x6d28ee00, 0x6bfb1300,
+    0xfdf1b380, 0x705d44c0, 0xb7e60020, 0x77b10030, 0xa8fa8008, 0x5d734014, 0x3c18a03a, 0xfa413035,
+    0x60a72821, 0xd6922c1d, 0x19a84e23, 0xa1be2323, 0xc5509b8a, 0xeace68fc, 0x444cce29, 0x19086314,
+    0xc72cbb9c, 0x44f918f9, 0x2777c609, 0x3b1d3f29, 0xd4c7fd80, 0xfde667ed, 0x28b21bb3, 0xca7928d9,
+    0x2a326e18, 0x0d38531f, 0x831113a7, 0x186874fa,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x68000000, 0x24000000, 0x12000000, 0xb5000000,
+    0x28800000, 0xa6400000, 0xe2600000, 0x82900000, 0x98b80000, 0xe9040000, 0x56820000, 0x95430000,
+    0xd5e68000, 0xf5534000, 0xc2192000, 0x88b49000, 0x334fa800, 0xe8ae4400, 0xb6fdb200, 0xbf615300,
+    0xce12b480, 0x4cfa95c0, 0x6e620020, 0x94930010, 0x8fbe8018, 0xcc874034, 0x9443201a, 0xe7639009,
+    0x12132804, 0x72fa042d, 0x3d60120a, 0x73158329, 0x907abcb8, 0xb22701e0, 0xe7f33a06, 0x37af872a,
+    0x477aae8d, 0xb7a282d1, 0x9531868f, 0xfd8c86e4, 0xcc8b948c, 0x060e05eb, 0xcd4da824, 0x9bad443e,
+    0xe17b3213, 0x18a2131f, 0xfcb39481, 0x094a05e4,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x78000000, 0xf4000000, 0xbe000000, 0xa3000000,
+    0x1f800000, 0x0c400000, 0xbe200000, 0xd7700000, 0xdd080000, 0x7f040000, 0x5d860000, 0xfd430000,
+    0xf8a58000, 0xa1b3c000, 0x27efa000, 0xbb91f000, 0x469b1800, 0x299ffc00, 0xdc1fe200, 0x1d5f3100,
+    0xd7fd9b80, 0x9f4c5840, 0xba260020, 0xb1730030, 0x5a0d8018, 0x1687c02c, 0x7ec1a01e, 0x16e6f03d,
+    0x3e90982f, 0x7e1b3c28, 0x9c5bc207, 0x897a0103, 0xad8aa3af, 0x86c29475, 0x22e0da17, 0xe095fd2f,
+    0x6d1d418f, 0xfbd9a553, 0x713b4180, 0xadaaa565, 0xf2b6c19e, 0xe06d655a, 0x93d761b0, 0x8ebb9568,
+    0xd1eff991, 0x5494a97b, 0x731a3b8c, 0x88d9a872,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xf8000000, 0x5c000000, 0x02000000, 0x3b000000,
+    0xe1800000, 0xacc00000, 0xb5a00000, 0xa8b00000, 0xdbb80000, 0x79040000, 0xfa860000, 0x9d470000,
+    0xd1618000, 0xb917c000, 0x2d0ae000, 0x9bbc1000, 0x5903d800, 0x2a860c00, 0x5543e600, 0x7563ed00,
+    0xe7106580, 0x140c2dc0, 0x413e0020, 0x14430030, 0x33e78038, 0x4850c00c, 0x066b603e, 0x45abd017,
+    0x97893800, 0x26fa1c0e, 0x58603e38, 0x5b95e12b, 0xdc4b83ad, 0xb0dbc0ea, 0x87106596, 0xe40c2dee,
+    0x593e0026, 0x7843001b, 0xc9e78032, 0x2f50c035, 0xe5eb6035, 0xd26bd03f, 0xc3a9382e, 0x228a1c2f,
+    0x36783e00, 0x8a21e11c, 0xfd7583a2, 0x5498c0f1,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x28000000, 0x24000000, 0xaa000000, 0x07000000,
+    0xc3800000, 0x53400000, 0x98200000, 0x92b00000, 0xfc580000, 0x3d040000, 0xac820000, 0x54c70000,
+    0x71608000, 0x25914000, 0x89692000, 0x38195000, 0x0ea63800, 0xa9731c00, 0x8abd1a00, 0xf1d10100,
+    0x92cef580, 0x19e892c0, 0x7ada0020, 0x2ec30010, 0xfe628038, 0x32164004, 0x4829a00a, 0xab381009,
+    0xd197182a, 0xab6e4c01, 0xeb192230, 0x5f251d14, 0xf1336fa6, 0x5f18d3e4, 0x9d25d59f, 0x3236c2df,
+    0x269cb833, 0xe1615c01, 0x4d96ba2e, 0x4d6e1104, 0x82196d82, 0x21a79ec6, 0x4ef20239, 0x73fb4d2f,
+    0x6ef5d78c, 0xc3fa8fc8, 0xb6f1ef97, 0xdffe93f4,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x58000000, 0x3c000000, 0x52000000, 0x0d000000,
+    0x01800000, 0x7fc00000, 0x13200000, 0x5b300000, 0x8a880000, 0xe3040000, 0x9e860000, 0x13430000,
+    0x3d628000, 0x6fd2c000, 0xfe99e000, 0x60be9000, 0xfa0a6800, 0x6f410c00, 0xcf647e00, 0x52d3d900,
+    0xf71b7980, 0x7b79e440, 0x872e0020, 0x6b770030, 0x496c8018, 0xcf95c014, 0x057d6016, 0x202f500f,
+    0x6bf10814, 0x6d2d5c03, 0xca77f600, 0x22ec451f, 0xd1556f84, 0x1ddb3156, 0x35d90782, 0xa1d93d48,
+    0xa7dff99f, 0xccd82460, 0xf65d6021, 0xeb1f5030, 0xd9790825, 0xe2295c00, 0x5ef1f63c, 0x00af4508,
+    0xbfb7efa3, 0x00c9f15e, 0xd9e0e7bb, 0xe597ad5f,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xd8000000, 0x84000000, 0x4e000000, 0xd3000000,
+    0xf9800000, 0x16c00000, 0xf9a00000, 0xe6d00000, 0x85d80000, 0x81040000, 0x80860000, 0xe2450000,
+    0x2de18000, 0xac36c000, 0x466c2000, 0x86ae7000, 0x480c9800, 0x8958cc00, 0x21463600, 0xfc638d00,
+    0x46f15680, 0x45cd3ec0, 0x257e0020, 0x63510030, 0xa91f8028, 0x9da7c00c, 0xf8d3a036, 0x1ed9b021,
+    0x14873813, 0x84457c34, 0x02e70e3e, 0xafb3f105, 0x15afd8be, 0xd18c0ff9, 0x0f9bf881, 0x30e67fd0,
+    0x46b160a8, 0x092bb3c4, 0x59ced6b5, 0x8f7afef6, 0x6e55a03c, 0x6b9cb008, 0x2ee6b821, 0xddb3bc3e,
+    0x9cab2e2d, 0x5d0d8116, 0x6fdb40a3, 0xec00c3f9,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x68000000, 0xb4000000, 0xe2000000, 0xab000000,
+    0xed800000, 0xbac00000, 0x25200000, 0xd7300000, 0x9b580000, 0x9d840000, 0x82c60000, 0x99230000,
+    0x11348000, 0xba5b4000, 0x6f01a000, 0x37811000, 0xadc65800, 0x0ea3ec00, 0x4cf69600, 0x4cfc8900,
+    0xfff5a980, 0x2d7f82c0, 0x1b348020, 0x655b4030, 0x0081a018, 0xb6411024, 0x0d66581a, 0xd753ec2d,
+    0x108e9638, 0xad48892a, 0x0beba9bb, 0x931882ee, 0xade60029, 0x91130005, 0xe5ec803e, 0xa61f4003,
+    0x4d67a03a, 0x7752100b, 0xe08ad83c, 0x554cac04, 0xd7e93620, 0xc51a9923, 0xe4e171a2, 0xd7942ef6,
+    0xb2af3615, 0x39f99904, 0xbf75f187, 0x3b3f6ec9,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0xe8000000, 0xfc000000, 0x06000000, 0x4d000000,
+    0x23800000, 0xe7c00000, 0xaa600000, 0x2e700000, 0xf7080000, 0x6b840000, 0x2bc60000, 0x64610000,
+    0x6f778000, 0x3a8f4000, 0x3d43e000, 0xa4263000, 0xe0d36800, 0x119e9c00, 0x98bc7e00, 0x4be8b300,
+    0x85b24380, 0x536d5040, 0x24f78020, 0xe14f4030, 0xb123e008, 0x3756303c, 0xdc5b683a, 0x61da9c3f,
+    0x1f1a7e01, 0x4cf9b313, 0x3e4dc388, 0xe5a61079, 0x9812600a, 0x0f78703b, 0xc98f0835, 0x77c3ec26,
+    0x5262f630, 0xea751f26, 0x150cd59a, 0x34853f5d, 0xbf45dda7, 0xeb27d360, 0x4c50abba, 0x7add8c43,
+    0xa99f9e09, 0xbcbe833b, 0x79e92b98, 0xc2b7cc5d,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x68000000, 0x3c000000, 0xce000000, 0x11000000,
+    0x50800000, 0x58400000, 0x8aa00000, 0x90d00000, 0x08380000, 0xfc840000, 0x3e460000, 0x87a10000,
+    0x1e508000, 0x297b4000, 0x1aa5e000, 0x38d07000, 0x14398800, 0x22860400, 0x47478200, 0xeb24b500,
+    0x88127680, 0xb2db89c0, 0xdaf08020, 0x68ab4030, 0x621de008, 0x8c147004, 0xc8df881a, 0x09f7040f,
+    0x9f2f0233, 0x2fdbf504, 0xfc719694, 0x55eaf9d6, 0x5a398802, 0xf3860414, 0x37c7820a, 0xa364b53b,
+    0x6ab27695, 0x1e0b89ee, 0x1cc88014, 0x852f403e, 0x0cdbe01a, 0x53f5701c, 0x5c2f083d, 0xb05c4423,
+    0x8db2e220, 0xeb8f8501, 0xd60e1eb9, 0xf0cdfddb,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0xe8000000, 0xe4000000, 0x3a000000, 0xe1000000,
+    0xd3800000, 0x31c00000, 0x88a00000, 0xa5700000, 0x3de80000, 0xc7840000, 0x93c60000, 0xd5a10000,
+    0xd0f48000, 0xdb2f4000, 0xa3a32000, 0x0ff7d000, 0x63ae0800, 0xd8e61c00, 0x6910ce00, 0xa1ba6500,
+    0x13dc7b80, 0x048ba740, 0x2a548020, 0x2b5f4030, 0xbfcb2008, 0x6cb3d024, 0x7948083a, 0x9cf71c39,
+    0xe52c4e0e, 0xc8a12538, 0x45715bb4, 0x8de9774c, 0xbf800802, 0x9fc31c19, 0x0ba24e07, 0x0bf42515,
+    0xe9abdb9e, 0x41e3374c, 0xb691a81a, 0x4e7a8c3e, 0x407be614, 0x937aa92b, 0xf5fe3d80, 0x55bc9e66,
+    0x01dd15bb, 0x21885251, 0x53d153bc, 0x139a6b6c,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xd8000000, 0x44000000, 0x4a000000, 0x97000000,
+    0x84800000, 0xb8400000, 0x99a00000, 0x5fd00000, 0x1d880000, 0x88840000, 0x8e460000, 0xf0a10000,
+    0x0e568000, 0x2a4ec000, 0x23e62000, 0x2d745000, 0x38ddb800, 0xf84ea400, 0x10e10200, 0x13f1ed00,
+    0x1f9f2e80, 0x79681440, 0xf9768020, 0x4adec030, 0xdb4e2008, 0xd6605034, 0xa0b3b836, 0x0cbba411,
+    0x43f98212, 0x6eda2d25, 0xa1498ea1, 0x6963846e, 0x68339806, 0x62fef427, 0x435aba0f, 0x688e4916,
+    0x1700ac95, 0x4483396d, 0x98418eb1, 0x49a7845f, 0x87d59801, 0x598ff421, 0xc2843a16, 0x1944890c,
+    0x74208ca7, 0xb6166952, 0xb3eab6b7, 0x7c37e059,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x78000000, 0x7c000000, 0x5a000000, 0xf9000000,
+    0x02800000, 0x08c00000, 0x9fe00000, 0xfcd00000, 0xa5780000, 0x98840000, 0xd1c20000, 0x6d630000,
+    0x1c148000, 0x3e9bc000, 0x4257e000, 0xd7b8d000, 0x0e60c800, 0xc7969400, 0xc4d88600, 0x3d779f00,
+    0xb08cf680, 0x71ce03c0, 0x596c8020, 0x761fc010, 0x8b95e018, 0x56dbd024, 0x3074481e, 0x7c0d541f,
+    0xde0f6616, 0x1b0f4f3e, 0x238c3e80, 0x424897c2, 0xa72c0607, 0x2f3c5f2f, 0x4fa316b1, 0xd2f2d3c2,
+    0xa4ce480a, 0x59ea5414, 0x0bd9e609, 0xa4f78f15, 0x5fcf5e8e, 0x2e6b87e8, 0xb39b2e12, 0xced21b20,
+    0x187b5880, 0xdc03d8cf, 0xea023881, 0x7107c8f7,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x78000000, 0x4c000000, 0xd6000000, 0xb1000000,
+    0x58800000, 0xbdc00000, 0xf8e00000, 0x61300000, 0x5ee80000, 0x22840000, 0xcac20000, 0x39610000,
+    0xc0738000, 0xf9cbc000, 0x90566000, 0xca9cb000, 0x11488800, 0x35974400, 0x2e7d2e00, 0xae7b2700,
+    0xee7bf980, 0xce79df40, 0xbe7b8020, 0xc67fc010, 0x8a7c6008, 0x5c79b01c, 0xed79081e, 0xb5fd8413,
+    0x0838ce35, 0xf0dc572c, 0x91ed1196, 0xcf062b6f, 0xed84261e, 0x2746a308, 0x1e23379f, 0xde558854,
+    0x279e918c, 0xb7cdeb4d, 0x7d52462d, 0x6c1a131e, 0x598bbfa4, 0x77f2cc52, 0xd98bbfb1, 0x37f2cc7a,
+    0xf98bbf97, 0x47f2cc6c, 0x818bbf97, 0x0bf2cc55,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xb8000000, 0xf4000000, 0x1e000000, 0xc7000000,
+    0xfa800000, 0x36400000, 0xefe00000, 0x2ef00000, 0x5f980000, 0xe0840000, 0xf7420000, 0xf6670000,
+    0x74318000, 0x0d3dc000, 0x3113a000, 0x696af000, 0x9b1d3800, 0x81c05400, 0x8a217200, 0x92d65f00,
+    0x964dc780, 0xee4aa4c0, 0xba498020, 0x7449c010, 0x3b49a038, 0x8dc9f00c, 0x518eb82e, 0x676a943d,
+    0x741b5207, 0xe7456f31, 0xde615fbe, 0xe83700cd, 0x6f3c4a1b, 0xa4160b1b, 0xbeecb5af, 0x8edcfbf4,
+    0x65e447b3, 0x87f364d0, 0x3a182002, 0xe844302e, 0x88e51824, 0x04746426, 0xe1dc6a34, 0x43663b27,
+    0x33b3ad8c, 0x70fb9fe0, 0xa933adbd, 0x36bb9fd0,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x28000000, 0xc4000000, 0x26000000, 0xc3000000,
+    0x4a800000, 0xeb400000, 0xae600000, 0xf8700000, 0x2e280000, 0x30840000, 0x22460000, 0x65e50000,
+    0x8cb18000, 0xca894000, 0x03522000, 0x973f3000, 0xee5f1800, 0x02a9d400, 0x78c11a00, 0x56a1d300,
+    0x6ed33380, 0xe0f98940, 0xe0798020, 0xea3d4030, 0x4bdc2028, 0xe16e300c, 0xe8e0980a, 0xa1319431,
+    0xdd4cba09, 0x9d76a330, 0x67a98b92, 0x31432d7a, 0x5764220b, 0x1bf3370e, 0x9eeb31a3, 0xf2248e40,
+    0xd29229a2, 0x9a185a58, 0xec4ab3a2, 0xeaf4c97e, 0x496da010, 0xece77012, 0x2732b83b, 0x2e4ea433,
+    0xfff3a204, 0x48ef7722, 0xb92091a3, 0x6c16fe55,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xa8000000, 0xdc000000, 0x8e000000, 0x77000000,
+    0x69800000, 0x71c00000, 0xbaa00000, 0xd3500000, 0x4a480000, 0xae840000, 0x70460000, 0xf7670000,
+    0xd7f18000, 0x461d4000, 0x514ae000, 0x21067000, 0x4a82b800, 0x9a45cc00, 0xaa674e00, 0x03767500,
+    0xd35c0a80, 0xd6a99a40, 0xa9b78020, 0x067a4030, 0x0f3b6038, 0x26db300c, 0x0968582a, 0xb413bc37,
+    0x24adf623, 0x40b7b91d, 0x60fd449a, 0x5378ef5c, 0x17ba0a8e, 0x459e9a44, 0xbd8e000a, 0xaf230017,
+    0x3c97800e, 0xffea4006, 0x5053603c, 0xb1cf303b, 0xc646582d, 0xe460bc09, 0x94727626, 0x8ad9f90e,
+    0x0f682496, 0xcf10df5f, 0x5b2dd29a, 0x32736653,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x28000000, 0x7c000000, 0x72000000, 0xa7000000,
+    0x10800000, 0x03c00000, 0xee600000, 0x5ef00000, 0x36480000, 0xff840000, 0x1f460000, 0x07a10000,
+    0xd3948000, 0x5e394000, 0x630fe000, 0xcb219000, 0xf2523800, 0x1f5d0400, 0xc179de00, 0xa4afd300,
+    0x38b18780, 0x3c6c9a40, 0x54528020, 0x76584030, 0x24fb6008, 0x80e8d024, 0xed95d80a, 0xf338941f,
+    0xa08de61c, 0x4663d729, 0xe2f4d984, 0x644e0940, 0xc882e79b, 0x27c04a67, 0x78615825, 0x4ff1d42b,
+    0xa7ca0605, 0x45c6473a, 0x3760e1a2, 0x03720d61, 0x460fb9a0, 0x24a6d96b, 0x05173fab, 0x44f8de44,
+    0xf0ecbe24, 0x7592030a, 0x373edf9a, 0x86884e63,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x58000000, 0xec000000, 0xbe000000, 0x4d000000,
+    0xc1800000, 0x4e400000, 0xd2a00000, 0xf0100000, 0x48c80000, 0x64840000, 0x6bc60000, 0x36e50000,
+    0xadb48000, 0xd25ac000, 0x028b6000, 0xec27f000, 0x7cd16800, 0x30af8c00, 0xedf03200, 0x45fd3500,
+    0x271c1080, 0x28ab5e40, 0x61f28020, 0xcbffc030, 0xa21fe028, 0x5d2d3024, 0x7db20816, 0xea5c7c3b,
+    0xde8f5a2f, 0x9a23b913, 0x85d6a2b0, 0xa32dab53, 0x50b77094, 0x1bdcae4c, 0x58cbe81a, 0xfc844c0d,
+    0x27c1d224, 0x18e10512, 0xb8b49892, 0xffdce25c, 0xf2cc3a3f, 0x7380490b, 0x4d41cab3, 0x36272747,
+    0x5bd3c2ab, 0xde2b5b7f, 0x593498a6, 0xe19ce259,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x48000000, 0xfc000000, 0x7e000000, 0x9b000000,
+    0xc6800000, 0x31c00000, 0x16e00000, 0xb3700000, 0x38680000, 0x59840000, 0x0d420000, 0x06210000,
+    0x62128000, 0x2d5a4000, 0x994be000, 0xe315f000, 0xfadfd800, 0x5709b400, 0xb7b74e00, 0x7b0e1700,
+    0xc1b60b80, 0x3c0c2b40, 0x29308020, 0xdecb4010, 0x05516008, 0x227bb014, 0xb0de3812, 0x0e09443f,
+    0xb430161f, 0x4748e326, 0x681225b1, 0x54588c4c, 0xcaca33a5, 0x97546f7c, 0xaf7a1626, 0x415de312,
+    0xcf4aa599, 0xf417cc6a, 0x5a5953b5, 0x49cedf42, 0xe5d6ae20, 0x1cbee716, 0xb2b95390, 0x21bedf5e,
+    0x3b3eae0b, 0x24fae72d, 0xe11b539b, 0x68efdf66,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x88000000, 0xd4000000, 0xb6000000, 0xc1000000,
+    0x8e800000, 0xf4400000, 0x0ea00000, 0x8ed00000, 0x03180000, 0x79840000, 0x7bc60000, 0x94670000,
+    0x84378000, 0x0b6c4000, 0x204aa000, 0xb75fb000, 0x57230800, 0xe512f400, 0xef7b6200, 0xa1b11500,
+    0x12ad2680, 0xc32e00c0, 0x7ce98020, 0x268f4030, 0x3fbb2038, 0xd854f03c, 0x7b5e2822, 0x8d210435,
+    0x2e12ca2d, 0x32fc5130, 0x24754ca3, 0x73cde1fd, 0x8f9fc4a3, 0xcac055d3, 0x52e78698, 0x4471b0d2,
+    0x43ca8824, 0xe79db41c, 0xeec04216, 0x6ce5e53b, 0x51730ea4, 0x7b4f04d5, 0xd2db4a38, 0x6ee31127,
+    0x16766ca3, 0x5ccd11d4, 0x8c1fec9b, 0xaa0251ed,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x38000000, 0xac000000, 0xfe000000, 0x9b000000,
+    0xd5800000, 0x62c00000, 0x71e00000, 0x80700000, 0x31f80000, 0xd0840000, 0xbc460000, 0xaea10000,
+    0x77538000, 0xaa69c000, 0xa8096000, 0xedbdf000, 0xee27d800, 0xa3122c00, 0xe0c85600, 0xb558af00,
+    0x8ed55380, 0xf6a8c7c0, 0x62ed8020, 0xc84cc030, 0x851ce008, 0xae75301c, 0x22fd380e, 0xb1061c2b,
+    0xfc86ee3f, 0x82477326, 0x15a2ddb5, 0xd2d644d8, 0xf0ae85bc, 0x75e9a8d0, 0x93c9b3a4, 0x44ddf7d8,
+    0xa610b809, 0x3e4adc2c, 0x6a1a0e24, 0x79f2433b, 0x6d3fe5ae, 0x1a6058ee, 0x99b06b8d, 0xc59adbc6,
+    0x7a356e35, 0x685eb30d, 0xac53bd9a, 0x9fefb4f5,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xf8000000, 0x1c000000, 0x92000000, 0x97000000,
+    0x21800000, 0x49c00000, 0xada00000, 0x1d300000, 0x18a80000, 0x14840000, 0x97460000, 0xc1e70000,
+    0x27528000, 0x553fc000, 0xbb9ce000, 0x612e9000, 0x3141b800, 0xe0e28c00, 0xffd00e00, 0x6878cd00,
+    0x117f5980, 0xa5fa0d40, 0x42bc8020, 0xcb5cc030, 0x58086038, 0x7b365014, 0xd9afd83e, 0x9c03dc07,
+    0x52055624, 0x7700d125, 0x7180ef88, 0xb1c34c52, 0xb1a7578b, 0x8f36c077, 0x8fadd99e, 0x3505cd61,
+    0xde806003, 0x6c425013, 0x3a61d82b, 0x4d90dc23, 0xaf19d63c, 0xf66c1128, 0xf0a08f8b, 0xc7b11c48,
+    0xaaee8fba, 0xd3e21c7a, 0x70520f94, 0x94bedc7f,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0xe8000000, 0x64000000, 0x7e000000, 0x77000000,
+    0x2e800000, 0xf5400000, 0x7d600000, 0x32b00000, 0xede80000, 0x9f840000, 0xf0c20000, 0xc4a50000,
+    0x0f918000, 0x09bf4000, 0xf61f2000, 0x2d0df000, 0x48f0b800, 0x420ff400, 0x0a706a00, 0x3d4c0b00,
+    0xa6170580, 0xda7f8740, 0x8a3b8020, 0xc4de4010, 0x6caca028, 0x04e7b00c, 0x9976183a, 0x2dc94419,
+    0x8455f21f, 0xb99f0f1d, 0xa5cc578b, 0x3052387d, 0xbf984fbf, 0x6ece7c5c, 0xecd43db3, 0x27da337b,
+    0x342d4a0e, 0x72a4fb34, 0x6c963d8e, 0x113f334a, 0x205cca33, 0xe8ebbb22, 0x9c011db1, 0x8206c370,
+    0x25067237, 0x73854f10, 0x3ac2f786, 0xb5a0887d,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x38000000, 0xdc000000, 0x5a000000, 0x6f000000,
+    0x82800000, 0xbf400000, 0x1fe00000, 0xe4300000, 0xcba80000, 0x2b840000, 0xe8c60000, 0x7d210000,
+    0xee108000, 0x6b39c000, 0x9dfae000, 0xd9d8d000, 0x98487800, 0x6db79400, 0xb06bba00, 0x9e225f00,
+    0xee945180, 0x5779d540, 0x729e8020, 0x09acc030, 0x48846008, 0x28441004, 0x6164180e, 0x11778437,
+    0x8349a216, 0x9134db1b, 0xc62d73a0, 0x2644ce6f, 0x606113a7, 0x62f4de49, 0x478b0b9a, 0xbe165a7e,
+    0xb33c299c, 0x71fe415c, 0xabdd3a0b, 0x134a9f02, 0x69363197, 0x3a2cc571, 0x6c421839, 0x37668411,
+    0x3c712227, 0xa2c91b24, 0xcef1939a, 0xd58d1e61,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x58000000, 0x4c000000, 0xda000000, 0x25000000,
+    0x2b800000, 0x7bc00000, 0x9ea00000, 0x00f00000, 0xec380000, 0x40840000, 0x23460000, 0x51e30000,
+    0x3b168000, 0x7a2ac000, 0x052e6000, 0xcbacd000, 0xfbe82800, 0xce8edc00, 0x08de9e00, 0xa813d500,
+    0xdeadf780, 0x9869b9c0, 0xc1488020, 0xd07dc030, 0x0fe6e018, 0x70111034, 0x52aec816, 0x226fcc13,
+    0x34485636, 0xa3f81909, 0x3823a18a, 0x34b2a0de, 0x775da187, 0xe5d5a0f0, 0x0f0d2183, 0x1e1c60d4,
+    0x6935c1a6, 0xe39a70c3, 0x4df38996, 0xc3b87cc0, 0x95453f93, 0xdee575f2, 0xdd96560b, 0xee6f1933,
+    0x2e4b21ac, 0xe6ff60ca, 0xc3a341a9, 0x1770b0d6,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x38000000, 0xf4000000, 0x6e000000, 0x6b000000,
+    0x47800000, 0x89400000, 0x50200000, 0xa8500000, 0xe6c80000, 0xbe840000, 0x27c60000, 0xffe70000,
+    0x7bb38000, 0x7779c000, 0xf4fba000, 0x0bb97000, 0x549e5800, 0x9d4cbc00, 0x55c30a00, 0x66e66100,
+    0x6534d280, 0x00bafe40, 0xa31d8020, 0xcc0ac030, 0x81e62038, 0x98b3b01c, 0x1cf8780e, 0x97bf0c3d,
+    0x669b721b, 0x24496d1a, 0xdb47a091, 0x59279362, 0xfed420b4, 0x5d0e535a, 0x736780a1, 0xe5732343,
+    0x061fd89f, 0xb0889f6e, 0xe7275293, 0xfdd33e56, 0x468e2021, 0x2827b02b, 0x7c56783d, 0x18cc0c14,
+    0x9d86f23c, 0xac43ad19, 0xeca180b7, 0x2e942342,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x88000000, 0x1c000000, 0x26000000, 0x21000000,
+    0xf7800000, 0xe8c00000, 0x56200000, 0x43300000, 0x24780000, 0xf0c40000, 0x02260000, 0x59310000,
+    0x33788000, 0xae404000, 0x0166e000, 0xc1d03000, 0x07698800, 0x3e8ffc00, 0x3d1da600, 0x65108100,
+    0x1f4c9180, 0x70bf5fc0, 0x50e6e020, 0xc8103030, 0x86c98808, 0x857ffc04, 0xc745a622, 0xcae48107,
+    0x1f129189, 0xf84a5fc8, 0x9638601d, 0xd7a1700a, 0xe2f7e81d, 0xa9df8c14, 0xe532ce2b, 0xc57f4d3b,
+    0x2740bf89, 0xfae422de, 0x8711d791, 0x6c4eeee1, 0xac3b79bd, 0xd0a0d3d4, 0x34742e02, 0xb69f7d00,
+    0x5bd137ac, 0xd06fdec4, 0x400a71bd, 0x2e5f6ff2,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x88000000, 0xdc000000, 0x2e000000, 0xdb000000,
+    0x7e800000, 0x97400000, 0x30200000, 0xc6300000, 0x16380000, 0xab440000, 0x0e220000, 0xf5330000,
+    0xe4ba8000, 0x9a004000, 0x3904e000, 0x63827000, 0xbec45800, 0x7fe7b400, 0x66525a00, 0xb0a8f100,
+    0x370ce980, 0xfdfd45c0, 0x0124e020, 0x39b27010, 0xe6fc5818, 0x5fa3b414, 0x9ef05a22, 0x0edbf137,
+    0xcd96698b, 0x7acd05f6, 0x5098003f, 0x66340035, 0x663a0014, 0x13470025, 0x0a208027, 0x8f37401d,
+    0xcdbc6008, 0x1181300b, 0x0bc23826, 0xba618403, 0xdf90e202, 0x9fce3529, 0x1d186baa, 0x03f640f5,
+    0x675cb3ba, 0xe256b4c1, 0x8aaa898c, 0x7e0b75dd,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xf8000000, 0x34000000, 0xee000000, 0xbd000000,
+    0x04800000, 0xf7400000, 0x25600000, 0x43f00000, 0x84580000, 0xdb440000, 0x8f620000, 0xd8f10000,
+    0xe1d88000, 0x0a83c000, 0x3a43a000, 0xe9e67000, 0x68b56800, 0x833fe400, 0xffb21a00, 0x5cbc5b00,
+    0x4d72f780, 0xb81e86c0, 0x43a3a020, 0x39567010, 0xff8d6808, 0x828be404, 0xe6081a3e, 0x21495b0d,
+    0xec2877bb, 0x63dc46ef, 0x3d808021, 0xb5c7c02d, 0x8321a001, 0xa8177014, 0x9bede81f, 0xf7fc243b,
+    0x0a11ba18, 0xbcea2b19, 0x807f9fb9, 0x54d562ff, 0x924bba27, 0x25af2b3a, 0x581d1fb3, 0x33a3a2d2,
+    0xf1529a02, 0x238b9b3c, 0xa08b5798, 0x810df6c7,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xd8000000, 0xec000000, 0xba000000, 0x01000000,
+    0x8b800000, 0x33c00000, 0xf5600000, 0xe3b00000, 0x2a480000, 0x77c40000, 0x6b660000, 0x4cb10000,
+    0x26ce8000, 0xac82c000, 0xcb416000, 0x5824d000, 0x28968800, 0x6c5a9c00, 0x2ddafe00, 0xd7187b00,
+    0xbdfc1580, 0xeb888e40, 0x57a16020, 0xa554d030, 0xe63e8808, 0x1aee9c34, 0xca14fe36, 0xd01d7b3b,
+    0x957c95ae, 0x9ecf4e40, 0x70808002, 0x5947c03c, 0xbd21e035, 0x2d13100c, 0x089f683c, 0xe83d8c26,
+    0x8deb9634, 0xda90f713, 0xb95f03ab, 0x005bb967, 0x57d983af, 0xf61d795e, 0xe67ee3a8, 0x0048a942,
+    0x4ec6eb8f, 0xfce0f559, 0xcd757596, 0xe6a85e6e,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xb8000000, 0xbc000000, 0x22000000, 0xdf000000,
+    0xb6800000, 0x42c00000, 0x8aa00000, 0x13700000, 0x22180000, 0xa0c40000, 0xf5a20000, 0x75f70000,
+    0xe8d98000, 0x2e61c000, 0x78d7e000, 0xaaee1000, 0x219b7800, 0x2f867400, 0xc542f600, 0xdbe3c500,
+    0x3e922380, 0xd58c84c0, 0x3acfe020, 0x7a2a1010, 0x8c397838, 0xd671740c, 0xb79b762e, 0x9682052f,
+    0xd2c5c388, 0xe2a294f7, 0x2774980d, 0x041c6400, 0xe1c38e1a, 0xbe26b108, 0x5eb355a6, 0x963d81c7,
+    0xf571a395, 0xc31e44fa, 0x3b43800f, 0x56e2c007, 0x0f14602a, 0xadccd00d, 0x68af1826, 0x80faa43b,
+    0x29d5ee29, 0xc96d610c, 0xdeddcdba, 0x4762e5ea,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x58000000, 0x84000000, 0x46000000, 0x6d000000,
+    0x64800000, 0xbe400000, 0x19200000, 0x92700000, 0xaff80000, 0x30440000, 0x88220000, 0x04f30000,
+    0x32ba8000, 0xfce6c000, 0x02962000, 0x27693000, 0x802de800, 0x938fe400, 0x3ebcd200, 0x26e57900,
+    0xcd96bb80, 0x38e93bc0, 0x6fee2020, 0xd46d3010, 0x4dafe818, 0x6f4ce414, 0xa4de5216, 0x9137b921,
+    0xca5a9b91, 0xf8770bdb, 0x28fb4839, 0x93c7143f, 0xf5e79a1e, 0x30126d30, 0xcc2921bd, 0x298f56ed,
+    0xa1bd0193, 0x616566ca, 0xa65269ad, 0x390842d4, 0x71fa9ba8, 0xf9470bc8, 0x9aa34834, 0xdfb31416,
+    0x931d9a2b, 0x12d56d09, 0x9f49a1a7, 0x8cde96c0,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x18000000, 0x84000000, 0x1a000000, 0x83000000,
+    0x11800000, 0x0c400000, 0x98a00000, 0xeb100000, 0xf7280000, 0x5e440000, 0x77a20000, 0x1c930000,
+    0x566d8000, 0x5664c000, 0x86762000, 0x74dbd000, 0xf4dd9800, 0xb4de4c00, 0xd4d94200, 0x64dc9500,
+    0x7cdb0080, 0xf8db5540, 0xe2de2020, 0x61dfd010, 0x705f9818, 0x7c1d4c2c, 0xe4bcc206, 0x0fac5521,
+    0xf8872086, 0xa6c78560, 0xd1643824, 0xcdf25c13, 0x9b9f7a3e, 0xcdfdc916, 0x4b89fabb, 0x3f525c76,
+    0xcb89fabb, 0x7f525c77, 0xab89faa9, 0xcf525c6a, 0xb389fab9, 0x4b525c7a, 0xa989fab8, 0xc8525c47,
+    0xb809fa94, 0xc4125c60, 0x20a9fa90, 0x2f025c6f,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xa8000000, 0x9c000000, 0x1e000000, 0x85000000,
+    0x51800000, 0x83400000, 0xd8e00000, 0x03d00000, 0x09f80000, 0x19440000, 0xb7e60000, 0x35530000,
+    0x70398000, 0x3e614000, 0x2212e000, 0x7adbf000, 0x3eb1a800, 0xcb69cc00, 0x335ffa00, 0x49f5b700,
+    0xf18c7680, 0x9b88a3c0, 0x3c8ae020, 0xa60ff030, 0x69cfa818, 0x57eecc0c, 0x8d987a2a, 0xc453f727,
+    0x03b91687, 0x762513e1, 0xb6762834, 0x9fcf8c10, 0xeeea9a2e, 0x9218070c, 0x5a10bea8, 0xbed8dfe1,
+    0x74b7d20a, 0xfc6d3b1c, 0x51d96c90, 0x8232e4e3, 0xe5a93eb4, 0x46f99fc9, 0xbfc53232, 0x3626cb29,
+    0x1670c487, 0xcfcf28d4, 0x76e8c49b, 0xa61b28df,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x38000000, 0x54000000, 0x62000000, 0xd3000000,
+    0xc4800000, 0x38c00000, 0xda600000, 0x34f00000, 0x90480000, 0x46c40000, 0xf7660000, 0x1d730000,
+    0xe10a8000, 0xbde5c000, 0x00b4a000, 0x2ee81000, 0xf6501800, 0x8ed9e400, 0xea7c6600, 0x1ded4300,
+    0xa2d7ff80, 0xee1f8640, 0x341ca020, 0x731c1030, 0xb59e1818, 0x0e5ee414, 0x28b8e60e, 0x708f8315,
+    0x58a7df98, 0xf9955674, 0x5a3c9811, 0x394f243e, 0xf542462e, 0x61209319, 0x10d347aa, 0xc51e7244,
+    0xc498de05, 0x79dcb703, 0x84fb8191, 0xaa2b2175, 0xe2373998, 0x3f2ed563, 0x5fb547b9, 0x4c6d7262,
+    0x27925e00, 0x4739773f, 0x78cf21a8, 0xe803316c,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x78000000, 0xec000000, 0x9e000000, 0x17000000,
+    0xdf800000, 0x50400000, 0x96a00000, 0x33900000, 0xaab80000, 0xd7440000, 0x71220000, 0x2fd50000,
+    0xb2188000, 0x8bd7c000, 0xe81d6000, 0x36d5f000, 0x429a3800, 0x48143c00, 0xa0ff6200, 0xfce28500,
+    0xe834c480, 0xfba999c0, 0x873f6020, 0x4e00f010, 0x8f02b828, 0x8383fc04, 0xa642021e, 0x15a7753b,
+    0x9e16fca7, 0x73f9a5c5, 0x89620217, 0xcd777504, 0x438efc8d, 0xc06da5c8, 0x63d80214, 0xba36751e,
+    0x22ac7c93, 0x97bb65ca, 0x3dc7e225, 0xafe5451d, 0xddb1a490, 0x7ee869c0, 0x2f1f5833, 0x7155cc18,
+    0x4edf5a1e, 0x28b7b939, 0x10692695, 0xfbdddcc6,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x68000000, 0xcc000000, 0x86000000, 0xf9000000,
+    0xa4800000, 0xa3c00000, 0x5ae00000, 0x4d100000, 0x7a580000, 0xd2c40000, 0x02620000, 0x70d10000,
+    0x2dba8000, 0x19574000, 0x68fc6000, 0x23713000, 0x9c8f4800, 0x4e7b6400, 0x23b7fe00, 0x55eea700,
+    0x612c4080, 0xea4d06c0, 0x061e6020, 0x2c603010, 0x25d5c808, 0x7f3c2414, 0x7b939e1a, 0x329b9733,
+    0x87a108a1, 0xc33762fe, 0x8c2b1e09, 0x5ccdd738, 0x0edfe89e, 0x590512c7, 0xb4823624, 0xebc28317,
+    0xc6e7dea9, 0xa31291f6, 0x4f5d6898, 0xf04652cd, 0x58a45605, 0x8eb6b321, 0xc3681682, 0x39ebb5e8,
+    0xf72e7685, 0x5b4f85c7, 0x3e99bead, 0x61a2a1da,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x98000000, 0xf4000000, 0x66000000, 0x3f000000,
+    0x55800000, 0xdd400000, 0x2ca00000, 0xaf900000, 0x4d280000, 0x0a440000, 0xb5220000, 0xb0d70000,
+    0x108d8000, 0xa350c000, 0xe2cda000, 0x12703000, 0x4c1e7800, 0x79fd6c00, 0x44caca00, 0x8d779500,
+    0x499ba480, 0x8cb9b540, 0x046fa020, 0xb0e73010, 0x5db3f838, 0xec7dac2c, 0x398f6a26, 0xf1d3a53d,
+    0xce0fdc99, 0xad97d94f, 0x9c2aea35, 0x5cc76527, 0x87e07cb3, 0x7430e947, 0x6b391215, 0x902ac92f,
+    0x3ec7168c, 0xa6e74c4f, 0x5ab4ce97, 0x1dfa1072, 0xaac87cb2, 0x8e74e95c, 0xa61b122b, 0x64fdc92a,
+    0xd04a96b0, 0xceb78c7a, 0x8bf96e8f, 0xedca205c,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x48000000, 0x6c000000, 0xa2000000, 0xb9000000,
+    0x33800000, 0x7bc00000, 0xd1a00000, 0x96300000, 0x4d880000, 0xa6c40000, 0x8c260000, 0x26f30000,
+    0xeead8000, 0x5cb64000, 0xa5cd6000, 0xdca21000, 0x33b64800, 0xd948dc00, 0xcbe13e00, 0x6d57dd00,
+    0x85991980, 0x5cbf6040, 0x2bcb6020, 0x67a11030, 0x6933c818, 0x690a9c2c, 0x45825e12, 0xe4c2cd1b,
+    0x4524d1a8, 0x3d72fc6e, 0x496abe2c, 0x67129d2e, 0xe6f9f9ac, 0x00ab3049, 0x57b04821, 0xb74bdc02,
+    0x00e4be13, 0x1fd59d0b, 0xe9da79a5, 0x38da7052, 0x775ea815, 0x36988c0c, 0xca3c160b, 0xe98e112d,
+    0xa8c3efb7, 0xf726216c, 0x1c762781, 0xeeefbd77,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x38000000, 0x64000000, 0xfe000000, 0xdb000000,
+    0xb7800000, 0xcd400000, 0xdea00000, 0x6b300000, 0x02680000, 0xfa440000, 0xfb220000, 0xaf770000,
+    0x824c8000, 0x92b5c000, 0x1c292000, 0xbf64f000, 0x6f505800, 0x0abb7c00, 0xe5bdca00, 0x343ec500,
+    0x367fa180, 0x095fdf40, 0x282b2020, 0xd963f010, 0xa054d838, 0xeb3abc24, 0xaffeea0e, 0xc7193519,
+    0xb50979bf, 0xb9126376, 0xc3994a0d, 0x33cf0523, 0x40f4818f, 0xfb0c2f7e, 0x6a17f82e, 0x581d4c37,
+    0xcc883239, 0xe7548939, 0x66bb1383, 0x37be964e, 0xdd3913af, 0xf8f99653, 0x329d93ae, 0xa948567b,
+    0xcd36b3a6, 0x6d6ba651, 0x6bc26b84, 0x19611a43,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x88000000, 0xdc000000, 0xe6000000, 0x97000000,
+    0x8d800000, 0xdc400000, 0x17600000, 0x81300000, 0x12380000, 0x3d440000, 0x35e20000, 0x64f70000,
+    0x131f8000, 0x52104000, 0x606ce000, 0xfc4c3000, 0x479d3800, 0x18d12c00, 0x58ca8200, 0x855f4b00,
+    0xfc739180, 0xc7dc6dc0, 0xa9b6e020, 0xaeff3010, 0xeae0b838, 0x95766c3c, 0x3959e222, 0xaa733b37,
+    0x38da49b9, 0x083571e5, 0x1cbb5a03, 0xb6825727, 0x7fc42bbd, 0x60220adc, 0x0dd7f386, 0x504816e8,
+    0x699fc9ac, 0xb3d631d0, 0xc34a3a1d, 0x46192738, 0xba9273be, 0x7cab56f1, 0x3beea98d, 0x9f0d41fc,
+    0x117c621c, 0xdba07b26, 0x9e932983, 0x8eaa01f6,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x78000000, 0x44000000, 0x06000000, 0x57000000,
+    0xdc800000, 0x80400000, 0x4ce00000, 0xcdd00000, 0xa4780000, 0x45440000, 0xe9620000, 0xea170000,
+    0x7e5f8000, 0x2937c000, 0x05a82000, 0xcb3b3000, 0x31233800, 0xb2f02400, 0xdd8ea200, 0x164a1700,
+    0xa66b3680, 0xb718d7c0, 0x1a522020, 0x0ab83010, 0x40e6b838, 0x57d4e43c, 0xa17b021e, 0x40c1e711,
+    0xdea7ae81, 0xb83003d5, 0x2e2d9a17, 0x87fa3330, 0x758594ab, 0x0fc2c0cf, 0xf6211697, 0xa674e7d0,
+    0x81ce9823, 0xe8afd403, 0x42b83a0e, 0xdce1c307, 0x05d10c8d, 0x787e14c5, 0xf744acb3, 0xc065e4d8,
+    0x259034b1, 0x249930c0, 0xb2958e8a, 0x581833f8,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x68000000, 0x0c000000, 0xde000000, 0x15000000,
+    0x86800000, 0x78400000, 0x39200000, 0xa8f00000, 0x55380000, 0x77440000, 0x68a60000, 0x25370000,
+    0xd3d98000, 0xa2574000, 0x130e6000, 0xdb1df000, 0xda332800, 0x0a5e7400, 0x13912200, 0x23ebf900,
+    0x170dcf80, 0xf91b8dc0, 0x99306020, 0xa5def030, 0x5b54a838, 0x7b8a340c, 0xde58c21a, 0x69924903,
+    0x04ef07b7, 0x6a8849c5, 0x7add8a01, 0x52d5cd2e, 0x924b0db6, 0x8abac4e6, 0x7780e7af, 0xecc6f9ee,
+    0x6a66c235, 0x32514930, 0x6b0887b7, 0x6f1c09f9, 0x50346a07, 0xa55c7d2a, 0x5211c58d, 0x162d00c8,
+    0xc5eb0d8b, 0x960ac4c6, 0x2598e785, 0x1672f9d8,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x78000000, 0xf4000000, 0x72000000, 0x93000000,
+    0xcd800000, 0x95c00000, 0x67e00000, 0x70300000, 0xb8480000, 0x48c40000, 0x27620000, 0xed750000,
+    0x6eeb8000, 0x1857c000, 0x56bda000, 0x7eea9000, 0xf0511800, 0x0abaac00, 0xf0e98e00, 0x9d56ef00,
+    0xd23c7980, 0x49ad1e40, 0x31f7a020, 0x9dab9010, 0x73f09828, 0xd6ac6c1c, 0x3a75ae1e, 0x496abf3d,
+    0x3a91419c, 0x86dbe264, 0x841b1613, 0x647f8335, 0x468a57b1, 0x56a46140, 0x10914190, 0xd1dbe27f,
+    0xe39b161d, 0xe6bf8313, 0xe6ea579e, 0xd4546142, 0x70b941ae, 0xefefe24a, 0xd6d11635, 0xa6fe831d,
+    0x70cbd7a9, 0x1982a144, 0xd7c561ba, 0x2ce3b253,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x58000000, 0x1c000000, 0xa2000000, 0x43000000,
+    0x97800000, 0xb3c00000, 0x9d200000, 0x2a300000, 0x9a380000, 0x84440000, 0xbee60000, 0xcf110000,
+    0xfc0a8000, 0xe47ec000, 0x65a2e000, 0x44721000, 0xc3d99800, 0x12d0fc00, 0x1829be00, 0x26cc1900,
+    0x29ded980, 0xf5d3b5c0, 0x41a86020, 0x7c0cd030, 0x247b7808, 0x45a2ec14, 0x14702616, 0x9bdce507,
+    0x0ed767a8, 0xba2facd0, 0x65ceb985, 0xbe5b65dc, 0x4615180f, 0xdc8f3c2e, 0x5639de38, 0xbe44c932,
+    0xc1e3a191, 0xaa9059e4, 0x54cac612, 0xf2daf535, 0x5e50ff93, 0x006a50e3, 0xfa2b8787, 0x85c8bcd8,
+    0xce5ba18f, 0x4e1459ef, 0x988cc601, 0xe83bf538,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x88000000, 0x9c000000, 0xd2000000, 0x3f000000,
+    0x58800000, 0x7f400000, 0x10e00000, 0xc2500000, 0x04480000, 0x1bc40000, 0x4da20000, 0xd5b30000,
+    0x5a9e8000, 0xa6c84000, 0x3782e000, 0xefc79000, 0x93a47800, 0xd0b00400, 0x711b5a00, 0xdb0ef700,
+    0xfb277a80, 0xb7f21e40, 0x15fc6020, 0x875fd010, 0x9a6e9818, 0x57b39434, 0xad9d2222, 0x024df327,
+    0x4ac2a0b4, 0x4824a94f, 0x9571fab6, 0x79be5e4f, 0x453c803c, 0x0c7b4014, 0x559c603b, 0xe64fd015,
+    0x3cc69805, 0x6127941d, 0x34f72234, 0x2f7af339, 0x9f1e209f, 0xc60fe954, 0xa4a59a81, 0x54358e52,
+    0xbc581836, 0x24efd421, 0xe9f5c201, 0x50fd6322,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xb8000000, 0x04000000, 0xb2000000, 0xbb000000,
+    0xb9800000, 0x14400000, 0xd9200000, 0xe0700000, 0xf6b80000, 0x39c40000, 0xa7660000, 0xf6550000,
+    0x754e8000, 0xdc39c000, 0x48022000, 0xac069000, 0xae065800, 0xdd068c00, 0x08854200, 0x12c37900,
+    0xc6e74b80, 0x9617a1c0, 0x766ca020, 0x3b4f5030, 0xb13c7828, 0x88841c34, 0xd2c51a2e, 0x66e0f501,
+    0x461489ac, 0xce6918ee, 0x3f4fcb8e, 0x033b61f5, 0x3380003e, 0x6b40003c, 0x72a0003b, 0x9f30003b,
+    0x2e18002b, 0xc9f40012, 0x3afe001f, 0x94e1001c, 0x9d10800a, 0x07e8c016, 0x430aa020, 0x661a5001,
+    0x65f2f816, 0x94fddc37, 0x49e73a05, 0x9596652f,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x98000000, 0x54000000, 0xfe000000, 0xc9000000,
+    0x37800000, 0x18c00000, 0xbee00000, 0x14f00000, 0x19780000, 0xb1440000, 0xff260000, 0x55910000,
+    0xa94b8000, 0x24d8c000, 0x3990a000, 0xb348f000, 0xabd8c800, 0xe314fc00, 0xfa09a600, 0x90fe2700,
+    0x80845f80, 0x26465fc0, 0xb1a32020, 0xc2d43030, 0x2b6e6808, 0xe8cd0c1c, 0xa91aee26, 0xd2f21b15,
+    0x347d59bf, 0xc0c088f2, 0x0ae7b7ad, 0xbaf293f6, 0x387aee07, 0x4ac21b29, 0x4de559a8, 0xdc7488e5,
+    0x4339b786, 0x12e793c2, 0x2ef76e30, 0xe67bdb11, 0xf3c67982, 0xe260b8e6, 0x90b7dfba, 0x03da9fc6,
+    0xcf15801d, 0x000dc005, 0x4ffd2039, 0xb201301f,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x48000000, 0x94000000, 0xe2000000, 0xb3000000,
+    0xf5800000, 0x8cc00000, 0x04e00000, 0x36300000, 0x78280000, 0x73440000, 0xbf220000, 0x4d510000,
+    0xb5da8000, 0x500e4000, 0x5192a000, 0x993c1000, 0x823a1800, 0xd3b96c00, 0x857d8600, 0xbe9e1700,
+    0x432cf980, 0x72c29540, 0xa1e02020, 0xe2b65010, 0xaa6ab808, 0xd2e47c14, 0x77351e12, 0x56ad3b25,
+    0xce01dfb8, 0x9d01926c, 0x5884419d, 0xec47e973, 0x64a7be29, 0x6c912b09, 0xd1bbc784, 0x0678fe6d,
+    0x7319c7a5, 0x43e9fe4a, 0x48234780, 0x52d7be5f, 0x3e19e792, 0x136fae69, 0x1061ffbd, 0x8477c268,
+    0xa98ef9af, 0xa353954e, 0x78daa02c, 0x40881038,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0xa8000000, 0x24000000, 0x4e000000, 0xc9000000,
+    0xef800000, 0xd0c00000, 0x1c600000, 0x48500000, 0xc6f80000, 0x89440000, 0xc9a20000, 0xb1b30000,
+    0x096e8000, 0xdd594000, 0x13f72000, 0x3c4b7000, 0xacafc800, 0x7cbabc00, 0xe067e600, 0x7a543300,
+    0x7dfeba80, 0xbdc1e3c0, 0xfde1a020, 0x31963010, 0xe51ae818, 0xb9d2cc34, 0x373eae2a, 0x3ea3cf09,
+    0xcf347c93, 0x0a29a0f2, 0x03fc529b, 0x4cc72fe4, 0xfe650e3f, 0x5b52ff36, 0xd67a1483, 0xf3052cdf,
+    0x80815cab, 0xb241d0c7, 0xbd251a92, 0xe5f0d3d5, 0x594fc82a, 0x992abc04, 0x537fe63c, 0x56803320,
+    0x6744bab9, 0xf0a6e3df, 0x46352020, 0x85a87030,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xc8000000, 0xf4000000, 0x9e000000, 0xc9000000,
+    0x0e800000, 0xae400000, 0x75600000, 0xbc700000, 0x92d80000, 0x9ac40000, 0x94260000, 0x22950000,
+    0x9c6d8000, 0xb83a4000, 0x6a75a000, 0x6fdff000, 0xaa42c800, 0x4365b400, 0x91719200, 0x7a5d7b00,
+    0xc1851b80, 0x85c30ac0, 0x67a02020, 0xbc51b030, 0x3e496828, 0xb7ab442c, 0x1e98da32, 0x8ca78f3d,
+    0xd1d4a9a7, 0x698fc1f2, 0x1b0c53a3, 0xbf49fedb, 0x8d299215, 0x8ed97b33, 0x80c31bbe, 0xab260af7,
+    0xa115a010, 0x6aaff037, 0x5e1ac82b, 0x33e1b41b, 0x26379236, 0xd9b87b22, 0x5fb09bb4, 0xc07d4aeb,
+    0xe2138006, 0xe32b402b, 0xefde2007, 0x6a40b004,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xb8000000, 0xac000000, 0xca000000, 0xb1000000,
+    0x17800000, 0x70400000, 0x4a600000, 0xa3100000, 0xeb680000, 0x39c40000, 0x2d260000, 0x25f50000,
+    0xceba8000, 0x4709c000, 0x21d76000, 0xc0cd5000, 0x2bf60800, 0x61b9fc00, 0x278a2200, 0x6d11e300,
+    0xe4680080, 0x09448f40, 0xd9e5e020, 0xad509030, 0x260f6828, 0x4e55ac14, 0xbc88aa2e, 0xfb90df2b,
+    0xcba9c2b2, 0x7764fc6c, 0xee9608a5, 0x3229736c, 0x9821c21a, 0x8c70730c, 0x99fbe8bc, 0x89ede371,
+    0x4000aa37, 0x6004df3e, 0xf007c2ac, 0xe805fc75, 0x14028891, 0x6601b35f, 0x7b022218, 0xa685e336,
+    0x67c600bb, 0x3a258f7f, 0xe971602f, 0x48785005,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xe8000000, 0x2c000000, 0x82000000, 0xaf000000,
+    0x51800000, 0x58c00000, 0xfa600000, 0xbe900000, 0x31c80000, 0x5f440000, 0xbba20000, 0xdc710000,
+    0x371d8000, 0x58ab4000, 0x2a576000, 0x99af1000, 0x36d5f800, 0xafea4c00, 0x9df1e600, 0x77dd2300,
+    0x46cedc80, 0x8ac49a40, 0x6d62e020, 0xbb105010, 0x9f089808, 0x4c205c2c, 0x55b39e3a, 0xc9f92f0b,
+    0x347fdaa0, 0xaeb8e96b, 0xdbd924b4, 0x84cfd646, 0x05c68616, 0x8ce23313, 0x0bd324be, 0x496ad670,
+    0x70b10614, 0xcb7c7307, 0xc73bc4b1, 0xd71f8647, 0x88ae1e3a, 0x52526f04, 0xeda8ba97, 0x70d7f954,
+    0xaeecdc8b, 0xe1759a70, 0xd19f6004, 0xb5eb103b,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xd8000000, 0x54000000, 0x3e000000, 0xd7000000,
+    0x6b800000, 0x2c400000, 0x0da00000, 0x1b500000, 0x50080000, 0xb0c40000, 0x3a620000, 0xd2b70000,
+    0x1afe8000, 0x499ac000, 0x67aca000, 0xda90f000, 0x62ed3800, 0xcbb01c00, 0x0e798200, 0xb25efd00,
+    0x244db280, 0xa9615440, 0xff302020, 0x85bd3010, 0x19bf1838, 0x0bba2c34, 0x86b81a36, 0x0c3e1115,
+    0x10f9088f, 0x309fb575, 0xe32c10ba, 0x1956995b, 0xa5088a9b, 0x5e454862, 0x10a3a23a, 0xa9d0cd1d,
+    0xc8ce2a8f, 0x76a6b860, 0x6ad21a05, 0x7d4d1129, 0x5de588b7, 0xd4f2757a, 0x1e5e3083, 0x3e4ca956,
+    0xa861128d, 0x1fb1a463, 0x707d1821, 0x855d2c1a,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x68000000, 0xb4000000, 0x1e000000, 0xed000000,
+    0x2b800000, 0x20400000, 0xdde00000, 0x8c700000, 0x17a80000, 0xd6c40000, 0x6e260000, 0x0dd50000,
+    0xfc388000, 0xd61f4000, 0xddcfa000, 0xa0f7f000, 0x8f6ce800, 0x5de39c00, 0x4c716200, 0xb7adff00,
+    0xc6c2db80, 0x06233940, 0xb9d12020, 0xe23db030, 0x3b1bc828, 0xf64b2c04, 0x80b22a1a, 0x5289932d,
+    0xd1975187, 0x5bd95a7b, 0x616c99aa, 0xa8e27678, 0x0bf6b3bf, 0x45efe557, 0x3427e217, 0xe6d3bf2c,
+    0x56bbfbae, 0x0fda8951, 0x0f6ce808, 0x9de39c1b, 0xec716225, 0xa7adff3c, 0xaec2db81, 0xb2233964,
+    0xa7d1201d, 0x0f3db033, 0x109bc832, 0xd60b2c0e,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xb8000000, 0x84000000, 0xc2000000, 0x61000000,
+    0x8d800000, 0x32c00000, 0xdca00000, 0x9cf00000, 0x33d80000, 0x06440000, 0x17e20000, 0xe8970000,
+    0x268d8000, 0x5ee9c000, 0xf5ba2000, 0x6a171000, 0x16cb8800, 0xf20f2c00, 0x25abf600, 0x39d81500,
+    0xfb415f80, 0x8c65aac0, 0x7155a020, 0x2aa9d010, 0x3b5c2838, 0x8b01fc2c, 0xc0825e2e, 0x11442921,
+    0xc16321b0, 0x52d593d8, 0x376a8983, 0xf579afdc, 0x8fb0f7af, 0x33bd96db, 0x8915de3a, 0x1a4ee91c,
+    0xfd4e81a3, 0x73c843ef, 0xfd8ca194, 0x966b53f2, 0xd8fd2981, 0xed777fd5, 0xe7195fab, 0x29e1aacb,
+    0x6f97a031, 0x580ed017, 0x88a9a83b, 0xaa5c3c16,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0xc8000000, 0x04000000, 0xfe000000, 0x37000000,
+    0xd6800000, 0x1ec00000, 0x0ce00000, 0x3b700000, 0x1d680000, 0x3b440000, 0x5aa20000, 0xae510000,
+    0xa47c8000, 0xcf9a4000, 0x8a6e2000, 0x3dc0f000, 0xac663800, 0x36b57400, 0xa90a1600, 0xe1f5f700,
+    0x99adb380, 0x4ea51740, 0xd850a020, 0xb77bb010, 0x771c9808, 0x6babc424, 0xe3a00e32, 0x77d17301,
+    0x103d1dbf, 0x7fbfd44d, 0xb8f92595, 0x005ba057, 0x8b0fb3ab, 0xa4f4177a, 0xa22c201d, 0xdfe1f03b,
+    0xe3f2b813, 0x4cab3403, 0xbd263611, 0x4d140738, 0x72df0bab, 0xbbce234b, 0x7716963a, 0xdbdfb71a,
+    0xea4b93b0, 0xe751e777, 0x05fc980c, 0x27dbc414,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xc8000000, 0x84000000, 0xb6000000, 0xad000000,
+    0x1e800000, 0x7b400000, 0x6b600000, 0x9e300000, 0xe7f80000, 0xfcc40000, 0x30a20000, 0xc7130000,
+    0x8a298000, 0x9b4a4000, 0xda7a2000, 0x5800b000, 0x5c05e800, 0xaa043400, 0x67003a00, 0x49848b00,
+    0xfac00980, 0x15a713c0, 0x3d91a020, 0x7769f010, 0x952e4818, 0xdecac40c, 0x72bdf232, 0x66a30f21,
+    0x1a145bad, 0x3ca9eceb, 0x5409b3a7, 0xcf19d8ce, 0xef3389a2, 0x137a53fb, 0x80800033, 0x22400002,
+    0x6be0000b, 0xfc700037, 0xec18003a, 0x30b40032, 0x14ba0006, 0x73a70004, 0x28938033, 0x45ed402c,
+    0xec69a036, 0x66adf03b, 0xdb0c4829, 0x5299c42d,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x08000000, 0x9c000000, 0xde000000, 0x2f000000,
+    0xf8800000, 0x7fc00000, 0xf8600000, 0x1df00000, 0x73680000, 0xbc440000, 0xfd260000, 0x8b570000,
+    0xf17d8000, 0x5d9c4000, 0x29aee000, 0xf1a3f000, 0xc695f800, 0x101d0400, 0xbbeede00, 0x0b879300,
+    0x79428780, 0xf8a15740, 0xa5156020, 0x8558b030, 0x754e9838, 0xad96b42c, 0xd29bc602, 0x412a6727,
+    0xd66221b7, 0x1af0804b, 0x47ea599e, 0xa581c46f, 0xbe4267a6, 0x2c21a75b, 0x0cd31806, 0xce3af414,
+    0x4e3d2632, 0x8e3d9722, 0x6e39d9b7, 0xde3e8464, 0xd63f079d, 0x4a3d175f, 0x943b8035, 0xbb3b402f,
+    0x43bb6009, 0x3c7bb003, 0xc41d1834, 0xd9e9f408,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x28000000, 0xbc000000, 0x86000000, 0x09000000,
+    0x5c800000, 0x31400000, 0xf4a00000, 0x96900000, 0x56580000, 0x10c40000, 0x73660000, 0xe4750000,
+    0x45ed8000, 0xafcd4000, 0xc31fe000, 0x36633000, 0x76f6c800, 0xd1ab2400, 0x19ecaa00, 0xf9cd3500,
+    0xf21a2c80, 0x4ee6aec0, 0x55b46020, 0x160b7030, 0x5cfca828, 0xcb51542c, 0x7bbb820a, 0x6ef4212f,
+    0x85abcea1, 0x83e8ffc2, 0xc6c886b7, 0x8f9a9bfc, 0x9f25ccb5, 0x16559ef9, 0x7d3aa817, 0xc0b45437,
+    0xbc8e021f, 0xecbd6138, 0x6f722ead, 0x726ecfca, 0x790bce9b, 0xd978ffd6, 0x9e9086aa, 0x9a5e9bc5,
+    0x1ec3ccb0, 0x76609ed5, 0x16f72807, 0xc1a91434,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x08000000, 0xd4000000, 0x26000000, 0x8d000000,
+    0xc8800000, 0xa9400000, 0xe7a00000, 0x39500000, 0x64980000, 0x00c40000, 0x1c620000, 0xd8b10000,
+    0x21ef8000, 0x6dcc4000, 0x0c58a000, 0x18a63000, 0x1ad0e800, 0xa8588400, 0x76a02a00, 0x63d2ed00,
+    0xb6db3280, 0x5ae3b8c0, 0x98f52020, 0x00cb7010, 0x54dfc808, 0x69e6f43c, 0xd1726202, 0x6b095935,
+    0x6d3e7089, 0x009091e3, 0x467b1892, 0x043155fa, 0xceae1291, 0xd768c8e2, 0x058ae833, 0x097d8419,
+    0x0fb5aa04, 0x266bad1c, 0x8f0e12b1, 0x2338c8e7, 0x8992e821, 0x50f98411, 0xfc77aa1c, 0x138aad32,
+    0xec7992b8, 0xc33088d5, 0x51284812, 0x39aeb408,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xa8000000, 0x64000000, 0x82000000, 0xf9000000,
+    0x21800000, 0x99400000, 0x31200000, 0xb0d00000, 0x88c80000, 0x27c40000, 0x32e60000, 0x60350000,
+    0x61fe8000, 0x1fb94000, 0x78192000, 0xcf0b1000, 0x65264800, 0x2ad1cc00, 0xcdc92a00, 0x98417900,
+    0x1ca63e80, 0x6f90b240, 0x12e9a020, 0xe7935030, 0x66efe828, 0x6d929c34, 0x2beec22a, 0x6617e519,
+    0x2faefca0, 0xf4b2577e, 0xff39dca8, 0x6cd84756, 0xf16f1484, 0xe7d1cb68, 0x064f9e80, 0x3c03e254,
+    0x5e06480e, 0x1701cc3b, 0xee812a32, 0x6bc57932, 0x14e03e90, 0xbb35b25e, 0x697f203f, 0x0f7e1007,
+    0xb478c831, 0xccf88c09, 0xa4380a17, 0xa45e6938,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x38000000, 0xe4000000, 0x8e000000, 0xfd000000,
+    0xd0800000, 0x63c00000, 0x9ca00000, 0x85100000, 0x35980000, 0x54440000, 0x34e60000, 0xebf30000,
+    0x656c8000, 0x50adc000, 0x850d2000, 0x069d7000, 0xd9c26800, 0x77a6c400, 0xbc927e00, 0xa0daf500,
+    0x8a255080, 0x97d590c0, 0x4bbfa020, 0xb597b030, 0x065dc818, 0x90e17424, 0xc5f7b60e, 0x686f8139,
+    0x282ce6a3, 0x3acd11ff, 0xf039c694, 0x2fd461e8, 0x6fbdae9f, 0x5b91a5f5, 0x6b5b50bb, 0x786290f8,
+    0x42352018, 0x7ac97028, 0x503c6818, 0xdfd1c42e, 0xc7b8fe1f, 0x8794353e, 0x015cf08b, 0x0b6120ce,
+    0x6fb6e81c, 0xc98f040e, 0xaf595e04, 0xc664851b,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x18000000, 0x8c000000, 0xda000000, 0x67000000,
+    0xb4800000, 0xebc00000, 0x8ce00000, 0x53f00000, 0x3d980000, 0x26440000, 0x3aa60000, 0x91510000,
+    0xd0ce8000, 0xe48cc000, 0x2d2e6000, 0x5efdd000, 0xd8f6b800, 0x631ce400, 0x42807e00, 0x46c69700,
+    0x6762d780, 0x50b340c0, 0x98bee020, 0x12541030, 0x3a485808, 0x0648f434, 0xf4482606, 0xf74e6323,
+    0xddcaf1b6, 0x1f0d23d9, 0x166c118d, 0xa7dd33ca, 0x2ee2498b, 0x88f4c7d0, 0xbb1cefa1, 0xee8264ee,
+    0x4cc67e16, 0x1867971a, 0x683457a1, 0xa97b80cd, 0xf9b68015, 0xdd38c033, 0xd0106009, 0x5ee8d012,
+    0x9e1e3819, 0x71012431, 0xe9809e39, 0xc847870d,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x98000000, 0x44000000, 0xde000000, 0x27000000,
+    0x28800000, 0x0f400000, 0x4e200000, 0xc0100000, 0x92180000, 0x1e240000, 0x38160000, 0x661d0000,
+    0x48248000, 0xc312c000, 0xd49f2000, 0xbe627000, 0x82b3f800, 0x334e6400, 0x6d1a0a00, 0x12a58900,
+    0x59566480, 0x873f9ac0, 0x7cb3f820, 0xc44e6430, 0x7d9a0a28, 0x49e58904, 0x517664a6, 0x242f9ad1,
+    0x182bf817, 0xf22a6439, 0x232c0a22, 0xe0a88907, 0xc56ae4b5, 0x39095ae1, 0x66bad833, 0x3471143e,
+    0xd1ad722c, 0x76e92d1e, 0x34cb4ea7, 0x56dc63d1, 0x69c06486, 0x3e629ad1, 0x42b7782c, 0x934ca422,
+    0x7d1d2a14, 0x8aa3f911, 0x1d539c96, 0x593cfed1,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xc8000000, 0xa4000000, 0x1a000000, 0x67000000,
+    0x8a800000, 0x26400000, 0xd8a00000, 0xe3b00000, 0x52b80000, 0xd0a40000, 0xe7b20000, 0x18bf0000,
+    0xcfa18000, 0x01344000, 0x80fa2000, 0x6a015000, 0x0f077800, 0x7e867400, 0x44446200, 0xd3a3bd00,
+    0xd7366980, 0x09fb77c0, 0xe5877820, 0xa8c67410, 0xb4e46238, 0x2413bd2c, 0x578e69b2, 0x1a5f77e9,
+    0x92b57826, 0xf1397409, 0x2965e21a, 0xe0d7fd25, 0x5d6c4984, 0x434a27d1, 0x28b80032, 0x47a4003d,
+    0x45320023, 0x2aff0023, 0xc5018037, 0x21844011, 0x42c22032, 0xfbe55037, 0xba957818, 0xa3c97410,
+    0x01fde214, 0xe183fd0c, 0xe2c64981, 0xabe127ec,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x78000000, 0x14000000, 0xea000000, 0xb9000000,
+    0x78800000, 0x64400000, 0x2be00000, 0x2f700000, 0x97480000, 0x43e40000, 0xd3720000, 0xc14d0000,
+    0x0ce78000, 0x94f1c000, 0x9a8da000, 0x68c0d000, 0x35a40800, 0x15966400, 0xdcbff200, 0x36eb5700,
+    0xfc741f80, 0x16cfc840, 0xff240820, 0xecd66410, 0xbddff228, 0xa0db571c, 0xaa5c1f9e, 0xb31bc845,
+    0x027e081a, 0x9c4f643e, 0xa6627236, 0xfdb39705, 0x53ec3f94, 0xe2f3d84e, 0xe58a203f, 0xf741102e,
+    0x0a61a802, 0xe3b2b435, 0xd0e9fa17, 0x2b70332b, 0x954c6db9, 0x46e55f64, 0x5df5b787, 0x9a0d7c7c,
+    0x1885f236, 0xf442572f, 0x83e19f8a, 0x33730856,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x88000000, 0xac000000, 0x6e000000, 0x5f000000,
+    0xa9800000, 0x6f400000, 0x59a00000, 0x5db00000, 0x49f80000, 0x55a40000, 0xe3b60000, 0xbef90000,
+    0x40248000, 0x6af24000, 0x145c6000, 0xda17f000, 0x1348b800, 0xb1dd2c00, 0x0b528a00, 0xbdeb7500,
+    0xf9ef4180, 0xcbec85c0, 0x42e8b820, 0x806d2c30, 0x0caa8a08, 0xa74f7504, 0x3bd941a2, 0xb65585eb,
+    0x356c383b, 0xe82f6c27, 0xf88eea22, 0x47bc851f, 0x9287f9b4, 0xe4c1a9fc, 0x37e23229, 0x6a925932,
+    0xf68bcb9a, 0xe8bef0f0, 0x83037984, 0xbf83e9d6, 0x24465224, 0xaa21a900, 0x27f573bc, 0x24dadcf7,
+    0xffd5739d, 0x6a2adcee, 0x298d73b0, 0x813edce9,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0xe8000000, 0x7c000000, 0xaa000000, 0x7b000000,
+    0xe0800000, 0xe3c00000, 0xc1a00000, 0x9d500000, 0xe1c80000, 0x6da40000, 0x1f560000, 0x86cb0000,
+    0x77268000, 0x6f914000, 0xdbe8e000, 0xa3b3d000, 0x34fa9800, 0xcb9be400, 0xbeeace00, 0x1e339500,
+    0x3ebf8e80, 0xb67f8040, 0xdf5a9820, 0xedcbe430, 0xdfa2ce18, 0xc0579514, 0x08498eba, 0xd1e4805f,
+    0xe3b4180a, 0x94fea42e, 0xfb9c2e20, 0x06ef452c, 0x8a35968a, 0xe8be2478, 0x677e3632, 0x44dae135,
+    0xee8f38a7, 0xfdc0214d, 0x9ca340b7, 0x74d71553, 0x5d8b969c, 0x91412449, 0x0d66b630, 0x0a74a110,
+    0x1e5f588a, 0x3e4db16e, 0x90e1b889, 0xf0356157,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0xba000000, 0xeb000000,
+    0x05800000, 0x28400000, 0xc3e00000, 0x39d00000, 0x62380000, 0x07e40000, 0x67d60000, 0xe73b0000,
+    0x9f608000, 0xf3114000, 0xbe19e000, 0xf2955000, 0xa85e1800, 0x2475cc00, 0xe10e1200, 0x8e890700,
+    0x05cd8280, 0x6fa9c3c0, 0xbc3e1820, 0x42e5cc30, 0x9f561218, 0xe3fd0724, 0x2e4382ba, 0xc2e6c3d7,
+    0x5f50980e, 0x83fb8c0a, 0xbe417219, 0x2ae6172e, 0x0352fa8a, 0x39fc1fd9, 0x5541f216, 0x2f67570b,
+    0x2b131a80, 0xfa1d4fd7, 0x6c91ea0d, 0x4d5d9b15, 0x2cf38881, 0x9dca08e3, 0x8bab08a8, 0xd23f48dd,
+    0xdfe4e8b9, 0x23d118ff, 0x793a70b5, 0x7a6594ef,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x28000000, 0xac000000, 0x6e000000, 0x95000000,
+    0x5e800000, 0xa4c00000, 0xbaa00000, 0x70f00000, 0x21380000, 0x7ca40000, 0x89f60000, 0x31b90000,
+    0x3d678000, 0x45d74000, 0x498c6000, 0xc8fcf000, 0xdc87e800, 0xbfc09400, 0x2124d600, 0xd2b3bd00,
+    0xbb5d5380, 0x74f63fc0, 0x233fe820, 0xa7a49430, 0x3272d608, 0xe3fabd1c, 0x8f02d38a, 0xe1857feb,
+    0x8d45883b, 0xcbe16415, 0x8d12be1f, 0xbd2d6935, 0x5d0a65a4, 0x8b3a32f7, 0xcba733b3, 0x7c73cfca,
+    0x06ff801d, 0xf9834009, 0xe9426023, 0x59e1f03a, 0x2e16680b, 0xf2aed423, 0x654f3619, 0x6e580d2f,
+    0xca76dbbe, 0xd7fa5bd0, 0x4504d63a, 0xc683bd1c,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x88000000, 0x74000000, 0x0a000000, 0xa5000000,
+    0xd3800000, 0x6d400000, 0x54200000, 0x3c700000, 0xbca80000, 0x76240000, 0xbd760000, 0x7d2f0000,
+    0x32678000, 0xccd24000, 0xf71ca000, 0xa66fd000, 0x9d436800, 0x1c243c00, 0xa877ba00, 0xc6a84900,
+    0x5b249f80, 0x1af37f40, 0x1a6b6820, 0xc3403c30, 0x2321ba38, 0x26f7491c, 0x846b1fa2, 0x1c453f5d,
+    0xdda1c822, 0xec30ec19, 0xb70d520c, 0xd1153507, 0x8efe05b7, 0x4bf9a652, 0x287fbf8d, 0x2d3eef44,
+    0x7d1ca003, 0xc36fd028, 0xaec36803, 0x01643c3d, 0x7457ba32, 0x8ed84900, 0xed8c9fbe, 0xc9d77f6a,
+    0x749d6827, 0xd32f3c26, 0x45663a29, 0xd6550921,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x68000000, 0xac000000, 0x32000000, 0xbd000000,
+    0xf9800000, 0xbec00000, 0xaba00000, 0xc0300000, 0x8bd80000, 0xb5a40000, 0x0f320000, 0x6f5d0000,
+    0x02e38000, 0x72514000, 0xa8c86000, 0x7b0a1000, 0xf128a800, 0xebd9ac00, 0xa5a0e600, 0x37337500,
+    0x5b5d6d80, 0xf4e598c0, 0x5150a820, 0xde4dac10, 0x814ae628, 0x1dca753c, 0x3e8ced9a, 0x45e9d8eb,
+    0xc97b482c, 0x6a16fc3f, 0x212a2e16, 0x33d9c913, 0xc1a4a3b0, 0x593301db, 0xbc5ec38e, 0x1c6411d2,
+    0x2415ebb5, 0x762cfdd8, 0x135d6db8, 0xe8e598eb, 0xab50a816, 0x3f4dac1b, 0x22cae63f, 0xb20a7536,
+    0x5eacedab, 0x8619d8ca, 0x10834826, 0xa142fc2e,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xd8000000, 0xfc000000, 0x32000000, 0xa9000000,
+    0x5c800000, 0x40c00000, 0xe3200000, 0xa8d00000, 0xbe080000, 0x9d240000, 0xbbd20000, 0x4f890000,
+    0x5f648000, 0x5b34c000, 0x0dfee000, 0x67fd1000, 0x72fd8800, 0x3c7a0400, 0xc5bc1600, 0xa21b1100,
+    0xb6090280, 0xd920c640, 0x45d58820, 0x1c8e0410, 0x8ee61608, 0x89761104, 0x351f82b6, 0xd98d067f,
+    0x7867e82c, 0xbcb3d43a, 0xa8bf7e1f, 0x409c0514, 0x85c89c8e, 0xfb81d355, 0xe7409c83, 0x6665d35d,
+    0x9fb29c91, 0x913cd357, 0xa6de1cb1, 0xab2c1357, 0x22f2fcbe, 0x2a58037f, 0x53ebf4b7, 0x0dd6c74d,
+    0x7889029b, 0x60e0c645, 0x0275883c, 0x189e0433,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x68000000, 0x84000000, 0x0e000000, 0xe1000000,
+    0xa7800000, 0x15c00000, 0xb3e00000, 0xbaf00000, 0x29480000, 0x01e40000, 0x69f20000, 0x1dc90000,
+    0x00a18000, 0x6b514000, 0xcd9d2000, 0x7db9f000, 0x19aa4800, 0x37961c00, 0x3dfe5e00, 0x4c895700,
+    0xba040b80, 0xc705f440, 0xe2824820, 0x0c421c10, 0x2f245e08, 0x2a94572c, 0x887f8b9a, 0x7a49b461,
+    0x7564e823, 0x48b7ac28, 0xaf68b621, 0xb8f7fb29, 0x924d3db6, 0xb1634f4f, 0x66b255a9, 0xfe68a368,
+    0x7774439b, 0x038ee87e, 0x0c87961e, 0x3d470b29, 0x70a6f5a8, 0x6354136a, 0xd9992b8c, 0xabbc0446,
+    0x14a80021, 0x1a140026, 0xc73a0000, 0xb9ed0008,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x88000000, 0x24000000, 0x42000000, 0xa1000000,
+    0x99800000, 0x3d400000, 0x76e00000, 0x89b00000, 0x56d80000, 0x17e40000, 0xb0320000, 0x1b9f0000,
+    0x79008000, 0x95864000, 0x2b462000, 0x8de69000, 0x1d314800, 0x941dec00, 0xbf47fa00, 0x77e4bf00,
+    0x60378180, 0x739fdcc0, 0xcd034820, 0x5f82ec10, 0xae477a38, 0x5662ff24, 0x8171a1a2, 0x7b794cc9,
+    0x0bb20030, 0x57df0038, 0xfe60801e, 0x9576402b, 0xc17e203f, 0x96b2902b, 0x205b4805, 0x0426ec2d,
+    0x99157a0a, 0x4d0dff09, 0x12492183, 0xf5ab0cc7, 0x1d9e203f, 0x4a029036, 0xc5034813, 0x3b82ec07,
+    0x0c477a13, 0x6762ff31, 0x90f1a182, 0x62394cd7,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x88000000, 0xac000000, 0xa2000000, 0x77000000,
+    0x5d800000, 0xf0400000, 0xeea00000, 0xd8100000, 0x42080000, 0x61a40000, 0x81960000, 0x6c4f0000,
+    0xe2058000, 0x57024000, 0x8d826000, 0x48449000, 0xcaa7f800, 0xd6178c00, 0x970a9200, 0x4b20cd00,
+    0x2c522c80, 0x72aa9240, 0xd4b1f820, 0xcd188c30, 0xae2f1238, 0xa8728d0c, 0x27784ca2, 0x585a026b,
+    0x22080008, 0x91a4002d, 0xe996002f, 0xf04f0030, 0xc8058019, 0x8c02401d, 0x72026038, 0xcf049005,
+    0x7987f837, 0xfe478c27, 0x3ba29203, 0xf294cd23, 0xefcc2cb3, 0x7f41924a, 0xb7227832, 0xf655cc1e,
+    0xc1a8f225, 0xb7345d0b, 0x605dd48a, 0xc6091e65,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x48000000, 0xe4000000, 0xd2000000, 0x7f000000,
+    0x60800000, 0x30400000, 0x7ce00000, 0x87300000, 0x16280000, 0x9be40000, 0x3bb20000, 0x386b0000,
+    0x9e058000, 0x39004000, 0xe5842000, 0xfbc71000, 0xb621b800, 0x50942400, 0x5f7f1200, 0x45bc1f00,
+    0xaa193d80, 0x2f4c3340, 0xf713b820, 0x83bf2410, 0x6f1a9218, 0x84cc5f0c, 0x0dd51d92, 0x1c1f2379,
+    0xc2480014, 0x8894000f, 0xe37a0000, 0x6bbf0000, 0x9b1f800d, 0x4ecf4018, 0xeed3a031, 0x029c5039,
+    0xbb081816, 0x39f77402, 0x4b888a38, 0x51b42b2f, 0xcb6a17bc, 0x98804858, 0xdc43b783, 0x2ae3184a,
+    0x1a342f88, 0x41ab2c43, 0x2f2705b9, 0xa5175750,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x68000000, 0x9c000000, 0xca000000, 0xb3000000,
+    0x86800000, 0x3d400000, 0xd0e00000, 0xadd00000, 0x5e580000, 0xf7e40000, 0xad560000, 0x921f0000,
+    0x9e848000, 0x19454000, 0x0ee3e000, 0x58d6d000, 0xc9ded800, 0x6324d400, 0x0c71ba00, 0x616c1f00,
+    0x3b6dda80, 0x206a58c0, 0xdae8d820, 0x3dabd430, 0x364d3a38, 0x811d5f04, 0x28003a9a, 0xbc0788e7,
+    0x3a048012, 0xcb05401c, 0x7283e019, 0x6b46d00b, 0xa9e6d82e, 0x9850d40c, 0xe59fba25, 0x1a471f11,
+    0xd0675a8a, 0x619418eb, 0x3739b833, 0x43f7441d, 0x31ac822c, 0x544e1b2f, 0x4e1ab883, 0x748693db,
+    0x5a42b8b6, 0xf06293c2, 0x9194b8b4, 0x4f3d93e5,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x28000000, 0xfc000000, 0x62000000, 0x63000000,
+    0xa2800000, 0x93c00000, 0x84a00000, 0x07900000, 0xe6480000, 0x23a40000, 0x83160000, 0xf0890000,
+    0xa0878000, 0x60c74000, 0x5e26e000, 0x30509000, 0xd4ea5800, 0xd936f400, 0xc6d98e00, 0x816a3d00,
+    0x9674a880, 0x80bdec40, 0x9bdc5820, 0x92eff430, 0xcc360e08, 0xb9597d1c, 0xc12c488a, 0x68907c7f,
+    0xcecf8038, 0x0f634028, 0xb730e020, 0x2fd99038, 0x9cedd82b, 0xb531b43e, 0xdcdf6e21, 0x466aad10,
+    0x82f6f088, 0xee7f1858, 0xbcfbd629, 0xc738c929, 0x9f9b26a6, 0x8a0ed158, 0x274770bf, 0x5a615845,
+    0x28b2b629, 0x3f9b190c, 0x3a099e98, 0x2f45b56b,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x98000000, 0x8c000000, 0x0a000000, 0x09000000,
+    0x9f800000, 0x0cc00000, 0xbf200000, 0x64900000, 0x17d80000, 0x3c240000, 0x32160000, 0xe49b0000,
+    0x3fc18000, 0x71a2c000, 0x1bd76000, 0x1e3dd000, 0x76908800, 0x52de9c00, 0xc9a73600, 0x87d63100,
+    0x5c381880, 0xdb9538c0, 0xd35e8820, 0x4a619c30, 0xa470b618, 0xa2eff12c, 0xe02ef8a6, 0x380a28e3,
+    0xb4196022, 0x0f82d032, 0x84c7083f, 0x7b275c2f, 0xca91d609, 0x00d9213a, 0x2ca7f087, 0xa25274cd,
+    0xc1ff360b, 0x7732310a, 0xb10e18b8, 0xeb9e38e9, 0x63470805, 0x8be75c1b, 0x87b1d60b, 0x51492103,
+    0x36fff0bc, 0x17b674dd, 0xd949361c, 0xf2f9311a,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x68000000, 0x44000000, 0x0a000000, 0x31000000,
+    0xc8800000, 0x6cc00000, 0xf0200000, 0x19900000, 0x0b480000, 0xeb240000, 0x70120000, 0x170f0000,
+    0x6bc58000, 0xbfa14000, 0x7ad52000, 0x7829d000, 0xb1d6c800, 0x49ae6400, 0x81903600, 0x874d8900,
+    0x7526c880, 0x13129c40, 0xc28cc820, 0x81856410, 0x1847b638, 0x6ae3c90c, 0xceb6689a, 0xcd5a0c51,
+    0x3b2f2022, 0xf452d01c, 0x1be9480a, 0x3a742417, 0xb17a9626, 0xbcbe1917, 0x9c1aa080, 0x254f6876,
+    0xa020960e, 0x0195190e, 0xc74d209e, 0x95212865, 0x23103626, 0xaa8d8925, 0xc586c88a, 0x12429c57,
+    0x5be4c832, 0x06316414, 0xa19db605, 0xcb08c91c,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x68000000, 0xbc000000, 0x4a000000, 0xfb000000,
+    0x57800000, 0x41400000, 0x48a00000, 0x34f00000, 0x9a880000, 0x29a40000, 0x90760000, 0x004d0000,
+    0x92408000, 0xc3254000, 0x0fb0e000, 0xd12ed000, 0x9ed09800, 0xbdb95c00, 0xd0495a00, 0x9a431d00,
+    0x4f235b80, 0xbdb2be40, 0xfe2e9820, 0x3f505c30, 0x4dffda28, 0x346b5d24, 0xb8733b9a, 0xdc492e6f,
+    0xe846e032, 0xc023d00e, 0x8c30183d, 0x666c1c34, 0x6771ba08, 0x25c9cd22, 0x5c05c3b4, 0xba06e274,
+    0xa3074211, 0x13860120, 0xdf44619e, 0x45a33359, 0xd2733b9f, 0x77492e61, 0x77c6e019, 0xad63d005,
+    0xe6901837, 0x159c1c0d, 0xe079ba0a, 0xb62dcd07,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x78000000, 0xe4000000, 0xe6000000, 0xd1000000,
+    0xf5800000, 0xa6400000, 0xc2600000, 0xeeb00000, 0x75e80000, 0xd1640000, 0x8c320000, 0x472f0000,
+    0x68c68000, 0x9aa1c000, 0x1c102000, 0x15fdb000, 0x96994800, 0x55ae8c00, 0xd2030200, 0x0f071b00,
+    0x20869980, 0xe5c0fd40, 0xcd234820, 0x3d558c10, 0xdb1f8238, 0xb7eddb14, 0x4662399e, 0x18b38d79,
+    0x3ceca019, 0x90e77024, 0xb475e805, 0xb049fc3d, 0x95f6ea2e, 0x980ee702, 0xfa9073a4, 0xd13e1a40,
+    0x663b3bbe, 0x02bf9668, 0xb17eb9b2, 0x85594d59, 0xd188001c, 0xaed4000c, 0xec5a0019, 0x600b001d,
+    0x5e948034, 0xd73ec001, 0xe73ea03b, 0x8f38701a,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0xd8000000, 0x8c000000, 0x76000000, 0x05000000,
+    0x51800000, 0x20c00000, 0xa0600000, 0x69300000, 0x0d680000, 0x01e40000, 0xd1f20000, 0xc10f0000,
+    0x6ed78000, 0x019cc000, 0x1d6f6000, 0xe9e63000, 0x65f3e800, 0x4b0a6c00, 0xc5d3f600, 0xd91d8b00,
+    0x1a2d1580, 0x6c477540, 0xfd246820, 0x0f96ac10, 0x693c9638, 0x603bbb1c, 0x07befdb6, 0xc27d1963,
+    0x439f9e3d, 0xd26f2711, 0xf36383ac, 0xedb3ce54, 0x342d15be, 0xa5477569, 0x3aa46806, 0x5a56ac0d,
+    0x40dc962e, 0xa5cbbb07, 0xdcb6fd98, 0xafa91978, 0xce859e2d, 0x32442723, 0xec2603a9, 0x44100e6c,
+    0x4afdf597, 0x4cd9855f, 0x93cae033, 0x39b5f01c,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x18000000, 0xf4000000, 0x2a000000, 0x43000000,
+    0x70800000, 0x88400000, 0x58600000, 0x6cd00000, 0xb7280000, 0xd0e40000, 0xc0920000, 0x4d490000,
+    0xa3318000, 0xc13ac000, 0x88eaa000, 0x2242d000, 0x5b61d800, 0x3c50a400, 0x8f685200, 0x9083bb00,
+    0x58441280, 0xd063b340, 0x30d05820, 0x712a6410, 0x4de2f208, 0xd9116b2c, 0xf68dca86, 0x0397177d,
+    0x7dca0a2a, 0x0b70df00, 0x295f6094, 0xfc3c184e, 0x616d32b0, 0xa182a376, 0xbfc2a00f, 0xb2a6d018,
+    0xbbf3d822, 0xc119a420, 0x3459d232, 0xa5b97b06, 0xfaaeb299, 0xb121634e, 0x1b31800c, 0xc53ac035,
+    0x9aeaa038, 0x2542d017, 0x19e1d82f, 0x0310a426,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x48000000, 0x34000000, 0x2a000000, 0xfb000000,
+    0x0c800000, 0xec400000, 0x14e00000, 0xdc100000, 0x34380000, 0x20640000, 0x5c560000, 0x56d90000,
+    0xc9728000, 0xfdec4000, 0x597ca000, 0x4b05d000, 0x74829800, 0x60464400, 0x92e2f200, 0x71149f00,
+    0xddbe7480, 0x11a44bc0, 0x53701820, 0x7eea0430, 0xd9fe5208, 0x21414f34, 0xcd64ec92, 0x55d60fcd,
+    0x971cea2a, 0x5e539b0e, 0xa9dca68b, 0x57f444cf, 0x46acd4b7, 0x171c9bca, 0x9e56002f, 0x89d90032,
+    0x87f2800e, 0x0eac4017, 0x231ca02f, 0xb455d03b, 0x72da9812, 0x8b72441f, 0xe2ecf20c, 0x37f99f01,
+    0x6842f49e, 0x46e50bdc, 0xab10381b, 0xbebe943b,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x28000000, 0x84000000, 0x5a000000, 0xdf000000,
+    0x42800000, 0x86400000, 0x7ba00000, 0xa2500000, 0xdb880000, 0x3d240000, 0xbe160000, 0x1f2b0000,
+    0x6df18000, 0xcbdf4000, 0xddab2000, 0x2bb55000, 0xd07ab800, 0xcffcb400, 0xd8382200, 0x69594500,
+    0x2bed7680, 0x18130640, 0x462b3820, 0x6673f430, 0xbe1b0218, 0xcfc8152c, 0x6d81ce8a, 0x4cc4b261,
+    0xa9e29a36, 0x1bf5f107, 0x0add5488, 0x7a2e434d, 0x80704eb4, 0x471bf279, 0x1449ba18, 0x8040a124,
+    0xf2a7ecad, 0x31d2f76b, 0x02486cb9, 0xf142b77c, 0x7d24ccbf, 0x1e13a74f, 0xcf2cd4a7, 0xf5f10340,
+    0x67db6ea1, 0x03aea245, 0xaeb30210, 0x4dfc152f,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x98000000, 0xa4000000, 0x36000000, 0x7d000000,
+    0x62800000, 0x3ec00000, 0x6aa00000, 0xf1d00000, 0x74a80000, 0xdc240000, 0xb1160000, 0xbf0f0000,
+    0xcd748000, 0x287ac000, 0xb20e2000, 0xe7f33000, 0xcabe2800, 0x5aadb400, 0xc5260600, 0x05905d00,
+    0xeccf8a80, 0x5d504b40, 0x436aa820, 0x9a077430, 0xb7002638, 0x6d876d04, 0x0747a2a6, 0xd3e2ff69,
+    0x63302e2d, 0xe719e92f, 0x4eff8ca0, 0x8acf164b, 0x9851a29c, 0x75edff65, 0x1ac4ae08, 0x9ca3291c,
+    0x6cd1acaa, 0x062c264d, 0x7ae78aa2, 0x7fb44b70, 0x78dca83c, 0xc4d8742a, 0x96dca602, 0x3dd9ad27,
+    0x325f82ae, 0xf61ecf76, 0x067a8613, 0xab0e9d16,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xf8000000, 0x7c000000, 0xbe000000, 0x6f000000,
+    0x89800000, 0x28400000, 0xefa00000, 0xb0100000, 0x2d480000, 0xd2240000, 0x62560000, 0x0feb0000,
+    0x8ab18000, 0x79d9c000, 0xeface000, 0x21127000, 0x6bc9b800, 0xa3e52c00, 0xedb0f200, 0xd45b2500,
+    0xd5ee5c80, 0x57b36040, 0xb9583820, 0xed6cec30, 0x29741218, 0x0f3d552c, 0xe699e4be, 0xe5894c5f,
+    0x2f474a0f, 0xf221092b, 0x72512eba, 0x27ee8566, 0xbeb504a5, 0x43db3c43, 0x42aef21c, 0x79942533,
+    0xa509dc9c, 0xed81a07a, 0x9a455828, 0xa6a75c06, 0x9a914a25, 0xca8a0901, 0x06c0aea5, 0x0a674552,
+    0xd5f1e4a6, 0x13fd4c58, 0x51f94a07, 0x40fe093c,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x08000000, 0xc4000000, 0x5a000000, 0x15000000,
+    0x5e800000, 0x7b400000, 0x2f200000, 0x90500000, 0x06080000, 0xe3a40000, 0x9a120000, 0xbdaf0000,
+    0x65b78000, 0x21ba4000, 0x2b1d2000, 0x998f5000, 0xa461e800, 0xf7725400, 0xb25dca00, 0xcfa83100,
+    0x64b4c680, 0xbd3ab240, 0xf95e6820, 0x7e2c1410, 0xc472ea38, 0xcfd8611c, 0xf16aae82, 0x5956a671,
+    0xfe8c8236, 0xfbe47515, 0x10b044af, 0x4f3ac742, 0xb85c2ca9, 0x02a9d345, 0x6631468f, 0x207ff271,
+    0x557cc82b, 0x3bfd040c, 0xa8bc223e, 0x339a6504, 0xf1c90c88, 0x26c28374, 0xc1e2aea5, 0x35b2a67d,
+    0xf9be8230, 0x771b7535, 0x7f8fc485, 0x27648757,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x38000000, 0xfc000000, 0x76000000, 0x05000000,
+    0xd1800000, 0x65400000, 0x38a00000, 0xfa500000, 0x59b80000, 0xc7240000, 0xb6160000, 0x1e9f0000,
+    0xb1348000, 0x488fc000, 0xbface000, 0xc1b93000, 0x0b24e800, 0x0810ec00, 0xcf9eda00, 0xd2b34d00,
+    0xa2cace80, 0x258e9fc0, 0x8a286820, 0xdefb2c30, 0x68843a38, 0xe2c57d04, 0xa8e2a68e, 0x3b75b3ff,
+    0x02ac523d, 0x2c3e5131, 0xf8669c8c, 0x25b0cedd, 0xdc4ef4a0, 0x124be2f1, 0x2b4ace93, 0x6cce9fc4,
+    0x1c88680f, 0xcdab2c15, 0xaebc3a09, 0xb9a17d16, 0x81d4a69e, 0xbffab3e6, 0x0300d228, 0x3c859113,
+    0x90c47c8b, 0xc7e2fef4, 0x89f09c9c, 0x8b6fcefe,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0xa8000000, 0x9c000000, 0xba000000, 0x2d000000,
+    0x09800000, 0x34400000, 0x35a00000, 0x9a500000, 0x69780000, 0xda240000, 0x19120000, 0x685b0000,
+    0xa5b78000, 0x458f4000, 0x3b8be000, 0xd8885000, 0xba092800, 0x60ca2c00, 0xedafb200, 0xe39fe500,
+    0xab566e80, 0x9afd71c0, 0xa366a820, 0x55316c10, 0x6e4e5218, 0x6568b51c, 0xbffac6aa, 0x66e31de7,
+    0x63757a0e, 0x32e9991b, 0x78baf49a, 0xe9c7b8d1, 0x17e2f487, 0xf0f3b8d1, 0x3ba8f48c, 0x9c9cb8f1,
+    0x73d57496, 0x6d3cf8fc, 0x27831498, 0x6f44e8d7, 0x13245caa, 0xce92d4ed, 0xef1ea69b, 0x4a900dc9,
+    0x411db23b, 0x5194e514, 0x0799eeab, 0x755631e7,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x78000000, 0xf4000000, 0x32000000, 0xbf000000,
+    0x2d800000, 0x4fc00000, 0x0ce00000, 0x3b700000, 0xac280000, 0x1f640000, 0xcdb60000, 0x20490000,
+    0x69508000, 0x773dc000, 0xb37aa000, 0x0d5f1000, 0x604d3800, 0x89514c00, 0xc73bae00, 0x5b787f00,
+    0x815bc780, 0xa64c1740, 0x0455b820, 0x55b88c30, 0x393f0e08, 0xc27a6f24, 0x91d87f9e, 0x930d9b7d,
+    0xe6f2362c, 0xebeb231f, 0x2f83d183, 0xd8c5e477, 0x8d61f1bd, 0x22b33443, 0x55c8698f, 0x4290687c,
+    0x31d87f8e, 0xc30d9b72, 0xbef23623, 0x8feb2315, 0x6583d196, 0x93c5e461, 0x92e1f191, 0xd2733455,
+    0x74a869ac, 0x3620686a, 0x91107faa, 0xe7199b51,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x38000000, 0x7c000000, 0x62000000, 0x13000000,
+    0x4d800000, 0xe6c00000, 0xa2a00000, 0xf9f00000, 0x64f80000, 0xa9240000, 0xc2320000, 0x52dd0000,
+    0x9c958000, 0x892bc000, 0x4b6ae000, 0x5d8c9000, 0x579a5800, 0xd1f30c00, 0x50fb6600, 0x2f272d00,
+    0xff371380, 0x5658b9c0, 0x58d7d820, 0xe2cccc10, 0xe5fb8628, 0x2fa2bd0c, 0x8572cb8e, 0x7cb975df,
+    0x90c1de38, 0x47a1b114, 0x1171adbb, 0xcaba58f5, 0x95c4cd86, 0x3f2408f1, 0xb733f589, 0xb25d54f2,
+    0x76d5aba5, 0xabca25ee, 0xc77b6611, 0xe6e72d1b, 0xd21713a6, 0x6a68b9d3, 0xeb0fd828, 0x28d8cc2c,
+    0x83918621, 0x3eabbd0c, 0xf2ad4bb1, 0xe8abb5e4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xf8000000, 0x3c000000, 0xee000000, 0xc5000000,
+    0xa1800000, 0x91400000, 0x7fa00000, 0x53b00000, 0x0e880000, 0xfb240000, 0x33760000, 0x886d0000,
+    0x23358000, 0x4449c000, 0x6ac0e000, 0x61e0f000, 0x0a94e800, 0x8efd6c00, 0xbbc83a00, 0x06863500,
+    0x23c3e180, 0x16665640, 0xc2d76820, 0x8ed9ac30, 0x443d5a28, 0xea2f050c, 0xf417e9be, 0x913bca4f,
+    0x43abba1b, 0x0152f501, 0x0c9e8180, 0xc31b6668, 0x575d6001, 0x297d302b, 0xdb8a0810, 0x52a49c03,
+    0xc637521a, 0x15cb9909, 0xa380bbac, 0x12405374, 0x4f230191, 0x7976a672, 0x0f6b8019, 0x61b0c029,
+    0x758b6033, 0xf7a0302d, 0xf7b7881b, 0x4c895c01,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x98000000, 0xcc000000, 0xa6000000, 0x89000000,
+    0xb3800000, 0x1cc00000, 0x0a600000, 0x47f00000, 0x94380000, 0x20e40000, 0x90b60000, 0x3e990000,
+    0x03768000, 0x9e7ac000, 0x53c36000, 0x80e1b000, 0x00b18800, 0x86997c00, 0x9f751600, 0xa07c7b00,
+    0x16c79380, 0x95648fc0, 0x95710820, 0x3f7abc30, 0xc440f608, 0x3e270b14, 0x71d57ba6, 0x91ec43f3,
+    0x8b0d9609, 0xacbbbb12, 0x64a473a4, 0x6e96ffd3, 0x6f4d6004, 0xc29cb012, 0x8d710804, 0x337abc3e,
+    0x4240f62e, 0xe7270b3b, 0x5a557bab, 0x412c43d4, 0x276d963d, 0x624bbb1f, 0x431c73ae, 0x52b2ffeb,
+    0xf59b6004, 0xbbf5b004, 0x1a3f8814, 0x8de47c3c,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x48000000, 0x34000000, 0xfa000000, 0x4b000000,
+    0xe2800000, 0xb6c00000, 0xa3e00000, 0x75f00000, 0x37680000, 0xa2640000, 0xe5b60000, 0xf04b0000,
+    0x3b758000, 0xf7ac4000, 0xac86a000, 0xc7c05000, 0x6a631800, 0x11b73400, 0x6a482600, 0x4072e700,
+    0x5d2d6b80, 0x2e415540, 0x9e209820, 0x54907430, 0xc45b0618, 0x7eeef70c, 0x0620d392, 0xd892314d,
+    0x225da61e, 0xcbeea722, 0x1da3cba0, 0xf3d50561, 0x2ffd801a, 0xe0384020, 0x1e58a02b, 0xa5ef5036,
+    0xfca09813, 0x2250741c, 0x07bb0618, 0x3b1ef732, 0x7948d39e, 0x4ef63174, 0x3deba62b, 0x70a5a715,
+    0xc4564b81, 0xb2b94549, 0x209b2037, 0x5208103b,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xf8000000, 0x94000000, 0x76000000, 0xc1000000,
+    0x65800000, 0x6f400000, 0x54e00000, 0xa1900000, 0x3d380000, 0x18640000, 0x87520000, 0x849b0000,
+    0xca108000, 0x477dc000, 0x3f03a000, 0x18853000, 0x70c2c800, 0x88a30c00, 0xdff70a00, 0xc3680f00,
+    0x0d799080, 0xc4029bc0, 0xae004820, 0xa505cc10, 0x7b842a18, 0xc240ff24, 0xd360f8be, 0x79d0a7e5,
+    0xcd5f8a3d, 0xb331cf20, 0x81c83081, 0x698cabff, 0xd1ea000b, 0xe6bf001d, 0x6a22800a, 0x5c36c012,
+    0x014b2006, 0x1d4cf01f, 0xd74b683a, 0x6c493c09, 0xbacf4219, 0xff09c31b, 0xf42fbaa8, 0x7d9964eb,
+    0x1b9030aa, 0xae38abf7, 0xc2e00015, 0xb0900022,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x58000000, 0xc4000000, 0x5e000000, 0x5b000000,
+    0x2c800000, 0xdec00000, 0x09200000, 0x97b00000, 0xe7280000, 0xb6a40000, 0xe9f20000, 0xa2c90000,
+    0xc1378000, 0x2ce8c000, 0xc0032000, 0x60037000, 0x50011800, 0x2804c400, 0x9c06c600, 0x9a04b100,
+    0x05072980, 0x77831740, 0xf2449820, 0xd7e50410, 0x9e926608, 0x709f011c, 0x518d1196, 0x5f50a371,
+    0x4b394637, 0x63f87106, 0xedde0983, 0xeced676b, 0xa0000034, 0x30000004, 0x78000026, 0xb4000027,
+    0x06000027, 0x9f00002e, 0x72800025, 0x85c00038, 0x25a00002, 0x49700002, 0xee08002c, 0x21140007,
+    0x0eda002e, 0x146d0000, 0x28c5800b, 0x8e21c02d,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x78000000, 0x34000000, 0x8a000000, 0xa9000000,
+    0x81800000, 0x96c00000, 0x3be00000, 0x45700000, 0x6f180000, 0x37640000, 0x30360000, 0x773b0000,
+    0x3e758000, 0xe39dc000, 0x8226a000, 0x4813d000, 0x4e2d8800, 0x95d82c00, 0x22812600, 0x7e403700,
+    0x4ca0f780, 0xd850c6c0, 0x698e0820, 0x290eec30, 0xe24a0618, 0xd96a270c, 0xf8fddf9e, 0x3c103acd,
+    0x642f2602, 0x6cdf371a, 0xeb0377b8, 0xa48606e9, 0xc9452830, 0xbe24fc2c, 0x2e172e21, 0x092adb2b,
+    0x7f5cf1b2, 0xd0c1e1f5, 0xece65783, 0x87f316f3, 0xe15b8023, 0x63c2c006, 0xec652017, 0xecb51028,
+    0x8a7ea820, 0x0a563c0d, 0x648a0e3d, 0x0a8bcb36,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0xd8000000, 0xc4000000, 0x02000000, 0xa3000000,
+    0x84800000, 0xf8400000, 0x21600000, 0xc9b00000, 0x52880000, 0x0ae40000, 0x9b760000, 0x56ab0000,
+    0xf3b38000, 0x0588c000, 0x34612000, 0xf4319000, 0x2948d800, 0xa5418400, 0xf0e71200, 0xac713300,
+    0x982df980, 0x44f28e40, 0xbe6d5820, 0x60924430, 0xa25db218, 0x35dc633c, 0x311a01b6, 0xbc3d9a71,
+    0x7eef1220, 0x5ad53318, 0x403bf9b9, 0x88e98e42, 0x43d6d81e, 0x53be8433, 0x2e2a920c, 0xfdf6f316,
+    0x3de959b1, 0xee50de5a, 0x007f2014, 0x9d8e902b, 0x50655820, 0x6636443d, 0xa24bb212, 0x3dc7633f,
+    0xcea18194, 0x2c115a40, 0x7618320c, 0x6abfa337,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xa8000000, 0xa4000000, 0x86000000, 0xf3000000,
+    0x52800000, 0x2a400000, 0x66200000, 0x0ef00000, 0x8df80000, 0xa1a40000, 0x55360000, 0x5b1b0000,
+    0x57348000, 0x9e1b4000, 0x2eb22000, 0xfadab000, 0xe8d5d800, 0x43093400, 0x4c5f2200, 0xe4930900,
+    0xa62e9380, 0xa82805c0, 0x4f2f5820, 0x43ad7430, 0x86ef8218, 0x3849f904, 0xcdfdebaa, 0x01a0c1e9,
+    0x25378201, 0xe31df90c, 0x5b33eb8c, 0xbc1fc1ce, 0x5bb50213, 0x5b5db91a, 0x90154b9a, 0x0f6e31d0,
+    0x248afa0b, 0x679a3d05, 0x8a71b1b5, 0x5cbb0cdc, 0x4101cbb6, 0x4f8571c5, 0x4fc0da2f, 0x88e48d0d,
+    0x19926983, 0x13a938d1, 0x8eea6985, 0xec4d38e4,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x08000000, 0x2c000000, 0xc6000000, 0x9d000000,
+    0x58800000, 0x41400000, 0x3de00000, 0x68d00000, 0xcf580000, 0x2e640000, 0x74120000, 0x1f7d0000,
+    0xa8178000, 0x917a4000, 0xb9106000, 0x7fffb000, 0x6d55a800, 0x369a2400, 0x82c57a00, 0x5925e700,
+    0x9cf2f280, 0xa32a78c0, 0x90082820, 0x17f96410, 0xd1509a28, 0x289d171c, 0x4bc0ba82, 0xe3a5accb,
+    0xaab09a11, 0x9d4d1737, 0x7c18babe, 0xfc81accc, 0xeb429a2d, 0xc6e01701, 0xdd573aaa, 0xde9fecf0,
+    0x7ec0fa29, 0xe722a70c, 0x25f512bc, 0x11af88c5, 0x8a4de029, 0xef9cf01f, 0xa0404810, 0x9b62d42b,
+    0x3097322d, 0x303a3322, 0x54f240aa, 0x6f2a0bcd,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x68000000, 0x84000000, 0xc2000000, 0x2d000000,
+    0xd1800000, 0x57400000, 0x45a00000, 0xf1300000, 0x45d80000, 0xb3240000, 0x6ef20000, 0xdcbb0000,
+    0x01f08000, 0x60394000, 0xe7332000, 0xe2d89000, 0x3ba52800, 0xd2342c00, 0x0f5cea00, 0x22e7e500,
+    0x6c965580, 0xa8eedcc0, 0x48ffa820, 0xded26c10, 0x08cd4a18, 0xb48d3524, 0x8fa8dd9a, 0xb55f20e1,
+    0xa3e74a10, 0x9b12351b, 0x80aa5dac, 0x99dd60f1, 0x2d24ea2b, 0x9df3e50d, 0x9e3c55b9, 0x8431dcc3,
+    0xc85d2815, 0x3a602c13, 0xb856ea39, 0xff08e50b, 0xa9ecd586, 0xff789ce0, 0x96960814, 0x49ecbc24,
+    0x2f79c23e, 0x9e93c90d, 0x5deabfb2, 0x857939f8,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x08000000, 0x5c000000, 0xa6000000, 0xcf000000,
+    0x1b800000, 0x4b400000, 0x86600000, 0xd5700000, 0x94380000, 0x98e40000, 0x04b20000, 0x819d0000,
+    0xc5338000, 0x13da4000, 0x3fd3e000, 0x3a68b000, 0xc8cf3800, 0x629bbc00, 0x30b42a00, 0x6b982b00,
+    0xf4358780, 0x635fa3c0, 0x9d16b820, 0xc248fc10, 0xe95e4a28, 0xbc13db3c, 0xeac8df82, 0xef9befd7,
+    0xb635ca09, 0xfe5d9b23, 0x43913fae, 0xf38a5fee, 0xacfb7203, 0x19c16732, 0xa9a575a4, 0xabd084e9,
+    0x206a2da5, 0xf1c9c8f5, 0x4e1adfb1, 0x3476efee, 0x5cbe4a26, 0xce23db01, 0xf690df91, 0xc10fefd5,
+    0x933fca15, 0x3f649b1e, 0xb9f0bf95, 0x30fd1fde,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0xd8000000, 0x8c000000, 0x36000000, 0xf1000000,
+    0x3a800000, 0x50c00000, 0x07a00000, 0x12300000, 0x7b580000, 0x17640000, 0x75960000, 0xb96d0000,
+    0xc43f8000, 0x36f4c000, 0x76ff6000, 0xba533000, 0x394cc800, 0x2a4a7400, 0x9bced600, 0x968fc500,
+    0x7a6e2d80, 0xddbb78c0, 0x6fb3a820, 0x20194430, 0xda821e28, 0x40c5b11c, 0x0fa0fbb6, 0x3634bde3,
+    0x195d85ad, 0x5c623ccc, 0x8811b606, 0x222cf538, 0xa9dae59f, 0x73a50cfb, 0x18337e05, 0xc45f812a,
+    0x20e5b3b6, 0xf1d709da, 0x930bb3a8, 0xdd2e09ce, 0xfe5a3387, 0x27e3c9ff, 0x9a54d3bd, 0x894d39fa,
+    0x524efbb8, 0x67cdbdf1, 0x788c0591, 0x076ffcf6,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xe8000000, 0x04000000, 0x72000000, 0xeb000000,
+    0x10800000, 0x5b400000, 0xe6200000, 0xa5f00000, 0xd6780000, 0x5d640000, 0x53d20000, 0xfb8b0000,
+    0xdf1e8000, 0x94b14000, 0x47592000, 0x46161000, 0xfbe89800, 0x2548e400, 0xfffdea00, 0x8da1b900,
+    0x26315380, 0x5f19bdc0, 0xd4b1b820, 0x275ef410, 0x16157218, 0x13e95d14, 0x214cb9ba, 0x8df804c1,
+    0x66a0ebbc, 0x36b749ea, 0x045cca3c, 0x3293a912, 0x82abcb9b, 0xc06a59fc, 0x4e8ad233, 0x729a0d3c,
+    0x76770194, 0xb9b9f0c6, 0xa2011990, 0x430054cb, 0x7485d3b9, 0x7947fdd1, 0xe5241819, 0xb172a433,
+    0xff3a4a1b, 0x5046e93e, 0xe6a0ebb8, 0x76b749d9,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x78000000, 0x34000000, 0xf6000000, 0xeb000000,
+    0x4d800000, 0xe3400000, 0x11200000, 0x2d500000, 0x28680000, 0x0a640000, 0x48720000, 0x133f0000,
+    0x190a8000, 0x7795c000, 0x8c0ca000, 0xed123000, 0xa8c81800, 0x9e730c00, 0x883d8a00, 0x1c88a100,
+    0x08d57e80, 0x272d4540, 0xe944b820, 0xd0213c10, 0xd1d59238, 0x7fabad34, 0xd080f49e, 0x05c1e44d,
+    0x5fe3c69d, 0x0f33796a, 0x6c1baa0b, 0x3b5f511c, 0x9c79c6a2, 0xba287972, 0x0fc32a09, 0x9ee59125,
+    0xf3b7e684, 0x3bdb8972, 0xe1bd922f, 0xd1cfad33, 0xf6f2f4a8, 0x19fee47c, 0x8569469f, 0x44e6b944,
+    0x4ab70a17, 0xf35d610b, 0x4079de98, 0xe02f756c,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x68000000, 0xdc000000, 0xfa000000, 0x03000000,
+    0x19800000, 0x90400000, 0xa2a00000, 0x0d900000, 0xc9e80000, 0x3ee40000, 0x4d320000, 0x137b0000,
+    0xf88d8000, 0x72954000, 0x3a6fe000, 0x95a15000, 0x7210d800, 0xc0ae6c00, 0xb6c03200, 0xa1639d00,
+    0x34755880, 0x935c5140, 0xf8df3820, 0xc79f3c10, 0x8eb8ea18, 0xfc69f10c, 0x1ca76a9a, 0x6094cc77,
+    0xa56fe09e, 0x16222d50, 0xd152323e, 0x13889d38, 0xf710d8aa, 0x302d1178, 0x0402d836, 0x0e056c28,
+    0x4505b233, 0xd082dd2c, 0xe2c0b88a, 0xf762014c, 0xc570600a, 0x65df1039, 0xe31ab823, 0x2a7e7c13,
+    0xc20d0a32, 0xab57a104, 0x508832b1, 0x8e94e052,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xd8000000, 0xac000000, 0xae000000, 0x3d000000,
+    0x60800000, 0x0dc00000, 0xa6600000, 0x67500000, 0x2f780000, 0x2fa40000, 0x6b320000, 0x9f2b0000,
+    0xd75e8000, 0xced0c000, 0x003c6000, 0x7700f000, 0xa787a800, 0x6243a400, 0xd022fe00, 0xe5776b00,
+    0x810cb680, 0x5f2b61c0, 0xf75bc820, 0xfed35410, 0x883d5618, 0x0300cf14, 0xa58448b6, 0xf1430aeb,
+    0x8da3fe8b, 0x8837f5df, 0x2aae7e20, 0x9e1cab07, 0xbf765687, 0xfe0f51e6, 0xccaa0036, 0xf71f003f,
+    0xedf48031, 0xe8cfc03f, 0x4348e032, 0xff0f3002, 0xfa2f4809, 0x5bdc9400, 0x0d15b620, 0xcb5fff37,
+    0xa8d300b2, 0x293b9ef4, 0x458448be, 0xe1430ae2,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xb8000000, 0x6c000000, 0x3a000000, 0x4d000000,
+    0x09800000, 0xb9c00000, 0xe7e00000, 0x73b00000, 0x00a80000, 0x94240000, 0x41520000, 0x469b0000,
+    0x9f4d8000, 0xab93c000, 0x2bfa6000, 0x17bf5000, 0x03982800, 0x02cbec00, 0x2453fe00, 0x8b19c700,
+    0x98899980, 0xdf757740, 0xf2ca4820, 0xbc50bc10, 0xb719d618, 0x2a892b0c, 0x4677e7ae, 0xad4f705b,
+    0x7291b1ae, 0x147e9b43, 0xe979b63a, 0x18f97b32, 0xad384f8f, 0x40d85c4b, 0xc66faf80, 0xa344cc7d,
+    0xb4a5e7a4, 0x5f147070, 0x63bc318a, 0x459d5b5f, 0x9dcbd62b, 0x84d22b28, 0xd55a67aa, 0xddacb05e,
+    0x35a3d185, 0x7c95cb73, 0x7f7b9e06, 0x4ffd9700,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x98000000, 0xa4000000, 0xca000000, 0x93000000,
+    0x02800000, 0x25400000, 0x81e00000, 0xc2500000, 0x08080000, 0x36a40000, 0x14b20000, 0x12d90000,
+    0x20ed8000, 0x9d72c000, 0x8f7e2000, 0xe2dad000, 0xd8efd800, 0x2976f400, 0xed78da00, 0x4dde8700,
+    0xb46cfe80, 0x5531ce40, 0xfd19f820, 0xa8482410, 0x18c50208, 0x2021730c, 0x23f1a4a6, 0x84398969,
+    0x1a392692, 0xbb3a3a74, 0x9ebea228, 0xdb7d6315, 0xd0da5c8e, 0xffe8ad55, 0x49f1a4b6, 0x67398950,
+    0xa0b9269f, 0x0a7a3a75, 0x4d5ea226, 0x2e2d632b, 0x10525c9d, 0x7f0cad44, 0xde23a485, 0x92f08957,
+    0x09bca68c, 0x63fcfa71, 0xde9a822a, 0xe88ab30b,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xd8000000, 0xac000000, 0x86000000, 0xe5000000,
+    0x84800000, 0x70400000, 0x4ce00000, 0xfa900000, 0x64b80000, 0x72a40000, 0x67720000, 0x50ab0000,
+    0x95588000, 0xe0b0c000, 0x958c6000, 0x1befb000, 0x543b6800, 0x08e28400, 0xb8975200, 0x83b83f00,
+    0x7127f880, 0x84b113c0, 0x278f0820, 0xb4e93410, 0x23be3a18, 0xc121bb24, 0xacb02ab6, 0xc38deceb,
+    0x46ee9081, 0xecb897e9, 0x26a0da19, 0xbd71cb28, 0x7bada2bd, 0x80db18f1, 0x3e764aae, 0xca2d5cde,
+    0xcc1f78a1, 0x3ed1d3c5, 0x225b6819, 0x1b328405, 0x2a4f5220, 0xa2cc3f12, 0xe48df8a3, 0x656e13c4,
+    0xff7d8821, 0x1986f407, 0xe8c0da2f, 0x92a1cb05,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0x14000000, 0x6e000000, 0xcd000000,
+    0x9a800000, 0x8e400000, 0x50600000, 0xe3f00000, 0xc1c80000, 0xd4240000, 0xe8960000, 0x81bf0000,
+    0x9f2b8000, 0x1793c000, 0x5439a000, 0xdceff000, 0x00322800, 0x066cb400, 0xae732200, 0x460fdb00,
+    0x8587c380, 0xabc4f840, 0x2ba38820, 0xe0574430, 0xfd9f0a38, 0x6bb86f3c, 0x3429618e, 0x8c17e345,
+    0xcafe6bbb, 0x99cb8c43, 0xf0210a3e, 0x5e936f2f, 0xa8bce1a2, 0x53af2341, 0x40d24b85, 0xf0dcbc73,
+    0x7c5f021f, 0x0b18eb3c, 0xbff9cba3, 0xd74f7c67, 0xf066a204, 0x33f71b3c, 0xe9cbe38f, 0x0823c878,
+    0xaa95802a, 0x36b8c01a, 0xa6ac2014, 0xce57301a,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x78000000, 0x14000000, 0x26000000, 0xbb000000,
+    0x2f800000, 0x80c00000, 0xf0a00000, 0xfcb00000, 0x7c580000, 0x5a640000, 0x55160000, 0x5a690000,
+    0x6e7d8000, 0x1a91c000, 0x0aafa000, 0xf6ddb000, 0xba259800, 0x3cf51c00, 0x25bd1600, 0x5db52d00,
+    0x72de2180, 0x142208c0, 0xcbf23820, 0x083cac30, 0x38768e08, 0x02fd310c, 0x61d0b79e, 0x7ccbe5c5,
+    0x71c839a9, 0x6d4bd4de, 0x8e0b0e23, 0xfc6cf11c, 0x157f17aa, 0x151655f6, 0xba6da1a8, 0x7e7ec8cd,
+    0x5296183f, 0x66a9dc24, 0xc4d9363a, 0x27205d10, 0xa87199b6, 0x8aff64ff, 0x2dd31606, 0x7ec82d31,
+    0x94cda1b8, 0xedcec8d4, 0x074e1818, 0x370ddc22,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x88000000, 0x7c000000, 0x42000000, 0x37000000,
+    0x0c800000, 0xa6400000, 0x26600000, 0xe4300000, 0x36d80000, 0x66240000, 0xa3520000, 0xe76d0000,
+    0x97388000, 0x8ad24000, 0x12ace000, 0x579f9000, 0xb4c77800, 0x2520c400, 0xd1d54200, 0x942a5b00,
+    0x1add5d80, 0x4c2310c0, 0xd8539820, 0xb1eb5410, 0xf2783a28, 0x9e339f24, 0x25da9fa2, 0x7ca20bdf,
+    0x9c10a5b0, 0x308c94dd, 0x92caba0b, 0x72a8df1d, 0x279cff83, 0x0cc2dbc2, 0x4123bd9f, 0x67d180db,
+    0x9d2c6033, 0x6359d029, 0xd1e19825, 0x54f65409, 0xd5f8ba32, 0x06f5df27, 0x2afc7f9b, 0xd6749bcd,
+    0x5ebd5d93, 0x4f1310fe, 0xca0b982b, 0x9d8f5430,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0xb8000000, 0x4c000000, 0x62000000, 0x8b000000,
+    0x85800000, 0x4ac00000, 0x79a00000, 0x45f00000, 0xe6680000, 0x18640000, 0x89d60000, 0xa15f0000,
+    0x63ac8000, 0x4247c000, 0x6be36000, 0xb6149000, 0xec791800, 0x3e1f8c00, 0xc8c92600, 0xc2118500,
+    0x027bdb80, 0x371f0940, 0xd64c7820, 0xb5541c30, 0x7d1cbe38, 0x6949c904, 0xfed19dae, 0xceda1c53,
+    0xa66ebbb8, 0x38649952, 0x79d1e039, 0x09585026, 0x97a8f808, 0x6c47dc06, 0x82e1de0f, 0xb8965937,
+    0x233a059b, 0x0d795058, 0xf49e7d87, 0x61894c63, 0xfc74c3a5, 0xa6af854e, 0xfec1de16, 0x77a65906,
+    0x5cf20586, 0x40ed507d, 0x23207db5, 0x94b24c63,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0xf8000000, 0xd4000000, 0xe6000000, 0x97000000,
+    0x04800000, 0x12c00000, 0x2d200000, 0x5f500000, 0x5ce80000, 0x90e40000, 0x02f20000, 0xe77f0000,
+    0x7e2b8000, 0xe5c1c000, 0x99a3a000, 0x5597b000, 0x55ccf800, 0xd1b54400, 0x1d1b0600, 0x9519e100,
+    0xf91fe680, 0x2b1c9540, 0xaa1d5820, 0xc19df410, 0x03dc7e38, 0xda3d653c, 0x3f4f40be, 0x3876c475,
+    0xe6bc4699, 0x598b2575, 0xe351a019, 0x26e8b028, 0x2de7780d, 0x5374841e, 0x7838a610, 0x064e5134,
+    0x67f31eb8, 0x32f9d158, 0xef6e5e35, 0xd6a0153a, 0xd5119882, 0x490ef05a, 0xb791989c, 0x8ccef04c,
+    0x7e3198b9, 0x315ef06c, 0xf7f998a0, 0x2aeaf07b,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x68000000, 0x14000000, 0x86000000, 0x81000000,
+    0x3e800000, 0x4f400000, 0xf1600000, 0x17700000, 0xf5080000, 0xc7240000, 0x64960000, 0x5f3b0000,
+    0xd44c8000, 0x63414000, 0xab65a000, 0x7477b000, 0xb48fc800, 0x4be61400, 0x7630ea00, 0xf16cb300,
+    0x78d1c780, 0x37d96640, 0x43fc6820, 0x6eeaa430, 0xb913a218, 0x91fbe704, 0xc9ec8d9a, 0x76966545,
+    0x983c6781, 0x0bcad650, 0x0285a037, 0x7d47b027, 0x8667c83e, 0xd0f21404, 0xf8ceea06, 0xeb03b314,
+    0x858347b7, 0x02c72664, 0x97a34826, 0x7ed7543e, 0x76dd4a33, 0x1d7f032d, 0x31a80f93, 0x20747271,
+    0x92880230, 0xbae35723, 0x30b1c596, 0xc22e315f,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x48000000, 0xd4000000, 0x2a000000, 0xa3000000,
+    0x3b800000, 0x27c00000, 0xf3200000, 0x09300000, 0x82680000, 0x6be40000, 0x7f920000, 0x859d0000,
+    0x922c8000, 0x49044000, 0x7881a000, 0xac44d000, 0x8ce7d800, 0x66153c00, 0x755ece00, 0x608fd100,
+    0x8cf28d80, 0xe64bf140, 0xc3547820, 0x213cec10, 0xbadd9628, 0x3d4aad04, 0x34d7e392, 0x10f9f075,
+    0x04ffadaa, 0xcefb6178, 0xddfa0006, 0xbe79001d, 0x05be8006, 0x08994033, 0x88ad2038, 0x92409007,
+    0xe5e6781b, 0x4e91ec00, 0x31191616, 0x286aed19, 0x88e44382, 0xe4102073, 0xb25cf5bc, 0x290e1d72,
+    0x94376e2e, 0xd0ef013f, 0x64a755a3, 0xc8f3cd71,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x68000000, 0x7c000000, 0xc6000000, 0x09000000,
+    0xed800000, 0xb4400000, 0x10a00000, 0xc5300000, 0x07180000, 0x99e40000, 0x32160000, 0xa16b0000,
+    0x6edc8000, 0xe5014000, 0x8386e000, 0xa145b000, 0x2b238800, 0x1077cc00, 0x86387200, 0x2e92fd00,
+    0x2caf0d80, 0x103df6c0, 0xef936820, 0xcd297c30, 0x1a7f7a18, 0xe2307104, 0x5f9f9f9a, 0x6625bbdf,
+    0x8ff56d91, 0xf17906f2, 0x64b60003, 0xad5b0019, 0xe4448006, 0xd8a5402a, 0xc930e02a, 0xb91eb03b,
+    0x84e70806, 0x65928c07, 0xda289224, 0x9afc4d25, 0x79f005a5, 0x207b7aff, 0xed35fa27, 0xdb1a313a,
+    0x2fe5ffae, 0x43114bde, 0x58e8058a, 0x609f7afa,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x68000000, 0x3c000000, 0x0e000000, 0x8d000000,
+    0x88800000, 0xc1400000, 0xed600000, 0x84700000, 0xdbb80000, 0x45240000, 0x2b960000, 0x298b0000,
+    0x147e8000, 0x48014000, 0xcc04e000, 0x5601f000, 0x8905a800, 0xd286e400, 0x7e479a00, 0xe6e37300,
+    0x40b40f80, 0x7f1c93c0, 0xed774820, 0x993c1430, 0x3364b218, 0xa170d714, 0x0f39759a, 0xda6110cf,
+    0x23f66fa3, 0x197823d3, 0x0080001a, 0x6d400014, 0xeb600039, 0x6570003a, 0x3538002f, 0x3564003d,
+    0x4076002b, 0xe1bb0019, 0xaa26801c, 0x48154003, 0xd14ae03e, 0xbedef025, 0x6d55281e, 0xf628a42f,
+    0x8dabfa10, 0xd168c321, 0x11cbc7a6, 0xb39ac7d8,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xe8000000, 0xec000000, 0x7e000000, 0xc1000000,
+    0x03800000, 0x0ac00000, 0x46600000, 0x02500000, 0xba680000, 0xb3a40000, 0x66b60000, 0x21790000,
+    0x1d698000, 0x95254000, 0x6df06000, 0x8adf7000, 0x9fd8c800, 0xb65a3c00, 0x979fc600, 0xd9794b00,
+    0x696fc680, 0xcf233940, 0x8ef6a820, 0xde584c30, 0xbb988e08, 0x877f372c, 0x1869e0ba, 0x24a0427b,
+    0x6831c6bf, 0xe63e3940, 0x78c92828, 0x3e940c1e, 0xa1096e23, 0x04710717, 0x4b1f488b, 0x41380e67,
+    0x5e4948a6, 0x35d10e4a, 0x0aa8c8be, 0x86c04e7b, 0x6866a8b6, 0xbb523e69, 0x0de9e09d, 0xc360425e,
+    0x73d1c6b6, 0x9fae394e, 0x6f41281a, 0x69a00c21,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x28000000, 0x9c000000, 0x5a000000, 0xbb000000,
+    0x56800000, 0x5dc00000, 0xe0200000, 0x43900000, 0x20c80000, 0x70e40000, 0x1c360000, 0x5f1f0000,
+    0x394b8000, 0x9da34000, 0x50d5e000, 0xc92a5000, 0xe5571800, 0xb6691400, 0x7eb7de00, 0x04d98900,
+    0xd86f3080, 0xc3b03c40, 0x535cf820, 0x98284430, 0x00d54638, 0x3128dd3c, 0x41508e8a, 0xa86fa567,
+    0x2bb2b0b6, 0x2f5c7c5e, 0x322a980d, 0x93d5541b, 0xfba9be0a, 0x4690990b, 0xf34dc894, 0x3ea37855,
+    0x52543e24, 0xa2ecd91e, 0xcc73a89b, 0x847a685c, 0x0f3ec60e, 0x72db9d29, 0x316d6e98, 0xa231f574,
+    0xda1ba8a9, 0x2ace6871, 0x33e0c61a, 0xeeb09d17,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x88000000, 0x04000000, 0xf6000000, 0xb3000000,
+    0x14800000, 0x49400000, 0x60e00000, 0xb3b00000, 0x4be80000, 0x00a40000, 0x56d60000, 0x089b0000,
+    0xd16c8000, 0xa6e64000, 0x38b52000, 0xa36c3000, 0x23e55800, 0xff32c400, 0xeeaeda00, 0x8245af00,
+    0x2862e180, 0xe0f39140, 0x7a0e7820, 0x6a91f430, 0xf7f90218, 0xc31e2b04, 0xaeab9ba2, 0x22434e41,
+    0x5866619d, 0x78f1d15c, 0xf60d583d, 0x9896c426, 0xb2f8da22, 0x649eaf29, 0xf36e618d, 0x0be5d16d,
+    0x8b33580d, 0x80a9c40c, 0xbd425a31, 0xcee3ef13, 0xecb7c1a6, 0xbd6fa145, 0x84e32012, 0x95b73021,
+    0x10e9d807, 0x0024841d, 0x6193fa0a, 0xdf7d9f14,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x28000000, 0x5c000000, 0xf2000000, 0xf9000000,
+    0xa6800000, 0xf7c00000, 0x12200000, 0xbeb00000, 0x14580000, 0x2b640000, 0x67560000, 0x5acd0000,
+    0xe0898000, 0x8aeb4000, 0x4f3de000, 0xc4351000, 0xd1980800, 0x60421400, 0xcf600e00, 0x91566900,
+    0x85cff980, 0xa10f30c0, 0xf8ac6820, 0xa95c4430, 0xcbe5e628, 0x67916d2c, 0x556fff8a, 0xde7f4dd7,
+    0x81d59f9c, 0xcd081dce, 0xe2aff7a1, 0xac5959e1, 0x2f639186, 0x415374e4, 0xbdc98e3b, 0x650d293f,
+    0x7eaa19ae, 0xfe5e20ee, 0x66626003, 0xcfd35029, 0x160c682f, 0x852c443b, 0x391de60f, 0x4c856d01,
+    0xbac1ffaf, 0xbaa64dcb, 0xf2721f9f, 0x71fa5dea,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x58000000, 0x74000000, 0xda000000, 0x2d000000,
+    0xc9800000, 0x63c00000, 0x6ea00000, 0x66700000, 0x73c80000, 0xa0e40000, 0xa9120000, 0x421b0000,
+    0x36d88000, 0x6cfcc000, 0x98cea000, 0x02675000, 0x28562800, 0xcdf8ac00, 0x4f4c2e00, 0x3ea3ad00,
+    0x1e706780, 0xf7cef440, 0xb2e00820, 0xa8133c10, 0x259ca618, 0xa2185124, 0xe6d86196, 0x54fef55d,
+    0x7cccc196, 0x8066a55b, 0x7150698a, 0xde79c96c, 0x018a67b5, 0x9981f470, 0x1bc28804, 0xeaa0fc0a,
+    0x74708620, 0x72ccc13a, 0xc7626984, 0x3dd2c94c, 0xc83ae785, 0xfae93472, 0x1256282d, 0x30f8ac3e,
+    0xbecc2e3d, 0xb963ad0c, 0xf2d067ba, 0xc8bef448,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x48000000, 0x24000000, 0xe2000000, 0x97000000,
+    0x53800000, 0x8ac00000, 0x4f200000, 0x4cb00000, 0xa7e80000, 0x2a640000, 0xa7520000, 0x057f0000,
+    0xbfbc8000, 0x089c4000, 0xd8282000, 0xe1c69000, 0xd2a37800, 0x77767c00, 0xde48a600, 0x6096b100,
+    0xb2d87180, 0x444b5d40, 0x2b97d820, 0x9f5cac10, 0xc78bfe38, 0x2e325d04, 0x5ba9af92, 0x83009049,
+    0x49818f98, 0x81c60075, 0x82a2f78c, 0xdf707c76, 0xea4a5199, 0xca96cd4e, 0x01da201b, 0xf5c99032,
+    0x3657f82f, 0x83fe3c0b, 0x01fa8602, 0xc6fb211f, 0x3d7d89a0, 0x83ba6166, 0x6699de13, 0x992fcd37,
+    0x8f4457b3, 0xb8e5ac46, 0x03158987, 0xc01e6179,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x28000000, 0x04000000, 0xa2000000, 0xfb000000,
+    0xad800000, 0x8f400000, 0x12e00000, 0x49b00000, 0xc5c80000, 0xc2240000, 0xd7120000, 0x8f1b0000,
+    0xbf1b8000, 0xb71f4000, 0x231d2000, 0x591f9000, 0x8e1c1800, 0x859e8c00, 0x53da8a00, 0x17b9bf00,
+    0x7cce0980, 0x24a61040, 0xbdd2b820, 0xe6ba5c10, 0x6e49b218, 0xc463a33c, 0x2b731b8a, 0x386e6341,
+    0x69731b88, 0x736e636e, 0x8cf31b93, 0x082e634f, 0x14131bb6, 0xbe9e637f, 0xde5b1bab, 0x08fa6363,
+    0xb6291b84, 0x41116353, 0xde1a9b9a, 0x7d9a234f, 0xefddbb8c, 0x69bab344, 0x09c82392, 0xd4207f50,
+    0xc614098a, 0x2d991059, 0x17db3809, 0xd5be1c03,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x88000000, 0x5c000000, 0xfa000000, 0x43000000,
+    0x12800000, 0xe1400000, 0xea200000, 0x70700000, 0x0a480000, 0x0be40000, 0x54160000, 0x00990000,
+    0xd79a8000, 0x831e4000, 0x2b59e000, 0xc8fa5000, 0x9f4bd800, 0x3863b400, 0x10d7fa00, 0x6f7bb900,
+    0x9d8aff80, 0x1e86a440, 0x5340b820, 0xd523a430, 0x48f3c208, 0x200b5d34, 0xaf445da2, 0x3f24e957,
+    0xa3f25d9e, 0xbe8de960, 0x3c00ddac, 0xca07a97c, 0x3b073db0, 0x1680f94f, 0xcf4065b6, 0x0f240d41,
+    0xdbf4ffa5, 0xba8ba47b, 0x1204383f, 0x2f00e407, 0x9086a222, 0xa6464d27, 0xd6a4e592, 0x74374d6f,
+    0x4be99f86, 0xcb52b47d, 0x47ba8019, 0x706e4037,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x68000000, 0x24000000, 0x56000000, 0xcd000000,
+    0x6e800000, 0xc5400000, 0x31200000, 0xd0700000, 0x18a80000, 0x34e40000, 0x95120000, 0xed7b0000,
+    0xf67f8000, 0x15fd4000, 0xde3c2000, 0x5a5c3000, 0xd30ba800, 0x3fd79400, 0x459c7a00, 0x296bb100,
+    0x3f86cf80, 0xe1c22340, 0x33600820, 0x28d2e410, 0xb419f218, 0x09ab151c, 0x70669d9a, 0xe7574649,
+    0x7ddc9db5, 0x7ec84663, 0x7eb11da3, 0xf64e067d, 0x3ef2bdae, 0x9def7671, 0x65c535b1, 0x3564d27b,
+    0x9dd2e789, 0xe698f77c, 0xd6e8521d, 0xfe416536, 0xc2a695b1, 0x03b5a278, 0xd0cd6faf, 0xcfb75356,
+    0xe2cd8035, 0x34b64003, 0x314ba033, 0xf775700d,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0x48000000, 0x6c000000, 0x22000000, 0xa5000000,
+    0x2a800000, 0xbbc00000, 0x18a00000, 0x73300000, 0x48080000, 0x97e40000, 0xff560000, 0xf0190000,
+    0x479c8000, 0x02d84000, 0x97b86000, 0x4f2a9000, 0xfd94e800, 0x583ff400, 0x3e686e00, 0x6ef75d00,
+    0x6ba8d580, 0x21541240, 0xff180820, 0x84192430, 0xa19ae608, 0xd9df3904, 0x623ed392, 0x5f6dfb5b,
+    0x3a7453a8, 0x0f6cbb59, 0x9270b3a2, 0x536e6b5a, 0xe8743bbc, 0xd26f0f73, 0x8cf6bda0, 0xeeaaa663,
+    0x1bd48607, 0x0cdca914, 0xf0bebb8f, 0xf0ae4f44, 0x34d25d81, 0xdf587646, 0x1bf80e3e, 0xd2c9cd20,
+    0x3bc23da5, 0xd8a6e66f, 0x5332e63f, 0x580b391c,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x38000000, 0xbc000000, 0x96000000, 0xf7000000,
+    0x03800000, 0xa6c00000, 0x7a200000, 0xe3100000, 0xb1180000, 0x8d640000, 0xc2f60000, 0xbaaf0000,
+    0xa52c8000, 0xa5ebc000, 0xe0c8e000, 0x94593000, 0x9183f800, 0xbbc5bc00, 0xf8a22600, 0xa1547900,
+    0x56782c80, 0x0e924ac0, 0x7cdf9820, 0x92c34c30, 0x58273e38, 0xf613f504, 0x479b728e, 0xcd234fef,
+    0xc097f285, 0xe7d88fcd, 0xdf471298, 0xa5e5bfdd, 0x21b2ea88, 0x494f03e3, 0x939c4c9f, 0xff20bac5,
+    0xed948003, 0xad5fc021, 0x0906e024, 0x5082302e, 0x7f417821, 0x95e57c05, 0xe9b04603, 0xdd49890f,
+    0x819fb495, 0x222506dd, 0x8f16a626, 0xff1bb90a,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x08000000, 0x0c000000, 0x46000000, 0x0b000000,
+    0x91800000, 0x09c00000, 0x24a00000, 0xa3f00000, 0x3ed80000, 0x0ee40000, 0x07960000, 0x890f0000,
+    0x368f8000, 0x884a4000, 0x936d6000, 0x4e5cb000, 0x8ba69800, 0x28762c00, 0xe21ede00, 0xc4c4ed00,
+    0x7e25db80, 0xdbb03cc0, 0x63bc7820, 0x81b4dc30, 0x96bb2638, 0xff35711c, 0x34fc1d82, 0x9753bdc3,
+    0x252b9db1, 0xd43dfdf2, 0x3370fdbc, 0xcb9e4dee, 0x2901e593, 0x608621c7, 0x3d445b84, 0xcbe17cee,
+    0x5110982e, 0xb7492c0d, 0x60e95e0b, 0x969aad15, 0x0b86bb95, 0x1cc78cfb, 0x2a236030, 0x09b7b033,
+    0x5abf182e, 0x59336c04, 0x4ffc3e33, 0x0ed21d0d,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x78000000, 0x0c000000, 0x6e000000, 0xfd000000,
+    0xa3800000, 0x7bc00000, 0x11600000, 0xcf500000, 0xe5b80000, 0x74240000, 0x66760000, 0x904d0000,
+    0x47af8000, 0x4cb9c000, 0x05a76000, 0x56b3f000, 0xa3aaa800, 0x46b93c00, 0x32a10e00, 0x62377300,
+    0x9ce97e80, 0x109a47c0, 0xa7d44820, 0x7cfe0c30, 0x0b034628, 0x42847f1c, 0x1e45b89e, 0x93a7f8c3,
+    0x67b690bb, 0xce2a04cf, 0xb07f7ea0, 0xf84747f2, 0xdaa3c812, 0x0633cc1c, 0x56ea2614, 0x879e8f3d,
+    0xe35690b4, 0x3bba04c6, 0x51277e86, 0xb9f347f1, 0xfd8dc81c, 0xa7cacc09, 0x4e6ba63a, 0x66de4f04,
+    0x907070b8, 0x714934ce, 0x222b36bd, 0xce794bf7,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0xb8000000, 0xcc000000, 0xd2000000, 0x95000000,
+    0xad800000, 0x66c00000, 0x1de00000, 0x9b500000, 0x80680000, 0x7ba40000, 0x41f60000, 0x92190000,
+    0x78788000, 0x34efc000, 0x82e56000, 0x1fd51000, 0x912ce800, 0x71864c00, 0xccc00600, 0x64e5b700,
+    0x74d70e80, 0xcbab3140, 0x07c70820, 0x6e659c30, 0x9a910e08, 0x35092b24, 0x93b680ae, 0x76b9da73,
+    0xbd0ae894, 0xb7b45655, 0xf0b98e83, 0xa60df14d, 0xe532e801, 0x62fb4c01, 0xa2ae8632, 0x9043772c,
+    0xc9a2eea1, 0xa4f5e16b, 0x37988026, 0xfabfc027, 0x8f0d6024, 0x92b11002, 0x753ae83d, 0xb4cf4c33,
+    0xe6d08629, 0xbeae770b, 0x1a446e98, 0x20a72145,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x58000000, 0x4c000000, 0xfe000000, 0x81000000,
+    0x1c800000, 0x8fc00000, 0x23200000, 0xe2d00000, 0xae280000, 0xfd640000, 0x6cb60000, 0x41190000,
+    0x5e3a8000, 0x0ae8c000, 0x11c16000, 0x92267000, 0x56514800, 0xe5eec400, 0x34472e00, 0xbd668100,
+    0x8cb50a80, 0xb11b6340, 0xd63ca820, 0x1ee97430, 0xa3c58608, 0xed22f534, 0xcbd40c96, 0x76ac5653,
+    0x98a5449f, 0x7c929250, 0xc04a6aaf, 0xe2501367, 0x47e96036, 0x3342702f, 0xbce7480a, 0xb9f7c438,
+    0xd0fdae35, 0xf54e4100, 0x5cd46aa7, 0xcf2d1379, 0x11e5e000, 0x6b73b030, 0x763ca825, 0x0ee9741a,
+    0xdbc5863c, 0x7122f511, 0x6dd40c94, 0xbbac567a,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x88000000, 0x84000000, 0xde000000, 0xbd000000,
+    0xa8800000, 0xb4400000, 0xdb600000, 0x66f00000, 0x58680000, 0xa6a40000, 0x33520000, 0x56bf0000,
+    0xd8de8000, 0x82aa4000, 0xd8852000, 0xcc417000, 0x67642800, 0xe4f7a400, 0x376b3200, 0xe925e300,
+    0x4c976b80, 0x2c18cd40, 0x798d8820, 0xd3139410, 0x9bdcba38, 0x672d7714, 0xfc4751a2, 0xff60fa61,
+    0x08f17997, 0x3d685e7f, 0x0224cbb2, 0xdd17fd69, 0xeede800c, 0xabaa403c, 0xc605203b, 0x11017033,
+    0x42842833, 0x0f47a436, 0xc2e3321d, 0x2031e33b, 0x544d6bbc, 0x0eb3cd6e, 0x11090815, 0xc752d40c,
+    0xf0bd1a19, 0xd9dd4732, 0xa82ad9bd, 0x03c36e6c,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x98000000, 0x34000000, 0x5e000000, 0x83000000,
+    0xd6800000, 0xc5400000, 0xf2200000, 0xe9d00000, 0x06380000, 0x1ce40000, 0x1b360000, 0xc60b0000,
+    0x586d8000, 0xe81ec000, 0xc730a000, 0x940e7000, 0xe16e5800, 0x4b985400, 0xfbf0ba00, 0x1fec5900,
+    0xe2dd5280, 0x03d257c0, 0x1b3d7820, 0x2967e430, 0x38f5c218, 0x696fbd0c, 0x779e90a6, 0x59f6eacd,
+    0x5eee68b7, 0x455fced0, 0xf9130a8d, 0x27da03cd, 0xa255c222, 0x36ffbd0b, 0xad0690a8, 0x6d82eae6,
+    0x77c068a2, 0xc160cedd, 0x34f08ab0, 0x536bc3ed, 0x029ee227, 0xa0740d18, 0x272de8aa, 0xaf3e0eff,
+    0xb7602a8b, 0xdbf5b3e7, 0x8fe8ba3f, 0x2ad85939,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x48000000, 0x94000000, 0xda000000, 0x39000000,
+    0xe0800000, 0x27400000, 0x42600000, 0x80d00000, 0x87180000, 0xd6a40000, 0x94720000, 0xc66d0000,
+    0x564f8000, 0xe27c4000, 0x2df7a000, 0x91aa5000, 0xab690800, 0x8ccc6400, 0x2c3a0a00, 0xb7112b00,
+    0xea3ada80, 0x2017aec0, 0x39bb2820, 0xe6d37410, 0x60192228, 0x4d265f1c, 0xc6b1f892, 0x45ccf1e5,
+    0xc4bd5096, 0xa457c5de, 0x0259d2b0, 0xd1c2cac5, 0xca24a20a, 0xcd371f09, 0x8f09d8ad, 0xeb1ae1c2,
+    0x30a3f899, 0x3371f1c3, 0xfdead083, 0x748f85d3, 0x29dc7298, 0x2b059ad1, 0x0d822a12, 0xbdc73b20,
+    0x2c2472ae, 0x6a319aef, 0xb4882a2d, 0xc9de3b37,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x38000000, 0x34000000, 0xd6000000, 0x0f000000,
+    0x45800000, 0xbf400000, 0x83a00000, 0x85b00000, 0x4a080000, 0x28640000, 0xcfd60000, 0x9cdd0000,
+    0x5e3e8000, 0x3b2bc000, 0xa316a000, 0xcf3f3000, 0xedaa3800, 0x23d2cc00, 0x4ed82a00, 0x4f38f500,
+    0x2da98980, 0x83d29e40, 0x1edc1820, 0x773f3c30, 0x19ac3228, 0x55d3c914, 0x11dbbb8e, 0x32b8574d,
+    0xa6ef2395, 0xd671ab73, 0x946b3199, 0x78b6924b, 0x8e8c1226, 0x19a33908, 0x08b12381, 0x2688ab50,
+    0xb5a3b1b9, 0xbab05276, 0xc78c321b, 0xcb23c927, 0x9673bbad, 0xf46c576e, 0x88b123a1, 0xe688ab7a,
+    0x15a3b189, 0xeab05278, 0xff8c3230, 0xff23c91d,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x28000000, 0xc4000000, 0xce000000, 0x75000000,
+    0x8c800000, 0xb4400000, 0xf4200000, 0xffb00000, 0x58280000, 0xa3e40000, 0x30520000, 0x227f0000,
+    0xda9d8000, 0x234f4000, 0x11f72000, 0x4f0af000, 0xa9d06800, 0x6ab88400, 0x52ffb200, 0xdcdbd900,
+    0x506e0180, 0x05c60f40, 0x78e0c820, 0xb9d63410, 0xd2bf7a38, 0x6efded0c, 0xced97b8a, 0x076fe271,
+    0xf643b393, 0xfb22d64d, 0x003349bb, 0xe1ef7b71, 0x8900922f, 0x3e852912, 0x33446997, 0x1fa58b48,
+    0x9070fa36, 0xebcdad19, 0x2bb3dba3, 0x2e2a524b, 0xeae4fba6, 0xaed0a248, 0xc13c93a6, 0xdd3c2644,
+    0x5f392189, 0xf03cff5f, 0xdfb8a036, 0x7e7ab01d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x88000000, 0xcc000000, 0xb6000000, 0xcd000000,
+    0x48800000, 0x9b400000, 0xc6600000, 0x9a500000, 0xaa580000, 0xaaa40000, 0xf8f60000, 0xbeaf0000,
+    0x320f8000, 0xfffe4000, 0x4dd36000, 0x9e9f3000, 0xdc02a800, 0x8e05e400, 0x69044a00, 0x42800b00,
+    0x68410e80, 0x8fe3c4c0, 0xff904820, 0x3a7f9430, 0xbe140238, 0x53bf9f1c, 0x78350ca2, 0xfa0c5bf3,
+    0x13fd448d, 0x6bd7cfc3, 0xab9f468a, 0xd08750ca, 0x6f45ca0b, 0xd4654b39, 0x5d53ee9d, 0x11d9b4f6,
+    0x78600003, 0x5b500039, 0xb4d8003a, 0x8ce40010, 0xfe160038, 0x73bf003e, 0xe837803f, 0x020a4033,
+    0x57fd6010, 0x11d4300c, 0xd09b280a, 0x5504a432,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x38000000, 0xa4000000, 0x26000000, 0x41000000,
+    0x6d800000, 0x60c00000, 0xabe00000, 0x6bd00000, 0x9ab80000, 0xcbd40000, 0x2aba0000, 0x03d70000,
+    0x26be8000, 0xb9d6c000, 0xe5bba000, 0xb357f000, 0xa9f9a800, 0x15f49c00, 0x090aae00, 0x4f79c300,
+    0x33b5f080, 0x34ab16c0, 0xd10aae20, 0x3b79c310, 0x4db5f098, 0x41ab16e4, 0xa28aae2e, 0xbeb9c339,
+    0xadd5f091, 0x0bbb16f4, 0xfe52ae35, 0x7e7dc321, 0xb637f0bb, 0xa86816ee, 0x68ee2e13, 0x0fa80313,
+    0x5f88d0b1, 0xa13e26ee, 0x0212a61a, 0x10ddaf3d, 0x1ac07688, 0x48e489c2, 0x915450b0, 0x7efbe6f8,
+    0xf175862a, 0x084f9f01, 0x3e5efe84, 0x308225c1,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xe8000000, 0x74000000, 0x02000000, 0x13000000,
+    0x92800000, 0xc2c00000, 0xa4e00000, 0xd2d00000, 0x8fe80000, 0x8ad40000, 0x53ea0000, 0x1cd30000,
+    0x72eb8000, 0x75544000, 0x56a82000, 0x11745000, 0x3398b800, 0xdec8c400, 0xf462ae00, 0xa313e300,
+    0x698d3880, 0x42c71c40, 0xe4e2ae20, 0x32d3e310, 0xbfed38b8, 0x62d71c4c, 0x27eaae1a, 0x1ed7e30d,
+    0x61ef38b8, 0xe7d01c48, 0x946b2e3e, 0xb590a33d, 0xe14c9891, 0x51200c7c, 0x7eb3b61d, 0xf0f8371f,
+    0x755c8e85, 0x30282b7b, 0x91b7a081, 0x64788842, 0xae9b3830, 0x5148842f, 0xf9208e15, 0xeab4b329,
+    0xc2fe00af, 0x8e5b9846, 0xd6a8202d, 0x5174503c,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xe8000000, 0x4c000000, 0x1e000000, 0x57000000,
+    0xe8800000, 0x81400000, 0xb7200000, 0x2d100000, 0x96a80000, 0x95140000, 0xe2ae0000, 0x3f150000,
+    0x3faf8000, 0x3a924000, 0x536de000, 0xad72b000, 0xf79f5800, 0xc82df400, 0xef575200, 0xb30a4d00,
+    0x1c413a80, 0x52a67840, 0x51d75220, 0xd94a4d30, 0x95e13a88, 0xd5f6784c, 0x6edf521a, 0xfb0e4d23,
+    0xa0473a8f, 0x84a77859, 0x7ad6d220, 0xc7cd0d03, 0x0fa35aa2, 0x94568852, 0x958dea05, 0x32010926,
+    0x9907309a, 0x4784c15d, 0xfdc6ba8a, 0x8c603868, 0x9f34b22e, 0x1a3dfd06, 0x3d79e29f, 0xa95dcc66,
+    0xc6cbe027, 0x0c23b035, 0x2b96d825, 0x88eeb434,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x18000000, 0x84000000, 0x42000000, 0x53000000,
+    0xc8800000, 0xe3c00000, 0x31600000, 0x7ff00000, 0x42080000, 0xbbf40000, 0x600e0000, 0xf8f10000,
+    0x008b8000, 0x8731c000, 0xf7efa000, 0xe9c3d000, 0x2e653800, 0x79719400, 0x9ccc2600, 0xcf111f00,
+    0xa1bc5980, 0xb1decf40, 0x8dac2620, 0xc0e11f30, 0x5bb459a8, 0x3e2acf6c, 0xb7a22626, 0xef101f11,
+    0xd1bfd9b8, 0x09db0f78, 0xb9ad8614, 0x9ae3cf29, 0x8cb2e1b4, 0xb4ae9b67, 0x0767a004, 0x16f7d007,
+    0x4d8b382c, 0x7ab09419, 0x7dafa624, 0xb8e4df16, 0xcfb5f9b9, 0xd4281f4f, 0x78a49e09, 0xe1944b19,
+    0x237a5fa6, 0xa33cc044, 0xed196795, 0x0a48546c,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0xe8000000, 0x34000000, 0x6a000000, 0xe3000000,
+    0x93800000, 0x7dc00000, 0x28a00000, 0x87500000, 0x77980000, 0xdb540000, 0x699a0000, 0x72550000,
+    0x49188000, 0x74124000, 0x28792000, 0xb1e11000, 0x3bb63800, 0x8ea98c00, 0xf4bbd600, 0xbdc7c900,
+    0x48a26c80, 0xf756b5c0, 0xcf9bd620, 0x0757c910, 0x379a6c88, 0xfb52b5d4, 0x3999d61a, 0x9a56c91d,
+    0x7d18ec92, 0x1e15f5ec, 0xcb78763e, 0x22659902, 0x4677f498, 0xa60d69cd, 0x73ed9823, 0xca5fdc34,
+    0x93f44e02, 0x9ec91511, 0xbdcca291, 0x4e4ce0f9, 0x438cd4a0, 0xd32d79f9, 0x8879200d, 0xa1e11013,
+    0xf3b63815, 0xeaa98c27, 0x76bbd633, 0x6ac7c900,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x38000000, 0x2c000000, 0x96000000, 0x1f000000,
+    0xba800000, 0xb9c00000, 0xafa00000, 0xdad00000, 0x5b680000, 0x5ed40000, 0xb96a0000, 0x8bd50000,
+    0xe2e88000, 0x8394c000, 0xf409e000, 0xc6207000, 0x7f911800, 0x1a09a400, 0xd522d600, 0x0317d900,
+    0x84cb8080, 0xec004240, 0xf602d620, 0x6f07d910, 0xd2838088, 0xadc44254, 0x15a0d62e, 0x53d6d91b,
+    0xfee900ad, 0x5d918253, 0xaf0bb600, 0xfea76935, 0x63517886, 0x86289665, 0x13b39816, 0xf4986422,
+    0x16abb628, 0x7b776907, 0xa2b9788e, 0x313c9652, 0x3d79983d, 0x899d6432, 0x392b3617, 0xb937a90b,
+    0x555a9883, 0xc509e66e, 0x0fa00015, 0xcad0001d,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x88000000, 0x14000000, 0xca000000, 0x3b000000,
+    0xe1800000, 0x54400000, 0x7c200000, 0xa0300000, 0xbeb80000, 0x0c340000, 0x38be0000, 0xc1310000,
+    0x2c3e8000, 0x0df54000, 0xcade2000, 0xae231000, 0xc737f800, 0x21381c00, 0xb9775a00, 0x1618fd00,
+    0xc0c7db80, 0x70e1c8c0, 0xe6d75a20, 0x0d68fd30, 0x89dfdba8, 0xa3a5c8c4, 0x75715a02, 0x201dfd35,
+    0x95c75b9a, 0xf86588ca, 0xf417fa3a, 0x1a8ead20, 0xf08e0385, 0x1b8ac4e2, 0xd20ff815, 0x824c1c23,
+    0xbc695a0b, 0x3359fd12, 0xa6615bbe, 0xc51088d0, 0x600f7a31, 0x554eed0d, 0xbbe8a385, 0x1a1994d9,
+    0x56c6a00f, 0x35e35003, 0xe651583f, 0x0bab4c1e,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x18000000, 0x04000000, 0x62000000, 0x1d000000,
+    0x9c800000, 0xdd400000, 0xf4a00000, 0xf7100000, 0x94880000, 0x35140000, 0xd98e0000, 0x91950000,
+    0x90ca8000, 0x1f35c000, 0x7edaa000, 0x753ed000, 0x8b8e4800, 0xc4943400, 0x304da200, 0x34711100,
+    0xed7bda80, 0x07a91540, 0x3cc5a220, 0xc5651130, 0x76f5da88, 0x1b3c1564, 0x288f2226, 0x0310d131,
+    0x9e8f7a90, 0x8412c563, 0xab096a01, 0x2fd0e506, 0x83ecd8ad, 0xd6e6d45e, 0x423030a4, 0x0258304b,
+    0x187ddabb, 0xf728154a, 0x2f812208, 0xc2c5d128, 0x7e65fa82, 0xb5770566, 0xd7fbca3a, 0x85ea353a,
+    0xd9e490af, 0x6bb3e05d, 0xcf9912a4, 0x4f99e141,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0xb8000000, 0xb4000000, 0xae000000, 0x59000000,
+    0x26800000, 0x8fc00000, 0x9be00000, 0x0b300000, 0x26b80000, 0x71340000, 0x61ba0000, 0x46b10000,
+    0x94f88000, 0xf094c000, 0x472d2000, 0x0c99b000, 0xe0e3e800, 0x2eb68400, 0xd8fc9600, 0x4a93db00,
+    0xe02c8f80, 0xcb1d0ec0, 0xfda49620, 0x9497db10, 0x112e8fa8, 0x41980ed4, 0x3866160e, 0x40721b3d,
+    0xd19b2f83, 0x60657ec2, 0x0470de07, 0x97992f1e, 0x356651a5, 0x38f521c0, 0xef5a478e, 0xd1063ac2,
+    0x9a81e81d, 0xddc38401, 0x30e61623, 0xd6b21b3a, 0xccfb2faa, 0xb4957eeb, 0x0128de16, 0x599d2f0e,
+    0xdc6451b8, 0x567021f5, 0x3c98c784, 0xe8e3fae6,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x68000000, 0xac000000, 0xb6000000, 0x93000000,
+    0xe9800000, 0x5c400000, 0x35600000, 0x74d00000, 0x23780000, 0xa6d40000, 0xfa7e0000, 0x22550000,
+    0xe0ba8000, 0x7cf64000, 0xc24fe000, 0x41bbf000, 0xfc777800, 0xe28ebc00, 0xaa1a9a00, 0xcc069700,
+    0xc604fc80, 0x4b02acc0, 0xbd829a20, 0x2e429730, 0xbc62fc88, 0xb853ace4, 0x05be1a3a, 0x2675d71b,
+    0xe7899ca5, 0x409b1cc0, 0x11c40200, 0x0322db0c, 0xbcb59ea8, 0x792dc7dd, 0x426f9c88, 0xbc8a1ce5,
+    0x55188236, 0xb3859b25, 0x9946fe98, 0xffe177ee, 0x39138494, 0x8e5c10fa, 0xfd600030, 0x88d00029,
+    0xdd780001, 0x09d4002e, 0xcdfe0004, 0x4115000e,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xb8000000, 0x3c000000, 0x9a000000, 0xcf000000,
+    0xed800000, 0xffc00000, 0x4f600000, 0xe0f00000, 0xff580000, 0x8ff40000, 0x62de0000, 0xe8350000,
+    0xa1bb8000, 0x2ac0c000, 0xade26000, 0xd2b01000, 0xfffa1800, 0x98627c00, 0x41766600, 0x0298c700,
+    0x83970180, 0x4da92dc0, 0x1ba86620, 0x16adc730, 0x982c8188, 0x1869edec, 0xe3ca060e, 0x07ddd73f,
+    0xb2b699ae, 0xaffb91df, 0xb0646035, 0x75711000, 0x1c9f983d, 0xea97bc27, 0xf52f862a, 0xc6e81713,
+    0x4b8f79ad, 0xc87b41f1, 0x48a4180c, 0x73977c26, 0x15ade628, 0xb7a8071a, 0x84ad6186, 0xd32d3dc0,
+    0x53ec7e25, 0x490abb0d, 0x6a396784, 0x4005eaec,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xe8000000, 0x64000000, 0xca000000, 0x6d000000,
+    0x14800000, 0x74c00000, 0x0ba00000, 0x47900000, 0x6c380000, 0xde940000, 0x7aba0000, 0x93570000,
+    0x17988000, 0x41004000, 0xca83a000, 0x0bc21000, 0xae22c800, 0x61d1b400, 0xd05bee00, 0x2122a900,
+    0x5c50f280, 0x4a1898c0, 0x8b41ee20, 0x88e5a910, 0xbb707298, 0x954cd8d4, 0x12d84e1a, 0x5ee0b909,
+    0xb0723aaa, 0x72c92ccf, 0x6d9a001f, 0xd4070014, 0x32008028, 0x8104401e, 0xea81a024, 0x3bc11033,
+    0x1620482e, 0xedd6f42e, 0x7e58ce1b, 0x8624f93a, 0x25d39aae, 0x2a583cf7, 0xf4224815, 0xc4d5f42f,
+    0x90da4e00, 0x27e3b93a, 0xb6f0ba83, 0xb70e6cc3,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0x68000000, 0x7c000000, 0xda000000, 0x51000000,
+    0x15800000, 0x14c00000, 0x51e00000, 0x66300000, 0x60180000, 0xbb340000, 0x979e0000, 0x0af30000,
+    0xe5fa8000, 0x9b004000, 0xec816000, 0x9d429000, 0x0f25e800, 0x0ed53c00, 0x6fa9ce00, 0x15e8f300,
+    0x2c4b1f80, 0xeedc23c0, 0x9bd7ce20, 0x942bf330, 0x86299fb8, 0x1b2863e4, 0xcca8ae3a, 0x0d6a632f,
+    0x870ef78e, 0x02f91ff0, 0xad86003f, 0x20c7002a, 0x87e4801a, 0x15334029, 0x309be007, 0x1c72d034,
+    0x59bc8807, 0x79a3ac0f, 0xe2922624, 0x050ecf0d, 0xf7f8518a, 0x060490fc, 0x3b05b182, 0x5c8140fb,
+    0x8545b99d, 0x8b25acf4, 0xc0d27fb3, 0x98aab3d5,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xf8000000, 0x7c000000, 0xb2000000, 0xf7000000,
+    0xf3800000, 0x53400000, 0x18200000, 0xa9b00000, 0xf5680000, 0xeab40000, 0x80ea0000, 0x88f50000,
+    0x7a4c8000, 0x4082c000, 0x0ec76000, 0xfe635000, 0x9d12d800, 0x781dcc00, 0x9abac200, 0x9ecccd00,
+    0x50476f80, 0x4da57640, 0x1bf0c220, 0xf7c9cd10, 0x5ec3ef88, 0x4663b674, 0xc115a21e, 0x1a1b9d0f,
+    0x95bfb7a4, 0x114dba49, 0xb1068022, 0xa287c01b, 0x41c3e022, 0x51e59023, 0xac57b83f, 0x6f3f9c31,
+    0xbc8e9a0a, 0x8ae6c135, 0x55d64d9f, 0x97782b7e, 0x352a1585, 0x7f162767, 0x371937ac, 0x353a7a50,
+    0xaf8d6013, 0x47665001, 0x6b965837, 0x0fdb0c0b,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xe8000000, 0xf4000000, 0xc2000000, 0x0b000000,
+    0xee800000, 0x49c00000, 0x6ba00000, 0xcc700000, 0xabb80000, 0x95740000, 0x963e0000, 0xce330000,
+    0x72598000, 0x48e44000, 0x04162000, 0x136e5000, 0x2e3c7800, 0x02342400, 0xbc5cb600, 0x6de6b900,
+    0x3f92b580, 0x892e94c0, 0x2bdab620, 0x09a1b930, 0x177535b8, 0xbd39d4fc, 0x30b5161a, 0x239ba90d,
+    0x3f476d88, 0xfe67a0fe, 0x71d3d821, 0x5ece341f, 0x334eee12, 0x5008cd0d, 0xb86c7bab, 0x10bf09ca,
+    0xb3f535af, 0x3bf9d4f2, 0x7f951615, 0xa92ba929, 0x3bdf6dbc, 0x11a3a0c5, 0x0b75d817, 0x8b393438,
+    0xf9b16e38, 0xc61b8d00, 0x9805db83, 0xdc0219ec,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x18000000, 0xa4000000, 0x96000000, 0x89000000,
+    0x44800000, 0x4ec00000, 0x59200000, 0x99500000, 0x8fb80000, 0xfe540000, 0x763a0000, 0x9a130000,
+    0x9cdc8000, 0x90e3c000, 0xe9f1a000, 0x6aab7000, 0x3f0be800, 0x14985400, 0x10021e00, 0xb807dd00,
+    0x9403b480, 0x5e07f0c0, 0x35001e20, 0x7680dd10, 0x51c534b8, 0x94a730f4, 0x93153e26, 0x985c6d39,
+    0x3e257c9d, 0x60d714d6, 0xebf84837, 0x14b4242a, 0x7ccf760b, 0xef3f4930, 0x66948ab4, 0x461c9dc5,
+    0x97c3e28e, 0x45a709f2, 0x93905c8d, 0x909ba4ec, 0xf600002f, 0x19000017, 0xbc800010, 0x3ac00021,
+    0xd7200011, 0xb450000a, 0x5d38002f, 0x39940021,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x88000000, 0xdc000000, 0x2e000000, 0x41000000,
+    0x03800000, 0x99400000, 0x18e00000, 0x9cf00000, 0xb7280000, 0xabf40000, 0xf1ae0000, 0xdb310000,
+    0x7b0f8000, 0x67a54000, 0x79116000, 0x96583000, 0x7c996800, 0x153f2c00, 0xdd2f2200, 0x60f72b00,
+    0x692ce580, 0xc2f74240, 0x1e292220, 0xe4722b30, 0xfeed65a8, 0xd6d3024c, 0x167fc202, 0x26cb5b07,
+    0x88836da3, 0x61c11e5c, 0x91200802, 0x41d31c21, 0x60f84a25, 0xde09073b, 0xde24478f, 0xf351297b,
+    0xd23aa791, 0xb3ac5971, 0xfc32afbb, 0x458b4569, 0xe364e58e, 0x31b3427f, 0xcdcf220d, 0xe0072b2d,
+    0x5004e594, 0x18034246, 0x64072214, 0x7a032b3a,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x38000000, 0x5c000000, 0xa6000000, 0x01000000,
+    0x79800000, 0xa3400000, 0xf1200000, 0xfe500000, 0x59480000, 0xc5540000, 0x27ca0000, 0xae930000,
+    0x64288000, 0x66a2c000, 0xc8126000, 0xa3ebf000, 0x0b446800, 0x35253c00, 0xb450f200, 0x964bf500,
+    0xd9d72080, 0x070e6f40, 0x5bf2f220, 0x13dcf510, 0x1b7da0b8, 0x066baf54, 0x4e02122e, 0x2506c507,
+    0x63832891, 0x5447a354, 0xb1a4e830, 0x7893fc2f, 0xad28922d, 0xcb23052b, 0xf953c886, 0x91cd934e,
+    0x7792603c, 0x11abf004, 0x5be46829, 0x64353c14, 0x8238f209, 0xf04ff52b, 0x78d520a4, 0xce896f65,
+    0x90b07228, 0x86fd351e, 0x1f2f40a9, 0xf8269f7e,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0xa8000000, 0x84000000, 0x9e000000, 0x1b000000,
+    0x1c800000, 0x8b400000, 0x6b600000, 0xddb00000, 0x82680000, 0x2cb40000, 0x73ee0000, 0x44770000,
+    0x614d8000, 0xcae24000, 0xcd71a000, 0xaccab000, 0x60237800, 0x76d75400, 0x3fdba200, 0xded8cf00,
+    0xf75d2f80, 0xb39a8c40, 0xbb3da220, 0xb0abcf30, 0x1b16af98, 0xeafbcc5c, 0xa78f820a, 0xcb443f11,
+    0xcb61f7bf, 0xcdb0685a, 0x5a68f80d, 0x00b61433, 0x69e98225, 0xc1773f2d, 0x66ca778d, 0x5d212848,
+    0x2d52d801, 0x1a1de410, 0x3ff8da27, 0xd80f9b07, 0x60868d9f, 0xe942436c, 0xd2608da7, 0x18314352,
+    0xbcab0d9f, 0xd1100343, 0xd7f92db5, 0xfc0ff379,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xe8000000, 0x4c000000, 0xf2000000, 0xb5000000,
+    0x6d800000, 0x43400000, 0xbee00000, 0xdef00000, 0xbec80000, 0x0df40000, 0xd04a0000, 0x78310000,
+    0x1f6d8000, 0x27e64000, 0x5177e000, 0xf08dd000, 0x8a944800, 0x1bf9bc00, 0x731cd200, 0xe6e88b00,
+    0xd2a50580, 0xb4918d40, 0x1cfed220, 0x0b9d8b10, 0x382a85a8, 0xc702cd7c, 0x9886b21a, 0x8ec31b03,
+    0x0da6ad94, 0x8815e151, 0x2c3e4801, 0x4138bc13, 0x68b9523b, 0xc5facb26, 0x2418e58e, 0x866d5d40,
+    0xa8671a07, 0x1f327714, 0x77e9b79b, 0x41239666, 0x10d5ff95, 0x8a9e2a56, 0x13ab2dbe, 0x3743a14d,
+    0x58e1a815, 0x9df16c34, 0x284f1a10, 0x8c367717,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x18000000, 0x24000000, 0x8e000000, 0x8b000000,
+    0x5b800000, 0xfc400000, 0x01e00000, 0x08d00000, 0xc9f80000, 0x43540000, 0xedbe0000, 0x46b50000,
+    0xfb6a8000, 0x1c4dc000, 0xc7bfa000, 0x83b7b000, 0x0beef800, 0x2b8fec00, 0x221cda00, 0xae844300,
+    0x44c52c80, 0x2a21f4c0, 0xd7765a20, 0xf9c98330, 0xf8fa8c88, 0xc5d644e4, 0xc578a226, 0xfe966f39,
+    0x9d1e56ab, 0xa30607c6, 0x37838eb0, 0x6e429bc6, 0xb0e28c8b, 0x4e5244f4, 0xc13ea20a, 0x6b776f32,
+    0x93cad696, 0xddfec7dc, 0x8556ae9f, 0x7ab8ebf3, 0x2733d497, 0x1a2a18fa, 0xe92c8036, 0xe6acc03d,
+    0x3ceb201d, 0x1a0f7007, 0xfb5bd817, 0xefe59c12,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x68000000, 0x5c000000, 0x82000000, 0x39000000,
+    0x70800000, 0xe6400000, 0xd5600000, 0xca900000, 0xd6780000, 0xea140000, 0x883a0000, 0x9b710000,
+    0xc4ab8000, 0xc20a4000, 0xb93d6000, 0x67f45000, 0x58eda800, 0x2a6c5400, 0xb528da00, 0xb0cb9f00,
+    0xac1cde80, 0xc70784c0, 0xbb835a20, 0x67c1df10, 0x2fa1bea8, 0x13b3d4cc, 0xac8ef23a, 0x047d8b07,
+    0x6b116488, 0x5cbc4bc2, 0xfb302ca6, 0x9ecf0ffe, 0x9f1bbe9d, 0x4282d4e0, 0xf745723b, 0x59e7cb08,
+    0x9ed40485, 0x521c1bc1, 0x0c078482, 0x3a025bfa, 0x9d00e48d, 0xf6870be6, 0x6946cca9, 0x42e11fc0,
+    0xe7537693, 0xacded0de, 0x63220035, 0xa7f5002b,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x38000000, 0x34000000, 0x3a000000, 0x9b000000,
+    0x35800000, 0x48400000, 0x10600000, 0x70300000, 0xd2c80000, 0xfdb40000, 0x6e8e0000, 0x77d10000,
+    0xd5bc8000, 0xa898c000, 0x54492000, 0xec771000, 0xc8acf800, 0xde823c00, 0xd5c27a00, 0xf4259f00,
+    0x3e57eb80, 0x3bfb7140, 0xb5fefa20, 0xd4fd5f30, 0xda7ecba8, 0xf7bc6154, 0x979a022e, 0xc3cb633d,
+    0x5b32b1a6, 0xef48fe72, 0x49f169a3, 0x18e8d26f, 0xaae56b82, 0xa7f2b15e, 0x89eb5a3f, 0xcc628f04,
+    0x863313b7, 0xe7cd4d7e, 0x3932802c, 0xb049c01c, 0x2e75a021, 0xc7afd01a, 0x8105d834, 0x3e852c21,
+    0xe5c68205, 0x3c23a31e, 0x625391aa, 0x0dfbee63,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x28000000, 0x24000000, 0xee000000, 0x3f000000,
+    0x89800000, 0x07400000, 0x86600000, 0xe6f00000, 0x6ca80000, 0xd7740000, 0xc7ee0000, 0x2b170000,
+    0x08188000, 0xfd3e4000, 0x9bada000, 0x6af73000, 0x66ab5800, 0x6673fc00, 0xa1692200, 0x8dd06f00,
+    0xad382580, 0x73abd240, 0x2ef1a220, 0x58ae2f30, 0x71758598, 0x0cece274, 0x6492fa2a, 0x1459d339,
+    0x1cdaa7a3, 0x451f8d7b, 0x09bc5f88, 0x506b4178, 0x4b5625a2, 0xbbfcd272, 0x2e89220b, 0xac606f39,
+    0x27f025b9, 0x922fd241, 0xadb7a22a, 0x80cd2f3d, 0x508305b7, 0xe5c5a253, 0x7ea7da29, 0x84d0a312,
+    0x67bc5fa6, 0xaf6b4144, 0xa2d62592, 0x6cbcd27d,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x28000000, 0x7c000000, 0xc6000000, 0xa5000000,
+    0x7e800000, 0x16400000, 0xa7200000, 0xaa700000, 0xab680000, 0xb8f40000, 0x332a0000, 0x36d50000,
+    0xd9dc8000, 0x7afc4000, 0x028b6000, 0x96e07000, 0xe3934800, 0x357b0400, 0xb04dba00, 0xb4845d00,
+    0xcd461980, 0x08a70e40, 0xa8b13a20, 0x51081d10, 0x12257988, 0x9cf37e74, 0x3128722a, 0x21d6190f,
+    0x685c43b9, 0x593f635d, 0x146f0bb5, 0x3574674a, 0xf6eab1b0, 0x55343a67, 0x2ccea817, 0x05c23410,
+    0x03e11216, 0x05176925, 0x87398bb8, 0x556d2743, 0x29f551ab, 0x07ac0a7e, 0xbb94803a, 0xf1784036,
+    0x02496021, 0x7b81702a, 0xf8c5c820, 0xb9624404,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xf8000000, 0x24000000, 0x6e000000, 0x3b000000,
+    0x99800000, 0x18c00000, 0x59e00000, 0xd0700000, 0xd0c80000, 0x35f40000, 0x720e0000, 0xe1150000,
+    0x14ff8000, 0x269ac000, 0x7f2aa000, 0x2a87b000, 0xbd467800, 0x1b20fc00, 0x14967e00, 0x7e3b9300,
+    0x2e79cd80, 0x53d82d40, 0xb809fe20, 0x74115330, 0x967b6d88, 0x97db9d4c, 0xc609861e, 0x8710af39,
+    0xd3fc9393, 0xc51fce42, 0xcaed6bb8, 0xf5e1f27f, 0x8277b5a5, 0xb1ccd146, 0x31718004, 0x144fc03e,
+    0x73b52027, 0xd0ad7007, 0xdd44d832, 0xcb234c05, 0xfc960628, 0x923a6f0e, 0x9c7e33ae, 0x22dc7e70,
+    0x748d1384, 0xce500e64, 0x4ed84ba1, 0x068c8252,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0xd8000000, 0x7c000000, 0xba000000, 0x45000000,
+    0x78800000, 0x93c00000, 0x81a00000, 0x2dd00000, 0xb9380000, 0xc1540000, 0x84fa0000, 0xebf30000,
+    0x9aaa8000, 0xe28dc000, 0xb21d6000, 0xde421000, 0xe2e44800, 0x7bb75400, 0xc34b1600, 0x72bb3900,
+    0xbd17e880, 0xff18f8c0, 0xa2c19620, 0x4726f910, 0xcd1288b8, 0x771ee8c4, 0xe6c7de16, 0x9126ad0f,
+    0xea111e96, 0x369f11d5, 0xb785d688, 0xc64685eb, 0x3ee12096, 0x31b66cce, 0x4e4de01e, 0xae3cd01f,
+    0xe8d3a821, 0x81b8843b, 0xb2923e0e, 0x155e7d22, 0x1e20b69d, 0x5f9095f3, 0x99df68ae, 0x73e238e9,
+    0x4d34762b, 0xeb8e293e, 0x189b20b0, 0x5c856cc8,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x18000000, 0xdc000000, 0xfe000000, 0xbf000000,
+    0x88800000, 0xc9c00000, 0xf8600000, 0xc2d00000, 0xee780000, 0x34540000, 0x58be0000, 0xe4b10000,
+    0x43ae8000, 0xeaa9c000, 0x692c6000, 0xa2683000, 0x06cc9800, 0x31fcf400, 0xc1121600, 0xe319d700,
+    0x4b05f080, 0x62865cc0, 0xecc49620, 0xfde41730, 0xda9790a8, 0x345f6cc4, 0xb1a68e26, 0xfab12307,
+    0x8ca9e697, 0xca2e8beb, 0x74efe684, 0x600b8bf5, 0x591f6689, 0x16034beb, 0x0b058697, 0x0286bbfc,
+    0x5cc37e99, 0xf5e77fe5, 0x1e9570b8, 0x165e9ce9, 0xf0a67621, 0xcd35e73f, 0xcdef68a7, 0xfb8fa8e5,
+    0x4e5e0002, 0x4ca1000e, 0x83368025, 0x7aedc033,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0x98000000, 0x34000000, 0xb6000000, 0x03000000,
+    0x6d800000, 0x7ec00000, 0xda600000, 0x3df00000, 0xbf580000, 0xd6740000, 0xfa9e0000, 0x45970000,
+    0xe7ad8000, 0x8fabc000, 0xe3a82000, 0x01a87000, 0xc4ab3800, 0x32289c00, 0x156c0e00, 0x07cfef00,
+    0xe3585180, 0x0c720dc0, 0x1b998e20, 0xed102f30, 0x6fee7198, 0x728d7ddc, 0xccff3606, 0x5a63733d,
+    0xfdf25fb5, 0xdf5ee2dc, 0xa6725f9d, 0x629ee2e2, 0x71925fa3, 0x51aee2e3, 0x8caa5faa, 0x8e2ae2cb,
+    0x7f6c5fbb, 0x1ec9e2ff, 0x0fdfdfbe, 0xaa3522e8, 0xd1ba7f98, 0x19c692e0, 0x49e167b9, 0xfc327eeb,
+    0x62b851b2, 0x8c420dd4, 0x73218e3f, 0x08542f39,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x78000000, 0x34000000, 0x92000000, 0xa5000000,
+    0x5e800000, 0xb5400000, 0xb4200000, 0x3ff00000, 0x76880000, 0x2b740000, 0xe2ca0000, 0x0bd50000,
+    0x097d8000, 0x7dfec000, 0x39b82000, 0x91185000, 0x36ac0800, 0xe6852400, 0xe1466200, 0xf6272500,
+    0x12f00d80, 0x640f3940, 0x3833e220, 0x61ede510, 0xcfa22d88, 0x9432697c, 0x57ea6a3e, 0x50a2011d,
+    0xd3b66fac, 0x62281c55, 0x52c3efa9, 0x31e2dc70, 0x6591cfa1, 0x50df8c4a, 0xc6c847bc, 0x71d06846,
+    0x307c0587, 0x0d7a1d45, 0xf7fd801a, 0x78bec030, 0xd598203e, 0x6ae8501c, 0xaa240812, 0x5cf1242a,
+    0xcf0c620d, 0xedb2251a, 0xf12d8d94, 0x9341f955,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x38000000, 0x14000000, 0xb6000000, 0xf9000000,
+    0xec800000, 0xe9400000, 0xe0200000, 0xd6300000, 0xaa180000, 0xc0b40000, 0x085a0000, 0xeb110000,
+    0xfead8000, 0x3ee8c000, 0xba492000, 0x093f7000, 0x1103e800, 0x70839400, 0xd3436200, 0x4b233500,
+    0x4db2e380, 0x42dfedc0, 0x9356e220, 0x2e0ff510, 0x9b99c3a8, 0xab759dfc, 0xbc3a8a2e, 0x4f81a115,
+    0xbec40185, 0xfae018c2, 0x02d72195, 0xb5ce68ef, 0x17f9499d, 0x1be53ce7, 0x4a530b97, 0x728979e3,
+    0xeada0011, 0xaf510018, 0xe40d8025, 0x0898c002, 0x24f1200c, 0xe2fb700c, 0xe561e83f, 0x74169412,
+    0x632ce231, 0xb72ef515, 0xe12c43b8, 0x48295dfc,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x58000000, 0xd4000000, 0xa2000000, 0x73000000,
+    0xb4800000, 0x81c00000, 0x01200000, 0xb4d00000, 0xd6880000, 0xbe540000, 0xba4a0000, 0xb6f30000,
+    0xcedd8000, 0xdd19c000, 0x573f2000, 0x936ed000, 0xce23b800, 0x8e50f400, 0x424b7600, 0x72f75d00,
+    0xc4d94080, 0x22183140, 0x95bef620, 0xc3aa9d10, 0x088460b8, 0x0fc1e17c, 0x1422ce36, 0x7954a925,
+    0x1ccfb690, 0xfc36ac60, 0x5f78969b, 0x120c7c45, 0x27912eb0, 0x8f6f885d, 0x5027d896, 0x33511556,
+    0x03c9b808, 0xceb3f405, 0x57bef62d, 0x00aa9d2d, 0x040460a8, 0xaa01e16d, 0xef02ce10, 0x6a84a93a,
+    0xdcc7b68b, 0xb0a2ac4b, 0x5092969c, 0x91ef7c56,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0xe4000000, 0x52000000, 0x05000000,
+    0x88800000, 0x33c00000, 0x96e00000, 0x41900000, 0x41d80000, 0x4c140000, 0x3a9e0000, 0x09330000,
+    0x1de98000, 0x317ac000, 0xf422a000, 0x15f25000, 0xc28f8800, 0x89ac9400, 0xdddd5600, 0xda104300,
+    0x7d9faa80, 0x4cb795c0, 0x7aaad620, 0x22598330, 0x74548ab8, 0x983f05fc, 0x7407fe2e, 0x5a074709,
+    0x390654ac, 0xce83d2fd, 0x5cc5028c, 0xff6091c5, 0xa8d32829, 0xe1fdc41d, 0x13e35e04, 0x0912171a,
+    0x921e5cb1, 0x2af686ea, 0x430b7481, 0xacec42e2, 0x03ffaaac, 0x0ee795d7, 0x7592d614, 0x3bdd8318,
+    0x65128ab6, 0x3c1805e6, 0x89f07e3e, 0x548e873b,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xf8000000, 0x1c000000, 0x5e000000, 0xa7000000,
+    0x9d800000, 0xe3400000, 0xdc600000, 0x59b00000, 0x77080000, 0x5b340000, 0xb5ca0000, 0x0a130000,
+    0xbf1c8000, 0x5d2cc000, 0x32606000, 0x96b03000, 0xee8c5800, 0x2a735c00, 0x68af0200, 0x75231f00,
+    0xe8d69f80, 0x9e3c4f40, 0x9ff98220, 0x515cdf10, 0xa1ca7fb8, 0xc010bf74, 0x921dba1e, 0x0dabb317,
+    0x51a325af, 0xd113fc5d, 0xec98a7b9, 0x0168236f, 0xbf845838, 0x8a475c1b, 0xfee5023c, 0xeb701f1d,
+    0xee2a1fb3, 0x65e08f7a, 0x58f1e222, 0x6268ef00, 0x10042789, 0x5804e37d, 0x2c043820, 0x76076c0a,
+    0x43015a09, 0xdf874331, 0x1a471d9a, 0xe6e49047,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xb8000000, 0x4c000000, 0x8e000000, 0x37000000,
+    0x6d800000, 0x50400000, 0x11a00000, 0x8fd00000, 0x82c80000, 0xdd540000, 0x7b0e0000, 0x16b70000,
+    0x387a8000, 0x3b2ac000, 0xaf24e000, 0x0814b000, 0x562c5800, 0x07a25c00, 0xc4d29a00, 0x294ef100,
+    0xde13b480, 0x7d2d8f40, 0x3c261a20, 0x7f933130, 0x636dd498, 0x7a83ff64, 0x0dc6a20e, 0x09e1dd23,
+    0xfa7516bb, 0x771c5269, 0xaa9b0c95, 0x72db6377, 0x6778d81f, 0x02af9c3a, 0xcd64fa0d, 0x88b48114,
+    0x777d0c97, 0x7aa86378, 0xe1645836, 0x96b65c10, 0xf87c9a32, 0x5b29f114, 0x3f213491, 0xb0134f48,
+    0x1a2cfa27, 0x89a08107, 0xf3d30cb1, 0x44cf6365,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x68000000, 0xf4000000, 0xa6000000, 0xbf000000,
+    0xbc800000, 0xaac00000, 0xc4200000, 0xd3b00000, 0x33f80000, 0x34340000, 0x7bba0000, 0xa9d70000,
+    0x5aaf8000, 0x665c4000, 0xc8c42000, 0xf5277000, 0x4434d800, 0x33be1400, 0x8dd04e00, 0x24ab5500,
+    0x455c0380, 0x264422c0, 0x46e5ce20, 0x83901510, 0xf64fa398, 0xd0cb12ec, 0x070f363a, 0xdeae712d,
+    0x885cb5b1, 0x53c613c3, 0x37a55b95, 0xcdf576c7, 0x191c2020, 0xeda37027, 0xd0f6d821, 0xb49d1436,
+    0x00e5ce1c, 0xcc90150c, 0x42cfa39c, 0x3e0b12d1, 0x0d2f3612, 0x461e7119, 0xa124b5a2, 0x723213c0,
+    0x34bf5bb9, 0x1d5276fe, 0xb46ba019, 0x6c7b3025,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0x98000000, 0x8c000000, 0xaa000000, 0xad000000,
+    0xd3800000, 0xe3400000, 0x1de00000, 0x1ef00000, 0xb1080000, 0x14740000, 0x37ca0000, 0xa5d10000,
+    0x2e598000, 0x84edc000, 0xfe81e000, 0x70c69000, 0x5ea78800, 0x73148c00, 0x37fad200, 0x297b4300,
+    0x89b9d580, 0x3f1d9340, 0x58095220, 0x49f78310, 0x6789b5a8, 0x90b2c35c, 0x9f6d3a06, 0x39c09f33,
+    0x73276f82, 0xbb51cc77, 0x8e9e5db2, 0x32491f4b, 0x99938025, 0x613cc020, 0x70d86016, 0x842b5002,
+    0x38266806, 0x8fd21c26, 0xc35d5a39, 0xf76fcf37, 0x6dc30781, 0xf526d04a, 0xcc508797, 0x681a106f,
+    0x8e88e79b, 0xcd314076, 0xcf2e8f9d, 0x0ca35c74,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xd8000000, 0xf4000000, 0x26000000, 0x37000000,
+    0xd8800000, 0x74c00000, 0x8ae00000, 0xe0100000, 0x39b80000, 0xd3940000, 0xcffa0000, 0x70b70000,
+    0x888a8000, 0xcc59c000, 0x87012000, 0x3086f000, 0x28c59800, 0x90e1b400, 0xdd137e00, 0x043c0300,
+    0x6ed02e80, 0xde58e6c0, 0xb603fe20, 0xaf02c310, 0x4c838e98, 0x42c3d6c4, 0x65e54616, 0xcc96872d,
+    0x6b7de891, 0x6e74a1c9, 0xf76fb6a0, 0x3dce52f0, 0xd1fa0013, 0xe3b70021, 0xce0a8016, 0x6b99c010,
+    0x2b61202e, 0x6756f014, 0x651d9813, 0xe0a5b417, 0x79317e06, 0x33cf0335, 0x9af8ae84, 0x513226db,
+    0x4fca5e0b, 0xf0fef335, 0xa437b698, 0x5e4a52f8,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0xc8000000, 0x14000000, 0x7a000000, 0xe7000000,
+    0x09800000, 0x7c400000, 0xa2200000, 0xf4d00000, 0x5a480000, 0x20540000, 0xe88e0000, 0x47b30000,
+    0x5a3c8000, 0x970d4000, 0xbaf02000, 0x989c9000, 0xdb1e1800, 0x185d5c00, 0x8fff3a00, 0x3969ad00,
+    0x94016c80, 0xba05d740, 0x0705ba20, 0x5983ed30, 0xb443ccb8, 0xb6270754, 0x8ed70212, 0xbd4f6135,
+    0x29d2cea6, 0x94cf666d, 0xe5974c90, 0xaeee476a, 0xcd41222e, 0x9aa4f120, 0x7016569e, 0x9ca87a46,
+    0x4262d69e, 0x18f13a60, 0x839cf684, 0x0c9eaa6f, 0x611e6e94, 0x1f5eb674, 0xd67974aa, 0x8d2f8b4e,
+    0x22200017, 0x34d00036, 0xba480001, 0x70540016,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x48000000, 0xb4000000, 0xaa000000, 0xd5000000,
+    0xef800000, 0x37400000, 0xfe600000, 0x94900000, 0x6f980000, 0x2c140000, 0x065a0000, 0x78b10000,
+    0x42eb8000, 0xc45b4000, 0x69b22000, 0xef681000, 0xa21b8800, 0x9a57ec00, 0x2dbff200, 0x7660ff00,
+    0xc0972680, 0x559fbbc0, 0x81167220, 0x15debf10, 0x51f486a8, 0xc38debcc, 0x6a4c5a32, 0xdeee033d,
+    0x0a58dc82, 0xceb3e8f9, 0x19ec8689, 0x2cd9ebf0, 0x98765a1d, 0xd7cf030c, 0x80ab5c9a, 0xa5bca8e7,
+    0x2264a6a6, 0xfa90fbf3, 0xf89e5212, 0x9297af1e, 0x3c9c8ea5, 0xd09547ec, 0x6d9b8804, 0xdd17ec31,
+    0x3bdff232, 0x66f0ff07, 0x4d0f269f, 0x188bbbd9,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x78000000, 0x04000000, 0xb6000000, 0x4f000000,
+    0x40800000, 0x94400000, 0x15600000, 0xa5300000, 0x51580000, 0x20b40000, 0x189e0000, 0x68110000,
+    0x9b088000, 0x21dac000, 0x7cf4a000, 0x01fd7000, 0xd725d800, 0x4f54f400, 0xbce83a00, 0x49adbf00,
+    0xe74f8380, 0x28bd7dc0, 0x1bc6ba20, 0x01a27f30, 0x8415a3a8, 0x310fcdf4, 0xacd9423e, 0x6d743b31,
+    0xe63a6185, 0xa48036e7, 0x924703ae, 0x5267bdd4, 0x49b21a20, 0x0f1f0f3e, 0xc8d07bb2, 0x042b39d8,
+    0xa2897810, 0x7a1d8411, 0x1d53e227, 0xf5e84b02, 0x4e2f3992, 0x9f8a02d7, 0x639d998f, 0x569272d9,
+    0x4c4ec1ba, 0x6e3d46e1, 0xc882dbbf, 0xf84349e7,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0xd8000000, 0xbc000000, 0x1e000000, 0xeb000000,
+    0x55800000, 0x76c00000, 0xf6600000, 0x86700000, 0x4df80000, 0x22f40000, 0xf7be0000, 0x3ad30000,
+    0xe3ee8000, 0xe27dc000, 0xdd376000, 0x415f3000, 0x64e13800, 0xa3321c00, 0x7a58ce00, 0x09648f00,
+    0x79f0ed80, 0x4a3ac8c0, 0xd8104e20, 0x7f8e4f30, 0xf50f0db8, 0x8c4f38c4, 0xb5ee9616, 0xad79a31f,
+    0xcab11bbf, 0xd2985bfe, 0xf4066d83, 0xba0308c2, 0xa9012e22, 0xb0867f2f, 0x1046b588, 0x6927e4fe,
+    0x18d1b811, 0xd6ecdc0a, 0xbcf92e01, 0xee727f13, 0x19f8b594, 0xa8f4e4cd, 0x76bf3828, 0xfe511c33,
+    0x89ae4e2a, 0xc25d4f0d, 0x65618d89, 0x5ff2f8c2,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x38000000, 0x84000000, 0xba000000, 0x9b000000,
+    0x1f800000, 0xaec00000, 0x9d200000, 0x08900000, 0x47780000, 0xe6540000, 0x3a5e0000, 0xfec50000,
+    0x75278000, 0xa490c000, 0x717da000, 0x7b501000, 0x80def800, 0x6e802c00, 0xc2462e00, 0x8064d500,
+    0x0d709280, 0x4fcac2c0, 0x133b8e20, 0x0b34c530, 0x95ae6a88, 0x954aeecc, 0x537da02e, 0x94501011,
+    0x3d5ef826, 0xef402c2a, 0xc2e62e09, 0x3934d50a, 0x72a89289, 0x94cec2e4, 0xecbd8e36, 0xb5f5c522,
+    0x008feaa1, 0x21db2ef1, 0x2a018022, 0x5301c001, 0x83842034, 0xa0c5d00b, 0x8424d834, 0x0810fc38,
+    0x4c3d7625, 0x4eb0e91a, 0xb06e44b5, 0x1fef3bda,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xa8000000, 0xe4000000, 0x2a000000, 0x7f000000,
+    0x1d800000, 0x6d400000, 0x17a00000, 0x6b700000, 0x7c880000, 0xb6340000, 0xf32e0000, 0x61450000,
+    0x39a08000, 0xce744000, 0x6609a000, 0x8af01000, 0x47cad800, 0x4894bc00, 0x4cd81200, 0x74081100,
+    0x59f39180, 0x64484940, 0x08d1b220, 0x75f80130, 0xa1b94988, 0x0a9cf574, 0xcc29a00a, 0x9cc01009,
+    0xa4e2d802, 0xa1d0bc2b, 0x417e122d, 0x38791122, 0xa4fd118f, 0xd9390945, 0x5d589238, 0x374c5106,
+    0xeb5231b1, 0x15bc5976, 0x489d6a1b, 0x772dbd17, 0x7b47dba7, 0x1ea1a451, 0x4ff51192, 0x6d4d0973,
+    0x2c56922d, 0x6439513e, 0x3bdab1bd, 0x9d8c1970,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0xf8000000, 0x5c000000, 0x3a000000, 0x19000000,
+    0x8a800000, 0x6fc00000, 0x90a00000, 0x07700000, 0x04980000, 0x80b40000, 0x003e0000, 0x79c70000,
+    0x8ba18000, 0xa8f4c000, 0x2bdd6000, 0x9ed21000, 0x416c2800, 0x2fea8400, 0x862e3600, 0xa58e1100,
+    0x397f2580, 0x7ca431c0, 0x65735620, 0xd19c0130, 0x38330d98, 0xd2feb5fc, 0x05e5601e, 0xc0161027,
+    0xaf4a2816, 0x4959843a, 0x6511b61c, 0x2fcdd10c, 0xa79bc5aa, 0xd336e1c7, 0x857c1e03, 0x76a3950b,
+    0xe47093bc, 0xf71ee0e3, 0x95f113bd, 0x075a20ed, 0xb21473bc, 0xb24c30f3, 0x35de5b96, 0x21d5b4e1,
+    0x88efed87, 0x23a865d2, 0x0bcc2835, 0x019a8432,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x58000000, 0xf4000000, 0xe6000000, 0x71000000,
+    0xa3800000, 0x12c00000, 0x2fa00000, 0x8e500000, 0xcb680000, 0xe8940000, 0x42ca0000, 0x37c50000,
+    0x5a218000, 0x9593c000, 0xc34c2000, 0xca01f000, 0x23062800, 0x14832c00, 0xb0409e00, 0xc6e6a100,
+    0x47334480, 0x8c981640, 0xdcacbe20, 0xc2b75110, 0x8f5d6c88, 0x008f3a5c, 0x76262036, 0xc794f02d,
+    0x744fa831, 0x6884ec00, 0xca46be3e, 0xdde25139, 0xf7b4ecb2, 0xf2d8fa7f, 0xc748003a, 0x3404002e,
+    0x86020013, 0x21010032, 0x8b838032, 0xbec2c022, 0x3da7a019, 0x19573023, 0x19eb8836, 0x59d11c24,
+    0x7faa9617, 0x96347d20, 0x1f1df2ab, 0xb6699b5c,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x28000000, 0x3c000000, 0x76000000, 0x29000000,
+    0xd3800000, 0x1a400000, 0x39e00000, 0x48900000, 0x56780000, 0x86d40000, 0xc59a0000, 0xc1410000,
+    0xb1658000, 0x9a544000, 0x1d5e6000, 0x7566f000, 0x4852a800, 0xf6582c00, 0x35e21a00, 0xf6941300,
+    0x137e7680, 0xfb534cc0, 0xe2dc7a20, 0x6122e310, 0xbcb4dea8, 0x0f4f60fc, 0x1adc602a, 0xc523f01f,
+    0x7eb52835, 0x9c496c36, 0xbe5bfa3e, 0x19e3a309, 0xf8953e93, 0xde7cd0d8, 0x4ad52801, 0x9b996c37,
+    0xd443fa17, 0x14e7a31e, 0xa9173eb3, 0xf739d0c8, 0x27b2a80b, 0x27c82c37, 0x381a1a22, 0xa6001321,
+    0xb104768e, 0x67824cfd, 0xa041fa3e, 0x4ee2a338,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xc8000000, 0xd4000000, 0xd2000000, 0x0b000000,
+    0xa4800000, 0x79400000, 0xc0e00000, 0xb1b00000, 0x16680000, 0x34f40000, 0x608a0000, 0xf4470000,
+    0xfd658000, 0x07754000, 0xfb492000, 0xf4e4d000, 0xd3b4f800, 0xb568b400, 0xb4701200, 0x03cf6300,
+    0xeba7e780, 0x3a55a440, 0x63593220, 0xabdbb310, 0x5c9b1f98, 0xf179107c, 0xcd4b2012, 0xc5e7d025,
+    0x3833782c, 0x82aef43e, 0x1ad6b21b, 0xd019f32b, 0xec3fbf84, 0x77ac806e, 0x5b54f80c, 0x90d8b403,
+    0x10181230, 0xcc3b632d, 0xe7ade788, 0x6352a479, 0x8cdcb232, 0x161ef312, 0x153a3fba, 0x4829c04e,
+    0xbe95d831, 0x35786434, 0x674eea27, 0xb2e0d710,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0xd8000000, 0x1c000000, 0x4e000000, 0x7b000000,
+    0x19800000, 0x70400000, 0xde600000, 0x8b900000, 0xab780000, 0xe5d40000, 0x261a0000, 0xd3c70000,
+    0x1f278000, 0x0575c000, 0xcc2d6000, 0x5a08b000, 0xe3fd3800, 0x3517bc00, 0xacbcda00, 0xe0f27f00,
+    0x026e7e80, 0x4768f8c0, 0xdde9ba20, 0x782ecf10, 0xf0094698, 0x1af844ec, 0x7192e016, 0x7a797017,
+    0x0552580b, 0x12dc0c32, 0xdb646230, 0x7113031b, 0xbeba44a4, 0xb5f4f7fc, 0xd0ea1c8c, 0xf6abfbf5,
+    0x6fcbfe86, 0x63de38ea, 0xabe15a23, 0x4dd0bf03, 0xc21c9e87, 0x51c188d7, 0x4223e23c, 0xa3f6c319,
+    0xf7ef24a2, 0xc12847e2, 0xd48d24b5, 0x7c3b47cc,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0xa8000000, 0x1c000000, 0x3e000000, 0xad000000,
+    0x0c800000, 0x7ec00000, 0xfba00000, 0xc3500000, 0x5b180000, 0xef940000, 0xdbba0000, 0xb1450000,
+    0x3ce58000, 0xdeb44000, 0xcf2f6000, 0xb778b000, 0x11e38800, 0x92329400, 0x91e81e00, 0xbcd97900,
+    0x7ab64c80, 0xd52f15c0, 0x407f7e20, 0xca65c910, 0xc777c488, 0x970c81fc, 0x6568e00a, 0xc61df017,
+    0x2b136807, 0x697b6417, 0x2ce17629, 0xe6b71d18, 0xab2abab1, 0xcd7848db, 0x36e0a4b5, 0x11b031f4,
+    0x70a96820, 0x983e6430, 0x3004f63b, 0xc8035d37, 0xcc05daa0, 0x6600f8ed, 0x19032ca7, 0x2e82a5f7,
+    0xedc17602, 0x5a271d01, 0xb112ba90, 0xde7c48e1,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x68000000, 0xb4000000, 0xb2000000, 0xab000000,
+    0x3d800000, 0x86c00000, 0x42a00000, 0x06f00000, 0xe4e80000, 0xf6340000, 0x274e0000, 0x8a470000,
+    0x46e48000, 0x7d104000, 0xb57f2000, 0xb70ad000, 0xb3a2f800, 0xe4717c00, 0x8da8ee00, 0xc9563900,
+    0x369ce180, 0x5a9a0240, 0xa49fce20, 0x0198e930, 0xc1181998, 0x0b587e54, 0xedbda03a, 0xa0a9901d,
+    0x2dd75834, 0x46dcec3e, 0xf379b615, 0x0e09d50c, 0x1527d7bc, 0x6730976b, 0x95cdb996, 0x8b05ee6c,
+    0xcd84f821, 0xdec27c07, 0xcea26e2a, 0x68f1791b, 0x49ef4192, 0xd2b49248, 0x370c163e, 0x73a4451d,
+    0x84768f89, 0xddaf7b6b, 0xa1568fb2, 0x829f7b4e,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x98000000, 0x5c000000, 0x66000000, 0x5d000000,
+    0x23800000, 0x71400000, 0x02a00000, 0x02b00000, 0xc3380000, 0x51f40000, 0xe69a0000, 0xebc10000,
+    0x9a678000, 0x7251c000, 0x51af6000, 0x3cbbf000, 0x06b60800, 0x193d2c00, 0x7af5e200, 0xb01a4900,
+    0x7d04bf80, 0xd3805cc0, 0x49428220, 0xeea5b910, 0xfcb0b7a8, 0xc23870ec, 0x1472e006, 0xcadb3007,
+    0xcae6e831, 0xe9931c3b, 0xb3ce8a2e, 0x02e9950b, 0x191ad599, 0xbc87f9d7, 0x65c33f98, 0xe3619cd8,
+    0x7bd5e231, 0x33ea4906, 0x849cbf98, 0x6cc45cf3, 0x92e08232, 0x5590b925, 0xc5cd379e, 0x77e8b0fd,
+    0xfe9a0004, 0xf7c10038, 0x5c67801f, 0x9f51c01d,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x68000000, 0xec000000, 0x12000000, 0x83000000,
+    0x6c800000, 0xa9400000, 0xfb600000, 0x1c700000, 0x4a180000, 0xf3340000, 0x847a0000, 0x8ec70000,
+    0xe2a28000, 0x44504000, 0xba0fe000, 0x5fdfd000, 0xe9157800, 0xff6af400, 0xb2aed200, 0x5c897f00,
+    0xa19c8980, 0x88f7fbc0, 0x27593220, 0x9652af10, 0x190bf198, 0x435e0fc4, 0x3857603a, 0x8008902b,
+    0x50d8181c, 0x87956424, 0xbd2c4a01, 0xc9485b11, 0xfbbd23ba, 0x93e370e7, 0x0b31e9a9, 0x40786bc6,
+    0x10c3aa27, 0x63a78b30, 0xc3d05ba8, 0x93cd84d2, 0x1ffd3bb1, 0x8d0214ff, 0xc587a3a5, 0xa2c730f0,
+    0xd0a40985, 0xb750bbc2, 0xae8c5218, 0x72993f29,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x78000000, 0x4c000000, 0x72000000, 0x85000000,
+    0x70800000, 0xc2c00000, 0x0d600000, 0x04b00000, 0xa1080000, 0x50740000, 0x9f6e0000, 0x87470000,
+    0x07278000, 0xe710c000, 0xeadbe000, 0x08cc5000, 0x52944800, 0xf89d1400, 0x976cbe00, 0x1343af00,
+    0x69229380, 0x58161340, 0x2b5f5e20, 0x848bff30, 0x6a30db98, 0x04c8074c, 0x8092603e, 0x2d9b9023,
+    0xafe82804, 0xe581842d, 0x5a431602, 0xaba2eb23, 0x7fd2659f, 0x66bca860, 0x3aff7394, 0x8a594354,
+    0x220a961c, 0x3bf52b1c, 0x8a2e05af, 0xf5603872, 0x88b0dbbf, 0xb3080761, 0xe572602d, 0x97eb902a,
+    0x09802806, 0x78458428, 0x66a51627, 0x3b51eb0f,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0xc8000000, 0xec000000, 0x16000000, 0xe5000000,
+    0xf1800000, 0xf6400000, 0x0f600000, 0x3fd00000, 0x9c080000, 0xe0940000, 0x24ea0000, 0xa4010000,
+    0xba028000, 0x53034000, 0x0487e000, 0xcfc6f000, 0x15204800, 0x26b73c00, 0x46d89a00, 0x8d1bf700,
+    0x32384c80, 0x8f8f9b40, 0x21d57a20, 0x750c0710, 0xb71284a8, 0xefafe744, 0x7ee00012, 0x8990002b,
+    0x3368002d, 0xcf44003d, 0x70e2000e, 0xa8950006, 0x88e88006, 0x12024036, 0x4f05601b, 0x6a85b005,
+    0x1ec7a80a, 0xd6a1cc26, 0xcff0d209, 0x4b38cb2c, 0x500ad688, 0xa6956c5a, 0xa9efb68b, 0xa980dc4d,
+    0x92401e98, 0x55651044, 0xdcd2ccb3, 0x4088db45,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x18000000, 0x24000000, 0xd6000000, 0x25000000,
+    0x5c800000, 0x87c00000, 0x97e00000, 0x3f300000, 0x52a80000, 0xe9f40000, 0x97ce0000, 0xb0030000,
+    0x08008000, 0xdc07c000, 0x2207a000, 0xeb067000, 0x5d800800, 0x0d445400, 0x35251600, 0xf4565b00,
+    0xea5dc780, 0x2cba2a40, 0x410cb620, 0x75632b30, 0x51f54fb8, 0x43cdbe74, 0x4e000026, 0xc1000039,
+    0x6a80000d, 0x72c0003d, 0xd3600011, 0x9cf00028, 0x13480010, 0xf3c40006, 0x99e60003, 0xde37001b,
+    0x082e8000, 0x5334c023, 0x78af2016, 0xdef5b00d, 0xe849a82d, 0x56412416, 0x60a59e15, 0x2515cf34,
+    0xfd7f7185, 0x33ea0147, 0xf6d1798d, 0x549d5578,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x08000000, 0x0c000000, 0x2e000000, 0x11000000,
+    0x8f800000, 0xf9400000, 0x8f200000, 0xee100000, 0x26680000, 0x82540000, 0x44ca0000, 0x8a050000,
+    0x03018000, 0x98814000, 0x1bc3e000, 0xdfe13000, 0x8570e800, 0xe6dd0c00, 0xa26b4200, 0xe0560500,
+    0x5bcbba80, 0x6483ca40, 0x7dc2a220, 0xe2e23510, 0x54f2d288, 0x069b865c, 0xaec80022, 0xd9040013,
+    0xe3820003, 0x87410018, 0xe6238021, 0x6590403d, 0xfd286028, 0x3275703f, 0x345a882a, 0xdaa97c1e,
+    0xf7324a32, 0xbd7f3919, 0x59b99089, 0x78dd8346, 0xdb6bbab7, 0x33d3ca45, 0xf48aa209, 0xfea63513,
+    0x3e50d2a4, 0x02ca8650, 0xc703801e, 0xda804037,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x98000000, 0xf4000000, 0xda000000, 0x3d000000,
+    0x09800000, 0x08c00000, 0x7c200000, 0x8e100000, 0xbe880000, 0x81d40000, 0xe62e0000, 0xf6870000,
+    0x08408000, 0xc767c000, 0x17f12000, 0xdd3a1000, 0x4d8a4800, 0x7f549c00, 0x226cae00, 0xafe22d00,
+    0xb0b07680, 0x42de7540, 0x753b8e20, 0x318b3d30, 0xa954be98, 0xe16a2974, 0xd9668006, 0x78f4c00d,
+    0xe5bfa02e, 0xb2cad03b, 0x74b3e824, 0x10dd4c3f, 0x4439c629, 0xc60ba12c, 0xde9610ad, 0x5dcf0462,
+    0x8c367686, 0x8f9d756e, 0x9fdd0e15, 0x6cbffd15, 0xc14b1e82, 0xf170f943, 0x2d7d6821, 0xaeed8c2c,
+    0x2aa06621, 0x36527125, 0x31eb7884, 0xfa228853,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xb8000000, 0xf4000000, 0x9a000000, 0x09000000,
+    0x49800000, 0x0ac00000, 0xf7200000, 0x17d00000, 0x42280000, 0x56140000, 0xd18e0000, 0x30850000,
+    0x1b478000, 0x19e4c000, 0xf2f62000, 0x10f91000, 0x33bbe800, 0x1e5c9c00, 0x56ab1e00, 0x7f577500,
+    0xbd6ba880, 0x5df34840, 0x667b3e20, 0x07ff6530, 0xb139c088, 0xb01e144c, 0xf0c9800e, 0xd061c00d,
+    0x3831a02e, 0xc7ddd00e, 0x146dc83c, 0xe4758c3f, 0xf4b8f61b, 0x34dfe907, 0x84eeb682, 0x0f313d57,
+    0x755f16a9, 0x022ced79, 0xb612debe, 0xc189616f, 0xb882288e, 0x57428849, 0x77e29e2a, 0x61f6b502,
+    0x507a08bb, 0x70fe986a, 0xe3bef624, 0xb65ee918,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x98000000, 0xac000000, 0xfa000000, 0x37000000,
+    0xbe800000, 0x2f400000, 0x7aa00000, 0x66100000, 0x91980000, 0xec540000, 0xb6be0000, 0xdd850000,
+    0x97c38000, 0x90e5c000, 0x1130e000, 0xa84b1000, 0x4928d800, 0x35d82400, 0x3a73c200, 0xdceca100,
+    0x6e3e0a80, 0x67c179c0, 0xe8e52220, 0x2d36b130, 0x7a4b5288, 0x4a299dec, 0xdd5d8006, 0xd830c01b,
+    0x2fcb6036, 0x99ead021, 0xa3be3809, 0x18023420, 0x6c069a20, 0xda044514, 0x8706a88b, 0x268708f0,
+    0x83451093, 0x80a5fcde, 0x5110eaa9, 0x2f1f69c3, 0xc3167a27, 0xcc1f552a, 0xbb9670a7, 0x065b2ce3,
+    0x7cb0d29d, 0xa7885dc0, 0x75cb602e, 0xdeead017,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0xd8000000, 0x0c000000, 0x1e000000, 0xf1000000,
+    0x21800000, 0xd5c00000, 0x4c200000, 0x63b00000, 0x1a280000, 0x25740000, 0x608a0000, 0x9f870000,
+    0xb4c78000, 0x65a5c000, 0xd275e000, 0x9c0e3000, 0xa5c3e800, 0xb423fc00, 0xbfb22600, 0x6c2eed00,
+    0x00777380, 0x5308d040, 0xa545c620, 0x2863dd10, 0xf2511b98, 0x51bdec6c, 0x96ef8016, 0xbcd1c013,
+    0x14ffe01f, 0x4e893010, 0xf684683e, 0xf9463c36, 0x1e67c614, 0xb750dd24, 0xd23c9b8e, 0x742f2c7c,
+    0x2c75e02b, 0x9d0e302f, 0x3c43e833, 0xdde3fc3c, 0x3592261a, 0xf29eed03, 0x25df7383, 0x52bcd048,
+    0xa86fc633, 0x0194dd19, 0x109e9ba2, 0x72dc2c4d,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x88000000, 0xac000000, 0x06000000, 0x8f000000,
+    0x9a800000, 0x45400000, 0x5aa00000, 0x2d100000, 0xa5280000, 0x85540000, 0x600e0000, 0xce830000,
+    0x97408000, 0xc7a34000, 0xca93e000, 0xd7ec7000, 0x25351800, 0x417ae400, 0x4cdd5200, 0x1f4a8100,
+    0xb5220280, 0x33d51c40, 0xe348b220, 0xfb21f130, 0xf0d19ab8, 0xafcbb874, 0xb9608002, 0x9cf3401b,
+    0xc19be039, 0xc9e87017, 0xae331804, 0x41fde43a, 0xd89bd217, 0x746ec128, 0xedf7628f, 0x001d2c70,
+    0x0428ca2e, 0xccd32508, 0x01ccb0b4, 0xaa63ed64, 0x5877a8a8, 0x2fda095d, 0xa3ca7ab5, 0x4f63c846,
+    0xabf3983d, 0xaf1ea40c, 0xaea83229, 0xd192b12b,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x78000000, 0x6c000000, 0x26000000, 0xeb000000,
+    0xf9800000, 0xee400000, 0x19e00000, 0x36300000, 0x9a880000, 0xa1f40000, 0xad2e0000, 0xee270000,
+    0x91928000, 0xad58c000, 0xf14be000, 0xc850f000, 0x65f93800, 0x0d1d7c00, 0xd7ad6a00, 0x2de6a300,
+    0x4c351380, 0xdf8e3340, 0x37740a20, 0x88ee9330, 0xb887cb98, 0x8ac3bf64, 0xfd20583e, 0xc4154c2b,
+    0x051fb211, 0x93ab2f1e, 0x7fe141a0, 0x7d35ec60, 0x930c73af, 0xa7b60347, 0xa0ced220, 0x92171f07,
+    0xc61d99ab, 0x1e2f6049, 0x4ba2a1b5, 0xd1d11c52, 0xdbbb4b98, 0x0cfc7f5c, 0x1d993823, 0xe96d7c11,
+    0x6ac56a26, 0xad22a319, 0xdc1313ae, 0xf91d337d,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x98000000, 0xcc000000, 0x7e000000, 0x0d000000,
+    0x1b800000, 0xef400000, 0x7b200000, 0x74900000, 0xea280000, 0xf8540000, 0xa24e0000, 0x71e70000,
+    0x2df08000, 0x9e1fc000, 0x59ace000, 0x53153000, 0x936b0800, 0x6a72ac00, 0x375b5200, 0x538ea500,
+    0x0a05ce80, 0xcf06d040, 0x40873220, 0x7dc45530, 0xa5e22698, 0x5ff14c74, 0x8d1f6806, 0x9f2c5c03,
+    0x3fd2ba07, 0xcb0e3937, 0x1bc514a0, 0xa4e51948, 0x5a754ea1, 0xbf59106e, 0x678bd204, 0x68016501,
+    0x24012e88, 0x9a07e046, 0xf7023a28, 0x3c81f919, 0x4bc1f4bf, 0xfce42944, 0xf67046b1, 0x115cbc58,
+    0xf2880018, 0xbf840024, 0xb546000c, 0xec230037,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0xd8000000, 0x44000000, 0xa2000000, 0xd3000000,
+    0x55800000, 0xfb400000, 0x97200000, 0x68d00000, 0x4eb80000, 0xc2140000, 0xe0de0000, 0x1ee10000,
+    0x1b348000, 0xf50bc000, 0x48dca000, 0x12e1d000, 0x8537b800, 0x90083c00, 0xb4590200, 0x2b20f500,
+    0x1ed0ac80, 0x47bac4c0, 0x50912220, 0x341ae530, 0xe583b4a8, 0x134728d4, 0xfb219816, 0x06d32c21,
+    0xa3be9a00, 0xa296d920, 0x3f1cb6a3, 0xf402ddef, 0x4a03b4ad, 0xbf0728de, 0x3b81980e, 0x16432c2a,
+    0xf7a69a03, 0xb712d918, 0xa45ab68b, 0x9327dde8, 0xaad134b7, 0x6db9e8f9, 0x1f97b81f, 0x87983c19,
+    0x6fc10217, 0x02e4f521, 0x3d36ac93, 0x240fc4e7,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x48000000, 0xcc000000, 0x2e000000, 0x51000000,
+    0x89800000, 0xeb400000, 0x4a200000, 0x96d00000, 0xf2180000, 0x98140000, 0x8d7e0000, 0x7de50000,
+    0x9c378000, 0x9aab4000, 0x5e7be000, 0x5b613000, 0xf7f3e800, 0x064dbc00, 0xc3ca1200, 0xc6883d00,
+    0xfdaffc80, 0xd2fcb9c0, 0x83a67220, 0x9d924d30, 0xa83ff488, 0x76c435cc, 0xfb618832, 0x07f2cc03,
+    0x6e4d9a03, 0x3fcbf118, 0xa08be6b0, 0x60a908c9, 0x7579f4b9, 0x39e535c1, 0x5e300836, 0xd5a88c20,
+    0xcefffa2b, 0xf5a48105, 0x78946e82, 0x8bbec4e7, 0x2e83eebd, 0x47c584d7, 0xa2e00e82, 0xb4b0b4c4,
+    0x906de6b3, 0x031808eb, 0xe19074ac, 0x0e3b75d7,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x98000000, 0x84000000, 0x1e000000, 0x91000000,
+    0x6e800000, 0x79400000, 0x5ee00000, 0x28500000, 0x8b780000, 0x8b940000, 0x9ada0000, 0x80230000,
+    0x45728000, 0x6a8bc000, 0x6f58a000, 0xc0e23000, 0xf955a800, 0x05fc0400, 0xe2d70200, 0x5c3b6300,
+    0x2c755880, 0xd00854c0, 0x701d2220, 0x9b029310, 0x398050b8, 0xe2c260c4, 0x47258806, 0x06f6f431,
+    0x17488a3f, 0x4ffe9720, 0x55d752bd, 0xd7b903ff, 0xadb0d0b0, 0x7aaea0fe, 0xf22da821, 0xce680428,
+    0x980d022f, 0xcc18630f, 0xf107d892, 0x3e8394fd, 0x01458201, 0xcae0a33a, 0xae55f89b, 0x9e7e64cb,
+    0xfb128a10, 0x729d973e, 0xb045d28e, 0x1462c3d7,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0xe8000000, 0xcc000000, 0x66000000, 0x67000000,
+    0x00800000, 0xac400000, 0xc9a00000, 0x7b700000, 0xa8b80000, 0x13b40000, 0xf75a0000, 0x98650000,
+    0x97158000, 0xcdae4000, 0xdb1de000, 0x11c27000, 0xcc665800, 0x9515b400, 0x20ae9a00, 0x2a9b6900,
+    0x3205e980, 0x650000c0, 0xed86fa20, 0x5dc75910, 0xea665188, 0xd213c4fc, 0xd02c381a, 0x6ed88423,
+    0x37a2a211, 0x7873ed25, 0x223f4bba, 0x4ef7edd8, 0xb17bb18b, 0x83d1b4d7, 0x3c4a6002, 0x0bcd3003,
+    0xff0c383d, 0x9ee88437, 0x763aa22c, 0x4cf7ed1d, 0x5c7d4b92, 0x7256edd0, 0x1f8c3184, 0xa2aef4f3,
+    0x8798000e, 0xe3840038, 0xb6c2001a, 0xace10011,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0xd8000000, 0xcc000000, 0xd6000000, 0x3d000000,
+    0x91800000, 0xbe400000, 0x57a00000, 0x6b500000, 0x9be80000, 0x52940000, 0xaf0a0000, 0xcfe10000,
+    0xa9f68000, 0x453bc000, 0x253ee000, 0x553e7000, 0xbd3c8800, 0x393dbc00, 0xfb3d5a00, 0xdc391100,
+    0xa6bf3880, 0xb47e1a40, 0xcc1d3a20, 0x4ea8a110, 0xe9b750a8, 0x4b9cd664, 0x2dea6816, 0x1f97cc23,
+    0xd68bd21d, 0xf5a5ad2b, 0x3c54e292, 0x096ccb5c, 0xc454e280, 0x156ccb45, 0x6a54e28a, 0x746ccb4f,
+    0xf5d4e280, 0x3b2ccb6e, 0xe5f4e287, 0xd33ccb4d, 0xb83ce28d, 0x54b8cb41, 0xdb7ee2bf, 0xa29dcb62,
+    0x466a62ab, 0x7ad30b4b, 0x65a802b1, 0xa537bb79,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x68000000, 0x94000000, 0x0e000000, 0xcb000000,
+    0x63800000, 0x73400000, 0xeea00000, 0x0a500000, 0x0a680000, 0x84940000, 0x748a0000, 0xf7e70000,
+    0x19f28000, 0x34b84000, 0x14382000, 0xc0fd3000, 0x5b1ff800, 0xf0eeec00, 0xf5530200, 0x57ea0900,
+    0x24d3d880, 0x15ac6e40, 0x7cf1a220, 0xac3b7910, 0x1cfe0098, 0xb118b244, 0x4defd83a, 0xa1d7dc35,
+    0xdd2efa1b, 0x7c37e536, 0x59d85a82, 0x6c092779, 0xbf20da98, 0x08166760, 0x324a7aa2, 0x2d031769,
+    0x7c85a284, 0x7ec4cb4e, 0x46e358a5, 0xef772e6f, 0xe7790221, 0x6e5d0912, 0x314958b2, 0xea802e7c,
+    0x19c38220, 0xb761491d, 0xdd3378b8, 0xc75e1e4d,
+    0x80000000, 0xc0000000, 0x20000000, 0x10000000, 0xf8000000, 0x6c000000, 0xb6000000, 0x1f000000,
+    0x7d800000, 0x5dc00000, 0x89e00000, 0xffb00000, 0xc3c80000, 0xb5f40000, 0x96ee0000, 0x15250000,
+    0x40d68000, 0xe63ec000, 0x1b1fe000, 0xcfc87000, 0xf3f09800, 0x41e9dc00, 0xeca05600, 0x3f158300,
+    0xaadd0380, 0x3028f940, 0x33413620, 0xefa73330, 0xfc947b88, 0x4c185544, 0xf649781e, 0xac30ac2b,
+    0x1d084e25, 0x3f979f03, 0xaf9c35a1, 0x008fca4c, 0x92554daf, 0xee7f6648, 0x7b3d0387, 0x7398f94e,
+    0x9e893611, 0x39533315, 0x59fa7bbb, 0x77fd554c, 0xf4fff80f, 0xf77e6c0d, 0x31bfae34, 0xe7dbef02,
+    0x80aaad8e, 0x1e071662, 0x6b059bac, 0x9785257c,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xe8000000, 0x0c000000, 0xc2000000, 0x09000000,
+    0x44800000, 0x1e400000, 0x4aa00000, 0x1b700000, 0x9d880000, 0xd7b40000, 0xd66a0000, 0x9de10000,
+    0xf5548000, 0xe8ba4000, 0x389ae000, 0xfeaf5000, 0x18032800, 0x7405b400, 0x86047a00, 0x1701d100,
+    0x67811980, 0x5fc08140, 0xd2621a20, 0x4690c110, 0x88dad1a8, 0x4f0f6574, 0xd6f3c81a, 0x874be413,
+    0x2353d218, 0x57be2536, 0xf31f83ab, 0xb36e0044, 0x2b602b82, 0x7a15f454, 0xd29cb1be, 0x1bae7556,
+    0xee800015, 0x5b400006, 0x6c200038, 0xdc30003a, 0x7ba80020, 0xde84001e, 0xc3420008, 0x58250024,
+    0xfa36800b, 0xbcaf400e, 0x5104601f, 0x9084101e,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xc8000000, 0x1c000000, 0x6a000000, 0x41000000,
+    0x03800000, 0xbc400000, 0xc9e00000, 0x29900000, 0xc1780000, 0x37540000, 0x075a0000, 0xc3670000,
+    0xca558000, 0xbedd4000, 0x66236000, 0x24371000, 0x9509e800, 0x59b8cc00, 0x1277aa00, 0xadefb300,
+    0xdbaf7e80, 0x834d8cc0, 0xf15b4a20, 0xa862e310, 0xe8d07698, 0xf11f10fc, 0xdb866812, 0xd8428c17,
+    0x17e14a02, 0xee95e32c, 0x21fdf6b2, 0xd59650e8, 0x1b7f0808, 0xde529c0a, 0xe8dd2228, 0xdd206f1e,
+    0x5eb13c81, 0xfecaf3fd, 0x5a1b9eb8, 0xd904dce2, 0x07864220, 0x92437f3a, 0xc6e2d48f, 0xd5153fc8,
+    0x49b9b4a5, 0x6a762fe8, 0x19ea5cb0, 0x5da9e3d5,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x48000000, 0xf4000000, 0x36000000, 0xb7000000,
+    0x45800000, 0x72c00000, 0x1f600000, 0x71d00000, 0x2fe80000, 0xd9940000, 0x23ce0000, 0xabe50000,
+    0x59978000, 0xe3cb4000, 0x8be22000, 0x6995f000, 0xabcb1800, 0x7fe61c00, 0x5f926e00, 0x1ccc7700,
+    0x3a642880, 0x2d507c40, 0x03a9ce20, 0x4bb7c730, 0x02ba9088, 0xda38d04c, 0x687a9832, 0xa95c5c0d,
+    0x83a9ce05, 0x8bb7c721, 0x22ba9083, 0xea38d061, 0x207a982a, 0x5d5c5c01, 0xb5a9ce12, 0x3cb7c726,
+    0x673a909d, 0x98f8d04b, 0x3f1a9830, 0x2c8c5c13, 0x9a41ce33, 0xe523c720, 0x44f490a9, 0x331dd046,
+    0x668d1813, 0xcf471c36, 0x11a3ee35, 0x8cb6372e,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x98000000, 0x74000000, 0x5a000000, 0x37000000,
+    0xcd800000, 0x0f400000, 0x48200000, 0x5a100000, 0x16380000, 0x67d40000, 0x50da0000, 0xa4a70000,
+    0x31d68000, 0x99dfc000, 0xfc232000, 0x20141000, 0xd1391800, 0xa2543c00, 0xb39f0200, 0xc2808d00,
+    0x06c6fa80, 0x756488c0, 0x5930a220, 0x37ac5d10, 0x67aa4298, 0xdfab64e4, 0x5bad9806, 0x09a8fc0d,
+    0xd2a8a20e, 0x31285d29, 0x53684295, 0xe1c864de, 0x79191824, 0x28443c0b, 0x5da7022e, 0x41548d3e,
+    0x941cfaa0, 0x92c388e2, 0xff66223f, 0x96339d22, 0x1e296280, 0xaaef74c6, 0xd48c802c, 0x9638c015,
+    0x27d5a018, 0x30dbd008, 0x34a23834, 0xa9d42c02,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x28000000, 0x5c000000, 0x16000000, 0xb5000000,
+    0x34800000, 0x37c00000, 0x9ca00000, 0x97900000, 0xd2580000, 0x8dd40000, 0xe3be0000, 0x50250000,
+    0x14548000, 0x24f84000, 0xe9446000, 0x9867f000, 0x82b64800, 0x4a8b9400, 0xcae95600, 0x8c5da100,
+    0xd4d6b280, 0x993a5fc0, 0xf6e7b620, 0xde771130, 0x7ba81a88, 0x303a7bd4, 0x3462c82a, 0x2cb3d427,
+    0xab8d360d, 0xe46a5139, 0x3098fa87, 0x95f5cbda, 0x1be8e002, 0x3adbb038, 0x6c142831, 0xea1d643a,
+    0xa3359e15, 0xd9cb7526, 0x698f04b4, 0x8b684ec7, 0xaf1b2caf, 0x2fb52acf, 0xba0eb2aa, 0xef2e5fda,
+    0x97f9b61e, 0xa0c21115, 0xb7249aaa, 0x47d63bce,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0xe8000000, 0x04000000, 0x9a000000, 0x37000000,
+    0xd0800000, 0xafc00000, 0x3ca00000, 0x80d00000, 0xb2e80000, 0x64940000, 0xcf0e0000, 0xaa270000,
+    0xde168000, 0x6fcc4000, 0xca04a000, 0x7f059000, 0xa4829800, 0xcdc2d400, 0xe7a38600, 0xce574100,
+    0xb02da880, 0xbfb1ca40, 0x309fa620, 0x8ba99130, 0x06f51098, 0x92fece44, 0xb65c181a, 0x0a8a9431,
+    0xae61263e, 0x73f1d109, 0x297fb08e, 0xcf1c5e6a, 0xc8e80009, 0x8394001d, 0x978e0000, 0x11e70036,
+    0x90b68006, 0xdc1c400b, 0x326ca03d, 0x8351900f, 0x87ac9835, 0x48f5d41e, 0xb7fd0611, 0x45df0108,
+    0x07cf08a7, 0x0e075a75, 0x8505be16, 0x83800506,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xd8000000, 0x7c000000, 0xbe000000, 0xcd000000,
+    0xb6800000, 0x05c00000, 0xb7200000, 0x32300000, 0x46680000, 0x5a740000, 0x6b0a0000, 0x0e210000,
+    0x2eb58000, 0x34abc000, 0xa4d06000, 0x41793000, 0xfd2c8800, 0xfc91c400, 0x541f8600, 0x857d6d00,
+    0x2f2b0f80, 0x0797c240, 0x7b986620, 0x2c3a9d10, 0x92c867a8, 0x4705f67c, 0x71868816, 0x3440c40f,
+    0x23620607, 0xe152ad0f, 0x7f396f9b, 0x594bf25e, 0x8c436e22, 0x4f65992f, 0xd7500194, 0xe63f6b6b,
+    0xf5ceefa1, 0x86853240, 0x3dc48e3d, 0x9b226904, 0xa4336980, 0x2f6d5f71, 0x2ef001a0, 0x1dcf6b42,
+    0xc286efa7, 0xafc13279, 0xc0268e10, 0x7bb76903,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x28000000, 0xb4000000, 0xde000000, 0xb9000000,
+    0x9c800000, 0x2ec00000, 0x11e00000, 0x04500000, 0xc3d80000, 0xc5140000, 0x577a0000, 0xd3e50000,
+    0x97528000, 0x18584000, 0xde512000, 0xdcddb000, 0x5c956800, 0x9f389400, 0xa3c0fe00, 0x13634b00,
+    0xb397e480, 0x5ebe79c0, 0xc7815e20, 0x3147bb10, 0xcca32c88, 0xbaf21dc4, 0x922d682a, 0x30bc943d,
+    0x0682fe3f, 0x11c24b2a, 0x986764ad, 0xe41739e6, 0x07f8fe13, 0x9f274b3f, 0x45b5e49f, 0xcf8f79fe,
+    0xa2c9de3b, 0x646afb08, 0x89988caa, 0x0633eddd, 0x18cb202f, 0x4b68b035, 0x281fe809, 0x5274d40a,
+    0x02ebde02, 0xa85bfb24, 0xa6500c9d, 0x60deadeb,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0xc8000000, 0x8c000000, 0xda000000, 0x25000000,
+    0xf4800000, 0x41400000, 0xaaa00000, 0xad300000, 0x0d980000, 0x93f40000, 0x39fa0000, 0xfaa70000,
+    0x25348000, 0xe19d4000, 0x99f6e000, 0xd4fa9000, 0x82224800, 0xbe75f400, 0x6e3e6200, 0xc0441100,
+    0x9825f380, 0xbb7371c0, 0x2abe0220, 0x9900c110, 0x7687db98, 0x404255f4, 0xd8224812, 0xdb75f433,
+    0xfabe622e, 0x5104113d, 0xfa85f38f, 0x9a4371f3, 0xfd26023c, 0x2ff4c132, 0xbbfddba6, 0xfba555c0,
+    0x57b6c836, 0x97d8b426, 0x6ed08224, 0x160a8121, 0x415dbba7, 0xde9185f8, 0xb62ce015, 0x0e2d9025,
+    0xba2ec81b, 0x942cb405, 0xff2a8210, 0xb0ad8137,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x48000000, 0x84000000, 0x2a000000, 0x3d000000,
+    0x44800000, 0x20c00000, 0x91200000, 0x6f900000, 0x2e280000, 0x2eb40000, 0x59be0000, 0x5c990000,
+    0xa10b8000, 0xde254000, 0x2a16a000, 0x0c6f9000, 0x3c520800, 0xbc8b1400, 0xc0649e00, 0x7ef19500,
+    0x4a5ef680, 0xbb2f7a40, 0xbe369620, 0x8b7a8130, 0x0cba68a8, 0x6c1eef4c, 0x43c860b2, 0xc605fb51,
+    0x6304fe82, 0xbb806e73, 0xed44080b, 0x97661415, 0x67711e14, 0xbe1dd539, 0x72cbd682, 0x8c81aa6d,
+    0x64c43e09, 0x1b23053b, 0x62917ebe, 0x22ac2e63, 0x8a712801, 0xe298c415, 0x0e0bb618, 0xcba05107,
+    0xd056c087, 0xe28e6b55, 0x3f60f6b1, 0xb3767a51,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x78000000, 0xac000000, 0x52000000, 0x13000000,
+    0x6a800000, 0x47c00000, 0xe2e00000, 0xd8b00000, 0x01b80000, 0x16540000, 0xcb0a0000, 0x24ed0000,
+    0x67d88000, 0xd024c000, 0x0dd0e000, 0x3d4ed000, 0xcf4cb800, 0x4c496400, 0x7eca5600, 0x450fcb00,
+    0x8ded7780, 0xea5950c0, 0xd366ee20, 0x91f6af10, 0xd29f2188, 0x69029bf4, 0xed8199be, 0xf342fffb,
+    0x34214fbc, 0x3bd0f4e0, 0x584e582c, 0xe0ceb41e, 0xf40c6e3a, 0x5c6b6f2d, 0x131d4190, 0x88458bfb,
+    0xeaa541a4, 0x72118bfa, 0x53af4193, 0x95fc8bf2, 0x26f7c1bf, 0xae184bfa, 0x9bc721ba, 0x58e69be0,
+    0x3fb3998f, 0x453bfff9, 0x6893cf8a, 0xfc6934fe,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x78000000, 0x2c000000, 0xa6000000, 0xd9000000,
+    0x47800000, 0x37400000, 0xc4600000, 0x66900000, 0xecf80000, 0xfef40000, 0xf46e0000, 0xb70f0000,
+    0xa31b8000, 0xf922c000, 0x1df5e000, 0x74ec7000, 0x52c9e800, 0x81bfac00, 0xcd922200, 0x387b6700,
+    0x91333a80, 0xa9cfe2c0, 0x1d3bca20, 0xf654cb30, 0xefd91898, 0x440085cc, 0x6206f0be, 0x7b0429fb,
+    0x5c815291, 0x5bc28eca, 0xe7a40837, 0xad37dc3a, 0xe7cdca1e, 0xc03fcb28, 0x73d4989d, 0xf39945cf,
+    0x94e69094, 0x0b5599ff, 0xfa5eda8b, 0x704792e9, 0xd6e42216, 0xe0506721, 0x8edeba95, 0x4f8622fd,
+    0xc343aa27, 0x1e617b00, 0x5190908e, 0x267e99c7,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xe8000000, 0xf4000000, 0x9a000000, 0x51000000,
+    0x82800000, 0x61c00000, 0xaf200000, 0x63700000, 0xd9680000, 0xa8540000, 0x381e0000, 0x1c3d0000,
+    0x4ccb8000, 0x66e44000, 0xd8512000, 0x301b1000, 0xf83fe800, 0x0eca2400, 0x2be1f600, 0x34d1b500,
+    0x9ad94980, 0x8499c840, 0x8efe1e20, 0x3c6b9130, 0x50d0bf88, 0x18dc7d4c, 0xe999579a, 0x527f594d,
+    0x7ead218e, 0xd877ac68, 0x02eec832, 0xaf113419, 0xb4fe1e3f, 0x9d6b913d, 0x1a50bf8a, 0xbd1c7d5b,
+    0x34b95783, 0x940f5963, 0xbf452186, 0x40e3ac4f, 0x1750c837, 0xb19c3438, 0x8e7d9e01, 0x30abd112,
+    0x23779f9d, 0x396e6d6e, 0xb8533fb0, 0xe01c3d53,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x48000000, 0xe4000000, 0x26000000, 0xb5000000,
+    0x8e800000, 0xbd400000, 0x64600000, 0x78d00000, 0xd4e80000, 0x40b40000, 0x563e0000, 0x1b5d0000,
+    0x370e8000, 0x41a14000, 0x0cf4a000, 0xcadef000, 0x03c84800, 0x7d022400, 0xaa815200, 0xbb464f00,
+    0x41613080, 0xbe517a40, 0x8da91a20, 0x02d46b30, 0x9be86288, 0x41333564, 0xcafe2ab2, 0x73fc1149,
+    0x6f79f8a1, 0x293f1e79, 0x02da6819, 0x27c99402, 0x7b03ba0a, 0x8f879b2e, 0x7dc6aa8d, 0x1824516b,
+    0xfc35d886, 0x4079ae6a, 0x58be002a, 0x661d0025, 0x736e8020, 0xa9714027, 0x901ca036, 0x6e6af00d,
+    0x73f6481b, 0xd35f2408, 0x130fd224, 0x47a70f23,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xf8000000, 0xcc000000, 0xbe000000, 0xb3000000,
+    0x66800000, 0xa0400000, 0xce200000, 0xd6700000, 0x34880000, 0x26540000, 0x11fa0000, 0xd4590000,
+    0x67e98000, 0xf384c000, 0xa9c6e000, 0x1ee0b000, 0xcf13b800, 0xc05f6400, 0xa5ef0a00, 0xa6832d00,
+    0x40421d80, 0x9e236340, 0xde74b220, 0x00884910, 0x545717a8, 0x1cf94e7c, 0x01df2f9e, 0xa12fea63,
+    0x9de545a7, 0xb191b740, 0xfc1b802f, 0xddc9c037, 0xf7f5600a, 0x604d7016, 0x1534d805, 0xd42f143e,
+    0xc4605201, 0x6955f904, 0xd17f2fbe, 0x5b1fea68, 0x794d45b0, 0x02b5b75e, 0x47e9802b, 0x4384c01c,
+    0xf1c6e008, 0x22e0b028, 0x8913b801, 0xbf5f6433,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0xe8000000, 0xb4000000, 0xae000000, 0xab000000,
+    0x14800000, 0x2ac00000, 0xd0e00000, 0xeb900000, 0x60b80000, 0x45740000, 0x782a0000, 0xcd490000,
+    0xfd998000, 0x88844000, 0xd0c52000, 0x85e0b000, 0x4c103800, 0x02ff3400, 0xa550de00, 0x465c0b00,
+    0xf9e2f680, 0xe611f0c0, 0x4ff8e620, 0x5ed73f10, 0x3e1828a8, 0x1cc4fbfc, 0x67e3909a, 0x45128ffd,
+    0xbf7deea3, 0xc21774c6, 0x19f98037, 0xf9d4400b, 0x289d200d, 0x6f04b001, 0x1282380c, 0x95c2341c,
+    0x9a635e04, 0x82514b29, 0x10de5696, 0x2a2500c3, 0x6375fe26, 0x772cbb29, 0xcfcace82, 0xd05ac4fc,
+    0xbee23826, 0xa092341c, 0x243b5e25, 0x77b54b2c,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xe8000000, 0x34000000, 0x62000000, 0xb1000000,
+    0xe5800000, 0x5b400000, 0x82a00000, 0xffb00000, 0xc7d80000, 0x43140000, 0x1f6e0000, 0x924d0000,
+    0x23b98000, 0x59444000, 0xa3a52000, 0xa236d000, 0x3098e800, 0x7fb6dc00, 0x07dbe200, 0x6310a100,
+    0x6f6a0380, 0x7a4d9dc0, 0x17bb0a20, 0x3b427d30, 0x12a7e188, 0x47b43cdc, 0x6bde899a, 0xfd12a0fd,
+    0xf86e4bb0, 0xa4c9d1c0, 0x2c7d202b, 0x6522d037, 0x85f6e80a, 0x18fbdc02, 0x4be2622a, 0xe414e127,
+    0xc9ef2386, 0xcd8b4de1, 0x87dbe200, 0xa310a11f, 0x4f6a03b6, 0x0a4d9dff, 0xffbb0a28, 0x0f427d00,
+    0x70a7e1b6, 0xf6b43cf2, 0x8e5e8994, 0xa652a0d4,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x68000000, 0xa4000000, 0xba000000, 0x95000000,
+    0x88800000, 0x0ac00000, 0xbde00000, 0x9a900000, 0x00780000, 0x02740000, 0x1a6e0000, 0xfcc90000,
+    0x87fd8000, 0x25344000, 0xedc9a000, 0x85781000, 0x72f74800, 0x8cacf400, 0xc72a8e00, 0xfe6e1300,
+    0x26cd0e80, 0x42f98ec0, 0x35b3c620, 0x2b0be730, 0x269a00a8, 0xc763ddfc, 0x915768ba, 0x471a79d9,
+    0x53268ea6, 0xfd70cee9, 0x5fe9e630, 0x128eb727, 0xded968b3, 0x454379d6, 0xcea30eb6, 0xbf308efe,
+    0x48ce462d, 0xc5ffa732, 0x2c33a09e, 0xe34bcdf8, 0x6cb82086, 0xcc928dc4, 0xbb7a00bf, 0x6df3ddfe,
+    0x592f68b2, 0x116e79e9, 0x9b488e9c, 0x30b9cefd,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x98000000, 0x4c000000, 0xd2000000, 0x8b000000,
+    0x1c800000, 0x9b400000, 0x8f200000, 0x7b700000, 0xf7780000, 0xcf540000, 0x988a0000, 0x976b0000,
+    0x96f88000, 0xb595c000, 0x2d69e000, 0xb9ffd000, 0x9f127800, 0xeb2dd400, 0xa759f200, 0x76a21500,
+    0x4db01d80, 0x909a8cc0, 0x6ac18a20, 0x8de4c110, 0x52916fb8, 0x93ed59f4, 0x01b87786, 0x08b19dc3,
+    0x0d1a9dac, 0xca004cc6, 0x8702ea39, 0x2e81d111, 0xc0407789, 0x0ba59ddb, 0xac309d88, 0xaa5b4cf4,
+    0x3f226a2e, 0x7370111b, 0xc37b97a2, 0x81554dc1, 0x59886581, 0x4cec58c7, 0xc338f832, 0x2af71421,
+    0xc5ba921b, 0xaeb70524, 0x28190596, 0xc78648c1,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0xc8000000, 0x0c000000, 0x7e000000, 0x0f000000,
+    0xd4800000, 0xfec00000, 0xe2e00000, 0x91d00000, 0x03380000, 0x54340000, 0xda6a0000, 0x7fcf0000,
+    0xbfb88000, 0x56f34000, 0x3e8fe000, 0xf51c3000, 0xd206d800, 0x81027400, 0xa3819e00, 0x3e42fb00,
+    0xa6238480, 0x0e35ccc0, 0x376d4620, 0x724f8f10, 0x46fa9a98, 0x285477f4, 0xb0792292, 0x2f9273d3,
+    0xe51b04a7, 0x3a068ce7, 0x3d02a63f, 0xc583bf18, 0xf544428d, 0x00a203d0, 0x81f2bcb8, 0x0e0f88fa,
+    0xc9d8001c, 0x59e40023, 0x0f520008, 0xf8fb002c, 0x0752802c, 0xd4fc4013, 0xc9576007, 0xc3ff7003,
+    0xd9d13833, 0x4f3a4423, 0x4a354605, 0x056b8f3a,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0x08000000, 0xf4000000, 0xa2000000, 0xc9000000,
+    0x0a800000, 0x36400000, 0x1b600000, 0x51700000, 0xab480000, 0x37140000, 0xf2be0000, 0xb31f0000,
+    0x6c4f8000, 0xda914000, 0x397f2000, 0xbbbfd000, 0x799e2800, 0x3a0ba400, 0x71f1ca00, 0x600b1700,
+    0xe4f04580, 0xec8d1740, 0x01b1e220, 0xc86fb330, 0x3a060f98, 0x2503406c, 0x848087a2, 0x1142744d,
+    0x86e64590, 0xf2b6176e, 0xe7e86238, 0x88c5f32c, 0x2f20afb4, 0x5316d077, 0x48b80fa2, 0x561c407f,
+    0x88cf07a8, 0x7bd3345b, 0xb799658f, 0xbd09c743, 0x3c764a0c, 0x7bce5708, 0x545165a3, 0x055dc777,
+    0xb7284a3b, 0xebe1570a, 0x2236e5ac, 0x84a88767,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0xf8000000, 0x9c000000, 0xce000000, 0xa9000000,
+    0x05800000, 0x40c00000, 0x24e00000, 0x06100000, 0x88280000, 0xe7f40000, 0xcdba0000, 0xd21d0000,
+    0xb12e8000, 0x5a70c000, 0x317f6000, 0x28fc3000, 0xe638e800, 0x4bda7c00, 0x584a5e00, 0x68a27300,
+    0x37b7f280, 0x321b9c40, 0xc128b620, 0x92750f10, 0x457b2c88, 0x82fd2f44, 0x1d3a249e, 0x295fa377,
+    0xb40df29b, 0x09069c7e, 0x55863617, 0x98c5cf23, 0xa8e44c8c, 0x30111f48, 0xbd2acc8e, 0x2c71df74,
+    0x247daca0, 0xf379ef5b, 0xf7ff4490, 0xf6be934d, 0xd09b9ab1, 0x6d6c205f, 0xd3d30820, 0x374b8c00,
+    0xd0235608, 0xe8f4ff2a, 0x453a24b3, 0xe55fa367,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x78000000, 0xf4000000, 0xb6000000, 0x59000000,
+    0xe8800000, 0x19c00000, 0x2fe00000, 0x5e100000, 0x61180000, 0xd4f40000, 0x3d8e0000, 0x2f2b0000,
+    0x251a8000, 0x2af6c000, 0x188e2000, 0xedadf000, 0x5fdc2800, 0xf6939400, 0x58d99600, 0x0b110300,
+    0x0b9d5280, 0xc2372dc0, 0xb3ebbe20, 0xf8799730, 0x862644b8, 0x39f4eec4, 0x430eccbe, 0x9fec4acd,
+    0x8a7d52b5, 0xe1272de2, 0x1473be3c, 0x884d970f, 0xb2c84480, 0xfc0feef8, 0x766c4cb1, 0x7f3e8ae8,
+    0x0905728b, 0xb085ddf8, 0x1dc31626, 0xf1e7c327, 0x8b137291, 0xcb9addfd, 0x22379620, 0xa3ea0314,
+    0x807fd2b2, 0x7225edfb, 0x8ff39e0d, 0x1a0b6715,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xf8000000, 0x14000000, 0x06000000, 0xf1000000,
+    0xc7800000, 0x8dc00000, 0xdc200000, 0xd7300000, 0x02680000, 0x1c140000, 0x93de0000, 0xcc3f0000,
+    0x7a2f8000, 0xaaf6c000, 0x86482000, 0x0f257000, 0xdfb36800, 0xcd2a6c00, 0x3c72f600, 0x1c0ea500,
+    0x16c4c380, 0x38a71040, 0x0a779e20, 0xd50fc930, 0xa547b598, 0x0360756c, 0xdf54fd9e, 0xd1bb6975,
+    0x2ceb63b9, 0x3250a060, 0x5c3ad637, 0x922bd53a, 0x96f7ab90, 0xdc4d7c60, 0x1425682e, 0xfb316c2d,
+    0x706b763b, 0x5b136535, 0x5d5d63a6, 0x927ba07e, 0x2dcb5613, 0x50e2150e, 0x94900ba4, 0x9c9ecc62,
+    0x8c5e203d, 0x45fe701d, 0x880ae814, 0xd0c7ac03,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0x38000000, 0x8c000000, 0x6e000000, 0xb5000000,
+    0xc6800000, 0x9fc00000, 0x1a600000, 0x83300000, 0x7bb80000, 0xfa540000, 0xc70e0000, 0x56ab0000,
+    0x5df88000, 0xf674c000, 0xe01fe000, 0x5e033000, 0x1d048800, 0x9287e400, 0x2dc4c600, 0xf961ff00,
+    0x7cb71b80, 0x4cfab4c0, 0xcaf64e20, 0x98d91b30, 0x31e55db8, 0xc6748bd4, 0x481e35ae, 0x0a005fd3,
+    0xaf067b83, 0x718244c9, 0xd243a627, 0xce260f20, 0x4c1473ab, 0x132a60fa, 0xadb8001a, 0x03540027,
+    0x8f8e0025, 0x2c6b002b, 0xb9188030, 0x6684c027, 0xefc7e00a, 0x92673031, 0x6732880e, 0xa1b8e415,
+    0xad524613, 0xda8e3f21, 0xbae87ba9, 0x1ed944e3,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xf8000000, 0x4c000000, 0x8e000000, 0xa7000000,
+    0x30800000, 0xfc400000, 0xfb600000, 0xca700000, 0xb2680000, 0x00140000, 0xb39a0000, 0x58bd0000,
+    0xd7ad8000, 0xd0b0c000, 0x6e4ae000, 0x57853000, 0xacc11800, 0x57215400, 0xb9174a00, 0xcc18d700,
+    0x707b6d80, 0x9a8e5e40, 0x7ca45220, 0x43d08310, 0x003ba788, 0x8feb495c, 0x41d05f9e, 0x493f2d43,
+    0x486e0d8b, 0x0512ae65, 0xfa182a1a, 0xbb792720, 0x540a95ab, 0xdfe73a54, 0xc437801d, 0xb80dc015,
+    0x61e76035, 0xbb35f02e, 0xb48bf815, 0xeba4642a, 0xab565206, 0xc0798318, 0x028c2792, 0x60a68948,
+    0x45d73fb2, 0x133add66, 0x7d6df59d, 0x9492ca5e,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x88000000, 0x3c000000, 0xa2000000, 0x87000000,
+    0x76800000, 0x8d400000, 0xb7a00000, 0x62500000, 0x86780000, 0x4cf40000, 0xf7aa0000, 0x964d0000,
+    0x873d8000, 0xb1534000, 0x7ef96000, 0x3cb7d000, 0x75881800, 0x19de0400, 0x22a12200, 0x7bd06100,
+    0x11bb9480, 0x2c1080c0, 0x115b3a20, 0x9ae76510, 0xe5f53688, 0x4c2aa1dc, 0x868e4e82, 0x915e75df,
+    0xdae2f480, 0xc5f750ed, 0x3c2b2217, 0x0e8d6120, 0xad5e14af, 0x78e7c0ea, 0x42f05a16, 0x4aa9b50e,
+    0x83caaead, 0x1afae5e3, 0x1ab38cb2, 0xc48e84db, 0x065a182e, 0x7467040c, 0x8cb6a217, 0x9d8e212c,
+    0x75df74ac, 0x78a410ee, 0x48d24213, 0xf93ab12f,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0xf8000000, 0xf4000000, 0x5a000000, 0x0b000000,
+    0x4c800000, 0xc4c00000, 0x08e00000, 0x2e900000, 0x11880000, 0x23740000, 0x929a0000, 0xb9bf0000,
+    0xad4a8000, 0x9f92c000, 0x860a2000, 0x33369000, 0x3abeb800, 0x3dcfd400, 0x6d518e00, 0xbbee6b00,
+    0xf8214e80, 0x5c771940, 0xc41d3620, 0x167abf10, 0x79284098, 0x8dc0b264, 0x6b66d89e, 0x64d6f66d,
+    0xb2ab6eae, 0xeb818976, 0x96438e15, 0x95256b38, 0xfdf1ceb2, 0xe15ad950, 0x5bdd961f, 0x8a1eef16,
+    0x977cd8bb, 0xa6a9f672, 0x6181eea0, 0x05434978, 0xbda1ae2d, 0x9b37fb17, 0x16bd76b0, 0x63ce0d66,
+    0x5454983f, 0xf069440f, 0xde673623, 0xc155bf2c,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xd8000000, 0xec000000, 0x32000000, 0xf9000000,
+    0x85800000, 0xb6c00000, 0x46a00000, 0xa2f00000, 0xa0980000, 0xb3540000, 0x34ee0000, 0x658f0000,
+    0x285b8000, 0xcef7c000, 0x529be000, 0x2a51d000, 0x8169e800, 0x0b4b1c00, 0x82ff0e00, 0x5e078100,
+    0x0b00a580, 0x1c837bc0, 0x0340e620, 0x28679d30, 0x08522b98, 0x30683acc, 0xeace2396, 0x023df6cb,
+    0xe7a345b4, 0x0b76abc2, 0x445f0e2f, 0x3cf7812a, 0xcb98a5b3, 0x9fd77be1, 0xefaee613, 0xa1e89d34,
+    0x1209aba9, 0x079ffaee, 0x3dd5c384, 0x9eac26d1, 0x206aad94, 0xa2cdb7d5, 0x66380020, 0xd1a4002e,
+    0xf4760000, 0xe6db0016, 0xc4b5800f, 0x4778c038,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x88000000, 0xec000000, 0xf2000000, 0x3b000000,
+    0x6b800000, 0x9c400000, 0x01e00000, 0xa1500000, 0x88d80000, 0xfb340000, 0xadca0000, 0x760f0000,
+    0x39a88000, 0x12194000, 0x0814e000, 0x83bc5000, 0xcaa37800, 0x79336c00, 0xeeca8a00, 0xc988fb00,
+    0xe3eebc80, 0xbef84fc0, 0xe7c17220, 0x35a2d710, 0xfcb0d698, 0x838ce4f4, 0x2cecb682, 0x2379f4eb,
+    0x8e832e84, 0x1ec2c8ea, 0x7d20dc80, 0x0f765ff8, 0xd02c6a26, 0xcd5fab39, 0xb67744be, 0xf8a963d8,
+    0xee9d980f, 0x6dd03c37, 0xf5197212, 0x8996d70f, 0x20fad6b3, 0x6ec3e4f7, 0x052436a9, 0xdb70b4cf,
+    0x962fce9c, 0x605a98f6, 0xf8f1a4b7, 0xc66e33ff,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x68000000, 0xa4000000, 0xfa000000, 0x9b000000,
+    0x99800000, 0x06c00000, 0x10600000, 0x8ed00000, 0x7c280000, 0x7f340000, 0x783a0000, 0xad7b0000,
+    0x78da8000, 0xc3ea4000, 0x91d0a000, 0x2fad1000, 0xfaf53800, 0xaddbfc00, 0x136e5e00, 0xd190e700,
+    0xca8dc480, 0x9181e040, 0x72c1e620, 0x62615b10, 0x21d33ab8, 0x77ac1764, 0x46f11aba, 0x0bdf4779,
+    0xbe6e82a6, 0x8d12ab52, 0x34cf64a4, 0x85a3f05c, 0x73345e38, 0x863be718, 0xbc7f44a5, 0x825fa075,
+    0x90ab4638, 0xd9774b16, 0xd01c82a5, 0x0a8dab4f, 0x3187e4bd, 0x02c6b064, 0x9a647e03, 0xedd7b71e,
+    0x29a85cbb, 0x27f70c77, 0x095a8028, 0x212a4023,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x28000000, 0x5c000000, 0x6e000000, 0xe7000000,
+    0xd8800000, 0xd5c00000, 0x68200000, 0x2f700000, 0xe3080000, 0xf3d40000, 0x9fbe0000, 0xe3fd0000,
+    0x459f8000, 0x858a4000, 0xb9146000, 0xcb18b000, 0xb34c1800, 0x33b19400, 0x54ae4600, 0x41e3ad00,
+    0x59579980, 0x2379ab40, 0x245dde20, 0xffa87930, 0xaf65bf88, 0x3f96b66c, 0x11d85faa, 0x7fed4667,
+    0x1701a7b3, 0x10832265, 0x19c01994, 0x9e27eb7e, 0xbc77be23, 0x098dc939, 0xaf162797, 0xc81d624b,
+    0xc1ca79ba, 0x1b725b58, 0x310c2636, 0xead21d06, 0x383a019f, 0x9abf7f73, 0xca787809, 0x4bd9243c,
+    0x4aea5e10, 0xd6863927, 0x62c7df84, 0x08a70646,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x48000000, 0x8c000000, 0xb6000000, 0x61000000,
+    0x22800000, 0x0bc00000, 0xd7e00000, 0x0f900000, 0x57580000, 0x26f40000, 0x7d0e0000, 0x134b0000,
+    0xf4ed8000, 0x21df4000, 0x6f36e000, 0x59e97000, 0x9559a800, 0x15f70400, 0x1489c600, 0x5509f300,
+    0xaf484e80, 0xeaed67c0, 0x3cddee20, 0xb3b1b730, 0xbf2f68b8, 0xd639e4fc, 0x70a208b2, 0xb6b0d4d3,
+    0x77aec0b5, 0x52fae0d4, 0xd0c5ce82, 0xe26227ed, 0xa7d30e12, 0x59fcc734, 0xbd40c09a, 0x7e21e0cc,
+    0xdb704ebd, 0x994967ce, 0x4bebee20, 0xfe5eb714, 0x4874e886, 0x20c9a4c5, 0x55af68b8, 0x91f9e4c8,
+    0xf1420894, 0x2820d4d0, 0x4a76c080, 0xf3cee0cb,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0x68000000, 0xd4000000, 0x06000000, 0xe7000000,
+    0x41800000, 0xf8c00000, 0xdf600000, 0x0e500000, 0xc6280000, 0x8cb40000, 0xf2ba0000, 0x4cff0000,
+    0x5adf8000, 0x7b2e4000, 0x88322000, 0xa2fbf000, 0x29dc5800, 0x5ca85c00, 0xa7f02e00, 0x541ddf00,
+    0x0b4f7080, 0x43e05840, 0xc213f620, 0x210bc310, 0x33c57e98, 0x34e2774c, 0xfb92deba, 0x35c8c765,
+    0x66a326b9, 0x67b52b65, 0x493d70b2, 0xe13b5857, 0x153e7614, 0x433e8313, 0xfc3adea2, 0x01bcc75d,
+    0x2b7926a0, 0x151a2b72, 0xbdcaf0b9, 0xc2a11865, 0x69b6562a, 0x4a3a730c, 0xceb906a5, 0xdefadb7a,
+    0xdbdb28bb, 0xedac0471, 0x5971d81e, 0x515d1c3a,
+    0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xd8000000, 0x24000000, 0xda000000, 0x5b000000,
+    0x05800000, 0xd8c00000, 0xae200000, 0xdf300000, 0x10380000, 0x78940000, 0x11ca0000, 0x338f0000,
+    0xdaef8000, 0x483bc000, 0x1c95a000, 0xabc89000, 0x588bb800, 0x076e9c00, 0xb4f82e00, 0x68b4f500,
+    0x2ffa5b80, 0x4d30a2c0, 0xa73c1620, 0x0b11a910, 0x840fd598, 0xe528c7cc, 0x7d9ff596, 0xaa6497d9,
+    0x93566d8e, 0x066d5bca, 0xaa7a5b8f, 0xd5f0a2e3, 0x691c1613, 0xe421a934, 0x4c37d59d, 0xb9bcc7fb,
+    0xb655f58e, 0xc2eb97c2, 0x4c39ed96, 0x96969bc7, 0x18cffba3, 0xa10832ec, 0x21afae33, 0x9bdb3535,
+    0xe905fb85, 0xe28732d5, 0x43402e22, 0xc7e0f528,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x68000000, 0xec000000, 0x8e000000, 0x69000000,
+    0xba800000, 0x2ac00000, 0xece00000, 0xb0f00000, 0x19780000, 0x08940000, 0xfa4e0000, 0xcf8b0000,
+    0x54ec8000, 0x265e4000, 0xa604e000, 0xa500b000, 0x0486e800, 0xfbc30400, 0xd2624e00, 0xf832eb00,
+    0x129e3d80, 0x6be466c0, 0x73702620, 0x013baf30, 0xf2b693b8, 0x405d3df4, 0xe30473ba, 0x71828dcb,
+    0x97401bbb, 0xc5a4c9de, 0xa45235ac, 0x83acd2f5, 0xc3f8001a, 0x32540009, 0x9eae0029, 0x437b001d,
+    0xab948012, 0xabca4034, 0x68cae025, 0x294bb037, 0x060a6804, 0x47ad443e, 0x81feae17, 0xe5565b2a,
+    0xf52ed5b0, 0x573862ef, 0x0fb0e827, 0x10dc0407,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x18000000, 0x64000000, 0xb6000000, 0x09000000,
+    0x67800000, 0x91c00000, 0x6ee00000, 0xfdb00000, 0xee480000, 0xaad40000, 0x573a0000, 0x4ffb0000,
+    0x069f8000, 0x8ae9c000, 0x3a43a000, 0x7521f000, 0xa8552800, 0xfb7cec00, 0xa3de9600, 0x01489300,
+    0xac54a780, 0x3d7f7f40, 0x52dc3e20, 0x32c9bf10, 0x939391b8, 0x3e9d1c4c, 0x7eea31a6, 0x4447ec49,
+    0x302099b5, 0x05d2c05e, 0xb1bdaf87, 0x15bba361, 0x03bc2028, 0xdab83038, 0x953e8831, 0x78f91c19,
+    0xc419be3c, 0x86ab7f13, 0x0667b195, 0x5af12c51, 0xf2eeb982, 0x2e45f06f, 0x3b26a786, 0xf5507f46,
+    0x32f9be17, 0x5f1b7f03, 0xbe2fb188, 0xc9252c4a,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x18000000, 0xc4000000, 0x4e000000, 0xd7000000,
+    0xdc800000, 0x3d400000, 0x90200000, 0x00100000, 0x97880000, 0xe6b40000, 0x43da0000, 0xc29b0000,
+    0x48b88000, 0x29a9c000, 0xa1a1a000, 0xf0d13000, 0x0d69d800, 0xcd40f400, 0xf821c200, 0xec178d00,
+    0xd58d4680, 0xa3b54b40, 0xc2589a20, 0xba5ab910, 0xee5f24b8, 0x585cf674, 0x9b5e84a6, 0x11ddc661,
+    0x3f9f5c8b, 0x3d393251, 0xd76c9ea9, 0x0c41bf5a, 0xd7a35829, 0x93d63420, 0xb7eae23f, 0x2b007d06,
+    0x2687be96, 0x8c464f7b, 0x97a1a017, 0x73d13019, 0x67e9d836, 0x3300f40e, 0xe281c206, 0xc2478d01,
+    0x40a546a2, 0xaf514b71, 0x5aaa9a0b, 0xa325b902,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x68000000, 0xc4000000, 0x0e000000, 0x7b000000,
+    0x79800000, 0x83c00000, 0xd1200000, 0x46900000, 0xf4880000, 0xac340000, 0x90da0000, 0x8d1f0000,
+    0x6f3c8000, 0xa42e4000, 0xe561a000, 0x4f70b000, 0x2abf3800, 0xa5e85c00, 0xb544d600, 0x3d61cd00,
+    0xf377ce80, 0xe8bce740, 0x44ef6e20, 0xd1c3d110, 0x7820b898, 0xd6169a54, 0x87c918ba, 0x5e522a61,
+    0x4c2c20bb, 0x6165765a, 0x217476bc, 0x01bafb55, 0xb46a1815, 0xf282ac3a, 0xe2404e22, 0xcee62130,
+    0x65b0a097, 0x6b1b367d, 0x903dd6af, 0xb3ae4b7d, 0x4da72032, 0x8fd1f02e, 0x2bea183e, 0x8e42ac3c,
+    0x24e04e0f, 0x20b62112, 0x5198a0ad, 0xc67f3674,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0xc8000000, 0x7c000000, 0xf2000000, 0x9f000000,
+    0x2c800000, 0xd6400000, 0x93a00000, 0xfdb00000, 0xe6780000, 0x4d940000, 0xe90e0000, 0x32090000,
+    0x288a8000, 0x97c94000, 0xe1ec6000, 0x3f9e5000, 0xa186d800, 0x15c0d400, 0x1165fa00, 0x1c53b100,
+    0xce6ef380, 0x4758f0c0, 0x0367a220, 0xf3532530, 0x9aede9a8, 0x351c51c4, 0xd6c3e992, 0x1fe551ef,
+    0x3d1169b4, 0x1d4811d3, 0x56ab09b1, 0x95bb41de, 0x9df151a2, 0xdcdfd5f3, 0xf5a44bb4, 0x3cb674ea,
+    0xcffc8013, 0x4454400a, 0xba68e03a, 0x695e1000, 0x7e60381a, 0x88d7c422, 0x1c2f4232, 0x82fd3512,
+    0x27d5d19a, 0x28af95f8, 0x40baabae, 0x2a7564ee,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0xb8000000, 0xec000000, 0xa6000000, 0x8f000000,
+    0xbb800000, 0x3f400000, 0x9d200000, 0x41900000, 0x8c880000, 0xb8340000, 0x21da0000, 0x52d90000,
+    0x875f8000, 0xc31ec000, 0x9fbde000, 0x706a7000, 0xb9445800, 0x6224cc00, 0xe2169200, 0x6fcb5d00,
+    0x3b11c280, 0x03488340, 0xc3d74a20, 0x8f285110, 0xc46530a8, 0xb3b76e4c, 0xe69f308e, 0x83fe6e6b,
+    0x50c8b0a1, 0x7894ae7f, 0x700f5088, 0xc8f7de68, 0xf9bc88a8, 0x1f69d254, 0x92c5fa82, 0xd565ff65,
+    0x2b35e00e, 0x645e700f, 0x9e9e5824, 0x8ffdcc15, 0x66c91202, 0x7f959d21, 0x9f8c2284, 0xbdb2f36e,
+    0x4d9b123c, 0x6a789d02, 0x9a89a282, 0xcf353352,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x98000000, 0x6c000000, 0xd2000000, 0x83000000,
+    0x8d800000, 0xa6400000, 0x9ce00000, 0x24300000, 0x68080000, 0xfa540000, 0x05fa0000, 0x43fb0000,
+    0x2efe8000, 0x8a7dc000, 0x5ebae000, 0xa39bd000, 0x520a3800, 0xdd54dc00, 0x4e7d9a00, 0xc8be2300,
+    0xf69bd780, 0x6a8ad140, 0x53932220, 0xc45c3f10, 0x0aaa2db8, 0x8e86e244, 0x6bc22d86, 0xdaa2e24b,
+    0xa8d02dac, 0xd43de274, 0xfe5cad9d, 0x2daf2266, 0xc502cdad, 0xe0823266, 0x02c495b3, 0x4824fe78,
+    0xd913d78f, 0x999ed15f, 0x7509221f, 0x96d73f13, 0xc53cada2, 0x10df2259, 0x166acdb2, 0x67a63270,
+    0x345695bc, 0x9cfbfe42, 0x597f5794, 0xab3c1166,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x38000000, 0x04000000, 0x62000000, 0x09000000,
+    0xcc800000, 0xbcc00000, 0x49600000, 0x0e700000, 0x11980000, 0x76940000, 0x95aa0000, 0x4fab0000,
+    0x02af8000, 0x4c28c000, 0xc9e8a000, 0x740a5000, 0xc2b92800, 0xf8441400, 0x89a3de00, 0xc1101700,
+    0x44e8c980, 0x9a8ffdc0, 0x977f7620, 0x4da7c310, 0x031437b8, 0x9de97acc, 0x5e0c37ae, 0x17bd7ad1,
+    0x62c63780, 0x66667ade, 0x49f1b7a5, 0x58dabaf2, 0x77b3179c, 0x6a7beadc, 0x7b25bfb9, 0x62d73eed,
+    0x720ec18a, 0xd9bd79e0, 0xe5c7202b, 0xcde2903d, 0x6eb1881c, 0x0efe4430, 0x2be2f626, 0x45b0030c,
+    0x2b791788, 0x6ba0eade, 0x88123fb3, 0x686bfee4,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x38000000, 0xbc000000, 0x16000000, 0x09000000,
+    0x27800000, 0x49400000, 0x2fe00000, 0x52900000, 0x91380000, 0xf7f40000, 0x776a0000, 0x87690000,
+    0x5f6f8000, 0x736ac000, 0x6d6f6000, 0xe06bf000, 0x6deee800, 0x3baf2c00, 0x3acc1200, 0x069e1b00,
+    0xf107ba80, 0x7b84d8c0, 0xcf477a20, 0x2ee2f710, 0xf11348a8, 0x727ff3cc, 0xc71348ae, 0x0b7ff3ff,
+    0x7893488d, 0xce3ff3de, 0x7973488f, 0x29aff3e1, 0xd9cb4888, 0x9e1bf3e5, 0xa6c148ae, 0x02a2f3de,
+    0x4776c89c, 0xd4ac33d6, 0xcc4ba8b2, 0x445ac3d6, 0x89a0c0a3, 0x8bf62fd3, 0x816c32b4, 0x1e6904f9,
+    0x70ea003a, 0xbe29000e, 0xe88f8033, 0xadfac00f,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0xe8000000, 0x3c000000, 0x16000000, 0x5f000000,
+    0x78800000, 0x1fc00000, 0xd9e00000, 0x90d00000, 0x3e580000, 0x04b40000, 0x60ce0000, 0x4b4d0000,
+    0x120f8000, 0x972c4000, 0x009f6000, 0x06d17000, 0xa15ed800, 0x5c355400, 0xcf0f9600, 0x7aace300,
+    0xbedb9480, 0xbf720fc0, 0x5b28ce20, 0x1e9cf730, 0xf5d2e288, 0x97dadcec, 0xc8f3629a, 0x446b9cff,
+    0xbcbb82ad, 0x7ee2accb, 0x4c543a8c, 0xc39b88d4, 0x1852f4a1, 0x299a7fe0, 0x71579606, 0xbe18e302,
+    0xfe159487, 0x443f0fc5, 0xa1274e3c, 0xb5b0b72f, 0xe34d82b2, 0xce0bacca, 0x112dba89, 0x079ec8fd,
+    0xaa5414bb, 0x949e4fc6, 0xccd7ae18, 0x785d871b,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x68000000, 0xcc000000, 0x5a000000, 0x4b000000,
+    0xcf800000, 0xdbc00000, 0xf5e00000, 0x1c900000, 0xeee80000, 0x13f40000, 0xfb3e0000, 0xedbb0000,
+    0x6efb8000, 0x6d5e4000, 0x80eae000, 0xb6f51000, 0x51bff800, 0x7cfb0c00, 0x3a58f600, 0x4d697b00,
+    0x82322e80, 0xfddb3240, 0xaaaa8e20, 0xa5d33730, 0x564db8b8, 0xb9c6194c, 0xeae038ba, 0xcd175943,
+    0xd82f588e, 0x5097096e, 0x74e9c0b1, 0xb8f35579, 0x04ba2ea9, 0x5e7f325a, 0x571c8e1c, 0x2bcc372f,
+    0x25003884, 0x6a875967, 0x71475882, 0x64a3097f, 0x4837c0a5, 0xced8554d, 0x1129ae86, 0xb0157245,
+    0x16a86e19, 0xb7d22700, 0x014c408d, 0x74461572,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x38000000, 0xa4000000, 0x62000000, 0x63000000,
+    0x6a800000, 0x3c400000, 0x96e00000, 0xc4f00000, 0xba880000, 0x15940000, 0xa5be0000, 0x123b0000,
+    0xc1fc8000, 0x30dcc000, 0xbf0ea000, 0x9d515000, 0x9a187800, 0xc3a92400, 0x16418a00, 0x99e20b00,
+    0x60703980, 0xcbcc9d40, 0x74f37220, 0x9288ef30, 0x699593b8, 0xb3bc0674, 0x2b3f13ae, 0x6c7fc659,
+    0x041b3380, 0x76ad565c, 0xa5c76b8c, 0xd826e262, 0xfa52b9ab, 0x629b5d44, 0x94e9523a, 0xa6217f3f,
+    0x9f554bb8, 0xe91b727f, 0x712c6197, 0x5e072976, 0x5506a017, 0x63855004, 0x79c67818, 0x3e22241e,
+    0xcb550a2e, 0x831acb0c, 0x5e2899a4, 0xca82cd7f,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x48000000, 0x7c000000, 0x06000000, 0x39000000,
+    0x85800000, 0x07c00000, 0xcfe00000, 0xd8300000, 0x1a680000, 0x70140000, 0x5dbe0000, 0x6a4d0000,
+    0xb3c78000, 0x75e54000, 0xc7376000, 0x16eff000, 0xba546800, 0xe999fc00, 0x7b9cc600, 0x489c1b00,
+    0x9a1b9380, 0xed5bf240, 0xeeffce20, 0xbbea1730, 0x35d33d88, 0xb95e156c, 0xc4f89bb2, 0x5cedfe6f,
+    0x0d576029, 0x091ff012, 0x4fdc683b, 0xf1bdfc32, 0x744ac620, 0x2ec51b27, 0x726213a6, 0xcbf3b253,
+    0x1f8f2e25, 0xdf20a71d, 0x87503591, 0x9e18195b, 0x1f583592, 0x8dfc196c, 0xb16e35bc, 0xc695196f,
+    0x88ffb598, 0xd2e95948, 0x685155b1, 0x3a9ee97f,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xe8000000, 0x24000000, 0x6e000000, 0x2f000000,
+    0x69800000, 0x97c00000, 0xc6600000, 0x68700000, 0xfdd80000, 0x91d40000, 0x97ce0000, 0x3a7d0000,
+    0x4cc18000, 0x99e04000, 0x3cb3a000, 0x683fb000, 0xc7631800, 0xcef71c00, 0x539fb600, 0x58738700,
+    0xf5ddf180, 0xe5d143c0, 0x61cf0e20, 0xd97b2b30, 0x6f415f88, 0x4f25d8dc, 0xbc55499a, 0xfe0deff9,
+    0x6b1da033, 0xf132b027, 0x51fa9828, 0x0e035c30, 0xbf021610, 0xd1813718, 0x2bc769be, 0x64621fca,
+    0x0d75182e, 0xd55e1c01, 0x40903615, 0xafeec704, 0x03afd1af, 0x1f0eb3f9, 0x9d9fb63c, 0xc7738720,
+    0x545df19a, 0x261143d3, 0x21af0e28, 0xba0b2b33,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0xe8000000, 0xd4000000, 0x4a000000, 0xef000000,
+    0x66800000, 0xb0400000, 0x55e00000, 0xc8300000, 0xb8680000, 0xa5940000, 0x41ba0000, 0x97cb0000,
+    0x97418000, 0xb7624000, 0xca762000, 0xce8a9000, 0x7d229800, 0x3895c400, 0xa43faa00, 0x270d6300,
+    0x1be1b780, 0xd5314e40, 0x1deb1220, 0xf5523710, 0x3d1c85b8, 0x1d99e97c, 0x94dd0f9a, 0xc6ba1a65,
+    0x654c200a, 0x8d019017, 0xfd83181b, 0x8cc78425, 0x12a18a3d, 0xc753f33c, 0x5e192fa5, 0xed1f8a7e,
+    0xa59d383c, 0xb8d9142b, 0x40b91226, 0xfc4d370a, 0x9a8705b6, 0x8e40a94a, 0xe0e2af98, 0x59b6ca6d,
+    0x52aa983f, 0xde31c417, 0x796daa3c, 0x6e126333,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0xf8000000, 0x34000000, 0x86000000, 0xef000000,
+    0xbc800000, 0x24c00000, 0x49e00000, 0xe8d00000, 0x79480000, 0x61f40000, 0xe67a0000, 0x0b6d0000,
+    0x94448000, 0xbf24c000, 0xb8322000, 0x6a197000, 0x4cfd1800, 0x58ae7400, 0x25256e00, 0x5d315300,
+    0xab9fc480, 0xedb84340, 0x9e0a5620, 0x28565710, 0x278fb288, 0xfd136454, 0x472afc9e, 0x4f62475d,
+    0x7916a009, 0x092db03f, 0x1c673819, 0xdf930410, 0x88ea760d, 0xb406270c, 0xc6042a90, 0xcf00d06a,
+    0xec8332a4, 0xdcc3a461, 0x7de2dc81, 0x6ed63749, 0x964f381f, 0xdd770409, 0xc2b87618, 0x428f2719,
+    0x7c92aab2, 0xc66d105d, 0xd9c792ab, 0x8c67146f,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x48000000, 0x7c000000, 0x8e000000, 0xdf000000,
+    0xec800000, 0x1e400000, 0x96600000, 0x45700000, 0x37f80000, 0x33540000, 0xb3ee0000, 0xf15d0000,
+    0xd7058000, 0x70854000, 0x40456000, 0xf161b000, 0x9df75800, 0xdbb8f400, 0xf4367a00, 0xc51b2f00,
+    0x3460bd80, 0x6c747ec0, 0x107c4220, 0xc3966b30, 0x22cf9f88, 0x6dcaa5fc, 0xd94f05b2, 0x230c7aef,
+    0xff6e000b, 0xdf1d003b, 0x29658021, 0xb9f54024, 0xb1bd6014, 0x6135b039, 0x4c995815, 0xeba5f43c,
+    0x59d3fa32, 0xeeae6f2a, 0xd5bddd9d, 0xeb31cec1, 0x099d1a11, 0xda279f3c, 0xb21265b6, 0x2909caea,
+    0x7a6f582a, 0xce9cf43e, 0x32a07a11, 0x36522f1a,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xb8000000, 0x84000000, 0xea000000, 0x93000000,
+    0xf6800000, 0x98c00000, 0xa1200000, 0xcab00000, 0x70f80000, 0x09540000, 0xa06a0000, 0xa99b0000,
+    0xa7078000, 0xe480c000, 0x6fc2a000, 0x8da31000, 0x79757800, 0xa35cc400, 0xb1230e00, 0xe2b42500,
+    0x9cfa2780, 0xef57c8c0, 0xe56cd620, 0xa21ff110, 0xb04651b8, 0xb8e429cc, 0x6a127f8e, 0x0e0cdcf1,
+    0x6b4a0022, 0xb02b0038, 0xc17f802b, 0x4514c00b, 0xd688a03c, 0x6a881037, 0x448af81b, 0xdd880400,
+    0x880bae3d, 0x5e4c353e, 0xe3a8df9d, 0x623bccc9, 0x4b75780f, 0xc45cc41b, 0xf5a30e27, 0x5d742537,
+    0x995a27b0, 0xaa27c8c3, 0x2834d638, 0x6a3bf104,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x88000000, 0x04000000, 0xa2000000, 0xdd000000,
+    0xfe800000, 0xfdc00000, 0x0c600000, 0xccd00000, 0x61780000, 0x87740000, 0xd0ce0000, 0x425b0000,
+    0xec048000, 0xb6054000, 0x1701a000, 0xf787d000, 0x29428800, 0x28a2c400, 0x9c341600, 0x8d697f00,
+    0x14ea7c80, 0x66ae86c0, 0xff8f3e20, 0xe5f86b30, 0xebb2e2b8, 0x642e3df4, 0xdc4dd482, 0x2a9ed2f1,
+    0xfae00030, 0xf5100033, 0x2f180025, 0x46a4000a, 0xc7360009, 0x3cef0005, 0x92aa800f, 0xe58e4029,
+    0x64fd2008, 0x3b36901c, 0xe2ed2820, 0x51ae143f, 0x380a1e29, 0x94bafb0c, 0x4f11ca85, 0x5e1b29f7,
+    0x2e234ab9, 0x1df169c3, 0x9f086a92, 0xfb38f9ea,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xf8000000, 0xfc000000, 0xca000000, 0x57000000,
+    0xf1800000, 0xb5c00000, 0x7fa00000, 0x84100000, 0x2b480000, 0x6c740000, 0x33fa0000, 0x9caf0000,
+    0x50018000, 0x2806c000, 0x94036000, 0x3e061000, 0x99029800, 0x90863c00, 0xd9414600, 0x6ce6c900,
+    0xbff60280, 0x653f0940, 0xbc88be20, 0xf0d2e510, 0xe86fdc98, 0x9320fc7c, 0xd7567a9e, 0x70a9e56f,
+    0xd200000a, 0x1b000039, 0xa380001a, 0xeec0002e, 0xbc200013, 0x9ad0000b, 0x6f680004, 0x0aa40023,
+    0x9692002f, 0xc10b002b, 0x37138001, 0x5ccdc030, 0xdcb0e023, 0xe6dbd033, 0x6efa783e, 0x1a29ec09,
+    0x84413e07, 0xea60251e, 0x6bb6bcac, 0xa759ec72,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x68000000, 0xec000000, 0x5a000000, 0x35000000,
+    0x07800000, 0x1ac00000, 0xeba00000, 0x5d500000, 0xde680000, 0x9a340000, 0xc79e0000, 0x8b890000,
+    0x6e858000, 0x4f404000, 0x7865e000, 0x73761000, 0x28fd8800, 0x1e798c00, 0x7539ae00, 0x4b59c900,
+    0x3f2a8080, 0xc952b840, 0x9069c620, 0x21325530, 0xdb18a6a8, 0x7dcffd7c, 0x1b6168ba, 0x8bf0644b,
+    0x38bb801e, 0x9c994001, 0x07086033, 0xa0c25001, 0x4ea66836, 0x62d69c21, 0xb0a9a620, 0xaf94052d,
+    0x19c8ce8a, 0x3d646173, 0xdcf34eb6, 0x7e3d2152, 0x5fdb2e90, 0x756f7154, 0x7eb546b2, 0xe5dded41,
+    0xd06ae09c, 0x4134e867, 0x8b19ae1e, 0xe5c9c915,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xe8000000, 0x7c000000, 0x3a000000, 0xbf000000,
+    0x78800000, 0x87c00000, 0xc6a00000, 0x02d00000, 0x13580000, 0x95b40000, 0x1a2e0000, 0x8fbf0000,
+    0x61848000, 0x6a424000, 0x0ee66000, 0x97301000, 0x82e95800, 0x819df400, 0xdc921600, 0x2bbd0900,
+    0xdf866c80, 0x1b4551c0, 0x5f652e20, 0x0574ed30, 0x780b2298, 0x80aeacf4, 0x337bd49a, 0x11a1f5ef,
+    0xc6528036, 0xce99402b, 0x3c14e03c, 0x8879500a, 0x6725b805, 0x0890a430, 0xcdb9ae14, 0xea82ad2b,
+    0xe4c342a9, 0x3421bcc2, 0x02160c8d, 0x397e41cf, 0x56a6f639, 0x4ad45927, 0xdf5bd49c, 0x97b1f5f2,
+    0x312a8038, 0xb13d4035, 0x6342e01c, 0x6b625039,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0x48000000, 0x04000000, 0xc6000000, 0x7f000000,
+    0xd0800000, 0x66c00000, 0x0c600000, 0x93f00000, 0xbd980000, 0x36540000, 0x8a8a0000, 0x42f90000,
+    0x57208000, 0x98d54000, 0x4f4ba000, 0xb41ef000, 0xcf179800, 0x08af9400, 0x27a97e00, 0xff2fdf00,
+    0x3de84d80, 0x278d29c0, 0x837f4620, 0xea67bb10, 0x7cf62ba8, 0x851822f4, 0x8495d5b2, 0x08ebbdd1,
+    0xaa0eb839, 0xfc39243b, 0xb747c60e, 0x1926fb2c, 0x03d78ba0, 0x29cfd2fa, 0x35dacd8a, 0x5ff569db,
+    0x3f9e661d, 0x2f550b09, 0xf50a9383, 0xbcbc06fa, 0x3980939d, 0xc14506fe, 0x1e201389, 0xef5046d6,
+    0x150bb3a2, 0xccbeb6ec, 0xa1842ba8, 0x8d4522f5,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x18000000, 0xa4000000, 0x9e000000, 0x83000000,
+    0xbb800000, 0x12c00000, 0x60600000, 0xe4700000, 0x27480000, 0x8dd40000, 0xfcda0000, 0xe22d0000,
+    0x05238000, 0xd4d3c000, 0x8a59a000, 0x406db000, 0xc007f800, 0x60070c00, 0x9004fe00, 0xa8078700,
+    0xbc078980, 0x3a037f40, 0x1d00a620, 0x38803b10, 0xa9470f88, 0x72a0346c, 0x841271a6, 0xc33d7379,
+    0xaa9dd80f, 0x71097c1c, 0x1ef2a620, 0xe7093b01, 0xd1f68fb9, 0x5e8af470, 0xca3251a0, 0x806e0357,
+    0xa0000021, 0xf0000039, 0x3800001e, 0x1400002a, 0x86000035, 0x27000002, 0x25800007, 0x91c00031,
+    0xdbe00031, 0xf6b00032, 0x4728002c, 0x69a4001c,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x08000000, 0x2c000000, 0xf2000000, 0xcf000000,
+    0x81800000, 0x41c00000, 0xe3e00000, 0x77100000, 0x91880000, 0x32340000, 0x78fa0000, 0xff6b0000,
+    0xa3a58000, 0x95354000, 0xe57de000, 0xa4a85000, 0xd3415800, 0x99a7fc00, 0xd6376600, 0x06feab00,
+    0x52689a80, 0x95216d40, 0x7171de20, 0x995a0710, 0x0d5b24b8, 0x7b5d7a5c, 0xfa5bc2a2, 0x3ed9915b,
+    0x15993824, 0xe83aec3f, 0x000bde1a, 0x4bf10707, 0x5f1ea486, 0x26783a65, 0x072e2298, 0xc585c17f,
+    0xafc2601e, 0x36e6103e, 0x6591380e, 0xabceec23, 0x7311de3d, 0x9f8a0722, 0x9733249e, 0x62797a5f,
+    0xe929c29c, 0x10869150, 0xbd46b81d, 0x0ca4ac37,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x08000000, 0xcc000000, 0x6e000000, 0x67000000,
+    0x36800000, 0x91400000, 0x21200000, 0x9e300000, 0x85580000, 0xd1540000, 0x38ca0000, 0x51790000,
+    0x38e38000, 0x11574000, 0xd8cee000, 0x417ab000, 0x80e31800, 0xd553a400, 0x7acc4200, 0x487a4500,
+    0xd161ef80, 0x7293b9c0, 0xcaabba20, 0xf76a5110, 0xca0d35a8, 0x269d18ec, 0x2330f7a2, 0x9edd1de3,
+    0xa3967813, 0x0f2a5425, 0xeaabba27, 0x076a513b, 0x620d3591, 0x5a9d18cd, 0x4530f797, 0x35dd1dd9,
+    0xfb167803, 0xf96a542b, 0xfd0bba0d, 0x081a5108, 0xc6753580, 0x15f918da, 0xf8a2f796, 0xb5f01dc8,
+    0xfb3ff82b, 0xb9441439, 0x1d26da21, 0x5837a135,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x18000000, 0x6c000000, 0x2a000000, 0x57000000,
+    0xdc800000, 0xb6c00000, 0x23200000, 0xe9b00000, 0x1fd80000, 0xd7540000, 0x9bca0000, 0x1cfb0000,
+    0xcee48000, 0x2c15c000, 0xefaee000, 0x0b2f1000, 0x21ef1800, 0x80cdd400, 0x1a7dde00, 0x87260500,
+    0xf7b03f80, 0xeedb21c0, 0x26d62620, 0xbe8fc110, 0x399e79b8, 0x98b130d4, 0xae5f27a6, 0x1216f5cb,
+    0xeeabf812, 0x52a9c411, 0x20ae4609, 0xfbaa1122, 0x5d290184, 0xa4e934f4, 0x374d819a, 0x423cf4dd,
+    0x29c36181, 0xcba3e4d4, 0xf97479a2, 0x11fa30db, 0xad63a78e, 0x825735fa, 0x744f183e, 0xc8bdd40b,
+    0x1a05de05, 0x5f020525, 0x48823fba, 0xa0c421f5,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0xe8000000, 0x1c000000, 0x3e000000, 0x27000000,
+    0x71800000, 0xd9c00000, 0x78600000, 0xa2900000, 0x23480000, 0x10340000, 0x8a3a0000, 0x812f0000,
+    0xcfa38000, 0xe5f64000, 0x645e6000, 0x78bf3000, 0x13689800, 0xe3079c00, 0xfb828600, 0xbcc0b700,
+    0xa0e27680, 0xc5d24740, 0xef6e7e20, 0x2d071b10, 0x5483e898, 0x61472c6c, 0xaf22ee9a, 0x86b1db57,
+    0x027ef837, 0xf08cac35, 0x4d501e3e, 0x90282b1d, 0x752370ab, 0xfbb4b066, 0x4efa68bb, 0x05ce6c6b,
+    0x70770e98, 0xe79cab42, 0x3dda0027, 0x9d7f002e, 0x850b801e, 0x3e924018, 0x5d4c603c, 0x57343019,
+    0x4bb91819, 0xb0eadc1f, 0xabc5663f, 0x7966c70d,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xd8000000, 0x9c000000, 0xb2000000, 0xcd000000,
+    0x4f800000, 0xc9c00000, 0xbca00000, 0x3f300000, 0xb3980000, 0x73540000, 0x498e0000, 0x75390000,
+    0x35638000, 0xe395c000, 0x1ca86000, 0x28ced000, 0x7ede4800, 0x12b40c00, 0x3f5f3600, 0x5477b100,
+    0x79786b80, 0x410087c0, 0xd5871e20, 0xe0c46d30, 0xed2295a8, 0x51f2facc, 0x11be2396, 0x78208bd7,
+    0x42762824, 0xca7adc0f, 0x39817e0d, 0xeac3bd19, 0x2c275db5, 0x447736db, 0x517f75b3, 0xa504eaed,
+    0xcb858ba6, 0x47c697dc, 0xf3a4b630, 0x65b67130, 0xa9de0b8b, 0xb43757d3, 0x071ad629, 0xb615a137,
+    0x3c6dc3bd, 0x65ef9be5, 0x1f2e0000, 0xdb090028,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x98000000, 0x3c000000, 0x3e000000, 0xc5000000,
+    0x8b800000, 0x32400000, 0x66a00000, 0xccf00000, 0xb7780000, 0x2b140000, 0xa7ae0000, 0xe3590000,
+    0x00238000, 0x1137c000, 0x419d6000, 0x4a40b000, 0x0aa50800, 0xfaf14c00, 0xe67fca00, 0x3a918100,
+    0x52ec4980, 0xf57fecc0, 0xb011a220, 0x792d7d30, 0xf29b0ba8, 0xf0c1e1cc, 0xb964c186, 0xc11060ff,
+    0x98a88807, 0x3fdf8c0d, 0xeae12a2c, 0xaf56f10f, 0x500c21b0, 0x9e2a10cd, 0x721d6020, 0xb400b007,
+    0x6a05083a, 0xff014c0b, 0x7c87ca12, 0xdac58132, 0x2662499b, 0x2d96ecd1, 0xea6a2229, 0xbdbebd3e,
+    0xc5706b98, 0xbe3c51e7, 0xa33449ad, 0xe29bece4,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0xd8000000, 0x94000000, 0x06000000, 0x03000000,
+    0x27800000, 0xa2400000, 0x35200000, 0x6df00000, 0xfdf80000, 0x03940000, 0x6baa0000, 0x175b0000,
+    0x88258000, 0xed73c000, 0x46382000, 0x73f17000, 0xcaf87800, 0xb216ac00, 0x62ecba00, 0x49fd4d00,
+    0xd5966180, 0x20a8a4c0, 0x0cdee220, 0x60659110, 0x495523b8, 0x094f85dc, 0x08cb9996, 0x798dc8f5,
+    0x232a7819, 0xd099ac0c, 0x52433a07, 0xad258d11, 0x19f3c1ba, 0x8bfe14fe, 0xd894ba17, 0xd8294d38,
+    0xb31c61a6, 0xbe03a4ea, 0xa7036206, 0x19825115, 0x4547038d, 0xcca5f5c5, 0x58b66195, 0xe958a4cf,
+    0xcf26e23f, 0x84f19122, 0xdb7f23ae, 0x2b5485e9,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x58000000, 0xbc000000, 0x96000000, 0x01000000,
+    0x1e800000, 0x49c00000, 0xd3200000, 0x26b00000, 0x93680000, 0x1f540000, 0x3b7a0000, 0xaecd0000,
+    0x68258000, 0xe731c000, 0x712f6000, 0x9931f000, 0xf42d2800, 0xfdb4a400, 0x02ef7600, 0xd511a500,
+    0x591fef80, 0x4b9b3c40, 0xac5f3e20, 0x927df110, 0x144a3188, 0x2be6fd5c, 0xd31747b6, 0x101e587f,
+    0xe11f280d, 0xe79da40c, 0x3258f619, 0x07796521, 0x78cf0f87, 0x49260c66, 0x89b0f626, 0x60ed6505,
+    0xf6150fb5, 0x449b0c6c, 0x6fdd763c, 0x0338a508, 0xa7a86f9b, 0x24f3fc56, 0x050fde18, 0xd800c100,
+    0xfc05f99f, 0xb606697f, 0x710079a2, 0x4687a961,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x98000000, 0x5c000000, 0x8e000000, 0x7d000000,
+    0xc8800000, 0xd3c00000, 0x52600000, 0xe4900000, 0x0a880000, 0x0db40000, 0xdfbe0000, 0x09cd0000,
+    0x10118000, 0xc74bc000, 0xead46000, 0x47ad3000, 0x9a80c800, 0xe0c55400, 0xc7e4c600, 0x8fd65f00,
+    0x132f4e80, 0xe7415140, 0xbfa1ee20, 0x73f5fb30, 0x0a9f2088, 0xc2ff6a5c, 0xa16aae86, 0x7467a167,
+    0xbd95460b, 0x400d9f33, 0xc5732e9c, 0xfc58617f, 0x241f2631, 0x683daf01, 0xc98a669b, 0xc032f574,
+    0xc8f98017, 0xee6fc028, 0x1fe26039, 0x33d43008, 0xcd2f481b, 0x72439433, 0xb3212622, 0x7230af2d,
+    0xabfbe6bf, 0x93e93574, 0xb0a5e01b, 0xf876f01b,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xa8000000, 0x14000000, 0xce000000, 0xc1000000,
+    0xbf800000, 0xb0c00000, 0x06200000, 0x57d00000, 0xaa880000, 0xeeb40000, 0x23be0000, 0x21cd0000,
+    0xce538000, 0x2f4a4000, 0x89142000, 0x9dacb000, 0x8ee6e800, 0x1df4ac00, 0xaf59be00, 0x6f3ee500,
+    0xd58bf480, 0xd8354b40, 0x28f8f620, 0xc12cb930, 0xe2a08288, 0xb793b24c, 0x72ec548a, 0x2803bb75,
+    0xd4023e1b, 0xee00a50c, 0xf101d48d, 0x1784fb65, 0xa4c59e38, 0xc8265510, 0x96d31cbe, 0x150ce76b,
+    0x5e72c82c, 0x25981c04, 0x761f5623, 0x64da4919, 0xc1fa4a85, 0xaaafae7d, 0xbc65028a, 0x40b0f251,
+    0x32bdf497, 0x264c4b5c, 0xf295763f, 0x5b6bf932,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xe8000000, 0x7c000000, 0xc6000000, 0x29000000,
+    0x80800000, 0xab400000, 0x60600000, 0x07300000, 0x26880000, 0x24940000, 0x569e0000, 0xc34f0000,
+    0xcab78000, 0xff4a4000, 0xacb16000, 0x064af000, 0x7432e800, 0x390a5400, 0xae526200, 0xd13e2f00,
+    0x215f1780, 0xde6a4540, 0xbce66a20, 0x7a74cb30, 0x57eefd98, 0x8724ce6c, 0x8951f79a, 0x14bef56f,
+    0xf49be209, 0x184b6f16, 0x0931f782, 0x638ef559, 0x5a13e233, 0xf0df6f24, 0x71aff780, 0xf5c1f545,
+    0xd6246237, 0x8dd52f34, 0x3dfe978a, 0x5ffb0551, 0xe4fe8a0d, 0x977b7b3d, 0xe3baf58b, 0x691e2a66,
+    0x59881d81, 0x75147e46, 0x395a7fba, 0xea6a515f,
+    0x80000000, 0xc0000000, 0x60000000, 0x10000000, 0x78000000, 0xf4000000, 0x5a000000, 0xad000000,
+    0xf1800000, 0xbdc00000, 0xb2200000, 0xdc900000, 0x39780000, 0x7af40000, 0xae0e0000, 0x7f3f0000,
+    0x00128000, 0xb53ec000, 0x15102000, 0xd0bf9000, 0xe2d60800, 0xb79c7c00, 0x3bc69600, 0x69265500,
+    0x5e169680, 0xea3bfcc0, 0xa5923e20, 0x22fb7930, 0x62362898, 0x71ae45c4, 0xc86c36be, 0x884eaccd,
+    0x15da162e, 0x6327951f, 0x2b1436ba, 0x9fbaacd6, 0x2a541604, 0xb1d89511, 0xe126b6bc, 0x02146cde,
+    0x5c3c3605, 0xb693050a, 0x5c7ebeac, 0x777710eb, 0xd5c82022, 0xb61b9017, 0x2e000831, 0x37077c07,
+    0x3c821627, 0x5c43950f, 0x77e236bb, 0x9ab1ace0,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x98000000, 0x3c000000, 0xd2000000, 0x4b000000,
+    0xfe800000, 0xe8400000, 0x68e00000, 0xde900000, 0xb4c80000, 0xb4b40000, 0xacfe0000, 0x7b0f0000,
+    0x21968000, 0xfc49c000, 0x99f26000, 0x3398d000, 0x60587800, 0xd77e0400, 0xc4ce4a00, 0x7cb28b00,
+    0x68fd9280, 0x050d5b40, 0x2092d220, 0x75cd9f30, 0x5d31c098, 0xf83d0464, 0x28af7286, 0x55674b7f,
+    0xac504a0c, 0xba6d8b06, 0x0bc31281, 0xeb209b51, 0x51b6b228, 0x6b7e4f1a, 0xd6c938be, 0x57b1c061,
+    0x067bd888, 0x754bd06c, 0x747140b3, 0x795fc463, 0xa2fd9281, 0xb20d5b5e, 0x6c12d20b, 0x468d9f2a,
+    0x5351c087, 0xf2ed0442, 0x26877298, 0x74434b46,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x38000000, 0xac000000, 0xce000000, 0x43000000,
+    0xf8800000, 0x44400000, 0xa1e00000, 0x5d100000, 0x7c480000, 0x0e340000, 0xc7fa0000, 0x160f0000,
+    0x3cd28000, 0x3a68c000, 0x1206e000, 0x65013000, 0x4f85b800, 0x4ec05400, 0x50a78a00, 0xeb75b300,
+    0x53997e80, 0xd4dad740, 0xcdbe5220, 0xb4e81710, 0xf947ac98, 0x92610064, 0x85d79eae, 0x53efe77b,
+    0xebc1ea0b, 0x3f274324, 0x55b2a6a8, 0xab3c735e, 0xaba80035, 0xfc24003c, 0xed32000a, 0x8f7b0026,
+    0x9a488029, 0x9937c028, 0x3d7c600b, 0x4f4df00a, 0x1eb15814, 0x77ba640e, 0x45eab205, 0xd4c2270f,
+    0x51a29480, 0x6ef2946e, 0x045e7492, 0x5dfca46a,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x28000000, 0x94000000, 0x86000000, 0x4d000000,
+    0x56800000, 0xe3c00000, 0xe8200000, 0x88f00000, 0xc1980000, 0xc3940000, 0x878a0000, 0x105b0000,
+    0x10b48000, 0x8cfc4000, 0x88852000, 0xe2c5f000, 0x34a08800, 0x4c30a400, 0x08380600, 0xa1273d00,
+    0xd077dd80, 0x4b5b3bc0, 0x83312e20, 0x1dba2910, 0x7d6073b8, 0x731252d4, 0x394afdaa, 0x66facbf5,
+    0xbf83a639, 0x0b458d17, 0x1ae6f5a7, 0x74522fc9, 0x49ac8031, 0x65a84004, 0xffaf203c, 0x10aef00f,
+    0xf52c0804, 0xb168e43b, 0xfe0f262f, 0xb61dcd22, 0x0cd1d5a6, 0x3368dff4, 0xbd0a8832, 0x899ba406,
+    0x4794862c, 0xc98f7d17, 0x9958fdb0, 0xe835cbd8,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x18000000, 0xac000000, 0xa6000000, 0x29000000,
+    0xa8800000, 0x34c00000, 0xc3e00000, 0x44f00000, 0x9c980000, 0xcd540000, 0x8e4e0000, 0x6a590000,
+    0xf5b08000, 0xe13bc000, 0xb586e000, 0x56417000, 0xd821e800, 0x56979400, 0x5c2d1e00, 0x0aeb7300,
+    0x6209f580, 0x157d15c0, 0x66a29620, 0x63525730, 0x734de3a8, 0x38d982d4, 0x067715a6, 0x175865db,
+    0x67357e21, 0xb2f8c32e, 0x13e67d84, 0x9cf431f2, 0x909e803f, 0x3b52c000, 0xbf4e600c, 0x6edeb020,
+    0x6771083f, 0x0bdbe42d, 0x59f27603, 0xfe1e2710, 0xd6928b83, 0x9c2cd6f1, 0xaaec6bb8, 0x3209a6d6,
+    0x0d7b83a8, 0xcaa332f8, 0xc5501dae, 0x5a4e81d0,
+    0x80000000, 0x40000000, 0xa0000000, 0xb0000000, 0x88000000, 0xac000000, 0xce000000, 0x2b000000,
+    0xe4800000, 0xb0400000, 0xae200000, 0x7dd00000, 0xc3f80000, 0xf1340000, 0xef4a0000, 0xc7b90000,
+    0x45138000, 0xf39b4000, 0x19c1e000, 0x8de5b000, 0x61357800, 0x9749b400, 0x43baea00, 0x97106d00,
+    0x9e983880, 0x7a4334c0, 0x1725f220, 0x54532910, 0xa03c4aa8, 0x4dd65dec, 0x0bf9d882, 0xfd3684fb,
+    0x91488a3b, 0x64be9d36, 0x0d94a0b3, 0x8ddb30eb, 0x9ce0601a, 0x14b3f02e, 0x128d1812, 0xc85e4436,
+    0xd125f20b, 0x93532929, 0x2abc4ab9, 0x66965dd5, 0xc959d894, 0x9ca684d2, 0x32908a11, 0xc35a9d0b,
+    0xc5a6a0bd, 0x0b1630c1, 0x9899e033, 0x5d41b018,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x48000000, 0xfc000000, 0x16000000, 0x9b000000,
+    0xeb800000, 0xaec00000, 0xfa600000, 0x03100000, 0x78b80000, 0xf2340000, 0x900a0000, 0xea7d0000,
+    0x7e518000, 0xd8194000, 0xc1456000, 0x96a77000, 0x58707800, 0xa52f8c00, 0xe0cf5e00, 0x371db700,
+    0xa0c58280, 0x656138c0, 0x6a93c620, 0x57780b10, 0x26d5c488, 0xb15973fc, 0x3be0e2b2, 0x40d648ef,
+    0x225bbe2d, 0x0c63870a, 0x08109aa0, 0x0b39c4e8, 0x18f4e009, 0xc86e3009, 0x986d1829, 0x606cfc0d,
+    0xf46d2632, 0xee6b3b2e, 0x2b695cba, 0xa7eccfec, 0xf4a8a490, 0x3b0a03d8, 0x29fa9abb, 0xfc94c4c9,
+    0x8c7d6033, 0xed537031, 0xef9a7835, 0x89828c0a,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x08000000, 0xdc000000, 0x6e000000, 0x7b000000,
+    0x14800000, 0x51c00000, 0xc3200000, 0xbaf00000, 0x53d80000, 0x2e940000, 0x7eca0000, 0x431d0000,
+    0x70348000, 0xb47b4000, 0xbda16000, 0x6a33b000, 0x6d7c7800, 0x9423b400, 0xd8706600, 0x8d1a8500,
+    0x1b320d80, 0x88ff91c0, 0x6061fe20, 0xcf15c110, 0x708d7388, 0xbd7c10d4, 0xdc216da2, 0x247521e7,
+    0xb31b0633, 0x6834351a, 0x407af58f, 0x5fa765f7, 0x7730f809, 0xdefcf417, 0xbf630602, 0x31903515,
+    0xe048f5a8, 0xa3de65dd, 0x36967803, 0x8aceb406, 0xa11ce629, 0x6d35c501, 0x07f96daa, 0x96e121c1,
+    0x83d1062a, 0x00293511, 0x2cce75a0, 0x661c25cd,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x18000000, 0x34000000, 0x06000000, 0xfd000000,
+    0x53800000, 0xae400000, 0xb8a00000, 0x15700000, 0x22380000, 0xd2140000, 0x82aa0000, 0xe77d0000,
+    0xac378000, 0x661bc000, 0xd1a52000, 0x30f37000, 0x497f8800, 0x3d368c00, 0x27986a00, 0xb4e41100,
+    0x3ed4f580, 0x95cf8ec0, 0x0fed4220, 0xa55e2d10, 0x52843788, 0x87c763cc, 0xd1e3d5a6, 0x1955fedd,
+    0x1d8f4a29, 0x7a4e6123, 0xfbaefd9a, 0xeafbc2ea, 0xeb758809, 0xbb3b8c27, 0x8f97ea15, 0xcdebd12c,
+    0x265bd59c, 0xd801feda, 0x54054a2c, 0x16036107, 0xd5017d80, 0x7f8402e6, 0x9c42a811, 0x43a1fc37,
+    0xbbf5e217, 0xdffb9d3f, 0xc4f11f9b, 0x2f7d5ff9,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x58000000, 0xcc000000, 0x4a000000, 0x5b000000,
+    0xc6800000, 0xb6400000, 0x50a00000, 0x76d00000, 0xb6780000, 0x75b40000, 0xfcce0000, 0xe1f90000,
+    0x9e728000, 0x7ca9c000, 0x61cfe000, 0x567a1000, 0x05b17800, 0x14c98400, 0x65fe7a00, 0x40704900,
+    0xa1adc080, 0xb64decc0, 0x7dbc6220, 0x25d31d30, 0x84ffa2a8, 0xf5f7f1c4, 0xf56940b6, 0x9ea92cc3,
+    0xdecf021a, 0xfef9cd22, 0x26f3ba8f, 0x07eda5da, 0xbee9a2b0, 0xc76af1c8, 0xd9adc086, 0xaa4decd2,
+    0xcfbc6204, 0xa2d31d0f, 0x507fa2b2, 0xd4b7f1fe, 0x2949409f, 0x05392cc8, 0xfe970226, 0x4bddcd30,
+    0x3ce5ba90, 0xe570a5c0, 0x6a2d2293, 0x2f8e31d0,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xe8000000, 0x44000000, 0x86000000, 0x27000000,
+    0xa6800000, 0x0b400000, 0x24e00000, 0x51700000, 0xe8280000, 0x1b540000, 0x867a0000, 0x62ad0000,
+    0x92158000, 0x239c4000, 0xc25ca000, 0x3ffef000, 0x536b5800, 0xb7b7a400, 0xc30a9200, 0x9486cb00,
+    0x02473580, 0xc1675440, 0x0ab2ea20, 0x088edf10, 0x8c47df88, 0xb2608b5c, 0x9232b59a, 0xf8cb1441,
+    0x24264a09, 0x0d542f05, 0x597e879b, 0x382e2f4f, 0xc357a7b2, 0x0a7c9f5c, 0xd0a85f9a, 0xdb11cb41,
+    0xe61b9593, 0xe999a453, 0x3759b224, 0x80797b0e, 0x05ad4d91, 0x14964057, 0x58dd800a, 0x0eb84023,
+    0x2a8ea017, 0x3d47f027, 0x8be4d825, 0xe3f6e40b,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xd8000000, 0xe4000000, 0xde000000, 0xdb000000,
+    0x85800000, 0xb6400000, 0xb3e00000, 0xf3900000, 0xfc980000, 0x5cb40000, 0x192a0000, 0x61190000,
+    0xeef58000, 0xa4c9c000, 0xe18da000, 0xabedb000, 0x743c3800, 0x4e457c00, 0x67e7da00, 0x55900b00,
+    0xb39a9280, 0xdf3201c0, 0x9069c220, 0x89780710, 0x702150a8, 0x6e3706dc, 0xf8ef1296, 0xfdbbc1e9,
+    0xba04623f, 0x4505b73a, 0xfe8568bf, 0x43c67ac8, 0xdda2c88d, 0xa472cae3, 0xd10b70a1, 0x7b2e76c0,
+    0xc0190ab5, 0xce77cdfc, 0x3c0da021, 0xb9adb001, 0xb9dc3809, 0x16d57c1b, 0xc6ffda2c, 0x5b640b3c,
+    0xc750929f, 0x96bb01e6, 0x0784421d, 0xc745c700,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x38000000, 0x74000000, 0xd6000000, 0xf3000000,
+    0x0d800000, 0x13400000, 0x15e00000, 0x96900000, 0x7f580000, 0xa2b40000, 0x00ea0000, 0x48dd0000,
+    0x10f38000, 0x258ac000, 0xf78b2000, 0x8a8cf000, 0x900af800, 0xd0cffc00, 0x696af200, 0x459fbb00,
+    0x9a121280, 0xac9e07c0, 0x8692aa20, 0xb75b7710, 0xceb2b888, 0x12ec70ec, 0x55d992ae, 0x9a70c7cd,
+    0xed4b8a1d, 0x022e8700, 0x0479c085, 0x6a804cf5, 0x18c1c09e, 0x5da44cd4, 0x72f3c081, 0x608d4cec,
+    0x990a40a0, 0x1c4a8cd2, 0xdaaae08d, 0xf1b8bcd9, 0xa5a138b2, 0x66f6b0c1, 0x268ab2b5, 0xe20837d3,
+    0x5dcb7229, 0x6bec7b2e, 0xc158b295, 0xcdb137eb,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x08000000, 0x74000000, 0x1a000000, 0x89000000,
+    0x7f800000, 0xe0400000, 0x0e200000, 0x98100000, 0x07a80000, 0x6af40000, 0xff5e0000, 0xa8a90000,
+    0x92728000, 0x439b4000, 0xa84a2000, 0xe7821000, 0x5c44a800, 0xc0250c00, 0x1b135a00, 0xf92c2500,
+    0x81374e80, 0x0b3dd640, 0xb7995220, 0x724d7930, 0xce861ca8, 0x33c4af44, 0x28614ea2, 0x6130d66d,
+    0x7b3dd20e, 0x0f9b3916, 0x1e48bcb5, 0xa880ff61, 0xd4c346a7, 0xc4e58a69, 0x77f28010, 0xeadb4034,
+    0x79ea2009, 0x8fd2102d, 0x5dcca822, 0x46c10c13, 0xf9e55a0d, 0xb2712513, 0x939bce90, 0x004f9654,
+    0x8381f22a, 0x4e44291b, 0x3d20948b, 0x7e97b37f,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xd8000000, 0xc4000000, 0x6e000000, 0xfb000000,
+    0x09800000, 0x88400000, 0xf3e00000, 0x60500000, 0x51c80000, 0xaf740000, 0x34be0000, 0xbecd0000,
+    0xd0f08000, 0xa3f9c000, 0x7aa9a000, 0x1be53000, 0xcc503800, 0x53ce9c00, 0x7670aa00, 0xb43eeb00,
+    0x6e0f5c80, 0x37d57b40, 0x4a8fb220, 0x39158730, 0xfea8ee88, 0x95e4fc54, 0x47515c96, 0xd2487b41,
+    0xe2373233, 0xedd8471a, 0x9b5f4ebc, 0x949ccc47, 0x6439e4b1, 0x760b2767, 0xd3d03825, 0x748e9c13,
+    0x1a10aa0d, 0x332eeb30, 0x73a75cb1, 0x4fb17b54, 0xbb99b23e, 0x3bbc872e, 0xb14e6ebc, 0xcfb43c7d,
+    0x7b9e7c89, 0x1bbd8b71, 0xe1482a1c, 0x17b32b01,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xd8000000, 0xe4000000, 0xa6000000, 0x3b000000,
+    0xc7800000, 0x73400000, 0xc5200000, 0x73f00000, 0x25f80000, 0x9b140000, 0xaaea0000, 0x0ffd0000,
+    0x5a158000, 0x446dc000, 0x8e39a000, 0x2cf67000, 0x047b3800, 0xf352ac00, 0xd8483200, 0xd74d2d00,
+    0x5ec9a980, 0x0a89b4c0, 0x3a2d2a20, 0x7f9d3110, 0x87c48388, 0x7de485dc, 0x7f91a996, 0x9a2db4e9,
+    0x4f9f2a01, 0x7fc43102, 0xe9e3038f, 0x019045f9, 0x452f898e, 0x2e1f04fb, 0x37823239, 0xeb402d38,
+    0x01242994, 0xa5f074e6, 0xc6fe8a11, 0xb896410c, 0x7faa3b90, 0xf1dbe9e0, 0xee603bab, 0x12d6e9c7,
+    0xd00dbbba, 0xf5ef29d7, 0x2e7e1bb9, 0x325459f9,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x58000000, 0x4c000000, 0x12000000, 0x9b000000,
+    0x18800000, 0x77400000, 0x27e00000, 0x70700000, 0x3a580000, 0x4d540000, 0x830a0000, 0xe05d0000,
+    0x82568000, 0xe589c000, 0x0e1fe000, 0x7e34d000, 0xb63f7800, 0x76e7b400, 0xfff7b200, 0x679c8300,
+    0x96f68d80, 0x941fdfc0, 0x9133aa20, 0xc0bf2710, 0xc0a52788, 0x4f90f8c4, 0x262e8db6, 0x750bdfc3,
+    0x0d59aa2c, 0x37d22732, 0x074ba798, 0xdb3d38ca, 0x83636dbb, 0x3d360ff9, 0xa2ba5224, 0x33a1533c,
+    0x531575b1, 0x576cabee, 0x97ed7815, 0xecaeb413, 0xf94b3227, 0x0238430b, 0x78e7edb4, 0x0ef6cfce,
+    0xb819323f, 0xb3314306, 0x13bb6dab, 0xcc220fd5,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0x28000000, 0x5c000000, 0x5a000000, 0x65000000,
+    0x00800000, 0x72400000, 0x98200000, 0xd8b00000, 0x0e980000, 0x19540000, 0xcfce0000, 0xd29b0000,
+    0x83508000, 0x4ac84000, 0x821b6000, 0xd9131000, 0x8ee98800, 0x00a9b400, 0xb28baa00, 0x97394d00,
+    0xbd22e380, 0xf8341fc0, 0xccdfc220, 0xf974a930, 0x637d21b8, 0xda00b6d4, 0xa502e3aa, 0xe0841fe7,
+    0x2247c20e, 0xb020a93d, 0x84b321b2, 0x549bb6df, 0x7c52639a, 0xcf4c5fc8, 0xa0dca20d, 0x1b73b927,
+    0x927aa9a7, 0x8c8202dd, 0xc041c9b9, 0x412112dc, 0xd230419e, 0x31dca6e5, 0xddf5eb84, 0x3f3eebff,
+    0x2127880d, 0x4232b41b, 0xf9db2a38, 0xd1f10d3c,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0xf8000000, 0x9c000000, 0x56000000, 0xcb000000,
+    0x6e800000, 0xd3c00000, 0x6aa00000, 0x0db00000, 0xead80000, 0x33540000, 0x598e0000, 0xaadd0000,
+    0xd3518000, 0x0988c000, 0x22da6000, 0xb754f000, 0xc38a7800, 0xbfdba400, 0x12d43600, 0x7eca5900,
+    0x06bd5780, 0x75c035c0, 0x99a3ae20, 0xdf34cd30, 0x1f1ef988, 0x6af4f8dc, 0xa6bd579e, 0xc5c035d7,
+    0x41a3ae3d, 0x3334cd1e, 0xb11ef9ad, 0x3df4f8cf, 0x9e3d5791, 0xdd0035e6, 0x4583ae1c, 0xed44cd26,
+    0x3166f9a1, 0x0310f8e6, 0x2d6b579f, 0x448935e8, 0xcf5c2e02, 0x4e110d21, 0xc0ed19a5, 0xbdccc8cb,
+    0xcc3b4f93, 0x4c0661da, 0x1e026033, 0x8f00f013,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0xd8000000, 0xf4000000, 0x12000000, 0x5f000000,
+    0x43800000, 0x51c00000, 0x0e200000, 0xb6f00000, 0xe0880000, 0x808c0000, 0xf08e0000, 0x188b0000,
+    0xa48f8000, 0x9a894000, 0x238ee000, 0x2d0e7000, 0x604c8800, 0x7c2e9400, 0x953c1a00, 0xcd646b00,
+    0x1b95dc80, 0x8b18d8c0, 0xe395dca0, 0xaf18d8d0, 0x8995dc88, 0x9418d8f4, 0x0015dcbe, 0x6ed8d8c9,
+    0x5fb5dcba, 0xd6e8d8de, 0xf29ddcaa, 0xb154d8ca, 0xecbbdca9, 0x9fa3d8e7, 0x58325c91, 0x9d2d98c7,
+    0x2fbd3cad, 0x3221a8c1, 0xc8f0d484, 0xf9880ce7, 0x1e0ea68c, 0x65cc83ec, 0xf0ebe814, 0x5f9ea433,
+    0x9dd7f211, 0xb6facf10, 0xec422ebf, 0x06e217d6,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xf8000000, 0xd4000000, 0x9e000000, 0x41000000,
+    0xeb800000, 0x61400000, 0xf2e00000, 0x15900000, 0xf5580000, 0xed5c0000, 0x495a0000, 0x4f590000,
+    0x6a5c8000, 0xe7db4000, 0x139de000, 0x94f91000, 0xa12b1800, 0x4d144400, 0xd49b4e00, 0x9a7ed900,
+    0x2deb7580, 0xfbb25f40, 0xbe6b75a0, 0x8ef25f70, 0xb28b75b8, 0x6a625f5c, 0x54537586, 0x327e5f69,
+    0x71e975a1, 0x29b75f79, 0x056df59b, 0x42701f61, 0xad4a15a7, 0x8c400f64, 0x93658d9a, 0xcbd30b5f,
+    0x1d392388, 0x8a0dc24c, 0xfba5ce12, 0xdb709935, 0x82c815b6, 0x5b050f60, 0xbc830da6, 0x87c14b6f,
+    0x822043b5, 0xa1339250, 0x6ca93627, 0x1954cd0b,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x88000000, 0x54000000, 0xc2000000, 0x8b000000,
+    0x9f800000, 0x4ac00000, 0x2ea00000, 0xf2700000, 0x63980000, 0x7b9c0000, 0xe79e0000, 0xd19b0000,
+    0x889b8000, 0x141fc000, 0x955d6000, 0x333b7000, 0x64ef2800, 0x6a83d400, 0x38458200, 0x8ae6f700,
+    0x4e946480, 0x740ff840, 0x931464a0, 0xf5cff850, 0x82346488, 0x5d7ff854, 0x470c64aa, 0x8093f841,
+    0x010a649a, 0xa194f863, 0xf18fe4bd, 0x2ed03871, 0xc2e904b6, 0xfb84884d, 0x50c34cae, 0xd9a02c53,
+    0xebf7e697, 0xe85e0f67, 0x15bd8035, 0x02a8c022, 0x5d60e030, 0xb053b03e, 0x28afc801, 0x72606420,
+    0xd5d24a2d, 0x256a9317, 0xdd402e8e, 0x70626b68,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x18000000, 0x84000000, 0x26000000, 0xe7000000,
+    0xf5800000, 0xfc400000, 0x22200000, 0x69900000, 0xea680000, 0x9e6c0000, 0xd06e0000, 0x9b6d0000,
+    0x84ef8000, 0x25af4000, 0xb70b6000, 0x165a5000, 0x30d56800, 0xeccec400, 0x613f2600, 0xc5e73700,
+    0x8cf1fb80, 0x965ee8c0, 0x70d1fba0, 0x8ccee8d0, 0x3139fbb8, 0xdde2e8c4, 0x08f7fbbe, 0xb05fe8e5,
+    0x97d07bb7, 0x794ca8dc, 0xcd7d1b8a, 0xffc7f8e3, 0x6161f382, 0x5a377cf9, 0x09bc35b8, 0xa9245bde,
+    0x5612460c, 0x7b2c6738, 0x44cd138d, 0xed3e6ce1, 0x1fe43d98, 0x99f1cfe0, 0xbad9880a, 0x1a17d42e,
+    0x812f2e3d, 0x61cea317, 0x89bc35b2, 0xe9245be0,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0xb8000000, 0x44000000, 0xd6000000, 0x45000000,
+    0x36800000, 0xedc00000, 0x9c600000, 0xe1d00000, 0x20a80000, 0xe6ac0000, 0xdbaa0000, 0x892b0000,
+    0x22ef8000, 0x438a4000, 0xd0d86000, 0xcbb6d000, 0xf478f800, 0xf8834c00, 0xbcc72200, 0xe4e04100,
+    0x9d170f80, 0xe44d6ac0, 0xebbf0fa0, 0x87e16af0, 0x26950fa8, 0x730a6ad4, 0x201a8f86, 0x95502ac5,
+    0x066aefb3, 0xfd4afad4, 0x1f3817be, 0x6122b6ef, 0x1d70b599, 0x2798b7d7, 0x7017da11, 0xeecf0d2e,
+    0xb47a2d87, 0x18862bfc, 0x0cc78027, 0xcce64038, 0x61126015, 0x764dd00f, 0x78bf783b, 0xf4650c15,
+    0xfdd54202, 0x02ad9117, 0x5da877ba, 0x542866fc,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x98000000, 0xbc000000, 0xd6000000, 0xb5000000,
+    0x9e800000, 0xee400000, 0x01200000, 0xfb300000, 0x39b80000, 0xffbc0000, 0x42be0000, 0x28390000,
+    0x04788000, 0x425c4000, 0xf8e9a000, 0xd2971000, 0xe9ceb800, 0x21a44c00, 0x24723e00, 0x1c1eb700,
+    0x21cf2e80, 0x75a44340, 0x36772ea0, 0xf3184350, 0x44492e98, 0x0c61435c, 0x9191aebe, 0x524d0373,
+    0x19600e8b, 0xdf16135e, 0x1408b6ac, 0x3c475f65, 0x4e2408ac, 0x4eb0a85b, 0xe87c0622, 0x7c5abb24,
+    0x89eab092, 0x1614e47e, 0xfc8e380b, 0xb7040c32, 0x79859e0b, 0x7fc0a735, 0xbae1169a, 0x1bd04f73,
+    0xd96ab087, 0xb154e45d, 0x552e3825, 0x27740c24,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x58000000, 0x8c000000, 0xca000000, 0xdf000000,
+    0xb2800000, 0x28400000, 0x51a00000, 0xb6100000, 0x04680000, 0xe26c0000, 0xa76a0000, 0x02ed0000,
+    0x3c2e8000, 0x5bcc4000, 0x25782000, 0x30867000, 0x93448800, 0x1d271c00, 0x77515e00, 0xaa485300,
+    0x9dbb2380, 0xa26160c0, 0xf07323a0, 0xca1d60f0, 0x21712398, 0x099c60ec, 0x9ab5a38e, 0x2bfd20cf,
+    0xaac303bc, 0x01e710f8, 0xfbb7ab90, 0x507a7cf2, 0xed007d84, 0xb18433df, 0x98c68005, 0x02e04027,
+    0x4b32200c, 0x033b7017, 0x1022083b, 0xb6d75c1d, 0x6a8b7e24, 0x135f2332, 0x23532bb2, 0x2c4b3cc2,
+    0x68bedda3, 0xbfe203d2, 0x32b02828, 0x3ffc2c0b,
+    0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0x68000000, 0xbc000000, 0xb2000000, 0x65000000,
+    0xfb800000, 0x7dc00000, 0x7a200000, 0x07d00000, 0x7a280000, 0xcc2c0000, 0x0f2a0000, 0xbfab0000,
+    0xbee98000, 0x4f8ac000, 0x083f2000, 0xc022b000, 0x6ed59800, 0x2bac4400, 0x50eaaa00, 0xe88dfb00,
+    0xaebf8580, 0x926334c0, 0x673785a0, 0x815f34f0, 0xb39585a8, 0xd8c834e4, 0x6a5e05b2, 0x5f15f4cb,
+    0x1b8aa59e, 0xc63a84d2, 0x37239da0, 0xa055b0cd, 0xc5ea0fbe, 0xeb0c7fcc, 0x277d9820, 0xb640443f,
+    0xdfe0aa03, 0xd9f6fb20, 0x4bfe05a4, 0xb505f4e7, 0x5382a59c, 0xe1c684c8, 0x98219da3, 0x0ad2b0cf,
+    0x3da98f93, 0x03edbfc5, 0x100b380a, 0x43f83439,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0x38000000, 0x64000000, 0xfa000000, 0x55000000,
+    0x3b800000, 0x7b400000, 0x65600000, 0x87300000, 0x16f80000, 0xe0fc0000, 0xc3fe0000, 0x9b7f0000,
+    0x98ba8000, 0x4e1b4000, 0x27886000, 0x3ce7b000, 0x3d711800, 0xaa1ac400, 0x9d8c1e00, 0x49e0b500,
+    0xb6f04c80, 0xe95c5b40, 0x9ce84ca0, 0x34d05b50, 0xf50e4ca8, 0x32235b7c, 0x2452cca6, 0xcacb1b65,
+    0xea862c98, 0x6ac4eb70, 0xe323d496, 0x35d1df6e, 0x4c8a328f, 0x58645e4f, 0x30b3980a, 0x5bbd8437,
+    0xc69a7e1a, 0x8c480501, 0x0643d4b4, 0xf2e1df7e, 0x7a723293, 0x08985e78, 0xcb4d982a, 0xa4c2842b,
+    0xa420fe2d, 0x9753452e, 0x1a4bb4af, 0xb5466f4c,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x28000000, 0xac000000, 0x96000000, 0x99000000,
+    0x4e800000, 0x7fc00000, 0xf1600000, 0xd0f00000, 0x7ba80000, 0x4aac0000, 0x682a0000, 0xa1eb0000,
+    0x598f8000, 0xeffec000, 0x4793a000, 0x6a5b1000, 0x4b85d800, 0xdf44ac00, 0xb3a4d200, 0xc5179900,
+    0xf399be80, 0xbbe6f3c0, 0x5533bea0, 0xa5cdf3f0, 0xdddc3ea8, 0x0ac333d4, 0xc9e79ea2, 0x863423ff,
+    0x7c484687, 0x619b8fd9, 0xd8e31494, 0x34b2d6c6, 0x9d890a28, 0x35ff3532, 0xf8976c96, 0x05da6ad0,
+    0xfec58024, 0x6be5c02c, 0x9d342010, 0xb9c9d028, 0xf3dc7826, 0x17c4bc1b, 0xbd668a08, 0xf6f1f53e,
+    0x5aacccae, 0x802d7ac2, 0x2dea5803, 0x5f8a6c25,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xd8000000, 0xd4000000, 0xc2000000, 0x41000000,
+    0xf2800000, 0xa6c00000, 0x69e00000, 0x48d00000, 0x6e880000, 0x7b8c0000, 0x0b0e0000, 0x0cc90000,
+    0x47af8000, 0x71b94000, 0xa2d7e000, 0x538df000, 0x7709f800, 0xf2cb6c00, 0x18afda00, 0x0c3cd500,
+    0xa196f380, 0xed2937c0, 0x4378f3a0, 0xe93037d0, 0x8a5f7398, 0x330577e4, 0xfb8693ae, 0xb84187d1,
+    0x0920eb9e, 0x7a33abc1, 0x41d8d1a2, 0x83428ee8, 0xfea7da38, 0xd470d53a, 0xcbf8f383, 0x4af037f4,
+    0xeb3f7399, 0x981577d3, 0x146e9386, 0xb8dd87ce, 0x35c6eba8, 0xa266abff, 0xf89151b9, 0x6baecefa,
+    0x17b9ba19, 0xc9d16502, 0x3c0f6b90, 0xed4aebe5,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xb8000000, 0x24000000, 0xd2000000, 0x63000000,
+    0x3d800000, 0x43c00000, 0x7b600000, 0x68500000, 0xfb180000, 0xe41c0000, 0x7f9e0000, 0x555d0000,
+    0x44bd8000, 0x38a84000, 0xbc116000, 0xa4bbf000, 0x68aee800, 0x64160400, 0x90bb9a00, 0x02ab0100,
+    0x2315a380, 0x7f3a4b40, 0x226ba3a0, 0x65f74b50, 0x54ae23b8, 0xa2130b54, 0xe9b94396, 0xd029fb5d,
+    0x13542ba2, 0xd29abf45, 0xbddb51ad, 0xebfe0e55, 0x4e8f7a33, 0x6964b10f, 0x2b542bad, 0xb69abf66,
+    0x0fdb518a, 0x98fe0e67, 0xcb0f7a2d, 0x0ea4b124, 0x82342bb8, 0xbdcabf4c, 0xc94351a1, 0x3f220e49,
+    0xcff17a22, 0x33a9b10e, 0x3d91abb0, 0x617eff73,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x58000000, 0x94000000, 0xa2000000, 0x63000000,
+    0x64800000, 0xb4c00000, 0x1ce00000, 0xec900000, 0xdb780000, 0x047c0000, 0xfefe0000, 0xf73b0000,
+    0x925b8000, 0xa3894000, 0xe155e000, 0x041cf000, 0x62a9e800, 0x6aa5cc00, 0x9db1ea00, 0x18894d00,
+    0x51d14980, 0xb2d8a240, 0x0d4f49a0, 0xbab3a250, 0x660cc988, 0xa216e254, 0x20bf299e, 0xfa1d1271,
+    0x2fab41b6, 0xfb269e69, 0x9cf2cbaf, 0xef2d6344, 0xe5e20a28, 0x8f12bd3f, 0x413d21be, 0x135f2e6e,
+    0x0408c389, 0x21135f47, 0xf43f8813, 0x06dc7c0e, 0xff4be235, 0x21b77122, 0x868ccbb4, 0xecd66349,
+    0xcb598a3b, 0xd00bfd2c, 0x2310c1be, 0x873fde76,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x28000000, 0xe4000000, 0xbe000000, 0x59000000,
+    0xdf800000, 0xbbc00000, 0xa1a00000, 0xe5f00000, 0x0e980000, 0x699c0000, 0x2f1a0000, 0xeb5f0000,
+    0x01388000, 0x6d69c000, 0x6206e000, 0xbb071000, 0xa483d800, 0xbf402400, 0xeee27a00, 0x23163700,
+    0xc98e3880, 0x1e17bc40, 0x680c38a0, 0x5cd4bc70, 0xe62eb888, 0x2ae27c4c, 0xad10d882, 0x188cac75,
+    0xd595e0ad, 0x45cb9863, 0x4074429a, 0x625d8b4d, 0xc6b80032, 0xbaac0034, 0x69a20011, 0x91f3001e,
+    0xc89a8032, 0x0c9ac018, 0x829c6038, 0x539dd03a, 0x981fb80f, 0xb5ddf402, 0xa97dc211, 0x2d0bc303,
+    0xc153fa82, 0xd6ec7f72, 0xa7c7c213, 0xe3a4c31f,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0xb8000000, 0x2c000000, 0xca000000, 0xab000000,
+    0x26800000, 0xc5c00000, 0x46e00000, 0x7d700000, 0xe8c80000, 0x71cc0000, 0x704a0000, 0xc9090000,
+    0xa92e8000, 0x203b4000, 0x96422000, 0x28a07000, 0xd9d32800, 0x6b191c00, 0xf9d4b200, 0x3b194500,
+    0xd1d2ce80, 0x5f1d58c0, 0x8fd0cea0, 0x121858f0, 0xc8544eb8, 0x5a5a18cc, 0x6df0ee96, 0xa40d28c7,
+    0xbeabe6a4, 0x407d44ed, 0x57e2fcad, 0x88f65ddc, 0x478ea03c, 0xc8ee3003, 0x58dd8826, 0x56372c2f,
+    0x3fe93a22, 0xcc5e6921, 0x74f3f4a6, 0x658f31e2, 0x67e93a11, 0xd05e6932, 0x66f3f496, 0x128f31d5,
+    0x33693a2f, 0x929e6903, 0xcc93f485, 0x013f31e5,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x58000000, 0x0c000000, 0xa2000000, 0x7f000000,
+    0x25800000, 0x59400000, 0x44a00000, 0xa5d00000, 0x57680000, 0x826c0000, 0x4cea0000, 0x7e2d0000,
+    0x6ecb8000, 0x8d394000, 0x0f412000, 0x65a03000, 0x3555c800, 0xd0abe400, 0x4c0bae00, 0x25dd4b00,
+    0x30b70580, 0x1dda2ec0, 0x2cb505a0, 0x07db2ef0, 0x8fb48598, 0xd85f6ec4, 0xa5f6258e, 0x1b7a1ec7,
+    0x2660cda6, 0x2d30cad8, 0x589f2baf, 0x721225ce, 0x0109203e, 0x075c3027, 0x7077c80b, 0x2a3ae437,
+    0xe6c22e20, 0x7ee50b1c, 0xdef7a5ad, 0x08fe5efc, 0x4e226da6, 0x4115baca, 0xa689c39a, 0x1118f1dc,
+    0x9956c60e, 0x82aedf3f, 0x5b09c39f, 0x8458f1f7,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xe8000000, 0x64000000, 0xa6000000, 0xa1000000,
+    0x5e800000, 0xa5400000, 0xd3a00000, 0x01d00000, 0xa2e80000, 0x21ec0000, 0xc86e0000, 0x1aad0000,
+    0xa6ca8000, 0x9ffdc000, 0x2a66e000, 0x5037d000, 0x1f18d800, 0x61152c00, 0xb1898600, 0xe35d5b00,
+    0xd030f580, 0x5f1f12c0, 0x0116f5a0, 0x418e12d0, 0x0b5a75b8, 0xb432d2ec, 0xf9181582, 0xa015c2f5,
+    0x1f0cadab, 0xae1afedd, 0x679313bc, 0xf8c959f4, 0x02fcb808, 0x3ee33c34, 0x6671be00, 0x7d3ea72c,
+    0x5e052baa, 0x9d07a5d6, 0x1483e619, 0x36464b38, 0x62224d82, 0x3f112ee8, 0x2c8dcb90, 0xf7dd75ec,
+    0xe671be03, 0x3d3ea719, 0x3e052bbb, 0x6d07a5ce,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xe8000000, 0xc4000000, 0xf6000000, 0xd7000000,
+    0xae800000, 0xa0400000, 0xda600000, 0xecf00000, 0xc2480000, 0x974c0000, 0xc0ce0000, 0x450d0000,
+    0x5bad8000, 0xc07ac000, 0x7f24e000, 0xe9175000, 0x7f7be800, 0x55a19c00, 0xdf521e00, 0x621a8d00,
+    0xffd44a80, 0x795c28c0, 0xd9324aa0, 0x04ed28d0, 0x5099cab8, 0xb596e8d4, 0xde3eaa82, 0x864678e5,
+    0xc56422bf, 0xf67674d0, 0x6c0f3494, 0xb62b35f8, 0xb0b90822, 0x0607cc03, 0x6f027632, 0xe280d136,
+    0x8241349a, 0x136635e3, 0x51748816, 0x3a8d0c32, 0x3a6e960c, 0x58db8129, 0xcbf4dc9a, 0xd4caa9e1,
+    0x7b0b163c, 0x38ad4116, 0x60fe3c9d, 0x2460f9e4,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x18000000, 0xfc000000, 0xb2000000, 0xaf000000,
+    0x9f800000, 0xabc00000, 0x53a00000, 0x76d00000, 0x33480000, 0x8ccc0000, 0x970a0000, 0x7caf0000,
+    0x867c8000, 0x1f344000, 0xc0ffa000, 0x7a703000, 0x021a4800, 0x48423400, 0x8a632a00, 0x2a761700,
+    0xaa199180, 0x0c4043c0, 0xb46511a0, 0x2f7403f0, 0x669ab188, 0x8a0433ec, 0xe300f98e, 0x958607d3,
+    0x88c3d3a2, 0x662010f8, 0x8e124205, 0x4d6c5312, 0xfedd53b1, 0x686750ff, 0x2d736235, 0x719b2330,
+    0x99863b9e, 0xe2c214fc, 0x9523201d, 0xd3947003, 0x51ade828, 0xcefe040f, 0x07736219, 0xe29b2322,
+    0x14063bae, 0x960214e8, 0x41032012, 0xf284703b,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x38000000, 0x8c000000, 0x6e000000, 0x5d000000,
+    0xbe800000, 0x0c400000, 0xeae00000, 0x9a900000, 0x18d80000, 0xd65c0000, 0x121a0000, 0x7cfd0000,
+    0xec6b8000, 0x73b24000, 0xa46a2000, 0x37b35000, 0x4e6cb800, 0x60b2cc00, 0x77e8ee00, 0x6d719900,
+    0x1cc81d80, 0xf2c76340, 0xc6a39da0, 0x40752370, 0x6a49bd98, 0x7a867344, 0x26450596, 0x5de4bf67,
+    0x7315eb8d, 0x2d192670, 0x317ff622, 0xe3af4533, 0xfb95ebb8, 0xe0592665, 0xd31ff626, 0x747f4514,
+    0xe12debb4, 0x71d5266c, 0xe3bdf602, 0x150e4500, 0x53e46b89, 0x3e16667e, 0x4b9e5602, 0x613e5533,
+    0x5f4b7381, 0xb004ba48, 0x2801a01c, 0x5401100b,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x18000000, 0xec000000, 0x4a000000, 0x75000000,
+    0x1f800000, 0x14400000, 0x3ae00000, 0x3b300000, 0xb7680000, 0xfcec0000, 0x5eaa0000, 0x634b0000,
+    0x0efd8000, 0xe0554000, 0xab1e2000, 0xa1e67000, 0x2fb6d800, 0x4fafa400, 0x22cc4600, 0x39bab100,
+    0x32379880, 0x1aee3bc0, 0x81aa18a0, 0xf9cb7bf0, 0xcd3c38a8, 0x3af10bc4, 0x0cc8e0ae, 0x12b9afff,
+    0x0eb326bc, 0xe62d5ee2, 0x318f1e3b, 0x55dc5527, 0x50c7fe95, 0x1a25fad9, 0x2914d810, 0x4a78a422,
+    0xc093c629, 0xb938f105, 0x5cf638b6, 0x13ca0bef, 0xa83d60af, 0x1d70efd4, 0x248f06b7, 0x7a5c2ee5,
+    0xac86461d, 0x04c1b124, 0xac221897, 0x2e177be6,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x28000000, 0x64000000, 0xfa000000, 0xa3000000,
+    0xaf800000, 0x64c00000, 0x57a00000, 0x4db00000, 0xe0480000, 0x55cc0000, 0x220e0000, 0x022f0000,
+    0xb7598000, 0xb6b0c000, 0x93cfe000, 0xa70e3000, 0x98a82800, 0x911ad400, 0x5b563200, 0x859aef00,
+    0x6c127280, 0xf0f8b8c0, 0xb303f2a0, 0xe78478d0, 0xf0c212a8, 0x55a548e4, 0xa2b3baa2, 0xd1cf5cfd,
+    0x680a689c, 0x792b83d5, 0x84d83237, 0x6475ef0c, 0xf96bf282, 0x1c3878cf, 0x10a41292, 0x583648fe,
+    0x078c3a98, 0x7dec9cd3, 0x237a0881, 0xd1c673cf, 0x55207a30, 0xd7f2cb17, 0x6bad8888, 0x5699b3e9,
+    0x7b961a1b, 0xf83c3b2a, 0xaaa24092, 0xdb3157fe,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x38000000, 0xcc000000, 0xce000000, 0x63000000,
+    0xf4800000, 0x6a400000, 0x62a00000, 0xc4d00000, 0xd5c80000, 0xbf4c0000, 0x3e0a0000, 0xfc2f0000,
+    0x20bc8000, 0x02d54000, 0x22cf2000, 0x99cb5000, 0xb14b4800, 0xfd0a6c00, 0x78ab7e00, 0x72ffcb00,
+    0xac71fd80, 0x281e3bc0, 0x2f057da0, 0xfa877bf0, 0xa9405d88, 0xe6232bec, 0x96979586, 0x7b6c07df,
+    0xb59bcbb5, 0x88c49cc7, 0xb7e37e08, 0x74f3cb1d, 0x9c5bfdb0, 0xc6a13bdc, 0x4ed1fd8d, 0x2cce3bdf,
+    0x5acd7da4, 0x35cb7bcf, 0xaf4a5d91, 0xd60c2be4, 0x782b1597, 0x1ab947c0, 0x63d4eb9e, 0x7b4fccd4,
+    0x6408362d, 0x4d29a716, 0x3138839c, 0x0b12f0fc,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x68000000, 0x74000000, 0xda000000, 0x6b000000,
+    0xd5800000, 0x3f400000, 0xa3200000, 0xab300000, 0xfbb80000, 0x2c3c0000, 0x547e0000, 0x94df0000,
+    0xfdad8000, 0xddb6c000, 0x75796000, 0x2259b000, 0x1369e800, 0xa3d4c400, 0x0a68fe00, 0x09530700,
+    0x4aac2e80, 0xc633fb40, 0x8b39aea0, 0x07f93b50, 0x4e9ecea8, 0x6a0f8b44, 0x9f42a6b2, 0xb3218f59,
+    0xe3353884, 0xdfb83843, 0x9e3b7e31, 0x4b7ac70c, 0x9b58ceb9, 0xa9ec8b76, 0xab1126af, 0xe1084f79,
+    0x7ac1d8a8, 0x94674855, 0x0c13f63b, 0xb28bb325, 0xa087d885, 0x03c44868, 0x4ee07634, 0x74d27321,
+    0x61eb3892, 0xcf17385b, 0x730efe2f, 0x35c00707,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x08000000, 0x8c000000, 0xa2000000, 0x25000000,
+    0x97800000, 0x54c00000, 0x6fa00000, 0x7d700000, 0x6af80000, 0x137c0000, 0xe0be0000, 0xcd9d0000,
+    0xeba98000, 0x3e31c000, 0x1199a000, 0x61aab000, 0x67329800, 0xbc1da400, 0x946dc200, 0xb1139f00,
+    0x12aa8480, 0x23b72b40, 0x1c5b04a0, 0xa38aeb50, 0xef84a4b8, 0x00c15b74, 0x29a1bcba, 0xe6703f57,
+    0x727c5e92, 0x7938d05e, 0xee5de237, 0xae88ef0b, 0x0401bc8c, 0x6e003f44, 0xe7045eae, 0x2284d074,
+    0xcb43e20c, 0xb765ef00, 0xb0d03cbc, 0x328dff75, 0xee03fe8d, 0xa7036045, 0x4280fa30, 0x5b458b27,
+    0xbf625e9e, 0x3cd5d06a, 0x908c620d, 0xcb052f3d,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x98000000, 0x34000000, 0x3e000000, 0xeb000000,
+    0x6c800000, 0xedc00000, 0x0fa00000, 0x35700000, 0x42a80000, 0x3c2c0000, 0x70ee0000, 0xa6cd0000,
+    0x09fa8000, 0x5db64000, 0x828be000, 0xb41c1000, 0xb2224800, 0xd931ac00, 0x834f8200, 0xd1bd9f00,
+    0xa251e480, 0x841e99c0, 0x0a2364a0, 0x7d34d9d0, 0xd54e84b8, 0x96b9c9ec, 0xc4d04c9e, 0xbcdf25e1,
+    0x8200ae91, 0xc905eadb, 0x7582620a, 0x90408f20, 0xc3e72ca9, 0x649475fd, 0xc73d8681, 0x429316de,
+    0x583ec823, 0x7016ec06, 0xaef8e228, 0x8b36cf36, 0x424ccc84, 0xf83865e6, 0xa017ceb6, 0xa6febafb,
+    0xd7374a12, 0xd04a730b, 0x193d86ab, 0x199316ea,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0xa8000000, 0xd4000000, 0x9a000000, 0x9d000000,
+    0x94800000, 0x85400000, 0xf0a00000, 0x2bb00000, 0x45180000, 0xe79c0000, 0xb9de0000, 0xfef90000,
+    0x7b898000, 0x3ef4c000, 0x5dbe6000, 0xf82cb000, 0x76c55800, 0x2c627c00, 0x82d19600, 0xdf4af300,
+    0x4b955e80, 0x79ec0e40, 0xeb24dea0, 0xc374ce50, 0x667cbe98, 0x48cd7e54, 0x555666b2, 0x218ec261,
+    0x03f61094, 0x39394146, 0xc56ff631, 0xfa664327, 0x49d006ad, 0xc0ce727d, 0x315548a4, 0xe38e3d50,
+    0xf2f1e018, 0x4bbd701e, 0x932cb82a, 0x99430c13, 0xeea32e06, 0x64b0ff2f, 0x889ff0a8, 0xc458317a,
+    0x853d4e23, 0x2b6c4f09, 0x0d62a8b3, 0xf8564d64,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xf8000000, 0xac000000, 0x7a000000, 0xa9000000,
+    0xc2800000, 0xb0400000, 0x1ea00000, 0x68700000, 0xb6680000, 0x31ec0000, 0xd92a0000, 0x2ecb0000,
+    0xb29d8000, 0x88c24000, 0xc7e32000, 0x9456f000, 0xbe5ab800, 0x61262c00, 0x2cb66e00, 0x3b8cfb00,
+    0x04bfe880, 0x21f053c0, 0xd1a868a0, 0xff8913f0, 0xcabec8a8, 0xaef1a3c4, 0xe82d5096, 0x704a7fef,
+    0x70dc0688, 0xbb62e8c5, 0x4316a038, 0xed78b029, 0x8093981f, 0x0bbbdc03, 0xd871563a, 0x7e68973b,
+    0x95eaa6b2, 0x272a58db, 0xa9cd3820, 0x0f1f6c23, 0x2900ce09, 0x02844b2e, 0x90447097, 0xcea78ff3,
+    0x90733eb1, 0x1a6a84c0, 0x4be9ee20, 0x7029bb31,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x98000000, 0x7c000000, 0x0a000000, 0x7d000000,
+    0xf7800000, 0x31400000, 0x2be00000, 0x4bf00000, 0x3d280000, 0x3bac0000, 0xaf6a0000, 0x54cd0000,
+    0xb95e8000, 0x1bc24000, 0xbd25a000, 0x5953f000, 0xc93f1800, 0xa3772400, 0xa2e86e00, 0x408fd100,
+    0xf93f5e80, 0x1b751fc0, 0x4eebdea0, 0xa28a5ff0, 0x6038fe98, 0x9af7efec, 0x08a846be, 0x03ee3bf3,
+    0xed09b0bc, 0xbd788eec, 0x53112001, 0xda5cb020, 0xbf44382b, 0x90e69402, 0xb372d61c, 0x8aeb0510,
+    0x44882891, 0xff3deada, 0xf474ee1d, 0xb86c9133, 0x6e4e7e97, 0x0799afc5, 0x0d67e691, 0x4f30cbcc,
+    0x2e8828aa, 0x323deace, 0x7bf4ee26, 0x852c9111,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x88000000, 0xf4000000, 0x06000000, 0xfd000000,
+    0x7b800000, 0x93400000, 0x99200000, 0x95300000, 0x3a480000, 0xa0cc0000, 0x4a0e0000, 0x836b0000,
+    0x65fb8000, 0x5847c000, 0xc7a46000, 0xa1f65000, 0xd22cb800, 0xdfdbe400, 0x97f13e00, 0xf72b5700,
+    0x78594e80, 0xe6b434c0, 0x1d0ccea0, 0x9fe8f4d0, 0x323b2e88, 0x4aa564d4, 0xa275f6aa, 0x8d6fd0e9,
+    0x2cfdf0ab, 0x85c3a3d6, 0xb9e26035, 0xcb515032, 0x20593833, 0x7ab72407, 0x5f0ede15, 0xfceac72b,
+    0xc8b996a5, 0x506580f6, 0x5317489d, 0x07ff47cf, 0x6b46de1a, 0x1526c707, 0x5f37968a, 0xad4e80f8,
+    0x5c4cc8a8, 0xadc887c6, 0x098abe30, 0x7c2c9702,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x08000000, 0x74000000, 0x62000000, 0xb1000000,
+    0x94800000, 0xf8c00000, 0xbb600000, 0x45900000, 0x16c80000, 0xb14c0000, 0x9c0a0000, 0x23290000,
+    0x61188000, 0xe320c000, 0xc4376000, 0x093a7000, 0x4e90f800, 0xbf4fdc00, 0xa3095a00, 0x48aba100,
+    0x92585d80, 0x9b0703c0, 0xf182dda0, 0x5e42c3f0, 0xcaa73db8, 0x167173fc, 0xbd9825ba, 0xcfe41fe1,
+    0x4d56e7a2, 0xc5aa12cd, 0xb0df1807, 0xe0c56c33, 0x5766c209, 0x0b920d12, 0x89cbff94, 0x8aca7ef2,
+    0x574b5a11, 0x270ea117, 0x42aadda9, 0x675ec3ff, 0x65853d87, 0xcc4473e2, 0x13a2a5b8, 0xc6f1dfe1,
+    0x2f5b07aa, 0xb185a2d0, 0xfe42003a, 0x9aa5003d,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x48000000, 0x6c000000, 0xde000000, 0xb9000000,
+    0x5f800000, 0xeac00000, 0xf3e00000, 0x36100000, 0xad880000, 0xe50c0000, 0x614a0000, 0x2be90000,
+    0x2ade8000, 0xb3a4c000, 0xfeb3a000, 0xe3bb5000, 0x06f19800, 0x0f1ee400, 0xfac2f200, 0x8be08d00,
+    0x72170d80, 0xef8a09c0, 0xca0b8da0, 0xebcbc9f0, 0x40acadb8, 0x8afd59cc, 0x29d015aa, 0x8fec2dd7,
+    0x58d8df9d, 0xb4a514f9, 0xe836b80a, 0x1ef87403, 0x73d64a16, 0x14edf93e, 0xd85dc7a5, 0x9ce630fb,
+    0x1c916a0f, 0x56cb691a, 0xfd297f8a, 0xdf3b44d4, 0xe8b3a008, 0xf6bb5001, 0xe7719828, 0xacdee436,
+    0x1ea2f217, 0x3b308d1e, 0xf27f0d9d, 0x859609ed,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x78000000, 0x5c000000, 0x66000000, 0x49000000,
+    0xf5800000, 0xbf400000, 0x3e600000, 0x69900000, 0x6b380000, 0x79bc0000, 0x7e7a0000, 0x20dd0000,
+    0x6f6a8000, 0xcb214000, 0xc0f7a000, 0x022a9000, 0xce43d800, 0xb7e1f400, 0xa0d6ce00, 0x945d5d00,
+    0xc1ad6180, 0x90035040, 0x4805e1a0, 0xc4031070, 0xca02c198, 0x5705c04c, 0xe0843986, 0x2cc3e45b,
+    0xc8248f9f, 0xa274dd49, 0xbdec7822, 0x2ce76426, 0x6e57160d, 0x359da90c, 0x360b2faf, 0xda324d6e,
+    0x2b0da00e, 0xadb79001, 0x07495814, 0xb950b42d, 0x15196e3f, 0xfacbcd29, 0xe214b984, 0xf17fa45b,
+    0x4c59afb2, 0x4daf0d6d, 0x9e000017, 0xd5000008,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xc8000000, 0x3c000000, 0xda000000, 0x85000000,
+    0xd2800000, 0xf4c00000, 0x8fe00000, 0x3e900000, 0xfef80000, 0xcf7c0000, 0x763a0000, 0x3a990000,
+    0x9da88000, 0xeee6c000, 0x52132000, 0xf13bf000, 0x0918d800, 0xc5e9cc00, 0x0ec32a00, 0x9ae33500,
+    0x74145180, 0xce3d6e40, 0x6e9ed1a0, 0x2baeae70, 0x49e771b8, 0xf1969e7c, 0x317e098a, 0x2d3b6273,
+    0x231e5bbc, 0xe8ecab52, 0xd042d808, 0x7c20cc2f, 0x42f3aa0b, 0xf829f510, 0xe1a571ac, 0xb9b39e6f,
+    0xf58c89a3, 0xba14a25e, 0x5d3dfbaa, 0x4b1d9b48, 0x84eb2038, 0xf247f006, 0x0d22d80f, 0x7670cc29,
+    0x53ebaa1f, 0x39c5f50d, 0xa167718e, 0x70569e70,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0xe8000000, 0x24000000, 0xc6000000, 0x73000000,
+    0x55800000, 0x82400000, 0x92600000, 0x2dd00000, 0x7b880000, 0x4f0c0000, 0xa5ce0000, 0xa76f0000,
+    0xd11e8000, 0x2861c000, 0x14d4e000, 0x9f08d000, 0x1dce4800, 0x7b6f6400, 0xeb1d3600, 0x51610500,
+    0xd0551b80, 0xfdce7fc0, 0x2b6d9ba0, 0x131cbfd0, 0xad61fba8, 0xda56afdc, 0x6ccbd392, 0xcbefdbd5,
+    0xb7dacda3, 0xe8c4aac9, 0xe7a64836, 0xa8f36429, 0xd2bb3632, 0x26920532, 0xa56d9ba4, 0x341cbfed,
+    0xf6e1fb97, 0x3f16afdd, 0x852bd39a, 0x337fdbc7, 0xcdb2cdb0, 0x7b58aac9, 0xfe004822, 0xef006400,
+    0x4f83b633, 0xcb40c537, 0xbee1fb82, 0x6b16afe6,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xf8000000, 0xa4000000, 0x02000000, 0xf3000000,
+    0xa2800000, 0xb1400000, 0xf5200000, 0x29700000, 0x4d080000, 0x0a8c0000, 0x004e0000, 0x29ad0000,
+    0x103f8000, 0x39204000, 0x57716000, 0x280b7000, 0xf10be800, 0xbc8e7c00, 0x694f3600, 0x8c2f3f00,
+    0xf9f9a480, 0x2a4174c0, 0x2ba024a0, 0x0e3034d0, 0xe128c4b8, 0x2e7a04c4, 0xf883cc86, 0x964248ed,
+    0x9da1f286, 0x67343bd1, 0x44abe82e, 0xc7be7c3d, 0xebe73633, 0xea933f27, 0xbb9fa4b8, 0x771074f1,
+    0xd659a4a6, 0x567174c2, 0xf18824ba, 0x8ecc34e4, 0xf6eec4b9, 0x181b04eb, 0x1fd24cb8, 0x5cbf08f2,
+    0x35671299, 0xcdd30bff, 0x17bf601a, 0x53e67035,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x28000000, 0x24000000, 0x6a000000, 0xb9000000,
+    0xa8800000, 0xcb400000, 0x28e00000, 0x1b100000, 0x22b80000, 0x153c0000, 0xe1fa0000, 0x49df0000,
+    0x18eb8000, 0x6662c000, 0xe757e000, 0xd1dab000, 0x44ee7800, 0xa061bc00, 0x8057a600, 0x825d4100,
+    0xba2e8980, 0x8141f440, 0xc1e709a0, 0x3b903470, 0x5df96988, 0x7fdb4454, 0x67e97182, 0xa9e1885d,
+    0x7f96cf98, 0x07fa0573, 0x7eddf832, 0xc36f7c01, 0x2ca24618, 0x8474f137, 0x0d6971b8, 0x3fa18856,
+    0x35f6cfaa, 0xfeaa057d, 0xf405f81e, 0x22037c2e, 0xad004627, 0x7a87f111, 0x7e40f18e, 0xce604875,
+    0x0350afa6, 0x1bdd755b, 0x6defe008, 0x20e6b025,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xc8000000, 0xa4000000, 0x62000000, 0x51000000,
+    0x8b800000, 0xa1400000, 0xd7e00000, 0x4e900000, 0x5f080000, 0x3b8c0000, 0x7e4e0000, 0xac6b0000,
+    0x90dd8000, 0x2363c000, 0x6352e000, 0xf92bf000, 0xad387800, 0x38f0a400, 0x05daee00, 0xfae1f100,
+    0x2b15d580, 0x194905c0, 0x4cee55a0, 0x271dc5d0, 0xc8473588, 0xa062f5e4, 0x41d62dba, 0xcfed61cd,
+    0x059ddba2, 0xfe8304d9, 0x08c3f800, 0x87a46431, 0xc2f38e15, 0x78dec132, 0x1764cdaa, 0x695691c8,
+    0xdc2da3a7, 0x8cbfa0da, 0x6cb71633, 0x3bbe9532, 0x4433db9b, 0x287804dd, 0xcd167815, 0xba4ba430,
+    0xfe6f6e1b, 0x79de3125, 0x14e135b9, 0xcc15f5d2,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x78000000, 0x0c000000, 0x16000000, 0xb1000000,
+    0x69800000, 0xe8c00000, 0x3b600000, 0x67900000, 0x38480000, 0xe08c0000, 0xe3ee0000, 0x68790000,
+    0xf6348000, 0xdfbb4000, 0x59d52000, 0xcf6c9000, 0xb33d9800, 0x62959400, 0xebcff200, 0xff4eb900,
+    0xc80e1d80, 0xfaab4bc0, 0x07db3da0, 0xc4c7dbd0, 0x3d66a598, 0xfe924ffc, 0x95c95786, 0x6a4cf6ff,
+    0x738f4a03, 0xc16bbd13, 0xfe3a77b9, 0x851566f9, 0x0e08522f, 0xc3ac691c, 0xfa5c25a7, 0x86000fdb,
+    0xd900f79c, 0x4d8726d2, 0x3ac1f218, 0xe867b91c, 0xa5129da1, 0x1e0c0bf3, 0xeba81d8a, 0x3e5e4be0,
+    0xe401bdb4, 0x72059bf1, 0x83070598, 0x0a859fec,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x18000000, 0x3c000000, 0x1e000000, 0xb3000000,
+    0x66800000, 0x92c00000, 0x58a00000, 0x4cb00000, 0xbd380000, 0x5ffc0000, 0xef5a0000, 0xb7ef0000,
+    0xa0d38000, 0x762f4000, 0x6ef4a000, 0x805b5000, 0x3f6d4800, 0xa9113c00, 0xfc090a00, 0x8a849f00,
+    0xf4c5b280, 0xe7a06740, 0xbc3112a0, 0xb8fb3770, 0x43dc5a88, 0x9c2a0b54, 0x87f5508e, 0x47de945b,
+    0xee28e209, 0x92f2f337, 0x3e5bf0b0, 0x1c6ac463, 0xd7962a2e, 0x52cc8f24, 0xcc265a8f, 0x0b750b68,
+    0x3c1ed0bd, 0x710dd472, 0x0f064225, 0xb886a30c, 0x81c53890, 0xae24b848, 0xc6738030, 0xd99f403a,
+    0x0d4ca01a, 0xe167500e, 0x8e974824, 0xdd4e3c04,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x18000000, 0xc4000000, 0xaa000000, 0xa5000000,
+    0x5e800000, 0xae400000, 0xc5200000, 0x3d100000, 0x8f280000, 0x896c0000, 0x004a0000, 0x2b590000,
+    0x3f738000, 0xff9f4000, 0x6e136000, 0xdaaf3000, 0x16a99800, 0xc0ac2c00, 0x3bae3e00, 0x22292700,
+    0xabee5780, 0xf00e82c0, 0xa97d37a0, 0x25e1b2f0, 0xee74afb8, 0x831d9ee4, 0xd95291be, 0x7f08b9d5,
+    0x92fec614, 0xb5233b3c, 0xd51271a3, 0x6328c9e7, 0xaf6c3e2a, 0xd34c270c, 0xbedfd7b7, 0xc0b4c2fb,
+    0x6f7fd7a3, 0x66e4c2cd, 0x03f7d78f, 0xe8d8c2d5, 0xfbb5d79e, 0x16fdc2df, 0xbf2457b4, 0x401782c0,
+    0x75aeb7bf, 0xdd2ef2c4, 0x786fcf88, 0x8cceaee0,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x58000000, 0x74000000, 0xb6000000, 0xd3000000,
+    0x93800000, 0x4e400000, 0x9e200000, 0x16b00000, 0x68a80000, 0xb2ec0000, 0x8ace0000, 0x477f0000,
+    0xe0548000, 0x86fa4000, 0x8317e000, 0x245d5000, 0xa2674800, 0xd5959400, 0x1a9e0600, 0x9980a700,
+    0x1747da80, 0x14a14bc0, 0xf2703aa0, 0x624c1bd0, 0xf6bf72a8, 0xd2358fcc, 0x1b6f74be, 0xe58a28d1,
+    0x57dc2e13, 0xfc212325, 0x43b3f497, 0x502c68e6, 0x6b2dce18, 0xb4af732f, 0x68ee3cbc, 0xbbccbcd2,
+    0xc1f8a80d, 0xb694c426, 0xb11f4e02, 0xf3c63331, 0x17635c92, 0xfd14acdb, 0xcb5c8038, 0xfbe6402d,
+    0xa2d1e000, 0x5e3e5018, 0x43f5c818, 0x590cd416,
+    0x80000000, 0xc0000000, 0x20000000, 0xf0000000, 0x88000000, 0xec000000, 0xb2000000, 0x0b000000,
+    0x4a800000, 0x07c00000, 0x4fa00000, 0x2d700000, 0x56180000, 0x45dc0000, 0x1c7a0000, 0x440b0000,
+    0x11948000, 0x9a0cc000, 0x6891a000, 0xfb8f3000, 0xd5d3e800, 0x3be97c00, 0x5b06e200, 0xf286af00,
+    0x43c1ac80, 0xe1a12040, 0x1c700ca0, 0xfb9e1070, 0xba9be4a8, 0x5f1b6c4c, 0x6c5f068a, 0x6d3ac377,
+    0xc6682a26, 0x1c402335, 0xf5e70694, 0xc596c344, 0xac0a2a2f, 0xed972303, 0x700986b0, 0xf7910365,
+    0x670f0a11, 0x8714d301, 0x87cbcea1, 0x30374f53, 0xa67a001c, 0x630b0012, 0xc914801b, 0x66ccc032,
+    0xe5b1a018, 0x3d3f3001, 0x7e6be811, 0x58457c2c,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xc8000000, 0xc4000000, 0xde000000, 0xbb000000,
+    0xdf800000, 0x72400000, 0x8ae00000, 0x79b00000, 0xad580000, 0xd11c0000, 0x08fe0000, 0x1acd0000,
+    0xc3d58000, 0x2f2fc000, 0x9be7e000, 0x3b341000, 0xc7984800, 0x343f7400, 0xe0ee0600, 0x3a47c100,
+    0x0ee58480, 0xc7b17a40, 0x065a64a0, 0xc6996a50, 0xbebc2cb8, 0x4e2b1e54, 0x0167aa8a, 0x5df31f65,
+    0x38bdce3d, 0xb92a750b, 0xa4e1e2aa, 0x5ab16b47, 0x1ede4830, 0xa95e740d, 0x2f1d8611, 0xc3f9011c,
+    0x1d49e48e, 0xbd97aa41, 0xbfc84cac, 0x8751ce59, 0xf2eb82b0, 0xbb46bb7f, 0xc467e00d, 0x09741035,
+    0x2d784829, 0x5d8f7429, 0x85b6061c, 0x2f5bc139,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x18000000, 0x14000000, 0xa6000000, 0xb9000000,
+    0x0c800000, 0x3dc00000, 0xcfe00000, 0x5a700000, 0xf9a80000, 0x3a6c0000, 0x788a0000, 0x787d0000,
+    0x0d158000, 0xf8194000, 0x5523e000, 0x0413d000, 0xdc9f9800, 0x94e4bc00, 0x71f7b200, 0x95ed9d00,
+    0x6acc9680, 0x65dd87c0, 0xaac776a0, 0xb66257f0, 0x3932ee98, 0x048bebf4, 0x0278dc9e, 0xfe1336f1,
+    0xef9daa37, 0x6360611f, 0x57b0c494, 0xde4fcae0, 0x7c1e783f, 0x2b266c02, 0x4917aa1f, 0x661d613d,
+    0x08254496, 0xa6968acc, 0x545d9819, 0xe585bc26, 0x0940320d, 0x1625dd24, 0x3b90f68a, 0x16da17d7,
+    0x7d468e8a, 0x60207bef, 0x7a9324a1, 0x1e5c1afd,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x28000000, 0xe4000000, 0x0a000000, 0x3b000000,
+    0xbd800000, 0x61400000, 0xf9200000, 0xb4700000, 0xc2e80000, 0x91ac0000, 0xdf8e0000, 0x987b0000,
+    0x9ad78000, 0x5cdac000, 0x0fe3e000, 0xc710b000, 0xba3ae800, 0xf273c400, 0x57ec2e00, 0xbd2ed700,
+    0xa84e5c80, 0x959cefc0, 0x52c5bca0, 0xd9605fd0, 0xd3d15488, 0xf6589bf4, 0x0d22fa82, 0xd6708ccd,
+    0xbde94600, 0xb62bd303, 0xadcf928f, 0x38d988cb, 0x45e68839, 0x5c15b412, 0x97bb260b, 0xbb36a33b,
+    0x4acf1a9c, 0x035b3cce, 0x51a42e3d, 0xb9b2d726, 0xec085ca5, 0xb83befd8, 0xfd743ca8, 0x686d9ff8,
+    0x936b34a4, 0xcee9ebeb, 0x9fac7288, 0xde8938f4,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0xc8000000, 0x0c000000, 0x42000000, 0x33000000,
+    0x55800000, 0x59400000, 0xeca00000, 0x5c900000, 0xc0180000, 0x375c0000, 0xbafe0000, 0x28ed0000,
+    0xb8378000, 0xb08dc000, 0x8543a000, 0x26a4b000, 0x0396a800, 0x879bc400, 0x9519ae00, 0x0fd8fb00,
+    0x6f3af780, 0xa78f5340, 0x8ec157a0, 0xa6e7e350, 0xd5b1ffb8, 0x5dcd2744, 0xdfe1d18a, 0x9f351c47,
+    0x230f061a, 0xc1033f0b, 0xbe8359af, 0xa8c7a84d, 0xabe3a02c, 0x0934b01e, 0xf60ea816, 0xb987c414,
+    0x0b47ae02, 0x77a5fb05, 0x5515779b, 0x135e9370, 0xe4fcf7a4, 0xf1ee5364, 0x82b0d7a2, 0x364b235d,
+    0x0fa3df90, 0x8115574d, 0xf55d59ab, 0xc9faa843,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0xc8000000, 0xdc000000, 0x0e000000, 0xc7000000,
+    0x36800000, 0xc2400000, 0x94200000, 0xfa900000, 0xea080000, 0x6f4c0000, 0x8dee0000, 0x55390000,
+    0x1b108000, 0x46cac000, 0xfd2a2000, 0xc95f5000, 0x98a7e800, 0xbdd59c00, 0x99a92e00, 0x82181f00,
+    0x1d020380, 0x83879fc0, 0xedc623a0, 0x5fe1cfd0, 0x8e714b98, 0x94fe93dc, 0x22f245aa, 0x03b9dceb,
+    0xa957ae29, 0xfaebdf1a, 0xebb8a384, 0xe5520ffa, 0xecebebb9, 0x08bb03d8, 0xc9d78d89, 0x3baf10f8,
+    0x631f681b, 0x00835c2b, 0xb1450e33, 0xa0a24f2b, 0x09d36b9c, 0x9badc3db, 0xb31badb7, 0xf88540f6,
+    0xa5460033, 0x72a50025, 0xc0d6800e, 0x6a2fc016,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x78000000, 0xcc000000, 0xbe000000, 0x87000000,
+    0xbc800000, 0xa0c00000, 0x3ae00000, 0xf6900000, 0x33c80000, 0xac0c0000, 0x866e0000, 0x3e3f0000,
+    0xb8148000, 0xea0b4000, 0x8d6b2000, 0x1cbdd000, 0xefd36800, 0x146acc00, 0x173c6a00, 0xab952d00,
+    0x324dd180, 0x1d4bd3c0, 0x25c8f1a0, 0xaf0903d0, 0x10ef19a8, 0x1bf88fcc, 0x7f7053b6, 0x4bdc72ff,
+    0x2600ea19, 0x5b026d1e, 0x2a80f196, 0xe3c503e6, 0x8c6119b0, 0xa3578fd7, 0x8cacd3aa, 0xc1db32d3,
+    0x9305ca3a, 0xfe80bd0e, 0xc1c719b4, 0xbd648fed, 0x2cd6539e, 0xe2ef72f3, 0x62fa6a31, 0x64f62d0f,
+    0x279f5188, 0xc42393c3, 0x5b7151a3, 0x51dc93f4,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x18000000, 0xf4000000, 0xd2000000, 0x33000000,
+    0xc8800000, 0x89400000, 0x06e00000, 0x87300000, 0x6fc80000, 0x1d8c0000, 0x7fee0000, 0x379d0000,
+    0xefb78000, 0x968b4000, 0x336ae000, 0x44dd7000, 0x66556800, 0xe73d6c00, 0xc0e04600, 0x76344100,
+    0xec4cf080, 0xf84d12c0, 0x3a4810a0, 0x714d62d0, 0x1dcaf8b8, 0x5e8b4ec4, 0x9f685ebe, 0x02de7ff9,
+    0xd757c60a, 0x04bf0135, 0x75261098, 0x2b9062c7, 0x5e9d78a1, 0xd8300ee2, 0xc14abea4, 0x15cf0fcc,
+    0xd28cae09, 0xe96f6d30, 0xfbd9d694, 0xd8d363eb, 0x977d6813, 0xc9816c3d, 0x62c6462e, 0x3f25411b,
+    0xac9570b0, 0x241b52e9, 0x3275708f, 0x172b52fc,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x18000000, 0x14000000, 0xde000000, 0x89000000,
+    0x92800000, 0xc2400000, 0x1ea00000, 0x28b00000, 0x08980000, 0xcbdc0000, 0x5bfa0000, 0xb30d0000,
+    0xce348000, 0xe4d84000, 0xa47ae000, 0xc5cd1000, 0xbfd7d800, 0x864e0c00, 0xdb127e00, 0x54a9db00,
+    0x4c06a680, 0xea027e40, 0x870646a0, 0x33826e70, 0x5cc51e98, 0x16e4227c, 0x6115809e, 0x3bace979,
+    0x9386fe29, 0x4cc19b1b, 0xdee446ad, 0x5d136e5b, 0xe9ab9eb2, 0xd081626d, 0x8943e08e, 0x0725b956,
+    0x1371c629, 0x1dff871d, 0xee0d60a9, 0x62b0f946, 0x9f9fa63b, 0x305ad731, 0x3b38588f, 0x77efe551,
+    0xec200004, 0x1af00001, 0xee38003f, 0xc76c0005,
+    0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xc8000000, 0xc4000000, 0xa6000000, 0x41000000,
+    0xcb800000, 0x2e400000, 0xe8200000, 0x1ff00000, 0x78980000, 0xe3dc0000, 0xee7e0000, 0x7acf0000,
+    0x37f28000, 0x6c98c000, 0x1dd8e000, 0x137ed000, 0x1b485800, 0x3ab5c400, 0xa838d600, 0xa6ea8700,
+    0x66064080, 0x21015040, 0x5b80a0a0, 0x56408050, 0xe42278a8, 0x7df1847c, 0x9f9c4e9a, 0x695ad34d,
+    0x0bb8d633, 0xbcaa871d, 0xc02640a1, 0x0bf15046, 0x8698a0b3, 0x1edc807d, 0x8ffc7897, 0x778e8458,
+    0xf356ceb7, 0xd7ae1367, 0x68a63621, 0x29375727, 0x7a7a988e, 0xc4cf547f, 0xeaf41684, 0xbd1f1772,
+    0xd8980031, 0x13dc0020, 0x067e0025, 0x0ecf001c,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x38000000, 0x5c000000, 0x02000000, 0x73000000,
+    0x89800000, 0xc2400000, 0x25a00000, 0x38f00000, 0x84b80000, 0xb1fc0000, 0x73de0000, 0xb06f0000,
+    0xdff58000, 0x7b384000, 0x46bca000, 0xa2ffb000, 0x8a5fa800, 0x1a28a400, 0x9e559600, 0x1dca4b00,
+    0xb3060480, 0xe982f540, 0xb244a4a0, 0x4da24550, 0x5cf68ca8, 0xdabea144, 0xc0f9baa6, 0x89585a53,
+    0xfbab9626, 0x38154b0f, 0x666b84a4, 0xfaf6b56f, 0x97be0485, 0x487ef565, 0xd99aa4a2, 0xf1cd454a,
+    0xb9030cb0, 0x8e86e16d, 0x0dc51aa5, 0x9ae7ea5f, 0xddd43e1f, 0xd88def09, 0x592612b5, 0x6e30fe7d,
+    0xd3de0007, 0xa06f003e, 0xc7f58026, 0x77384025,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x78000000, 0xb4000000, 0x2e000000, 0x45000000,
+    0xc6800000, 0x29400000, 0x6a200000, 0xb3900000, 0xaf280000, 0x5f6c0000, 0x71ce0000, 0x841f0000,
+    0x04938000, 0x8eac4000, 0x4fafe000, 0x0f2d9000, 0x8f6a3800, 0x29cb4c00, 0xa01c6600, 0x5293e700,
+    0x7fa92380, 0xa72e46c0, 0x6368c3a0, 0x23ccd6d0, 0xb3197ba8, 0x6517daf4, 0xbe6cfdb6, 0xa14aadd9,
+    0x7c5a663d, 0x1630e708, 0x297ca3ac, 0xf82106d2, 0xe492a39e, 0x7eae06ca, 0x87a923a3, 0x532e46d4,
+    0x6d68c3aa, 0xf6ccd6c9, 0x0d997b91, 0xf857daf1, 0xfa4cfda9, 0x57daadd4, 0x15f2663b, 0x601ce725,
+    0x3292a384, 0xcfae06d7, 0x4f2923ae, 0xaf6e46c8,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x78000000, 0x24000000, 0x02000000, 0x23000000,
+    0xc5800000, 0x5fc00000, 0xe0200000, 0xcbb00000, 0x8cd80000, 0xb21c0000, 0x14ba0000, 0x15490000,
+    0x6e708000, 0xabff4000, 0xde2c6000, 0xd4a17000, 0xfaf6e800, 0xcabc8400, 0xd04b9a00, 0xeaf22b00,
+    0x22b9be80, 0xdc484c40, 0x84f7dea0, 0x5fbc3c70, 0x1ccbb6b8, 0x3fb6f87c, 0x06dccca6, 0xad1ae375,
+    0xc73ffa26, 0x138f5b3d, 0x4cd556b7, 0xf80dc85a, 0xce14c4b7, 0x88ad5754, 0xf6e46812, 0x0116c43d,
+    0xd32d7a29, 0xcc251b09, 0x35b3b69b, 0xd9daf870, 0x1e9ecc9b, 0xf1ffe359, 0x492d7a26, 0x3b251b31,
+    0xea33b6bb, 0xb11af84c, 0x413eccad, 0x628fe352,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x78000000, 0x2c000000, 0x42000000, 0x0d000000,
+    0xa8800000, 0x74c00000, 0x87e00000, 0xfe700000, 0xef280000, 0x4cec0000, 0x528e0000, 0x1abf0000,
+    0x2fb48000, 0xa44b4000, 0xf05c2000, 0x29453000, 0x37a3d800, 0x6f54dc00, 0xebbcda00, 0x8d341500,
+    0xa98c5e80, 0xc1386bc0, 0x94f67ea0, 0xd6ee5bd0, 0x438f26a8, 0xa83ec7cc, 0x0a735cb6, 0xb128a2c7,
+    0x5beefa26, 0xab0e2504, 0xfcfb06ac, 0xfd97f7c9, 0x375e84a5, 0x98c37efa, 0xa5e6a008, 0xa371703e,
+    0x4fab7832, 0x6c2aac20, 0xbb6b220b, 0xabc9f90b, 0x651d5ca2, 0x34e7a2e8, 0x51f27a05, 0x4a69650c,
+    0x09492692, 0x68ddc7eb, 0x0581dcb0, 0xec40e2cc,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0xf8000000, 0x2c000000, 0xf2000000, 0x91000000,
+    0xab800000, 0x2a400000, 0x5b200000, 0x2b100000, 0x51380000, 0x707c0000, 0xa5da0000, 0xa10f0000,
+    0xca518000, 0x249a4000, 0xa4a92000, 0x3685b000, 0x63c6b800, 0xb864a400, 0x27b65600, 0x5a6cff00,
+    0xc7657f80, 0xa7354240, 0xeeae5fa0, 0xeb83f270, 0x4a436788, 0x2b221644, 0x031591b6, 0x853d194f,
+    0xae7d760a, 0xc6da4f2b, 0x9b884780, 0x4b94a651, 0x55f8a99e, 0xd49cfd5f, 0x4cab801c, 0x4285403c,
+    0x6dc0a017, 0x2363f00b, 0x3135981b, 0x29ae1418, 0x27016e30, 0x7c821b1e, 0xbec20996, 0x91e00d42,
+    0xb4f79828, 0x7ecd141a, 0x1832ee1c, 0xce2b5b1c,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xf8000000, 0x54000000, 0xee000000, 0xe7000000,
+    0x72800000, 0x53c00000, 0x05a00000, 0xe6500000, 0xb9b80000, 0x177c0000, 0xb95e0000, 0xf14d0000,
+    0x30128000, 0xd5594000, 0x6b4de000, 0xa9139000, 0x48dc2800, 0xe609fc00, 0x45770200, 0xa22a7f00,
+    0x2c634580, 0xcaf24440, 0x5a68a5a0, 0x8780d450, 0x54400db8, 0x11e1686c, 0x97b66f86, 0xde0cc779,
+    0x3176623d, 0xdc2daf00, 0xc3600d81, 0x14716844, 0xb3ae6fb8, 0x8b20c751, 0x27906230, 0x891caf3d,
+    0xd6ac8dbb, 0x84a5287d, 0x9fd10fa8, 0x42fa1749, 0xb819aa09, 0x372ac327, 0xdbe1a791, 0x26b7ab5e,
+    0x7f89c81f, 0x0e366c0e, 0x954d2a2d, 0x06128321,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x28000000, 0xec000000, 0xa6000000, 0x31000000,
+    0x4c800000, 0x16400000, 0x8ee00000, 0x5e900000, 0x2ed80000, 0x941c0000, 0xfcbe0000, 0xb4cf0000,
+    0x90838000, 0x4846c000, 0x5be3a000, 0xc8179000, 0x879a9800, 0x8b7dbc00, 0x5ee86e00, 0x33b02900,
+    0x986bd580, 0xd2f78740, 0xdb0bf5a0, 0x6ea6d750, 0xa972cda8, 0x60ccfb74, 0xb2803ba2, 0x13416e4f,
+    0x1063800b, 0x8bd6c003, 0xbfbba038, 0xeb4b9016, 0x91449813, 0x9b62bc25, 0xc853ee12, 0x807ae93b,
+    0x5d6e7584, 0x2c73175a, 0xbe4ced93, 0x47c2ab5d, 0x122283ae, 0x4731825c, 0x0a2cd60e, 0x3613c520,
+    0x829903af, 0x15fb425d, 0x8ba97616, 0xa2d7550a,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x98000000, 0xc4000000, 0xf6000000, 0x8b000000,
+    0x9b800000, 0x35400000, 0x4ee00000, 0x81d00000, 0xc1280000, 0xd7ec0000, 0x184e0000, 0x197b0000,
+    0x26828000, 0xc5c24000, 0x70266000, 0x5274d000, 0x52190800, 0xa5151400, 0x738c9e00, 0xc65a5900,
+    0x89748f80, 0x819a1ac0, 0x5c506fa0, 0xa76c8ad0, 0x668f0788, 0x6add4edc, 0x85b291ae, 0x2f3e03ed,
+    0xdfe48013, 0x7b55400f, 0x76eae015, 0x7acd9012, 0x6abde82e, 0x7da3843e, 0x3c33f638, 0x8f7b9d3a,
+    0x7d811983, 0x564557de, 0x29607e0c, 0xb690c932, 0x6acde78b, 0x82bcdeda, 0x81a17981, 0x3e3687eb,
+    0x6a7df628, 0xa9009d03, 0x0e83999e, 0xd9c717dc,
+    0x80000000, 0x40000000, 0xa0000000, 0x10000000, 0x68000000, 0x84000000, 0x2a000000, 0x59000000,
+    0xff800000, 0xf1400000, 0x37e00000, 0xb9900000, 0xb6080000, 0x4ccc0000, 0x386e0000, 0xb31b0000,
+    0x95038000, 0x0184c000, 0x4a42e000, 0x0566b000, 0xf554f800, 0xada84400, 0x173fee00, 0x9fb50100,
+    0x81bb0580, 0xa977c9c0, 0x0b1a65a0, 0x9905b9d0, 0x1f847d88, 0x41474dd4, 0x4fe16b92, 0x559108f5,
+    0x180e0018, 0x3fcb0023, 0x9eeb8007, 0xbdd8c00f, 0x53a4e022, 0x8ff1b025, 0x45d97815, 0xffa78417,
+    0x81f68e11, 0x26db712d, 0x31231d8b, 0x0b323dd1, 0xca7af3b4, 0xe6503cee, 0xfb2c760c, 0xc1f83512,
+    0xa51773bd, 0xc9cffce8, 0x51ed163a, 0x7d5a4500,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xf8000000, 0x84000000, 0xca000000, 0x1d000000,
+    0xf1800000, 0x18c00000, 0xd6200000, 0x83f00000, 0x0d180000, 0x565c0000, 0x41be0000, 0x4fed0000,
+    0xd4c58000, 0x50264000, 0x58f06000, 0x479d3000, 0x14195800, 0x2ddb1400, 0x247cf200, 0x784c9300,
+    0xb7f7a480, 0x0f186040, 0x475a44a0, 0x163f1050, 0xfc2d7cb8, 0xa0643454, 0xed955686, 0x7789f375,
+    0xad506034, 0x15ad3032, 0x6ca15828, 0x69b71424, 0x7d7af205, 0x63cd9300, 0xc23424b8, 0xc0bf2074,
+    0xa069a4ba, 0x83056070, 0xfe87c4ab, 0x00455066, 0x1d631cb6, 0x2c140476, 0xe7498e82, 0x1774a76f,
+    0x205cf227, 0x32bc9324, 0x196fa4aa, 0xc8846043,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xb8000000, 0xbc000000, 0xf2000000, 0xd3000000,
+    0x40800000, 0x9d400000, 0xb9e00000, 0x0cb00000, 0xde780000, 0x99bc0000, 0x9a1e0000, 0x79c90000,
+    0x1b448000, 0x3ce54000, 0x8132a000, 0x2aba3000, 0x2a9d2800, 0xbc88ec00, 0xaea6e600, 0x7a553900,
+    0x304c0480, 0x01861340, 0xfec224a0, 0x3c256350, 0x2413ac98, 0x212ebf7c, 0x2474e2b6, 0x6f5a2a53,
+    0x342aa00a, 0x01f63027, 0xcf9b283a, 0xc10dec10, 0x0264662c, 0xc575793f, 0x5cdc24b5, 0xb9ec6376,
+    0x2d572c8f, 0x7ecbff4c, 0x5dc642a1, 0x64a01a48, 0x55578801, 0x62cedc2e, 0xffc5ce20, 0xbfa4d503,
+    0x11d66285, 0xb18a6a48, 0x6722802f, 0x2090403b,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x68000000, 0x14000000, 0x92000000, 0xaf000000,
+    0x7c800000, 0x6c400000, 0x51a00000, 0x06d00000, 0x38880000, 0x264c0000, 0xa2ae0000, 0xbc5d0000,
+    0xf9c38000, 0x5c64c000, 0x58b66000, 0xc739f000, 0x59f60800, 0x6d18ac00, 0x3be66e00, 0x2a73f300,
+    0x8dda7d80, 0xde023dc0, 0xd1079da0, 0x5d830dd0, 0xe9c1f5b8, 0x146351e4, 0xfcb413a2, 0xed3dcee1,
+    0x8af3e006, 0x979c300a, 0x6aa5e839, 0xa8549c01, 0x9bcb8635, 0xdb6b6f34, 0xa03ffba1, 0xc17452f8,
+    0x4b5be60d, 0x69439f2c, 0xbe247394, 0xf0153ec4, 0xe8686834, 0xcabd5c15, 0x9836662a, 0x0d7a5f0d,
+    0x855193b5, 0x00480ee9, 0x6fa8003d, 0xb7dc002d,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x98000000, 0xec000000, 0xe6000000, 0x4b000000,
+    0xaa800000, 0x98400000, 0xd5e00000, 0xa5b00000, 0x4d080000, 0x0ccc0000, 0x57ee0000, 0xd1fd0000,
+    0xa3a68000, 0x7f564000, 0xb03b2000, 0xe8831000, 0x1144c800, 0xb6645c00, 0x3e777e00, 0x7329a100,
+    0x0f597480, 0xf57418c0, 0x99aad4a0, 0xf71c48d0, 0x5093bcb8, 0xa41d44cc, 0x56132a9e, 0xba5fa9f7,
+    0xb8f7e827, 0x2d6b4c25, 0x81bdb62d, 0x1240fd13, 0x38e08a80, 0x2437f9e6, 0x0fce8015, 0xd96a400e,
+    0xcbbd2001, 0xdf421024, 0xa964483d, 0x8ef31c3a, 0xae6cde3b, 0x4f3df100, 0x68061cbf, 0x340414da,
+    0x6a02c29a, 0xdd05e5ed, 0x7982de0f, 0xdec0f139,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0xd8000000, 0xc4000000, 0x22000000, 0xd3000000,
+    0x77800000, 0xe3c00000, 0xcae00000, 0x78500000, 0x00280000, 0x0f6c0000, 0x2dce0000, 0xeabf0000,
+    0x21258000, 0xeeb74000, 0xbe7b6000, 0xae477000, 0x5e256800, 0x97331400, 0xc0b9aa00, 0xae26ad00,
+    0x5f351780, 0xfcba9ac0, 0x1825f7a0, 0x7635aad0, 0x9c3e7fa8, 0xae668ec4, 0x8c11dd9e, 0x590847f5,
+    0x35de0816, 0x49b46401, 0x03fcc22b, 0xf285b929, 0x8d44bd91, 0xe1a037e3, 0x48f6e007, 0xbc5c3001,
+    0xff70082c, 0x3f9b640b, 0x45914207, 0xcfcef92d, 0x59b9dda4, 0x26a447dd, 0x85700806, 0x689b6427,
+    0x3011423a, 0xaf0ef914, 0x3cd9dda9, 0x793447e8,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0x28000000, 0xcc000000, 0x26000000, 0x3d000000,
+    0x6a800000, 0xd3400000, 0x22e00000, 0xd2300000, 0x28980000, 0x305c0000, 0xc57a0000, 0xa7ef0000,
+    0x0b278000, 0xcb92c000, 0xb44aa000, 0xfcf29000, 0xc8b9f800, 0xf44cec00, 0x9cf79600, 0x78bf7100,
+    0xcc4da880, 0x78f2a840, 0x92ba88a0, 0xd74df870, 0x2f765088, 0x2b7d4474, 0x26e89e82, 0xdfa34947,
+    0xd1d4d80b, 0x3e2cbc08, 0x2a84ce31, 0xb3410d0c, 0x92e3c691, 0xea31357c, 0xcc98b631, 0xda5c2133,
+    0xde7b7081, 0xf06d1461, 0xb2e3c69b, 0x3a313560, 0x4498b615, 0x065c2113, 0xd07b7094, 0x016d145a,
+    0xfe63c691, 0xd4713556, 0x0cf8b61a, 0x072c2101,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x78000000, 0x5c000000, 0x62000000, 0x47000000,
+    0x13800000, 0x63c00000, 0x28600000, 0xaa700000, 0xd3c80000, 0xb08c0000, 0x6eaa0000, 0x96790000,
+    0x01268000, 0x87514000, 0x8e9da000, 0x4511b000, 0xaa3e0800, 0x60845400, 0x06432a00, 0x2aa03900,
+    0x1892f280, 0x54f940c0, 0x2063d2a0, 0x4e70b0f0, 0x8dcefab8, 0x658814cc, 0x422c78a6, 0xba38c9db,
+    0x2887283e, 0x4241a40a, 0xe4a4021a, 0x45919d22, 0x5c7ef088, 0x3224ddf4, 0x42d72202, 0x1c5d6d3f,
+    0x7ff758b9, 0x090839c7, 0xce6c801b, 0x03984025, 0x6593200c, 0x0c7cf014, 0xda21a808, 0x96d0e402,
+    0x5a59a21a, 0x06f02d19, 0x3f88f8be, 0xf92c89e2,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0xc8000000, 0x2c000000, 0x8e000000, 0x71000000,
+    0x38800000, 0x83400000, 0x5ea00000, 0x9b300000, 0x54f80000, 0x903c0000, 0x445e0000, 0x1a0f0000,
+    0x38268000, 0xf174c000, 0xc6dfa000, 0xd24ed000, 0x5506d800, 0xfa845c00, 0x6c42a600, 0xef236300,
+    0xc4f4d280, 0x2b1d8140, 0xaaabf2a0, 0xc2149150, 0x650a0aa8, 0x31a5dd5c, 0x6ab7549a, 0x2b38f257,
+    0xdfd85839, 0xb6cc9c0b, 0xb0430617, 0xc922b33b, 0x89f48a88, 0x659d1d51, 0xbceef48f, 0x0635226e,
+    0x827e0035, 0x727f0038, 0xda7e8027, 0xe678c03f, 0x9079a02a, 0x057dd03d, 0x9ffe5816, 0xe3bf9c0c,
+    0xf49b8628, 0xd469730b, 0x5d75aa8c, 0x08db0d79,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x78000000, 0x9c000000, 0x2e000000, 0xc7000000,
+    0x5d800000, 0xa7400000, 0x7c600000, 0xd2900000, 0x58780000, 0x6bbc0000, 0x5a1a0000, 0xc6290000,
+    0xa3e78000, 0xf4d04000, 0x3a9aa000, 0x21ecd000, 0x48426800, 0x05e44400, 0xa7d51e00, 0x1d1f4d00,
+    0x5ba98180, 0x64a51e40, 0x58b6a1a0, 0x900c8e70, 0xe593e9b8, 0x0dfd5a44, 0x98f9bfa6, 0x3c7ac363,
+    0x19bde82d, 0xd3180412, 0x8cadbe1a, 0x21269d0b, 0xb3f6699d, 0xba681a4b, 0x6c069fb5, 0x26035346,
+    0x9300a030, 0x4785d032, 0xaa45e831, 0x54e40431, 0xc157be33, 0x19df9d08, 0x3f89e9be, 0x0bd45a5d,
+    0x5b1e3fb5, 0x18aa835e, 0x5b274812, 0x6ef4d43d,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xa8000000, 0x8c000000, 0x22000000, 0x57000000,
+    0x78800000, 0x0c400000, 0xd9600000, 0xf3500000, 0x05980000, 0xcc5c0000, 0x4afe0000, 0x7e090000,
+    0x36e48000, 0x2795c000, 0x80bba000, 0xfe69b000, 0x98348800, 0x19c90c00, 0xc5468e00, 0x3ce51500,
+    0xbc94d780, 0x3a39ef40, 0x152df7a0, 0x91d09f50, 0x6ad85f98, 0x3bbce37c, 0x54ed79b2, 0x8b708a5f,
+    0x842e083a, 0xb055cc0a, 0x3b19ae04, 0xd9196519, 0x2e1bff8a, 0x06995349, 0x12dff181, 0xefbc8649,
+    0xb2ea0623, 0xc270192f, 0x21ac59ba, 0x1f95fa56, 0xb4bda03c, 0xa86cb03c, 0x79360837, 0x3049cc15,
+    0x4887ae38, 0xe4406523, 0xb5677fa7, 0x61509362,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x88000000, 0x74000000, 0x26000000, 0x63000000,
+    0x3c800000, 0xcec00000, 0x09e00000, 0x80500000, 0xc0080000, 0xc34c0000, 0xddee0000, 0x15190000,
+    0x16e08000, 0xaed0c000, 0xabc96000, 0xa52b5000, 0x27fbc800, 0x66316c00, 0x7a193a00, 0x50620500,
+    0x8117bf80, 0x63adebc0, 0x48385fa0, 0x4e537bd0, 0xa70c7798, 0xb1cc87dc, 0x342965ba, 0x8e7d7ec1,
+    0x4f75c833, 0x7db86c19, 0x4511ba1c, 0x8daec53a, 0xbf38dfa6, 0x64d3bbd6, 0x82cd1794, 0xf0abd7eb,
+    0x5cbcad92, 0x249512fa, 0x30ec723a, 0xae9aa93f, 0x9aa765bb, 0xc6f47efe, 0xc4fd4815, 0x1ab4ac38,
+    0x54deda04, 0x69809508, 0x89459782, 0xd7a717c2,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xe8000000, 0xd4000000, 0xf6000000, 0xc1000000,
+    0xcc800000, 0xd0c00000, 0x63600000, 0x96d00000, 0x73d80000, 0x2c9c0000, 0x74ba0000, 0x944b0000,
+    0x0a658000, 0x6e56c000, 0xa51e6000, 0xb6fcd000, 0x12ea5800, 0x15527c00, 0x7298da00, 0x81b93100,
+    0x5eca2f80, 0x23a7e540, 0xfdb3cfa0, 0xc10af570, 0x914077a8, 0xa5259944, 0x0e731592, 0x79efc471,
+    0x0ad0582f, 0x41d97c01, 0xd39d5a3c, 0x7d3ff105, 0x960c4fac, 0xacc73554, 0xa16397aa, 0x51d3895a,
+    0x465d2d82, 0x0cda687b, 0xe51f5a09, 0x56f8f121, 0xe2ebcfa5, 0x2d56f569, 0x4e9a77a6, 0xa3be9957,
+    0x69ce958d, 0x2e250476, 0xe1f43803, 0x72aeac2b,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0x88000000, 0x3c000000, 0x6e000000, 0x29000000,
+    0x1f800000, 0xa1c00000, 0xa1a00000, 0x60f00000, 0x97d80000, 0x3a9c0000, 0x0e7a0000, 0x2e6f0000,
+    0x6ba68000, 0x63f6c000, 0xdb592000, 0xcfdb5000, 0xde9d9800, 0x447c2400, 0xed694a00, 0x87237700,
+    0xa6b4d880, 0x83bdea40, 0x99c978a0, 0x36537a70, 0x89a94088, 0x8f01ce7c, 0x6a8032aa, 0x40400d73,
+    0x91659831, 0x6ad02439, 0x04eb4a16, 0x4be07721, 0x62905896, 0x09882a75, 0x7f34d8b1, 0xc77dea44,
+    0x61e97881, 0xd2637a49, 0x465140be, 0x61adce76, 0x230232bb, 0xbc830d57, 0xdd41182e, 0xdce5e420,
+    0x8c16ea1e, 0x93cee722, 0x955060a0, 0xf52a9e58,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0x88000000, 0xc4000000, 0x0e000000, 0xb1000000,
+    0x01800000, 0x24c00000, 0x49a00000, 0x5ef00000, 0x1d880000, 0xdbcc0000, 0xa42e0000, 0x9d3b0000,
+    0x55a58000, 0x5cf5c000, 0xc689e000, 0xb5489000, 0xb8699800, 0x35d8d400, 0x68315200, 0xe72b2f00,
+    0x5fbc7080, 0xf3e3e0c0, 0x131610a0, 0x0799b0d0, 0xe1d5e888, 0xf33b34e4, 0xd4a742aa, 0xa5729fd5,
+    0x7e499829, 0xbee8d439, 0x9d995209, 0x96d72f20, 0x27ba70b3, 0x2fe4e0c3, 0xf11590b6, 0xac9b70d4,
+    0xd75788b4, 0xa37d64eb, 0xb642ba83, 0x27e71bc4, 0x7510b223, 0x029fbf12, 0xb653e8aa, 0x2afc34f0,
+    0x5684c283, 0x60405fdd, 0x9ae3f80c, 0x1e928405,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0x18000000, 0xd4000000, 0xae000000, 0xd3000000,
+    0x9c800000, 0xdcc00000, 0x93a00000, 0xdef00000, 0x28880000, 0xab2c0000, 0xadda0000, 0xb1550000,
+    0x247d8000, 0x42a74000, 0xbb74e000, 0xedcc1000, 0xefcdc800, 0x16cdcc00, 0x0f4f3a00, 0xd80a6f00,
+    0xbdeb5480, 0x2b782ac0, 0x7c269ca0, 0x2eb5e6f0, 0x0fe9a698, 0xba7f89d4, 0x39a2f21e, 0x9bf7a321,
+    0xc30c6e95, 0xcd6e45e5, 0xfdbfc82a, 0x1a84cc06, 0x73c0ba10, 0x1d242f10, 0x2330348f, 0x96aa7adf,
+    0x9c98348e, 0x2bb67af5, 0xc46a3497, 0xac3f7af5, 0xe1c5b4b6, 0xfc213ad1, 0xeeb6d4a3, 0xefef6ad1,
+    0x2a7a7caf, 0x21a5f6c6, 0x4ff66ea8, 0x6d0b45fc,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x68000000, 0x8c000000, 0xbe000000, 0x6b000000,
+    0xd7800000, 0x54400000, 0x1d600000, 0xcfd00000, 0x4bf80000, 0x6e9c0000, 0xe54e0000, 0xecb10000,
+    0xaf298000, 0x12e4c000, 0x4f91a000, 0x7c9dd000, 0x004a4800, 0x48355400, 0x58ee2600, 0x01c68500,
+    0xc422af80, 0xfa325640, 0xcde8e7a0, 0x5d470250, 0xd7e6c198, 0xdb118774, 0x935c6e02, 0x676fd117,
+    0x9982898d, 0x8745d35d, 0xcee3c820, 0x3991943c, 0xeb9f8625, 0x91cb5518, 0x9370e7ba, 0xa00b025e,
+    0x4f50c1a3, 0x2e3c8759, 0xd8bbee2d, 0x2a7a1105, 0x0ddaa9a4, 0x19acc354, 0x0020203f, 0xf835103b,
+    0x00ede81e, 0x95c5841d, 0x0e23ee24, 0xcb36112d,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xe8000000, 0xdc000000, 0xb2000000, 0xd1000000,
+    0xbf800000, 0x69400000, 0x25200000, 0x7a100000, 0xbaf80000, 0xa3dc0000, 0x7bce0000, 0xa8370000,
+    0xf0688000, 0x64e1c000, 0x56f02000, 0x000d3000, 0xd9d27800, 0xdf1ba400, 0x92abde00, 0x06878f00,
+    0x4ac37b80, 0xde66b240, 0xfe3103a0, 0x976d1650, 0xac62dda8, 0x4f36994c, 0x78efa612, 0x5d272b3b,
+    0x5e16259e, 0x6cfbfd5f, 0x84dcd819, 0x934c5409, 0xc1f70602, 0x608bdb2c, 0x3c147db2, 0x65fd695b,
+    0x2f5d7e35, 0xe40c7f38, 0x6fd1a3ab, 0xa81de65d, 0xe22e85a4, 0x5b470d4a, 0xb4268035, 0xe596c03e,
+    0xa3b8a004, 0x6efcf02d, 0xddda5823, 0xa0ca9405,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x58000000, 0x3c000000, 0xda000000, 0xdb000000,
+    0xd5800000, 0x14c00000, 0x23a00000, 0x2af00000, 0xbbe80000, 0xc24c0000, 0x73be0000, 0x3dd70000,
+    0xbb598000, 0xb3454000, 0x9863a000, 0x42d4f000, 0xe8db6800, 0xd6806400, 0xbd433200, 0xcd60c700,
+    0x8254b480, 0x1c99f9c0, 0x5167dca0, 0x48559dd0, 0xbf9aeea8, 0xe8e25ac4, 0xde97da3e, 0x7b3ee30b,
+    0xcd93a6a8, 0xaabf8eed, 0x4f522035, 0x801db02c, 0x0f26c803, 0x1cb3942d, 0xfb09da05, 0x68d9e330,
+    0x9682268c, 0x9d46cec3, 0x9d678009, 0xda524006, 0x209a2014, 0x8b61b02d, 0x9350c80a, 0x6a189436,
+    0xfc265a19, 0xfd37a32f, 0x51ce06ae, 0x767c7ee8,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xc8000000, 0x7c000000, 0xa6000000, 0xe5000000,
+    0x99800000, 0xfa400000, 0x20e00000, 0xf2300000, 0x12080000, 0xc4ec0000, 0xebda0000, 0x34550000,
+    0xe0f88000, 0xa0c4c000, 0x66222000, 0x51179000, 0x0a987800, 0xcc349400, 0xb309d600, 0xaf6c2900,
+    0x1e9dec80, 0x06318ac0, 0xe80d94a0, 0x57e91ef0, 0x6f5e4298, 0xe39037fc, 0xe05b2e2a, 0x31157d23,
+    0xfa9c9aa3, 0xe437f3ea, 0xff08a01d, 0xc16a5028, 0x8798d83f, 0x39b2c437, 0xf74b0e18, 0xee8bed2c,
+    0x672e629f, 0xd17ea7f9, 0xd681d600, 0xc8c0291a, 0x0a27ecab, 0xef148ac1, 0x6b9d14b5, 0x47b1ded1,
+    0x764e62bc, 0x550ea7c4, 0x6a69d61d, 0x571c292c,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x28000000, 0x4c000000, 0x7a000000, 0x09000000,
+    0xe0800000, 0x74400000, 0x73a00000, 0x53b00000, 0x1b680000, 0x0acc0000, 0x847e0000, 0x91930000,
+    0xa81d8000, 0xa842c000, 0xc1a3a000, 0xe6b5f000, 0xf9eb7800, 0x1389dc00, 0x21588e00, 0xc567eb00,
+    0x29576a80, 0x65f81fc0, 0x595412a0, 0x5dfdc3d0, 0x9d529c88, 0x3bf928c4, 0xc6507602, 0x9e7ff717,
+    0x2891c4bc, 0x9098c4c5, 0xe803a00c, 0xac05f01c, 0x8a037812, 0x7105dc1f, 0x84868e28, 0x4244eb18,
+    0x00a2eaa5, 0xba36dff0, 0x8fa9b295, 0x0d2b33c1, 0xa46c64af, 0xd94e34e7, 0xb9bd5820, 0x26f2ec02,
+    0xd44e561b, 0xdf38c72d, 0xf9b11cba, 0x7a68e8ca,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0xb8000000, 0x74000000, 0x62000000, 0x25000000,
+    0x18800000, 0x11c00000, 0xb5e00000, 0x24b00000, 0x20080000, 0x53ec0000, 0xc85e0000, 0x01d30000,
+    0x697d8000, 0x98064000, 0x6406e000, 0x7a007000, 0x01031800, 0xc2873c00, 0x40c2c200, 0xcf66f500,
+    0x10752c80, 0x8d6d4ec0, 0x669e34a0, 0x5db672d0, 0x768af688, 0x812f87c4, 0xca3c5a26, 0xc5278919,
+    0xe3d10e9e, 0x0c7bcbc0, 0x20800010, 0x25c00000, 0x77e0001b, 0x51b00010, 0x8088000d, 0x362c0004,
+    0x1fbe000f, 0x00630014, 0x51f58026, 0xda2a4036, 0x19b8e01c, 0x5f637031, 0x4876980b, 0x096d7c1c,
+    0xec9a223b, 0xb4b5851d, 0x780bb48e, 0xd7ec32c6,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x48000000, 0x0c000000, 0x8a000000, 0x33000000,
+    0x12800000, 0xc3400000, 0xf8600000, 0x5af00000, 0xa9380000, 0x3f5c0000, 0xe0ae0000, 0xda150000,
+    0x53898000, 0x5485c000, 0x5a40a000, 0xa9e57000, 0xd3352800, 0xd61f1c00, 0x1bcb7600, 0xaa631d00,
+    0x6df39680, 0x5dbb4940, 0x551ebea0, 0x71485550, 0x5d23c8b8, 0x4b924854, 0xfa4fde2a, 0x5ba5c117,
+    0x26d640a8, 0xa728244b, 0xe2d18014, 0x6129c02f, 0x3bd6a000, 0x50ac702e, 0xa212a802, 0x678fdc2a,
+    0x8a82560e, 0xa743ad23, 0x0e661e82, 0xbbf1255c, 0xccb8e0a4, 0x6898544f, 0x628d2824, 0x59031c11,
+    0x71857616, 0xf9c61d04, 0x2722168d, 0x60928952,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x58000000, 0x24000000, 0xea000000, 0x27000000,
+    0xe6800000, 0x03400000, 0x91600000, 0xc4100000, 0x7b780000, 0xcc1c0000, 0x990a0000, 0xfdf50000,
+    0xb12e8000, 0x07864000, 0x84c26000, 0xd5a3b000, 0xf1b0c800, 0x7acb9c00, 0xeed33600, 0x53daf500,
+    0x44288980, 0xd203c440, 0x330041a0, 0xb4845870, 0xf0417798, 0xc5e7ad4c, 0xc4557e0e, 0xe69b2905,
+    0x2c4bdf94, 0x9593817c, 0xf6bc8035, 0xc23f4030, 0xf27ee01f, 0xd79cf004, 0xe3ce2815, 0x5d576c0b,
+    0x551d1e26, 0x2a8d9934, 0xfb3597ad, 0xdf8e5d4d, 0x2eb5d62f, 0x684a050a, 0x4f94a197, 0x69bda865,
+    0xf0b9dfb6, 0x433a8179, 0x45f80018, 0x2b5c003a,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x98000000, 0x2c000000, 0xc6000000, 0xb3000000,
+    0xc8800000, 0xa3400000, 0xa6600000, 0x83f00000, 0x9e880000, 0x2bec0000, 0x509a0000, 0x05550000,
+    0x2cd98000, 0x8db74000, 0x4f6b2000, 0xf5d89000, 0xf0304800, 0x77a82c00, 0x287c2600, 0xeee61d00,
+    0xe0b50e80, 0xd8eb4cc0, 0xb81f46a0, 0x561660f0, 0x02bae098, 0xba473df4, 0x3be4ce3e, 0xab34e13f,
+    0xdb2bc0af, 0x193aade3, 0x01850605, 0x46c78d3f, 0xb2a6c692, 0x985120e0, 0x3359c0ba, 0x7473adf9,
+    0x034e8633, 0xf5c9cd33, 0x198e6694, 0xc96bf0cf, 0x66db2892, 0xc8b451e8, 0x5ce9c818, 0x3a1f6c39,
+    0x87170634, 0x0b3e8d1d, 0x8885469c, 0x834360fe,
+    0x80000000, 0x40000000, 0xa0000000, 0xd0000000, 0xe8000000, 0x2c000000, 0x4a000000, 0x93000000,
+    0x9b800000, 0x1cc00000, 0xb9200000, 0xcd300000, 0x4ed80000, 0x4cfc0000, 0xd64e0000, 0xbe530000,
+    0xe0898000, 0xa470c000, 0x2e3aa000, 0xe26d7000, 0x55e72800, 0x0c125400, 0xe7ea4200, 0xb4266300,
+    0xabb3fe80, 0x4c9b3a40, 0x939ad6a0, 0x721a6e50, 0x65d91488, 0x537ccd64, 0x0c884a32, 0x0e76872f,
+    0xcd3bb480, 0x41edbd5b, 0x8d21622e, 0xd337d338, 0xf3d876b2, 0xf27b1e64, 0x60083c81, 0x3f31997c,
+    0x59dd8812, 0x117f240f, 0xc38d6a2a, 0xe7f4372d, 0x86f9bc80, 0x054d5962, 0x85d12821, 0x2c4d5432,
+    0xf555c232, 0xcf09a339, 0xe6b6deb9, 0x8a198a7f,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0x68000000, 0x14000000, 0x92000000, 0xf7000000,
+    0x89800000, 0xdbc00000, 0x6ce00000, 0x21700000, 0x23d80000, 0x803c0000, 0x14ce0000, 0x4ad70000,
+    0x130d8000, 0x3030c000, 0x1f7f6000, 0xa96a7000, 0x37030800, 0xe9872400, 0x2bc20a00, 0xd4e0a500,
+    0x5d70ea80, 0xa5db0e40, 0xe53de2a0, 0x6a4b2a50, 0x189268a8, 0xa42b4f64, 0x7da5e232, 0x1dd63121,
+    0x0a8d08b6, 0xa3f13f4c, 0xb79eea3c, 0x721d151e, 0xf7d90295, 0x723a9a77, 0x13cd808b, 0x7b51db4b,
+    0xb4ce001a, 0xdad70023, 0x5b0d8039, 0xf430c039, 0xe57f6023, 0x4a6a7004, 0x2c830800, 0xc5472437,
+    0xcea20a3c, 0x2e50a509, 0x1248ea87, 0x04970e42,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0xf8000000, 0x74000000, 0x42000000, 0x69000000,
+    0x4b800000, 0xa4c00000, 0x5c600000, 0xebf00000, 0x7a980000, 0x1bfc0000, 0x898e0000, 0x66d30000,
+    0x2ece8000, 0x52774000, 0x0f5fe000, 0xd81f7000, 0xb4bd7800, 0xd12fc400, 0xe1451200, 0x47a6c900,
+    0x47133280, 0xdf2bc440, 0x5e404aa0, 0xb7270050, 0x0953d888, 0xe00a895c, 0x7b110a36, 0x512d3d01,
+    0xa142b886, 0xe7a2b94b, 0x7713921c, 0x272d893e, 0x2a42d2bd, 0xf527b445, 0x6053b285, 0xab8f8458,
+    0xdfd12ab3, 0x0d4f3063, 0x4ab14093, 0x9d3a3d63, 0x6ce9601b, 0xaea4301a, 0x4c94982f, 0xdbefb432,
+    0x3220ea1f, 0xa4d14d1e, 0x07c94084, 0xb9f63d49,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xe8000000, 0xa4000000, 0x42000000, 0xef000000,
+    0x27800000, 0x40400000, 0x82a00000, 0xa5d00000, 0x6c980000, 0xfd3c0000, 0x616a0000, 0x4cb50000,
+    0xcdaf8000, 0x2bd2c000, 0xa59ae000, 0x97bb7000, 0x39acc800, 0x41d61400, 0x0e9ee600, 0x223ffb00,
+    0x7eeaa180, 0x40f61340, 0xa90c69a0, 0x23050770, 0x01850f98, 0x0d443c44, 0x9a274e22, 0x2a105f2d,
+    0x0a7a6f92, 0x98488c66, 0x4726e603, 0xfa93fb32, 0x11b8a181, 0x84af1376, 0x8151e9a8, 0x1d5ec75f,
+    0x4ada6fab, 0x12988c7a, 0xec3ee61b, 0x97effb1b, 0x1a72a1b0, 0xc9ca1373, 0x6266698c, 0x24b00772,
+    0xa9aa8f9d, 0x89d6fc68, 0x9a9dae31, 0x583b2f29,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0xd8000000, 0x24000000, 0xba000000, 0xc3000000,
+    0xf1800000, 0xfdc00000, 0x79600000, 0xba500000, 0x58d80000, 0xc8bc0000, 0xd86e0000, 0xc8f50000,
+    0x2ee98000, 0x2eb14000, 0xd14d6000, 0x28053000, 0xac006800, 0x26018400, 0x4d07da00, 0x5080b900,
+    0xeb46cf80, 0xcf268340, 0xfdf0a7a0, 0x6a6e0750, 0xd7f0fdb8, 0x316afe54, 0x6272d20e, 0x66a80d1d,
+    0x00177d80, 0x097ebe7d, 0x1f0e3204, 0x7ba07d16, 0x8cb47594, 0x164f0a65, 0x13800022, 0x5ac0003a,
+    0x52e00008, 0x9490001a, 0x08380021, 0xab2c003d, 0x43d60039, 0x7919002d, 0x5fdf803e, 0xd3384005,
+    0x5eaae024, 0x7411700b, 0x0b7c881f, 0xe809f417,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x48000000, 0xac000000, 0x0a000000, 0xdf000000,
+    0xe2800000, 0x65400000, 0xe4e00000, 0xcad00000, 0x2f080000, 0x86ec0000, 0xb5be0000, 0xcb750000,
+    0x41398000, 0x0335c000, 0x0c5da000, 0xb0207000, 0x93f14800, 0x8378fc00, 0x91532600, 0x5dcb0900,
+    0x4acec380, 0x4449cdc0, 0x4b098ba0, 0x60e831d0, 0x00bd2db8, 0xe6f3f8d4, 0x8effce2a, 0x2e93853f,
+    0x286cad88, 0x8afa38d8, 0xd8946e08, 0xd56af515, 0x0b7a659b, 0x1d5204d8, 0x27cb6838, 0xcdc84c31,
+    0x42ce4e05, 0x884a4512, 0x510f0d9c, 0xf7ef48e1, 0x4e3ca625, 0x89b7c90b, 0xb51ce39c, 0x06c57df0,
+    0x6222e3a5, 0xe8f07de0, 0xa7fb63b3, 0x3115bde4,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x88000000, 0x1c000000, 0x7a000000, 0xd1000000,
+    0x08800000, 0xb3c00000, 0xd4200000, 0x13900000, 0x0de80000, 0x4ecc0000, 0xeede0000, 0x51710000,
+    0x54d88000, 0x2072c000, 0x0c5b2000, 0xabb47000, 0x4c7a3800, 0xde225c00, 0xea943200, 0x496beb00,
+    0xbf0c4b80, 0x7fff86c0, 0x2c6073a0, 0x41f0dad0, 0x631ac198, 0x4a54f1fc, 0x90cb2a3a, 0x91dcc73b,
+    0x0ef2c184, 0xf498f1df, 0x16152a1e, 0x6cadc70f, 0xa82a4191, 0x19ea31f0, 0x68ce0a36, 0xa5d9b72c,
+    0x38f079ab, 0x67986ddb, 0x5b92382d, 0xb1ee5c34, 0x64ca323e, 0x07daeb2a, 0xcdf4cba6, 0x811d46ed,
+    0x5f53539a, 0xc648aafb, 0x1d1ef98a, 0x6557adf1,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x38000000, 0x64000000, 0x82000000, 0x8f000000,
+    0x2b800000, 0x93c00000, 0xfb200000, 0xb5700000, 0x07680000, 0x7d4c0000, 0xf2be0000, 0xc4910000,
+    0xa7bf8000, 0x14104000, 0x45fa6000, 0xc7f5f000, 0x9a2bc800, 0x0d2bf400, 0xb2ae1a00, 0x2b6ecb00,
+    0x534a3480, 0x13bd45c0, 0xee17fca0, 0x4efbb1d0, 0x3e706698, 0xbee83ac4, 0xb989b216, 0x161dcf1d,
+    0x58266696, 0xb0f53aee, 0x75a83204, 0xacec8f0e, 0xae8b86ac, 0xe99c8afe, 0x21e79a1b, 0x98d38b1f,
+    0x0d59d49b, 0x3545f5f4, 0x8c67d4b0, 0x1614f5c6, 0x8af85482, 0x0c74b5cf, 0x59ea3483, 0xce0d45d1,
+    0x63dffcaf, 0xae07b1da, 0xa106668a, 0xca853acd,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x08000000, 0x44000000, 0x1e000000, 0x5b000000,
+    0x0b800000, 0xf0400000, 0x74e00000, 0x6f300000, 0x8a080000, 0xc56c0000, 0x421e0000, 0x10f10000,
+    0xb0af8000, 0x35b9c000, 0xf4a6e000, 0xe3d79000, 0x193d8800, 0x4d64b400, 0x8e756600, 0x5869a900,
+    0x1e9bf480, 0x51304ac0, 0x2109fca0, 0x76ed3ed0, 0xae5a7a98, 0x361307c4, 0xde9c061a, 0xf137f915,
+    0x91089cbd, 0x2eef6ed3, 0xe25f12a7, 0x6c1123eb, 0x9b9b6800, 0xa1b32435, 0x6ac8ee07, 0xaa4d1d13,
+    0xf98e92aa, 0x8929e3e9, 0xd4fa083b, 0x26c17402, 0x38258626, 0x0a133902, 0x7c9ffcaa, 0x48303edd,
+    0xc38bfa8d, 0xdc2bc7ce, 0x6c7d6604, 0xc905a920,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0x58000000, 0x54000000, 0xbe000000, 0x01000000,
+    0x1c800000, 0xd7400000, 0x82a00000, 0x82f00000, 0x70a80000, 0x668c0000, 0x6f3e0000, 0x3f310000,
+    0x984a8000, 0x285e4000, 0x5121e000, 0x50309000, 0x8dcfd800, 0xff9c5c00, 0x46c03600, 0xe3601b00,
+    0x8813c080, 0x9d7e2bc0, 0x071698a0, 0x78fc37d0, 0x88d74e98, 0x3b1cbcec, 0x8583560e, 0x3fc2cb39,
+    0xfbe3f881, 0xa953e7e9, 0xe2dbf69e, 0x2b6230f0, 0x34135810, 0xcf7f1c39, 0x1c155615, 0x837fcb25,
+    0x1617789e, 0xcc7ca7e3, 0x539096a9, 0x53bce0f9, 0xb8756016, 0x52efd028, 0x56acb803, 0x938e8c31,
+    0xddb80e18, 0x4171d710, 0x0a68ae85, 0x97ed2cd0,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x68000000, 0x24000000, 0xde000000, 0x15000000,
+    0x6e800000, 0x30c00000, 0x76600000, 0x82f00000, 0x5a780000, 0x869c0000, 0x3aae0000, 0x0bb50000,
+    0xd55d8000, 0x3348c000, 0xf900e000, 0x24859000, 0x63c0f800, 0xd1e2ec00, 0x4eb03a00, 0xf3de9100,
+    0xd78c7a80, 0xa960ab40, 0xff7102a0, 0xad3a8750, 0xacb9d8b8, 0x79fd8674, 0x24db5a22, 0x1e0ac13d,
+    0xe827e2b5, 0xe4561768, 0x5eeaa096, 0xa612aa50, 0xaa4e0009, 0x4d85001d, 0x2f458012, 0x9624c020,
+    0x4156e028, 0xa86c900f, 0x4ad37801, 0x4e2f2c26, 0x20755a03, 0xd0bfc12b, 0x5bfa6298, 0x53ded770,
+    0x678a408d, 0x31673a72, 0x2376f83d, 0x3f3bec18,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x48000000, 0xe4000000, 0xda000000, 0x73000000,
+    0x80800000, 0xb0400000, 0x08200000, 0xa9f00000, 0xd5480000, 0x71ec0000, 0x965a0000, 0x82310000,
+    0x79ab8000, 0x427bc000, 0xedc1e000, 0x79e6b000, 0xb617e800, 0x065d8c00, 0x6a337e00, 0x2dae3700,
+    0x007ac480, 0x32c0b8c0, 0xc766aca0, 0xaf56f4f0, 0xfdfc32b8, 0xf30273c4, 0x40831e2a, 0xd042473d,
+    0xd8274cbc, 0xe1f044d1, 0x314bdaa4, 0xabeffff9, 0xe558602c, 0x02b0701e, 0xc9ef881d, 0x4a5dfc17,
+    0x4434f636, 0xacafcb33, 0xc7fc3298, 0x900273dc, 0xe8031e02, 0x54024718, 0x42074ca7, 0xdf0044cd,
+    0xbe83daae, 0x1943ffe1, 0xfba26002, 0x9931702e,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0xf8000000, 0xec000000, 0xf2000000, 0x73000000,
+    0x1a800000, 0xdc400000, 0xa9a00000, 0x2c100000, 0x22880000, 0xe5ac0000, 0xdbfe0000, 0xb9d30000,
+    0x3dee8000, 0xe45f4000, 0xa0c72000, 0x58e03000, 0xbe37e800, 0x8b5a9c00, 0x50410e00, 0x8ba18b00,
+    0x3713c780, 0x1c0ff5c0, 0xdfeaafa0, 0x1f5a29d0, 0x0e448188, 0xaaa792dc, 0x6e96ae36, 0x22cdfb27,
+    0x460d8faa, 0x68ea19eb, 0x73db69a4, 0x77010ec0, 0x8c81a018, 0xe943703c, 0xee26c832, 0x93d5ac32,
+    0xd2ee6616, 0x54db573b, 0xd385698b, 0xe1c20ec5, 0xd167201d, 0xe8f0302d, 0x36bfe802, 0xc1f69c33,
+    0x9b3f0e13, 0x71328b3a, 0x4bdd47b3, 0x7b00b5cc,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xf8000000, 0x34000000, 0x36000000, 0x23000000,
+    0xc1800000, 0x72c00000, 0x5ea00000, 0x25100000, 0xd5f80000, 0x9cdc0000, 0x980a0000, 0x4ad10000,
+    0x05d88000, 0xd88d4000, 0x3c91e000, 0x833ad000, 0xdc7b8800, 0xe21ee400, 0xa4abe200, 0xcac3ff00,
+    0xcaa7a380, 0xc3128640, 0xbefcaba0, 0x915d2270, 0xe8cca9b8, 0x01750d5c, 0xc2480206, 0xbe342f11,
+    0x8c2eabab, 0x60802269, 0x664629a3, 0xaee54d69, 0x78f36212, 0x248ebf01, 0x069643b4, 0xc2385653,
+    0xb8ff2397, 0x8a5fc649, 0x7d4d4b97, 0xf5b7f24b, 0x47ef2189, 0x6ea7e940, 0x1d11e010, 0x81fad019,
+    0x1adb8819, 0x430ee42a, 0xbf53e21f, 0x411fff0e,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0xf8000000, 0x64000000, 0x3e000000, 0xe7000000,
+    0x1d800000, 0xbdc00000, 0x2c200000, 0xe1d00000, 0x0f180000, 0x10bc0000, 0xc3aa0000, 0x31150000,
+    0x6fbc8000, 0xca294000, 0xaad36000, 0x709c1000, 0xf07b6800, 0x9b0e6400, 0x6f83da00, 0x88c23300,
+    0x94a00980, 0x4c90a340, 0xa27fe1a0, 0xfe0b8770, 0x9f055b98, 0xb980a45c, 0x63c2ba26, 0x7b272305,
+    0x0455e189, 0xd6de874c, 0x0299db96, 0xc579e47f, 0x2389da1b, 0xc2c73332, 0x25a48989, 0x7a15e366,
+    0x103e81a9, 0x2aee9760, 0xf270b3b8, 0x2e0e8070, 0x1704802b, 0xc5854030, 0x89c1600c, 0x5a251038,
+    0x9ad5e808, 0x489e241f, 0x747e3a2a, 0x150e6307,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0x98000000, 0x14000000, 0x0a000000, 0x29000000,
+    0xc8800000, 0xf1400000, 0xade00000, 0x5c100000, 0x3e080000, 0x196c0000, 0xe13e0000, 0xdc510000,
+    0xef688000, 0xee3a4000, 0x73d7e000, 0xdda93000, 0xdf1f3800, 0xa5e01c00, 0xc011e600, 0xe80ed100,
+    0x066c5d80, 0x86bb13c0, 0xd293e5a0, 0x584d4fd0, 0x0f0be398, 0x85ebaefc, 0xfa78063e, 0xe8b6e139,
+    0xe3fbe59c, 0x35714fe3, 0xcd5de3b6, 0x4986aee3, 0xf5c68603, 0xa2a1a11d, 0x62f28588, 0x1b9f3fc4,
+    0x0aa3bb98, 0xaef4c2f9, 0xe59f3824, 0x99a01c00, 0xd771e618, 0xc85ed139, 0xcf045d90, 0x0f8713fb,
+    0x62c5e5bc, 0x19204ff3, 0x5a356384, 0x03bceee9,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x28000000, 0xfc000000, 0x2a000000, 0xa9000000,
+    0xf5800000, 0x0ec00000, 0xc0600000, 0xbd700000, 0xd0c80000, 0xc32c0000, 0xcb9e0000, 0x73b70000,
+    0x70a98000, 0x0e5ec000, 0x63522000, 0x649cf000, 0x6d347800, 0xfeea7400, 0x4f793200, 0x9183e900,
+    0xc0c5c680, 0xe763eac0, 0x4ff03ea0, 0x7c8b5ed0, 0x870d2ca8, 0xcecf47c4, 0x8c2b1222, 0xe51f193b,
+    0xa5f1be88, 0xb5899ec1, 0x02890c9d, 0xb808b7c6, 0x9848ea2f, 0x8e6cad02, 0xf63b2cbb, 0x2a2447f7,
+    0x32549216, 0x051ad906, 0x95f41ea5, 0xed8caed2, 0x868ed491, 0x460cf3c5, 0xe7498039, 0xf8eec014,
+    0xa47a2029, 0x1100f006, 0x41827826, 0xa8c17407,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x18000000, 0x14000000, 0xa6000000, 0x6f000000,
+    0x7b800000, 0x9c400000, 0xd4e00000, 0x45700000, 0x36380000, 0xf35c0000, 0x976e0000, 0xf7370000,
+    0xc1db8000, 0x5fad4000, 0xe416e000, 0xdc0e5000, 0xe0036800, 0x7007d400, 0x28052600, 0x1c048900,
+    0xaa06e680, 0xdd053940, 0xb2860ea0, 0x88c3ad50, 0x3323c8a8, 0x0dd27454, 0xa7abc62e, 0x8016d911,
+    0x520b8ea7, 0x9305ed5a, 0xa180a891, 0x69466469, 0x7a65ce2a, 0x67b21d39, 0xd81b20a8, 0x4c08e04b,
+    0xb8000012, 0x4400001e, 0x9e000027, 0x6b00002e, 0xc5800001, 0xe740002e, 0x09600020, 0xb630000a,
+    0x9958003d, 0x2a6c000a, 0x75b60038, 0x411b0017,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0x58000000, 0xb4000000, 0x26000000, 0x71000000,
+    0x65800000, 0x48400000, 0x59200000, 0x5ff00000, 0xa3a80000, 0xa50c0000, 0x79ba0000, 0xedb10000,
+    0x518b8000, 0x447b4000, 0xc4d3e000, 0x335b5000, 0xc6269800, 0x0773d400, 0xac6e8600, 0x6b6db700,
+    0x87e80c80, 0xae292fc0, 0x114d14a0, 0x8a9dbbf0, 0x814272b8, 0x98a65cc4, 0x09b5662e, 0xef8ae729,
+    0xe17c9487, 0x5757fbc5, 0x521a12a6, 0x4e864cd3, 0x36c01e3e, 0x27e2631d, 0xf4148aa9, 0x15b998f5,
+    0xbfb4981f, 0x668ed404, 0x80ff062a, 0xb117f739, 0x96386ca2, 0xe6f53fce, 0x8a2a6c83, 0xcf483fce,
+    0xff9becbc, 0x4ac27fd3, 0x4de38c89, 0xa7126fe4,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x98000000, 0x4c000000, 0xf2000000, 0x75000000,
+    0x17800000, 0xdec00000, 0x09e00000, 0x3ff00000, 0x22280000, 0x014c0000, 0x13fa0000, 0x60f70000,
+    0x6cac8000, 0x6a0a4000, 0xd9db2000, 0x95663000, 0x11b2d800, 0xc78ac400, 0x9618c200, 0x0d053500,
+    0xcb821e80, 0xe4c6a5c0, 0x58e646a0, 0x0e7121f0, 0x37e92488, 0x982864cc, 0x904ac22e, 0x827e351f,
+    0x85349eb2, 0x6dcbe5f2, 0xa5b9e6bf, 0xc95151c9, 0x09fadcb3, 0x01f390e9, 0x652c5821, 0xc3cb8427,
+    0x2abd623c, 0x2fd24525, 0x16bd6688, 0x85d711fb, 0x8fbbfcb2, 0x9052a0f2, 0xbc7a002e, 0xc237003a,
+    0xaf4c8017, 0x9cfa402f, 0x8673201e, 0x73ea3007,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x18000000, 0x94000000, 0xea000000, 0xd1000000,
+    0x63800000, 0x7ec00000, 0xf6600000, 0xc9100000, 0xa4480000, 0xf4ac0000, 0x0afa0000, 0xd7130000,
+    0x4f4e8000, 0x4e2c4000, 0xabbfe000, 0x39b03000, 0xdf3bf800, 0x7bf68400, 0x371d8200, 0x2cc16900,
+    0x4360f580, 0x7897ddc0, 0xaf8f8da0, 0x934e19f0, 0xf82b6fa8, 0x0cbf00fc, 0xdd35822e, 0x757d6919,
+    0x3fd2f5b4, 0x2e28dddd, 0x7bbb0d84, 0x31b159fe, 0x633a0fb7, 0x1df370e5, 0x98199a22, 0x7447dd09,
+    0x8f268fa2, 0x93b030e7, 0xee3afa3c, 0x0874ad25, 0x61de17ac, 0x56a5c4fd, 0xf477e024, 0xe7dc3006,
+    0xe9a1f834, 0x84f58419, 0xa79b020c, 0x7c81292a,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x68000000, 0x14000000, 0xb6000000, 0x8d000000,
+    0x5d800000, 0xf4c00000, 0xc8e00000, 0xa5b00000, 0x2cd80000, 0x18bc0000, 0x1e4a0000, 0x81b70000,
+    0x42dd8000, 0x99bcc000, 0x59cf6000, 0x32715000, 0xf8bcf800, 0xae4ff400, 0x99b64200, 0xeede5d00,
+    0x53ba3480, 0x76cef840, 0x54f14ca0, 0xdcfacc70, 0xcfedee88, 0x00250144, 0xaf564212, 0xc26e5d01,
+    0x5ce2349f, 0xd3b2f852, 0x01db4c80, 0x953dcc6b, 0x82886e80, 0x5d55c173, 0x516b223c, 0x38640d13,
+    0x1cf14ca1, 0xd8facc5d, 0xb1edee9c, 0x4925016b, 0x2cd64201, 0xafae5d39, 0x7f8234a4, 0x0fc2f862,
+    0xb8634ca7, 0xdcf1cc45, 0x78fa6ea2, 0x61eec157,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0x48000000, 0xa4000000, 0xb6000000, 0x03000000,
+    0x64800000, 0x9d400000, 0x96200000, 0x72f00000, 0x2d480000, 0xbaec0000, 0x8ada0000, 0x44710000,
+    0xa50c8000, 0x1b4cc000, 0x79ece000, 0x8e5bd000, 0xe9300800, 0x7b284c00, 0xcdbe3600, 0xe2a22f00,
+    0x37b40480, 0x076f60c0, 0xa21a8ca0, 0xfe96ecf0, 0x8b1edab8, 0x6312d3fc, 0x33dab62a, 0xc1f2ef15,
+    0xc1cae4a7, 0xe3a9b0e5, 0x9afc0486, 0x7d8360fe, 0x48c08ca9, 0x8ae7ecc7, 0x66125a9d, 0xdc5e13c0,
+    0xfc36560b, 0x4ca93f36, 0x4c7aecbe, 0x05c1fcfb, 0xc16232b6, 0xedd14fd6, 0x523c882f, 0x37648c12,
+    0x4ed2d608, 0x66b9ff24, 0xd2240c9e, 0x34f72ceb,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x38000000, 0x0c000000, 0x02000000, 0xb5000000,
+    0xfd800000, 0x2e400000, 0x45200000, 0x1fb00000, 0xe4e80000, 0x334c0000, 0x163e0000, 0x3e350000,
+    0xd0a98000, 0xbf6e4000, 0xde0f2000, 0x759cb000, 0x55405800, 0x2fa21400, 0x09711600, 0x5a0d8700,
+    0x739bbe80, 0xa641c7c0, 0x412466a0, 0x59b493d0, 0x77edd0b8, 0x4dcee4d4, 0x7ef89636, 0x1fd3c717,
+    0xecfc9e96, 0xd2d177ea, 0x7d7a3e91, 0xee9387f5, 0xb55d4696, 0x00a123f5, 0x7df208a1, 0xc3cbb0d7,
+    0x89f9201b, 0x3755b03e, 0x2f3fd821, 0x81b5540d, 0x2be9b626, 0x17ca771f, 0xd7fd4681, 0xd85123d4,
+    0x7bba0892, 0x6877b0df, 0xf98f202a, 0xb2dcb00b,
+    0x80000000, 0xc0000000, 0xe0000000, 0xb0000000, 0x68000000, 0x4c000000, 0xd2000000, 0x5f000000,
+    0xd3800000, 0x68400000, 0xa4600000, 0xc9500000, 0x31080000, 0x93ec0000, 0x5a7a0000, 0x0ad50000,
+    0xa14c8000, 0x438dc000, 0xd52ea000, 0x92dab000, 0x20268800, 0x2f353400, 0x9f5c8600, 0xe566cd00,
+    0x57d36380, 0x35cd75c0, 0x3c4b6ba0, 0x370c81f0, 0x1aefcd98, 0x18f83cdc, 0xe4100602, 0xc5eb0d0f,
+    0x0b7dc396, 0x6c57c5e8, 0xd18de3ba, 0xaa29b5ee, 0x115b4bb1, 0x9062f1e3, 0xaf516593, 0xc80f78c3,
+    0x59682805, 0x48bf840c, 0xd4720e10, 0xfabff904, 0xfb75e5b8, 0xa13eb8da, 0x6f348810, 0xbf5c3414,
+    0xb5620625, 0x8fd20d34, 0x11cb43bb, 0xa24f05e8,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x48000000, 0xac000000, 0x4a000000, 0x21000000,
+    0x88800000, 0xf4c00000, 0xc0600000, 0x43700000, 0x61280000, 0x2ecc0000, 0x1afe0000, 0x36f70000,
+    0x5f6c8000, 0xb768c000, 0xab6ea000, 0x896a7000, 0xf46eb800, 0x7eeddc00, 0x072d4a00, 0x05c81100,
+    0x937f4680, 0xbab7adc0, 0x23cb7ea0, 0x9879b1d0, 0xc33214a8, 0xe78810ec, 0x48dfca3a, 0xa627d107,
+    0xaed56688, 0xaeb91ddf, 0xee556682, 0xb6791dce, 0x443566a8, 0x24091dc9, 0xe59d66b0, 0x52051de1,
+    0x75036686, 0x06821dd9, 0xc3c7e683, 0x6be6ddd9, 0xb2374681, 0x970bade0, 0x781d7ea2, 0x7042b1f9,
+    0xcea094b2, 0xca17d0d8, 0xf6ddea2b, 0xb9256139,
+    0x80000000, 0xc0000000, 0xe0000000, 0x50000000, 0x78000000, 0xd4000000, 0xae000000, 0xe1000000,
+    0x35800000, 0xb7400000, 0xfea00000, 0x5b900000, 0x7ee80000, 0xfecc0000, 0xd09a0000, 0x07150000,
+    0x202b8000, 0x052a4000, 0xc6ade000, 0xf4ed1000, 0xb9cb5800, 0x181f9400, 0xd7d1fe00, 0x8ac87300,
+    0x0e9e0b80, 0x2e10eac0, 0xe9acd3a0, 0xb06c3ef0, 0xa3094d98, 0xd5fa1de4, 0xa4e07e06, 0x9ab73311,
+    0x15386b8d, 0x5607bad9, 0xf5026b98, 0x7b82bad0, 0x0641eb81, 0xb324fae7, 0x38d60bab, 0x2e4cead5,
+    0x445ed3b9, 0x9bf53ec1, 0x4e98cd83, 0x0e155dfb, 0x59ae1e12, 0x986c632f, 0x0f0cd3b7, 0xaffc3ee7,
+    0xebe14da6, 0x4e361de1, 0x97fa7e3a, 0x1fe23337,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0xb8000000, 0xfc000000, 0xba000000, 0x3b000000,
+    0x95800000, 0xa7400000, 0x4e600000, 0x09b00000, 0x40e80000, 0x160c0000, 0x647a0000, 0x97330000,
+    0x9a2b8000, 0x9b2c4000, 0xf5aca000, 0xe76cf000, 0x9e4c6800, 0x619ae400, 0xd4c5ee00, 0x38279500,
+    0x7154ac80, 0x179e3dc0, 0xbdc144a0, 0xf2a799f0, 0x95918aa8, 0xc7bfbcd4, 0xa8946e06, 0x3338d52b,
+    0x11538c88, 0x679e8dd5, 0xb5c18c85, 0x26a18de8, 0x6b900cb0, 0xbabecdf1, 0xbc172ca0, 0x3afe7dc5,
+    0x6df7e4b2, 0x870869c7, 0xb2fe62a2, 0x79f518ca, 0x590f201c, 0x5ffcb034, 0xd572c812, 0xacc9143c,
+    0x99580610, 0x0ee23115, 0x3e7662b9, 0x614918f0,
+    0x80000000, 0xc0000000, 0x60000000, 0x50000000, 0x78000000, 0x0c000000, 0x7e000000, 0x57000000,
+    0xfd800000, 0xf5400000, 0x46600000, 0xe7100000, 0x9b680000, 0xa84c0000, 0xc93a0000, 0xd5410000,
+    0xb6678000, 0xaf124000, 0xbf692000, 0xa24c5000, 0xec3b2800, 0x01c7a400, 0xe9a57e00, 0xe1b03500,
+    0xeb5b8180, 0x985580c0, 0x380989a0, 0xfbde74f0, 0x5d97dfb8, 0x4ba9e5e4, 0x27692026, 0x3e4c5027,
+    0x8a3b2819, 0x0ac7a402, 0x12257e3e, 0x4ff0352b, 0x2ebb81b1, 0xdd0580d1, 0x188189a8, 0x41c274de,
+    0x49a5dfbd, 0xd1b4e5c2, 0xc35ca01f, 0xec531013, 0x4a0f8829, 0xd2d8b434, 0xf710f607, 0x43698128,
+    0x944cf79f, 0x9f3e41c1, 0xf6445e13, 0x39e06500,
+    0x80000000, 0x40000000, 0x20000000, 0x90000000, 0x38000000, 0x74000000, 0x1a000000, 0xa7000000,
+    0x8f800000, 0xc8400000, 0xb4200000, 0x30f00000, 0x75f80000, 0x599c0000, 0x854e0000, 0x04470000,
+    0x9a278000, 0xadf44000, 0xcd7ee000, 0x265eb000, 0x8d284800, 0x9a969400, 0x78288600, 0x0e17df00,
+    0xd9ee8480, 0x13703940, 0x23b82ca0, 0xd8b81d50, 0x4138e2a8, 0x60795674, 0x76dee026, 0x9eeeb029,
+    0x6cf04800, 0x63fa9410, 0xb09e862b, 0x27ccdf06, 0xdc870488, 0x1dc37957, 0xfb614cb3, 0x9b52ed78,
+    0xb54e4ab9, 0xec41726f, 0xf6262e3c, 0x53f3fb15, 0x48784ab4, 0x7ada7273, 0xd0efae3d, 0x41f0bb12,
+    0x73792a8e, 0x835c825f, 0x41af0636, 0xff539f04,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x38000000, 0xac000000, 0x0e000000, 0x31000000,
+    0xe0800000, 0x73c00000, 0xe8600000, 0x9ff00000, 0x4ea80000, 0xbd0c0000, 0x8e9e0000, 0x55c30000,
+    0x3d668000, 0x45754000, 0x9e6d2000, 0xb2e8d000, 0x1f2a9800, 0x6e4dfc00, 0xb53f9200, 0x12509900,
+    0xd43a0280, 0x1ad599c0, 0xa3fdbaa0, 0x58b0b5d0, 0x9708b088, 0x659dd0dc, 0x86452006, 0xa124d037,
+    0x37549825, 0xd6befc2b, 0xf6111235, 0xaa19d93b, 0x6c012281, 0xee0249f7, 0xa107a2b1, 0xe88709c4,
+    0xe7c28282, 0x4a63d9c1, 0xa0f61ab9, 0x9f2d25dc, 0x2e4f088e, 0x1538fcd3, 0x22502a12, 0xec39b501,
+    0xb6d108aa, 0xadfbfce3, 0x69b6aa23, 0x778cf53a,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xf8000000, 0xcc000000, 0xf6000000, 0x75000000,
+    0x28800000, 0xa7400000, 0x3b200000, 0xa0300000, 0x1f580000, 0x6f3c0000, 0x322e0000, 0xa0430000,
+    0x44a48000, 0xb0f14000, 0xa0ba2000, 0x3aea1000, 0x03a47800, 0x6f725c00, 0xc0795600, 0x7d0de700,
+    0x9a764280, 0xa8faf240, 0x7a481aa0, 0xd152be50, 0xf0cd3488, 0xa911054c, 0x486c2036, 0xb7e5103f,
+    0x21d6f82b, 0xab8c1c32, 0x3c31f609, 0x2159b707, 0x8e3a9ab9, 0x50acfe5c, 0x7c0594a3, 0xee05554a,
+    0x6900f80d, 0x56831c17, 0xe643760c, 0x29a7f730, 0x84723ab6, 0x79f8ae64, 0x40c94cae, 0xb113595d,
+    0x546d7614, 0xc9e4f707, 0x60d6ba89, 0xb909ee72,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0xb8000000, 0x6c000000, 0x8e000000, 0xff000000,
+    0xec800000, 0xb9c00000, 0xdfa00000, 0xe1100000, 0x44f80000, 0xfe9c0000, 0xc62a0000, 0xb8c30000,
+    0x64218000, 0x40524000, 0x10982000, 0x092ed000, 0x1c42e800, 0xd9e3ec00, 0xc5f2d200, 0xec8f1700,
+    0xd0569b80, 0x489ac240, 0x952c53a0, 0xfa47fe70, 0xf2e469a8, 0xcb770544, 0x244a2006, 0x1c71d01f,
+    0xfcc96805, 0xb7b2ac10, 0x3a6b7236, 0x9123870a, 0xc9d453af, 0x78dbfe69, 0x22ce69bd, 0x30b40559,
+    0x9aeba03f, 0x76e3902d, 0x5171481f, 0x194c7c2e, 0x51f19a21, 0xee8c6b18, 0x51548182, 0x331be975,
+    0x146b721e, 0x7e238729, 0xbd5453ac, 0x7d1bfe6c,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0xf8000000, 0x5c000000, 0x42000000, 0xbb000000,
+    0x29800000, 0x2cc00000, 0x78200000, 0x67f00000, 0xd6880000, 0x166c0000, 0x4fba0000, 0x4bc10000,
+    0xd3a68000, 0x90374000, 0xf72ea000, 0xa55b3000, 0xbd172800, 0x785b8400, 0xf1917200, 0xe419ab00,
+    0x47f3b880, 0x068b3ac0, 0xfe6a30a0, 0x9bbb8ef0, 0xadc46ab8, 0x76a5a1ec, 0x40b4a006, 0x496a303b,
+    0xd839a836, 0x8e00c425, 0xb105d224, 0x56839b32, 0x6b421084, 0x55e7fefc, 0xd155e281, 0xf63915e7,
+    0x2f00fa00, 0x6f851f22, 0x99c7e2b4, 0x20a415f8, 0x7db47a14, 0xb5ef5f16, 0xdc7dc2a4, 0x146565ea,
+    0xe9917224, 0x0819ab3b, 0x9df3b8a1, 0x918b3ac8,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0x28000000, 0xe4000000, 0x02000000, 0x19000000,
+    0x64800000, 0x83c00000, 0xed600000, 0xacd00000, 0x18080000, 0x84ac0000, 0x341a0000, 0xe0450000,
+    0x8d228000, 0xe1f5c000, 0x19fde000, 0xed527000, 0xf149f800, 0xf508fc00, 0x7a2e7200, 0x82dc1100,
+    0xffa7b380, 0x91311dc0, 0x8d1baba0, 0xd4c791f0, 0xb6e62198, 0xb0967cec, 0x7b2d6012, 0x0a5eb015,
+    0x0a649832, 0x3f564c23, 0x904aea13, 0xcd8a5d1f, 0x47ed59a2, 0xb8bb40cd, 0xc8f6f204, 0x757cd11a,
+    0x1a90d388, 0x462aadee, 0x8cddb382, 0xe0a41de8, 0x9eb12bb0, 0x5b5e51d1, 0x3ae1c192, 0x76910ccb,
+    0xf02e180d, 0x2fdf8c1e, 0x21250a30, 0xb7f12d1b,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x88000000, 0x6c000000, 0x3a000000, 0x0d000000,
+    0xce800000, 0x07c00000, 0x2d600000, 0xfb300000, 0xaa080000, 0xb2ac0000, 0xc5fa0000, 0xc0470000,
+    0xa2208000, 0xbe92c000, 0x2bdca000, 0xf4d2f000, 0xacfcd800, 0x98c6cc00, 0x32e3a200, 0x20766100,
+    0x91ae6c80, 0x187a72c0, 0xf20614a0, 0x01024ef0, 0x24836e88, 0xf2c5e3ec, 0x07e6202a, 0xaaf73037,
+    0xb068f804, 0x8a1afc04, 0xa6b1da1f, 0x58495d19, 0xb18b96b6, 0x2fef1ffc, 0x915ffa32, 0x3d126d23,
+    0x82996eb8, 0x0e72e3fe, 0x82aea031, 0x2df9f018, 0x7c465835, 0x60230c3d, 0x57978218, 0xb35a512e,
+    0xc41414a8, 0x42194eee, 0xaab1eeba, 0xb24c23f5,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0x38000000, 0x9c000000, 0x1e000000, 0x19000000,
+    0xf5800000, 0xbd400000, 0x9e200000, 0x32f00000, 0xd1c80000, 0x04ac0000, 0x357a0000, 0xd1c50000,
+    0x16e58000, 0xf1924000, 0xed19a000, 0xdf91d000, 0x7c1ad800, 0xfe149400, 0xeb580e00, 0xc3347700,
+    0xb72eec80, 0x5d39d8c0, 0x7c6594a0, 0x8c509cf0, 0x6dfd4298, 0xe3057fd4, 0x4e862016, 0x3fc69033,
+    0x67e6f831, 0x40170405, 0x425b7634, 0x7eb1330e, 0x3e6c3a9d, 0x79193be5, 0xd593760e, 0xaf1d3312,
+    0x38963aa6, 0x009c3bdd, 0x8ed6f612, 0x543f731e, 0x71e79a8c, 0x4d11ebdd, 0x7dde2e32, 0xf0f2e70c,
+    0xf6c81486, 0x982edcd6, 0xd5bee2af, 0x56a1afe1,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x68000000, 0x9c000000, 0xb2000000, 0xc3000000,
+    0xad800000, 0x0dc00000, 0x98600000, 0xc7100000, 0x9b480000, 0x88ec0000, 0x5a9e0000, 0x0a470000,
+    0xe2a48000, 0xfcf5c000, 0xd0982000, 0x8d463000, 0x29256800, 0xec37e400, 0xfc7c9a00, 0xb5168100,
+    0x384c7580, 0xf56eb1c0, 0xcf593da0, 0x662365d0, 0x0bb6cfa8, 0x16b900ec, 0x36f4a032, 0x779ff00b,
+    0xb6c3483e, 0x51e6d42b, 0x5cd5721d, 0x7628a514, 0x677ecfa1, 0x8c9500c2, 0xcb8aa00b, 0x8408f000,
+    0x92cfc81e, 0x732f142d, 0x67fb5212, 0x7dd59538, 0xc0a927b6, 0x133c24ee, 0xc7349a0d, 0x8dfa8134,
+    0x2ad275af, 0x9329b1f8, 0xf7fdbda7, 0xc5d6a5fe,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x58000000, 0x64000000, 0x42000000, 0xef000000,
+    0x75800000, 0x43400000, 0x21200000, 0xccb00000, 0x72480000, 0xce2c0000, 0xfabe0000, 0xe4c70000,
+    0xb5618000, 0x8c114000, 0xb7bb6000, 0x4e47f000, 0xaba44800, 0x8277c400, 0x242c4e00, 0x39be7f00,
+    0xcf405680, 0x6f25eec0, 0x2db77ea0, 0xc6c9dad0, 0x69e978a8, 0x245b61c4, 0x9292e03e, 0xf37ab01d,
+    0xeb21280e, 0x5fb73436, 0x01c9861b, 0x7068fb32, 0x191f7885, 0x7ab061d8, 0xe74d6029, 0x40acf000,
+    0x867bc822, 0x55a1842d, 0x0b76ae19, 0xdca8cf30, 0xb07f7eae, 0x80a5daf2, 0xa5f77897, 0xf06c61d0,
+    0x591b602e, 0x5ab7f00a, 0xb74c4812, 0x18abc40b,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xe8000000, 0xfc000000, 0xbe000000, 0xdf000000,
+    0x9e800000, 0x24400000, 0xa1a00000, 0x4cf00000, 0xcf880000, 0xfd6c0000, 0xb2ba0000, 0x4b850000,
+    0xd7c78000, 0xa266c000, 0x4c962000, 0x321d5000, 0x66f0e800, 0x9289fc00, 0xe2e82e00, 0x077c5b00,
+    0xb8e40880, 0xe2d176c0, 0x3eb8c0a0, 0xed80daf0, 0x2cc78698, 0x46e3bdc4, 0x1dd58022, 0x903fc03b,
+    0xf1c3a02d, 0x9962903c, 0x48134832, 0xb35b6c01, 0x2cd36620, 0xf9bb372d, 0x57056ebc, 0x728341e5,
+    0x92402e17, 0x52a05b0e, 0x84760892, 0xc8c876f1, 0x7c4d4090, 0x050f1af9, 0xbb2c2688, 0x421d2df3,
+    0x7ef4c837, 0xb68dac1b, 0x98ed460a, 0x727a6732,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x58000000, 0xdc000000, 0x7a000000, 0xdb000000,
+    0x14800000, 0xd9c00000, 0x9ce00000, 0xa9100000, 0x3e280000, 0x680c0000, 0x6d7e0000, 0x35810000,
+    0x56478000, 0xb1a74000, 0xbc36a000, 0x53dff000, 0x2337e800, 0x415fac00, 0xebf3b200, 0x1a3a0d00,
+    0x0ba6a180, 0xc7363ec0, 0xf759e9a0, 0xf2f762d0, 0x59ba3398, 0xe4e583c4, 0x8511800e, 0xac2a4033,
+    0x670f2030, 0x87f9b015, 0x9146c80d, 0x7f271c37, 0x0ef2fa3c, 0xd3ba512a, 0xd7e2fbad, 0x45939fc6,
+    0x8becfa13, 0x86eb512d, 0x9b6d7bb9, 0xb928dfef, 0x468c5a17, 0x8fb9a112, 0xede31394, 0x7e9133ca,
+    0xcf6ec82e, 0x072b1c13, 0xdb8cfa31, 0x6a3b512f,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0xc8000000, 0xec000000, 0x8e000000, 0xfd000000,
+    0xd6800000, 0xa6400000, 0x8f200000, 0x69900000, 0xbc180000, 0x307c0000, 0x544a0000, 0x2d870000,
+    0x01c68000, 0x61e6c000, 0xc5f4a000, 0xa82d5000, 0x21b0f800, 0x240b2c00, 0x0ba56e00, 0xb4d47f00,
+    0x54b94780, 0x172c3540, 0xf4371fa0, 0xc1cd4970, 0xa8440988, 0xb224da5c, 0x1f14803a, 0xaa5dc027,
+    0x77582039, 0xd1dc9028, 0x1f9ad824, 0xccbcbc2d, 0xe32b3635, 0x4e35030c, 0x26ca51b9, 0x29c5a67f,
+    0x5de79639, 0x93f4532d, 0xc128a992, 0x5d358a73, 0x1d4e7836, 0x2101ec32, 0x5083ce32, 0x17422f28,
+    0x67a53fbc, 0xfad6d95c, 0x09b851bf, 0x71aea674,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x18000000, 0xec000000, 0xda000000, 0x99000000,
+    0x5c800000, 0x49400000, 0x56600000, 0xa5b00000, 0x93880000, 0xe9ac0000, 0x34fe0000, 0x46850000,
+    0xf0468000, 0x9ae44000, 0x04f02000, 0x31e97000, 0x7a197800, 0xe476fc00, 0x6aad6600, 0xd17a9d00,
+    0xc3449480, 0x0765a2c0, 0x9d33cca0, 0x04cf2ed0, 0x08c952b8, 0x62cb0fec, 0x83ce803e, 0xc3484017,
+    0x480e2028, 0x6b6c7021, 0x485ff827, 0x0b92bc0f, 0xe8dd4634, 0x30d3ed1d, 0xb3bdeca6, 0x0fe35ec1,
+    0x3276aa9c, 0x99a9b3d2, 0x6cfbc62d, 0xca87ad21, 0xda45ccae, 0x1be62efc, 0xb471d2b3, 0xa2aa4fde,
+    0xb578201c, 0x1d457019, 0xb0677828, 0x6eb3fc30,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x18000000, 0x2c000000, 0xa6000000, 0xd1000000,
+    0xef800000, 0xc2c00000, 0x02200000, 0x78f00000, 0x72a80000, 0x754c0000, 0x491a0000, 0x12070000,
+    0x9b048000, 0x38824000, 0x0c462000, 0x37649000, 0x9412d800, 0xae7cc400, 0xae13e200, 0xa17f7b00,
+    0xec94c080, 0xc239c3c0, 0xc97238a0, 0xdd6a97f0, 0x76ed8288, 0xe62c68cc, 0x1f08000e, 0x0f7c0007,
+    0x99920007, 0xefbb0003, 0xb8b68014, 0x73c9400f, 0x7858a012, 0xcce1d01a, 0xecd07827, 0x635a1433,
+    0x34671a0e, 0x40972f07, 0xa43dfaa8, 0xb8767cd7, 0xc2ef1a17, 0xac2b2f37, 0xc80ffaa8, 0xc1fd7cd4,
+    0xacd19a2b, 0x035e6f35, 0x64655ab4, 0xa897acfb,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0xb8000000, 0xcc000000, 0x76000000, 0x75000000,
+    0x36800000, 0x27c00000, 0xa4600000, 0x93500000, 0xbd580000, 0xc5fc0000, 0x5d4a0000, 0x64050000,
+    0xc2058000, 0x2f064000, 0xa5872000, 0xdc401000, 0x79267800, 0x66f1ac00, 0xff6b2200, 0xdd71a900,
+    0x022e8780, 0x58d75340, 0x681ddfa0, 0x345fef70, 0x8d7f0598, 0x0b8caa54, 0x64e00036, 0x01900027,
+    0xcfb8000b, 0xe16c000a, 0xfc72003e, 0xfea90027, 0x54178039, 0xfbff4030, 0x0c48a00b, 0xb083501c,
+    0xbac4d811, 0x06e7fc0c, 0x9e927a08, 0xa23c1523, 0xd929dd8e, 0x87525665, 0x575dfa27, 0x9eff5515,
+    0x42cb7db0, 0xbb44067d, 0xf8a4a22f, 0x58b2e93b,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x88000000, 0xac000000, 0x6e000000, 0xe5000000,
+    0x55800000, 0xddc00000, 0x2e200000, 0x88300000, 0xca280000, 0x1acc0000, 0xd05a0000, 0x5c070000,
+    0x86068000, 0x5904c000, 0x0384a000, 0x1cc25000, 0xb9a13800, 0xdef6f400, 0x548b6a00, 0x1ab93300,
+    0xe991de80, 0xe0d861c0, 0x144646a0, 0xd5e7c5f0, 0x4f109488, 0xc99bc2dc, 0x6020002a, 0x1d300037,
+    0xb7a80011, 0xdb0c003e, 0x187a002c, 0x9d370025, 0x77ae8005, 0x7b08c02c, 0xa87ea02c, 0x15355013,
+    0xdbafb80d, 0x150e3433, 0x4d7dca27, 0x40b06307, 0x066c6695, 0x3b2d55c6, 0xc54f0caf, 0x8a9866d6,
+    0x1ca4d233, 0xeb77072a, 0x994c148b, 0x0c9802ce,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x68000000, 0x04000000, 0x42000000, 0x17000000,
+    0x89800000, 0x65400000, 0x53a00000, 0x6cd00000, 0xb8e80000, 0x9f0c0000, 0x1cfa0000, 0xe3070000,
+    0x63808000, 0xd645c000, 0xe820e000, 0x76917000, 0x66ca8800, 0xd49ae400, 0xe0b5de00, 0x995efb00,
+    0xa2d05180, 0xf9eafcc0, 0xfb8839a0, 0xfb3a68f0, 0x7265ef88, 0x68b0b7ec, 0x8d5a0012, 0x58d7002d,
+    0xf2e88022, 0x5c09c018, 0xcf7ae028, 0xfd46701d, 0xffa20826, 0x8ad32407, 0x9def3e18, 0x69888b25,
+    0xf43a59bd, 0x97e5d8c4, 0x4bf50791, 0x8bf9e3da, 0xaa85362d, 0xa6c7af3c, 0xf5e76789, 0xecf653c0,
+    0xca7dde31, 0xbbc2fb3a, 0xdf625193, 0x8a31fced,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x28000000, 0x34000000, 0xd6000000, 0xa7000000,
+    0x14800000, 0x9a400000, 0x62200000, 0xcbf00000, 0x7c180000, 0xd17c0000, 0xa52e0000, 0xf7050000,
+    0xcc848000, 0xe647c000, 0x30256000, 0x92f65000, 0xfb9a2800, 0x89bf2c00, 0xfa4f4e00, 0x4a526700,
+    0xe12d1c80, 0x29056e40, 0x8f8454a0, 0xbcc51250, 0x1163b2b8, 0x0656997c, 0xdb2e0032, 0x24050031,
+    0x6e048027, 0x6b07c008, 0x6e85603a, 0xf7465002, 0x33a22828, 0x34332c3d, 0x37f94e02, 0xf66b6720,
+    0xeaa79cae, 0xf3b7ae7b, 0x0f3db4a5, 0x19088260, 0x7ff2faae, 0xea1ae55c, 0x167fe633, 0x01af8b0e,
+    0x4543b2ab, 0x9aa6995c, 0xfbb60022, 0xeb390028,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x28000000, 0xa4000000, 0xf2000000, 0x4d000000,
+    0xec800000, 0x3ec00000, 0xc8e00000, 0xc5d00000, 0x18780000, 0xa2dc0000, 0xe12e0000, 0xfc670000,
+    0x17128000, 0x9699c000, 0xc009e000, 0xd4d5f000, 0xdafbd800, 0xbf1e1c00, 0xc64a3a00, 0xd8f3b500,
+    0x0ec9af80, 0x54309240, 0xab2917a0, 0x4d62be50, 0xa59115a8, 0x835ae75c, 0x73695822, 0x2a47dc35,
+    0x2a23da3e, 0x63364536, 0xdeaa778d, 0x65228e75, 0x8ab52d95, 0xb8ea0b7d, 0x79043a27, 0x4684b50a,
+    0xdfc32f8e, 0x42655243, 0x6c16f7ad, 0xd51c4e58, 0x474e4da2, 0x42763b6f, 0xbf0e0228, 0x15535934,
+    0x18bccdbd, 0x513ffb43, 0x477fe217, 0x535aa93e,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x78000000, 0x34000000, 0x7e000000, 0x17000000,
+    0xa9800000, 0xab400000, 0x22200000, 0x15f00000, 0x67d80000, 0xd73c0000, 0x23ae0000, 0x6fa30000,
+    0x08b08000, 0xbef94000, 0xd149e000, 0xfe379000, 0x103de800, 0x42288400, 0x28e2ee00, 0x90946700,
+    0xba0c3480, 0x3c118840, 0x55c8bca0, 0xadf7dc50, 0xb3de5a88, 0xf93caf6c, 0xbcad6816, 0x8a21c421,
+    0xe9f30e29, 0xf5dff734, 0x7a3fdcab, 0x9b2a0c62, 0x4e62d29d, 0x5656fb7a, 0x356d8e33, 0x28c5b736,
+    0xf066bcaf, 0xa154dc5a, 0xcceedab2, 0x0b85ef59, 0x9e448803, 0xfea65408, 0xc236e626, 0x623b731e,
+    0xbf2b32aa, 0x18616b73, 0xfd506624, 0xaeed332a,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xa8000000, 0xd4000000, 0x1a000000, 0x1b000000,
+    0x47800000, 0xcf400000, 0xd3600000, 0x00900000, 0x2ac80000, 0x696c0000, 0xe9de0000, 0x0ee30000,
+    0x94d48000, 0x1e2bc000, 0xd6bc6000, 0xb8753000, 0xb319a800, 0x4dc0b400, 0x62a05e00, 0xec343d00,
+    0x67fd1580, 0x221681c0, 0xeb0c5da0, 0x2dc8c5d0, 0x70e9cb88, 0x5f997ccc, 0x8d052822, 0x7a877439,
+    0xfdc23e04, 0x2aa20d2f, 0xe8303dbd, 0xa5fdf5c0, 0x45106383, 0x6289c8e5, 0xe38d7627, 0xff0f4925,
+    0xd7c92b86, 0xbbeb8cf0, 0xc01ee019, 0x3e41f03d, 0x67e74815, 0xfc514403, 0x5cef1633, 0x0999793c,
+    0x500403b9, 0x9800f8da, 0xdc02de2c, 0xbe00fd1a,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0xb8000000, 0x64000000, 0xd6000000, 0x95000000,
+    0x30800000, 0x3ec00000, 0x3d200000, 0x07f00000, 0x06c80000, 0xd1ac0000, 0xc3ba0000, 0x8aa50000,
+    0x9ab68000, 0x08ad4000, 0xc93d6000, 0xcb625000, 0x40103800, 0xfa1b1c00, 0x0d929a00, 0xf8597b00,
+    0x1476b080, 0xad8a78c0, 0xbacd68a0, 0x53ae74f0, 0xd8baaa98, 0xbb2243cc, 0xfaf4b836, 0xfa4f5c15,
+    0x156b7a23, 0x619f6b00, 0x9ad76897, 0xd13b74f3, 0x7f642a96, 0x3e1303db, 0x431bd81a, 0x37140c36,
+    0xe19fc228, 0x5ad03732, 0x313c128c, 0x8f641fe8, 0x8613423d, 0x27187708, 0xe117f2bf, 0x749b0ffc,
+    0x6a561a21, 0x0ffd3b2e, 0xb24750aa, 0x81e068e4,
+    0x80000000, 0x40000000, 0xe0000000, 0xf0000000, 0xc8000000, 0xc4000000, 0xfa000000, 0xcd000000,
+    0x08800000, 0x03c00000, 0xc8e00000, 0x98b00000, 0x46980000, 0xd13c0000, 0x00ae0000, 0x37610000,
+    0xfef28000, 0x953ec000, 0xbaabe000, 0x1a61b000, 0x06729800, 0x5ef97c00, 0xb64bf200, 0x78d07d00,
+    0x8dee6c80, 0x87458e40, 0xb52594a0, 0x87538250, 0xebaf1e98, 0x54e7336c, 0xdeb6182a, 0x9d9abc1d,
+    0xdabc9234, 0xf4ee0d3e, 0xfdc5948e, 0x5fe38242, 0x4d371eae, 0x75db3345, 0x16181839, 0x6efbbc3e,
+    0xde4e1229, 0xacd0cd12, 0x4fee74a2, 0x4642325e, 0x83a5868d, 0xb3924f4d, 0xe6cbea38, 0xc717c13e,
+    0x530e7eab, 0x1cf44350, 0x04396032, 0x542f7003,
+    0x80000000, 0xc0000000, 0xa0000000, 0x70000000, 0x58000000, 0x24000000, 0xaa000000, 0x83000000,
+    0xc1800000, 0x37400000, 0x68e00000, 0x46700000, 0xb7680000, 0xa54c0000, 0xaa9a0000, 0x9e670000,
+    0x52b08000, 0xc7cc4000, 0xec5c6000, 0x39c4b000, 0x28236800, 0xc4d1c400, 0x597aee00, 0x5d931100,
+    0xa89abe80, 0xa16487c0, 0xbd3536a0, 0xa98db3f0, 0x9e38d088, 0xad77d6ec, 0x6ae9e81e, 0xcc0a8425,
+    0xc37e0e14, 0xe697e135, 0x9d1fb68c, 0x1426f3e4, 0x2ad430a0, 0xa07f26cc, 0x3716e00f, 0x225ff019,
+    0x70c78823, 0xbaa5340a, 0x1a17e62f, 0x7add6516, 0xfd01b89f, 0x708112f5, 0xf1c3862f, 0x4425d522,
+    0xc2d0d0b8, 0x7c7bd6cc, 0xc913e807, 0x535d8439,
+    0x80000000, 0xc0000000, 0x60000000, 0xd0000000, 0xd8000000, 0x2c000000, 0xfe000000, 0xdf000000,
+    0x21800000, 0x46c00000, 0x36600000, 0x19100000, 0x96080000, 0xb92c0000, 0xcf9a0000, 0x37610000,
+    0x97938000, 0x994b4000, 0xed8d2000, 0xeaea5000, 0xb5796800, 0xb8378400, 0x57b88200, 0x4454a900,
+    0x23ab9d80, 0x02dc63c0, 0xfac455a0, 0xb066f7f0, 0x5a129fb8, 0x418e8ac4, 0xd4eb680e, 0x0a7a840f,
+    0x49b10211, 0xc97ee908, 0x5e353d81, 0xc4bd73ed, 0x4bd01d9a, 0x626b23d0, 0x393b7598, 0x5b11a7fd,
+    0xcf0a7795, 0xdbaf4eea, 0x5edf4a02, 0x6cc23d3d, 0x4b6757b4, 0x71951ec8, 0x0a4e221f, 0xe209b929,
+    0xab2dd584, 0x8e9cb7ff, 0x19e43fb5, 0x28d39af0,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x08000000, 0x54000000, 0xae000000, 0xdd000000,
+    0x8e800000, 0x2ac00000, 0x20200000, 0xf1300000, 0xdea80000, 0x94cc0000, 0xa21e0000, 0x7b230000,
+    0x46b08000, 0x7ceac000, 0xe7296000, 0x5b899000, 0xf77a3800, 0x0c736c00, 0x4ccb0a00, 0x4e184300,
+    0x2921ce80, 0x91b605c0, 0x896a16a0, 0xca6a39d0, 0x71ec4488, 0xd1ab86ec, 0x8d4c380a, 0xdd5c6c39,
+    0x86458a01, 0x95e1831e, 0xd0902e8a, 0x5cd955e8, 0xb5074ea0, 0xca83c5d1, 0xdcc5f694, 0x512669ff,
+    0x8db19c9d, 0xd368bafe, 0x496cea2f, 0x2a68132a, 0x21ea16a2, 0x29aa39c3, 0xd14c44b7, 0x275b86ce,
+    0xf5443828, 0xc6606c0e, 0x74d38a3a, 0x563e8310,
+    0x80000000, 0x40000000, 0x20000000, 0x30000000, 0x28000000, 0x2c000000, 0x2e000000, 0xeb000000,
+    0xa2800000, 0xcac00000, 0x08200000, 0x14b00000, 0xf4f80000, 0x529c0000, 0x23ce0000, 0x57270000,
+    0xdc328000, 0xf739c000, 0x0d3da000, 0x9c3f5000, 0xefb84800, 0x76fdbc00, 0xd79bda00, 0x4a4da300,
+    0x0f616280, 0x36d3cb40, 0xc7ae0aa0, 0xc374e750, 0xd15e38a8, 0x3c6ca85c, 0xc8d64822, 0x54aabc17,
+    0x75f15a09, 0x0198633d, 0xf54ac2a9, 0x97e79b43, 0x0d12c281, 0xac0b9b5d, 0x5684c2bf, 0x80c09b7f,
+    0xf1204295, 0x6b325b53, 0x73b962ab, 0x30ffcb51, 0x30980abd, 0xf6cfe750, 0x06a2b88c, 0xb072685d,
+    0x37d96833, 0xd4ac2c26, 0x35f4b21b, 0x219a8f09,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x48000000, 0xc4000000, 0x7e000000, 0x03000000,
+    0xec800000, 0x7dc00000, 0xa1a00000, 0x2d500000, 0xf8580000, 0xbabc0000, 0xb28e0000, 0xf7a30000,
+    0x3a568000, 0xb2dec000, 0x7879e000, 0x0da99000, 0x1e36c800, 0xf22a0c00, 0xb7765200, 0x024e6700,
+    0xb1811980, 0xe245ed40, 0xd8e0b1a0, 0x0534b150, 0x42afcb88, 0xa8b64a5c, 0x62eec81a, 0x81560c2d,
+    0x32585225, 0x2fbd673d, 0xa90f99b6, 0x50e72d6e, 0x213751ac, 0xacae2144, 0xd3b783a5, 0x026e8666,
+    0x46977a20, 0xeefefb35, 0xed6f03b9, 0xc0134642, 0x36381a2a, 0x1f49ab2c, 0x8e002bb3, 0xab00da4b,
+    0xb880802b, 0x7bc1c024, 0x2ea1600b, 0x7bd45010,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0x58000000, 0x74000000, 0x06000000, 0x09000000,
+    0x6d800000, 0xe5c00000, 0xc3200000, 0xbe500000, 0x71a80000, 0x30cc0000, 0xe37e0000, 0xa5210000,
+    0x67518000, 0xa4294000, 0x3109e000, 0x7e5f5000, 0x6672d800, 0x7d7a9400, 0x78214a00, 0x7cd5bb00,
+    0x80ea9580, 0xb3ab3ec0, 0x57c82da0, 0xdbfbbad0, 0x97645f98, 0x62b6c5f4, 0xcfdad80e, 0x79b69429,
+    0x7d5f4a2f, 0x40f4bb3b, 0xd23b158c, 0x86427ee6, 0xa3e1cd8a, 0x12f4eac4, 0xed3e87af, 0xcac051ee,
+    0x97a5921a, 0x1e122f23, 0xeb4c5f92, 0x67bac5f7, 0x5784d801, 0x86c79429, 0x35a6ca1e, 0xa911fb19,
+    0x6bccf5aa, 0xb1fc2ed8, 0x0c629592, 0x90373ef4,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xb8000000, 0x74000000, 0x7e000000, 0x2d000000,
+    0x02800000, 0xaa400000, 0x15e00000, 0x8d500000, 0x8ad80000, 0x6afc0000, 0xc28a0000, 0xdea50000,
+    0x50308000, 0x1ecb4000, 0xea43e000, 0x35e39000, 0x5d563800, 0x02db0c00, 0xa6fdee00, 0xc88c4900,
+    0x8da76780, 0x7fb17b40, 0xb6083fa0, 0x55e7a770, 0xad508998, 0x5ad8327c, 0xe2ffd836, 0x0e8d9c21,
+    0xd4a35609, 0x0330051a, 0x314b69b1, 0x4287a27c, 0x8a43e03a, 0xc5e39032, 0x0556381f, 0x46db0c1f,
+    0x60fdee39, 0x918c4913, 0xf1276799, 0xf8f17b61, 0xa1683fbe, 0x72f7a767, 0x326889a4, 0xbd743255,
+    0xaaadd810, 0xbad49c04, 0x4619d624, 0xc35e4500,
+    0x80000000, 0xc0000000, 0xa0000000, 0x30000000, 0xb8000000, 0x1c000000, 0x9a000000, 0x3b000000,
+    0x0d800000, 0x88400000, 0x0a200000, 0x7fb00000, 0xb7e80000, 0x9b0c0000, 0x875a0000, 0x4f670000,
+    0x01128000, 0xfa994000, 0xb100a000, 0x7e87f000, 0x81c0e800, 0x3464e400, 0x6c903600, 0xe2dc9f00,
+    0x33273e80, 0xa531cbc0, 0xb02ff6a0, 0x0e6c9ff0, 0xdd4d0888, 0x287a54fc, 0xb0524826, 0x2a38143b,
+    0xc9705e20, 0x5c0a3b05, 0x40df288b, 0xd424e4eb, 0x92b20007, 0x336b0003, 0xb1c88004, 0x36be4017,
+    0x0fb22020, 0x6feeb02d, 0x1708482a, 0x955f1405, 0xd062de0e, 0x8a937b10, 0xd3df888e, 0x8da314f3,
+    0x84f2e81a, 0xb44fe43c, 0xdaf8b63e, 0x2392df03,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0xe8000000, 0x7c000000, 0x1e000000, 0x65000000,
+    0x90800000, 0x33400000, 0x10600000, 0xaa700000, 0x8b380000, 0x059c0000, 0x484a0000, 0x08230000,
+    0x77968000, 0x068fc000, 0x17812000, 0x18c25000, 0xf6a13800, 0x29d6dc00, 0xea697600, 0x48b27d00,
+    0x729aba80, 0xabcfa140, 0xa2e622a0, 0x0237ed70, 0x7ed9cca8, 0xd42edc7c, 0x74521812, 0xd5ab8c23,
+    0x6594ce35, 0x7588610a, 0x8e046c99, 0x7d004c4a, 0x24848007, 0x6140c00f, 0x8365a00a, 0x23f2902b,
+    0x36fc980e, 0x43b84c36, 0x62dfee2a, 0x1a29311b, 0xe953d4ae, 0xe1295049, 0xdcd4d601, 0x62eced17,
+    0xcff422a8, 0x30f8ed53, 0x92bd4c93, 0xa05e1c5f,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x18000000, 0xe4000000, 0x8a000000, 0xc9000000,
+    0x81800000, 0x61400000, 0x51600000, 0x35300000, 0x28f80000, 0xf45c0000, 0x9aca0000, 0x4e270000,
+    0xff578000, 0x11494000, 0x26676000, 0x8fb5b000, 0xd5383800, 0x47fe9400, 0xaadb8e00, 0xed0dad00,
+    0x3487f880, 0x4ec36d40, 0xd32520a0, 0xbcd60970, 0xe50e7688, 0x0885c054, 0x80c7580e, 0x0027242d,
+    0xec51b60c, 0xe1c8392f, 0xb2a1f684, 0x2d908053, 0x9c6a3836, 0x51b59417, 0xde3e0e16, 0x537fed0c,
+    0xb41d18be, 0x86289d5a, 0xfc55f891, 0x39c86d68, 0xf6a0a0b8, 0x37944978, 0x4d6c9699, 0x34323056,
+    0x357d8030, 0xcb1e402b, 0x00a8e018, 0xcf90f01d,
+    0x80000000, 0xc0000000, 0x60000000, 0x30000000, 0xc8000000, 0x5c000000, 0x1e000000, 0xff000000,
+    0xd4800000, 0x68400000, 0xbbe00000, 0x46300000, 0x19380000, 0x1f1c0000, 0x510a0000, 0xa1210000,
+    0x5e948000, 0x226cc000, 0x31512000, 0x534a5000, 0x57c2e800, 0x85262c00, 0x14975e00, 0xff6b5900,
+    0x34d61c80, 0xd88c3f40, 0x0ce354a0, 0x0eb18370, 0x0f7942b8, 0x6bfb667c, 0x0bbf480a, 0x8c5cbc2b,
+    0xe46e962d, 0xca562524, 0x5dcf2a80, 0x7a818a72, 0x9f41363c, 0x5361b504, 0x00706285, 0x95dd3640,
+    0xd1afa02e, 0x3e779019, 0xfadf481a, 0x9d2cbc14, 0xf2369608, 0xcb3a2539, 0x661d2aa7, 0xde8c8a53,
+    0x97e7b60f, 0x30307527, 0xea3fc2bf, 0x2d9aa65d,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x18000000, 0x9c000000, 0xee000000, 0xc1000000,
+    0x2e800000, 0xd4400000, 0x4be00000, 0x75b00000, 0x9cc80000, 0xf2ec0000, 0x9f7a0000, 0xf3230000,
+    0x8d138000, 0xe91d4000, 0x0354a000, 0x59fd5000, 0xd3609800, 0x1cf28c00, 0xa7a81e00, 0x1a19e900,
+    0xe2d2e080, 0xd238fcc0, 0xea4758a0, 0x72e560f0, 0x7732fea8, 0x2e8d15d4, 0xfc0fb82e, 0xb64e9c33,
+    0x94ae2635, 0xdb993533, 0xf913e6b6, 0xf31dd9e2, 0x18528602, 0x4c786528, 0x82a17eb6, 0x1ed055f0,
+    0x4c3b1834, 0x8343cc0a, 0x4866be10, 0xc977b92e, 0xd669f889, 0x883b30c2, 0x1141e69e, 0xd762d9f6,
+    0xaef30619, 0xa8aa250a, 0x659c5e9e, 0x001345d7,
+    0x80000000, 0xc0000000, 0xa0000000, 0x10000000, 0xb8000000, 0x64000000, 0x06000000, 0xcf000000,
+    0x2a800000, 0xa8c00000, 0x3b200000, 0xcd900000, 0x94580000, 0xdc3c0000, 0xc3ca0000, 0x85670000,
+    0x4c738000, 0xa3e84000, 0xac726000, 0xd3e8d000, 0xa4746800, 0x1fe99c00, 0x7e76aa00, 0xb2e86b00,
+    0x9df60380, 0xffa8cf40, 0x24918ba0, 0xa1dac370, 0x4678a988, 0x242ca474, 0xcdd58826, 0xbeb90c2d,
+    0xc708a207, 0xd445272e, 0x84e6c1a5, 0x08323840, 0x5f08a225, 0x6045271a, 0x9ae6c189, 0xb332386a,
+    0xcb88a215, 0x6385270c, 0x8d46c1af, 0x19623849, 0x4e70a20c, 0xdae9273e, 0xe1f4c1b6, 0x8da9385f,
+    0x5591223c, 0x205a672b, 0xc23f2186, 0x78cea871,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0x08000000, 0x94000000, 0x52000000, 0xff000000,
+    0x15800000, 0x7d400000, 0x48e00000, 0x92b00000, 0xc2e80000, 0x67cc0000, 0x0e5e0000, 0xd4210000,
+    0xa5158000, 0x0fbfc000, 0xa6106000, 0x8c3c7000, 0x9e52c800, 0xaa5f4400, 0x3e27fe00, 0x36172100,
+    0xc438e880, 0xea571dc0, 0xe859c0a0, 0xc926e9d0, 0xb7971698, 0xeb7c3cd4, 0x5db7281a, 0x6f6cf431,
+    0x768d562e, 0x98b8151e, 0x7793de93, 0x4b7e78c5, 0xadb35623, 0x77691514, 0xea8e5e9f, 0x5ebdb8e6,
+    0xda953603, 0xa1f8653d, 0xc57716b6, 0x42cc3cd4, 0x30df2824, 0x0ee0f43f, 0x7fb35619, 0xc8691515,
+    0x1f0e5eb6, 0x33fdb8e7, 0x9a75363c, 0xa7486523,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0x84000000, 0xa2000000, 0xc5000000,
+    0xbb800000, 0x5c400000, 0xdce00000, 0x1d100000, 0xe5e80000, 0x11cc0000, 0x7efa0000, 0x43250000,
+    0x39b68000, 0x96194000, 0xe4366000, 0x8d5ef000, 0x56541800, 0xbf8f6400, 0x8f1bd600, 0x49b07900,
+    0x6e193d80, 0x8037a8c0, 0xdf5fc5a0, 0xab567cf0, 0x800aeb98, 0x715bd1cc, 0x5054f816, 0xc888d42d,
+    0x3999ae1e, 0x1271ed2c, 0xc8bef388, 0x26c4b5c7, 0x59272e11, 0xb8b4ad11, 0x6f9a9398, 0x8d7345e8,
+    0xd23fb63f, 0x93078902, 0x2481a5b8, 0x46c48cfc, 0x6924f3a8, 0x60b1b5d0, 0x1b99ae25, 0x1771ed13,
+    0x933ef3ae, 0x8a84b5d0, 0xbdc72e03, 0x21a4ad2a,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xe8000000, 0x2c000000, 0x3a000000, 0x4b000000,
+    0xc3800000, 0x7dc00000, 0x5a600000, 0xc1f00000, 0xd4080000, 0xed2c0000, 0xf9fe0000, 0xe3230000,
+    0x8fd48000, 0x4159c000, 0xd531a000, 0x82edf000, 0x249be800, 0x0b501400, 0xf51fea00, 0xf194c100,
+    0x86f82280, 0x9ea704c0, 0x0f10eaa0, 0xd9bc20d0, 0x0987c888, 0x9ec3c5cc, 0x15e0c832, 0xf6372407,
+    0x2d69221c, 0xc35ce505, 0x6a338084, 0x1f6d21c6, 0x74584a08, 0x0bb6312d, 0xeda94abb, 0xfbbdd0c8,
+    0xc6822087, 0xeb40d1e8, 0x6923a22e, 0xccd6250d, 0x7edea08a, 0x0af511c0, 0x578c021d, 0x30e8d50b,
+    0xd399c8a2, 0xcad0c5f4, 0x77dc4817, 0x9672e415,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x98000000, 0xc4000000, 0xba000000, 0x81000000,
+    0x1a800000, 0x74400000, 0xfa600000, 0xe6700000, 0x75080000, 0x46ac0000, 0xeb7e0000, 0x07a50000,
+    0xefd48000, 0x19da4000, 0xd0f36000, 0xc74d3000, 0x93c8f800, 0x048a8c00, 0xc16ebe00, 0x43da0500,
+    0xa1f2d280, 0x25c920c0, 0x938bcaa0, 0xdceddcd0, 0x621c6cb8, 0x965325fc, 0x1219181e, 0x2e54fc0d,
+    0x061fa610, 0xbc52f93d, 0xdb1b748e, 0xd8d2d9cc, 0x945a3e36, 0xb9304514, 0x1ce9b2bd, 0x421810d9,
+    0x4655329f, 0x3a1e50c8, 0x7250528a, 0x781a60ca, 0x87522aad, 0x409face6, 0xb61374a5, 0x1a7ed9e2,
+    0xa5243e0b, 0x8f954501, 0x71bd32ae, 0xeb8250c0,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x48000000, 0xcc000000, 0x3a000000, 0x9f000000,
+    0x4b800000, 0x71c00000, 0xca600000, 0x24b00000, 0xbd680000, 0x59dc0000, 0x84b60000, 0x0d6b0000,
+    0x31da8000, 0xb8b3c000, 0x7f6f2000, 0x62dd3000, 0xc936e800, 0x91a98400, 0xe33ce200, 0x9c433500,
+    0xe6a6f280, 0x9e542cc0, 0xa59a10a0, 0xb21719d0, 0x2b3ce228, 0x1043352c, 0xfca6f29a, 0xf1542ccf,
+    0xa61a10bc, 0x0fd719c4, 0xdb5ce234, 0xabf33517, 0x0a4ef2ba, 0xd9482cda, 0xe8cc10a1, 0x260c19db,
+    0x57ee623a, 0x4a9cf502, 0xf197d297, 0xb6fe1cf7, 0x102078b2, 0x0f165ded, 0xcbbda017, 0xb402f03e,
+    0xde07c81d, 0xb903b434, 0x56868a13, 0x2142713d,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xa8000000, 0xcc000000, 0xb2000000, 0x0f000000,
+    0x59800000, 0xd6c00000, 0x72600000, 0x21500000, 0x94580000, 0xd50c0000, 0xb1520000, 0x2c590000,
+    0x610a8000, 0x5756c000, 0xf55a2000, 0x49897000, 0x65132800, 0x07789400, 0x95fd0a00, 0x74be0d00,
+    0x151e0d80, 0x44aea240, 0xa8e307a0, 0x3c10af70, 0xeffd0a18, 0x47be0d3c, 0xb69e0d92, 0x616ea27f,
+    0x990307a6, 0x0880af40, 0xe2450a22, 0x6a220d0a, 0xb8740d98, 0x6faba242, 0xaf63879f, 0x87d36f7d,
+    0x531faa2b, 0x8da8bd34, 0xf86585ac, 0x5a55865c, 0xcbdd05ba, 0xeaca467a, 0x43b5a58f, 0xf349f667,
+    0xb576adb0, 0x512d1270, 0x0c200fa2, 0xa1744b7a,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x58000000, 0xac000000, 0xf6000000, 0x85000000,
+    0x11800000, 0x6f400000, 0x09200000, 0x24900000, 0x2c380000, 0x30ac0000, 0xa0960000, 0x6e3f0000,
+    0x4fae8000, 0x32134000, 0xcdf8a000, 0x944c5000, 0x86e0d800, 0x49740400, 0xbdcba600, 0x55a1eb00,
+    0xbe506980, 0x185c6340, 0x6a1bcfa0, 0x3ebd8850, 0xd36ba608, 0xdb71eb2c, 0x2ac869be, 0x93206357,
+    0xb795cf8b, 0xe8be885a, 0xe66b2631, 0x32f1ab16, 0x198849b8, 0x34007345, 0xba033782, 0x23059c5f,
+    0x3c80f812, 0x8ac41401, 0x3c655e03, 0x5eb6ff12, 0x9c2db782, 0x6256dc66, 0xf6585828, 0xe318440a,
+    0x293d861b, 0x612efb3e, 0x6fd011bf, 0x97183754,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x68000000, 0xc4000000, 0x6a000000, 0x05000000,
+    0xba800000, 0x94c00000, 0xe3200000, 0x6dd00000, 0x53080000, 0x06dc0000, 0x89d60000, 0xa90d0000,
+    0xbbda8000, 0xaf54c000, 0xfbc86000, 0xf3fff000, 0x1707b800, 0x83859c00, 0x38428200, 0x84e20700,
+    0xf774eb80, 0xbb9df4c0, 0xf7b669a0, 0x2abff3d0, 0xd3628218, 0x5d32071c, 0x467ceba2, 0x4c41f4fd,
+    0xc6e069a0, 0xd672f3e0, 0x5b18022c, 0x0e76c738, 0xb71c8bb4, 0x407204c3, 0xe819d18c, 0x97f66ffa,
+    0x025e001a, 0x8a110013, 0x89ac8038, 0x0e49c002, 0x48bae03c, 0x6267303d, 0x05b1d839, 0x23bb6c13,
+    0x17e1ba11, 0xfef25b3e, 0xb6da898f, 0xa1d5c3c2,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xe8000000, 0x2c000000, 0x3a000000, 0x2d000000,
+    0xe5800000, 0xffc00000, 0xe9600000, 0xe2500000, 0xd5a80000, 0xbbfc0000, 0x84560000, 0x6aa90000,
+    0x3f788000, 0x5416c000, 0xb90f2000, 0xfdc83000, 0xa62e7800, 0x133d6400, 0xc5b2e200, 0x62b9cd00,
+    0x1470bd80, 0xc8db1dc0, 0x30a25fa0, 0xd432d0d0, 0x437ae238, 0x5615cd2c, 0xc00ebda2, 0x164e1df7,
+    0x8aecdf94, 0x24dd10d0, 0x2aa5420f, 0x69373d18, 0xbef9e5a1, 0x6dd249f0, 0x3f68c59b, 0xe31f79c6,
+    0x97c03d9b, 0x8561ddec, 0xb8537faf, 0x08afe0ef, 0xb67a1a22, 0x57976910, 0xb9cbff86, 0xf02920cc,
+    0x643d3a21, 0xfd335904, 0x90fb87b5, 0x2ed144de,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x78000000, 0x24000000, 0xea000000, 0x7f000000,
+    0xc9800000, 0x74c00000, 0xec600000, 0x59700000, 0x73a80000, 0xb6dc0000, 0xab760000, 0x58ad0000,
+    0x0d5a8000, 0xb4b44000, 0xef4fe000, 0x7becf000, 0x08f8e800, 0x0b265400, 0x86d71a00, 0xcb3d2d00,
+    0x32c77c80, 0x85630cc0, 0xf1f066a0, 0x13ee21d0, 0xc4ff1a18, 0x6d212d1c, 0x7fd17ca6, 0xcbbe0cc5,
+    0x3e82e684, 0x414661c6, 0x86a6fa10, 0x6810dd1e, 0xc05b14af, 0xb63018ce, 0x100c1ca3, 0xf04abcfd,
+    0xc26beea6, 0xe43dc5e5, 0xf3458826, 0x4da2e425, 0xe3961203, 0xa79a8912, 0x25d20e99, 0x8cbc35ec,
+    0x3307e019, 0xef80f00f, 0x6dc6e838, 0xdce7540a,
+    0x80000000, 0xc0000000, 0x20000000, 0x30000000, 0x28000000, 0x04000000, 0x72000000, 0xa9000000,
+    0x7f800000, 0xa5c00000, 0xa5e00000, 0x37d00000, 0x15780000, 0x20ac0000, 0x54d20000, 0x1fff0000,
+    0x84ec8000, 0xbbf2c000, 0x014e6000, 0xd1073000, 0x83801800, 0xebc6d400, 0x62e4e200, 0xbf579900,
+    0x103a0580, 0x84085740, 0xb5a6e7a0, 0x42f3ce70, 0x86cee228, 0x48c4993c, 0x48648582, 0x2b15974d,
+    0xd71c07b6, 0x9eba3e5b, 0x924a9a2b, 0xd3867d3f, 0x13c6ffb4, 0x5ee51a69, 0xd152001a, 0xe73f001e,
+    0x248c801b, 0xb4e2c000, 0x94566008, 0xcebb300c, 0x6a4a1811, 0xef85d435, 0x7dc2622f, 0xa9e65928,
+    0x71d2e5a5, 0xe67ea771, 0xf22e1f98, 0xfc93ea6c,
+    0x80000000, 0x40000000, 0xe0000000, 0xb0000000, 0xd8000000, 0x34000000, 0x6e000000, 0xc3000000,
+    0x3c800000, 0xdb400000, 0xe4e00000, 0xf9500000, 0xe7780000, 0x982c0000, 0x10560000, 0x0efd0000,
+    0xd8ee8000, 0x15754000, 0xe70a6000, 0x44a45000, 0xe5342800, 0x936f9c00, 0xdcb46e00, 0xfba85500,
+    0xd595b180, 0xf85b3f40, 0x3cd9dfa0, 0xc39f6a50, 0xc17a6e18, 0xa729553c, 0xfed5318e, 0x24bf7f61,
+    0x418b3f8d, 0x92e37a6d, 0xae52a60c, 0xe5fbd93a, 0xe86917b8, 0x2431e669, 0x76e8480d, 0x7676cc35,
+    0x8b8ec611, 0xf7e2890f, 0xedd3bfb2, 0x303b3a43, 0x96ce4634, 0x9c06c92c, 0x22015f8a, 0xc9072a4a,
+    0xf9868e13, 0xc8c4453f, 0x59257997, 0x6bf5b36e,
+    0x80000000, 0x40000000, 0xe0000000, 0x70000000, 0x58000000, 0x24000000, 0xc2000000, 0x61000000,
+    0x1f800000, 0xf6c00000, 0xd3e00000, 0x10b00000, 0x74c80000, 0x727c0000, 0xbdb60000, 0x754d0000,
+    0x93b88000, 0xfad74000, 0x91bee000, 0x3bd7b000, 0x1e3e5800, 0xe5131400, 0xb1d9fa00, 0x13a5f100,
+    0x66154e80, 0x1f5fb9c0, 0x32e4b4a0, 0x4f3648d0, 0x620ffa18, 0xd198f10c, 0xf505ceae, 0x2584f9d5,
+    0x23c45486, 0x2660f8c1, 0xfb77222f, 0x5eada529, 0x370c549d, 0x641cf8ec, 0xfec1222f, 0x7fe0a519,
+    0x3eb4d4bd, 0xdbcbb8f8, 0xb2ffc20e, 0xd3f71503, 0xecea8ca1, 0xd8a8acec, 0xa40e3820, 0xa29ee412,
+    0x4381c21c, 0xc0c6151e, 0xb8e40cb9, 0x6232ecdb,
+    0x80000000, 0x40000000, 0x20000000, 0xd0000000, 0xc8000000, 0x5c000000, 0x06000000, 0xad000000,
+    0xb0800000, 0xac400000, 0xa0200000, 0x4d300000, 0x85280000, 0xda1c0000, 0xe8360000, 0x09ab0000,
+    0x805b8000, 0xfd16c000, 0x601fa000, 0xf3315000, 0x2c2f9800, 0xd8994c00, 0x6372b600, 0x7a087b00,
+    0x20aee480, 0xc2d856c0, 0x565452a0, 0xc3bc2dd0, 0x9bc4b628, 0x4fe37b24, 0xe8d564ba, 0xfefe96e3,
+    0x7d63f293, 0x1b917dec, 0xe95d2e05, 0x9f913724, 0x1b5c52be, 0x98902ddb, 0x18dab61a, 0xbd547b09,
+    0xfe38e4be, 0x570356c0, 0x8b87d28c, 0x59c6edc2, 0x30e51618, 0x8f552b29, 0x993f7c9e, 0xa4861af6,
+    0xb64364b2, 0x2b2596ea, 0x80b0728c, 0x51ebbdff,
+    0x80000000, 0x40000000, 0xa0000000, 0xf0000000, 0x38000000, 0x0c000000, 0xca000000, 0xcd000000,
+    0x96800000, 0xf7400000, 0x0d200000, 0xa2700000, 0xe5980000, 0x46ec0000, 0x1ff60000, 0x335f0000,
+    0x7f0e8000, 0x2d604000, 0x7cd6a000, 0xb22cb000, 0x6f127800, 0xc9497c00, 0x3cc43e00, 0x44e26b00,
+    0x3c136680, 0x44ced140, 0x320158a0, 0x2103ba50, 0x0c84be08, 0xf2412b2c, 0xafa546a6, 0x9331217f,
+    0xefbd009c, 0xbf193660, 0x9baad81f, 0x8fd6cc22, 0xcfaec600, 0x59d4572a, 0xa0af7886, 0xc6504a49,
+    0x3f6ee609, 0x3734a703, 0x01bda096, 0xdc1a866e, 0xce2e2014, 0xdd13f00d, 0xa84a5820, 0x90458c0c,
+    0x46a0e627, 0xabb7a71a, 0xdbfd20b1, 0x17b9c67c,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x18000000, 0x1c000000, 0xb2000000, 0xf3000000,
+    0xd4800000, 0x55400000, 0xffa00000, 0x69700000, 0xcb980000, 0xd5ec0000, 0xa4f20000, 0x455f0000,
+    0xa9898000, 0x1c664000, 0xc8972000, 0x25c8f000, 0x18424800, 0xb2271c00, 0x27b07e00, 0x97fbdf00,
+    0xfd794a80, 0x313bf940, 0xd51b34a0, 0x1faf2670, 0x95d3fe28, 0x1e6e9f14, 0xff35ea8e, 0xa8ba4963,
+    0xcddfdc8a, 0xaeca8a4b, 0xa0c4e831, 0xfd65ac3d, 0xbf171604, 0x9c0b332c, 0x3ea2fca7, 0x8ef17a54,
+    0x6a5d2022, 0xef0bf03d, 0x2a21c801, 0xfbb25c3d, 0x05fcde13, 0x9e7a6f26, 0xfdbda283, 0x9c5e557d,
+    0x520c2299, 0x0fa4157a, 0x017102b9, 0x7f9fe569,
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x58000000, 0x4c000000, 0xa6000000, 0xe3000000,
+    0xbd800000, 0xec400000, 0xc8600000, 0x4ff00000, 0x79b80000, 0x714c0000, 0x67720000, 0xa37b0000,
+    0x5cee8000, 0x16a74000, 0x4053a000, 0x096dd000, 0x8ee36800, 0x32345400, 0xab98de00, 0xbdd88900,
+    0xf8be0880, 0xadce5c40, 0xc7b4d6a0, 0xc3ddd570, 0x97bc5e08, 0x1648c90c, 0x38f128be, 0xee3fcc6f,
+    0x780a9e9f, 0x0193115b, 0xe54c480e, 0x4d75c40f, 0x467e9623, 0xf26a4d07, 0x1f641e93, 0x28745164,
+    0x28ffe821, 0x5ba81407, 0x8745fe3b, 0x51e21939, 0xb1b6c0bd, 0x48dbd85b, 0x9e3d60bd, 0xe00a0872,
+    0xed9408a0, 0xb3495c79, 0xf67056bf, 0xb7fd954f,
+    0x80000000, 0x40000000, 0x20000000, 0xf0000000, 0x88000000, 0xe4000000, 0x02000000, 0xfb000000,
+    0x5f800000, 0xbfc00000, 0x3be00000, 0x63100000, 0x5df80000, 0xb7ec0000, 0x86960000, 0x153b0000,
+    0xe58a8000, 0xdb45c000, 0xbba06000, 0x3731b000, 0x7d0c0800, 0xe5024400, 0x8a823200, 0xdd42e900,
+    0xd2a06380, 0xcbb2b540, 0x014c51a0, 0x0f275c50, 0xcf70b228, 0x452b292c, 0x23f603aa, 0x21680545,
+    0x75d2d982, 0xfa9cd857, 0x78bce03f, 0x494f703d, 0x8b26e833, 0x1d763432, 0xc62e5a3b, 0x10711d32,
+    0x78ae59a1, 0xb7321879, 0x3d0e0021, 0xc5070014, 0x7a848006, 0x5542c032, 0x36a4e025, 0xc9b37025,
+    0xfa48e825, 0x50a13403, 0x70b2da16, 0x7ecfdd11,
+    0x80000000, 0xc0000000, 0xe0000000, 0x90000000, 0x48000000, 0x84000000, 0x16000000, 0xdd000000,
+    0xf0800000, 0x68400000, 0x79200000, 0xc9700000, 0x15180000, 0x276c0000, 0xe7f20000, 0xe4590000,
+    0x58cf8000, 0x0bc4c000, 0x30e76000, 0x5a971000, 0x96089800, 0xe7a30c00, 0x00365600, 0x36b9d300,
+    0x115c6e80, 0x7c4a6540, 0xdd8038a0, 0x80c6b670, 0x3d61d618, 0xb2511314, 0x5f690eaa, 0x9bf47545,
+    0x4e5f20b7, 0xcfcd7a66, 0xfc42e021, 0xe726d039, 0x70727808, 0x6399dc2d, 0xda2e2e0c, 0xea550f2d,
+    0x536fc085, 0xe9f2aa5d, 0x155f182d, 0xaa4bcc23, 0xe0833639, 0xe047c30b, 0x1d237687, 0x4f71a97e,
+    0x801b0eaf, 0x53ed7548, 0x99b0a0ba, 0x4079ba48,
+    0x80000000, 0xc0000000, 0x20000000, 0x70000000, 0x28000000, 0xc4000000, 0x12000000, 0xf1000000,
+    0xaa800000, 0x75400000, 0xefa00000, 0x82100000, 0x7c880000, 0x599c0000, 0xac920000, 0xfbcf0000,
+    0x173e8000, 0xdc00c000, 0x1e006000, 0x77043000, 0x1185d800, 0xc2c5bc00, 0xe6604e00, 0xfbf3cf00,
+    0x4ab9b180, 0x78c50ac0, 0x1363ffa0, 0x6375c5f0, 0xbefece28, 0x15e30f2c, 0x2031d182, 0xe25d3aed,
+    0x947427ae, 0x6f7f79fd, 0xf7200006, 0xb650000d, 0x31a80013, 0x1acc0010, 0x05ba002a, 0x1543003b,
+    0x7fa4801a, 0xfa13c009, 0xe08ce03f, 0xa79bf034, 0x8b933826, 0xb24d4c1c, 0x39fff637, 0xec614305,
+    0x06f0a793, 0x663cb9c7, 0xb684e009, 0x4b47f03a,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0xa8000000, 0xec000000, 0x46000000, 0x41000000,
+    0xb8800000, 0xe5400000, 0xbaa00000, 0x3cf00000, 0x16d80000, 0x292c0000, 0x0c720000, 0x4f9b0000,
+    0xdd8d8000, 0x0d83c000, 0x0fc12000, 0xbce4d000, 0x45d1f800, 0xd8edec00, 0x7593be00, 0xb8ccc700,
+    0x7722fe80, 0x93313f40, 0x3a3b40a0, 0xc4faf870, 0x389e3e08, 0x7c0f0714, 0x2443de82, 0xc225ef5f,
+    0x79b2b89b, 0x3c7b145b, 0xbddf8037, 0xf6a8c03d, 0x20b4a002, 0x90fb1029, 0xfa9ad810, 0x370e3c2e,
+    0x0bc5c639, 0x76e5eb30, 0x72d7e0b4, 0x196de866, 0x6c56e63f, 0x4d2a3b19, 0xf67398bc, 0x409fc464,
+    0x580e7832, 0xbe452c03, 0xfd271e0d, 0xc437d712,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x08000000, 0xa4000000, 0x26000000, 0x29000000,
+    0x78800000, 0xd2400000, 0x22e00000, 0x3fd00000, 0x1b080000, 0x37dc0000, 0xb7560000, 0x214f0000,
+    0x21388000, 0x9687c000, 0xbf426000, 0xec609000, 0xfc91b800, 0xed6fec00, 0x584d4600, 0x81b9fd00,
+    0x78c16580, 0x27a2f0c0, 0x70b223a0, 0x34180dd0, 0x7f75c608, 0x4e3e3d1c, 0x170305aa, 0x8d8260e5,
+    0x80c39bab, 0x6ba7e1f3, 0x62b0801f, 0x031bc022, 0x82f4603c, 0x66ff901e, 0xe8a13805, 0xc1342c21,
+    0x0ed9263c, 0xb7d66d3b, 0xff085db3, 0xb1dadcd6, 0xae550589, 0x51cd60cc, 0x577b1bb3, 0x926021d9,
+    0xa992e006, 0x8feb5003, 0x190dd80c, 0x78dc7c18,
+    0x80000000, 0xc0000000, 0x20000000, 0xb0000000, 0x78000000, 0x24000000, 0x36000000, 0x3f000000,
+    0x8e800000, 0xd8400000, 0xf3e00000, 0x2f100000, 0x2c380000, 0x0dac0000, 0x39d20000, 0xe79f0000,
+    0x41588000, 0xf2fc4000, 0x9a09e000, 0x67261000, 0x25b10800, 0x8d4bec00, 0x82406600, 0x66e5f500,
+    0xac92fb80, 0xc3ff6740, 0xef8a1da0, 0x97e6d270, 0xf9110628, 0x833fa51c, 0x4b2a1396, 0xbd929b65,
+    0x067b73b3, 0x6748cb76, 0x6f439b86, 0xc1653765, 0x7552f5a9, 0x1edb2e68, 0xcf38680c, 0x512dbc12,
+    0xc8928e3d, 0x15fb0926, 0x40891592, 0xd1623e7e, 0x7d51e00f, 0x62da1024, 0x6d3b083d, 0x2028ec11,
+    0x5d12e61e, 0x753ab53e, 0x54299bb5, 0x83163779,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0x98000000, 0x94000000, 0x86000000, 0x03000000,
+    0x19800000, 0xbdc00000, 0xb0600000, 0x1c500000, 0x4d880000, 0x685c0000, 0x68160000, 0x982d0000,
+    0xb0698000, 0xefc84000, 0x26f96000, 0x3da31000, 0x24376800, 0xbddd3400, 0x67d7f200, 0x1d4e2500,
+    0x7ebe2580, 0x0a0704c0, 0x510057a0, 0x7c8161d0, 0xf7471218, 0x8c257504, 0x85f02d9e, 0x67b920f1,
+    0x3a80cda7, 0x544270f5, 0x25a6c5bf, 0xf03054eb, 0xdbd85f94, 0x34d245c9, 0x9cce0820, 0x577e243c,
+    0x3c609a30, 0x4e531122, 0x2889d79b, 0x22d921d0, 0x5456721d, 0x018a6516, 0x9a59459b, 0xbd1514e0,
+    0x1aa8bf9f, 0x802915dd, 0x64680013, 0x89cc0009,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x98000000, 0x64000000, 0x46000000, 0xf5000000,
+    0x1b800000, 0xadc00000, 0x86a00000, 0xd9d00000, 0x7d080000, 0xcf5c0000, 0xa7920000, 0x5a690000,
+    0xca2a8000, 0xe44f4000, 0xd8bae000, 0x85a59000, 0x6f51d800, 0x4f4e1400, 0x923de200, 0x95e12300,
+    0x81359f80, 0x93bb8cc0, 0xff22fda0, 0xe715eff0, 0xc52d8218, 0x08cbf33c, 0x0dfea79e, 0xe84008d5,
+    0x7a66c797, 0xe2f6d8d4, 0xcc1fff8f, 0xb7745cea, 0x78d94599, 0x40542bf8, 0x73cbd811, 0xef7b141d,
+    0x0405623f, 0xb6076326, 0x8d05ff8b, 0x4f815cd6, 0x73c1c58d, 0x17a26bfb, 0x8453b824, 0x25cdc436,
+    0x527c5a04, 0xd385e709, 0xa1c34581, 0xdca12bd8,
+    0x80000000, 0xc0000000, 0xa0000000, 0xb0000000, 0x28000000, 0xec000000, 0xf2000000, 0xf9000000,
+    0xa4800000, 0xd6c00000, 0x8fa00000, 0x57b00000, 0x9f580000, 0x046c0000, 0xc1f20000, 0xa83b0000,
+    0x5f7c8000, 0x089ec000, 0x9b492000, 0xd6827000, 0xefc79800, 0x8b244c00, 0x3177a200, 0x38fcb300,
+    0xbfd8dc80, 0xacaf8d40, 0x5553fea0, 0x3a0dfe70, 0x76620208, 0x4b90031c, 0x89ae64a2, 0xebd5b157,
+    0x59c9c496, 0x1f420175, 0x1be3fc9d, 0xd1d6fd57, 0x9cc8e688, 0x71c77247, 0x4024803a, 0xccf2c00d,
+    0xfabb2020, 0xceb97037, 0x98bb181b, 0x6fba8c0d, 0x583e8220, 0x177ec328, 0xf49f4480, 0xf14bc15c,
+    0xeb845cb4, 0x55414d60, 0x56e2de8d, 0xe3538e65,
+    0x80000000, 0xc0000000, 0x20000000, 0xd0000000, 0x48000000, 0x8c000000, 0xd6000000, 0x05000000,
+    0x1b800000, 0xb8400000, 0x45200000, 0xb0700000, 0x19480000, 0xf6bc0000, 0xcdb20000, 0x8d2f0000,
+    0x3e6b8000, 0x41cec000, 0xf87d2000, 0x57575000, 0x8e3d5800, 0x13755400, 0x5fcd0e00, 0xb17a5300,
+    0xbad10e80, 0xe37b8fc0, 0x05d780a0, 0xdbff1cf0, 0x3b13ae28, 0xf91fc304, 0x2c03769a, 0xc6068bd7,
+    0x6d045687, 0x4782dbd2, 0x26408e9b, 0xcc264feb, 0x7df320ad, 0xa4098ce5, 0xa8185618, 0xc5830737,
+    0xd146009c, 0x78a2dce6, 0x95370e2c, 0x83e95323, 0xa4888e9d, 0x42da4fcf, 0xd561208d, 0x49568cde,
+    0xc73bd602, 0xfef1c70f, 0x328920a1, 0xa7da8cf6,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x28000000, 0xdc000000, 0x4e000000, 0x75000000,
+    0xb9800000, 0xaec00000, 0xb5200000, 0x23b00000, 0x81180000, 0x052c0000, 0xa7f60000, 0xca790000,
+    0xaaf88000, 0x9bb8c000, 0x495aa000, 0xd84e1000, 0x89221800, 0xddb74400, 0xbc19ea00, 0x90ad2700,
+    0x6f310080, 0xd65b9740, 0x7ec86aa0, 0xc1627050, 0x40d5ca38, 0xf2cef72c, 0x77673892, 0x51d3034b,
+    0x0149b8b9, 0x16a2c37a, 0xba739885, 0x0eb8135a, 0x40dd20b1, 0x3e884778, 0x100652ac, 0x9806e44f,
+    0x94021806, 0x6207441e, 0x1301ea01, 0x10812721, 0x59470082, 0x6ee29758, 0x2f10ea89, 0x0c6ab05c,
+    0x31176a15, 0x816ce709, 0xec9320ba, 0x65ad4771,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x78000000, 0xfc000000, 0x86000000, 0x99000000,
+    0x8c800000, 0xa4c00000, 0x93200000, 0xed500000, 0x0ac80000, 0xb11c0000, 0x30120000, 0x8fa90000,
+    0xeeab8000, 0x5e294000, 0x9ce92000, 0x86ce5000, 0x9f1eb800, 0xcd110c00, 0x6928d200, 0x2d6ee700,
+    0x2489cf80, 0xdfffa6c0, 0x22e29da0, 0xdcf401f0, 0xbe587218, 0xce70f734, 0xaf9dd7a6, 0xcfd5bafb,
+    0x930fd79f, 0x24bcbae9, 0x0e84579a, 0x87c5fafb, 0xe0a57781, 0x4c17aad9, 0xc9a9cf81, 0x97afa6fa,
+    0xc2aa9d8a, 0x402801cb, 0xe9ea7217, 0xf449f70b, 0x5ede579e, 0x54b0fad5, 0xb9bcf796, 0x3807eae6,
+    0xdc036fb6, 0x7604b6e0, 0xf1070580, 0x08825ddc,
+    0x80000000, 0x40000000, 0x20000000, 0x10000000, 0x28000000, 0x7c000000, 0xee000000, 0x97000000,
+    0xcd800000, 0x29400000, 0x5b600000, 0xe2700000, 0x55c80000, 0x583c0000, 0x25b60000, 0x086b0000,
+    0xa96d8000, 0xd7efc000, 0xb92da000, 0xf60b1000, 0xaf9d0800, 0xf9e7f400, 0x32b17600, 0x85eb7900,
+    0xa02e4c80, 0x9c8df3c0, 0x735abaa0, 0xdfc54ad0, 0x19a75628, 0x4b54a914, 0xf75b6482, 0x05c2d7db,
+    0x2ca56491, 0xc7d5d7ea, 0xd09ee4a0, 0x286117fb, 0xc5f6c487, 0x7189c7f9, 0xdbd86c9a, 0x720223c0,
+    0x090792b6, 0x42866ef0, 0xa0c6881f, 0xc823342b, 0xbc11560d, 0x033fa912, 0x7e36e48d, 0xc22d17df,
+    0xbd88c4af, 0x4ddec7f9, 0x9103ecb1, 0x4686e3fc,
+    0x80000000, 0xc0000000, 0xa0000000, 0xd0000000, 0x88000000, 0x74000000, 0xfa000000, 0xd7000000,
+    0x93800000, 0x98c00000, 0x3e200000, 0xd2100000, 0xa7280000, 0x71bc0000, 0xbdd20000, 0x1a8b0000,
+    0x136f8000, 0x80d8c000, 0xcb20e000, 0xaa91f000, 0x826ea800, 0x2a5f4400, 0x9d626a00, 0x10f4ff00,
+    0x99191580, 0x77838bc0, 0x8ac6ffa0, 0xed24b4f0, 0xe3908a08, 0x14ee0f04, 0x52183d8a, 0xda040fe9,
+    0xc70475bc, 0xbb81bbd8, 0x3cc73792, 0x4c22c0d7, 0x7114a801, 0xcea8440b, 0x3e7fea12, 0x10773f34,
+    0x505e75be, 0x8a66bbd4, 0x2372b79e, 0xd1dd00f1, 0xc1a1c83c, 0x2cd67400, 0xb00c2211, 0x452a4b27,
+    0x3abdd799, 0xd05430dc, 0xf74f8022, 0x95c8c036,
+    0x80000000, 0x40000000, 0xe0000000, 0x50000000, 0xb8000000, 0x14000000, 0xf6000000, 0x1b000000,
+    0xa9800000, 0xb1400000, 0xb9600000, 0x8d100000, 0xc0480000, 0x9fdc0000, 0x57560000, 0x98ad0000,
+    0xbf898000, 0x24794000, 0xcb646000, 0x78109000, 0xd6cf2800, 0x991e7400, 0xe8741600, 0xe75a8900,
+    0xae920780, 0x700f34c0, 0x963991a0, 0x7f81fdd0, 0x5a467618, 0x18e71904, 0xc054af96, 0x232800d1,
+    0xebc9e7b3, 0xd39be4d3, 0x04b3598f, 0x497a59ee, 0x39e72832, 0x80d2740e, 0x8eea1635, 0x156b8913,
+    0xb12d879c, 0x4ecb74ee, 0x7d1c71a3, 0x16742df6, 0x005b3e31, 0x5d14fd3b, 0x384e119a, 0x6bd9bdcb,
+    0xf1559636, 0x3bafc904, 0x020867b6, 0x633ea4ea,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x48000000, 0x4c000000, 0xaa000000, 0x3b000000,
+    0xf7800000, 0xb6400000, 0xc0200000, 0x87100000, 0xb8580000, 0x5bcc0000, 0xfe560000, 0x61fb0000,
+    0x979e8000, 0x90afc000, 0x8fe72000, 0x1c77b000, 0xf1ec4800, 0x2241c400, 0x7e257e00, 0x42145b00,
+    0xaada8d80, 0x8f0c6c40, 0xa2b773a0, 0xf60cf750, 0x12345e28, 0x75c8eb0c, 0x6350459a, 0x277e684f,
+    0x9d5b2d98, 0x59481c4d, 0x32921bbf, 0x491d836f, 0xae6de837, 0xc805b413, 0x0c00162e, 0x8a052f36,
+    0x4b033b94, 0xbf81337e, 0xfa47201d, 0x6a27b02e, 0xbc14482d, 0x4fddc425, 0xed8b7e2c, 0x3e735b33,
+    0xe6ea0dbd, 0x2fc4ac7c, 0xcb60d391, 0x71b38775,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0xf8000000, 0x84000000, 0x76000000, 0xef000000,
+    0x64800000, 0x80c00000, 0x4ce00000, 0x61700000, 0xac780000, 0x428c0000, 0x3cb60000, 0x3d190000,
+    0x98b98000, 0x106f4000, 0xaec6e000, 0xd7e01000, 0x3bf68800, 0x6fb93c00, 0x60eb7200, 0xc0008900,
+    0x20078080, 0xb003b240, 0x280372a0, 0x7c057b50, 0xf2039238, 0x99059924, 0x8b8688a6, 0xe440ce55,
+    0xcc216083, 0x2d93a24a, 0xcd0dfabc, 0xeef0477f, 0x7e3ee00c, 0x01ac103d, 0xa5a08803, 0x88d03c26,
+    0xbeaaf21e, 0x7923c925, 0xec1760be, 0x544aa248, 0x0f547a8c, 0xa0ef0749, 0xe000002a, 0x9000000d,
+    0x98000032, 0x54000025, 0x8e000031, 0x6b000038,
+    0x80000000, 0xc0000000, 0x20000000, 0x90000000, 0x38000000, 0xf4000000, 0xda000000, 0x7d000000,
+    0xdc800000, 0x42400000, 0x46e00000, 0x49100000, 0xcca80000, 0xb03c0000, 0x48520000, 0x09cf0000,
+    0xcee98000, 0x16df4000, 0xb1456000, 0x6d62f000, 0xb2d17800, 0x708fec00, 0x1f884600, 0x0208bd00,
+    0x3ac8a380, 0xc56cb3c0, 0x7d1b65a0, 0x35644ef0, 0x36d72628, 0x22894d14, 0x3e8a5b86, 0xc88f1fd9,
+    0x2b8dc398, 0xf80e43d2, 0xd7ca1da9, 0x21eba2db, 0xcb5f6000, 0xa981f02b, 0x02c2f812, 0x32a3ac38,
+    0xccf6a62e, 0xc63a0d1c, 0x6b553bb1, 0xfa4eefda, 0x712f3baa, 0xd8fdefc0, 0xaff4bb98, 0xd5bdafef,
+    0x64905b9f, 0x9c6c1fde, 0xa39e4394, 0x4e2203db,
+    0x80000000, 0x40000000, 0x60000000, 0x90000000, 0x58000000, 0xbc000000, 0x5a000000, 0x3d000000,
+    0xb4800000, 0xc5c00000, 0x05e00000, 0x77d00000, 0x29380000, 0x1b6c0000, 0x91160000, 0xf3590000,
+    0xd2fb8000, 0x85884000, 0x55422000, 0x0622b000, 0xe5367800, 0x1f6fec00, 0xa7127a00, 0xac5ad900,
+    0x777a0d80, 0xf6ca1540, 0x1465f7a0, 0x9a918c50, 0x431e5a38, 0x9c5d6934, 0x9f79f58e, 0xe2c8b94b,
+    0x3a602da0, 0x1994a570, 0x2c9d8f83, 0x8a1b604a, 0xd6d9a002, 0xe1baf037, 0xa4ac5813, 0x59f15c1f,
+    0xf80a0203, 0xda00352b, 0x7d05f7aa, 0xd4818c7e, 0x55c65a27, 0x5de1691a, 0xcbd7f59b, 0x733db945,
+    0x266dadbc, 0x2595e548, 0x369c2f9f, 0xd71d9071,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0x1c000000, 0xee000000, 0xdb000000,
+    0xeb800000, 0x48c00000, 0x87600000, 0xdb500000, 0x78380000, 0xcdec0000, 0xd5920000, 0x30590000,
+    0xd73c8000, 0x3c6c4000, 0x9c542000, 0xbdb91000, 0xbe2ce800, 0x49752400, 0x9bca4a00, 0x3463b500,
+    0xc4d45580, 0xeaf8f940, 0xab889fa0, 0xf4420c70, 0xc2266a18, 0x0bf6a50c, 0xcd8abdb6, 0x2b44dd7b,
+    0xcba65595, 0xbe31f941, 0x5e6c1f99, 0xa1524c68, 0x49384a0d, 0x1f6ab52f, 0x8bd0d5b2, 0xeb78b948,
+    0xda4ebf84, 0x65a21c6c, 0x45360238, 0xa5efc11d, 0x2194d7a1, 0xea5e784c, 0x363ee817, 0xc6ec2435,
+    0x1616ca2e, 0xac9ff537, 0x9a5875a5, 0xce3de978,
+    0x80000000, 0x40000000, 0x60000000, 0x50000000, 0x88000000, 0xcc000000, 0x56000000, 0xeb000000,
+    0x33800000, 0x07c00000, 0xcea00000, 0xe8500000, 0x83880000, 0x555c0000, 0x91960000, 0x1a290000,
+    0x608d8000, 0xead8c000, 0xa0572000, 0x6f8f5000, 0x335c2800, 0xa2906400, 0x6da83200, 0xfd484300,
+    0x997d8380, 0x908284c0, 0xd84631a0, 0xaf6707d0, 0x15f71238, 0x1c5b1304, 0x4b17abba, 0xb76be0e7,
+    0xbb6b8397, 0xcd6b84d9, 0x166bb1a1, 0xfdefc7ff, 0xbe283225, 0xea88430c, 0xbfdd83bc, 0xe4d284f2,
+    0x85ce319f, 0xdd3b07cb, 0xe1e1122e, 0xeab21339, 0xd6ba2b95, 0xb22320e8, 0x5614a384, 0x1fe8d4c3,
+    0x372999aa, 0x100aa3c1, 0x229b800c, 0xe731c030,
+    0x80000000, 0x40000000, 0xe0000000, 0x30000000, 0x28000000, 0x94000000, 0x4e000000, 0x09000000,
+    0xc2800000, 0x23c00000, 0x06200000, 0xf4900000, 0x8c780000, 0x156c0000, 0x45560000, 0x295d0000,
+    0xac7a8000, 0x856ec000, 0xbd54e000, 0xa55a5000, 0x5e79f800, 0x566c3c00, 0x38d19a00, 0x4d1b3700,
+    0xb9187980, 0x871ee540, 0x461d63a0, 0x209a1250, 0x655d7a18, 0xfe7d671c, 0x866f81b2, 0x20d3d969,
+    0xf11879b9, 0x631ee577, 0xc01d63bb, 0x8d9a1256, 0xc1dd7a23, 0x40bd670c, 0x0ccf8183, 0xfe83d953,
+    0xb9c079bb, 0xa122e570, 0x0f136380, 0x453b1240, 0xa489fa04, 0xf9e2a701, 0x58b7e1aa, 0xf7ea4965,
+    0xf697e189, 0xd77a4957, 0xd4efe18f, 0xfb16495b,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x58000000, 0xb4000000, 0x6a000000, 0xe3000000,
+    0xad800000, 0xb4400000, 0x0da00000, 0xa9500000, 0x2b680000, 0xa2bc0000, 0x71120000, 0x38c90000,
+    0x62eb8000, 0x04f84000, 0xdbb26000, 0x1e1f7000, 0xf2c50800, 0xd8639400, 0x3eb3fe00, 0x4e9ca700,
+    0x8202c380, 0x3f0067c0, 0xdb80bda0, 0xc14180f0, 0xc5219e18, 0x8d93d734, 0xb60fcbae, 0xfc8ff3e9,
+    0xe74943ac, 0xa1a827e5, 0x3edadda9, 0x6da2f0e1, 0x79569626, 0x9369431e, 0x06bfb5bd, 0x431714d3,
+    0x6fcbe011, 0xa56e302e, 0x53bce80e, 0x7b94a434, 0x030c961a, 0xd40c4304, 0xd38e3585, 0x64ca54c5,
+    0xd4e80036, 0x91fc001f, 0x03320026, 0x62d9003a,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0xe8000000, 0x34000000, 0x62000000, 0x05000000,
+    0x20800000, 0xd8c00000, 0x28a00000, 0xa4b00000, 0x44580000, 0x5b6c0000, 0xe4720000, 0x70fd0000,
+    0xc9d88000, 0x372ac000, 0xe617e000, 0xe1e83000, 0xd5b4f800, 0xa6de4c00, 0x16ad3200, 0xf4509b00,
+    0xd08c1c80, 0xf321cd40, 0xaff3aea0, 0x63ba9670, 0x323ad238, 0x0878ab0c, 0x6d18e4a2, 0xd98f8171,
+    0x3da69c82, 0x5c360d7c, 0x209ccea8, 0xd5c8667b, 0xcfc1ca00, 0x2522d71e, 0x08f32ea1, 0x263c564f,
+    0x3a7f3222, 0x101d9b00, 0xd50c9c89, 0x5b670d73, 0x27964e82, 0xc3afa641, 0x6cd6aa0e, 0x444c273f,
+    0x95823680, 0xb0472a54, 0x5466780b, 0xf6158c3d,
+    0x80000000, 0xc0000000, 0xe0000000, 0xf0000000, 0x38000000, 0xcc000000, 0x62000000, 0x93000000,
+    0x3a800000, 0x54400000, 0xe9a00000, 0x56d00000, 0xc6380000, 0x3f6c0000, 0xa2920000, 0xff990000,
+    0x41bc8000, 0x60ac4000, 0x56f2a000, 0xde2bf000, 0x54b2c800, 0xea8b6c00, 0x39e4c600, 0xe9758700,
+    0x4ded6780, 0xc2d68940, 0x583f21a0, 0x166a4e70, 0x37166618, 0xcb5e770c, 0xa35fafb6, 0x875de54f,
+    0x015be7b6, 0xf45fc967, 0xcbdb018e, 0x9818fe7d, 0xee788e22, 0xaccbab3f, 0xbb4781a5, 0xa924be71,
+    0x4d922e21, 0xbf1c5b02, 0x5aff49ac, 0x198ad27f, 0x3360682e, 0x65309c23, 0x984e0e03, 0xce02eb30,
+    0xc103a192, 0x71860e4e, 0x52c4c632, 0xe7e5871b,
+    0x80000000, 0xc0000000, 0xe0000000, 0x10000000, 0x08000000, 0xc4000000, 0x62000000, 0xa3000000,
+    0xe4800000, 0xd1400000, 0x0be00000, 0x73900000, 0x1d680000, 0x1fbc0000, 0x39320000, 0xad190000,
+    0x55438000, 0x49e6c000, 0x20916000, 0xe1edb000, 0x02fa4800, 0x94d61c00, 0x1f8bbe00, 0x0fa84d00,
+    0x639e2780, 0xc3069dc0, 0x3484f9a0, 0x394360f0, 0xdfe09618, 0x1993e134, 0x7a6fd1ba, 0x9938ccf5,
+    0x4b71603a, 0x427db029, 0xf7924819, 0x5f6a1c18, 0x4cb9be39, 0xc5b14d19, 0xb05da79d, 0xf8a05df3,
+    0xfb759990, 0x7a7ed0e8, 0xcb92de01, 0xe169fd11, 0x41be6fa0, 0x243581d8, 0x449ec7bc, 0x6584eddf,
+    0xb6c451ab, 0xce220cdc, 0xb1320036, 0xa9190026,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x48000000, 0x44000000, 0xfe000000, 0x87000000,
+    0xa6800000, 0x7a400000, 0x40a00000, 0x92b00000, 0x6bd80000, 0xb72c0000, 0xea520000, 0x6ccd0000,
+    0x45c08000, 0x2b63c000, 0x26d36000, 0x778a5000, 0xb8e58800, 0x67152c00, 0x02ea1600, 0x89366f00,
+    0x0e1fe480, 0xcc493940, 0x148692a0, 0xe7450670, 0x0f24fe38, 0x7175130c, 0xfbba7a8a, 0xa57b7a6d,
+    0x2a99e00d, 0x03089000, 0xafa4680e, 0x2031bc03, 0x379c7e2b, 0x8c8ad32b, 0x2c631ab5, 0x40502a68,
+    0x6dcee829, 0x08437c18, 0xbda59e23, 0x2d324309, 0xc01f7290, 0x634d966b, 0x06009602, 0x2b04af0e,
+    0x8c86048e, 0xbb41a953, 0x6d22faa8, 0xf474ba49,
+    0x80000000, 0x40000000, 0x60000000, 0xb0000000, 0x18000000, 0x1c000000, 0xae000000, 0x91000000,
+    0x9f800000, 0x09400000, 0xc5200000, 0x3b700000, 0x90080000, 0xae3c0000, 0xdd160000, 0x0d590000,
+    0xc5c28000, 0x54e74000, 0x35932000, 0xfe981000, 0x5c253800, 0x30f29400, 0xe34ac600, 0xe01b6b00,
+    0x42e5ce80, 0x2894f1c0, 0x071c28a0, 0x70678ad0, 0xc054de38, 0xf27def3c, 0x15b4309e, 0xd0680eeb,
+    0x366fa00d, 0x536a5033, 0x06ea9814, 0x9ca8c40a, 0xe1085e10, 0xc1bfaf0b, 0xac5390be, 0x647e5ec1,
+    0x48b33814, 0x49eb943a, 0xaa28462f, 0xbbcc2b34, 0x0bdeee9e, 0xc700e1c6, 0xe28710a6, 0x40c01ec4,
+    0xf1629801, 0xd7d4c41f, 0x2f3e5e0a, 0xca96af28,
+    0x80000000, 0xc0000000, 0xa0000000, 0x50000000, 0x48000000, 0x04000000, 0xe6000000, 0x0b000000,
+    0x0c800000, 0xfe400000, 0x68200000, 0xb6900000, 0x66880000, 0x125c0000, 0x26f20000, 0x833b0000,
+    0x76c38000, 0xb066c000, 0x75b76000, 0x8c9d9000, 0xc2912800, 0x588e1c00, 0xf559a600, 0x98746b00,
+    0xd87fd180, 0x1d62d2c0, 0x123117a0, 0x8e5b29f0, 0x4cf7ee08, 0xca3be724, 0xc7455fba, 0x01a3a5d5,
+    0xeb54e00b, 0x4a6b5033, 0x71ae4812, 0x964f8c29, 0x593a8e0b, 0x47c17713, 0xbde5f7ba, 0x3e7079eb,
+    0xb379a633, 0xe1e46b2b, 0xf477d1a6, 0xaa7ed2f5, 0x186317ab, 0x41b029fa, 0xd29c6e15, 0xd5912733,
+    0xfe083fa9, 0xe21935f6, 0x11d44807, 0x97288c1c,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0xd8000000, 0x34000000, 0x9a000000, 0x07000000,
+    0x00800000, 0x51400000, 0x56600000, 0x8a500000, 0x13d80000, 0x0acc0000, 0x3c760000, 0x46690000,
+    0xcf808000, 0x0dc34000, 0x11246000, 0x8532f000, 0xdc0a2800, 0x7bd59c00, 0xab1c5e00, 0xf06f0100,
+    0x46876580, 0x00418640, 0x6fe75ba0, 0x6e907750, 0xff7c1638, 0x293d6d2c, 0x3fdf93ae, 0x84cd5b71,
+    0x31768030, 0x69ea401c, 0x7244e03e, 0xa4e1b029, 0x50164823, 0x1b3b6c3b, 0x94d87637, 0x4a4f9d21,
+    0x0d35bb8b, 0xe008c747, 0x9dd2de09, 0x2a194120, 0x81ed85ab, 0xbe45365c, 0x9ae793aa, 0xe5115b73,
+    0xf0b88030, 0x6b1f400c, 0xd06a6011, 0xd687f038,
+    0x80000000, 0xc0000000, 0x60000000, 0x70000000, 0xf8000000, 0x74000000, 0x6e000000, 0x29000000,
+    0x66800000, 0xbe400000, 0xf6a00000, 0x10b00000, 0xe6680000, 0x4e9c0000, 0x4b520000, 0xf1fd0000,
+    0xa1848000, 0x31c24000, 0x4e676000, 0x28535000, 0xf87df800, 0xcc45dc00, 0x8da3b600, 0xdd300f00,
+    0x7daf8480, 0xdff984c0, 0x288352a0, 0x4746dbf0, 0x78232e38, 0x52f7832c, 0xf2cf4aa6, 0x6d2f17c1,
+    0x8cbe8005, 0x4ea34027, 0xc4b1e01a, 0x986c1039, 0xef9e181a, 0xa1d4cc36, 0x55b92e1f, 0x1026831d,
+    0x6ef1cab2, 0x70cc57c0, 0x2e2f6027, 0x553f5004, 0xcb67f81a, 0x84d4dc09, 0xe93d3601, 0x89634f18,
+    0xa7d664ae, 0x40b994c2, 0xf4a74a96, 0x93b317d5,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0xb8000000, 0x0c000000, 0x16000000, 0x8f000000,
+    0x20800000, 0xe0c00000, 0x4aa00000, 0x93d00000, 0xd6580000, 0x3f4c0000, 0xfab20000, 0x19a90000,
+    0x25058000, 0x11854000, 0x1b42a000, 0x40e65000, 0x18b10800, 0xacad1c00, 0xac82b200, 0x36c72b00,
+    0x25a0cf80, 0x6353aa40, 0x8e98dda0, 0x79eed170, 0x7f631a18, 0x40f56704, 0x3acef596, 0x0bf0dd77,
+    0x484a000b, 0xf6350010, 0xdf6f8035, 0x88a0402f, 0x16d52034, 0x37da101f, 0xac0e2822, 0xde520c08,
+    0xa31b1a03, 0x1ca96731, 0x0484f5a5, 0x62c5dd66, 0xefa5801a, 0x4255402b, 0x2d1aa006, 0xafaa5009,
+    0x5a03080a, 0xb9041c21, 0x9f873221, 0xa8426b26,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0xb8000000, 0x2c000000, 0x02000000, 0x7f000000,
+    0x46800000, 0x9ac00000, 0xfee00000, 0xe9700000, 0xf3c80000, 0x4e7c0000, 0x2e560000, 0x58db0000,
+    0xbd068000, 0x59844000, 0x8c45a000, 0xac22f000, 0x83962800, 0x34b8ac00, 0xc0b2f200, 0x59aad900,
+    0xaacf8880, 0x81f983c0, 0xf310daa0, 0xcf7daad0, 0x9dd77a28, 0x4f9b850c, 0xc1a5d2a6, 0x11d3b6d7,
+    0x7d9e000e, 0x26a70004, 0x0b508019, 0x5d5f4025, 0x8b432000, 0xa6a6b017, 0x4b538803, 0x7d5a5c36,
+    0xfb44da26, 0x1ea27523, 0x67557a9b, 0x7f5f5afa, 0x84415210, 0x5823293e, 0xfd972080, 0x81b96fec,
+    0x6d3188b1, 0xabee83d6, 0xb3e85a91, 0xafeeeace,
+    0x80000000, 0x40000000, 0xe0000000, 0x10000000, 0xa8000000, 0x34000000, 0x5a000000, 0x8b000000,
+    0x38800000, 0xfc400000, 0x98a00000, 0x85900000, 0x43980000, 0x3c4c0000, 0xa2760000, 0x022d0000,
+    0x14038000, 0xea07c000, 0xd303e000, 0xb483f000, 0x3a43c800, 0x7da43c00, 0x6c14f600, 0x0c5f7f00,
+    0x602d2d80, 0x4307d240, 0x5c823ba0, 0xee475d50, 0x37a2de18, 0x4f15b314, 0x00d99392, 0xc66b5149,
+    0x50a0001c, 0xe190003f, 0x51980020, 0x934c0019, 0x68f60002, 0x416d0013, 0xee238020, 0x18d7c00c,
+    0x30bbe036, 0xf11ff030, 0x430dc80b, 0xc6553c1b, 0x99f97621, 0xd839bf18, 0x055b4d92, 0x1faee272,
+    0xa1c193b0, 0xcd675176, 0xb2f6002f, 0x8a6d002a,
+    0x80000000, 0x40000000, 0x60000000, 0x10000000, 0x38000000, 0x64000000, 0x46000000, 0xc3000000,
+    0xad800000, 0xffc00000, 0x4d600000, 0x16100000, 0x86f80000, 0x952c0000, 0xefb60000, 0x1c090000,
+    0x90078000, 0x78034000, 0x0401e000, 0x56019000, 0xfb05e800, 0xc9805400, 0xb9c0a200, 0x8e638d00,
+    0xbb97b780, 0x793b7940, 0xd84ef5a0, 0xf9a56450, 0x9af2aa38, 0x052b4914, 0x97b57d96, 0x180be05d,
+    0xc600003f, 0x83000039, 0xcd800022, 0xefc0000b, 0x75600036, 0x72100023, 0xc0f80023, 0x562c0010,
+    0x42360031, 0xe3c9001d, 0xdd67800c, 0x6e13401c, 0x82f9e004, 0xc32d902e, 0x14b3e804, 0xd5895420,
+    0x29c72234, 0xf660cd0f, 0xbf965781, 0x2f3ae968,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x88000000, 0xe4000000, 0xee000000, 0x49000000,
+    0x08800000, 0xfcc00000, 0x0b200000, 0x52500000, 0xfd380000, 0x4aac0000, 0x7c360000, 0x9c0d0000,
+    0x28258000, 0x6dd0c000, 0x287c6000, 0x81cad000, 0xbf877800, 0x95402c00, 0x2b615200, 0x13b73700,
+    0x8149ef80, 0xe746cd40, 0x9062dda0, 0xe0362a50, 0x6e09ca18, 0xd3200b24, 0x7e56259a, 0x173bc64d,
+    0x3da97839, 0xb5b12c3b, 0x8c4ad201, 0x31c6f719, 0x7da60fa2, 0x8f91dd52, 0xca9c45a6, 0x823c1644,
+    0x272b803a, 0x6271c02e, 0x1cefe00d, 0xb8d71025, 0xd2fe9826, 0x860a3c02, 0xa7224a03, 0xf851cb0f,
+    0x6a39c5af, 0x532cd651, 0xe477e03d, 0x61eb100b,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0xf8000000, 0xbc000000, 0xfa000000, 0xe7000000,
+    0x80800000, 0xd6400000, 0x5ba00000, 0xdb700000, 0x03580000, 0xfd6c0000, 0xdb160000, 0x6a4d0000,
+    0xa4258000, 0xa1b34000, 0x6cbd2000, 0xf6b8b000, 0x81b92800, 0x193fdc00, 0xa37ae600, 0xfadb1700,
+    0x7aada280, 0x03720340, 0xcf5c64a0, 0xcf6ca450, 0xa8156e18, 0x54ce3b24, 0xd360cc86, 0x6790385b,
+    0x060aa820, 0xf8819c06, 0x2a424626, 0x41a0e738, 0xec71aa88, 0x7bd96f6c, 0x9729aa88, 0x7ab56f60,
+    0x563faa8e, 0x27f86f74, 0x8a9a2aaf, 0xec0b2f4f, 0x47870aaa, 0x26c39f58, 0x45e622b5, 0x14d04366,
+    0x662ac48f, 0x5f365461, 0xbbfae603, 0x009b170d,
+    0x80000000, 0x40000000, 0x20000000, 0x70000000, 0x38000000, 0x4c000000, 0xfa000000, 0xed000000,
+    0xb3800000, 0xaec00000, 0x1e600000, 0xcb700000, 0x11e80000, 0x7d5c0000, 0x5f560000, 0x74bb0000,
+    0x84e68000, 0x38304000, 0xaa4aa000, 0x44ca3000, 0x018f7800, 0xad2f9400, 0xca3bba00, 0x89a7ad00,
+    0xe0901480, 0x7b5d52c0, 0x10570ea0, 0x363bcfd0, 0x2ba6e228, 0x1195490c, 0x7adef686, 0x27941bcf,
+    0x2ddff810, 0x5914d438, 0x409f9a1a, 0xd1b1dd0c, 0xd98bcca5, 0xf12ff6ca, 0x583b4c9d, 0x60a4b6c6,
+    0xdd176c97, 0x8e9ec6e5, 0xaab2b497, 0x0d0b62fe, 0x396e7697, 0x171f5be4, 0xe173d809, 0xf4eea428,
+    0x9ada4200, 0x3794792a, 0x45df0ebf, 0x5d17cfc3,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0x18000000, 0xb4000000, 0x96000000, 0x2f000000,
+    0xc1800000, 0x4bc00000, 0xe0600000, 0x6ff00000, 0x70880000, 0xbdbc0000, 0x2bd20000, 0x3fd90000,
+    0xdd228000, 0x71d74000, 0xc2da6000, 0x5da49000, 0x50930800, 0x26fdd400, 0x02f56200, 0xf80ae500,
+    0xe0fcd080, 0xc5f595c0, 0x7589d2a0, 0xb93ee0f0, 0x84968a18, 0x40f8e13c, 0xd5f2dabe, 0xbd8f34e1,
+    0x2539e803, 0xbe970416, 0x4dfe8a2d, 0xad74e115, 0x18c8da8e, 0x4f1a34e4, 0x7ac16821, 0xb2e5440f,
+    0x0fb46a1e, 0xfe2e313e, 0xe6ab3283, 0xb3e830e5, 0x21cf621e, 0x619fe51f, 0x108450a6, 0x2947d5cf,
+    0x982332bd, 0x555430ed, 0xbd9d6229, 0x0a86e500,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x28000000, 0x4c000000, 0x9e000000, 0x77000000,
+    0x97800000, 0x6e400000, 0xe4a00000, 0x26100000, 0x45580000, 0x8c0c0000, 0xe1720000, 0x1cab0000,
+    0xfd658000, 0x5cf3c000, 0x9fee2000, 0x1b461000, 0xd2200800, 0xd4d06400, 0x7e3fbe00, 0x33faaf00,
+    0x231ac980, 0x74ab9840, 0xd16157a0, 0xf2f02770, 0x50ec1608, 0xe8c31b14, 0x6e615fa2, 0xd9704377,
+    0xb8aba80d, 0x8f65b43e, 0x25f1962a, 0x576cdb22, 0xae857f96, 0xc6c15341, 0x6164202f, 0x8af11007,
+    0x94ef8824, 0xaac4a41b, 0xaf661e34, 0x75f47f0a, 0xdf6961ae, 0x72822c6f, 0x70c2c1ae, 0x5a67fc5f,
+    0x8374e9a5, 0x8dad8873, 0xd9e15fa3, 0xe7304373,
+    0x80000000, 0x40000000, 0xa0000000, 0x70000000, 0xd8000000, 0x24000000, 0x66000000, 0xbd000000,
+    0x56800000, 0x6f400000, 0xfb600000, 0x65f00000, 0xa4680000, 0x8ddc0000, 0x2d560000, 0xe2bf0000,
+    0x16a28000, 0x0fd74000, 0x03fae000, 0x0cc59000, 0x0ea21800, 0xcbd37c00, 0xb5f97e00, 0x19c38900,
+    0xa4256080, 0xe69346c0, 0x9598fea0, 0x97b65fd0, 0x398b0608, 0xff6e250c, 0x265ae69e, 0xb49523d5,
+    0x069a780f, 0x5031ac36, 0xea498624, 0x4c496528, 0x1148069d, 0x97ccb3cb, 0x508e602e, 0x57edd005,
+    0x701a781f, 0x0f71ac1c, 0x69298606, 0x7db9652b, 0x0b2006b5, 0x8310b3cc, 0x4d58603d, 0x6712d002,
+    0xcb58f83d, 0x0a16ec32, 0x35db661b, 0x9950f516,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xd8000000, 0xcc000000, 0x02000000, 0xa9000000,
+    0x31800000, 0x0bc00000, 0x2de00000, 0xcbb00000, 0xf0c80000, 0x93bc0000, 0x92120000, 0x76590000,
+    0xfda08000, 0x70934000, 0x791fa000, 0xa001f000, 0x50059800, 0x4805a400, 0x64024200, 0x1602b900,
+    0x6700de80, 0x9a8195c0, 0x93473ca0, 0x17a7dcf0, 0xed90fa18, 0x1699ad2c, 0xa8c2a48e, 0xf16278ef,
+    0x77f2b816, 0x19eb1429, 0xfb6a7a34, 0xf42fed34, 0xa98f84b1, 0x8919c8c3, 0xb8000017, 0x7c00000c,
+    0x3a00002c, 0x15000027, 0xeb800026, 0x6ec00000, 0x1e60001d, 0x6970003a, 0xeca80022, 0x53cc0025,
+    0x4f3a0039, 0x2e550039, 0x9f7a8022, 0x9576402f,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x28000000, 0xe4000000, 0x7e000000, 0x5d000000,
+    0xc6800000, 0x2f400000, 0x35e00000, 0xd4f00000, 0x2b480000, 0xf7fc0000, 0xbcd60000, 0x63190000,
+    0x3ca08000, 0x3014c000, 0xc33d6000, 0x20f75000, 0xcd4a5800, 0x06fcfc00, 0x10553e00, 0x475a7d00,
+    0x76c69c80, 0x7321b7c0, 0xafd0c2a0, 0xf0999ad0, 0xa1628638, 0x8cb5112c, 0xb32c9a92, 0x83cc66c5,
+    0x1dbf3835, 0xe3b7ac3e, 0xf2a96636, 0x290f8120, 0x6c9b22b6, 0xc3630ac7, 0xf3b53e38, 0x6aaa7d0f,
+    0x850e9c8c, 0x069db7d2, 0xc866c287, 0x8c309ac8, 0xfd6a0612, 0xc2add102, 0x210ffa8f, 0x189e36df,
+    0x6563e000, 0x62b69017, 0x3629b815, 0x894a6c18,
+    0x80000000, 0x40000000, 0xa0000000, 0x50000000, 0x48000000, 0x9c000000, 0xce000000, 0x8f000000,
+    0xbf800000, 0x11c00000, 0xd6a00000, 0x9a300000, 0x7e280000, 0xf8dc0000, 0xa6d60000, 0xd97f0000,
+    0xe7638000, 0x5c92c000, 0x0c1ba000, 0x4af41000, 0xd80a4800, 0x6cac6400, 0x4f9f8200, 0x25330300,
+    0x39aef880, 0xcd181ec0, 0x3a74daa0, 0xd64c0dd0, 0xfbcdea08, 0x4b09b704, 0x5d2892ba, 0x715f69f3,
+    0x1c11e801, 0xfa587414, 0xf995ca34, 0x969f6733, 0x81b17a88, 0x65eb1dc5, 0x1b7a2239, 0x0e64132b,
+    0x001130b1, 0x7459bad8, 0xd6937897, 0x7919def0, 0xd872fa9e, 0x2f49dddc, 0x4f498229, 0xff4c0318,
+    0x474d78a0, 0xc34adef3, 0xd94f7aaf, 0x04481dc6,
+    0x80000000, 0xc0000000, 0xe0000000, 0x70000000, 0xb8000000, 0x5c000000, 0x36000000, 0x8d000000,
+    0xef800000, 0xd4c00000, 0xa6a00000, 0x15b00000, 0x16580000, 0xb82c0000, 0x98520000, 0x79890000,
+    0x19608000, 0x39904000, 0x4ca82000, 0x7e14d000, 0xcc6e1800, 0xeab2f400, 0x4eddea00, 0x10693b00,
+    0x1cb18480, 0x23dbc940, 0x8fee4ea0, 0x70732270, 0xd97b5218, 0xac5f5f2c, 0xeb2a5696, 0x8ed4d64b,
+    0xe0cc3803, 0x34032404, 0x8201720e, 0xcf028f0a, 0xc084cebc, 0x64466267, 0x7ae1f201, 0x3352cf38,
+    0x130ceea0, 0x2622b259, 0x51f7ea07, 0xfcbc3b32, 0x437b04a5, 0x6f5e8940, 0x35aceeb3, 0xf392b269,
+    0xa7afea1a, 0x34903b3a, 0x632904af, 0x4ad78943,
+    0x80000000, 0xc0000000, 0x60000000, 0xf0000000, 0x58000000, 0x4c000000, 0xf2000000, 0xfd000000,
+    0x05800000, 0x8cc00000, 0xc9600000, 0x7d300000, 0xba980000, 0x96ec0000, 0xe5d20000, 0x556d0000,
+    0x7a108000, 0x5e8b4000, 0x9767a000, 0x42347000, 0x7a19b800, 0xa3ae2400, 0x0a731a00, 0x503aa500,
+    0x4a3a9e80, 0x033af340, 0x28bea4a0, 0x037f6670, 0x269a2238, 0x6cefc10c, 0x9cd6248e, 0x46e8266f,
+    0xedd7820a, 0xd16ab11c, 0x6c151ca5, 0x458c4260, 0xd1e1b805, 0xc472241f, 0xc7391a38, 0x9ebba539,
+    0x88781e80, 0xc81cb340, 0xfea98489, 0x9ff05652, 0x74fc3a2c, 0x9759950d, 0xc00e86be, 0x9d21a75d,
+    0x6d1686a6, 0x4a0da752, 0x7c248689, 0x5290a741,
+    0x80000000, 0x40000000, 0xe0000000, 0x90000000, 0xd8000000, 0xe4000000, 0x56000000, 0x05000000,
+    0x60800000, 0xae400000, 0xc1e00000, 0x6d300000, 0xf1080000, 0xe6fc0000, 0x56560000, 0x8d7d0000,
+    0x39978000, 0x34584000, 0xae02e000, 0xd1031000, 0x9e835800, 0x07408c00, 0xcb620600, 0x74747500,
+    0x036cd280, 0x400dbbc0, 0xa87bb4a0, 0x69129ed0, 0x321ede18, 0xc3e0b934, 0xd632348e, 0xd88bdedd,
+    0xd23dbe03, 0x56f6e928, 0xf62c0cb5, 0x386c02ce, 0x2988003e, 0x7cbc0019, 0xf9b60021, 0x914d0007,
+    0x261f8007, 0x9de44008, 0x0f34e03e, 0x9a0e1034, 0xf77cd801, 0x1694cc2b, 0x03dee629, 0xa3c6650d,
+    0x03260aa7, 0x189477e8, 0xa2dad2ac, 0x7540bbca,
+    0x80000000, 0xc0000000, 0xe0000000, 0xd0000000, 0x28000000, 0x3c000000, 0x2e000000, 0x39000000,
+    0x1e800000, 0x0d400000, 0xe6a00000, 0x8bd00000, 0x37180000, 0x230c0000, 0xe9f20000, 0x6a890000,
+    0xe3358000, 0xafe9c000, 0x7b03a000, 0xe1801000, 0xf6c6f800, 0x0b665c00, 0x7130ae00, 0x78ef7700,
+    0xbc86ef80, 0x22424440, 0x352061a0, 0x5a14e370, 0xe3fbd618, 0x55fceb04, 0x68ffe1b2, 0xac78237b,
+    0x5a3ff621, 0x9d1c3b31, 0x000f39b4, 0xec77af79, 0x964a0014, 0x3b55001e, 0xc35f8007, 0x3b2cc010,
+    0xbf64203a, 0x9330d01e, 0x77e8d830, 0x5f038c1d, 0x0b87f62c, 0xf5c03b18, 0x3ee539a0, 0x75f2af42,
+    0xb48d8034, 0xc235c00f, 0x7569a02f, 0x4c451000,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x88000000, 0x94000000, 0xca000000, 0x65000000,
+    0x98800000, 0x21c00000, 0x74200000, 0x02f00000, 0xf9680000, 0xbedc0000, 0x1c520000, 0x935d0000,
+    0x8d138000, 0xa2bec000, 0x29c7e000, 0x20207000, 0xa8f23800, 0x0c6fcc00, 0xae5bda00, 0xa9972700,
+    0x2d7c2280, 0xeae67ac0, 0xc35398a0, 0xb6dfedf0, 0x48526238, 0x395a2b14, 0x781218ba, 0xb23c2dc1,
+    0x9c060230, 0x9e049b0c, 0xcf07c08c, 0x6d8391f5, 0x3147e039, 0xc1e0701d, 0xbcd23809, 0x9e9fcc12,
+    0xdf33da3d, 0x834b2726, 0xfb2e228b, 0x1cbb7ad5, 0xd6c01891, 0x35a12df7, 0x15b58202, 0x1b8a5b3b,
+    0x298820a6, 0x008fe1ef, 0x2e0fd81d, 0xa4cebc30,
+    0x80000000, 0x40000000, 0xa0000000, 0x30000000, 0x18000000, 0x84000000, 0x76000000, 0x97000000,
+    0xf9800000, 0x27c00000, 0xaea00000, 0x9a900000, 0xa6780000, 0x2eac0000, 0x3bb60000, 0xcc2f0000,
+    0x7ff08000, 0x984b4000, 0xe745e000, 0x83e41000, 0x0c72c800, 0x0a8ed400, 0xd9618200, 0xfc36d300,
+    0x30e8dd80, 0x52d1e140, 0x681c3fa0, 0x6b186250, 0x8c9bca08, 0x005f471c, 0x957abfae, 0xd12c226d,
+    0x3376aa3b, 0x2f0c1714, 0xaca3978b, 0x8395e660, 0xdcfb6033, 0xaeec501e, 0xb9d1a837, 0xf39e8404,
+    0x85de2a1f, 0xd0bb5710, 0xb68877a7, 0x5b62f656, 0xa5372811, 0xea6ac414, 0xe2934a37, 0xf278073b,
+    0xd0a95f85, 0x00b73271, 0xdface230, 0x3d35830d,
+    0x80000000, 0x40000000, 0x60000000, 0x70000000, 0x08000000, 0xbc000000, 0xfe000000, 0x9f000000,
+    0x1d800000, 0xf8400000, 0x60a00000, 0x06b00000, 0x50780000, 0x850c0000, 0x4e960000, 0x5e090000,
+    0x59148000, 0x1b49c000, 0x3532a000, 0x6f3b9000, 0x5c2c4800, 0x8a647400, 0xbbd19e00, 0x1a2d5f00,
+    0x8163ec80, 0xf4562c40, 0xfb6c52a0, 0xd7452350, 0x55275638, 0x52f5eb0c, 0xb6da529a, 0xa8bc2373,
+    0x416bd61d, 0x42402b18, 0xf5a6f2a0, 0xa632b345, 0xa4bd1e3d, 0x97689f00, 0x71474cab, 0x6e24bc5b,
+    0x75749aa2, 0x9b989777, 0x089c681a, 0xc31f243b, 0x085bf612, 0xf37b7b31, 0x268c9aac, 0x49d49767,
+    0x532a6823, 0xafe6240a, 0x97977617, 0x488ebb14,
+    0x80000000, 0x40000000, 0xe0000000, 0xd0000000, 0x88000000, 0xac000000, 0x8e000000, 0xe7000000,
+    0xb1800000, 0x48400000, 0x32200000, 0x78700000, 0xa5080000, 0x72bc0000, 0x2ed60000, 0x65bd0000,
+    0x67558000, 0x39f8c000, 0x2f722000, 0x0c8d9000, 0xfefc0800, 0xeef75400, 0xfccafe00, 0x38dc0b00,
+    0x3c876e80, 0x9cc7b1c0, 0x92e230a0, 0x0c92ead0, 0x329d7618, 0xf7a29f24, 0xda34309a, 0xb22feadf,
+    0x0248f621, 0x1d1a5f02, 0x70e610b7, 0xc5927adf, 0x541cfe22, 0x56610b37, 0x8452eebe, 0xda7f71cf,
+    0xb630108c, 0x9c2f7aec, 0xd5497e25, 0xf499cb14, 0x1ca0ce8d, 0xd5b2e1dc, 0x456c1892, 0xa5e82efd,
+    0x0f2b8027, 0x8ec9c028, 0x99d9a00a, 0x26045020,
+    0x80000000, 0xc0000000, 0xa0000000, 0x90000000, 0x28000000, 0x94000000, 0xc6000000, 0x3f000000,
+    0xe1800000, 0x3d400000, 0x32200000, 0xebb00000, 0x32380000, 0x174c0000, 0xb3120000, 0x934b0000,
+    0x9d158000, 0x984bc000, 0x2a94e000, 0xb2081000, 0x6d378800, 0xa2f8d400, 0x52289600, 0xbf83a300,
+    0xee45b080, 0x09a3fe40, 0x2bf446a0, 0x309f8d70, 0x8b389e08, 0xa9cbb714, 0x5fd446a2, 0x242f8d41,
+    0xf8809e1b, 0x13c7b71a, 0xf6e646a1, 0xc8d48d64, 0x91ad1e1e, 0xa3c07720, 0x8ee0a699, 0xd4d79d54,
+    0x53af1632, 0x72c3633b, 0xc464d09e, 0xce102e53, 0x63cf2eb3, 0x4ad34955, 0x20ad8008, 0xd947c001,
+    0x2c26e03e, 0x58b3102a, 0x39ba083e, 0x6f0f1435,
+    0x80000000, 0x40000000, 0x20000000, 0x50000000, 0x78000000, 0xf4000000, 0xee000000, 0xd5000000,
+    0xa1800000, 0x43400000, 0x73e00000, 0xcc300000, 0x8cc80000, 0x183c0000, 0x8f560000, 0x043b0000,
+    0x8d578000, 0xbb3b4000, 0x3fd16000, 0x00f85000, 0xb4f71800, 0xc76c8400, 0xf36ea600, 0x7d6c0d00,
+    0xd86b4280, 0x51ec95c0, 0x9eab04a0, 0xf74f88d0, 0x00783e28, 0xf830c904, 0x02cb0496, 0xbd3f88e9,
+    0x06d03e05, 0xcb7cc918, 0xe4b5049b, 0x4c0888f1, 0xc799be0a, 0xfa00890e, 0x0b05e48c, 0x7c8098d4,
+    0x3ec04616, 0x52a31d3a, 0x70d33aaf, 0xda7f41fd, 0xa3333a99, 0x064f41d5, 0x77fb3a8e, 0xba7341f2,
+    0x6ead3a8f, 0x9f4841ef, 0xac7abab7, 0xb23301f5,
+    0x80000000, 0x40000000, 0x60000000, 0xd0000000, 0x38000000, 0x14000000, 0xe2000000, 0xdd000000,
+    0x2c800000, 0xa0400000, 0x26e00000, 0x22300000, 0x5ed80000, 0x762c0000, 0x59560000, 0x46290000,
+    0xf1518000, 0x0a2d4000, 0xd7566000, 0x0d2b3000, 0x32d73800, 0x63ebac00, 0x6975b600, 0x4bbefd00,
+    0xb5d89280, 0x45a82d40, 0xb892c4a0, 0x040ca050, 0x79c50e38, 0xa6211124, 0xe292c496, 0x8d0ca071,
+    0xd7450e16, 0x0b611122, 0xd0f2c4ab, 0x1b7ca06b, 0x4d7d0e2c, 0x827d1135, 0xfbfcc4be, 0x8b39a056,
+    0xc39a8e24, 0xec495131, 0x83232483, 0xfa13d058, 0x7f4dd63b, 0xc4a0cd21, 0x29d02a86, 0xd86bc141,
+    0x74b69298, 0xc79d2d68, 0x164d449d, 0xfa24e04a,
+    0x80000000, 0x40000000, 0xa0000000, 0x90000000, 0x98000000, 0xfc000000, 0x62000000, 0x9b000000,
+    0xa1800000, 0x8fc00000, 0x10600000, 0xd0d00000, 0xf4c80000, 0x8c5c0000, 0xa2360000, 0x165f0000,
+    0xb5358000, 0x7dda4000, 0x55f22000, 0xca3a7000, 0x0fe20800, 0x7610b400, 0xe62ba200, 0x1ecf4500,
+    0x335ad980, 0x3db566c0, 0xf09edba0, 0x07d613d0, 0x7f4a2a08, 0x5c9ab134, 0x1dd6db8e, 0x284a13db,
+    0x971c2a1e, 0x6d15b109, 0x07ab5b98, 0x310c53e1, 0xb3380a2a, 0x7560c138, 0xf854d389, 0xe98aa7d5,
+    0x467f883b, 0xeb46f42a, 0x27278231, 0x45f6352c, 0x123b518b, 0x53e092d2, 0x8412d982, 0xe52966e8,
+    0x4348db8a, 0xde9913f1, 0xb6d7aa22, 0x81ccf100,
+    0x80000000, 0xc0000000, 0xe0000000, 0x30000000, 0xf8000000, 0xa4000000, 0xf2000000, 0x2d000000,
+    0xc9800000, 0xd8400000, 0x16a00000, 0xc0700000, 0xb8180000, 0x67ac0000, 0x7a520000, 0xf5a90000,
+    0xa7528000, 0x24284000, 0xeb11e000, 0x388d3000, 0xa2623800, 0xbb158400, 0x308fde00, 0x1e67bf00,
+    0xdd16aa80, 0x178e4940, 0x5ee214a0, 0x3ed08670, 0xf46d6618, 0x41b37b3c, 0x9efa1486, 0x3d7c8665,
+    0x9c3f6622, 0xa91a7b12, 0x082894b6, 0x6514c671, 0x938e8629, 0x7ce74b1f, 0xdbd2acaf, 0x61ed426e,
+    0xcff3581c, 0x5759f40b, 0x198e8636, 0x35e74b03, 0x0052acb1, 0xa4ad4241, 0xe8d35815, 0xeb69f416,
+    0x45368629, 0xbf3b4b03, 0x0b98aca4, 0xeee84268,
+    0x80000000, 0xc0000000, 0x60000000, 0xb0000000, 0xc8000000, 0xcc000000, 0x62000000, 0xc1000000,
+    0x8d800000, 0xbcc00000, 0xa5e00000, 0xf0b00000, 0x35380000, 0xc5cc0000, 0x0c520000, 0x3c0d0000,
+    0x4a328000, 0xb37dc000, 0x266f2000, 0x7f42f000, 0xca265800, 0xb1d28400, 0x88c95a00, 0x43d2e100,
+    0x91cdb680, 0x1a51d240, 0xfb0b4ca0, 0x18b10370, 0xe93d0238, 0xbfcd651c, 0x79566caa, 0xd78ef35f,
+    0x99f1da0a, 0x381e2133, 0x269a16a3, 0xdedfe273, 0xe67ab498, 0xddedb750, 0xb485a004, 0xf5433021,
+    0xd7237807, 0x4651740e, 0x410f8212, 0x0db0a51c, 0xb2b94cad, 0xa40c035d, 0x3e378212, 0xb57ca51f,
+    0xf96b4cbe, 0x99c10371, 0xf6650213, 0x47716534,
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0x28000000, 0xf4000000, 0x0e000000, 0x79000000,
+    0xdc800000, 0x13400000, 0x69a00000, 0x0ad00000, 0xb0b80000, 0x34ac0000, 0x3e720000, 0xe0ed0000,
+    0xea538000, 0x09fbc000, 0x6209e000, 0xf5215000, 0xb997f800, 0x291d0c00, 0x867fce00, 0x52ccff00,
+    0x2e432f80, 0xa3277140, 0x4c9481a0, 0x479c1e70, 0x863a3638, 0xb8ecf314, 0x66576192, 0xbbfc4e59,
+    0x710c4e29, 0x76a73f13, 0xfb52cfac, 0x817a217e, 0x8f497987, 0x5d80124b, 0xb3c4780d, 0x70e6cc05,
+    0xac762e06, 0xc3edaf28, 0xb1d4d7a6, 0x073a7d5b, 0x186b4f9a, 0x7f10e158, 0x1d5919ac, 0x025b825d,
+    0xf3dbe031, 0xc21c503a, 0x79fc7800, 0x1a0acc33,
+    0x80000000, 0x40000000, 0x60000000, 0xf0000000, 0x78000000, 0xf4000000, 0xae000000, 0xbb000000,
+    0xc2800000, 0x2bc00000, 0x21e00000, 0x82f00000, 0xc1680000, 0x05dc0000, 0x12160000, 0x3c190000,
+    0x8af08000, 0x0d6e4000, 0x3fdb6000, 0xf7135000, 0x3d9a6800, 0x97b4ec00, 0xa94c7a00, 0x27cee900,
+    0x76085c80, 0xd2ed2ac0, 0xa199c6a0, 0x05b7d3d0, 0x50481238, 0xf44f052c, 0xf24aa686, 0x554883c1,
+    0x45cc7a15, 0xf70ee903, 0xf5685c83, 0x8bdd2ad8, 0x3911c686, 0x769bd3c7, 0x2d36122b, 0x768a0523,
+    0xa82c2682, 0x4fffc3c5, 0xd1079a3b, 0x8f83f92f, 0x3641549e, 0xeea696e7, 0xbfd1d482, 0xfaf8d6d0,
+    0x7882b4ac, 0x8ec786cc, 0x4066dc9a, 0x6fb66ad5,
+];
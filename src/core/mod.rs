@@ -1,2 +1,16 @@
+pub mod camera;
+pub mod film;
+pub mod filter;
 pub mod geometry;
-pub mod medium;
\ No newline at end of file
+pub mod imageio;
+pub mod lowdiscrepancy;
+pub mod medium;
+pub mod pbrt;
+pub mod primitive;
+pub mod quaternion;
+pub mod rng;
+pub mod sampler;
+pub mod sampling;
+pub mod shape;
+pub mod spectrum;
+pub mod transform;
@@ -0,0 +1,3 @@
+pub use pbrt::*;
+
+mod pbrt;
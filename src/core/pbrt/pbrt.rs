@@ -0,0 +1,150 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+pub const PI: f32 = std::f32::consts::PI;
+pub const INV_PI: f32 = std::f32::consts::FRAC_1_PI;
+pub const INV_2_PI: f32 = 0.5 * std::f32::consts::FRAC_1_PI;
+pub const INV_4_PI: f32 = 0.25 * std::f32::consts::FRAC_1_PI;
+pub const PI_OVER_2: f32 = std::f32::consts::FRAC_PI_2;
+pub const PI_OVER_4: f32 = std::f32::consts::FRAC_PI_4;
+pub const MACHINE_EPSILON: f32 = f32::EPSILON * 0.5;
+pub const ONE_MINUS_EPSILON: f32 = 1.0 - f32::EPSILON * 0.5;
+
+#[inline]
+pub fn gamma(n: i32) -> f32 {
+    (n as f32 * MACHINE_EPSILON) / (1.0 - n as f32 * MACHINE_EPSILON)
+}
+
+#[inline]
+pub fn lerp(t: f32, v1: f32, v2: f32) -> f32 {
+    (1.0 - t) * v1 + t * v2
+}
+
+#[inline]
+pub fn clamp<T: PartialOrd>(val: T, low: T, high: T) -> T {
+    if val < low {
+        low
+    } else if val > high {
+        high
+    } else {
+        val
+    }
+}
+
+#[inline]
+pub fn radians(deg: f32) -> f32 {
+    (PI / 180.0) * deg
+}
+
+#[inline]
+pub fn degrees(rad: f32) -> f32 {
+    (180.0 / PI) * rad
+}
+
+// real roots of a*t^2 + b*t + c in ascending order
+pub fn quadratic(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
+    let discrim = b as f64 * b as f64 - 4.0 * a as f64 * c as f64;
+    if discrim < 0.0 {
+        return None;
+    }
+    let root_discrim = discrim.sqrt();
+    let q = if b < 0.0 {
+        -0.5 * (b as f64 - root_discrim)
+    } else {
+        -0.5 * (b as f64 + root_discrim)
+    };
+    let t0 = (q / a as f64) as f32;
+    let t1 = (c as f64 / q) as f32;
+    if t0 > t1 {
+        Some((t1, t0))
+    } else {
+        Some((t0, t1))
+    }
+}
+
+// 64-bit finalizer that spreads every input bit over the whole output
+#[inline]
+pub fn mix_bits(mut v: u64) -> u64 {
+    v ^= v >> 31;
+    v = v.wrapping_mul(0x7fb5_d329_728e_a185);
+    v ^= v >> 27;
+    v = v.wrapping_mul(0x81da_def4_bc2d_d44d);
+    v ^= v >> 33;
+    v
+}
+
+const MURMUR_M: u64 = 0xc6a4_a793_5bd1_e995;
+const MURMUR_R: u32 = 47;
+
+#[inline]
+fn murmur_mix(h: u64, word: u64) -> u64 {
+    let mut k = word.wrapping_mul(MURMUR_M);
+    k ^= k >> MURMUR_R;
+    k = k.wrapping_mul(MURMUR_M);
+    (h ^ k).wrapping_mul(MURMUR_M)
+}
+
+#[inline]
+fn murmur_finalize(mut h: u64) -> u64 {
+    h ^= h >> MURMUR_R;
+    h = h.wrapping_mul(MURMUR_M);
+    h ^ (h >> MURMUR_R)
+}
+
+// Appleby's MurmurHash64A
+pub fn murmur_hash64a(key: &[u8], seed: u64) -> u64 {
+    let mut h = seed ^ (key.len() as u64).wrapping_mul(MURMUR_M);
+    let mut chunks = key.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(chunk);
+        h = murmur_mix(h, u64::from_le_bytes(buf));
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        for (i, &b) in tail.iter().enumerate() {
+            h ^= (b as u64) << (8 * i);
+        }
+        h = h.wrapping_mul(MURMUR_M);
+    }
+    murmur_finalize(h)
+}
+
+// hash of a few integers, e.g. a pixel, a dimension and a seed. same as
+// murmur_hash64a over their little endian bytes, without building them.
+pub fn hash(values: &[u64]) -> u64 {
+    let h = values.iter().fold((8 * values.len() as u64).wrapping_mul(MURMUR_M), |h, &v| murmur_mix(h, v));
+    murmur_finalize(h)
+}
+
+// f32 that can be read, written and accumulated from several threads at once
+#[derive(Debug, Default)]
+pub struct AtomicFloat {
+    bits: AtomicU32,
+}
+
+impl AtomicFloat {
+    pub fn new(v: f32) -> Self {
+        Self {
+            bits: AtomicU32::new(v.to_bits()),
+        }
+    }
+
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, v: f32) {
+        self.bits.store(v.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn add(&self, v: f32) {
+        let mut old = self.bits.load(Ordering::Relaxed);
+        loop {
+            let new = (f32::from_bits(old) + v).to_bits();
+            match self.bits.compare_exchange_weak(old, new, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => return,
+                Err(current) => old = current,
+            }
+        }
+    }
+}
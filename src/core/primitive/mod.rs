@@ -0,0 +1,3 @@
+pub use primitive::*;
+
+mod primitive;
@@ -0,0 +1,80 @@
+use crate::core::geometry::{Bounds3f, Ray};
+use crate::core::shape::Shape;
+use crate::core::transform::{AnimatedTransform, Transform};
+use std::sync::Arc;
+
+pub trait Primitive: Send + Sync {
+    fn world_bound(&self) -> Bounds3f;
+    // on a hit, ray.t_max is shortened to the hit distance
+    fn intersect(&self, ray: &mut Ray) -> bool;
+    fn intersect_p(&self, ray: &Ray) -> bool;
+}
+
+pub struct GeometricPrimitive {
+    pub shape: Arc<dyn Shape>,
+}
+
+impl GeometricPrimitive {
+    pub fn new(shape: Arc<dyn Shape>) -> Self {
+        Self { shape }
+    }
+}
+
+impl Primitive for GeometricPrimitive {
+    fn world_bound(&self) -> Bounds3f {
+        self.shape.world_bound()
+    }
+
+    fn intersect(&self, ray: &mut Ray) -> bool {
+        match self.shape.intersect(ray) {
+            Some(t_hit) => {
+                ray.t_max = t_hit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.shape.intersect_p(ray)
+    }
+}
+
+// an instance of a shared primitive, typically a whole accelerator, placed
+// in the scene with its own transform. rays are taken into the primitive's
+// space instead of copying its geometry to world space.
+pub struct TransformedPrimitive {
+    primitive: Arc<dyn Primitive>,
+    primitive_to_world: AnimatedTransform,
+}
+
+impl TransformedPrimitive {
+    pub fn new(primitive: Arc<dyn Primitive>, primitive_to_world: AnimatedTransform) -> Self {
+        Self {
+            primitive,
+            primitive_to_world,
+        }
+    }
+}
+
+impl Primitive for TransformedPrimitive {
+    fn world_bound(&self) -> Bounds3f {
+        self.primitive_to_world.motion_bounds(&self.primitive.world_bound())
+    }
+
+    fn intersect(&self, ray: &mut Ray) -> bool {
+        let interpolated_prim_to_world = self.primitive_to_world.interpolate(ray.time);
+        let mut r = Transform::inverse(&interpolated_prim_to_world).transform_ray(ray);
+        if !self.primitive.intersect(&mut r) {
+            return false;
+        }
+        ray.t_max = r.t_max;
+        true
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        let interpolated_prim_to_world = self.primitive_to_world.interpolate(ray.time);
+        let r = Transform::inverse(&interpolated_prim_to_world).transform_ray(ray);
+        self.primitive.intersect_p(&r)
+    }
+}
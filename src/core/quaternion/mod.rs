@@ -0,0 +1,3 @@
+pub use quaternion::*;
+
+mod quaternion;
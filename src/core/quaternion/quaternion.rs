@@ -0,0 +1,146 @@
+use crate::core::geometry::Vector3f;
+use crate::core::pbrt::clamp;
+use crate::core::transform::{Matrix4x4, Transform};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quaternion {
+    pub v: Vector3f,
+    pub w: f32,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::new(Vector3f::new(0.0, 0.0, 0.0), 1.0)
+    }
+}
+
+impl Quaternion {
+    pub const fn new(v: Vector3f, w: f32) -> Self {
+        Self { v, w }
+    }
+
+    pub fn dot(q1: &Self, q2: &Self) -> f32 {
+        Vector3f::dot(&q1.v, &q2.v) + q1.w * q2.w
+    }
+
+    pub fn normalize(q: &Self) -> Self {
+        *q / Self::dot(q, q).sqrt()
+    }
+
+    pub fn slerp(t: f32, q1: &Self, q2: &Self) -> Self {
+        let cos_theta = Self::dot(q1, q2);
+        if cos_theta > 0.9995 {
+            Self::normalize(&(*q1 * (1.0 - t) + *q2 * t))
+        } else {
+            let theta = clamp(cos_theta, -1.0, 1.0).acos();
+            let thetap = theta * t;
+            let qperp = Self::normalize(&(*q2 - *q1 * cos_theta));
+            *q1 * thetap.cos() + qperp * thetap.sin()
+        }
+    }
+
+    // rotation part of t, which must not contain scale or shear
+    pub fn from_transform(t: &Transform) -> Self {
+        let m = &t.m.m;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace > 0.0 {
+            let mut s = (trace + 1.0).sqrt();
+            let w = s / 2.0;
+            s = 0.5 / s;
+            Self::new(
+                Vector3f::new(
+                    (m[2][1] - m[1][2]) * s,
+                    (m[0][2] - m[2][0]) * s,
+                    (m[1][0] - m[0][1]) * s,
+                ),
+                w,
+            )
+        } else {
+            let nxt = [1, 2, 0];
+            let mut q = [0.0f32; 3];
+            let mut i = 0;
+            if m[1][1] > m[0][0] {
+                i = 1;
+            }
+            if m[2][2] > m[i][i] {
+                i = 2;
+            }
+            let j = nxt[i];
+            let k = nxt[j];
+            let mut s = ((m[i][i] - (m[j][j] + m[k][k])) + 1.0).sqrt();
+            q[i] = s * 0.5;
+            if s != 0.0 {
+                s = 0.5 / s;
+            }
+            let w = (m[k][j] - m[j][k]) * s;
+            q[j] = (m[j][i] + m[i][j]) * s;
+            q[k] = (m[k][i] + m[i][k]) * s;
+            Self::new(Vector3f::new(q[0], q[1], q[2]), w)
+        }
+    }
+
+    pub fn to_transform(&self) -> Transform {
+        let (x, y, z) = (self.v.x, self.v.y, self.v.z);
+        let xx = x * x;
+        let yy = y * y;
+        let zz = z * z;
+        let xy = x * y;
+        let xz = x * z;
+        let yz = y * z;
+        let wx = x * self.w;
+        let wy = y * self.w;
+        let wz = z * self.w;
+
+        let m = Matrix4x4::new([
+            [1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy), 0.0],
+            [2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx), 0.0],
+            [2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        // transpose since we are left-handed
+        Transform::new(Matrix4x4::transpose(&m), m)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+    fn add(self, q: Self) -> Self {
+        Self::new(self.v + q.v, self.w + q.w)
+    }
+}
+
+impl AddAssign for Quaternion {
+    fn add_assign(&mut self, q: Self) {
+        self.v += q.v;
+        self.w += q.w;
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+    fn sub(self, q: Self) -> Self {
+        Self::new(self.v - q.v, self.w - q.w)
+    }
+}
+
+impl Mul<f32> for Quaternion {
+    type Output = Self;
+    fn mul(self, f: f32) -> Self {
+        Self::new(self.v * f, self.w * f)
+    }
+}
+
+impl Div<f32> for Quaternion {
+    type Output = Self;
+    fn div(self, f: f32) -> Self {
+        Self::new(self.v / f, self.w / f)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.v, -self.w)
+    }
+}
@@ -0,0 +1,3 @@
+pub use rng::*;
+
+mod rng;
@@ -0,0 +1,102 @@
+use crate::core::pbrt::ONE_MINUS_EPSILON;
+
+const PCG32_DEFAULT_STATE: u64 = 0x853c_49e6_748f_ea9b;
+const PCG32_DEFAULT_STREAM: u64 = 0xda3e_39cb_94b9_5bdb;
+const PCG32_MULT: u64 = 0x5851_f42d_4c95_7f2d;
+
+// O'Neill's PCG32 generator, 64 bits of state and a selectable stream
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rng {
+    state: u64,
+    inc: u64,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self {
+            state: PCG32_DEFAULT_STATE,
+            inc: PCG32_DEFAULT_STREAM,
+        }
+    }
+}
+
+impl Rng {
+    pub fn new(sequence_index: u64) -> Self {
+        let mut rng = Self::default();
+        rng.set_sequence(sequence_index);
+        rng
+    }
+
+    pub fn new_with_offset(sequence_index: u64, offset: u64) -> Self {
+        let mut rng = Self::default();
+        rng.set_sequence_with_offset(sequence_index, offset);
+        rng
+    }
+
+    // restarts the generator on one of 2^63 independent streams
+    pub fn set_sequence(&mut self, sequence_index: u64) {
+        self.set_sequence_with_offset(sequence_index, PCG32_DEFAULT_STATE);
+    }
+
+    // the offset seeds the starting point within the stream
+    pub fn set_sequence_with_offset(&mut self, sequence_index: u64, offset: u64) {
+        self.state = 0;
+        self.inc = (sequence_index << 1) | 1;
+        self.uniform_u32();
+        self.state = self.state.wrapping_add(offset);
+        self.uniform_u32();
+    }
+
+    // skips delta values in O(log delta), negative deltas go back. Brown's
+    // jump ahead for LCGs.
+    pub fn advance(&mut self, delta: i64) {
+        let (mut cur_mult, mut cur_plus) = (PCG32_MULT, self.inc);
+        let (mut acc_mult, mut acc_plus) = (1u64, 0u64);
+        // the period is 2^64, so going back is going far ahead
+        let mut delta = delta as u64;
+        while delta > 0 {
+            if delta & 1 != 0 {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta >>= 1;
+        }
+        self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
+    }
+
+    pub fn uniform_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state.wrapping_mul(PCG32_MULT).wrapping_add(self.inc);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rot)
+    }
+
+    // uniform in [0, b) without modulo bias
+    pub fn uniform_u32_bounded(&mut self, b: u32) -> u32 {
+        let threshold = b.wrapping_neg() % b;
+        loop {
+            let r = self.uniform_u32();
+            if r >= threshold {
+                return r % b;
+            }
+        }
+    }
+
+    pub fn uniform_u64(&mut self) -> u64 {
+        let high = self.uniform_u32() as u64;
+        (high << 32) | self.uniform_u32() as u64
+    }
+
+    // uniform in [0, 1)
+    pub fn uniform_f32(&mut self) -> f32 {
+        (self.uniform_u32() as f32 * (1.0 / 4_294_967_296.0)).min(ONE_MINUS_EPSILON)
+    }
+
+    // uniform in [0, 1) with all 53 bits of the mantissa random
+    pub fn uniform_f64(&mut self) -> f64 {
+        (self.uniform_u64() >> 11) as f64 * (1.0 / 9_007_199_254_740_992.0)
+    }
+}
@@ -0,0 +1,3 @@
+pub use sampler::*;
+
+mod sampler;
@@ -0,0 +1,52 @@
+use crate::core::camera::CameraSample;
+use crate::core::geometry::{Point2f, Point2i, Vector2f};
+use crate::core::pbrt::hash;
+use crate::core::rng::Rng;
+
+// source of sample vectors for the pixels of an image. the samples of a
+// pixel are taken one after another, each consumed as a sequence of 1D and
+// 2D dimensions.
+pub trait Sampler: Send {
+    fn samples_per_pixel(&self) -> usize;
+
+    // moves to the first sample of pixel p
+    fn start_pixel(&mut self, p: Point2i);
+
+    fn get_1d(&mut self) -> f32;
+    fn get_2d(&mut self) -> Point2f;
+
+    // moves to the next sample of the pixel, false once all of them are used
+    fn start_next_sample(&mut self) -> bool;
+
+    // moves to an arbitrary sample of the current pixel
+    fn set_sample_number(&mut self, sample_num: usize) -> bool;
+
+    fn current_sample_number(&self) -> usize;
+
+    // independent copy, e.g. for another worker thread
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler>;
+
+    // film position inside pixel p_raster, then time and lens position
+    fn get_camera_sample(&mut self, p_raster: Point2i) -> CameraSample {
+        let p_film = self.get_2d() + Vector2f::new(p_raster.x as f32, p_raster.y as f32);
+        let time = self.get_1d();
+        let p_lens = self.get_2d();
+        CameraSample { p_film, p_lens, time }
+    }
+}
+
+// rng stream of a pixel, hashed from its coordinates and the seed
+pub fn pixel_sequence_index(p: Point2i, seed: u64) -> u64 {
+    hash(&[p.x as u64, p.y as u64, seed])
+}
+
+// values of the stream of a pixel reserved for each of its samples
+pub const PIXEL_SAMPLE_RNG_STRIDE: i64 = 65536;
+
+// generator for a sample of a pixel, the same whatever order the pixels and
+// samples are visited in
+pub fn pixel_sample_rng(p: Point2i, sample_index: usize, seed: u64) -> Rng {
+    let mut rng = Rng::new(pixel_sequence_index(p, seed));
+    rng.advance(sample_index as i64 * PIXEL_SAMPLE_RNG_STRIDE);
+    rng
+}
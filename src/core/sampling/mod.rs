@@ -0,0 +1,3 @@
+pub use sampling::*;
+
+mod sampling;
@@ -0,0 +1,728 @@
+use crate::core::geometry::{spherical_phi, Bounds2f, Point2f, Point3f, Vector2f, Vector3f};
+use crate::core::pbrt::{INV_2_PI, INV_4_PI, INV_PI, ONE_MINUS_EPSILON, PI, PI_OVER_2, PI_OVER_4};
+use crate::core::rng::Rng;
+
+// Shirley's concentric mapping from [0, 1)^2 to the unit disk
+pub fn concentric_sample_disk(u: &Point2f) -> Point2f {
+    let u_offset = *u * 2.0 - Vector2f::new(1.0, 1.0);
+    if u_offset.x == 0.0 && u_offset.y == 0.0 {
+        return Point2f::new(0.0, 0.0);
+    }
+    let (r, theta) = if u_offset.x.abs() > u_offset.y.abs() {
+        (u_offset.x, PI_OVER_4 * (u_offset.y / u_offset.x))
+    } else {
+        (u_offset.y, PI_OVER_2 - PI_OVER_4 * (u_offset.x / u_offset.y))
+    };
+    Point2f::new(theta.cos(), theta.sin()) * r
+}
+
+pub fn concentric_disk_pdf() -> f32 {
+    INV_PI
+}
+
+pub fn invert_concentric_sample_disk(p: &Point2f) -> Point2f {
+    let r = (p.x * p.x + p.y * p.y).sqrt();
+    let mut theta = p.y.atan2(p.x);
+    // the four wedges of the square start at -pi/4
+    if theta < -PI_OVER_4 {
+        theta += 2.0 * PI;
+    }
+    let (x, y) = if theta < PI_OVER_4 {
+        (r, r * theta / PI_OVER_4)
+    } else if theta < 3.0 * PI_OVER_4 {
+        (r * (PI_OVER_2 - theta) / PI_OVER_4, r)
+    } else if theta < 5.0 * PI_OVER_4 {
+        (-r, -r * (theta - PI) / PI_OVER_4)
+    } else {
+        (-r * (PI_OVER_2 - (theta - PI)) / PI_OVER_4, -r)
+    };
+    Point2f::new(
+        (0.5 * (x + 1.0)).clamp(0.0, ONE_MINUS_EPSILON),
+        (0.5 * (y + 1.0)).clamp(0.0, ONE_MINUS_EPSILON),
+    )
+}
+
+pub fn uniform_sample_hemisphere(u: &Point2f) -> Vector3f {
+    let z = u.x;
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.y;
+    Vector3f::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_hemisphere_pdf() -> f32 {
+    INV_2_PI
+}
+
+pub fn invert_uniform_hemisphere_sample(w: &Vector3f) -> Point2f {
+    Point2f::new(w.z.clamp(0.0, ONE_MINUS_EPSILON), (spherical_phi(w) * INV_2_PI).min(ONE_MINUS_EPSILON))
+}
+
+// Malley's method, points on the disk projected up to the hemisphere
+pub fn cosine_sample_hemisphere(u: &Point2f) -> Vector3f {
+    let d = concentric_sample_disk(u);
+    let z = (1.0 - d.x * d.x - d.y * d.y).max(0.0).sqrt();
+    Vector3f::new(d.x, d.y, z)
+}
+
+pub fn cosine_hemisphere_pdf(cos_theta: f32) -> f32 {
+    cos_theta * INV_PI
+}
+
+pub fn invert_cosine_hemisphere_sample(w: &Vector3f) -> Point2f {
+    invert_concentric_sample_disk(&Point2f::new(w.x, w.y))
+}
+
+pub fn uniform_sample_sphere(u: &Point2f) -> Vector3f {
+    let z = 1.0 - 2.0 * u.x;
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.y;
+    Vector3f::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_sphere_pdf() -> f32 {
+    INV_4_PI
+}
+
+pub fn invert_uniform_sphere_sample(w: &Vector3f) -> Point2f {
+    Point2f::new(
+        (0.5 * (1.0 - w.z)).clamp(0.0, ONE_MINUS_EPSILON),
+        (spherical_phi(w) * INV_2_PI).min(ONE_MINUS_EPSILON),
+    )
+}
+
+// directions within the cone of angle acos(cos_theta_max) around +z
+pub fn uniform_sample_cone(u: &Point2f, cos_theta_max: f32) -> Vector3f {
+    let cos_theta = (1.0 - u.x) + u.x * cos_theta_max;
+    let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.y;
+    Vector3f::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta)
+}
+
+pub fn uniform_cone_pdf(cos_theta_max: f32) -> f32 {
+    1.0 / (2.0 * PI * (1.0 - cos_theta_max))
+}
+
+pub fn invert_uniform_cone_sample(w: &Vector3f, cos_theta_max: f32) -> Point2f {
+    let u0 = (1.0 - w.z) / (1.0 - cos_theta_max);
+    Point2f::new(u0.clamp(0.0, ONE_MINUS_EPSILON), (spherical_phi(w) * INV_2_PI).min(ONE_MINUS_EPSILON))
+}
+
+// barycentrics of a uniformly distributed point in a triangle
+pub fn uniform_sample_triangle(u: &Point2f) -> Point2f {
+    let su0 = u.x.sqrt();
+    Point2f::new(1.0 - su0, u.y * su0)
+}
+
+// area density over the triangle
+pub fn uniform_triangle_pdf(p0: &Point3f, p1: &Point3f, p2: &Point3f) -> f32 {
+    1.0 / (0.5 * Vector3f::cross(&(*p1 - *p0), &(*p2 - *p0)).length())
+}
+
+pub fn invert_uniform_triangle_sample(b: &Point2f) -> Point2f {
+    let su0 = 1.0 - b.x;
+    let u1 = if su0 > 0.0 { b.y / su0 } else { 0.0 };
+    Point2f::new((su0 * su0).clamp(0.0, ONE_MINUS_EPSILON), u1.clamp(0.0, ONE_MINUS_EPSILON))
+}
+
+// angle between unit vectors, accurate for nearly parallel ones
+fn angle_between(v1: &Vector3f, v2: &Vector3f) -> f32 {
+    if Vector3f::dot(v1, v2) < 0.0 {
+        PI - 2.0 * ((*v1 + *v2).length() / 2.0).min(1.0).asin()
+    } else {
+        2.0 * ((*v2 - *v1).length() / 2.0).min(1.0).asin()
+    }
+}
+
+// component of v orthogonal to the unit vector w
+fn gram_schmidt(v: &Vector3f, w: &Vector3f) -> Vector3f {
+    *v - *w * Vector3f::dot(v, w)
+}
+
+// directions from p to the corners and the interior angles at them. None
+// for triangles that are degenerate as seen from p.
+fn spherical_triangle(v: &[Point3f; 3], p: &Point3f) -> Option<([Vector3f; 3], [f32; 3])> {
+    let a = v[0] - *p;
+    let b = v[1] - *p;
+    let c = v[2] - *p;
+    if a.length_squared() == 0.0 || b.length_squared() == 0.0 || c.length_squared() == 0.0 {
+        return None;
+    }
+    let (a, b, c) = (Vector3f::normalize(&a), Vector3f::normalize(&b), Vector3f::normalize(&c));
+    let n_ab = Vector3f::cross(&a, &b);
+    let n_bc = Vector3f::cross(&b, &c);
+    let n_ca = Vector3f::cross(&c, &a);
+    if n_ab.length_squared() == 0.0 || n_bc.length_squared() == 0.0 || n_ca.length_squared() == 0.0 {
+        return None;
+    }
+    let (n_ab, n_bc, n_ca) = (Vector3f::normalize(&n_ab), Vector3f::normalize(&n_bc), Vector3f::normalize(&n_ca));
+    let alpha = angle_between(&n_ab, &-n_ca);
+    let beta = angle_between(&n_bc, &-n_ab);
+    let gamma = angle_between(&n_ca, &-n_bc);
+    Some(([a, b, c], [alpha, beta, gamma]))
+}
+
+// solid angle of the triangle seen from p
+pub fn spherical_triangle_area(v: &[Point3f; 3], p: &Point3f) -> f32 {
+    spherical_triangle(v, p).map_or(0.0, |(_, angles)| (angles[0] + angles[1] + angles[2] - PI).max(0.0))
+}
+
+pub fn spherical_triangle_pdf(v: &[Point3f; 3], p: &Point3f) -> f32 {
+    let area = spherical_triangle_area(v, p);
+    if area > 0.0 {
+        1.0 / area
+    } else {
+        0.0
+    }
+}
+
+// Arvo's uniform sampling of the solid angle of a triangle seen from p.
+// u.x picks the sub-triangle of the same area through a, u.y the point
+// along the arc from b. returns the unit direction and its solid angle
+// density.
+pub fn sample_spherical_triangle(v: &[Point3f; 3], p: &Point3f, u: &Point2f) -> Option<(Vector3f, f32)> {
+    let ([a, b, c], [alpha, _, _]) = spherical_triangle(v, p)?;
+    let area = spherical_triangle_area(v, p);
+    if area <= 0.0 {
+        return None;
+    }
+    // area of the sub-triangle plus pi
+    let ap_pi = (1.0 - u.x) * PI + u.x * (area + PI);
+    let (cos_alpha, sin_alpha) = (alpha.cos(), alpha.sin());
+    let sin_phi = ap_pi.sin() * cos_alpha - ap_pi.cos() * sin_alpha;
+    let cos_phi = ap_pi.cos() * cos_alpha + ap_pi.sin() * sin_alpha;
+    let k1 = cos_phi + cos_alpha;
+    let k2 = sin_phi - sin_alpha * Vector3f::dot(&a, &b);
+    let cos_bp = ((k2 + (k2 * cos_phi - k1 * sin_phi) * cos_alpha) / ((k2 * sin_phi + k1 * cos_phi) * sin_alpha))
+        .clamp(-1.0, 1.0);
+    let sin_bp = (1.0 - cos_bp * cos_bp).max(0.0).sqrt();
+    // third corner of the sub-triangle, on the arc from a to c
+    let cp = a * cos_bp + Vector3f::normalize(&gram_schmidt(&c, &a)) * sin_bp;
+
+    let cos_theta = 1.0 - u.y * (1.0 - Vector3f::dot(&cp, &b));
+    let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+    let w = b * cos_theta + Vector3f::normalize(&gram_schmidt(&cp, &b)) * sin_theta;
+    Some((Vector3f::normalize(&w), 1.0 / area))
+}
+
+pub fn invert_spherical_triangle_sample(v: &[Point3f; 3], p: &Point3f, w: &Vector3f) -> Point2f {
+    let ([a, b, c], [alpha, beta, gamma]) = match spherical_triangle(v, p) {
+        Some(t) => t,
+        None => return Point2f::new(0.5, 0.5),
+    };
+    // the arc from b through w meets the arc from a to c at cp
+    let cp = Vector3f::cross(&Vector3f::cross(&b, w), &Vector3f::cross(&c, &a));
+    if cp.length_squared() == 0.0 {
+        return Point2f::new(0.5, 0.5);
+    }
+    let mut cp = Vector3f::normalize(&cp);
+    if Vector3f::dot(&cp, &(a + c)) < 0.0 {
+        cp = -cp;
+    }
+
+    let n_ab = Vector3f::normalize(&Vector3f::cross(&a, &b));
+    let n_cpb = Vector3f::cross(&cp, &b);
+    let n_acp = Vector3f::cross(&a, &cp);
+    let u0 = if n_cpb.length_squared() == 0.0 || n_acp.length_squared() == 0.0 {
+        0.0
+    } else {
+        let (n_cpb, n_acp) = (Vector3f::normalize(&n_cpb), Vector3f::normalize(&n_acp));
+        let ap = alpha + angle_between(&n_ab, &n_cpb) + angle_between(&n_acp, &-n_cpb) - PI;
+        ap / (alpha + beta + gamma - PI)
+    };
+    let u1 = (1.0 - Vector3f::dot(w, &b)) / (1.0 - Vector3f::dot(&cp, &b));
+    Point2f::new(u0.clamp(0.0, ONE_MINUS_EPSILON), u1.clamp(0.0, ONE_MINUS_EPSILON))
+}
+
+// the rectangle s + [0, 1] ex + [0, 1] ey as seen from p_ref, in a frame
+// with x along ex and y along ey and the rectangle at z0 < 0
+struct SphericalRectangle {
+    x: Vector3f,
+    y: Vector3f,
+    z: Vector3f,
+    x0: f32,
+    y0: f32,
+    z0: f32,
+    x1: f32,
+    y1: f32,
+}
+
+impl SphericalRectangle {
+    fn new(p_ref: &Point3f, s: &Point3f, ex: &Vector3f, ey: &Vector3f) -> Self {
+        let (x, y) = (Vector3f::normalize(ex), Vector3f::normalize(ey));
+        let mut z = Vector3f::cross(&x, &y);
+        let d = *s - *p_ref;
+        let (x0, y0) = (Vector3f::dot(&d, &x), Vector3f::dot(&d, &y));
+        let mut z0 = Vector3f::dot(&d, &z);
+        if z0 > 0.0 {
+            z0 = -z0;
+            z = -z;
+        }
+        Self {
+            x,
+            y,
+            z,
+            x0,
+            y0,
+            z0,
+            x1: x0 + ex.length(),
+            y1: y0 + ey.length(),
+        }
+    }
+
+    // interior angles of the part between x0 and x1 seen from the origin,
+    // and the z of the normals of its two edges along x. None if it's seen
+    // edge on.
+    fn angles(&self, x1: f32) -> Option<([f32; 4], f32, f32)> {
+        let v00 = Vector3f::new(self.x0, self.y0, self.z0);
+        let v01 = Vector3f::new(self.x0, self.y1, self.z0);
+        let v10 = Vector3f::new(x1, self.y0, self.z0);
+        let v11 = Vector3f::new(x1, self.y1, self.z0);
+        let normals = [
+            Vector3f::cross(&v00, &v10),
+            Vector3f::cross(&v10, &v11),
+            Vector3f::cross(&v11, &v01),
+            Vector3f::cross(&v01, &v00),
+        ];
+        if self.z0 == 0.0 || normals.iter().any(|n| n.length_squared() == 0.0) {
+            return None;
+        }
+        let n: Vec<Vector3f> = normals.iter().map(Vector3f::normalize).collect();
+        let mut g = [0.0; 4];
+        for (i, g) in g.iter_mut().enumerate() {
+            *g = angle_between(&-n[i], &n[(i + 1) % 4]);
+        }
+        Some((g, n[0].z, n[2].z))
+    }
+
+    fn solid_angle(&self, x1: f32) -> f32 {
+        self.angles(x1).map_or(0.0, |(g, _, _)| (g.iter().sum::<f32>() - 2.0 * PI).max(0.0))
+    }
+
+    // height fraction of y on the arc through the rectangle at xu
+    fn h(&self, xu: f32, y: f32) -> f32 {
+        let dd2 = xu * xu + self.z0 * self.z0;
+        y / (dd2 + y * y).sqrt()
+    }
+}
+
+pub fn spherical_rectangle_pdf(p_ref: &Point3f, s: &Point3f, ex: &Vector3f, ey: &Vector3f) -> f32 {
+    let r = SphericalRectangle::new(p_ref, s, ex, ey);
+    let solid_angle = r.solid_angle(r.x1);
+    if solid_angle > 0.0 {
+        1.0 / solid_angle
+    } else {
+        0.0
+    }
+}
+
+// Urena et al.'s uniform sampling of the solid angle of the rectangle
+// s + [0, 1] ex + [0, 1] ey seen from p_ref, ex and ey orthogonal. returns
+// the point on the rectangle and the solid angle density.
+pub fn sample_spherical_rectangle(
+    p_ref: &Point3f,
+    s: &Point3f,
+    ex: &Vector3f,
+    ey: &Vector3f,
+    u: &Point2f,
+) -> Option<(Point3f, f32)> {
+    let r = SphericalRectangle::new(p_ref, s, ex, ey);
+    let (g, b0, b1) = r.angles(r.x1)?;
+    let solid_angle = r.solid_angle(r.x1);
+    if solid_angle <= 0.0 {
+        return None;
+    }
+    // au is the solid angle left of xu minus g2 and g3
+    let au = u.x * solid_angle - g[2] - g[3];
+    let fu = (au.cos() * b0 - b1) / au.sin();
+    let cu = (1.0 / (fu * fu + b0 * b0).sqrt()).copysign(fu).clamp(-ONE_MINUS_EPSILON, ONE_MINUS_EPSILON);
+    let xu = (-(cu * r.z0) / (1.0 - cu * cu).max(0.0).sqrt()).clamp(r.x0, r.x1);
+
+    let (h0, h1) = (r.h(xu, r.y0), r.h(xu, r.y1));
+    let hv = h0 + u.y * (h1 - h0);
+    let dd = (xu * xu + r.z0 * r.z0).sqrt();
+    let yv = if hv * hv < 1.0 - 1e-6 {
+        (hv * dd) / (1.0 - hv * hv).sqrt()
+    } else {
+        r.y1
+    };
+    let p = *p_ref + r.x * xu + r.y * yv.clamp(r.y0, r.y1) + r.z * r.z0;
+    Some((p, 1.0 / solid_angle))
+}
+
+pub fn invert_spherical_rectangle_sample(
+    p_ref: &Point3f,
+    s: &Point3f,
+    ex: &Vector3f,
+    ey: &Vector3f,
+    p_rect: &Point3f,
+) -> Point2f {
+    let r = SphericalRectangle::new(p_ref, s, ex, ey);
+    let d = *p_rect - *p_ref;
+    let (xu, yv) = (Vector3f::dot(&d, &r.x), Vector3f::dot(&d, &r.y));
+    let solid_angle = r.solid_angle(r.x1);
+    if solid_angle <= 0.0 {
+        return Point2f::new(0.5, 0.5);
+    }
+    // u.x is the fraction of the solid angle left of xu
+    let u0 = if xu <= r.x0 {
+        0.0
+    } else {
+        r.solid_angle(xu) / solid_angle
+    };
+    let (h0, h1) = (r.h(xu, r.y0), r.h(xu, r.y1));
+    let u1 = (r.h(xu, yv) - h0) / (h1 - h0);
+    Point2f::new(u0.clamp(0.0, ONE_MINUS_EPSILON), u1.clamp(0.0, ONE_MINUS_EPSILON))
+}
+
+// Clarberg's equal-area mapping from [0, 1]^2 to the unit sphere, the
+// square folds into an octahedron with +z at the center
+pub fn equal_area_square_to_sphere(p: &Point2f) -> Vector3f {
+    let u = 2.0 * p.x - 1.0;
+    let v = 2.0 * p.y - 1.0;
+    let up = u.abs();
+    let vp = v.abs();
+
+    let signed_distance = 1.0 - (up + vp);
+    let d = signed_distance.abs();
+    let r = 1.0 - d;
+    let phi = if r == 0.0 { 1.0 } else { (vp - up) / r + 1.0 } * PI_OVER_4;
+    let z = (1.0 - r * r).copysign(signed_distance);
+
+    let cos_phi = phi.cos().copysign(u);
+    let sin_phi = phi.sin().copysign(v);
+    let s = r * (2.0 - r * r).max(0.0).sqrt();
+    Vector3f::new(cos_phi * s, sin_phi * s, z)
+}
+
+pub fn equal_area_sphere_to_square(d: &Vector3f) -> Point2f {
+    let x = d.x.abs();
+    let y = d.y.abs();
+    let z = d.z.abs().min(1.0);
+    let r = (1.0 - z).sqrt();
+
+    let a = x.max(y);
+    let b = if a == 0.0 { 0.0 } else { x.min(y) / a };
+    let mut phi = b.atan() * 2.0 / PI;
+    if x < y {
+        phi = 1.0 - phi;
+    }
+    let mut v = phi * r;
+    let mut u = r - v;
+    if d.z < 0.0 {
+        std::mem::swap(&mut u, &mut v);
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+    u = u.copysign(d.x);
+    v = v.copysign(d.y);
+    Point2f::new(0.5 * (u + 1.0), 0.5 * (v + 1.0))
+}
+
+// random permutation of the samples, each made of n_dimensions consecutive
+// values that move together
+pub fn shuffle<T>(samples: &mut [T], n_dimensions: usize, rng: &mut Rng) {
+    let count = samples.len() / n_dimensions;
+    for i in 0..count {
+        let other = i + rng.uniform_u32_bounded((count - i) as u32) as usize;
+        for j in 0..n_dimensions {
+            samples.swap(n_dimensions * i + j, n_dimensions * other + j);
+        }
+    }
+}
+
+// one sample in each of samples.len() equal intervals of [0, 1)
+pub fn stratified_sample_1d(samples: &mut [f32], rng: &mut Rng, jitter: bool) {
+    let inv_n_samples = 1.0 / samples.len() as f32;
+    for (i, s) in samples.iter_mut().enumerate() {
+        let delta = if jitter { rng.uniform_f32() } else { 0.5 };
+        *s = ((i as f32 + delta) * inv_n_samples).min(ONE_MINUS_EPSILON);
+    }
+}
+
+// one sample in each cell of an nx by ny grid over [0, 1)^2, in scanline order
+pub fn stratified_sample_2d(samples: &mut [Point2f], nx: usize, ny: usize, rng: &mut Rng, jitter: bool) {
+    assert_eq!(samples.len(), nx * ny);
+    let (dx, dy) = (1.0 / nx as f32, 1.0 / ny as f32);
+    for (i, s) in samples.iter_mut().enumerate() {
+        let (x, y) = (i % nx, i / nx);
+        let jx = if jitter { rng.uniform_f32() } else { 0.5 };
+        let jy = if jitter { rng.uniform_f32() } else { 0.5 };
+        *s = Point2f::new(
+            ((x as f32 + jx) * dx).min(ONE_MINUS_EPSILON),
+            ((y as f32 + jy) * dy).min(ONE_MINUS_EPSILON),
+        );
+    }
+}
+
+// n_samples points of n_dimensions values each, every dimension stratified
+// into n_samples intervals on its own
+pub fn latin_hypercube(samples: &mut [f32], n_dimensions: usize, rng: &mut Rng) {
+    let n_samples = samples.len() / n_dimensions;
+    let inv_n_samples = 1.0 / n_samples as f32;
+    for i in 0..n_samples {
+        for j in 0..n_dimensions {
+            let sj = (i as f32 + rng.uniform_f32()) * inv_n_samples;
+            samples[n_dimensions * i + j] = sj.min(ONE_MINUS_EPSILON);
+        }
+    }
+    // permute each dimension independently
+    for i in 0..n_dimensions {
+        for j in 0..n_samples {
+            let other = j + rng.uniform_u32_bounded((n_samples - j) as u32) as usize;
+            samples.swap(n_dimensions * j + i, n_dimensions * other + i);
+        }
+    }
+}
+
+// piecewise constant function over [min, max] with func.len() pieces,
+// sampled by inverting its cdf
+#[derive(Clone, Debug)]
+pub struct Distribution1D {
+    func: Vec<f32>,
+    cdf: Vec<f32>,
+    func_int: f32,
+    min: f32,
+    max: f32,
+}
+
+impl Distribution1D {
+    pub fn new(f: &[f32], min: f32, max: f32) -> Self {
+        assert!(!f.is_empty() && max > min);
+        let func: Vec<f32> = f.iter().map(|v| v.abs()).collect();
+        let n = func.len();
+        let mut cdf = vec![0.0; n + 1];
+        for i in 1..=n {
+            cdf[i] = cdf[i - 1] + func[i - 1] * (max - min) / n as f32;
+        }
+        let func_int = cdf[n];
+        if func_int == 0.0 {
+            // sample uniformly if the function is zero
+            for (i, c) in cdf.iter_mut().enumerate() {
+                *c = i as f32 / n as f32;
+            }
+        } else {
+            for c in cdf.iter_mut() {
+                *c /= func_int;
+            }
+        }
+        Self {
+            func,
+            cdf,
+            func_int,
+            min,
+            max,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.func.len()
+    }
+
+    pub fn integral(&self) -> f32 {
+        self.func_int
+    }
+
+    pub fn func(&self) -> &[f32] {
+        &self.func
+    }
+
+    // point in [min, max), its density and the index of its piece
+    pub fn sample_continuous(&self, u: f32) -> (f32, f32, usize) {
+        let offset = self.find_interval(u);
+        let mut du = u - self.cdf[offset];
+        if self.cdf[offset + 1] - self.cdf[offset] > 0.0 {
+            du /= self.cdf[offset + 1] - self.cdf[offset];
+        }
+        let pdf = if self.func_int > 0.0 {
+            self.func[offset] / self.func_int
+        } else {
+            0.0
+        };
+        let t = (offset as f32 + du) / self.count() as f32;
+        let x = (self.min + t * (self.max - self.min)).min(self.max);
+        (x, pdf, offset)
+    }
+
+    // index of a piece with probability proportional to its value, its
+    // probability and u remapped to [0, 1) inside the piece
+    pub fn sample_discrete(&self, u: f32) -> (usize, f32, f32) {
+        let offset = self.find_interval(u);
+        let width = self.cdf[offset + 1] - self.cdf[offset];
+        let u_remapped = if width > 0.0 {
+            ((u - self.cdf[offset]) / width).min(ONE_MINUS_EPSILON)
+        } else {
+            0.0
+        };
+        (offset, self.discrete_pdf(offset), u_remapped)
+    }
+
+    pub fn discrete_pdf(&self, index: usize) -> f32 {
+        self.cdf[index + 1] - self.cdf[index]
+    }
+
+    // density of sample_continuous at x
+    pub fn pdf(&self, x: f32) -> f32 {
+        if x < self.min || x > self.max || self.func_int == 0.0 {
+            return 0.0;
+        }
+        self.func[self.offset(x).0] / self.func_int
+    }
+
+    // the u that sample_continuous maps to x
+    pub fn invert(&self, x: f32) -> Option<f32> {
+        if x < self.min || x > self.max {
+            return None;
+        }
+        let (offset, delta) = self.offset(x);
+        Some((1.0 - delta) * self.cdf[offset] + delta * self.cdf[offset + 1])
+    }
+
+    // last piece whose cdf starts at or below u
+    fn find_interval(&self, u: f32) -> usize {
+        self.cdf.partition_point(|&c| c <= u).saturating_sub(1).min(self.count() - 1)
+    }
+
+    // piece containing x and the position of x inside it
+    fn offset(&self, x: f32) -> (usize, f32) {
+        let c = (x - self.min) / (self.max - self.min) * self.count() as f32;
+        let offset = (c as usize).min(self.count() - 1);
+        (offset, c - offset as f32)
+    }
+}
+
+// piecewise constant function over the domain from an nu by nv grid of
+// values in scanline order, sampled by the marginal density of v and then
+// the conditional density of u
+#[derive(Clone, Debug)]
+pub struct Distribution2D {
+    domain: Bounds2f,
+    p_conditional_v: Vec<Distribution1D>,
+    p_marginal: Distribution1D,
+}
+
+impl Distribution2D {
+    pub fn new(func: &[f32], nu: usize, nv: usize, domain: &Bounds2f) -> Self {
+        assert_eq!(func.len(), nu * nv);
+        let p_conditional_v: Vec<Distribution1D> = func
+            .chunks(nu)
+            .map(|row| Distribution1D::new(row, domain.p_min.x, domain.p_max.x))
+            .collect();
+        let marginal_func: Vec<f32> = p_conditional_v.iter().map(|d| d.integral()).collect();
+        let p_marginal = Distribution1D::new(&marginal_func, domain.p_min.y, domain.p_max.y);
+        Self {
+            domain: *domain,
+            p_conditional_v,
+            p_marginal,
+        }
+    }
+
+    pub fn domain(&self) -> &Bounds2f {
+        &self.domain
+    }
+
+    pub fn integral(&self) -> f32 {
+        self.p_marginal.integral()
+    }
+
+    // point in the domain and its density per unit area
+    pub fn sample_continuous(&self, u: &Point2f) -> (Point2f, f32) {
+        let (d1, pdf1, v) = self.p_marginal.sample_continuous(u.y);
+        let (d0, pdf0, _) = self.p_conditional_v[v].sample_continuous(u.x);
+        (Point2f::new(d0, d1), pdf0 * pdf1)
+    }
+
+    pub fn pdf(&self, p: &Point2f) -> f32 {
+        if !Bounds2f::inside(p, &self.domain) || self.integral() == 0.0 {
+            return 0.0;
+        }
+        let (v, _) = self.p_marginal.offset(p.y);
+        let (u, _) = self.p_conditional_v[v].offset(p.x);
+        self.p_conditional_v[v].func()[u] / self.integral()
+    }
+
+    pub fn invert(&self, p: &Point2f) -> Option<Point2f> {
+        let v = self.p_marginal.invert(p.y)?;
+        let (iv, _) = self.p_marginal.offset(p.y);
+        let u = self.p_conditional_v[iv].invert(p.x)?;
+        Some(Point2f::new(u, v))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct AliasBin {
+    // probability of keeping the bin's own index
+    q: f32,
+    // probability of the bin's index
+    p: f32,
+    alias: usize,
+}
+
+// Walker's alias method with Vose's construction, constant time sampling
+// of an index with probability proportional to its weight
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    bins: Vec<AliasBin>,
+}
+
+impl AliasTable {
+    pub fn new(weights: &[f32]) -> Self {
+        assert!(!weights.is_empty());
+        let n = weights.len();
+        let sum: f64 = weights.iter().map(|&w| w as f64).sum();
+        assert!(sum > 0.0 && weights.iter().all(|&w| w >= 0.0));
+        let mut bins: Vec<AliasBin> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| AliasBin {
+                q: 0.0,
+                p: (w as f64 / sum) as f32,
+                alias: i,
+            })
+            .collect();
+
+        // probabilities scaled by n, bins below 1 get filled up by bins above
+        let mut p_hat: Vec<f64> = weights.iter().map(|&w| w as f64 / sum * n as f64).collect();
+        let mut under: Vec<usize> = (0..n).filter(|&i| p_hat[i] < 1.0).collect();
+        let mut over: Vec<usize> = (0..n).filter(|&i| p_hat[i] >= 1.0).collect();
+        while let (Some(&u), Some(&o)) = (under.last(), over.last()) {
+            under.pop();
+            over.pop();
+            bins[u].q = p_hat[u] as f32;
+            bins[u].alias = o;
+            p_hat[o] -= 1.0 - p_hat[u];
+            if p_hat[o] < 1.0 {
+                under.push(o);
+            } else {
+                over.push(o);
+            }
+        }
+        // what's left is 1 up to rounding
+        for i in under.into_iter().chain(over) {
+            bins[i].q = 1.0;
+            bins[i].alias = i;
+        }
+        Self { bins }
+    }
+
+    pub fn size(&self) -> usize {
+        self.bins.len()
+    }
+
+    pub fn pmf(&self, index: usize) -> f32 {
+        self.bins[index].p
+    }
+
+    // index, its probability and u remapped to [0, 1)
+    pub fn sample(&self, u: f32) -> (usize, f32, f32) {
+        let n = self.bins.len();
+        let offset = ((u * n as f32) as usize).min(n - 1);
+        let up = (u * n as f32 - offset as f32).min(ONE_MINUS_EPSILON);
+        let bin = &self.bins[offset];
+        if up < bin.q {
+            (offset, bin.p, (up / bin.q).min(ONE_MINUS_EPSILON))
+        } else {
+            let alias = bin.alias;
+            (alias, self.bins[alias].p, ((up - bin.q) / (1.0 - bin.q)).min(ONE_MINUS_EPSILON))
+        }
+    }
+}
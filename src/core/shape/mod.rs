@@ -0,0 +1,3 @@
+pub use shape::*;
+
+mod shape;
@@ -0,0 +1,11 @@
+use crate::core::geometry::{Bounds3f, Ray};
+
+pub trait Shape: Send + Sync {
+    fn world_bound(&self) -> Bounds3f;
+    // parametric distance of the closest hit in (0, ray.t_max)
+    fn intersect(&self, ray: &Ray) -> Option<f32>;
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect(ray).is_some()
+    }
+    fn area(&self) -> f32;
+}
@@ -0,0 +1,659 @@
+use crate::core::spectrum::{
+    SampledSpectrum, SampledWavelengths, SpectrumSamples, CIE_DAYLIGHT_S0, CIE_DAYLIGHT_S1, CIE_DAYLIGHT_S2, CIE_ILLUMINANT_D65,
+    CIE_ILLUMINANT_LAMBDA_START, CIE_ILLUMINANT_LAMBDA_STEP, CIE_LAMBDA_END, CIE_LAMBDA_START, CIE_X, CIE_Y, CIE_Y_INTEGRAL, CIE_Z,
+    N_CIE_ILLUMINANT_SAMPLES, N_CIE_SAMPLES,
+};
+use std::ops::Mul;
+use std::sync::{Arc, OnceLock};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Matrix3x3 {
+    pub m: [[f32; 3]; 3],
+}
+
+impl Default for Matrix3x3 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Matrix3x3 {
+    pub const fn new(m: [[f32; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    pub const fn identity() -> Self {
+        Self::diagonal(&[1.0, 1.0, 1.0])
+    }
+
+    pub const fn diagonal(d: &[f32; 3]) -> Self {
+        Self::new([[d[0], 0.0, 0.0], [0.0, d[1], 0.0], [0.0, 0.0, d[2]]])
+    }
+
+    pub fn mul_vec(&self, v: &[f32; 3]) -> [f32; 3] {
+        let row = |r: &[f32; 3]| r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+        [row(&self.m[0]), row(&self.m[1]), row(&self.m[2])]
+    }
+
+    // adjugate over the determinant, None for singular matrices
+    pub fn inverse(m: &Self) -> Option<Self> {
+        let a = |i: usize, j: usize| m.m[i][j] as f64;
+        let cofactor = |i: usize, j: usize| {
+            let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+            let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+            a(r0, c0) * a(r1, c1) - a(r0, c1) * a(r1, c0)
+        };
+        let det = a(0, 0) * cofactor(0, 0) + a(0, 1) * cofactor(0, 1) + a(0, 2) * cofactor(0, 2);
+        if det == 0.0 {
+            return None;
+        }
+        let mut r = [[0.0; 3]; 3];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (cofactor(j, i) / det) as f32;
+            }
+        }
+        Some(Self::new(r))
+    }
+}
+
+impl Mul for Matrix3x3 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut r = [[0.0; 3]; 3];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (0..3).map(|k| self.m[i][k] * other.m[k][j]).sum();
+            }
+        }
+        Self::new(r)
+    }
+}
+
+// xyz of a chromaticity with luminance y
+pub fn xy_to_xyz(xy: &[f32; 2], y: f32) -> [f32; 3] {
+    if xy[1] == 0.0 {
+        return [0.0; 3];
+    }
+    [xy[0] * y / xy[1], y, (1.0 - xy[0] - xy[1]) * y / xy[1]]
+}
+
+pub fn xyz_to_xy(xyz: &[f32; 3]) -> [f32; 2] {
+    let sum = xyz[0] + xyz[1] + xyz[2];
+    if sum == 0.0 {
+        return [0.0; 2];
+    }
+    [xyz[0] / sum, xyz[1] / sum]
+}
+
+// xyz to the cone responses of the Bradford transform
+const LMS_FROM_XYZ: Matrix3x3 = Matrix3x3::new([
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+]);
+
+// von Kries scaling of the Bradford cone responses, maps xyz seen under the
+// source white to the xyz of the same surface under the target white
+pub fn bradford_adaptation(src_white: &[f32; 2], dst_white: &[f32; 2]) -> Matrix3x3 {
+    let xyz_from_lms = Matrix3x3::inverse(&LMS_FROM_XYZ).unwrap();
+    let src = LMS_FROM_XYZ.mul_vec(&xy_to_xyz(src_white, 1.0));
+    let dst = LMS_FROM_XYZ.mul_vec(&xy_to_xyz(dst_white, 1.0));
+    let scale = Matrix3x3::diagonal(&[dst[0] / src[0], dst[1] / src[1], dst[2] / src[2]]);
+    xyz_from_lms * scale * LMS_FROM_XYZ
+}
+
+fn sigmoid(x: f32) -> f32 {
+    if x.is_infinite() {
+        return if x > 0.0 { 1.0 } else { 0.0 };
+    }
+    0.5 + x / (2.0 * (1.0 + x * x).sqrt())
+}
+
+// smooth spectrum in [0, 1], a sigmoid of a quadratic in lambda (Jakob and
+// Hanika 2019)
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct RGBSigmoidPolynomial {
+    pub c0: f32,
+    pub c1: f32,
+    pub c2: f32,
+}
+
+impl RGBSigmoidPolynomial {
+    pub fn new(c0: f32, c1: f32, c2: f32) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    // from coefficients of the quadratic in wavelength normalized over the
+    // CIE range
+    fn from_normalized(c: &[f64; 3]) -> Self {
+        let l0 = CIE_LAMBDA_START as f64;
+        let s = 1.0 / (CIE_LAMBDA_END - CIE_LAMBDA_START) as f64;
+        Self::new(
+            (c[0] * s * s) as f32,
+            (c[1] * s - 2.0 * c[0] * l0 * s * s) as f32,
+            (c[2] - c[1] * l0 * s + c[0] * l0 * l0 * s * s) as f32,
+        )
+    }
+
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        sigmoid((self.c0 * lambda + self.c1) * lambda + self.c2)
+    }
+
+    // over the CIE range, at the ends or the extremum of the quadratic
+    pub fn max_value(&self) -> f32 {
+        let mut result = self.evaluate(CIE_LAMBDA_START).max(self.evaluate(CIE_LAMBDA_END));
+        let lambda = -self.c1 / (2.0 * self.c0);
+        if (CIE_LAMBDA_START..=CIE_LAMBDA_END).contains(&lambda) {
+            result = result.max(self.evaluate(lambda));
+        }
+        result
+    }
+}
+
+// the fits integrate over every FIT_STEP nm of the CIE tables
+const FIT_STEP: usize = 5;
+
+// wavelength normalized to [0, 1] and the trapezoid weights of the matching
+// functions times the illuminant, normalized so that the illuminant has y 1
+struct FitSample {
+    lambda: f64,
+    xyz: [f64; 3],
+}
+
+fn fit_samples(illuminant: impl Fn(f32) -> f32) -> Vec<FitSample> {
+    let n = (N_CIE_SAMPLES - 1) / FIT_STEP + 1;
+    let mut samples: Vec<FitSample> = (0..n)
+        .map(|i| {
+            let k = i * FIT_STEP;
+            let w = if i == 0 || i == n - 1 { 0.5 } else { 1.0 } * illuminant(CIE_LAMBDA_START + k as f32) as f64;
+            FitSample {
+                lambda: i as f64 / (n - 1) as f64,
+                xyz: [CIE_X[k] as f64 * w, CIE_Y[k] as f64 * w, CIE_Z[k] as f64 * w],
+            }
+        })
+        .collect();
+    let y: f64 = samples.iter().map(|s| s.xyz[1]).sum();
+    for s in samples.iter_mut() {
+        for v in s.xyz.iter_mut() {
+            *v /= y;
+        }
+    }
+    samples
+}
+
+// CIELAB relative to white and its derivatives with respect to xyz
+fn lab(xyz: &[f64; 3], white: &[f64; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let delta = 6.0 / 29.0;
+    let f = |t: f64| {
+        if t > delta * delta * delta {
+            let c = t.cbrt();
+            (c, 1.0 / (3.0 * c * c))
+        } else {
+            (t / (3.0 * delta * delta) + 4.0 / 29.0, 1.0 / (3.0 * delta * delta))
+        }
+    };
+    let (fx, dfx) = f(xyz[0] / white[0]);
+    let (fy, dfy) = f(xyz[1] / white[1]);
+    let (fz, dfz) = f(xyz[2] / white[2]);
+    let (dfx, dfy, dfz) = (dfx / white[0], dfy / white[1], dfz / white[2]);
+    (
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)],
+        [
+            [0.0, 116.0 * dfy, 0.0],
+            [500.0 * dfx, -500.0 * dfy, 0.0],
+            [0.0, 200.0 * dfy, -200.0 * dfz],
+        ],
+    )
+}
+
+// Cramer's rule, None for (nearly) singular systems
+fn solve3(a: &[[f64; 3]; 3], b: &[f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1]) - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(a);
+    if d.abs() < 1e-15 {
+        return None;
+    }
+    let mut x = [0.0; 3];
+    for (k, v) in x.iter_mut().enumerate() {
+        let mut m = *a;
+        for i in 0..3 {
+            m[i][k] = b[i];
+        }
+        *v = det(&m) / d;
+    }
+    Some(x)
+}
+
+// Gauss-Newton fit of the normalized coefficients of a sigmoid polynomial so
+// that its xyz under the fit samples is target, with the error measured in
+// CIELAB. starts from the coefficients passed in.
+fn fit_sigmoid_polynomial(samples: &[FitSample], white: &[f64; 3], target: &[f64; 3], c: &mut [f64; 3]) {
+    let (target_lab, _) = lab(target, white);
+    for _ in 0..15 {
+        let mut xyz = [0.0; 3];
+        let mut d_xyz = [[0.0; 3]; 3];
+        for s in samples {
+            let l = s.lambda;
+            let x = (c[0] * l + c[1]) * l + c[2];
+            let d = 1.0 / (1.0 + x * x);
+            let y = 0.5 + 0.5 * x * d.sqrt();
+            let dy = 0.5 * d * d.sqrt();
+            let dx = [l * l, l, 1.0];
+            for j in 0..3 {
+                xyz[j] += s.xyz[j] * y;
+                for k in 0..3 {
+                    d_xyz[j][k] += s.xyz[j] * dy * dx[k];
+                }
+            }
+        }
+        let (current, d_lab) = lab(&xyz, white);
+        let r = [target_lab[0] - current[0], target_lab[1] - current[1], target_lab[2] - current[2]];
+        if (r[0] * r[0] + r[1] * r[1] + r[2] * r[2]).sqrt() < 1e-6 {
+            break;
+        }
+        let mut jacobian = [[0.0; 3]; 3];
+        for (i, row) in jacobian.iter_mut().enumerate() {
+            for (k, v) in row.iter_mut().enumerate() {
+                *v = (0..3).map(|j| d_lab[i][j] * d_xyz[j][k]).sum();
+            }
+        }
+        match solve3(&jacobian, &r) {
+            Some(step) => {
+                for k in 0..3 {
+                    c[k] += step[k];
+                }
+            }
+            None => break,
+        }
+    }
+    // keeps the coefficients in a range where the evaluation in f32 is stable
+    let max = c[0].max(c[1]).max(c[2]);
+    if max > 200.0 {
+        for v in c.iter_mut() {
+            *v *= 200.0 / max;
+        }
+    }
+}
+
+fn smooth_step(x: f32) -> f32 {
+    x * x * (3.0 - 2.0 * x)
+}
+
+// resolution of the rgb to spectrum tables along each axis
+pub const RGB_TO_SPECTRUM_RES: usize = 32;
+
+// sigmoid polynomials for a grid of rgb values in [0, 1]^3, indexed by the
+// largest component, its value z, and the other two relative to it. the z
+// nodes get denser near 0 and 1 where the coefficients change quickly.
+#[derive(Clone, Debug)]
+pub struct RGBToSpectrumTable {
+    z_nodes: Vec<f32>,
+    coeffs: Vec<[f32; 3]>,
+}
+
+impl RGBToSpectrumTable {
+    // fits each entry starting from the solution of its neighbour along z,
+    // outwards from a fifth of the range where the fit is easy
+    fn generate(samples: &[FitSample], white: &[f64; 3], xyz_from_rgb: &Matrix3x3) -> Self {
+        let res = RGB_TO_SPECTRUM_RES;
+        let z_nodes: Vec<f32> = (0..res).map(|k| smooth_step(smooth_step(k as f32 / (res - 1) as f32))).collect();
+        let mut coeffs = vec![[0.0; 3]; 3 * res * res * res];
+        let start = res / 5;
+        for l in 0..3 {
+            for j in 0..res {
+                let y = j as f32 / (res - 1) as f32;
+                for i in 0..res {
+                    let x = i as f32 / (res - 1) as f32;
+                    let mut fit = |k: usize, c: &mut [f64; 3]| {
+                        let b = z_nodes[k];
+                        let mut rgb = [0.0; 3];
+                        rgb[l] = b;
+                        rgb[(l + 1) % 3] = x * b;
+                        rgb[(l + 2) % 3] = y * b;
+                        let xyz = xyz_from_rgb.mul_vec(&rgb);
+                        fit_sigmoid_polynomial(samples, white, &[xyz[0] as f64, xyz[1] as f64, xyz[2] as f64], c);
+                        let p = RGBSigmoidPolynomial::from_normalized(c);
+                        coeffs[((l * res + k) * res + j) * res + i] = [p.c0, p.c1, p.c2];
+                    };
+                    let mut c = [0.0; 3];
+                    for k in start..res {
+                        fit(k, &mut c);
+                    }
+                    let mut c = [0.0; 3];
+                    for k in (0..=start).rev() {
+                        fit(k, &mut c);
+                    }
+                }
+            }
+        }
+        Self { z_nodes, coeffs }
+    }
+
+    // trilinear interpolation of the coefficients, rgb in [0, 1]^3
+    pub fn lookup(&self, rgb: &[f32; 3]) -> RGBSigmoidPolynomial {
+        if rgb[0] == rgb[1] && rgb[1] == rgb[2] {
+            return RGBSigmoidPolynomial::new(0.0, 0.0, (rgb[0] - 0.5) / (rgb[0] * (1.0 - rgb[0])).sqrt());
+        }
+        let res = RGB_TO_SPECTRUM_RES;
+        let maxc = if rgb[0] > rgb[1] {
+            if rgb[0] > rgb[2] {
+                0
+            } else {
+                2
+            }
+        } else if rgb[1] > rgb[2] {
+            1
+        } else {
+            2
+        };
+        let z = rgb[maxc];
+        let x = rgb[(maxc + 1) % 3] * (res - 1) as f32 / z;
+        let y = rgb[(maxc + 2) % 3] * (res - 1) as f32 / z;
+        let xi = (x as usize).min(res - 2);
+        let yi = (y as usize).min(res - 2);
+        let zi = self.z_nodes.partition_point(|&n| n <= z).saturating_sub(1).min(res - 2);
+        let dx = x - xi as f32;
+        let dy = y - yi as f32;
+        let dz = (z - self.z_nodes[zi]) / (self.z_nodes[zi + 1] - self.z_nodes[zi]);
+        let entry = |di: usize, dj: usize, dk: usize| &self.coeffs[((maxc * res + zi + dk) * res + yi + dj) * res + xi + di];
+        let mut c = [0.0; 3];
+        for (n, v) in c.iter_mut().enumerate() {
+            let lerp = |t: f32, a: f32, b: f32| (1.0 - t) * a + t * b;
+            let along_x = |dj: usize, dk: usize| lerp(dx, entry(0, dj, dk)[n], entry(1, dj, dk)[n]);
+            let along_y = |dk: usize| lerp(dy, along_x(0, dk), along_x(1, dk));
+            *v = lerp(dz, along_y(0), along_y(1));
+        }
+        RGBSigmoidPolynomial::new(c[0], c[1], c[2])
+    }
+}
+
+// spectral power of a standard illuminant at 1nm steps over the CIE range,
+// scaled to luminance 1
+#[derive(Clone, PartialEq, Debug)]
+pub struct Illuminant {
+    values: Arc<[f32]>,
+}
+
+impl Illuminant {
+    // from CIE_ILLUMINANT_LAMBDA_STEP samples, linearly interpolated
+    fn from_tabulated(table: &[f32; N_CIE_ILLUMINANT_SAMPLES]) -> Self {
+        let values: Vec<f32> = (0..N_CIE_SAMPLES)
+            .map(|i| {
+                let x = (CIE_LAMBDA_START + i as f32 - CIE_ILLUMINANT_LAMBDA_START) / CIE_ILLUMINANT_LAMBDA_STEP;
+                let j = (x as usize).min(N_CIE_ILLUMINANT_SAMPLES - 2);
+                let t = x - j as f32;
+                (1.0 - t) * table[j] + t * table[j + 1]
+            })
+            .collect();
+        let y: f32 = values.iter().zip(CIE_Y.iter()).map(|(v, y)| v * y).sum();
+        Self {
+            values: values.iter().map(|v| v * CIE_Y_INTEGRAL / y).collect(),
+        }
+    }
+
+    pub fn d65() -> Self {
+        Self::from_tabulated(&CIE_ILLUMINANT_D65)
+    }
+
+    // CIE daylight of the given chromaticity, a combination of the daylight
+    // basis functions
+    pub fn daylight(white: &[f32; 2]) -> Self {
+        let (x, y) = (white[0], white[1]);
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+        let m2 = (0.03 - 31.4424 * x + 30.0717 * y) / m;
+        let mut table = [0.0; N_CIE_ILLUMINANT_SAMPLES];
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = CIE_DAYLIGHT_S0[i] + m1 * CIE_DAYLIGHT_S1[i] + m2 * CIE_DAYLIGHT_S2[i];
+        }
+        Self::from_tabulated(&table)
+    }
+
+    // zero outside the CIE range
+    pub fn value(&self, lambda: f32) -> f32 {
+        let x = lambda - CIE_LAMBDA_START;
+        if x < 0.0 || x > (N_CIE_SAMPLES - 1) as f32 {
+            return 0.0;
+        }
+        let i = (x as usize).min(N_CIE_SAMPLES - 2);
+        let t = x - i as f32;
+        (1.0 - t) * self.values[i] + t * self.values[i + 1]
+    }
+}
+
+// linear rgb defined by the chromaticities of its primaries and white point
+#[derive(Debug)]
+pub struct RGBColorSpace {
+    name: &'static str,
+    primaries: [[f32; 2]; 3],
+    white: [f32; 2],
+    illuminant: Illuminant,
+    xyz_from_rgb: Matrix3x3,
+    rgb_from_xyz: Matrix3x3,
+    table: OnceLock<RGBToSpectrumTable>,
+}
+
+impl RGBColorSpace {
+    // the illuminant should have the chromaticity of the white point
+    pub fn new(name: &'static str, r: &[f32; 2], g: &[f32; 2], b: &[f32; 2], white: &[f32; 2], illuminant: Illuminant) -> Self {
+        let (r_xyz, g_xyz, b_xyz) = (xy_to_xyz(r, 1.0), xy_to_xyz(g, 1.0), xy_to_xyz(b, 1.0));
+        let rgb = Matrix3x3::new([
+            [r_xyz[0], g_xyz[0], b_xyz[0]],
+            [r_xyz[1], g_xyz[1], b_xyz[1]],
+            [r_xyz[2], g_xyz[2], b_xyz[2]],
+        ]);
+        // scales the primaries so that rgb (1, 1, 1) is the white point
+        let c = Matrix3x3::inverse(&rgb).unwrap().mul_vec(&xy_to_xyz(white, 1.0));
+        let xyz_from_rgb = rgb * Matrix3x3::diagonal(&c);
+        Self {
+            name,
+            primaries: [*r, *g, *b],
+            white: *white,
+            illuminant,
+            xyz_from_rgb,
+            rgb_from_xyz: Matrix3x3::inverse(&xyz_from_rgb).unwrap(),
+            table: OnceLock::new(),
+        }
+    }
+
+    pub fn srgb() -> &'static Self {
+        static SRGB: OnceLock<RGBColorSpace> = OnceLock::new();
+        SRGB.get_or_init(|| Self::new("srgb", &[0.64, 0.33], &[0.3, 0.6], &[0.15, 0.06], &[0.3127, 0.329], Illuminant::d65()))
+    }
+
+    // ACES AP1 primaries with the ACES white, which is daylight close to D60
+    pub fn aces_cg() -> &'static Self {
+        static ACES_CG: OnceLock<RGBColorSpace> = OnceLock::new();
+        ACES_CG.get_or_init(|| {
+            let white = [0.32168, 0.33767];
+            Self::new("acescg", &[0.713, 0.293], &[0.165, 0.83], &[0.128, 0.044], &white, Illuminant::daylight(&white))
+        })
+    }
+
+    pub fn rec2020() -> &'static Self {
+        static REC2020: OnceLock<RGBColorSpace> = OnceLock::new();
+        REC2020.get_or_init(|| Self::new("rec2020", &[0.708, 0.292], &[0.17, 0.797], &[0.131, 0.046], &[0.3127, 0.329], Illuminant::d65()))
+    }
+
+    pub fn by_name(name: &str) -> Option<&'static Self> {
+        match name {
+            "srgb" => Some(Self::srgb()),
+            "acescg" => Some(Self::aces_cg()),
+            "rec2020" => Some(Self::rec2020()),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    pub fn primaries(&self) -> &[[f32; 2]; 3] {
+        &self.primaries
+    }
+
+    pub fn white_point(&self) -> [f32; 2] {
+        self.white
+    }
+
+    pub fn illuminant(&self) -> &Illuminant {
+        &self.illuminant
+    }
+
+    pub fn xyz_from_rgb(&self) -> &Matrix3x3 {
+        &self.xyz_from_rgb
+    }
+
+    pub fn rgb_from_xyz(&self) -> &Matrix3x3 {
+        &self.rgb_from_xyz
+    }
+
+    pub fn xyz(&self, rgb: &[f32; 3]) -> [f32; 3] {
+        self.xyz_from_rgb.mul_vec(rgb)
+    }
+
+    pub fn rgb(&self, xyz: &[f32; 3]) -> [f32; 3] {
+        self.rgb_from_xyz.mul_vec(xyz)
+    }
+
+    pub fn luminance(&self, rgb: &[f32; 3]) -> f32 {
+        self.xyz(rgb)[1]
+    }
+
+    // rgb in from to rgb in to, adapting between the white points
+    pub fn conversion(from: &Self, to: &Self) -> Matrix3x3 {
+        let adaptation = if from.white == to.white {
+            Matrix3x3::identity()
+        } else {
+            bradford_adaptation(&from.white, &to.white)
+        };
+        to.rgb_from_xyz * adaptation * from.xyz_from_rgb
+    }
+
+    // fitted on first use, which takes a moment
+    pub fn rgb_to_spectrum_table(&self) -> &RGBToSpectrumTable {
+        self.table.get_or_init(|| {
+            let white = xy_to_xyz(&self.white, 1.0);
+            let samples = fit_samples(|lambda| self.illuminant.value(lambda));
+            RGBToSpectrumTable::generate(&samples, &[white[0] as f64, white[1] as f64, white[2] as f64], &self.xyz_from_rgb)
+        })
+    }
+
+    // reflectance spectrum of rgb in [0, 1]^3 under the illuminant
+    pub fn sigmoid_polynomial(&self, rgb: &[f32; 3]) -> RGBSigmoidPolynomial {
+        self.rgb_to_spectrum_table().lookup(rgb)
+    }
+}
+
+macro_rules! impl_rgb_spectrum {
+    ($Spectrum: ident) => {
+        impl $Spectrum {
+            pub fn sample(&self, lambda: &SampledWavelengths) -> SpectrumSamples {
+                let mut s = SpectrumSamples::default();
+                for (v, &l) in s.c.iter_mut().zip(lambda.wavelengths().iter()) {
+                    *v = self.value(l);
+                }
+                s
+            }
+
+            pub fn sampled(&self) -> SampledSpectrum {
+                let lambda: Vec<f32> = (0..N_CIE_SAMPLES).map(|i| CIE_LAMBDA_START + i as f32).collect();
+                let values: Vec<f32> = lambda.iter().map(|&l| self.value(l)).collect();
+                SampledSpectrum::from_sampled(&lambda, &values)
+            }
+        }
+    };
+}
+
+// reflectance with rgb in [0, 1]^3
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RGBAlbedoSpectrum {
+    rsp: RGBSigmoidPolynomial,
+}
+
+impl RGBAlbedoSpectrum {
+    pub fn new(cs: &RGBColorSpace, rgb: &[f32; 3]) -> Self {
+        debug_assert!(rgb.iter().all(|v| (0.0..=1.0).contains(v)));
+        Self {
+            rsp: cs.sigmoid_polynomial(rgb),
+        }
+    }
+
+    pub fn value(&self, lambda: f32) -> f32 {
+        self.rsp.evaluate(lambda)
+    }
+
+    pub fn max_value(&self) -> f32 {
+        self.rsp.max_value()
+    }
+}
+
+// any non-negative rgb, a reflectance of at most 1/2 scaled up
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RGBUnboundedSpectrum {
+    scale: f32,
+    rsp: RGBSigmoidPolynomial,
+}
+
+impl RGBUnboundedSpectrum {
+    pub fn new(cs: &RGBColorSpace, rgb: &[f32; 3]) -> Self {
+        let (scale, rsp) = unbounded_polynomial(cs, rgb);
+        Self { scale, rsp }
+    }
+
+    pub fn value(&self, lambda: f32) -> f32 {
+        self.scale * self.rsp.evaluate(lambda)
+    }
+
+    pub fn max_value(&self) -> f32 {
+        self.scale * self.rsp.max_value()
+    }
+}
+
+fn unbounded_polynomial(cs: &RGBColorSpace, rgb: &[f32; 3]) -> (f32, RGBSigmoidPolynomial) {
+    debug_assert!(rgb.iter().all(|&v| v >= 0.0));
+    let scale = 2.0 * rgb[0].max(rgb[1]).max(rgb[2]);
+    let rgb = if scale > 0.0 {
+        [rgb[0] / scale, rgb[1] / scale, rgb[2] / scale]
+    } else {
+        [0.0; 3]
+    };
+    (scale, cs.sigmoid_polynomial(&rgb))
+}
+
+// emission with the given rgb, an unbounded spectrum times the illuminant of
+// the color space so that rgb (1, 1, 1) is its white
+#[derive(Clone, PartialEq, Debug)]
+pub struct RGBIlluminantSpectrum {
+    scale: f32,
+    rsp: RGBSigmoidPolynomial,
+    illuminant: Illuminant,
+}
+
+impl RGBIlluminantSpectrum {
+    pub fn new(cs: &RGBColorSpace, rgb: &[f32; 3]) -> Self {
+        let (scale, rsp) = unbounded_polynomial(cs, rgb);
+        Self {
+            scale,
+            rsp,
+            illuminant: cs.illuminant().clone(),
+        }
+    }
+
+    pub fn value(&self, lambda: f32) -> f32 {
+        self.scale * self.rsp.evaluate(lambda) * self.illuminant.value(lambda)
+    }
+
+    pub fn illuminant(&self) -> &Illuminant {
+        &self.illuminant
+    }
+}
+
+impl_rgb_spectrum!(RGBAlbedoSpectrum);
+impl_rgb_spectrum!(RGBUnboundedSpectrum);
+impl_rgb_spectrum!(RGBIlluminantSpectrum);
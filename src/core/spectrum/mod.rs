@@ -0,0 +1,9 @@
+pub use cie::*;
+pub use color::*;
+pub use spectrum::*;
+pub use wavelengths::*;
+
+mod cie;
+mod color;
+mod spectrum;
+mod wavelengths;
@@ -0,0 +1,423 @@
+use crate::core::spectrum::{
+    SampledWavelengths, CIE_LAMBDA_START, CIE_X, CIE_Y, CIE_Y_INTEGRAL, CIE_Z, N_CIE_SAMPLES, N_SPECTRUM_SAMPLES,
+};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::OnceLock;
+
+pub const SAMPLED_LAMBDA_START: f32 = 400.0;
+pub const SAMPLED_LAMBDA_END: f32 = 700.0;
+pub const N_SPECTRAL_SAMPLES: usize = 60;
+
+// linear sRGB coefficients
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RGBSpectrum {
+    pub c: [f32; 3],
+}
+
+// spectral power in N_SPECTRAL_SAMPLES equal bins over
+// [SAMPLED_LAMBDA_START, SAMPLED_LAMBDA_END]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SampledSpectrum {
+    pub c: [f32; N_SPECTRAL_SAMPLES],
+}
+
+// values of a spectrum at the wavelengths of SampledWavelengths
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpectrumSamples {
+    pub c: [f32; N_SPECTRUM_SAMPLES],
+}
+
+pub type Spectrum = RGBSpectrum;
+
+macro_rules! impl_spectrum {
+    ($Spectrum: ident, $n: expr) => {
+        impl $Spectrum {
+            // the same value in every sample
+            #[inline]
+            pub const fn new(v: f32) -> Self {
+                Self { c: [v; $n] }
+            }
+
+            pub fn is_black(&self) -> bool {
+                self.c.iter().all(|&v| v == 0.0)
+            }
+
+            pub fn has_nans(&self) -> bool {
+                self.c.iter().any(|v| v.is_nan())
+            }
+
+            pub fn max_component_value(&self) -> f32 {
+                self.c.iter().fold(f32::MIN, |m, &v| m.max(v))
+            }
+
+            pub fn average(&self) -> f32 {
+                self.c.iter().sum::<f32>() / $n as f32
+            }
+
+            pub fn map(s: &Self, f: impl Fn(f32) -> f32) -> Self {
+                let mut r = *s;
+                for v in r.c.iter_mut() {
+                    *v = f(*v);
+                }
+                r
+            }
+
+            pub fn sqrt(s: &Self) -> Self {
+                Self::map(s, f32::sqrt)
+            }
+
+            pub fn exp(s: &Self) -> Self {
+                Self::map(s, f32::exp)
+            }
+
+            pub fn pow(s: &Self, e: f32) -> Self {
+                Self::map(s, |v| v.powf(e))
+            }
+
+            pub fn clamp(s: &Self, low: f32, high: f32) -> Self {
+                Self::map(s, |v| v.clamp(low, high))
+            }
+
+            pub fn lerp(t: f32, s1: &Self, s2: &Self) -> Self {
+                *s1 * (1.0 - t) + *s2 * t
+            }
+        }
+
+        impl Default for $Spectrum {
+            fn default() -> Self {
+                Self::new(0.0)
+            }
+        }
+
+        impl Add for $Spectrum {
+            type Output = Self;
+            fn add(mut self, other: Self) -> Self {
+                self += other;
+                self
+            }
+        }
+
+        impl AddAssign for $Spectrum {
+            fn add_assign(&mut self, other: Self) {
+                for (v, o) in self.c.iter_mut().zip(other.c.iter()) {
+                    *v += o;
+                }
+            }
+        }
+
+        impl Sub for $Spectrum {
+            type Output = Self;
+            fn sub(mut self, other: Self) -> Self {
+                self -= other;
+                self
+            }
+        }
+
+        impl SubAssign for $Spectrum {
+            fn sub_assign(&mut self, other: Self) {
+                for (v, o) in self.c.iter_mut().zip(other.c.iter()) {
+                    *v -= o;
+                }
+            }
+        }
+
+        impl Mul for $Spectrum {
+            type Output = Self;
+            fn mul(mut self, other: Self) -> Self {
+                self *= other;
+                self
+            }
+        }
+
+        impl MulAssign for $Spectrum {
+            fn mul_assign(&mut self, other: Self) {
+                for (v, o) in self.c.iter_mut().zip(other.c.iter()) {
+                    *v *= o;
+                }
+            }
+        }
+
+        impl Mul<f32> for $Spectrum {
+            type Output = Self;
+            fn mul(mut self, other: f32) -> Self {
+                self *= other;
+                self
+            }
+        }
+
+        impl MulAssign<f32> for $Spectrum {
+            fn mul_assign(&mut self, other: f32) {
+                for v in self.c.iter_mut() {
+                    *v *= other;
+                }
+            }
+        }
+
+        impl Mul<$Spectrum> for f32 {
+            type Output = $Spectrum;
+            #[inline]
+            fn mul(self, s: $Spectrum) -> $Spectrum {
+                s * self
+            }
+        }
+
+        // samples divided by zero are zero, as for a ratio of black spectra
+        impl Div for $Spectrum {
+            type Output = Self;
+            fn div(mut self, other: Self) -> Self {
+                self /= other;
+                self
+            }
+        }
+
+        impl DivAssign for $Spectrum {
+            fn div_assign(&mut self, other: Self) {
+                for (v, o) in self.c.iter_mut().zip(other.c.iter()) {
+                    *v = if *o != 0.0 { *v / o } else { 0.0 };
+                }
+            }
+        }
+
+        impl Div<f32> for $Spectrum {
+            type Output = Self;
+            fn div(mut self, other: f32) -> Self {
+                self /= other;
+                self
+            }
+        }
+
+        impl DivAssign<f32> for $Spectrum {
+            fn div_assign(&mut self, other: f32) {
+                let inv = 1.0 / other;
+                for v in self.c.iter_mut() {
+                    *v *= inv;
+                }
+            }
+        }
+
+        impl Neg for $Spectrum {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self::map(&self, |v| -v)
+            }
+        }
+
+        impl Index<usize> for $Spectrum {
+            type Output = f32;
+            fn index(&self, i: usize) -> &f32 {
+                &self.c[i]
+            }
+        }
+
+        impl IndexMut<usize> for $Spectrum {
+            fn index_mut(&mut self, i: usize) -> &mut f32 {
+                &mut self.c[i]
+            }
+        }
+    };
+}
+
+impl_spectrum!(RGBSpectrum, 3);
+impl_spectrum!(SampledSpectrum, N_SPECTRAL_SAMPLES);
+impl_spectrum!(SpectrumSamples, N_SPECTRUM_SAMPLES);
+
+// linear sRGB with a D65 white point
+pub fn xyz_to_rgb(xyz: &[f32; 3]) -> [f32; 3] {
+    [
+        3.240_479 * xyz[0] - 1.537_15 * xyz[1] - 0.498_535 * xyz[2],
+        -0.969_256 * xyz[0] + 1.875_991 * xyz[1] + 0.041_556 * xyz[2],
+        0.055_648 * xyz[0] - 0.204_043 * xyz[1] + 1.057_311 * xyz[2],
+    ]
+}
+
+pub fn rgb_to_xyz(rgb: &[f32; 3]) -> [f32; 3] {
+    [
+        0.412_453 * rgb[0] + 0.357_58 * rgb[1] + 0.180_423 * rgb[2],
+        0.212_671 * rgb[0] + 0.715_16 * rgb[1] + 0.072_169 * rgb[2],
+        0.019_334 * rgb[0] + 0.119_193 * rgb[1] + 0.950_227 * rgb[2],
+    ]
+}
+
+impl RGBSpectrum {
+    pub fn from_rgb(rgb: &[f32; 3]) -> Self {
+        Self { c: *rgb }
+    }
+
+    pub fn from_xyz(xyz: &[f32; 3]) -> Self {
+        Self { c: xyz_to_rgb(xyz) }
+    }
+
+    pub fn rgb(&self) -> [f32; 3] {
+        self.c
+    }
+
+    pub fn xyz(&self) -> [f32; 3] {
+        rgb_to_xyz(&self.c)
+    }
+
+    // luminance
+    pub fn y(&self) -> f32 {
+        0.212_671 * self.c[0] + 0.715_16 * self.c[1] + 0.072_169 * self.c[2]
+    }
+}
+
+// average over [lambda_0, lambda_1] of the piecewise linear function through
+// the samples, constant past the ends. lambda must be sorted.
+pub fn average_spectrum_samples(lambda: &[f32], values: &[f32], lambda_0: f32, lambda_1: f32) -> f32 {
+    let n = lambda.len();
+    assert!(n > 0 && n == values.len());
+    assert!(lambda.windows(2).all(|w| w[0] <= w[1]));
+    if lambda_1 <= lambda[0] || n == 1 {
+        return values[0];
+    }
+    if lambda_0 >= lambda[n - 1] {
+        return values[n - 1];
+    }
+    let mut sum = 0.0;
+    if lambda_0 < lambda[0] {
+        sum += values[0] * (lambda[0] - lambda_0);
+    }
+    if lambda_1 > lambda[n - 1] {
+        sum += values[n - 1] * (lambda_1 - lambda[n - 1]);
+    }
+    let mut i = 0;
+    while lambda_0 > lambda[i + 1] {
+        i += 1;
+    }
+    let interpolate = |w: f32, i: usize| {
+        let t = (w - lambda[i]) / (lambda[i + 1] - lambda[i]);
+        (1.0 - t) * values[i] + t * values[i + 1]
+    };
+    while i + 1 < n && lambda_1 >= lambda[i] {
+        let seg_0 = lambda_0.max(lambda[i]);
+        let seg_1 = lambda_1.min(lambda[i + 1]);
+        sum += 0.5 * (interpolate(seg_0, i) + interpolate(seg_1, i)) * (seg_1 - seg_0);
+        i += 1;
+    }
+    sum / (lambda_1 - lambda_0)
+}
+
+// wavelength range of bin i
+fn bin_range(i: usize) -> (f32, f32) {
+    let t = |i: usize| i as f32 / N_SPECTRAL_SAMPLES as f32;
+    let lerp = |t: f32| (1.0 - t) * SAMPLED_LAMBDA_START + t * SAMPLED_LAMBDA_END;
+    (lerp(t(i)), lerp(t(i + 1)))
+}
+
+// matching functions averaged over the bins
+struct BinnedCie {
+    x: SampledSpectrum,
+    y: SampledSpectrum,
+    z: SampledSpectrum,
+}
+
+fn binned_cie() -> &'static BinnedCie {
+    static BINNED: OnceLock<BinnedCie> = OnceLock::new();
+    BINNED.get_or_init(|| {
+        let lambda: Vec<f32> = (0..N_CIE_SAMPLES).map(|i| CIE_LAMBDA_START + i as f32).collect();
+        let bin = |table: &[f32]| {
+            let mut s = SampledSpectrum::default();
+            for i in 0..N_SPECTRAL_SAMPLES {
+                let (l0, l1) = bin_range(i);
+                s.c[i] = average_spectrum_samples(&lambda, table, l0, l1);
+            }
+            s
+        };
+        BinnedCie {
+            x: bin(&CIE_X),
+            y: bin(&CIE_Y),
+            z: bin(&CIE_Z),
+        }
+    })
+}
+
+impl SampledSpectrum {
+    // from samples of a spectral distribution at sorted wavelengths
+    pub fn from_sampled(lambda: &[f32], values: &[f32]) -> Self {
+        let mut s = Self::default();
+        for i in 0..N_SPECTRAL_SAMPLES {
+            let (l0, l1) = bin_range(i);
+            s.c[i] = average_spectrum_samples(lambda, values, l0, l1);
+        }
+        s
+    }
+
+    // integral of the product with the matching functions, y of a constant
+    // spectrum of 1 is about 1
+    pub fn xyz(&self) -> [f32; 3] {
+        let cie = binned_cie();
+        let scale = (SAMPLED_LAMBDA_END - SAMPLED_LAMBDA_START) / (CIE_Y_INTEGRAL * N_SPECTRAL_SAMPLES as f32);
+        let dot = |m: &SampledSpectrum| m.c.iter().zip(self.c.iter()).map(|(a, b)| a * b).sum::<f32>() * scale;
+        [dot(&cie.x), dot(&cie.y), dot(&cie.z)]
+    }
+
+    pub fn y(&self) -> f32 {
+        self.xyz()[1]
+    }
+
+    pub fn rgb(&self) -> [f32; 3] {
+        xyz_to_rgb(&self.xyz())
+    }
+
+    pub fn to_rgb_spectrum(self) -> RGBSpectrum {
+        RGBSpectrum::from_rgb(&self.rgb())
+    }
+
+    // value of the bin holding lambda, zero outside the sampled range
+    pub fn value(&self, lambda: f32) -> f32 {
+        let t = (lambda - SAMPLED_LAMBDA_START) / (SAMPLED_LAMBDA_END - SAMPLED_LAMBDA_START);
+        if !(0.0..=1.0).contains(&t) {
+            return 0.0;
+        }
+        self.c[((t * N_SPECTRAL_SAMPLES as f32) as usize).min(N_SPECTRAL_SAMPLES - 1)]
+    }
+
+    pub fn sample(&self, lambda: &SampledWavelengths) -> SpectrumSamples {
+        let mut s = SpectrumSamples::default();
+        for (v, &l) in s.c.iter_mut().zip(lambda.wavelengths().iter()) {
+            *v = self.value(l);
+        }
+        s
+    }
+}
+
+// matching functions at lambda, interpolated from the tables
+pub fn cie_xyz(lambda: f32) -> [f32; 3] {
+    let x = lambda - CIE_LAMBDA_START;
+    if x < 0.0 || x > (N_CIE_SAMPLES - 1) as f32 {
+        return [0.0; 3];
+    }
+    let i = (x as usize).min(N_CIE_SAMPLES - 2);
+    let t = x - i as f32;
+    let lerp = |table: &[f32]| (1.0 - t) * table[i] + t * table[i + 1];
+    [lerp(&CIE_X), lerp(&CIE_Y), lerp(&CIE_Z)]
+}
+
+impl SpectrumSamples {
+    // Monte Carlo estimate of the integral of the product with the matching
+    // functions. wavelengths with zero density were terminated and don't
+    // count.
+    pub fn xyz(&self, lambda: &SampledWavelengths) -> [f32; 3] {
+        let pdf = lambda.pdf();
+        let mut xyz = [0.0; 3];
+        for i in 0..N_SPECTRUM_SAMPLES {
+            if pdf[i] == 0.0 {
+                continue;
+            }
+            let cie = cie_xyz(lambda.lambda(i));
+            for (v, m) in xyz.iter_mut().zip(cie.iter()) {
+                *v += m * self.c[i] / pdf[i];
+            }
+        }
+        let scale = 1.0 / (N_SPECTRUM_SAMPLES as f32 * CIE_Y_INTEGRAL);
+        [xyz[0] * scale, xyz[1] * scale, xyz[2] * scale]
+    }
+
+    pub fn y(&self, lambda: &SampledWavelengths) -> f32 {
+        self.xyz(lambda)[1]
+    }
+
+    pub fn rgb(&self, lambda: &SampledWavelengths) -> [f32; 3] {
+        xyz_to_rgb(&self.xyz(lambda))
+    }
+}
@@ -0,0 +1,91 @@
+use crate::core::spectrum::SpectrumSamples;
+
+// wavelengths carried by each path
+pub const N_SPECTRUM_SAMPLES: usize = 4;
+pub const LAMBDA_MIN: f32 = 360.0;
+pub const LAMBDA_MAX: f32 = 830.0;
+
+// wavelength with density close to the sensitivity of the eye, proportional
+// to 1 / cosh^2(0.0072 (lambda - 538)) over [LAMBDA_MIN, LAMBDA_MAX]
+pub fn sample_visible_wavelengths(u: f32) -> f32 {
+    538.0 - 138.888_89 * (0.856_910_6 - 1.827_502 * u).atanh()
+}
+
+pub fn visible_wavelengths_pdf(lambda: f32) -> f32 {
+    if !(LAMBDA_MIN..=LAMBDA_MAX).contains(&lambda) {
+        return 0.0;
+    }
+    0.003_939_804 / (0.0072 * (lambda - 538.0)).cosh().powi(2)
+}
+
+// the hero wavelength and the others spread evenly after it, all with their
+// densities. a path carries the radiance at every one of them.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SampledWavelengths {
+    lambda: [f32; N_SPECTRUM_SAMPLES],
+    pdf: [f32; N_SPECTRUM_SAMPLES],
+}
+
+impl SampledWavelengths {
+    // hero wavelength at u, the others at equal steps wrapping around the range
+    pub fn sample_uniform(u: f32, lambda_min: f32, lambda_max: f32) -> Self {
+        let mut lambda = [0.0; N_SPECTRUM_SAMPLES];
+        lambda[0] = (1.0 - u) * lambda_min + u * lambda_max;
+        let delta = (lambda_max - lambda_min) / N_SPECTRUM_SAMPLES as f32;
+        for i in 1..N_SPECTRUM_SAMPLES {
+            lambda[i] = lambda[i - 1] + delta;
+            if lambda[i] > lambda_max {
+                lambda[i] = lambda_min + (lambda[i] - lambda_max);
+            }
+        }
+        Self {
+            lambda,
+            pdf: [1.0 / (lambda_max - lambda_min); N_SPECTRUM_SAMPLES],
+        }
+    }
+
+    // stratified in u, each wavelength importance sampled by the visible
+    // wavelengths density
+    pub fn sample_visible(u: f32) -> Self {
+        let mut lambda = [0.0; N_SPECTRUM_SAMPLES];
+        let mut pdf = [0.0; N_SPECTRUM_SAMPLES];
+        for i in 0..N_SPECTRUM_SAMPLES {
+            let mut up = u + i as f32 / N_SPECTRUM_SAMPLES as f32;
+            if up > 1.0 {
+                up -= 1.0;
+            }
+            lambda[i] = sample_visible_wavelengths(up);
+            pdf[i] = visible_wavelengths_pdf(lambda[i]);
+        }
+        Self { lambda, pdf }
+    }
+
+    pub fn lambda(&self, i: usize) -> f32 {
+        self.lambda[i]
+    }
+
+    pub fn wavelengths(&self) -> &[f32; N_SPECTRUM_SAMPLES] {
+        &self.lambda
+    }
+
+    pub fn pdf(&self) -> SpectrumSamples {
+        SpectrumSamples { c: self.pdf }
+    }
+
+    // keeps only the hero wavelength, for events like refraction through
+    // dispersive media that send each wavelength in its own direction. its
+    // density is scaled so that estimates stay unbiased.
+    pub fn terminate_secondary(&mut self) {
+        if self.secondary_terminated() {
+            return;
+        }
+        for pdf in self.pdf[1..].iter_mut() {
+            *pdf = 0.0;
+        }
+        self.pdf[0] /= N_SPECTRUM_SAMPLES as f32;
+    }
+
+    pub fn secondary_terminated(&self) -> bool {
+        self.pdf[1..].iter().all(|&pdf| pdf == 0.0)
+    }
+}
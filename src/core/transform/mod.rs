@@ -0,0 +1,3 @@
+pub use transform::*;
+
+mod transform;
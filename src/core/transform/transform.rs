@@ -0,0 +1,549 @@
+use crate::core::geometry::{BaseRay, Bounds3f, Normal3f, Point3f, Ray, RayDifferential, Vector3f};
+use crate::core::pbrt::{clamp, lerp, radians};
+use crate::core::quaternion::Quaternion;
+use std::ops::Mul;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Matrix4x4 {
+    pub m: [[f32; 4]; 4],
+}
+
+impl Default for Matrix4x4 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Matrix4x4 {
+    pub const fn new(m: [[f32; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    pub const fn identity() -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn transpose(m: &Self) -> Self {
+        let mut r = [[0.0; 4]; 4];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = m.m[j][i];
+            }
+        }
+        Self::new(r)
+    }
+
+    // Gauss-Jordan elimination with full pivoting, None for singular matrices
+    pub fn inverse(m: &Self) -> Option<Self> {
+        let mut indxc = [0usize; 4];
+        let mut indxr = [0usize; 4];
+        let mut ipiv = [0i32; 4];
+        let mut minv = m.m;
+        for i in 0..4 {
+            let mut irow = 0;
+            let mut icol = 0;
+            let mut big = 0.0f32;
+            for j in 0..4 {
+                if ipiv[j] != 1 {
+                    for k in 0..4 {
+                        if ipiv[k] == 0 {
+                            if minv[j][k].abs() >= big {
+                                big = minv[j][k].abs();
+                                irow = j;
+                                icol = k;
+                            }
+                        } else if ipiv[k] > 1 {
+                            return None;
+                        }
+                    }
+                }
+            }
+            ipiv[icol] += 1;
+            if irow != icol {
+                minv.swap(irow, icol);
+            }
+            indxr[i] = irow;
+            indxc[i] = icol;
+            if minv[icol][icol] == 0.0 {
+                return None;
+            }
+
+            let pivinv = 1.0 / minv[icol][icol];
+            minv[icol][icol] = 1.0;
+            for v in minv[icol].iter_mut() {
+                *v *= pivinv;
+            }
+            let pivot_row = minv[icol];
+            for (j, row) in minv.iter_mut().enumerate() {
+                if j != icol {
+                    let save = row[icol];
+                    row[icol] = 0.0;
+                    for (v, p) in row.iter_mut().zip(pivot_row.iter()) {
+                        *v -= p * save;
+                    }
+                }
+            }
+        }
+        for j in (0..4).rev() {
+            if indxr[j] != indxc[j] {
+                for row in minv.iter_mut() {
+                    row.swap(indxr[j], indxc[j]);
+                }
+            }
+        }
+        Some(Self::new(minv))
+    }
+}
+
+impl Mul for Matrix4x4 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let mut r = [[0.0; 4]; 4];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = self.m[i][0] * other.m[0][j]
+                    + self.m[i][1] * other.m[1][j]
+                    + self.m[i][2] * other.m[2][j]
+                    + self.m[i][3] * other.m[3][j];
+            }
+        }
+        Self::new(r)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Transform {
+    pub m: Matrix4x4,
+    pub m_inv: Matrix4x4,
+}
+
+impl Transform {
+    pub const fn new(m: Matrix4x4, m_inv: Matrix4x4) -> Self {
+        Self { m, m_inv }
+    }
+
+    pub fn from_matrix(m: Matrix4x4) -> Self {
+        let m_inv = Matrix4x4::inverse(&m).expect("singular matrix in Transform::from_matrix");
+        Self::new(m, m_inv)
+    }
+
+    pub fn inverse(t: &Self) -> Self {
+        Self::new(t.m_inv, t.m)
+    }
+
+    pub fn transpose(t: &Self) -> Self {
+        Self::new(Matrix4x4::transpose(&t.m), Matrix4x4::transpose(&t.m_inv))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.m == Matrix4x4::identity()
+    }
+
+    pub fn has_scale(&self) -> bool {
+        let la2 = self.transform_vector(&Vector3f::new(1.0, 0.0, 0.0)).length_squared();
+        let lb2 = self.transform_vector(&Vector3f::new(0.0, 1.0, 0.0)).length_squared();
+        let lc2 = self.transform_vector(&Vector3f::new(0.0, 0.0, 1.0)).length_squared();
+        let not_one = |x: f32| !(0.999..=1.001).contains(&x);
+        not_one(la2) || not_one(lb2) || not_one(lc2)
+    }
+
+    pub fn translate(delta: &Vector3f) -> Self {
+        let m = Matrix4x4::new([
+            [1.0, 0.0, 0.0, delta.x],
+            [0.0, 1.0, 0.0, delta.y],
+            [0.0, 0.0, 1.0, delta.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m_inv = Matrix4x4::new([
+            [1.0, 0.0, 0.0, -delta.x],
+            [0.0, 1.0, 0.0, -delta.y],
+            [0.0, 0.0, 1.0, -delta.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self::new(m, m_inv)
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Self {
+        let m = Matrix4x4::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m_inv = Matrix4x4::new([
+            [1.0 / x, 0.0, 0.0, 0.0],
+            [0.0, 1.0 / y, 0.0, 0.0],
+            [0.0, 0.0, 1.0 / z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self::new(m, m_inv)
+    }
+
+    pub fn rotate_x(theta: f32) -> Self {
+        let (sin_theta, cos_theta) = radians(theta).sin_cos();
+        let m = Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos_theta, -sin_theta, 0.0],
+            [0.0, sin_theta, cos_theta, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self::new(m, Matrix4x4::transpose(&m))
+    }
+
+    pub fn rotate_y(theta: f32) -> Self {
+        let (sin_theta, cos_theta) = radians(theta).sin_cos();
+        let m = Matrix4x4::new([
+            [cos_theta, 0.0, sin_theta, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin_theta, 0.0, cos_theta, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self::new(m, Matrix4x4::transpose(&m))
+    }
+
+    pub fn rotate_z(theta: f32) -> Self {
+        let (sin_theta, cos_theta) = radians(theta).sin_cos();
+        let m = Matrix4x4::new([
+            [cos_theta, -sin_theta, 0.0, 0.0],
+            [sin_theta, cos_theta, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self::new(m, Matrix4x4::transpose(&m))
+    }
+
+    // rotation by theta degrees around an arbitrary axis
+    pub fn rotate(theta: f32, axis: &Vector3f) -> Self {
+        let a = Vector3f::normalize(axis);
+        let (sin_theta, cos_theta) = radians(theta).sin_cos();
+        let mut m = Matrix4x4::identity();
+        m.m[0][0] = a.x * a.x + (1.0 - a.x * a.x) * cos_theta;
+        m.m[0][1] = a.x * a.y * (1.0 - cos_theta) - a.z * sin_theta;
+        m.m[0][2] = a.x * a.z * (1.0 - cos_theta) + a.y * sin_theta;
+        m.m[1][0] = a.x * a.y * (1.0 - cos_theta) + a.z * sin_theta;
+        m.m[1][1] = a.y * a.y + (1.0 - a.y * a.y) * cos_theta;
+        m.m[1][2] = a.y * a.z * (1.0 - cos_theta) - a.x * sin_theta;
+        m.m[2][0] = a.x * a.z * (1.0 - cos_theta) - a.y * sin_theta;
+        m.m[2][1] = a.y * a.z * (1.0 - cos_theta) + a.x * sin_theta;
+        m.m[2][2] = a.z * a.z + (1.0 - a.z * a.z) * cos_theta;
+        Self::new(m, Matrix4x4::transpose(&m))
+    }
+
+    // world to camera transform for a camera at pos looking at look
+    pub fn look_at(pos: &Point3f, look: &Point3f, up: &Vector3f) -> Self {
+        let dir = Vector3f::normalize(&(*look - *pos));
+        let right = Vector3f::cross(&Vector3f::normalize(up), &dir);
+        assert!(right.length() > 0.0, "up vector and viewing direction are parallel");
+        let right = Vector3f::normalize(&right);
+        let new_up = Vector3f::cross(&dir, &right);
+        let camera_to_world = Matrix4x4::new([
+            [right.x, new_up.x, dir.x, pos.x],
+            [right.y, new_up.y, dir.y, pos.y],
+            [right.z, new_up.z, dir.z, pos.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let world_to_camera = Matrix4x4::inverse(&camera_to_world).unwrap();
+        Self::new(world_to_camera, camera_to_world)
+    }
+
+    // camera to screen projection mapping z in [z_near, z_far] to [0, 1]
+    pub fn orthographic(z_near: f32, z_far: f32) -> Self {
+        Self::scale(1.0, 1.0, 1.0 / (z_far - z_near)) * Self::translate(&Vector3f::new(0.0, 0.0, -z_near))
+    }
+
+    // camera to screen projection, fov in degrees
+    pub fn perspective(fov: f32, n: f32, f: f32) -> Self {
+        let persp = Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, f / (f - n), -f * n / (f - n)],
+            [0.0, 0.0, 1.0, 0.0],
+        ]);
+        let inv_tan_ang = 1.0 / (radians(fov) / 2.0).tan();
+        Self::scale(inv_tan_ang, inv_tan_ang, 1.0) * Self::from_matrix(persp)
+    }
+
+    pub fn transform_point(&self, p: &Point3f) -> Point3f {
+        let m = &self.m.m;
+        let xp = m[0][0] * p.x + m[0][1] * p.y + m[0][2] * p.z + m[0][3];
+        let yp = m[1][0] * p.x + m[1][1] * p.y + m[1][2] * p.z + m[1][3];
+        let zp = m[2][0] * p.x + m[2][1] * p.y + m[2][2] * p.z + m[2][3];
+        let wp = m[3][0] * p.x + m[3][1] * p.y + m[3][2] * p.z + m[3][3];
+        if wp == 1.0 {
+            Point3f::new(xp, yp, zp)
+        } else {
+            Point3f::new(xp, yp, zp) / wp
+        }
+    }
+
+    pub fn transform_vector(&self, v: &Vector3f) -> Vector3f {
+        let m = &self.m.m;
+        Vector3f::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        )
+    }
+
+    // normals go through the inverse transpose
+    pub fn transform_normal(&self, n: &Normal3f) -> Normal3f {
+        let m_inv = &self.m_inv.m;
+        Normal3f::new(
+            m_inv[0][0] * n.x + m_inv[1][0] * n.y + m_inv[2][0] * n.z,
+            m_inv[0][1] * n.x + m_inv[1][1] * n.y + m_inv[2][1] * n.z,
+            m_inv[0][2] * n.x + m_inv[1][2] * n.y + m_inv[2][2] * n.z,
+        )
+    }
+
+    // the direction is not normalized, so parametric distances carry over
+    pub fn transform_ray<'a>(&self, r: &Ray<'a>) -> Ray<'a> {
+        Ray::new(
+            self.transform_point(&r.o),
+            self.transform_vector(&r.d),
+            r.t_max,
+            r.time,
+            r.medium,
+        )
+    }
+
+    pub fn transform_ray_differential<'a>(&self, r: &RayDifferential<'a>) -> RayDifferential<'a> {
+        let mut ret = RayDifferential::from_ray(self.transform_ray(r.ray()));
+        ret.has_differentials = r.has_differentials;
+        ret.rx_origin = self.transform_point(&r.rx_origin);
+        ret.ry_origin = self.transform_point(&r.ry_origin);
+        ret.rx_direction = self.transform_vector(&r.rx_direction);
+        ret.ry_direction = self.transform_vector(&r.ry_direction);
+        ret
+    }
+
+    pub fn transform_bounds(&self, b: &Bounds3f) -> Bounds3f {
+        (1..8).fold(
+            Bounds3f::from_single_point(self.transform_point(&b.corner(0))),
+            |ret, i| Bounds3f::union_from_point(&ret, self.transform_point(&b.corner(i))),
+        )
+    }
+
+    pub fn swaps_handedness(&self) -> bool {
+        let m = &self.m.m;
+        let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        det < 0.0
+    }
+}
+
+impl Mul for Transform {
+    type Output = Self;
+    fn mul(self, t2: Self) -> Self {
+        Self::new(self.m * t2.m, t2.m_inv * self.m_inv)
+    }
+}
+
+// transform interpolated between two keyframes, decomposed into
+// translation, rotation and scale so rotations stay rigid over time
+#[derive(Copy, Clone, Debug)]
+pub struct AnimatedTransform {
+    start_transform: Transform,
+    end_transform: Transform,
+    start_time: f32,
+    end_time: f32,
+    actually_animated: bool,
+    // false when a keyframe is singular, the matrices are lerped instead
+    decomposed: bool,
+    t: [Vector3f; 2],
+    r: [Quaternion; 2],
+    s: [Matrix4x4; 2],
+}
+
+impl AnimatedTransform {
+    pub fn new(start_transform: Transform, start_time: f32, end_transform: Transform, end_time: f32) -> Self {
+        let actually_animated = start_transform != end_transform;
+        let mut animated = Self {
+            start_transform,
+            end_transform,
+            start_time,
+            end_time,
+            actually_animated,
+            decomposed: false,
+            t: [Vector3f::default(); 2],
+            r: [Quaternion::default(); 2],
+            s: [Matrix4x4::identity(); 2],
+        };
+        if !actually_animated {
+            return animated;
+        }
+        if let (Some((t0, r0, s0)), Some((t1, mut r1, s1))) =
+            (Self::decompose(&start_transform.m), Self::decompose(&end_transform.m))
+        {
+            // take the shorter way around
+            if Quaternion::dot(&r0, &r1) < 0.0 {
+                r1 = -r1;
+            }
+            animated.decomposed = true;
+            animated.t = [t0, t1];
+            animated.r = [r0, r1];
+            animated.s = [s0, s1];
+        }
+        animated
+    }
+
+    pub fn from_transform(t: Transform) -> Self {
+        Self::new(t, 0.0, t, 1.0)
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.actually_animated
+    }
+
+    pub fn has_scale(&self) -> bool {
+        self.start_transform.has_scale() || self.end_transform.has_scale()
+    }
+
+    // none when the upper 3x3 is singular, e.g. scaled to zero
+    fn decompose(m: &Matrix4x4) -> Option<(Vector3f, Quaternion, Matrix4x4)> {
+        let t = Vector3f::new(m.m[0][3], m.m[1][3], m.m[2][3]);
+
+        let mut mm = *m;
+        for i in 0..3 {
+            mm.m[i][3] = 0.0;
+            mm.m[3][i] = 0.0;
+        }
+        mm.m[3][3] = 1.0;
+
+        // polar decomposition, average the matrix with its inverse transpose
+        // until it converges to the rotation
+        let mut r = mm;
+        for _ in 0..100 {
+            let rit = Matrix4x4::inverse(&Matrix4x4::transpose(&r))?;
+            let mut r_next = Matrix4x4::identity();
+            let mut norm = 0.0f32;
+            for i in 0..4 {
+                let mut n = 0.0;
+                for j in 0..4 {
+                    r_next.m[i][j] = 0.5 * (r.m[i][j] + rit.m[i][j]);
+                    n += (r.m[i][j] - r_next.m[i][j]).abs();
+                }
+                norm = norm.max(n);
+            }
+            r = r_next;
+            if norm <= 0.0001 {
+                break;
+            }
+        }
+        let rquat = Quaternion::from_transform(&Transform::new(r, Matrix4x4::transpose(&r)));
+        let s = Matrix4x4::inverse(&r)? * mm;
+        Some((t, rquat, s))
+    }
+
+    pub fn interpolate(&self, time: f32) -> Transform {
+        if !self.actually_animated || time <= self.start_time {
+            return self.start_transform;
+        }
+        if time >= self.end_time {
+            return self.end_transform;
+        }
+        let dt = (time - self.start_time) / (self.end_time - self.start_time);
+        if !self.decomposed {
+            let mut m = Matrix4x4::identity();
+            for i in 0..4 {
+                for j in 0..4 {
+                    m.m[i][j] = lerp(dt, self.start_transform.m.m[i][j], self.end_transform.m.m[i][j]);
+                }
+            }
+            return Self::from_matrix_or_singular(m);
+        }
+        let trans = self.t[0] * (1.0 - dt) + self.t[1] * dt;
+        let rotate = Quaternion::slerp(dt, &self.r[0], &self.r[1]);
+        let mut scale = Matrix4x4::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                scale.m[i][j] = lerp(dt, self.s[0].m[i][j], self.s[1].m[i][j]);
+            }
+        }
+        Transform::translate(&trans) * rotate.to_transform() * Self::from_matrix_or_singular(scale)
+    }
+
+    // a singular matrix gets an infinite inverse, as Transform::scale(0.0, ..)
+    // does, rather than a panic
+    fn from_matrix_or_singular(m: Matrix4x4) -> Transform {
+        let m_inv = Matrix4x4::inverse(&m).unwrap_or_else(|| Matrix4x4::new([[f32::INFINITY; 4]; 4]));
+        Transform::new(m, m_inv)
+    }
+
+    pub fn transform_ray<'a>(&self, r: &Ray<'a>) -> Ray<'a> {
+        if !self.actually_animated || r.time <= self.start_time {
+            self.start_transform.transform_ray(r)
+        } else if r.time >= self.end_time {
+            self.end_transform.transform_ray(r)
+        } else {
+            self.interpolate(r.time).transform_ray(r)
+        }
+    }
+
+    pub fn transform_ray_differential<'a>(&self, r: &RayDifferential<'a>) -> RayDifferential<'a> {
+        let time = r.ray().time;
+        if !self.actually_animated || time <= self.start_time {
+            self.start_transform.transform_ray_differential(r)
+        } else if time >= self.end_time {
+            self.end_transform.transform_ray_differential(r)
+        } else {
+            self.interpolate(time).transform_ray_differential(r)
+        }
+    }
+
+    pub fn transform_point(&self, time: f32, p: &Point3f) -> Point3f {
+        self.interpolate(time).transform_point(p)
+    }
+
+    pub fn transform_vector(&self, time: f32, v: &Vector3f) -> Vector3f {
+        self.interpolate(time).transform_vector(v)
+    }
+
+    // bounds of b swept over the whole time range. the corners are sampled
+    // densely and the result padded by the farthest a corner can move
+    // between two samples, so the bounds are conservative.
+    pub fn motion_bounds(&self, b: &Bounds3f) -> Bounds3f {
+        if !self.actually_animated {
+            return self.start_transform.transform_bounds(b);
+        }
+        // without rotation, or with lerped matrices, the corners move along
+        // lines
+        if !self.decomposed || self.r[0] == self.r[1] {
+            return Bounds3f::union(&self.start_transform.transform_bounds(b), &self.end_transform.transform_bounds(b));
+        }
+        const N_STEPS: usize = 128;
+        let sampled = (0..N_STEPS).fold(Bounds3f::empty(), |ret, i| {
+            let t = lerp(i as f32 / (N_STEPS - 1) as f32, self.start_time, self.end_time);
+            Bounds3f::union(&ret, &self.interpolate(t).transform_bounds(b))
+        });
+        // every point of a path is within half a step of a sample, plus
+        // some room for rounding in the interpolated transforms
+        let speed = (0..8).map(|i| self.max_speed(&b.corner(i))).fold(0.0, f32::max);
+        let extent = [sampled.p_min, sampled.p_max].iter().fold(0.0f32, |m, p| m.max(p.x.abs()).max(p.y.abs()).max(p.z.abs()));
+        Bounds3f::expand(&sampled, speed / (2 * (N_STEPS - 1)) as f32 + 1e-5 * extent)
+    }
+
+    // bound on the speed of p over the time range normalized to [0, 1]. the
+    // translation and the scale part move linearly, the rotation turns
+    // S(t) p at twice the angle between the quaternions. the normalized lerp
+    // that slerp uses for close rotations is at most a fraction of a percent
+    // faster.
+    fn max_speed(&self, p: &Point3f) -> f32 {
+        let scaled = |s: &Matrix4x4| {
+            let m = &s.m;
+            Vector3f::new(
+                m[0][0] * p.x + m[0][1] * p.y + m[0][2] * p.z,
+                m[1][0] * p.x + m[1][1] * p.y + m[1][2] * p.z,
+                m[2][0] * p.x + m[2][1] * p.y + m[2][2] * p.z,
+            )
+        };
+        let (v0, v1) = (scaled(&self.s[0]), scaled(&self.s[1]));
+        let theta = clamp(Quaternion::dot(&self.r[0], &self.r[1]), -1.0, 1.0).acos();
+        (self.t[1] - self.t[0]).length() + 2.02 * theta * v0.length().max(v1.length()) + (v1 - v0).length()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::filter::Filter;
+use crate::core::geometry::{Point2f, Vector2f};
+
+#[derive(Copy, Clone, Debug)]
+pub struct BoxFilter {
+    radius: Vector2f,
+}
+
+impl BoxFilter {
+    pub fn new(radius: Vector2f) -> Self {
+        Self { radius }
+    }
+}
+
+impl Default for BoxFilter {
+    fn default() -> Self {
+        Self::new(Vector2f::new(0.5, 0.5))
+    }
+}
+
+impl Filter for BoxFilter {
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+
+    fn evaluate(&self, _p: &Point2f) -> f32 {
+        1.0
+    }
+}
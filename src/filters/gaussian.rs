@@ -0,0 +1,42 @@
+use crate::core::filter::Filter;
+use crate::core::geometry::{Point2f, Vector2f};
+
+// gaussian shifted down so that it reaches zero at the radius
+#[derive(Copy, Clone, Debug)]
+pub struct GaussianFilter {
+    radius: Vector2f,
+    alpha: f32,
+    exp_x: f32,
+    exp_y: f32,
+}
+
+impl GaussianFilter {
+    pub fn new(radius: Vector2f, alpha: f32) -> Self {
+        Self {
+            radius,
+            alpha,
+            exp_x: (-alpha * radius.x * radius.x).exp(),
+            exp_y: (-alpha * radius.y * radius.y).exp(),
+        }
+    }
+
+    fn gaussian(&self, d: f32, expv: f32) -> f32 {
+        ((-self.alpha * d * d).exp() - expv).max(0.0)
+    }
+}
+
+impl Default for GaussianFilter {
+    fn default() -> Self {
+        Self::new(Vector2f::new(1.5, 1.5), 2.0)
+    }
+}
+
+impl Filter for GaussianFilter {
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+
+    fn evaluate(&self, p: &Point2f) -> f32 {
+        self.gaussian(p.x, self.exp_x) * self.gaussian(p.y, self.exp_y)
+    }
+}
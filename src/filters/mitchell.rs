@@ -0,0 +1,53 @@
+use crate::core::filter::Filter;
+use crate::core::geometry::{Point2f, Vector2f};
+
+// Mitchell-Netravali cubic, b and c trade blurring against ringing
+#[derive(Copy, Clone, Debug)]
+pub struct MitchellFilter {
+    radius: Vector2f,
+    inv_radius: Vector2f,
+    b: f32,
+    c: f32,
+}
+
+impl MitchellFilter {
+    pub fn new(radius: Vector2f, b: f32, c: f32) -> Self {
+        Self {
+            radius,
+            inv_radius: Vector2f::new(1.0 / radius.x, 1.0 / radius.y),
+            b,
+            c,
+        }
+    }
+
+    // x in [-1, 1] over the filter radius
+    fn mitchell_1d(&self, x: f32) -> f32 {
+        let x = (2.0 * x).abs();
+        let (b, c) = (self.b, self.c);
+        if x > 2.0 {
+            0.0
+        } else if x > 1.0 {
+            ((-b - 6.0 * c) * x * x * x + (6.0 * b + 30.0 * c) * x * x + (-12.0 * b - 48.0 * c) * x + (8.0 * b + 24.0 * c))
+                * (1.0 / 6.0)
+        } else {
+            ((12.0 - 9.0 * b - 6.0 * c) * x * x * x + (-18.0 + 12.0 * b + 6.0 * c) * x * x + (6.0 - 2.0 * b))
+                * (1.0 / 6.0)
+        }
+    }
+}
+
+impl Default for MitchellFilter {
+    fn default() -> Self {
+        Self::new(Vector2f::new(2.0, 2.0), 1.0 / 3.0, 1.0 / 3.0)
+    }
+}
+
+impl Filter for MitchellFilter {
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+
+    fn evaluate(&self, p: &Point2f) -> f32 {
+        self.mitchell_1d(p.x * self.inv_radius.x) * self.mitchell_1d(p.y * self.inv_radius.y)
+    }
+}
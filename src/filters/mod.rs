@@ -0,0 +1,11 @@
+pub use boxfilter::*;
+pub use gaussian::*;
+pub use mitchell::*;
+pub use sinc::*;
+pub use triangle::*;
+
+mod boxfilter;
+mod gaussian;
+mod mitchell;
+mod sinc;
+mod triangle;
@@ -0,0 +1,48 @@
+use crate::core::filter::Filter;
+use crate::core::geometry::{Point2f, Vector2f};
+use crate::core::pbrt::PI;
+
+// sinc windowed by a Lanczos sinc, tau is the number of cycles it spans
+#[derive(Copy, Clone, Debug)]
+pub struct LanczosSincFilter {
+    radius: Vector2f,
+    tau: f32,
+}
+
+impl LanczosSincFilter {
+    pub fn new(radius: Vector2f, tau: f32) -> Self {
+        Self { radius, tau }
+    }
+
+    fn sinc(x: f32) -> f32 {
+        let x = x.abs();
+        if x < 1e-5 {
+            return 1.0;
+        }
+        (PI * x).sin() / (PI * x)
+    }
+
+    fn windowed_sinc(&self, x: f32, radius: f32) -> f32 {
+        let x = x.abs();
+        if x > radius {
+            return 0.0;
+        }
+        Self::sinc(x) * Self::sinc(x / self.tau)
+    }
+}
+
+impl Default for LanczosSincFilter {
+    fn default() -> Self {
+        Self::new(Vector2f::new(4.0, 4.0), 3.0)
+    }
+}
+
+impl Filter for LanczosSincFilter {
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+
+    fn evaluate(&self, p: &Point2f) -> f32 {
+        self.windowed_sinc(p.x, self.radius.x) * self.windowed_sinc(p.y, self.radius.y)
+    }
+}
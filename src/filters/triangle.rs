@@ -0,0 +1,29 @@
+use crate::core::filter::Filter;
+use crate::core::geometry::{Point2f, Vector2f};
+
+#[derive(Copy, Clone, Debug)]
+pub struct TriangleFilter {
+    radius: Vector2f,
+}
+
+impl TriangleFilter {
+    pub fn new(radius: Vector2f) -> Self {
+        Self { radius }
+    }
+}
+
+impl Default for TriangleFilter {
+    fn default() -> Self {
+        Self::new(Vector2f::new(2.0, 2.0))
+    }
+}
+
+impl Filter for TriangleFilter {
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+
+    fn evaluate(&self, p: &Point2f) -> f32 {
+        (self.radius.x - p.x.abs()).max(0.0) * (self.radius.y - p.y.abs()).max(0.0)
+    }
+}
@@ -1,2 +1,9 @@
+#![allow(clippy::module_inception)]
+
 #[macro_use]
-pub mod core;
\ No newline at end of file
+pub mod core;
+pub mod accelerators;
+pub mod cameras;
+pub mod filters;
+pub mod samplers;
+pub mod shapes;
@@ -0,0 +1,176 @@
+use crate::core::geometry::{Bounds2i, Point2f, Point2i};
+use crate::core::lowdiscrepancy::{
+    compute_radical_inverse_permutations, inverse_radical_inverse, multiplicative_inverse,
+    owen_scrambled_radical_inverse, radical_inverse, scrambled_radical_inverse, PRIMES, PRIME_SUMS, PRIME_TABLE_SIZE,
+};
+use crate::core::pbrt::hash;
+use crate::core::rng::Rng;
+use crate::core::sampler::Sampler;
+use std::sync::Arc;
+
+// pixels further apart than this share their sample indices
+const MAX_RESOLUTION: i16 = 128;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum HaltonRandomization {
+    None,
+    // one random permutation of the digits per base
+    PermuteDigits,
+    // every digit permuted depending on the digits before it
+    Owen,
+}
+
+// samples from a single Halton sequence over the whole image. the first two
+// dimensions, scaled by 2^j and 3^k, place the points in the pixels, so each
+// pixel takes every (2^j 3^k)-th point of the sequence.
+#[derive(Clone, Debug)]
+pub struct HaltonSampler {
+    samples_per_pixel: usize,
+    randomization: HaltonRandomization,
+    seed: u64,
+    sample_bounds: Bounds2i,
+    permutations: Arc<Vec<u16>>,
+    base_scales: [u64; 2],
+    base_exponents: [u32; 2],
+    mult_inverse: [u64; 2],
+    sample_stride: u64,
+    pixel_offset: u64,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl HaltonSampler {
+    pub fn new(samples_per_pixel: usize, sample_bounds: Bounds2i, randomization: HaltonRandomization, seed: u64) -> Self {
+        let resolution = sample_bounds.p_max - sample_bounds.p_min;
+        let mut base_scales = [1u64; 2];
+        let mut base_exponents = [0u32; 2];
+        for i in 0..2 {
+            let base = PRIMES[i];
+            let resolution = resolution[i].clamp(1, MAX_RESOLUTION) as u64;
+            while base_scales[i] < resolution {
+                base_scales[i] *= base;
+                base_exponents[i] += 1;
+            }
+        }
+        let mult_inverse = [
+            multiplicative_inverse(base_scales[1] as i64, base_scales[0] as i64),
+            multiplicative_inverse(base_scales[0] as i64, base_scales[1] as i64),
+        ];
+        // the permutations of all bases take a few megabytes
+        let permutations = match randomization {
+            HaltonRandomization::PermuteDigits => compute_radical_inverse_permutations(&mut Rng::new(seed)),
+            _ => Vec::new(),
+        };
+        Self {
+            samples_per_pixel,
+            randomization,
+            seed,
+            sample_bounds,
+            permutations: Arc::new(permutations),
+            base_scales,
+            base_exponents,
+            mult_inverse,
+            sample_stride: base_scales[0] * base_scales[1],
+            pixel_offset: 0,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    // index in the sequence of a sample of the current pixel
+    pub fn halton_index(&self) -> u64 {
+        self.pixel_offset + self.sample_index as u64 * self.sample_stride
+    }
+
+    // first index whose first two dimensions fall into pixel p, found with
+    // the chinese remainder theorem
+    fn pixel_offset(&self, p: Point2i) -> u64 {
+        if self.sample_stride == 1 {
+            return 0;
+        }
+        let p = p - self.sample_bounds.p_min;
+        let pm = [
+            p.x.rem_euclid(MAX_RESOLUTION) as u64,
+            p.y.rem_euclid(MAX_RESOLUTION) as u64,
+        ];
+        let mut offset = 0;
+        for i in 0..2 {
+            let dim_offset = inverse_radical_inverse(PRIMES[i], pm[i], self.base_exponents[i]);
+            offset += dim_offset * (self.sample_stride / self.base_scales[i]) * self.mult_inverse[i];
+        }
+        offset % self.sample_stride
+    }
+
+    fn sample_dimension(&self, dimension: usize) -> f32 {
+        let index = self.halton_index();
+        match dimension {
+            // position inside the pixel, the digits picking the pixel removed
+            0 => radical_inverse(0, index >> self.base_exponents[0]),
+            1 => radical_inverse(1, index / self.base_scales[1]),
+            _ => match self.randomization {
+                HaltonRandomization::None => radical_inverse(dimension, index),
+                HaltonRandomization::PermuteDigits => {
+                    let start = PRIME_SUMS[dimension] as usize;
+                    let perm = &self.permutations[start..start + PRIMES[dimension] as usize];
+                    scrambled_radical_inverse(dimension, index, perm)
+                }
+                HaltonRandomization::Owen => {
+                    owen_scrambled_radical_inverse(dimension, index, hash(&[dimension as u64, self.seed]))
+                }
+            },
+        }
+    }
+
+    fn next_dimension(&mut self) -> usize {
+        // past the prime table the dimensions start over, skipping the pixel
+        if self.dimension >= PRIME_TABLE_SIZE {
+            self.dimension = 2;
+        }
+        self.dimension += 1;
+        self.dimension - 1
+    }
+}
+
+impl Sampler for HaltonSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel_offset = self.pixel_offset(p);
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let dimension = self.next_dimension();
+        self.sample_dimension(dimension)
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        // both values come from consecutive bases
+        if self.dimension + 1 >= PRIME_TABLE_SIZE {
+            self.dimension = 2;
+        }
+        let dimension = self.dimension;
+        self.dimension += 2;
+        Point2f::new(self.sample_dimension(dimension), self.sample_dimension(dimension + 1))
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(self.samples_per_pixel, self.sample_bounds, self.randomization, seed))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::geometry::{Point2f, Point2i};
+use crate::core::rng::Rng;
+use crate::core::sampler::{pixel_sample_rng, Sampler};
+
+// uniform random samples with no correlation between dimensions. every
+// pixel has its own stream and every sample its own part of it, so the
+// values don't depend on the order pixels and samples are visited in.
+#[derive(Clone, Debug)]
+pub struct IndependentSampler {
+    samples_per_pixel: usize,
+    seed: u64,
+    rng: Rng,
+    pixel: Point2i,
+    sample_index: usize,
+}
+
+impl IndependentSampler {
+    pub fn new(samples_per_pixel: usize, seed: u64) -> Self {
+        Self {
+            samples_per_pixel,
+            seed,
+            rng: Rng::default(),
+            pixel: Point2i::default(),
+            sample_index: 0,
+        }
+    }
+
+    fn start_sample(&mut self) {
+        self.rng = pixel_sample_rng(self.pixel, self.sample_index, self.seed);
+    }
+}
+
+impl Sampler for IndependentSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel = p;
+        self.sample_index = 0;
+        self.start_sample();
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.rng.uniform_f32()
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let x = self.rng.uniform_f32();
+        Point2f::new(x, self.rng.uniform_f32())
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.start_sample();
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(self.samples_per_pixel, seed))
+    }
+}
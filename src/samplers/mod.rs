@@ -0,0 +1,13 @@
+pub use halton::*;
+pub use independent::*;
+pub use pmj02::*;
+pub use sobol::*;
+pub use stratified::*;
+pub use zsobol::*;
+
+mod halton;
+mod independent;
+mod pmj02;
+mod sobol;
+mod stratified;
+mod zsobol;
@@ -0,0 +1,121 @@
+use crate::core::geometry::{Point2f, Point2i};
+use crate::core::lowdiscrepancy::{generate_pmj02, permutation_element};
+use crate::core::pbrt::{hash, mix_bits, ONE_MINUS_EPSILON};
+use crate::core::rng::Rng;
+use crate::core::sampler::Sampler;
+use std::sync::Arc;
+
+// blue noise pmj02 point sets shared by all pixels
+pub const N_PMJ02_SETS: usize = 5;
+
+// fraction of 32 fixed point bits
+fn bits_to_f32(v: u32) -> f32 {
+    (v as f32 * (1.0 / 4_294_967_296.0)).min(ONE_MINUS_EPSILON)
+}
+
+// xor with a random value maps elementary intervals onto elementary
+// intervals, so the scrambled points of a set are still a (0, 2)-net
+fn scramble(v: f32, hash: u32) -> f32 {
+    bits_to_f32(((v as f64 * 4_294_967_296.0) as u32) ^ hash)
+}
+
+// 2D samples from a few precomputed progressive multi-jittered (0, 2) sets
+// with blue noise spectra. each pixel and dimension pair picks a set,
+// shuffles its sample order and xor scrambles the points. 1D samples are
+// jittered strata.
+#[derive(Clone, Debug)]
+pub struct Pmj02Sampler {
+    samples_per_pixel: usize,
+    seed: u64,
+    sets: Arc<Vec<Vec<Point2f>>>,
+    pixel: Point2i,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl Pmj02Sampler {
+    // the sample count is rounded up to a power of two
+    pub fn new(samples_per_pixel: usize, seed: u64) -> Self {
+        let samples_per_pixel = samples_per_pixel.max(1).next_power_of_two();
+        let mut rng = Rng::new(seed);
+        let sets = (0..N_PMJ02_SETS).map(|_| generate_pmj02(samples_per_pixel, true, &mut rng)).collect();
+        Self {
+            samples_per_pixel,
+            seed,
+            sets: Arc::new(sets),
+            pixel: Point2i::default(),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    pub fn sets(&self) -> &[Vec<Point2f>] {
+        &self.sets
+    }
+
+    fn hash(&self) -> u64 {
+        hash(&[self.pixel.x as u64, self.pixel.y as u64, self.dimension as u64, self.seed])
+    }
+
+    fn index(&self, hash: u64) -> usize {
+        permutation_element(self.sample_index as u32, self.samples_per_pixel as u32, hash as u32) as usize
+    }
+}
+
+impl Sampler for Pmj02Sampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel = p;
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = self.hash();
+        let index = self.index(hash);
+        self.dimension += 1;
+        let delta = bits_to_f32((hash >> 32) as u32);
+        ((index as f32 + delta) / self.samples_per_pixel as f32).min(ONE_MINUS_EPSILON)
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let hash = self.hash();
+        let index = self.index(hash);
+        let set = if self.dimension / 2 < N_PMJ02_SETS {
+            self.dimension / 2
+        } else {
+            (hash >> 32) as usize % N_PMJ02_SETS
+        };
+        self.dimension += 2;
+        let p = self.sets[set][index];
+        let bits = mix_bits(hash);
+        Point2f::new(scramble(p.x, bits as u32), scramble(p.y, (bits >> 32) as u32))
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        // the point sets don't depend on the seed of the copy
+        Box::new(Self {
+            seed,
+            pixel: Point2i::default(),
+            sample_index: 0,
+            dimension: 0,
+            ..self.clone()
+        })
+    }
+}
@@ -0,0 +1,261 @@
+use crate::core::geometry::{Bounds2i, Point2f, Point2i};
+use crate::core::lowdiscrepancy::{permutation_element, sobol_sample, sobol_sample_bits, SobolScrambler, N_SOBOL_DIMENSIONS};
+use crate::core::pbrt::{hash, ONE_MINUS_EPSILON};
+use crate::core::sampler::Sampler;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SobolRandomization {
+    None,
+    PermuteDigits,
+    FastOwen,
+    Owen,
+}
+
+impl SobolRandomization {
+    pub(crate) fn scrambler(&self, hash: u32) -> SobolScrambler {
+        match *self {
+            SobolRandomization::None => SobolScrambler::None,
+            SobolRandomization::PermuteDigits => SobolScrambler::BinaryPermute(hash),
+            SobolRandomization::FastOwen => SobolScrambler::FastOwen(hash),
+            SobolRandomization::Owen => SobolScrambler::Owen(hash),
+        }
+    }
+}
+
+// fraction of 32 fixed point bits
+fn bits_to_f32(v: u32) -> f32 {
+    (v as f32 * (1.0 / 4_294_967_296.0)).min(ONE_MINUS_EPSILON)
+}
+
+// samples from a single Sobol sequence over the whole image. the first two
+// dimensions scaled by 2^m cover the pixels, every 4^m consecutive points
+// put one point into each pixel.
+#[derive(Clone, Debug)]
+pub struct SobolSampler {
+    samples_per_pixel: usize,
+    randomization: SobolRandomization,
+    seed: u64,
+    sample_bounds: Bounds2i,
+    log2_scale: u32,
+    // index bits selecting the pixel, for each bit of the pixel coordinates
+    pixel_index_columns: Vec<u64>,
+    pixel: Point2i,
+    sample_index: usize,
+    sobol_index: u64,
+    dimension: usize,
+}
+
+impl SobolSampler {
+    pub fn new(samples_per_pixel: usize, sample_bounds: Bounds2i, randomization: SobolRandomization, seed: u64) -> Self {
+        let resolution = sample_bounds.p_max - sample_bounds.p_min;
+        let scale = (resolution.x.max(resolution.y).max(1) as u32).next_power_of_two();
+        let log2_scale = scale.trailing_zeros();
+        Self {
+            samples_per_pixel,
+            randomization,
+            seed,
+            sample_bounds,
+            log2_scale,
+            pixel_index_columns: pixel_index_columns(log2_scale),
+            pixel: Point2i::default(),
+            sample_index: 0,
+            sobol_index: 0,
+            dimension: 0,
+        }
+    }
+
+    pub fn sobol_index(&self) -> u64 {
+        self.sobol_index
+    }
+
+    // index of sample sample_index of the current pixel. the low 2m index
+    // bits are the ones that place the point in the pixel.
+    fn compute_sobol_index(&self) -> u64 {
+        let m = self.log2_scale;
+        let frame = (self.sample_index as u64) << (2 * m);
+        let p = self.pixel - self.sample_bounds.p_min;
+        let target = ((p.x as u64) << m) | p.y as u64;
+        let mut delta = target ^ pixel_key(frame, m);
+        let mut index = frame;
+        for column in self.pixel_index_columns.iter() {
+            if delta & 1 != 0 {
+                index ^= column;
+            }
+            delta >>= 1;
+        }
+        index
+    }
+
+    fn sample_dimension(&self, dimension: usize) -> f32 {
+        match dimension {
+            // position inside the pixel, the bits picking the pixel removed
+            0 | 1 => bits_to_f32(sobol_sample_bits(self.sobol_index, dimension) << self.log2_scale),
+            _ => {
+                let hash = hash(&[dimension as u64, self.seed]) as u32;
+                sobol_sample(self.sobol_index, dimension, self.randomization.scrambler(hash))
+            }
+        }
+    }
+}
+
+// the top m bits of the first two dimensions of sample a, x above y
+fn pixel_key(a: u64, m: u32) -> u64 {
+    if m == 0 {
+        return 0;
+    }
+    let x = (sobol_sample_bits(a, 0) >> (32 - m)) as u64;
+    let y = (sobol_sample_bits(a, 1) >> (32 - m)) as u64;
+    (x << m) | y
+}
+
+// pixel_key is linear over GF(2) in the low 2m bits of the index and, the
+// first two dimensions being a (0, 2)-sequence, invertible. column b of the
+// inverse is the index bits that flip bit b of the key.
+fn pixel_index_columns(m: u32) -> Vec<u64> {
+    let n = 2 * m as usize;
+    let mut rows: Vec<(u64, u64)> = (0..n).map(|c| (pixel_key(1 << c, m), 1 << c)).collect();
+    for bit in 0..n {
+        let pivot = (bit..n)
+            .find(|&i| (rows[i].0 >> bit) & 1 != 0)
+            .expect("first Sobol dimensions are not a (0, 2)-sequence");
+        rows.swap(bit, pivot);
+        let (key, index) = rows[bit];
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != bit && (row.0 >> bit) & 1 != 0 {
+                row.0 ^= key;
+                row.1 ^= index;
+            }
+        }
+    }
+    rows.into_iter().map(|(_, index)| index).collect()
+}
+
+impl Sampler for SobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel = p;
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        if self.dimension >= N_SOBOL_DIMENSIONS {
+            self.dimension = 2;
+        }
+        self.dimension += 1;
+        self.sample_dimension(self.dimension - 1)
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        if self.dimension + 1 >= N_SOBOL_DIMENSIONS {
+            self.dimension = 2;
+        }
+        self.dimension += 2;
+        Point2f::new(
+            self.sample_dimension(self.dimension - 2),
+            self.sample_dimension(self.dimension - 1),
+        )
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.sobol_index = self.compute_sobol_index();
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(self.samples_per_pixel, self.sample_bounds, self.randomization, seed))
+    }
+}
+
+// per pixel stratification from the first two Sobol dimensions, a (0, 2)-
+// sequence. every dimension pair gets its own scrambling and its own
+// shuffle of the sample order, so dimensions aren't correlated.
+#[derive(Clone, Debug)]
+pub struct PaddedSobolSampler {
+    samples_per_pixel: usize,
+    randomization: SobolRandomization,
+    seed: u64,
+    pixel: Point2i,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl PaddedSobolSampler {
+    // the sample count is rounded up to a power of two
+    pub fn new(samples_per_pixel: usize, randomization: SobolRandomization, seed: u64) -> Self {
+        Self {
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two(),
+            randomization,
+            seed,
+            pixel: Point2i::default(),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        hash(&[self.pixel.x as u64, self.pixel.y as u64, self.dimension as u64, self.seed])
+    }
+
+    fn index(&self, hash: u64) -> u64 {
+        permutation_element(self.sample_index as u32, self.samples_per_pixel as u32, hash as u32) as u64
+    }
+}
+
+impl Sampler for PaddedSobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel = p;
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = self.hash();
+        let index = self.index(hash);
+        self.dimension += 1;
+        sobol_sample(index, 0, self.randomization.scrambler((hash >> 32) as u32))
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let hash = self.hash();
+        let index = self.index(hash);
+        self.dimension += 2;
+        Point2f::new(
+            sobol_sample(index, 0, self.randomization.scrambler(hash as u32)),
+            sobol_sample(index, 1, self.randomization.scrambler((hash >> 32) as u32)),
+        )
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(self.samples_per_pixel, self.randomization, seed))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::core::geometry::{Point2f, Point2i};
+use crate::core::rng::Rng;
+use crate::core::sampler::{pixel_sequence_index, Sampler};
+use crate::core::sampling::{latin_hypercube, shuffle, stratified_sample_1d, stratified_sample_2d};
+
+// jittered samples over an x_pixel_samples by y_pixel_samples grid. the first
+// n_sampled_dimensions 1D and 2D dimensions are stratified and shuffled
+// against each other, further dimensions are padded with latin hypercube
+// samples as they are requested.
+#[derive(Clone, Debug)]
+pub struct StratifiedSampler {
+    x_pixel_samples: usize,
+    y_pixel_samples: usize,
+    jitter: bool,
+    n_sampled_dimensions: usize,
+    seed: u64,
+    rng: Rng,
+    sample_index: usize,
+    dimension_1d: usize,
+    dimension_2d: usize,
+    samples_1d: Vec<Vec<f32>>,
+    samples_2d: Vec<Vec<Point2f>>,
+}
+
+impl StratifiedSampler {
+    pub fn new(x_pixel_samples: usize, y_pixel_samples: usize, jitter: bool, n_sampled_dimensions: usize, seed: u64) -> Self {
+        assert!(x_pixel_samples > 0 && y_pixel_samples > 0);
+        Self {
+            x_pixel_samples,
+            y_pixel_samples,
+            jitter,
+            n_sampled_dimensions,
+            seed,
+            rng: Rng::default(),
+            sample_index: 0,
+            dimension_1d: 0,
+            dimension_2d: 0,
+            samples_1d: Vec::new(),
+            samples_2d: Vec::new(),
+        }
+    }
+
+    fn stratified_1d(&mut self) -> Vec<f32> {
+        let mut samples = vec![0.0; self.samples_per_pixel()];
+        stratified_sample_1d(&mut samples, &mut self.rng, self.jitter);
+        shuffle(&mut samples, 1, &mut self.rng);
+        samples
+    }
+
+    fn stratified_2d(&mut self) -> Vec<Point2f> {
+        let mut samples = vec![Point2f::default(); self.samples_per_pixel()];
+        stratified_sample_2d(&mut samples, self.x_pixel_samples, self.y_pixel_samples, &mut self.rng, self.jitter);
+        shuffle(&mut samples, 1, &mut self.rng);
+        samples
+    }
+
+    // past the end of the pixel the last sample is repeated
+    fn array_index(&self) -> usize {
+        self.sample_index.min(self.samples_per_pixel() - 1)
+    }
+
+    fn latin_hypercube_2d(&mut self) -> Vec<Point2f> {
+        let mut values = vec![0.0; 2 * self.samples_per_pixel()];
+        latin_hypercube(&mut values, 2, &mut self.rng);
+        values.chunks(2).map(|v| Point2f::new(v[0], v[1])).collect()
+    }
+}
+
+impl Sampler for StratifiedSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.x_pixel_samples * self.y_pixel_samples
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.rng.set_sequence(pixel_sequence_index(p, self.seed));
+        self.samples_1d.clear();
+        self.samples_2d.clear();
+        for _ in 0..self.n_sampled_dimensions {
+            let samples = self.stratified_1d();
+            self.samples_1d.push(samples);
+            let samples = self.stratified_2d();
+            self.samples_2d.push(samples);
+        }
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        if self.dimension_1d == self.samples_1d.len() {
+            // 1D latin hypercube samples are stratified samples
+            let samples = self.stratified_1d();
+            self.samples_1d.push(samples);
+        }
+        self.dimension_1d += 1;
+        self.samples_1d[self.dimension_1d - 1][self.array_index()]
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        if self.dimension_2d == self.samples_2d.len() {
+            let samples = self.latin_hypercube_2d();
+            self.samples_2d.push(samples);
+        }
+        self.dimension_2d += 1;
+        self.samples_2d[self.dimension_2d - 1][self.array_index()]
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.dimension_1d = 0;
+        self.dimension_2d = 0;
+        self.sample_index = sample_num;
+        sample_num < self.samples_per_pixel()
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(
+            self.x_pixel_samples,
+            self.y_pixel_samples,
+            self.jitter,
+            self.n_sampled_dimensions,
+            seed,
+        ))
+    }
+}
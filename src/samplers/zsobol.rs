@@ -0,0 +1,150 @@
+use crate::core::geometry::{Bounds2i, Point2f, Point2i};
+use crate::core::lowdiscrepancy::sobol_sample;
+use crate::core::pbrt::{hash, mix_bits};
+use crate::core::sampler::Sampler;
+use crate::samplers::SobolRandomization;
+
+// the 24 permutations of a base 4 digit
+static PERMUTATIONS: [[u8; 4]; 24] = [
+    [0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 1, 3], [0, 2, 3, 1], [0, 3, 2, 1], [0, 3, 1, 2],
+    [1, 0, 2, 3], [1, 0, 3, 2], [1, 2, 0, 3], [1, 2, 3, 0], [1, 3, 2, 0], [1, 3, 0, 2],
+    [2, 1, 0, 3], [2, 1, 3, 0], [2, 0, 1, 3], [2, 0, 3, 1], [2, 3, 0, 1], [2, 3, 1, 0],
+    [3, 1, 2, 0], [3, 1, 0, 2], [3, 2, 1, 0], [3, 2, 0, 1], [3, 0, 2, 1], [3, 0, 1, 2],
+];
+
+// interleaves the bits of x and y, x in the even bits
+pub fn encode_morton2(x: u32, y: u32) -> u64 {
+    fn left_shift2(x: u32) -> u64 {
+        let mut x = x as u64;
+        x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+        x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+        x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+        x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+        x
+    }
+    (left_shift2(y) << 1) | left_shift2(x)
+}
+
+// screen space blue noise from a single (0, 2)-sequence, after Ahmed and
+// Wonka. pixels are ranked along a z-order curve whose base 4 digits are
+// randomly permuted per dimension, and each pixel takes the next chunk of
+// samples_per_pixel points. the samples of any aligned 2^k by 2^k block of
+// pixels together form a (0, 2)-net, so the error of neighboring pixels is
+// negatively correlated.
+#[derive(Clone, Debug)]
+pub struct ZSobolSampler {
+    samples_per_pixel: usize,
+    randomization: SobolRandomization,
+    seed: u64,
+    sample_bounds: Bounds2i,
+    log2_samples_per_pixel: u32,
+    n_base4_digits: u32,
+    pixel: Point2i,
+    morton_index: u64,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl ZSobolSampler {
+    // the sample count is rounded up to a power of two
+    pub fn new(samples_per_pixel: usize, sample_bounds: Bounds2i, randomization: SobolRandomization, seed: u64) -> Self {
+        let samples_per_pixel = samples_per_pixel.max(1).next_power_of_two();
+        let log2_samples_per_pixel = samples_per_pixel.trailing_zeros();
+        let d = sample_bounds.p_max - sample_bounds.p_min;
+        let res = (d.x.max(d.y).max(1) as u32).next_power_of_two();
+        Self {
+            samples_per_pixel,
+            randomization,
+            seed,
+            sample_bounds,
+            log2_samples_per_pixel,
+            n_base4_digits: res.trailing_zeros() + log2_samples_per_pixel.div_ceil(2),
+            pixel: Point2i::default(),
+            morton_index: 0,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    // position of the current sample in the sequence
+    pub fn sample_index(&self) -> u64 {
+        let mut index = 0;
+        // an odd power of two leaves a single base 2 digit at the bottom
+        let pow2_samples = self.log2_samples_per_pixel & 1 == 1;
+        let last_digit = if pow2_samples { 1 } else { 0 };
+        let dim_hash = 0x5555_5555u64.wrapping_mul(self.dimension as u64);
+        for i in (last_digit..self.n_base4_digits).rev() {
+            let shift = 2 * i - if pow2_samples { 1 } else { 0 };
+            let digit = (self.morton_index >> shift) & 3;
+            // the permutation depends on the digits above, which keeps the
+            // blocks of the curve contiguous
+            let higher_digits = self.morton_index >> (shift + 2);
+            let p = (mix_bits(higher_digits ^ dim_hash) >> 24) % 24;
+            index |= (PERMUTATIONS[p as usize][digit as usize] as u64) << shift;
+        }
+        if pow2_samples {
+            let digit = self.morton_index & 1;
+            index |= digit ^ (mix_bits((self.morton_index >> 1) ^ dim_hash) & 1);
+        }
+        index
+    }
+
+    fn hash(&self) -> u64 {
+        hash(&[self.dimension as u64, self.seed])
+    }
+
+    fn set_morton_index(&mut self) {
+        // pixel offsets from the bounds are nonnegative
+        let x = (self.pixel.x - self.sample_bounds.p_min.x) as u32;
+        let y = (self.pixel.y - self.sample_bounds.p_min.y) as u32;
+        self.morton_index = (encode_morton2(x, y) << self.log2_samples_per_pixel) | self.sample_index as u64;
+    }
+}
+
+impl Sampler for ZSobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p: Point2i) {
+        self.pixel = p;
+        self.set_sample_number(0);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let index = self.sample_index();
+        self.dimension += 1;
+        let hash = self.hash();
+        sobol_sample(index, 0, self.randomization.scrambler(hash as u32))
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let index = self.sample_index();
+        self.dimension += 2;
+        let hash = self.hash();
+        Point2f::new(
+            sobol_sample(index, 0, self.randomization.scrambler(hash as u32)),
+            sobol_sample(index, 1, self.randomization.scrambler((hash >> 32) as u32)),
+        )
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_index + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num: usize) -> bool {
+        self.sample_index = sample_num;
+        self.dimension = 0;
+        self.set_morton_index();
+        sample_num < self.samples_per_pixel
+    }
+
+    fn current_sample_number(&self) -> usize {
+        self.sample_index
+    }
+
+    fn clone_with_seed(&self, seed: u64) -> Box<dyn Sampler> {
+        Box::new(Self::new(self.samples_per_pixel, self.sample_bounds, self.randomization, seed))
+    }
+}
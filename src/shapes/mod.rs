@@ -0,0 +1,3 @@
+pub use triangle::*;
+
+mod triangle;
@@ -0,0 +1,165 @@
+use crate::core::geometry::{Bounds3f, Point3f, Ray, Vector3f};
+use crate::core::pbrt::{gamma, AtomicFloat};
+use crate::core::shape::Shape;
+use std::sync::Arc;
+
+pub struct TriangleMesh {
+    pub n_triangles: usize,
+    pub n_vertices: usize,
+    pub vertex_indices: Vec<usize>,
+    // plain loads on the intersection path, so threads don't contend on a lock
+    p: Vec<[AtomicFloat; 3]>,
+}
+
+impl TriangleMesh {
+    pub fn new(vertex_indices: Vec<usize>, p: Vec<Point3f>) -> Self {
+        assert_eq!(vertex_indices.len() % 3, 0);
+        assert!(vertex_indices.iter().all(|&i| i < p.len()));
+        Self {
+            n_triangles: vertex_indices.len() / 3,
+            n_vertices: p.len(),
+            vertex_indices,
+            p: p.iter().map(|q| [AtomicFloat::new(q.x), AtomicFloat::new(q.y), AtomicFloat::new(q.z)]).collect(),
+        }
+    }
+
+    pub fn position(&self, i: usize) -> Point3f {
+        let p = &self.p[i];
+        Point3f::new(p[0].get(), p[1].get(), p[2].get())
+    }
+
+    // overwrite the vertex positions in place, the topology stays the same.
+    // this is a phase of its own: no rays may be traced against the mesh
+    // meanwhile, and accelerators built over it have to be refit afterwards.
+    pub fn update_positions(&self, p: &[Point3f]) {
+        assert_eq!(p.len(), self.n_vertices);
+        for (a, q) in self.p.iter().zip(p.iter()) {
+            a[0].set(q.x);
+            a[1].set(q.y);
+            a[2].set(q.z);
+        }
+    }
+}
+
+pub struct Triangle {
+    mesh: Arc<TriangleMesh>,
+    v: usize,
+}
+
+impl Triangle {
+    pub fn new(mesh: Arc<TriangleMesh>, tri_number: usize) -> Self {
+        assert!(tri_number < mesh.n_triangles);
+        Self {
+            mesh,
+            v: 3 * tri_number,
+        }
+    }
+
+    pub fn create_triangle_mesh(mesh: &Arc<TriangleMesh>) -> Vec<Arc<dyn Shape>> {
+        (0..mesh.n_triangles)
+            .map(|i| Arc::new(Triangle::new(mesh.clone(), i)) as Arc<dyn Shape>)
+            .collect()
+    }
+
+    fn vertices(&self) -> (Point3f, Point3f, Point3f) {
+        let v = &self.mesh.vertex_indices[self.v..self.v + 3];
+        (self.mesh.position(v[0]), self.mesh.position(v[1]), self.mesh.position(v[2]))
+    }
+}
+
+impl Shape for Triangle {
+    fn world_bound(&self) -> Bounds3f {
+        let (p0, p1, p2) = self.vertices();
+        let b = Bounds3f::union_from_point(&Bounds3f::from_single_point(p0), p1);
+        Bounds3f::union_from_point(&b, p2)
+    }
+
+    // watertight ray-triangle test from pbrt
+    fn intersect(&self, ray: &Ray) -> Option<f32> {
+        let (p0, p1, p2) = self.vertices();
+
+        // translate vertices based on ray origin
+        let mut p0t = p0 - ray.o;
+        let mut p1t = p1 - ray.o;
+        let mut p2t = p2 - ray.o;
+
+        // permute components so that the ray direction has the largest z
+        let kz = Vector3f::max_dimension(&Vector3f::abs(&ray.d));
+        let kx = if kz + 1 == 3 { 0 } else { kz + 1 };
+        let ky = if kx + 1 == 3 { 0 } else { kx + 1 };
+        let d = Vector3f::permute(ray.d, kx, ky, kz);
+        p0t = Vector3f::permute(p0t, kx, ky, kz);
+        p1t = Vector3f::permute(p1t, kx, ky, kz);
+        p2t = Vector3f::permute(p2t, kx, ky, kz);
+
+        // shear so that the ray direction points along +z
+        let sx = -d.x / d.z;
+        let sy = -d.y / d.z;
+        let sz = 1.0 / d.z;
+        p0t.x += sx * p0t.z;
+        p0t.y += sy * p0t.z;
+        p1t.x += sx * p1t.z;
+        p1t.y += sy * p1t.z;
+        p2t.x += sx * p2t.z;
+        p2t.y += sy * p2t.z;
+
+        // edge functions
+        let mut e0 = p1t.x * p2t.y - p1t.y * p2t.x;
+        let mut e1 = p2t.x * p0t.y - p2t.y * p0t.x;
+        let mut e2 = p0t.x * p1t.y - p0t.y * p1t.x;
+        if e0 == 0.0 || e1 == 0.0 || e2 == 0.0 {
+            let p2txp1ty = p2t.x as f64 * p1t.y as f64;
+            let p2typ1tx = p2t.y as f64 * p1t.x as f64;
+            e0 = (p2typ1tx - p2txp1ty) as f32;
+            let p0txp2ty = p0t.x as f64 * p2t.y as f64;
+            let p0typ2tx = p0t.y as f64 * p2t.x as f64;
+            e1 = (p0typ2tx - p0txp2ty) as f32;
+            let p1txp0ty = p1t.x as f64 * p0t.y as f64;
+            let p1typ0tx = p1t.y as f64 * p0t.x as f64;
+            e2 = (p1typ0tx - p1txp0ty) as f32;
+        }
+        if (e0 < 0.0 || e1 < 0.0 || e2 < 0.0) && (e0 > 0.0 || e1 > 0.0 || e2 > 0.0) {
+            return None;
+        }
+        let det = e0 + e1 + e2;
+        if det == 0.0 {
+            return None;
+        }
+
+        // scaled hit distance, compared against the ray range before dividing
+        p0t.z *= sz;
+        p1t.z *= sz;
+        p2t.z *= sz;
+        let t_scaled = e0 * p0t.z + e1 * p1t.z + e2 * p2t.z;
+        if det < 0.0 && (t_scaled >= 0.0 || t_scaled < ray.t_max * det) {
+            return None;
+        }
+        if det > 0.0 && (t_scaled <= 0.0 || t_scaled > ray.t_max * det) {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let t = t_scaled * inv_det;
+
+        // make sure t is conservatively greater than zero
+        let max_zt = Vector3f::max_component(&Vector3f::abs(&Vector3f::new(p0t.z, p1t.z, p2t.z)));
+        let delta_z = gamma(3) * max_zt;
+        let max_xt = Vector3f::max_component(&Vector3f::abs(&Vector3f::new(p0t.x, p1t.x, p2t.x)));
+        let max_yt = Vector3f::max_component(&Vector3f::abs(&Vector3f::new(p0t.y, p1t.y, p2t.y)));
+        let delta_x = gamma(5) * (max_xt + max_zt);
+        let delta_y = gamma(5) * (max_yt + max_zt);
+        let delta_e = 2.0 * (gamma(2) * max_xt * max_yt + delta_y * max_xt + delta_x * max_yt);
+        let max_e = Vector3f::max_component(&Vector3f::abs(&Vector3f::new(e0, e1, e2)));
+        let delta_t = 3.0
+            * (gamma(3) * max_e * max_zt + delta_e * max_zt + delta_z * max_e)
+            * inv_det.abs();
+        if t <= delta_t {
+            return None;
+        }
+        Some(t)
+    }
+
+    fn area(&self) -> f32 {
+        let (p0, p1, p2) = self.vertices();
+        0.5 * Vector3f::cross(&(p1 - p0), &(p2 - p0)).length()
+    }
+}
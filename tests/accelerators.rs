@@ -0,0 +1,432 @@
+mod accelerators_tests {
+    use rust_my_pbrt::accelerators::*;
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::primitive::*;
+    use rust_my_pbrt::core::transform::*;
+    use rust_my_pbrt::shapes::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // small deterministic generator so the tests don't need a rng crate
+    fn next_f32(state: &mut u32) -> f32 {
+        *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        (*state >> 8) as f32 / (1 << 24) as f32
+    }
+
+    // n x n grid of quads on the z = 0 plane, two triangles each
+    fn make_grid(n: usize) -> (Vec<usize>, Vec<Point3f>) {
+        let mut p = Vec::new();
+        for j in 0..=n {
+            for i in 0..=n {
+                p.push(Point3f::new(i as f32, j as f32, 0.0));
+            }
+        }
+        let mut indices = Vec::new();
+        for j in 0..n {
+            for i in 0..n {
+                let v = j * (n + 1) + i;
+                indices.extend_from_slice(&[v, v + 1, v + n + 2, v, v + n + 2, v + n + 1]);
+            }
+        }
+        (indices, p)
+    }
+
+    fn make_primitives(mesh: &Arc<TriangleMesh>) -> Vec<Arc<dyn Primitive>> {
+        Triangle::create_triangle_mesh(mesh)
+            .into_iter()
+            .map(|s| Arc::new(GeometricPrimitive::new(s)) as Arc<dyn Primitive>)
+            .collect()
+    }
+
+    fn brute_force(prims: &[Arc<dyn Primitive>], ray: &mut Ray) -> bool {
+        let mut hit = false;
+        for p in prims {
+            if p.intersect(ray) {
+                hit = true;
+            }
+        }
+        hit
+    }
+
+    // n small triangles scattered through [0, 8]^3, like a particle system
+    fn make_particles(n: usize, seed: u32) -> (Vec<usize>, Vec<Point3f>) {
+        let mut state = seed;
+        let mut p = Vec::new();
+        for _ in 0..n {
+            let c = Point3f::new(
+                next_f32(&mut state) * 8.0,
+                next_f32(&mut state) * 8.0,
+                next_f32(&mut state) * 8.0,
+            );
+            for _ in 0..3 {
+                p.push(Point3f::new(
+                    c.x + next_f32(&mut state) * 0.6 - 0.3,
+                    c.y + next_f32(&mut state) * 0.6 - 0.3,
+                    c.z + next_f32(&mut state) * 0.6 - 0.3,
+                ));
+            }
+        }
+        ((0..3 * n).collect(), p)
+    }
+
+    struct CountingPrimitive {
+        primitive: Arc<dyn Primitive>,
+        count: Arc<AtomicUsize>,
+    }
+
+    impl Primitive for CountingPrimitive {
+        fn world_bound(&self) -> Bounds3f {
+            self.primitive.world_bound()
+        }
+
+        fn intersect(&self, ray: &mut Ray) -> bool {
+            self.count.fetch_add(1, Ordering::Relaxed);
+            self.primitive.intersect(ray)
+        }
+
+        fn intersect_p(&self, ray: &Ray) -> bool {
+            self.count.fetch_add(1, Ordering::Relaxed);
+            self.primitive.intersect_p(ray)
+        }
+    }
+
+    fn check_against_brute_force(
+        bvh: &dyn Primitive,
+        prims: &[Arc<dyn Primitive>],
+        seed: u32,
+        tolerance: f32,
+    ) {
+        let mut state = seed;
+        for _ in 0..200 {
+            let o = Point3f::new(
+                next_f32(&mut state) * 10.0 - 1.0,
+                next_f32(&mut state) * 10.0 - 1.0,
+                5.0,
+            );
+            let target = Point3f::new(
+                next_f32(&mut state) * 8.0,
+                next_f32(&mut state) * 8.0,
+                next_f32(&mut state) - 0.5,
+            );
+            let ray = Ray::new(o, target - o, f32::INFINITY, 0.0, None);
+            let mut r0 = ray;
+            let mut r1 = ray;
+            let hit = brute_force(prims, &mut r1);
+            assert_eq!(bvh.intersect(&mut r0), hit);
+            if hit {
+                assert!((r0.t_max - r1.t_max).abs() <= tolerance * r1.t_max);
+            }
+            assert_eq!(bvh.intersect_p(&ray), hit);
+        }
+    }
+
+    #[test]
+    fn check_bvh_intersect() {
+        let (indices, p) = make_grid(8);
+        let mesh = Arc::new(TriangleMesh::new(indices, p));
+        let prims = make_primitives(&mesh);
+        for &method in &[SplitMethod::SAH, SplitMethod::Middle, SplitMethod::EqualCounts] {
+            let bvh = BVHAccel::new(prims.clone(), 4, method);
+            assert_eq!(
+                bvh.world_bound(),
+                Bounds3f::new(Point3f::new(0.0, 0.0, 0.0), Point3f::new(8.0, 8.0, 0.0))
+            );
+            check_against_brute_force(&bvh, &prims, 7, 1e-5);
+        }
+
+        let mut ray = Ray::new(
+            Point3f::new(2.5, 3.5, 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            f32::INFINITY,
+            0.0,
+            None,
+        );
+        let bvh = BVHAccel::new(prims, 4, SplitMethod::SAH);
+        assert!(bvh.intersect(&mut ray));
+        assert!((ray.t_max - 1.0).abs() < 1e-6);
+        ray.o = Point3f::new(20.0, 3.5, 1.0);
+        assert!(!bvh.intersect_p(&ray));
+    }
+
+    #[test]
+    fn check_bvh_large_leaf() {
+        // coincident triangles share a centroid and end up in a single leaf
+        let n = 70000;
+        let p = vec![Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 0.0, 0.0), Point3f::new(0.0, 1.0, 0.0)];
+        let mesh = Arc::new(TriangleMesh::new([0, 1, 2].repeat(n), p));
+        let count = Arc::new(AtomicUsize::new(0));
+        let prims: Vec<Arc<dyn Primitive>> = make_primitives(&mesh)
+            .into_iter()
+            .map(|primitive| {
+                let count = count.clone();
+                Arc::new(CountingPrimitive { primitive, count }) as Arc<dyn Primitive>
+            })
+            .collect();
+        let bvh = BVHAccel::new(prims, 4, SplitMethod::SAH);
+        assert_eq!(bvh.node_count(), 1);
+        let mut ray = Ray::new(
+            Point3f::new(0.9, 0.9, 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            f32::INFINITY,
+            0.0,
+            None,
+        );
+        assert!(!bvh.intersect(&mut ray));
+        assert_eq!(count.load(Ordering::Relaxed), n);
+    }
+
+    struct UnboundedPrimitive;
+
+    impl Primitive for UnboundedPrimitive {
+        fn world_bound(&self) -> Bounds3f {
+            let inf = f32::INFINITY;
+            Bounds3f::new(Point3f::new(-inf, -inf, -inf), Point3f::new(inf, inf, inf))
+        }
+
+        fn intersect(&self, _ray: &mut Ray) -> bool {
+            false
+        }
+
+        fn intersect_p(&self, _ray: &Ray) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn check_bvh_degenerate_primitives() {
+        // infinite bounds have a NaN centroid
+        let (indices, p) = make_grid(2);
+        let mut prims = make_primitives(&Arc::new(TriangleMesh::new(indices, p)));
+        prims.push(Arc::new(UnboundedPrimitive));
+        prims.push(Arc::new(UnboundedPrimitive));
+        for &method in &[SplitMethod::SAH, SplitMethod::Middle, SplitMethod::EqualCounts] {
+            let bvh = BVHAccel::new(prims.clone(), 1, method);
+            let ray = Ray::new(Point3f::new(0.5, 0.25, 1.0), Vector3f::new(0.0, 0.0, -1.0), f32::INFINITY, 0.0, None);
+            assert!(bvh.intersect_p(&ray));
+        }
+
+        // centroids at powers of three make middle splits peel off one
+        // triangle per level, a much deeper tree than the traversal stack
+        let n = 150;
+        let x = |i: usize| 3f32.powi(i as i32 - 78);
+        let p: Vec<Point3f> = (0..n)
+            .flat_map(|i| {
+                let x0 = x(i);
+                vec![Point3f::new(x0, 0.0, 0.0), Point3f::new(2.0 * x0, 0.0, 0.0), Point3f::new(x0, x0, 0.0)]
+            })
+            .collect();
+        let prims = make_primitives(&Arc::new(TriangleMesh::new((0..3 * n).collect(), p)));
+        let bvh = BVHAccel::new(prims, 1, SplitMethod::Middle);
+        assert_eq!(bvh.max_depth(), n - 1);
+        // scales the watertight test resolves from a unit distance, the
+        // smallest of them sits 79 levels down
+        for i in 70..100 {
+            let o = Point3f::new(1.05 * x(i), 0.01 * x(i), 1.0);
+            let mut ray = Ray::new(o, Vector3f::new(0.0, 0.0, -1.0), f32::INFINITY, 0.0, None);
+            assert!(bvh.intersect_p(&ray));
+            assert!(bvh.intersect(&mut ray));
+            assert!((ray.t_max - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn check_bvh_refit() {
+        let (indices, p) = make_grid(8);
+        let mesh = Arc::new(TriangleMesh::new(indices, p.clone()));
+        let prims = make_primitives(&mesh);
+        let mut bvh = BVHAccel::new(prims.clone(), 2, SplitMethod::SAH);
+        let n_nodes = bvh.node_count();
+
+        // a small wave keeps the topology good enough, refit must not rebuild
+        let moved: Vec<Point3f> = p
+            .iter()
+            .map(|q| Point3f::new(q.x, q.y, 0.25 * (q.x + q.y).sin()))
+            .collect();
+        mesh.update_positions(&moved);
+        assert!((0..mesh.n_vertices).all(|i| mesh.position(i) == moved[i]));
+        assert!(!bvh.update(1.5));
+        assert_eq!(bvh.node_count(), n_nodes);
+        let expected = prims
+            .iter()
+            .fold(Bounds3f::empty(), |b, p| Bounds3f::union(&b, &p.world_bound()));
+        assert_eq!(bvh.world_bound(), expected);
+        check_against_brute_force(&bvh, &prims, 11, 1e-5);
+    }
+
+    #[test]
+    fn check_bvh_rebuild_heuristic() {
+        let (indices, p) = make_grid(8);
+        let mesh = Arc::new(TriangleMesh::new(indices, p.clone()));
+        let prims = make_primitives(&mesh);
+        let mut bvh = BVHAccel::new(prims.clone(), 2, SplitMethod::SAH);
+        let build_cost = bvh.build_cost();
+        assert!((bvh.sah_cost() - build_cost).abs() < 1e-4);
+
+        // scattering the vertices makes neighbouring leaves overlap badly
+        let mut state = 3;
+        let scrambled: Vec<Point3f> = p
+            .iter()
+            .map(|_| Point3f::new(next_f32(&mut state) * 8.0, next_f32(&mut state) * 8.0, 0.0))
+            .collect();
+        mesh.update_positions(&scrambled);
+        bvh.refit();
+        assert!(bvh.sah_cost() > build_cost);
+        assert!(bvh.needs_rebuild(1.5));
+        // slivers from the scattering make t less accurate between triangles
+        check_against_brute_force(&bvh, &prims, 13, 1e-2);
+
+        assert!(bvh.update(1.5));
+        assert!(!bvh.needs_rebuild(1.5));
+        check_against_brute_force(&bvh, &prims, 17, 1e-2);
+    }
+
+    #[test]
+    fn check_grid_intersect() {
+        let (indices, p) = make_particles(300, 5);
+        let mesh = Arc::new(TriangleMesh::new(indices, p));
+        let prims = make_primitives(&mesh);
+        for &mailboxing in &[false, true] {
+            let grid = GridAccel::new(prims.clone(), mailboxing);
+            let expected = prims
+                .iter()
+                .fold(Bounds3f::empty(), |b, p| Bounds3f::union(&b, &p.world_bound()));
+            assert_eq!(grid.world_bound(), expected);
+            check_against_brute_force(&grid, &prims, 19, 1e-5);
+        }
+
+        let (indices, p) = make_grid(8);
+        let mesh = Arc::new(TriangleMesh::new(indices, p));
+        let prims = make_primitives(&mesh);
+        let grid = GridAccel::new(prims.clone(), true);
+        assert_eq!(grid.n_voxels().z, 1);
+        check_against_brute_force(&grid, &prims, 23, 1e-5);
+    }
+
+    #[test]
+    fn check_grid_voxels() {
+        let (indices, p) = make_particles(100, 29);
+        let mesh = Arc::new(TriangleMesh::new(indices, p));
+        let prims = make_primitives(&mesh);
+        let grid = GridAccel::new(prims.clone(), false);
+        let n = grid.n_voxels();
+        assert_eq!(grid.voxel_bounds(), Bounds3i::new(Point3i::new(0, 0, 0), n));
+
+        // every primitive is listed exactly in the cells its bounds touch
+        for z in 0..n.z {
+            for y in 0..n.y {
+                for x in 0..n.x {
+                    let cell = Point3i::new(x, y, z);
+                    let cell_bounds = grid.voxel_world_bound(&cell);
+                    for (i, p) in prims.iter().enumerate() {
+                        let b = p.world_bound();
+                        let v_min = grid.pos_to_voxel(&b.p_min);
+                        let v_max = grid.pos_to_voxel(&b.p_max);
+                        let listed = grid.voxel(&cell).contains(&i);
+                        assert_eq!(listed, Bounds3i::inside(&cell, &Bounds3i::new(v_min, v_max)));
+                        if listed {
+                            let o = Bounds3f::intersect(&cell_bounds, &b);
+                            assert!(!Bounds3f::expand(&o, 1e-4).is_empty());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_grid_mailboxing() {
+        let (indices, p) = make_particles(300, 31);
+        let mesh = Arc::new(TriangleMesh::new(indices, p));
+        let count = Arc::new(AtomicUsize::new(0));
+        let prims: Vec<Arc<dyn Primitive>> = make_primitives(&mesh)
+            .into_iter()
+            .map(|primitive| {
+                Arc::new(CountingPrimitive {
+                    primitive,
+                    count: count.clone(),
+                }) as Arc<dyn Primitive>
+            })
+            .collect();
+
+        let mut tests = [0; 2];
+        for (k, &mailboxing) in [false, true].iter().enumerate() {
+            let grid = GridAccel::new(prims.clone(), mailboxing);
+            count.store(0, Ordering::Relaxed);
+            let mut state = 37;
+            for _ in 0..100 {
+                let o = Point3f::new(-1.0, next_f32(&mut state) * 8.0, next_f32(&mut state) * 8.0);
+                let d = Vector3f::new(1.0, next_f32(&mut state) * 0.2, next_f32(&mut state) * 0.2);
+                let mut ray = Ray::new(o, d, f32::INFINITY, 0.0, None);
+                grid.intersect(&mut ray);
+            }
+            tests[k] = count.load(Ordering::Relaxed);
+        }
+        assert!(tests[1] < tests[0]);
+    }
+
+    #[test]
+    fn check_instancing() {
+        // one unit quad at z = 0 shared by every instance
+        let mesh = Arc::new(TriangleMesh::new(
+            vec![0, 1, 2, 0, 2, 3],
+            vec![
+                Point3f::new(0.0, 0.0, 0.0),
+                Point3f::new(1.0, 0.0, 0.0),
+                Point3f::new(1.0, 1.0, 0.0),
+                Point3f::new(0.0, 1.0, 0.0),
+            ],
+        ));
+        let blas: Arc<dyn Primitive> = Arc::new(BVHAccel::new(make_primitives(&mesh), 4, SplitMethod::SAH));
+
+        let mut instances: Vec<Arc<dyn Primitive>> = Vec::new();
+        for i in 0..10 {
+            let t = Transform::translate(&Vector3f::new(2.0 * i as f32, 0.0, i as f32))
+                * Transform::scale(1.5, 1.5, 1.5);
+            instances.push(Arc::new(TransformedPrimitive::new(
+                blas.clone(),
+                AnimatedTransform::from_transform(t),
+            )));
+        }
+        let tlas = BVHAccel::new(instances.clone(), 2, SplitMethod::SAH);
+        assert_eq!(
+            tlas.world_bound(),
+            Bounds3f::new(Point3f::new(0.0, 0.0, 0.0), Point3f::new(19.5, 1.5, 9.0))
+        );
+
+        for i in 0..10 {
+            let mut ray = Ray::new(
+                Point3f::new(2.0 * i as f32 + 1.2, 1.2, -5.0),
+                Vector3f::new(0.0, 0.0, 2.0),
+                f32::INFINITY,
+                0.0,
+                None,
+            );
+            assert!(tlas.intersect_p(&ray));
+            assert!(tlas.intersect(&mut ray));
+            assert!((ray.t_max - (i as f32 + 5.0) / 2.0).abs() < 1e-5);
+        }
+        // misses the scaled quad of instance 3 by falling in the gap
+        let ray = Ray::new(Point3f::new(7.7, 0.5, -5.0), Vector3f::new(0.0, 0.0, 1.0), f32::INFINITY, 0.0, None);
+        assert!(!tlas.intersect_p(&ray));
+
+        // an instance sliding along +y over the shutter interval
+        let moving = TransformedPrimitive::new(
+            blas,
+            AnimatedTransform::new(
+                Transform::translate(&Vector3f::new(0.0, 0.0, 0.0)),
+                0.0,
+                Transform::translate(&Vector3f::new(0.0, 4.0, 0.0)),
+                1.0,
+            ),
+        );
+        assert_eq!(
+            moving.world_bound(),
+            Bounds3f::new(Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 5.0, 0.0))
+        );
+        let mut ray = Ray::new(Point3f::new(0.5, 4.5, -1.0), Vector3f::new(0.0, 0.0, 1.0), f32::INFINITY, 0.0, None);
+        assert!(!moving.intersect(&mut ray));
+        ray.time = 1.0;
+        assert!(moving.intersect(&mut ray));
+        assert!((ray.t_max - 1.0).abs() < 1e-5);
+    }
+}
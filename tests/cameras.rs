@@ -0,0 +1,437 @@
+mod cameras_tests {
+    use rust_my_pbrt::cameras::*;
+    use rust_my_pbrt::core::camera::*;
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::pbrt::radians;
+    use rust_my_pbrt::core::transform::*;
+
+    fn assert_vector_near(a: Vector3f, b: Vector3f) {
+        assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_point_near(a: Point3f, b: Point3f) {
+        assert!(Point3f::distance(a, b) < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    fn identity() -> AnimatedTransform {
+        AnimatedTransform::from_transform(Transform::default())
+    }
+
+    fn sample(x: f32, y: f32) -> CameraSample {
+        CameraSample {
+            p_film: Point2f::new(x, y),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn check_perspective_camera() {
+        let res = Point2i::new(200, 100);
+        let camera = PerspectiveCamera::from_resolution(identity(), res, 60.0, 0.0, 1.0, None);
+
+        let (wt, ray) = camera.generate_ray(&sample(100.0, 50.0));
+        assert_eq!(wt, 1.0);
+        assert_point_near(ray.o, Point3f::new(0.0, 0.0, 0.0));
+        assert_vector_near(ray.d, Vector3f::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.t_max, f32::INFINITY);
+
+        // fov spans the shorter (vertical) axis, raster y points down
+        let (_, top) = camera.generate_ray(&sample(100.0, 0.0));
+        let half_fov = radians(30.0);
+        assert_vector_near(top.d, Vector3f::new(0.0, half_fov.sin(), half_fov.cos()));
+        let (_, right) = camera.generate_ray(&sample(200.0, 50.0));
+        let x = 2.0 * half_fov.tan();
+        assert_vector_near(right.d, Vector3f::normalize(&Vector3f::new(x, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn check_perspective_camera_transform_and_time() {
+        let pos = Point3f::new(1.0, 2.0, 3.0);
+        let look = Point3f::new(1.0, 2.0, -3.0);
+        let world_to_camera = Transform::look_at(&pos, &look, &Vector3f::new(0.0, 1.0, 0.0));
+        let camera_to_world = AnimatedTransform::from_transform(Transform::inverse(&world_to_camera));
+        let screen = Bounds2f::new(Point2f::new(-1.0, -1.0), Point2f::new(1.0, 1.0));
+        let camera = PerspectiveCamera::new(camera_to_world, screen, Point2i::new(64, 64), 90.0, 0.25, 0.75, None);
+
+        let mut s = sample(32.0, 32.0);
+        s.time = 0.5;
+        let (_, ray) = camera.generate_ray(&s);
+        assert_point_near(ray.o, pos);
+        assert_vector_near(ray.d, Vector3f::new(0.0, 0.0, -1.0));
+        assert_eq!(ray.time, 0.5);
+        s.time = 0.0;
+        assert_eq!(camera.generate_ray(&s).1.time, 0.25);
+        s.time = 1.0;
+        assert_eq!(camera.generate_ray(&s).1.time, 0.75);
+    }
+
+    #[test]
+    fn check_perspective_ray_differentials() {
+        let t = Transform::translate(&Vector3f::new(0.5, -1.0, 2.0)) * Transform::rotate_y(30.0);
+        let camera = PerspectiveCamera::from_resolution(
+            AnimatedTransform::from_transform(t),
+            Point2i::new(320, 240),
+            45.0,
+            0.0,
+            1.0,
+            None,
+        );
+        let s = sample(37.5, 201.25);
+        let (wt, rd) = camera.generate_ray_differential(&s);
+        assert_eq!(wt, 1.0);
+        assert!(rd.has_differentials);
+        let (_, ray) = camera.generate_ray(&s);
+        assert_eq!(*rd.ray(), ray);
+
+        let (_, rx) = camera.generate_ray(&sample(38.5, 201.25));
+        let (_, ry) = camera.generate_ray(&sample(37.5, 202.25));
+        assert_point_near(rd.rx_origin, rx.o);
+        assert_point_near(rd.ry_origin, ry.o);
+        assert_vector_near(rd.rx_direction, rx.d);
+        assert_vector_near(rd.ry_direction, ry.d);
+    }
+
+    // deterministic stratified points in [0, 1)^2
+    fn grid_samples(n: usize) -> Vec<Point2f> {
+        let mut u = Vec::new();
+        for j in 0..n {
+            for i in 0..n {
+                u.push(Point2f::new((i as f32 + 0.5) / n as f32, (j as f32 + 0.5) / n as f32));
+            }
+        }
+        u
+    }
+
+    #[test]
+    fn check_thin_lens() {
+        let focal_distance = 5.0;
+        let camera = PerspectiveCamera::from_resolution(identity(), Point2i::new(100, 100), 60.0, 0.0, 1.0, None)
+            .with_lens(ThinLens::new(0.2, focal_distance, Aperture::Circular));
+
+        let p_film = Point2f::new(30.0, 70.0);
+        let mut focus_points = Vec::new();
+        for u in grid_samples(8) {
+            let s = CameraSample { p_film, p_lens: u, time: 0.0 };
+            let (wt, ray) = camera.generate_ray(&s);
+            assert_eq!(wt, 1.0);
+            assert_eq!(ray.o.z, 0.0);
+            assert!(Point3f::distance(ray.o, Point3f::new(0.0, 0.0, 0.0)) <= 0.2 + 1e-5);
+            focus_points.push(ray.point(focal_distance / ray.d.z));
+
+            // differentials share the lens point and focus like the main ray
+            let (_, rd) = camera.generate_ray_differential(&s);
+            let (_, rx) = camera.generate_ray(&CameraSample { p_film: p_film + Vector2f::new(1.0, 0.0), p_lens: u, time: 0.0 });
+            assert_point_near(rd.rx_origin, rx.o);
+            assert_vector_near(rd.rx_direction, rx.d);
+        }
+        // everything on the plane of focus is sharp
+        for p in &focus_points {
+            assert_point_near(*p, focus_points[0]);
+        }
+    }
+
+    #[test]
+    fn check_polygonal_aperture() {
+        let blades = 6;
+        let aperture = Aperture::polygonal(blades, 0.0);
+        let inradius = (std::f32::consts::PI / blades as f32).cos();
+        let mut max_r: f32 = 0.0;
+        for u in grid_samples(32) {
+            let p = aperture.sample(&u);
+            let r = (p.x * p.x + p.y * p.y).sqrt();
+            max_r = max_r.max(r);
+            // inside the hexagon: within the inradius along every edge normal
+            for k in 0..blades {
+                let phi = 2.0 * std::f32::consts::PI * (k as f32 + 0.5) / blades as f32;
+                assert!(p.x * phi.cos() + p.y * phi.sin() <= inradius + 1e-5);
+            }
+        }
+        assert!(max_r > 0.9);
+    }
+
+    #[test]
+    fn check_image_aperture() {
+        // 4x4 mask with only the top right quarter open
+        let mut mask = vec![0.0; 16];
+        for y in 0..2 {
+            for x in 2..4 {
+                mask[y * 4 + x] = 1.0;
+            }
+        }
+        let aperture = Aperture::Image(ImageAperture::new(&mask, 4, 4));
+        let samples = grid_samples(16);
+        let mut mean = Point2f::new(0.0, 0.0);
+        for u in &samples {
+            let p = aperture.sample(u);
+            assert!(p.x >= 0.0 && p.x <= 1.0 && p.y >= 0.0 && p.y <= 1.0, "{:?}", p);
+            mean = Point2f::add_element(mean, p / samples.len() as f32);
+        }
+        assert!((mean.x - 0.5).abs() < 1e-2 && (mean.y - 0.5).abs() < 1e-2);
+
+        // the bokeh of a defocused point takes the shape of the aperture
+        let lens = ThinLens::new(1.0, 10.0, aperture);
+        for u in &samples {
+            let p = lens.sample_lens(u);
+            assert!(p.x >= 0.0 && p.y >= 0.0);
+        }
+    }
+
+    #[test]
+    fn check_orthographic_camera() {
+        let screen = Bounds2f::new(Point2f::new(-4.0, -2.0), Point2f::new(4.0, 2.0));
+        let camera = OrthographicCamera::new(identity(), screen, Point2i::new(80, 40), 0.0, 1.0, None)
+            .with_clipping(1.0, 11.0);
+
+        let (wt, ray) = camera.generate_ray(&sample(0.0, 0.0));
+        assert_eq!(wt, 1.0);
+        assert_point_near(ray.o, Point3f::new(-4.0, 2.0, 1.0));
+        assert_eq!(ray.d, Vector3f::new(0.0, 0.0, 1.0));
+        assert_point_near(ray.point(ray.t_max), Point3f::new(-4.0, 2.0, 11.0));
+
+        let (_, center) = camera.generate_ray(&sample(40.0, 20.0));
+        assert_point_near(center.o, Point3f::new(0.0, 0.0, 1.0));
+
+        // parallel rays, one pixel apart in origin
+        let (_, rd) = camera.generate_ray_differential(&sample(10.0, 30.0));
+        assert!(rd.has_differentials);
+        assert_eq!(rd.rx_direction, rd.ray().d);
+        assert_eq!(rd.ry_direction, rd.ray().d);
+        assert_vector_near(rd.rx_origin - rd.ray().o, Vector3f::new(0.1, 0.0, 0.0));
+        assert_vector_near(rd.ry_origin - rd.ray().o, Vector3f::new(0.0, -0.1, 0.0));
+
+        let unclipped = OrthographicCamera::new(identity(), screen, Point2i::new(80, 40), 0.0, 1.0, None);
+        assert_eq!(unclipped.generate_ray(&sample(3.0, 3.0)).1.t_max, f32::INFINITY);
+    }
+
+    #[test]
+    fn check_orthographic_camera_transform_and_lens() {
+        let t = Transform::translate(&Vector3f::new(1.0, 2.0, 3.0)) * Transform::rotate_x(90.0);
+        let screen = Bounds2f::new(Point2f::new(-1.0, -1.0), Point2f::new(1.0, 1.0));
+        let camera = OrthographicCamera::new(AnimatedTransform::from_transform(t), screen, Point2i::new(10, 10), 0.0, 1.0, None)
+            .with_clipping(0.5, 20.0)
+            .with_lens(ThinLens::new(0.1, 4.0, Aperture::Circular));
+
+        // rays start on the lens around the pixel, off axis too
+        for &(p_film, center) in [(Point2f::new(2.5, 7.5), Point2f::new(-0.5, -0.5)), (Point2f::new(9.5, 0.5), Point2f::new(0.9, 0.9))].iter() {
+            let focus = Point3f::new(center.x, center.y, 4.5);
+            let expected_focus = t.transform_point(&focus);
+            for u in grid_samples(4) {
+                let s = CameraSample { p_film, p_lens: u, time: 0.0 };
+                let (_, ray) = camera.generate_ray(&s);
+                let local = Transform::inverse(&t).transform_ray(&ray);
+                assert!((local.o.z - 0.5).abs() < 1e-5);
+                assert!(Point2f::distance(Point2f::new(local.o.x, local.o.y), center) <= 0.1 + 1e-5);
+                assert_point_near(local.point(4.0 / local.d.z), focus);
+                assert_point_near(ray.point(4.0 / local.d.z), expected_focus);
+                assert!((local.point(local.t_max).z - 20.0).abs() < 1e-4);
+
+                let (_, rd) = camera.generate_ray_differential(&s);
+                let (_, ry) = camera.generate_ray(&CameraSample { p_film: p_film + Vector2f::new(0.0, 1.0), p_lens: u, time: 0.0 });
+                assert_point_near(rd.ry_origin, ry.o);
+                assert_vector_near(rd.ry_direction, ry.d);
+            }
+        }
+    }
+
+    #[test]
+    fn check_environment_mappings() {
+        let mappings = [
+            EnvironmentMapping::Equirectangular,
+            EnvironmentMapping::EqualArea,
+            EnvironmentMapping::Fisheye { fov: 180.0 },
+            EnvironmentMapping::CubeMap,
+        ];
+        for mapping in &mappings {
+            for u in grid_samples(12) {
+                let d = mapping.direction(&u, 1.0).unwrap_or_else(|| Vector3f::new(0.0, 0.0, 1.0));
+                assert!((d.length() - 1.0).abs() < 1e-4);
+                if mapping.direction(&u, 1.0).is_none() {
+                    continue;
+                }
+                let uv = mapping.film_position(&d, 1.0).unwrap();
+                assert!(Point2f::distance(uv, u) < 1e-3, "{:?} {:?} {:?}", mapping, u, uv);
+            }
+        }
+
+        let forward = Vector3f::new(0.0, 0.0, 1.0);
+        let center = Point2f::new(0.5, 0.5);
+        assert_vector_near(EnvironmentMapping::EqualArea.direction(&center, 1.0).unwrap(), forward);
+        assert_vector_near(EnvironmentMapping::Fisheye { fov: 120.0 }.direction(&center, 1.0).unwrap(), forward);
+        let cube_center = Point2f::new(4.5 / 6.0, 0.5);
+        assert_vector_near(EnvironmentMapping::CubeMap.direction(&cube_center, 6.0).unwrap(), forward);
+        assert_vector_near(
+            EnvironmentMapping::Equirectangular.direction(&Point2f::new(0.0, 0.0), 2.0).unwrap(),
+            Vector3f::new(0.0, 1.0, 0.0),
+        );
+        assert_vector_near(
+            EnvironmentMapping::Equirectangular.direction(&Point2f::new(0.25, 0.5), 2.0).unwrap(),
+            forward,
+        );
+
+        // the fisheye edge lies at half the fov, corners are outside the circle
+        let fisheye = EnvironmentMapping::Fisheye { fov: 120.0 };
+        let edge = fisheye.direction(&Point2f::new(1.0, 0.5), 1.0).unwrap();
+        assert!((edge.z - radians(60.0).cos()).abs() < 1e-5);
+        assert!(fisheye.direction(&Point2f::new(0.02, 0.02), 1.0).is_none());
+        assert!(fisheye.film_position(&Vector3f::new(0.0, 0.0, -1.0), 1.0).is_none());
+
+        // equal area: every pixel covers the same solid angle, so directions
+        // of a regular grid average out to nothing
+        let mut mean = Vector3f::new(0.0, 0.0, 0.0);
+        let samples = grid_samples(64);
+        for u in &samples {
+            mean += EnvironmentMapping::EqualArea.direction(u, 1.0).unwrap() / samples.len() as f32;
+        }
+        assert!(mean.length() < 1e-3);
+    }
+
+    #[test]
+    fn check_environment_camera() {
+        let t = Transform::translate(&Vector3f::new(1.0, 2.0, 3.0)) * Transform::rotate_y(90.0);
+        let res = Point2i::new(120, 20);
+        let camera = EnvironmentCamera::new(
+            AnimatedTransform::from_transform(t),
+            res,
+            EnvironmentMapping::CubeMap,
+            0.0,
+            1.0,
+            None,
+        );
+        let (wt, ray) = camera.generate_ray(&sample(90.0, 10.0));
+        assert_eq!(wt, 1.0);
+        assert_point_near(ray.o, Point3f::new(1.0, 2.0, 3.0));
+        assert_vector_near(ray.d, t.transform_vector(&Vector3f::new(0.0, 0.0, 1.0)));
+        let p = camera.raster_position(&ray.d, 0.0).unwrap();
+        assert!(Point2f::distance(p, Point2f::new(90.0, 10.0)) < 1e-3);
+
+        let fisheye = EnvironmentCamera::new(
+            AnimatedTransform::from_transform(Transform::default()),
+            Point2i::new(64, 64),
+            EnvironmentMapping::Fisheye { fov: 190.0 },
+            0.0,
+            1.0,
+            None,
+        );
+        assert_eq!(fisheye.generate_ray(&sample(0.5, 0.5)).0, 0.0);
+        let (wt, rd) = fisheye.generate_ray_differential(&sample(32.0, 32.0));
+        assert_eq!(wt, 1.0);
+        assert!(rd.has_differentials);
+    }
+
+    const DGAUSS_50MM: &str = "# D-GAUSS F/2 22deg HFOV
+# radius thickness ior aperture
+29.475 3.76 1.67 25.2
+84.83 0.12 1 25.2
+19.275 4.025 1.67 23
+40.77 3.275 1.699 23
+12.75 5.705 1 18
+0 4.5 0 17.1
+-14.495 1.18 1.603 17
+40.77 6.065 1.658 20
+-20.385 0.19 1 20
+437.065 3.22 1.717 20
+-39.73 0 1 20
+";
+
+    #[test]
+    fn check_lens_table() {
+        let lens = LensElementInterface::parse_table(DGAUSS_50MM).unwrap();
+        assert_eq!(lens.len(), 11);
+        assert!((lens[0].curvature_radius - 0.029475).abs() < 1e-6);
+        assert!((lens[0].aperture_radius - 0.0126).abs() < 1e-6);
+        assert_eq!(lens[5].curvature_radius, 0.0);
+        assert!(LensElementInterface::parse_table("1 2 3").is_err());
+        assert!(LensElementInterface::parse_table("# empty").is_err());
+    }
+
+    #[test]
+    fn check_realistic_camera() {
+        let lens = LensElementInterface::parse_table(DGAUSS_50MM).unwrap();
+        let res = Point2i::new(64, 64);
+        let focus = 2.0;
+        let camera = RealisticCamera::new(identity(), &lens, 10.0, focus, 35.0, res).unwrap();
+        assert!((camera.effective_focal_length() - 0.05).abs() < 0.005);
+        assert!(!camera.exit_pupil_bounds(0.0).is_empty());
+
+        // rays from the film center leave along +z and meet at the focus distance
+        let spread = |z: f32| {
+            let mut points = Vec::new();
+            for i in 0..8 {
+                for j in 0..8 {
+                    let mut s = sample(32.0, 32.0);
+                    s.p_lens = Point2f::new((i as f32 + 0.5) / 8.0, (j as f32 + 0.5) / 8.0);
+                    let (wt, ray) = camera.generate_ray(&s);
+                    if wt > 0.0 {
+                        assert!(ray.d.z > 0.99);
+                        let t = (z - ray.o.z) / ray.d.z;
+                        points.push(ray.point(t));
+                    }
+                }
+            }
+            assert!(points.len() > 8);
+            points.iter().map(|p| (p.x * p.x + p.y * p.y).sqrt()).fold(0.0, f32::max)
+        };
+        let at_focus = spread(focus);
+        assert!(at_focus < spread(focus / 2.0));
+        assert!(at_focus < spread(focus * 2.0));
+
+        // focus distance closer than the focal length allows is an error
+        assert!(RealisticCamera::new(identity(), &lens, 10.0, 0.1, 35.0, res).is_err());
+    }
+
+    #[test]
+    fn check_stereo_camera() {
+        let res = Point2i::new(64, 128);
+        let camera = StereoCamera::new(identity(), res, StereoLayout::TopBottom, 90.0, 0.0, 1.0, None);
+        assert_eq!(StereoLayout::TopBottom.eye_resolution(res), Point2i::new(64, 64));
+
+        // parallel eyes: the image centers look straight ahead from either side
+        let (_, left) = camera.generate_ray(&sample(32.0, 32.0));
+        let (_, right) = camera.generate_ray(&sample(32.0, 96.0));
+        assert_point_near(left.o, Point3f::new(-0.032, 0.0, 0.0));
+        assert_point_near(right.o, Point3f::new(0.032, 0.0, 0.0));
+        assert_vector_near(left.d, Vector3f::new(0.0, 0.0, 1.0));
+        assert_vector_near(right.d, Vector3f::new(0.0, 0.0, 1.0));
+
+        // converged eyes meet on the convergence plane for every pixel
+        let camera = camera.with_interpupillary_distance(0.1).with_convergence(2.0);
+        for &(x, y) in &[(32.0, 32.0), (5.0, 10.0), (60.0, 50.0)] {
+            let uv = Point2f::new(x / 64.0, y / 64.0);
+            let pl = StereoLayout::TopBottom.film_position(StereoEye::Left, &uv);
+            let pr = StereoLayout::TopBottom.film_position(StereoEye::Right, &uv);
+            let (_, left) = camera.generate_ray(&sample(pl.x * 64.0, pl.y * 128.0));
+            let (_, right) = camera.generate_ray(&sample(pr.x * 64.0, pr.y * 128.0));
+            let hit_left = left.point((2.0 - left.o.z) / left.d.z);
+            let hit_right = right.point((2.0 - right.o.z) / right.d.z);
+            assert_point_near(hit_left, hit_right);
+        }
+    }
+
+    #[test]
+    fn check_ods_camera() {
+        let res = Point2i::new(128, 128);
+        let camera = ODSCamera::new(identity(), res, 0.064, 0.0, 1.0, None);
+
+        // top half is the left eye, bottom half the right eye
+        for &(x, y) in &[(32.0, 32.0), (96.0, 16.0), (10.0, 50.0)] {
+            let (_, left) = camera.generate_ray(&sample(x, y));
+            let (_, right) = camera.generate_ray(&sample(x, y + 64.0));
+            assert_vector_near(left.d, right.d);
+            // origins on the eye circle, perpendicular to the view direction
+            let o = left.o - Point3f::new(0.0, 0.0, 0.0);
+            assert!((o.length() - 0.032).abs() < 1e-5);
+            assert!(Vector3f::dot(&o, &left.d).abs() < 1e-5);
+            assert_point_near(right.o, Point3f::new(-left.o.x, -left.o.y, -left.o.z));
+        }
+
+        // looking along +z the left eye sits at -x
+        let (_, forward) = camera.generate_ray(&sample(32.0, 32.0));
+        assert_vector_near(forward.d, Vector3f::new(0.0, 0.0, 1.0));
+        assert_point_near(forward.o, Point3f::new(-0.032, 0.0, 0.0));
+
+        let side_by_side = camera.with_layout(StereoLayout::SideBySide);
+        let (_, right) = side_by_side.generate_ray(&sample(96.0, 64.0));
+        assert_vector_near(right.d, Vector3f::new(-1.0, 0.0, 0.0));
+        assert_point_near(right.o, Point3f::new(0.0, 0.0, 0.032));
+    }
+}
@@ -80,6 +80,14 @@ mod core_geometry_tests {
             Vector3f::abs(&c),
             Vector3f::new(c.x.abs(), c.y.abs(), c.z.abs())
         );
+
+        let x = Vector3f::new(1.0, 0.0, 0.0);
+        let y = Vector3f::new(0.0, 1.0, 0.0);
+        let z = Vector3f::new(0.0, 0.0, 1.0);
+        assert_eq!(Vector3f::cross(&x, &y), z);
+        assert_eq!(Vector3f::cross(&y, &z), x);
+        assert_eq!(Vector3f::cross(&z, &x), y);
+        assert_eq!(Vector3f::dot(&Vector3f::cross(&a, &b), &a), 0.0);
     }
 
     #[test]
@@ -165,6 +173,7 @@ mod core_geometry_tests {
         assert_eq!(Point3f::min(&a, &b), Point3f::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)));
         assert_eq!(Point3f::permute(&a, 1, 2, 0), Point3f::new(a.y, a.z, a.x));
 
+        #[allow(unused_variables)]
         let c = Point3f::new(1.5, 1.1, 3.3);
     }
 
@@ -225,7 +234,17 @@ mod core_geometry_tests {
     
     #[test]
     fn check_bounds2() {
-
+        let p1 = Point2::new(1.0, 2.0);
+        let p2 = Point2::new(2.0, 4.0);
+        let b1 = Bounds2::new(p1, p2);
+        let b2 = Bounds2::new(Point2::new(-1.0, 3.0), Point2::new(1.5, 5.0));
+
+        assert_eq!(b1.diagonal(), Vector2::new(1.0, 2.0));
+        assert_eq!(Bounds2::union(&b1, &b2), Bounds2::new(Point2::new(-1.0, 2.0), Point2::new(2.0, 5.0)));
+        assert_eq!(Bounds2::intersect(&b1, &b2), Bounds2::new(Point2::new(1.0, 3.0), Point2::new(1.5, 4.0)));
+        assert!(Bounds2::overlaps(&b1, &b2));
+        assert!(Bounds2::inside(&Point2::new(1.5, 2.5), &b1));
+        assert!(!Bounds2::inside_exclusive(Point2::new(2.0, 2.5), b1));
     }
     #[test]
     fn check_rays() {
@@ -0,0 +1,175 @@
+mod core_transform_tests {
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::quaternion::*;
+    use rust_my_pbrt::core::transform::*;
+
+    fn assert_point_near(a: Point3f, b: Point3f) {
+        assert!(Point3f::distance(a, b) < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_matrix_near(a: &Matrix4x4, b: &Matrix4x4) {
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((a.m[i][j] - b.m[i][j]).abs() < 1e-4, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn check_matrix() {
+        let m = Matrix4x4::new([
+            [2.0, 0.0, 1.0, 3.0],
+            [0.0, 1.0, 4.0, 0.0],
+            [1.0, 0.0, 3.0, 2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m_inv = Matrix4x4::inverse(&m).unwrap();
+        assert_matrix_near(&(m * m_inv), &Matrix4x4::identity());
+        assert_matrix_near(&(m_inv * m), &Matrix4x4::identity());
+        assert_eq!(Matrix4x4::transpose(&Matrix4x4::transpose(&m)), m);
+        assert_eq!(Matrix4x4::transpose(&m).m[0][3], m.m[3][0]);
+
+        let singular = Matrix4x4::new([[1.0; 4]; 4]);
+        assert!(Matrix4x4::inverse(&singular).is_none());
+    }
+
+    #[test]
+    fn check_transform() {
+        let p = Point3f::new(1.0, 2.0, 3.0);
+        let v = Vector3f::new(1.0, 2.0, 3.0);
+
+        let t = Transform::translate(&Vector3f::new(1.0, -1.0, 2.0));
+        assert_eq!(t.transform_point(&p), Point3f::new(2.0, 1.0, 5.0));
+        assert_eq!(t.transform_vector(&v), v);
+        assert_eq!(Transform::inverse(&t).transform_point(&t.transform_point(&p)), p);
+        assert!(!t.has_scale());
+
+        let s = Transform::scale(2.0, 3.0, 4.0);
+        assert_eq!(s.transform_point(&p), Point3f::new(2.0, 6.0, 12.0));
+        assert!(s.has_scale());
+        assert!(!s.swaps_handedness());
+        assert!(Transform::scale(-1.0, 1.0, 1.0).swaps_handedness());
+
+        let r = Transform::rotate_z(90.0);
+        assert_point_near(r.transform_point(&Point3f::new(1.0, 0.0, 0.0)), Point3f::new(0.0, 1.0, 0.0));
+        let ra = Transform::rotate(90.0, &Vector3f::new(0.0, 0.0, 1.0));
+        assert_matrix_near(&r.m, &ra.m);
+        assert_matrix_near(&Transform::rotate_x(30.0).m, &Transform::rotate(30.0, &Vector3f::new(1.0, 0.0, 0.0)).m);
+        assert_matrix_near(&Transform::rotate_y(30.0).m, &Transform::rotate(30.0, &Vector3f::new(0.0, 1.0, 0.0)).m);
+
+        // normals stay perpendicular to transformed tangents
+        let n = Normal3f::new(0.0, 0.0, 1.0);
+        let tangent = Vector3f::new(1.0, 1.0, 0.0);
+        let nt = s.transform_normal(&n);
+        let tt = s.transform_vector(&tangent);
+        assert_eq!(nt.x * tt.x + nt.y * tt.y + nt.z * tt.z, 0.0);
+
+        let composed = t * s;
+        assert_eq!(composed.transform_point(&p), t.transform_point(&s.transform_point(&p)));
+
+        let b = Bounds3f::new(Point3f::new(-1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0));
+        let tb = (t * s).transform_bounds(&b);
+        assert_eq!(tb, Bounds3f::new(Point3f::new(-1.0, -4.0, -2.0), Point3f::new(3.0, 2.0, 6.0)));
+
+        let ray = Ray::new(p, v, 10.0, 0.5, None);
+        let tr = t.transform_ray(&ray);
+        assert_eq!(tr.point(2.0), t.transform_point(&ray.point(2.0)));
+        assert_eq!(tr.t_max, ray.t_max);
+        assert_eq!(tr.time, ray.time);
+    }
+
+    #[test]
+    fn check_look_at() {
+        let pos = Point3f::new(1.0, 2.0, 3.0);
+        let look = Point3f::new(1.0, 2.0, 10.0);
+        let world_to_camera = Transform::look_at(&pos, &look, &Vector3f::new(0.0, 1.0, 0.0));
+        assert_point_near(world_to_camera.transform_point(&pos), Point3f::new(0.0, 0.0, 0.0));
+        assert_point_near(world_to_camera.transform_point(&look), Point3f::new(0.0, 0.0, 7.0));
+    }
+
+    #[test]
+    fn check_quaternion() {
+        let r = Transform::rotate(60.0, &Vector3f::new(1.0, 2.0, 3.0));
+        let q = Quaternion::from_transform(&r);
+        assert!((Quaternion::dot(&q, &q) - 1.0).abs() < 1e-5);
+        assert_matrix_near(&q.to_transform().m, &r.m);
+
+        let q0 = Quaternion::from_transform(&Transform::rotate_z(0.0));
+        let q1 = Quaternion::from_transform(&Transform::rotate_z(90.0));
+        let half = Quaternion::slerp(0.5, &q0, &q1);
+        assert_matrix_near(&half.to_transform().m, &Transform::rotate_z(45.0).m);
+    }
+
+    #[test]
+    fn check_animated_transform() {
+        let start = Transform::translate(&Vector3f::new(0.0, 0.0, 0.0));
+        let end = Transform::translate(&Vector3f::new(4.0, 0.0, 0.0)) * Transform::rotate_z(90.0) * Transform::scale(2.0, 2.0, 2.0);
+        let at = AnimatedTransform::new(start, 0.0, end, 1.0);
+        assert!(at.is_animated());
+        assert!(at.has_scale());
+        assert_eq!(at.interpolate(-1.0), start);
+        assert_eq!(at.interpolate(2.0), end);
+
+        let mid = at.interpolate(0.5);
+        let expected = Transform::translate(&Vector3f::new(2.0, 0.0, 0.0)) * Transform::rotate_z(45.0) * Transform::scale(1.5, 1.5, 1.5);
+        assert_matrix_near(&mid.m, &expected.m);
+
+        let p = Point3f::new(1.0, 0.0, 0.0);
+        let ray = Ray::new(p, Vector3f::new(0.0, 0.0, 1.0), 1.0, 0.5, None);
+        assert_point_near(at.transform_ray(&ray).o, expected.transform_point(&p));
+
+        let b = Bounds3f::new(Point3f::new(-1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0));
+        let mb = at.motion_bounds(&b);
+        for i in 0..=10 {
+            let tb = at.interpolate(i as f32 / 10.0).transform_bounds(&b);
+            assert_eq!(Bounds3f::union(&mb, &tb), mb);
+        }
+
+        // a fast rotation about an off-center axis, dense samples of the
+        // motion stay inside the bounds
+        let spin = AnimatedTransform::new(Transform::rotate_y(-20.0), 0.0, Transform::translate(&Vector3f::new(0.5, 0.0, 0.0)) * Transform::rotate_y(150.0), 2.0);
+        let b = Bounds3f::new(Point3f::new(1.0, -0.5, 0.0), Point3f::new(3.0, 0.5, 0.5));
+        let mb = spin.motion_bounds(&b);
+        for i in 0..=10000 {
+            let t = 2.0 * i as f32 / 10000.0;
+            for c in 0..8 {
+                let p = spin.transform_point(t, &b.corner(c));
+                assert!(Bounds3f::inside(&p, &mb), "{:?} outside {:?} at {}", p, mb, t);
+            }
+        }
+        // and the padding stays small
+        let tight = (0..=10000).fold(Bounds3f::empty(), |r, i| Bounds3f::union(&r, &spin.interpolate(2.0 * i as f32 / 10000.0).transform_bounds(&b)));
+        assert!(mb.volume() < 1.15 * tight.volume());
+
+        let fixed = AnimatedTransform::from_transform(end);
+        assert!(!fixed.is_animated());
+        assert_eq!(fixed.interpolate(0.3), end);
+    }
+
+    #[test]
+    fn check_singular_animated_transform() {
+        let gone = Transform::scale(0.0, 0.0, 0.0);
+        let fixed = AnimatedTransform::from_transform(gone);
+        assert!(!fixed.is_animated());
+        assert_eq!(fixed.interpolate(0.5).m, gone.m);
+
+        // a shrink away instance falls back to lerping the matrices
+        let start = Transform::translate(&Vector3f::new(2.0, 0.0, 0.0)) * Transform::rotate_z(30.0);
+        let end = Transform::translate(&Vector3f::new(2.0, 4.0, 0.0)) * gone;
+        let at = AnimatedTransform::new(start, 0.0, end, 1.0);
+        assert!(at.is_animated());
+        assert_eq!(at.interpolate(1.0).m, end.m);
+        let p = Point3f::new(1.0, 1.0, 0.0);
+        let mid = at.interpolate(0.25);
+        let expected = Point3f::lerp(0.25, start.transform_point(&p), end.transform_point(&p));
+        assert_point_near(mid.transform_point(&p), expected);
+        assert_point_near(Transform::inverse(&mid).transform_point(&expected), p);
+
+        let b = Bounds3f::new(Point3f::new(-1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0));
+        let mb = at.motion_bounds(&b);
+        for i in 0..=10 {
+            let tb = at.interpolate(i as f32 / 10.0).transform_bounds(&b);
+            assert_eq!(Bounds3f::union(&mb, &tb), mb);
+        }
+    }
+}
@@ -0,0 +1,457 @@
+mod film_tests {
+    use rust_my_pbrt::core::film::*;
+    use rust_my_pbrt::core::filter::*;
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::imageio::*;
+    use rust_my_pbrt::core::spectrum::*;
+    use rust_my_pbrt::filters::*;
+    use std::sync::Arc;
+
+    fn assert_rgb_near(a: [f32; 3], b: [f32; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+
+    // numerical integral of the filter over its support
+    fn integral(filter: &dyn Filter) -> f32 {
+        let r = filter.radius();
+        let n = 200;
+        let mut sum = 0.0;
+        for y in 0..n {
+            for x in 0..n {
+                let p = Point2f::new(
+                    -r.x + 2.0 * r.x * (x as f32 + 0.5) / n as f32,
+                    -r.y + 2.0 * r.y * (y as f32 + 0.5) / n as f32,
+                );
+                sum += filter.evaluate(&p);
+            }
+        }
+        sum * 4.0 * r.x * r.y / (n * n) as f32
+    }
+
+    #[test]
+    fn check_filters() {
+        let filters: Vec<Box<dyn Filter>> = vec![
+            Box::new(BoxFilter::default()),
+            Box::new(TriangleFilter::default()),
+            Box::new(GaussianFilter::default()),
+            Box::new(MitchellFilter::default()),
+            Box::new(LanczosSincFilter::default()),
+        ];
+        for filter in filters.iter() {
+            let r = filter.radius();
+            let center = filter.evaluate(&Point2f::new(0.0, 0.0));
+            assert!(center > 0.0);
+            // symmetric, largest at the center and vanishing at the radius
+            let p = Point2f::new(0.3 * r.x, 0.6 * r.y);
+            let v = filter.evaluate(&p);
+            assert!((v - filter.evaluate(&Point2f::new(-p.x, -p.y))).abs() < 1e-6);
+            assert!(v <= center);
+            if r.x > 0.5 {
+                assert!(filter.evaluate(&Point2f::new(r.x, 0.0)).abs() < 1e-4);
+            }
+        }
+
+        assert_eq!(TriangleFilter::default().evaluate(&Point2f::new(1.0, 1.0)), 1.0);
+        // Mitchell with b + 2c = 1 reproduces constants exactly
+        assert!((integral(&MitchellFilter::default()) - 1.0).abs() < 1e-3);
+        assert!(MitchellFilter::default().evaluate(&Point2f::new(1.5, 0.0)) < 0.0);
+        assert!(LanczosSincFilter::default().evaluate(&Point2f::new(1.5, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn check_film_add_sample() {
+        let mut film = Film::new(Point2i::new(4, 3), Arc::new(BoxFilter::default()));
+        assert_eq!(film.pixel_bounds(), Bounds2i::new(Point2i::new(0, 0), Point2i::new(4, 3)));
+        assert_eq!(film.sample_bounds(), film.pixel_bounds());
+
+        // box filter averages the samples inside each pixel
+        film.add_sample(&Point2f::new(1.25, 2.5), [1.0, 2.0, 3.0], 1.0);
+        film.add_sample(&Point2f::new(1.75, 2.25), [3.0, 2.0, 1.0], 1.0);
+        assert_rgb_near(film.pixel_value(&Point2i::new(1, 2), 1.0), [2.0, 2.0, 2.0]);
+        assert_rgb_near(film.pixel_value(&Point2i::new(0, 2), 1.0), [0.0, 0.0, 0.0]);
+
+        let image = film.image(1.0);
+        assert_eq!(image.len(), 12);
+        assert_rgb_near(image[2 * 4 + 1], [2.0, 2.0, 2.0]);
+
+        film.clear();
+        assert_rgb_near(film.pixel_value(&Point2i::new(1, 2), 1.0), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn check_film_spectral_samples() {
+        let film = Film::new(Point2i::new(4, 4), Arc::new(BoxFilter::default()));
+        assert_eq!(film.spectral_mode(), SpectralMode::Rgb);
+        assert!(film.sample_wavelengths(0.5).is_none());
+
+        let mut film = film.with_spectral_mode(SpectralMode::HeroWavelength);
+        assert_eq!(film.spectral_mode(), SpectralMode::HeroWavelength);
+        let lambda = film.sample_wavelengths(0.25).unwrap();
+        assert_eq!(lambda, SampledWavelengths::sample_visible(0.25));
+
+        // spectral samples land on the film as their rgb
+        let l = SpectrumSamples::new(0.5);
+        film.add_spectral_sample(&Point2f::new(2.5, 1.5), &l, &lambda, 1.0);
+        assert_rgb_near(film.pixel_value(&Point2i::new(2, 1), 1.0), l.rgb(&lambda));
+        let mut tile = film.get_film_tile(&film.sample_bounds());
+        tile.add_spectral_sample(&Point2f::new(0.5, 0.5), &l, &lambda, 1.0);
+        film.merge_film_tile(tile);
+        assert_rgb_near(film.pixel_value(&Point2i::new(0, 0), 1.0), l.rgb(&lambda));
+    }
+
+    #[test]
+    fn check_film_filter_weights() {
+        let mut film = Film::new(Point2i::new(8, 8), Arc::new(TriangleFilter::new(Vector2f::new(1.0, 1.0))));
+        assert_eq!(film.sample_bounds(), Bounds2i::new(Point2i::new(-1, -1), Point2i::new(9, 9)));
+
+        // a constant signal stays constant whatever the filter weights are
+        let n = 16;
+        for y in 0..8 * n {
+            for x in 0..8 * n {
+                let p = Point2f::new((x as f32 + 0.5) / n as f32, (y as f32 + 0.5) / n as f32);
+                film.add_sample(&p, [0.5, 0.25, 1.0], 1.0);
+            }
+        }
+        for p in film.pixel_bounds().points() {
+            assert_rgb_near(film.pixel_value(&p, 1.0), [0.5, 0.25, 1.0]);
+        }
+
+        // samples only reach pixels within the filter radius
+        let mut film = Film::new(Point2i::new(8, 8), Arc::new(TriangleFilter::new(Vector2f::new(1.0, 1.0))));
+        film.add_sample(&Point2f::new(4.0, 4.0), [1.0, 1.0, 1.0], 1.0);
+        let lit: Vec<Point2i> = film
+            .pixel_bounds()
+            .points()
+            .filter(|p| film.pixel_value(p, 1.0)[0] > 0.0)
+            .collect();
+        assert_eq!(lit.len(), 4);
+        assert!(lit.iter().all(|p| (p.x == 3 || p.x == 4) && (p.y == 3 || p.y == 4)));
+    }
+
+    #[test]
+    fn check_film_splat() {
+        let mut film = Film::new(Point2i::new(4, 4), Arc::new(GaussianFilter::default()));
+        film.add_splat(&Point2f::new(2.5, 1.2), [1.0, 2.0, 4.0]);
+        film.add_splat(&Point2f::new(2.9, 1.9), [1.0, 2.0, 4.0]);
+        film.add_splat(&Point2f::new(-0.5, 1.0), [1.0, 1.0, 1.0]);
+        film.add_splat(&Point2f::new(0.5, 0.5), [f32::NAN, 1.0, 1.0]);
+        assert_rgb_near(film.pixel_value(&Point2i::new(2, 1), 0.5), [1.0, 2.0, 4.0]);
+        assert_rgb_near(film.pixel_value(&Point2i::new(0, 0), 1.0), [0.0, 0.0, 0.0]);
+
+        // splats add on top of the filtered samples
+        film.add_sample(&Point2f::new(2.5, 1.5), [1.0, 1.0, 1.0], 1.0);
+        film.scale = 2.0;
+        assert_rgb_near(film.pixel_value(&Point2i::new(2, 1), 0.5), [4.0, 6.0, 10.0]);
+
+        // splatting from several threads at once
+        let film = Arc::new(Film::new(Point2i::new(2, 2), Arc::new(BoxFilter::default())));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let film = film.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        film.add_splat(&Point2f::new(1.5, 0.5), [1.0, 0.0, 0.0]);
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_rgb_near(film.pixel_value(&Point2i::new(1, 0), 1.0), [4000.0, 0.0, 0.0]);
+    }
+
+    // deterministic per-sample radiance for the tile tests
+    fn shade(p: &Point2f) -> [f32; 3] {
+        [p.x * 0.1, (p.y * 0.37).sin().abs(), 1.0 / (1.0 + p.x * p.y)]
+    }
+
+    fn render_tile(film: &Film, sample_bounds: &Bounds2i) -> FilmTile {
+        let mut tile = film.get_film_tile(sample_bounds);
+        for p in sample_bounds.points() {
+            for &(dx, dy) in &[(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)] {
+                let p_film = Point2f::new(p.x as f32 + dx, p.y as f32 + dy);
+                tile.add_sample(&p_film, shade(&p_film), 1.0);
+            }
+        }
+        tile
+    }
+
+    #[test]
+    fn check_film_tiles() {
+        let film = Film::new(Point2i::new(37, 21), Arc::new(MitchellFilter::default()));
+        let sample_bounds = film.sample_bounds();
+        let tiles = film.tiles(8);
+        assert_eq!(sample_bounds, Bounds2i::new(Point2i::new(-2, -2), Point2i::new(39, 23)));
+        assert_eq!(tiles.len(), 6 * 4);
+        assert_eq!(tiles.iter().map(|t| t.area()).sum::<i32>(), sample_bounds.area());
+        for t in tiles.iter() {
+            assert!(Bounds2i::inside(&t.p_min, &sample_bounds) && Bounds2i::inside(&t.p_max, &sample_bounds));
+        }
+
+        // tiles reach the filter radius beyond their samples, clipped to the film
+        let tile = film.get_film_tile(&Bounds2i::new(Point2i::new(8, 8), Point2i::new(16, 16)));
+        assert_eq!(tile.pixel_bounds(), Bounds2i::new(Point2i::new(6, 6), Point2i::new(18, 18)));
+        let tile = film.get_film_tile(&tiles[0]);
+        assert_eq!(tile.pixel_bounds().p_min, Point2i::new(0, 0));
+    }
+
+    #[test]
+    fn check_film_tile_merge() {
+        let filter = Arc::new(GaussianFilter::default());
+        let mut direct = Film::new(Point2i::new(40, 30), filter.clone());
+        let mut sequential = Film::new(Point2i::new(40, 30), filter.clone());
+        let mut parallel = Film::new(Point2i::new(40, 30), filter);
+
+        let tiles = sequential.tiles(16);
+        for t in tiles.iter() {
+            for p in t.points() {
+                for &(dx, dy) in &[(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)] {
+                    let p_film = Point2f::new(p.x as f32 + dx, p.y as f32 + dy);
+                    direct.add_sample(&p_film, shade(&p_film), 1.0);
+                }
+            }
+            let tile = render_tile(&sequential, t);
+            sequential.merge_film_tile(tile);
+        }
+
+        // workers finish in reverse order, merging still follows tile order
+        let rendered: Vec<FilmTile> = std::thread::scope(|s| {
+            let handles: Vec<_> = tiles
+                .iter()
+                .rev()
+                .map(|t| {
+                    let film = &parallel;
+                    s.spawn(move || render_tile(film, t))
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        parallel.merge_film_tiles(rendered);
+
+        assert_eq!(parallel.image(1.0), sequential.image(1.0));
+        for (a, b) in parallel.image(1.0).iter().zip(direct.image(1.0).iter()) {
+            assert_rgb_near(*a, *b);
+        }
+    }
+
+    fn hit(depth: f32, id: u32, normal: Normal3f) -> AovSample {
+        AovSample {
+            normal,
+            depth,
+            position: Point3f::new(depth, 0.0, 1.0),
+            uv: Point2f::new(0.5, depth),
+            albedo: [0.5, 0.25, 1.0],
+            primitive_id: id,
+            material_id: id + 100,
+        }
+    }
+
+    #[test]
+    fn check_film_aovs() {
+        assert_eq!(Aov::PrimitiveId.filtering(), AovFiltering::Nearest);
+        assert_eq!(Aov::Depth.filtering(), AovFiltering::Box);
+        assert_eq!(Aov::Normal.filtering(), AovFiltering::Filter);
+        assert!(!AovSample::default().is_hit());
+
+        let filter = Arc::new(TriangleFilter::default());
+        let mut film = Film::new(Point2i::new(4, 4), filter.clone());
+        film.add_aov_sample(&Point2f::new(1.5, 1.5), &hit(1.0, 1, Normal3f::new(0.0, 0.0, 1.0)));
+        assert_eq!(film.aov_value(&Point2i::new(1, 1)), None);
+
+        let mut film = Film::new(Point2i::new(4, 4), filter).with_aovs(&Aov::ALL);
+        let samples = [
+            (Point2f::new(1.2, 1.1), hit(2.0, 3, Normal3f::new(0.0, 0.0, 1.0))),
+            (Point2f::new(1.6, 1.4), hit(4.0, 7, Normal3f::new(0.0, 0.0, 1.0))),
+            (Point2f::new(2.5, 1.5), hit(100.0, 9, Normal3f::new(1.0, 0.0, 0.0))),
+            (Point2f::new(3.5, 3.5), AovSample::default()),
+        ];
+        for (p, s) in samples.iter() {
+            film.add_sample(p, [1.0, 1.0, 1.0], 1.0);
+            film.add_aov_sample(p, s);
+        }
+        assert_eq!(film.sample_count(&Point2i::new(1, 1)), 2);
+        assert_eq!(film.sample_count(&Point2i::new(0, 0)), 0);
+
+        // depth and position average the hits inside the pixel only, the ids
+        // come from the sample nearest to the pixel center
+        let v = film.aov_value(&Point2i::new(1, 1)).unwrap();
+        assert_eq!(v.depth, 3.0);
+        assert_eq!(v.position, Point3f::new(3.0, 0.0, 1.0));
+        assert_eq!((v.primitive_id, v.material_id), (7, 107));
+        // normals are filtered across pixels and renormalized
+        assert!(v.normal.x > 0.0 && v.normal.z > v.normal.x);
+        assert!((v.normal.length() - 1.0).abs() < 1e-5);
+        // the miss at the edge of the filter counts as black albedo
+        assert!(v.albedo[1] > 0.2 && v.albedo[1] < 0.25);
+
+        let miss = film.aov_value(&Point2i::new(3, 3)).unwrap();
+        assert_eq!(miss.depth, f32::INFINITY);
+        assert_eq!(miss.primitive_id, AOV_NO_ID);
+        // filtered buffers reach pixels without samples of their own
+        let empty = film.aov_value(&Point2i::new(0, 3)).unwrap();
+        assert_eq!((empty.depth, empty.primitive_id), (f32::INFINITY, AOV_NO_ID));
+        assert_eq!(empty.normal, Normal3f::new(1.0, 0.0, 0.0));
+
+        let image = film.exr_image(1.0);
+        assert_eq!(image.layers(), vec!["", "N", "P", "albedo", "uv"]);
+        let ids = image.channel("primitiveId").unwrap();
+        assert_eq!(ids.pixel_type(), ExrPixelType::Uint);
+        assert_eq!(ids.data.value(4 + 1), ExrValue::Uint(7));
+        assert_eq!(image.channel("sampleCount").unwrap().data.value(4 + 1), ExrValue::Uint(2));
+        assert_eq!(image.channel("Z").unwrap().data.float_value(4 + 2), 100.0);
+        let decoded = ExrImage::decode(&image.encode()).unwrap();
+        assert_eq!(decoded.channel("materialId").unwrap().data.value(4 + 1), ExrValue::Uint(107));
+        assert_eq!(decoded.channel("primitiveId").unwrap().data.value(3 * 4 + 3), ExrValue::Uint(AOV_NO_ID));
+
+        film.clear();
+        assert_eq!(film.sample_count(&Point2i::new(1, 1)), 0);
+        assert_eq!(film.aov_value(&Point2i::new(1, 1)).unwrap(), AovSample::default());
+    }
+
+    #[test]
+    fn check_film_aov_large_ids() {
+        // ids above 2^24 have no exact f32 representation
+        let filter = Arc::new(TriangleFilter::default());
+        let mut film = Film::new(Point2i::new(2, 2), filter).with_aovs(&[Aov::PrimitiveId, Aov::MaterialId]);
+        let id = (1 << 24) + 1;
+        film.add_aov_sample(&Point2f::new(0.5, 0.5), &hit(1.0, id, Normal3f::new(0.0, 0.0, 1.0)));
+        film.add_aov_sample(&Point2f::new(1.5, 0.5), &hit(1.0, 0xfeed_beef, Normal3f::new(0.0, 0.0, 1.0)));
+        let decoded = ExrImage::decode(&film.exr_image(1.0).encode()).unwrap();
+        let ids = decoded.channel("primitiveId").unwrap();
+        assert_eq!((ids.data.value(0), ids.data.value(1)), (ExrValue::Uint(id), ExrValue::Uint(0xfeed_beef)));
+        assert_eq!(ids.data.value(2), ExrValue::Uint(AOV_NO_ID));
+        assert_eq!(decoded.channel("materialId").unwrap().data.value(0), ExrValue::Uint(id + 100));
+    }
+
+    #[test]
+    fn check_film_aov_tiles() {
+        let filter = Arc::new(MitchellFilter::default());
+        let mut direct = Film::new(Point2i::new(20, 12), filter.clone()).with_aovs(&[Aov::Depth, Aov::PrimitiveId]);
+        let mut tiled = Film::new(Point2i::new(20, 12), filter).with_aovs(&[Aov::Depth, Aov::PrimitiveId]);
+        let aov = |p: &Point2f| hit(p.x + p.y, (p.x * 2.0) as u32, Normal3f::new(0.0, 1.0, 0.0));
+
+        let mut tiles = Vec::new();
+        for t in tiled.tiles(5) {
+            let mut tile = tiled.get_film_tile(&t);
+            for p in t.points() {
+                for &(dx, dy) in &[(0.25, 0.25), (0.75, 0.25), (0.5, 0.5), (0.75, 0.75)] {
+                    let p_film = Point2f::new(p.x as f32 + dx, p.y as f32 + dy);
+                    tile.add_sample(&p_film, shade(&p_film), 1.0);
+                    tile.add_aov_sample(&p_film, &aov(&p_film));
+                    direct.add_sample(&p_film, shade(&p_film), 1.0);
+                    direct.add_aov_sample(&p_film, &aov(&p_film));
+                }
+            }
+            tiles.push(tile);
+        }
+        tiled.merge_film_tiles(tiles);
+
+        for p in direct.pixel_bounds().points() {
+            let (a, b) = (direct.aov_value(&p).unwrap(), tiled.aov_value(&p).unwrap());
+            assert_eq!(a.primitive_id, b.primitive_id);
+            assert_eq!(a.primitive_id, 2 * p.x as u32 + 1);
+            assert!((a.depth - b.depth).abs() < 1e-4);
+            assert_eq!(tiled.sample_count(&p), 4);
+        }
+        let image = tiled.exr_image(1.0);
+        assert_eq!(image.channels.len(), 5);
+    }
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("rust_my_pbrt_film_{}_{}", std::process::id(), name))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn render(film: &mut Film) {
+        for p in film.sample_bounds().points() {
+            for &(dx, dy) in &[(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)] {
+                let p_film = Point2f::new(p.x as f32 + dx, p.y as f32 + dy);
+                film.add_sample(&p_film, shade(&p_film), 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn check_film_crop_window() {
+        let full_resolution = Point2i::new(8, 6);
+        let crop = Bounds2f::new(Point2f::new(0.25, 0.5), Point2f::new(0.75, 1.0));
+        assert_eq!(
+            Film::crop_pixel_bounds(full_resolution, &crop),
+            Bounds2i::new(Point2i::new(2, 3), Point2i::new(6, 6))
+        );
+        // adjacent windows split the pixels between them
+        let left = Film::crop_pixel_bounds(Point2i::new(37, 5), &Bounds2f::new(Point2f::new(0.0, 0.0), Point2f::new(0.3, 1.0)));
+        let right = Film::crop_pixel_bounds(Point2i::new(37, 5), &Bounds2f::new(Point2f::new(0.3, 0.0), Point2f::new(1.0, 1.0)));
+        assert_eq!(left.p_max.x, right.p_min.x);
+        assert_eq!(left.area() + right.area(), 37 * 5);
+
+        let filter = Arc::new(TriangleFilter::default());
+        let mut full = Film::new(full_resolution, filter.clone());
+        let mut cropped = Film::new(full_resolution, filter).with_crop_window(&crop);
+        assert_eq!(cropped.pixel_bounds(), Bounds2i::new(Point2i::new(2, 3), Point2i::new(6, 6)));
+        // samples are still taken over the filter's reach around the window
+        assert_eq!(cropped.sample_bounds(), Bounds2i::new(Point2i::new(0, 1), Point2i::new(8, 8)));
+        render(&mut full);
+        render(&mut cropped);
+        assert_eq!(cropped.image(1.0).len(), 12);
+        for p in cropped.pixel_bounds().points() {
+            assert_rgb_near(cropped.pixel_value(&p, 1.0), full.pixel_value(&p, 1.0));
+        }
+
+        let name = temp_path("crop.pfm");
+        cropped.write_image(&name, 1.0).unwrap();
+        let (image, resolution) = rust_my_pbrt::core::imageio::read_image(&name).unwrap();
+        assert_eq!(resolution, Point2i::new(4, 3));
+        assert_eq!(image, cropped.image(1.0));
+
+        // compositing both halves of the image reproduces the full render
+        std::fs::remove_file(&name).unwrap();
+        let top_crop = Bounds2f::new(Point2f::new(0.0, 0.0), Point2f::new(1.0, 0.5));
+        let mut top = Film::new(full_resolution, Arc::new(TriangleFilter::default())).with_crop_window(&top_crop);
+        render(&mut top);
+        top.composite_image(&name, 1.0).unwrap();
+        let (image, _) = rust_my_pbrt::core::imageio::read_image(&name).unwrap();
+        assert_eq!(image[5 * 8 + 3], [0.0; 3]);
+        let bottom_crop = Bounds2f::new(Point2f::new(0.0, 0.5), Point2f::new(1.0, 1.0));
+        let mut bottom = Film::new(full_resolution, Arc::new(TriangleFilter::default())).with_crop_window(&bottom_crop);
+        render(&mut bottom);
+        bottom.composite_image(&name, 1.0).unwrap();
+        let (image, _) = rust_my_pbrt::core::imageio::read_image(&name).unwrap();
+        for (a, b) in image.iter().zip(full.image(1.0).iter()) {
+            assert_rgb_near(*a, *b);
+        }
+        std::fs::remove_file(&name).unwrap();
+
+        // OpenEXR composites keep the AOV layers
+        let name = temp_path("crop.exr");
+        let mut film = Film::new(full_resolution, Arc::new(BoxFilter::default()))
+            .with_aovs(&[Aov::Depth, Aov::PrimitiveId, Aov::SampleCount])
+            .with_crop_window(&crop);
+        film.add_sample(&Point2f::new(3.5, 4.5), [1.0, 2.0, 3.0], 1.0);
+        film.add_aov_sample(&Point2f::new(3.5, 4.5), &hit(5.0, 0, Normal3f::new(0.0, 0.0, 1.0)));
+        film.composite_image(&name, 1.0).unwrap();
+        film.composite_image(&name, 1.0).unwrap();
+        let image = ExrImage::read(&name).unwrap();
+        std::fs::remove_file(&name).unwrap();
+        assert_eq!(image.resolution, full_resolution);
+        assert_eq!(image.channel("Z").unwrap().data.float_value(4 * 8 + 3), 5.0);
+        assert_eq!(image.channel("G").unwrap().data.float_value(4 * 8 + 3), 2.0);
+        assert_eq!(image.channel("G").unwrap().data.float_value(0), 0.0);
+        // pixels outside the crop window have no id
+        let ids = image.channel("primitiveId").unwrap();
+        assert_eq!((ids.data.value(4 * 8 + 3), ids.data.value(0)), (ExrValue::Uint(0), ExrValue::Uint(AOV_NO_ID)));
+        assert_eq!(image.channel("sampleCount").unwrap().data.value(0), ExrValue::Uint(0));
+
+        // the existing image has to be full size
+        let name = temp_path("small.pfm");
+        cropped.write_image(&name, 1.0).unwrap();
+        assert!(cropped.composite_image(&name, 1.0).is_err());
+        std::fs::remove_file(&name).unwrap();
+    }
+}
@@ -0,0 +1,340 @@
+mod imageio_tests {
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::imageio::*;
+
+    fn lcg_bytes(n: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    fn gradient(width: usize, height: usize) -> Vec<[f32; 3]> {
+        (0..width * height)
+            .map(|i| {
+                let (x, y) = ((i % width) as f32, (i / width) as f32);
+                [x / width as f32, y / height as f32, 0.25 * (x + y).sin().abs()]
+            })
+            .collect()
+    }
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("rust_my_pbrt_{}_{}", std::process::id(), name))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn check_zlib_round_trip() {
+        let mut inputs = vec![
+            Vec::new(),
+            b"a".to_vec(),
+            b"abcabcabcabcabcabcabcabc".to_vec(),
+            vec![7u8; 100_000],
+            lcg_bytes(50_000, 1),
+        ];
+        // repeats further back than the deflate window
+        let mut far = lcg_bytes(40_000, 2);
+        far.extend(far.clone());
+        inputs.push(far);
+        for input in inputs.iter() {
+            let compressed = zlib_compress(input);
+            assert_eq!(&zlib_decompress(&compressed).unwrap(), input);
+        }
+        assert!(zlib_compress(&vec![7u8; 100_000]).len() < 1000);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+
+        let mut corrupted = zlib_compress(b"some data to corrupt");
+        let n = corrupted.len();
+        corrupted[n - 1] ^= 1;
+        assert!(zlib_decompress(&corrupted).is_err());
+        assert!(zlib_decompress(&[0x78, 0x01]).is_err());
+    }
+
+    #[test]
+    fn check_zlib_reference_streams() {
+        // produced by a reference zlib: a stored block, fixed and dynamic codes
+        let stored = [
+            120, 1, 1, 12, 0, 243, 255, 115, 116, 111, 114, 101, 100, 32, 98, 108, 111, 99, 107, 31, 128, 4, 189,
+        ];
+        assert_eq!(zlib_decompress(&stored).unwrap(), b"stored block");
+
+        let fixed = [
+            120, 218, 43, 201, 72, 85, 40, 44, 205, 76, 206, 86, 72, 42, 202, 47, 207, 83, 72, 203, 175, 80, 200, 42,
+            205, 45, 40, 86, 200, 47, 75, 45, 82, 40, 1, 74, 231, 36, 86, 85, 42, 164, 228, 167, 235, 129, 121, 163,
+            138, 201, 82, 204, 192, 200, 196, 204, 194, 202, 198, 206, 193, 201, 197, 205, 195, 203, 199, 47, 32, 40,
+            36, 44, 34, 42, 38, 46, 33, 41, 37, 45, 35, 43, 39, 175, 160, 168, 164, 172, 162, 170, 166, 174, 161, 169,
+            165, 173, 163, 171, 167, 111, 96, 104, 100, 108, 98, 106, 102, 110, 97, 105, 101, 109, 99, 107, 103, 15, 0,
+            106, 143, 138, 25,
+        ];
+        let mut expected = b"the quick brown fox jumps over the lazy dog. ".repeat(8);
+        expected.extend(0..64u8);
+        assert_eq!(zlib_decompress(&fixed).unwrap(), expected);
+
+        let dynamic = [
+            120, 218, 37, 142, 129, 13, 0, 49, 8, 2, 87, 97, 53, 144, 253, 103, 248, 163, 111, 162, 105, 236, 9, 88,
+            231, 40, 149, 229, 248, 236, 220, 37, 181, 235, 11, 131, 53, 5, 17, 26, 194, 235, 2, 228, 88, 156, 194,
+            217, 168, 45, 36, 14, 250, 31, 116, 240, 195, 25, 124, 12, 158, 252, 74, 179, 121, 194, 168, 174, 15, 255,
+            222, 246, 211, 90, 12, 188, 159, 159, 180, 40, 133, 233, 156, 60, 7, 98, 241, 120, 105, 8, 245, 2, 169,
+            31, 11, 248, 68, 193,
+        ];
+        let text = "a cab bd a abacaabccbbdaadacbadaa aaaab bbb babaaabaadbdabcb bc b abdaa dabc  adad \
+                    aaaabdbababaaabbaa  ab bcacaaaaaa caaba aaabbbabbbcbd dca cabdaabacacbab bab  daaddbbbdb \
+                    aba adabadba  bababb aabaa d";
+        assert_eq!(zlib_decompress(&dynamic).unwrap(), text.as_bytes());
+    }
+
+    #[test]
+    fn check_srgb() {
+        for i in 0..=100 {
+            let v = i as f32 / 100.0;
+            assert!((inverse_gamma_correct(gamma_correct(v)) - v).abs() < 1e-5);
+        }
+        assert_eq!(to_srgb8(&[[0.0, 1.0, 0.5]], 0.0), vec![0, 255, 188]);
+        // out of range values clamp, exposure scales by powers of two
+        assert_eq!(to_srgb8(&[[-1.0, 7.0, f32::INFINITY]], 0.0), vec![0, 255, 255]);
+        assert_eq!(to_srgb8(&[[0.25, 0.5, 2.0]], 1.0), to_srgb8(&[[0.5, 1.0, 4.0]], 0.0));
+        let bytes: Vec<u8> = (0..=255).collect();
+        let back = to_srgb8(&from_srgb8(&[bytes.clone(), bytes.clone(), bytes.clone()].concat()), 0.0);
+        assert_eq!(back, [bytes.clone(), bytes.clone(), bytes].concat());
+    }
+
+    #[test]
+    fn check_ppm_png_pfm_round_trip() {
+        let (width, height) = (13, 7);
+        let image = gradient(width, height);
+        let bytes = to_srgb8(&image, 0.0);
+
+        assert_eq!(decode_ppm(&encode_ppm(&bytes, width, height)).unwrap(), (bytes.clone(), width, height));
+        let commented = b"P6\n# comment\n2 1\n# another\n255\n\x01\x02\x03\x04\x05\x06";
+        assert_eq!(decode_ppm(commented).unwrap(), (vec![1, 2, 3, 4, 5, 6], 2, 1));
+        assert!(decode_ppm(b"P3\n1 1\n255\n0 0 0").is_err());
+        // sizes that overflow are errors, not panics
+        assert!(decode_ppm(b"P6\n18446744073709551615 2\n255\n\x01\x02\x03").is_err());
+        assert!(decode_ppm(b"P6\n6148914691236517206 1\n255\n\x01\x02\x03").is_err());
+        assert!(decode_pfm(b"PF\n4611686018427387904 1\n-1\n\0\0\0\0").is_err());
+
+        let png = encode_png(&bytes, width, height);
+        assert_eq!(&png[1..4], b"PNG");
+        assert_eq!(decode_png(&png).unwrap(), (bytes, width, height));
+        let mut corrupted = png.clone();
+        corrupted[20] ^= 0xff;
+        assert!(decode_png(&corrupted).is_err());
+        let mut huge = encode_png(&[0, 0, 0], 1, 1);
+        huge[16..24].copy_from_slice(&[0xff; 8]);
+        let crc = crc32(&huge[12..29]);
+        huge[29..33].copy_from_slice(&crc.to_be_bytes());
+        assert!(decode_png(&huge).is_err());
+
+        let (rgb, w, h) = decode_pfm(&encode_pfm(&image, width, height)).unwrap();
+        assert_eq!((rgb, w, h), (image, width, height));
+    }
+
+    #[test]
+    fn check_image_files() {
+        let res = Point2i::new(16, 9);
+        let image = gradient(16, 9);
+        for ext in &["ppm", "png", "pfm"] {
+            let name = temp_path(&format!("gradient.{}", ext));
+            write_image(&name, &image, res, 0.0).unwrap();
+            let (back, back_res) = read_image(&name).unwrap();
+            std::fs::remove_file(&name).unwrap();
+            assert_eq!(back_res, res);
+            let tolerance = if *ext == "pfm" { 0.0 } else { 0.01 };
+            for (a, b) in image.iter().zip(back.iter()) {
+                for c in 0..3 {
+                    assert!((a[c] - b[c]).abs() <= tolerance, "{}: {:?} != {:?}", ext, a, b);
+                }
+            }
+        }
+
+        assert!(write_image(&temp_path("image.xyz"), &image, res, 0.0).is_err());
+        assert!(write_image(&temp_path("image.png"), &image[1..], res, 0.0).is_err());
+        assert!(read_image(&temp_path("missing.png")).is_err());
+    }
+
+    #[test]
+    fn check_half_conversion() {
+        assert_eq!(f32_to_half(1.0), 0x3c00);
+        assert_eq!(f32_to_half(-2.0), 0xc000);
+        assert_eq!(f32_to_half(0.1), 0x2e66);
+        assert_eq!(f32_to_half(65504.0), 0x7bff);
+        assert_eq!(f32_to_half(65520.0), 0x7c00);
+        assert_eq!(f32_to_half(f32::NEG_INFINITY), 0xfc00);
+        assert_eq!(f32_to_half(5.960_464_5e-8), 0x0001);
+        assert_eq!(f32_to_half(1e-9), 0x0000);
+        assert!(half_to_f32(f32_to_half(f32::NAN)).is_nan());
+        // ties round to even
+        assert_eq!(f32_to_half(1.0 + 1.0 / 2048.0), 0x3c00);
+        assert_eq!(f32_to_half(1.0 + 3.0 / 2048.0), 0x3c02);
+        for h in 0..=u16::MAX {
+            let v = half_to_f32(h);
+            if !v.is_nan() {
+                assert_eq!(f32_to_half(v), h);
+            }
+        }
+    }
+
+    fn exr_test_image(width: i16, height: i16) -> ExrImage {
+        let n = width as usize * height as usize;
+        let noise = lcg_bytes(4 * n, 9);
+        let rgb = gradient(width as usize, height as usize);
+        let mut image = ExrImage::new(Point2i::new(width, height));
+        image.add_layer("", ["R", "G", "B"], ExrPixelType::Half, &rgb);
+        image.add_channel("A", ExrChannelData::Half(vec![1.0; n]));
+        let depth = (0..n)
+            .map(|i| {
+                let b = &noise[4 * i..4 * i + 4];
+                1000.0 * f32::from_le_bytes([b[0], b[1], b[2], 0x3f])
+            })
+            .collect();
+        image.add_channel("depth.Z", ExrChannelData::Float(depth));
+        let ids = (0..n as u32).map(|i| (i / 7) | 0x8000_0000).collect();
+        image.add_channel("id.id", ExrChannelData::Uint(ids));
+        let normals: Vec<[f32; 3]> = rgb.iter().map(|p| [2.0 * p[0] - 1.0, 2.0 * p[1] - 1.0, p[2]]).collect();
+        image.add_layer("normal", ["X", "Y", "Z"], ExrPixelType::Float, &normals);
+        image
+    }
+
+    #[test]
+    fn check_exr_round_trip() {
+        for &(width, height) in &[(1, 1), (37, 45), (64, 16), (5, 70)] {
+            let image = exr_test_image(width, height);
+            for &compression in &[ExrCompression::None, ExrCompression::Zip, ExrCompression::Piz] {
+                let image = image.clone().with_compression(compression);
+                let decoded = ExrImage::decode(&image.encode()).unwrap();
+                assert_eq!(decoded.compression, compression);
+                assert_eq!(decoded.resolution, image.resolution);
+                assert_eq!(decoded.channels.len(), image.channels.len());
+                for c in image.channels.iter() {
+                    let d = decoded.channel(&c.name).unwrap();
+                    assert_eq!(d.pixel_type(), c.pixel_type());
+                    assert_eq!(d.data.len(), c.data.len());
+                    for i in 0..c.data.len() {
+                        let expected = match c.data.value(i) {
+                            ExrValue::Float(v) if c.pixel_type() == ExrPixelType::Half => {
+                                ExrValue::Float(half_to_f32(f32_to_half(v)))
+                            }
+                            v => v,
+                        };
+                        let message = format!("{} {:?} {}x{}", c.name, compression, width, height);
+                        match (expected, d.data.value(i)) {
+                            (ExrValue::Float(a), ExrValue::Float(b)) => assert_eq!(a.to_bits(), b.to_bits(), "{}", message),
+                            (a, b) => assert_eq!(a, b, "{}", message),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_exr_layout() {
+        let mut image = exr_test_image(32, 32);
+        assert_eq!(image.layers(), vec!["", "depth", "id", "normal"]);
+        let data = image.encode();
+        assert_eq!(&data[..5], &[0x76, 0x2f, 0x31, 0x01, 2]);
+        assert_eq!(data[5], 0);
+        let header = String::from_utf8_lossy(&data[..500]).into_owned();
+        for attribute in &["channels", "compression", "dataWindow", "displayWindow", "lineOrder"] {
+            assert!(header.contains(attribute));
+        }
+        // channels are stored sorted by name
+        let positions: Vec<usize> = ["A", "B", "G", "R", "depth.Z", "id.id", "normal.X"]
+            .iter()
+            .map(|c| header.find(&format!("\0{}\0", c)).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        // smooth half data compresses, PIZ and ZIP beat storing it raw
+        let mut smooth = ExrImage::new(Point2i::new(64, 64));
+        smooth.add_layer("", ["R", "G", "B"], ExrPixelType::Half, &gradient(64, 64));
+        let raw = smooth.clone().with_compression(ExrCompression::None).encode().len();
+        assert!(smooth.clone().with_compression(ExrCompression::Zip).encode().len() < raw / 2);
+        assert!(smooth.clone().with_compression(ExrCompression::Piz).encode().len() < raw / 2);
+
+        let long_name = "a_layer_name_longer_than_thirty_one_bytes.R";
+        image.add_channel(long_name, ExrChannelData::Half(vec![0.5; 32 * 32]));
+        let data = image.encode();
+        assert_eq!(data[5], 0x04);
+        assert_eq!(ExrImage::decode(&data).unwrap().channel(long_name).unwrap().data.float_value(5), 0.5);
+
+        assert!(ExrImage::decode(&data[..100]).is_err());
+        assert!(ExrImage::decode(b"not an exr file").is_err());
+        // a data window whose size overflows an i32
+        let mut data = data;
+        let window = find(&data, b"dataWindow\0box2i\0") + 21;
+        data[window..window + 4].copy_from_slice(&i32::MIN.to_le_bytes());
+        data[window + 8..window + 12].copy_from_slice(&i32::MAX.to_le_bytes());
+        assert!(ExrImage::decode(&data).is_err());
+
+        // scanline blocks with a y coordinate that overflows against the data
+        // window, or with a negative size
+        let mut data = image.encode();
+        data[window + 4..window + 8].copy_from_slice(&1i32.to_le_bytes());
+        data[window + 12..window + 16].copy_from_slice(&32i32.to_le_bytes());
+        let table = find(&data, b"screenWindowWidth\0float\0") + 33;
+        let mut offset = [0u8; 8];
+        offset.copy_from_slice(&data[table..table + 8]);
+        let chunk = u64::from_le_bytes(offset) as usize;
+        data[chunk..chunk + 4].copy_from_slice(&i32::MIN.to_le_bytes());
+        assert!(ExrImage::decode(&data).is_err());
+        data[chunk..chunk + 4].copy_from_slice(&1i32.to_le_bytes());
+        data[chunk + 4..chunk + 8].copy_from_slice(&(-1i32).to_le_bytes());
+        assert!(ExrImage::decode(&data).is_err());
+    }
+
+    #[test]
+    fn check_exr_reference_file() {
+        // uncompressed file written by OpenEXR, the test image of CPython's
+        // imghdr module
+        let image = ExrImage::decode(include_bytes!("data/python.exr")).unwrap();
+        assert_eq!(image.resolution, Point2i::new(16, 16));
+        assert_eq!(image.compression, ExrCompression::None);
+        assert_eq!(image.layers(), vec![""]);
+        let names: Vec<&str> = image.channels.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["A", "B", "G", "R"]);
+        assert!(image.channels.iter().all(|c| c.pixel_type() == ExrPixelType::Half));
+        let pixel = |x: usize, y: usize| -> Vec<u16> {
+            image
+                .channels
+                .iter()
+                .map(|c| f32_to_half(c.data.float_value(16 * y + x)))
+                .collect()
+        };
+        assert_eq!(pixel(0, 0), vec![0, 0, 0, 0]);
+        assert_eq!(pixel(5, 0), vec![0x3b6f, 0x39d6, 0x3834, 0x34a5]);
+        assert_eq!(pixel(8, 8), vec![0x3c00, 0x3575, 0x3b1f, 0x3c00]);
+        assert_eq!(pixel(5, 15), vec![0x2f47, 0, 0, 0]);
+
+        let decoded = ExrImage::decode(&image.encode()).unwrap();
+        assert_eq!(decoded, image);
+    }
+
+    fn find(data: &[u8], pattern: &[u8]) -> usize {
+        data.windows(pattern.len()).position(|w| w == pattern).unwrap()
+    }
+
+    #[test]
+    fn check_exr_file() {
+        let res = Point2i::new(20, 10);
+        let image: Vec<[f32; 3]> = gradient(20, 10).iter().map(|p| [p[0] * 100.0, p[1], -p[2]]).collect();
+        let name = temp_path("gradient.exr");
+        write_image(&name, &image, res, 0.0).unwrap();
+        let (back, back_res) = read_image(&name).unwrap();
+        let exr = ExrImage::read(&name).unwrap();
+        std::fs::remove_file(&name).unwrap();
+        assert_eq!(back_res, res);
+        assert_eq!(exr.channels.len(), 3);
+        for (a, b) in image.iter().zip(back.iter()) {
+            for c in 0..3 {
+                assert_eq!(half_to_f32(f32_to_half(a[c])), b[c]);
+            }
+        }
+    }
+}
@@ -0,0 +1,553 @@
+mod samplers_tests {
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::lowdiscrepancy::*;
+    use rust_my_pbrt::core::pbrt::*;
+    use rust_my_pbrt::core::rng::*;
+    use rust_my_pbrt::core::sampler::*;
+    use rust_my_pbrt::core::sampling::*;
+    use rust_my_pbrt::samplers::*;
+
+    // all sample vectors of a pixel, n_1d 1D values then n_2d 2D values each
+    fn pixel_samples(sampler: &mut dyn Sampler, p: Point2i, n_1d: usize, n_2d: usize) -> Vec<(Vec<f32>, Vec<Point2f>)> {
+        sampler.start_pixel(p);
+        let mut samples = Vec::new();
+        loop {
+            let v1: Vec<f32> = (0..n_1d).map(|_| sampler.get_1d()).collect();
+            let v2: Vec<Point2f> = (0..n_2d).map(|_| sampler.get_2d()).collect();
+            samples.push((v1, v2));
+            if !sampler.start_next_sample() {
+                return samples;
+            }
+        }
+    }
+
+    // true if each of the n intervals of [0, 1) holds exactly one value
+    fn is_stratified(values: &[f32]) -> bool {
+        let n = values.len();
+        let mut hit = vec![false; n];
+        for &v in values {
+            assert!((0.0..1.0).contains(&v));
+            let i = (v * n as f32) as usize;
+            if hit[i] {
+                return false;
+            }
+            hit[i] = true;
+        }
+        true
+    }
+
+    #[test]
+    fn check_rng() {
+        let mut rng = Rng::new(7);
+        let mut same = Rng::new(7);
+        let mut other = Rng::new(8);
+        let a: Vec<u32> = (0..16).map(|_| rng.uniform_u32()).collect();
+        assert_eq!(a, (0..16).map(|_| same.uniform_u32()).collect::<Vec<u32>>());
+        assert_ne!(a, (0..16).map(|_| other.uniform_u32()).collect::<Vec<u32>>());
+
+        let n = 100_000;
+        let mean = (0..n).map(|_| rng.uniform_f32()).sum::<f32>() / n as f32;
+        assert!((mean - 0.5).abs() < 0.01);
+        let mut counts = [0; 5];
+        for _ in 0..n {
+            counts[rng.uniform_u32_bounded(5) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (c as f32 - n as f32 / 5.0).abs() < 0.02 * n as f32));
+    }
+
+    #[test]
+    fn check_rng_advance() {
+        let mut rng = Rng::new_with_offset(3, 17);
+        let start = rng;
+        let values: Vec<u32> = (0..1000).map(|_| rng.uniform_u32()).collect();
+        let mut skipped = start;
+        skipped.advance(600);
+        assert_eq!(skipped.uniform_u32(), values[600]);
+        skipped.advance(-101);
+        assert_eq!(skipped.uniform_u32(), values[500]);
+        rng.advance(-1000);
+        assert_eq!(rng, start);
+
+        // the offset picks the starting point, the sequence the stream
+        assert_ne!(Rng::new_with_offset(3, 18).uniform_u32(), Rng::new_with_offset(3, 17).uniform_u32());
+        assert_eq!(Rng::new(5), Rng::new_with_offset(5, 0x853c_49e6_748f_ea9b));
+
+        let n = 100_000;
+        let values: Vec<f64> = (0..n).map(|_| rng.uniform_f64()).collect();
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        let mean = values.iter().sum::<f64>() / n as f64;
+        assert!((mean - 0.5).abs() < 0.01);
+        // more random bits than an f32
+        assert!(values.iter().any(|&v| v as f32 as f64 != v));
+    }
+
+    #[test]
+    fn check_hash() {
+        assert_eq!(hash(&[1, 2, 3]), hash(&[1, 2, 3]));
+        assert_ne!(hash(&[1, 2, 3]), hash(&[1, 2, 4]));
+        assert_ne!(hash(&[1, 2]), hash(&[2, 1]));
+        for values in [&[][..], &[7], &[1, 2, 3], &[u64::MAX, 0, 42, 1 << 63]].iter() {
+            let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect();
+            assert_eq!(hash(values), murmur_hash64a(&bytes, 0));
+        }
+        assert_ne!(murmur_hash64a(b"pbrt", 0), murmur_hash64a(b"pbrt", 1));
+        // bytes past the last full word count
+        assert_ne!(murmur_hash64a(b"abcdefghi", 0), murmur_hash64a(b"abcdefghj", 0));
+        assert_ne!(murmur_hash64a(b"abcdefgh", 0), murmur_hash64a(b"abcdefgh\0", 0));
+
+        // neighboring pixels get unrelated streams
+        let mut counts = [0; 16];
+        for p in Bounds2i::new(Point2i::new(-16, -16), Point2i::new(16, 16)).points() {
+            counts[(pixel_sequence_index(p, 0) & 15) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (32..=96).contains(&c)));
+        // seeds that differ only in their high bits don't share streams
+        let p = Point2i::new(-3, 9);
+        let indices: Vec<u64> = [0, 1 << 32, 1 << 48, 1 << 63, u64::MAX]
+            .iter()
+            .map(|&seed| pixel_sequence_index(p, seed) & (u64::MAX >> 1))
+            .collect();
+        assert!(indices.iter().enumerate().all(|(i, a)| indices[i + 1..].iter().all(|b| a != b)));
+
+        // a pixel sample's values don't depend on the samples before it
+        let p = Point2i::new(3, -7);
+        let mut sampler = IndependentSampler::new(8, 1);
+        let samples = pixel_samples(&mut sampler, p, 2, 2);
+        let mut rng = pixel_sample_rng(p, 5, 1);
+        assert_eq!(samples[5].0[0], rng.uniform_f32());
+        sampler.start_pixel(p);
+        sampler.set_sample_number(5);
+        assert_eq!(sampler.get_1d(), samples[5].0[0]);
+    }
+
+    #[test]
+    fn check_sampling_patterns() {
+        let mut rng = Rng::new(1);
+        let mut samples = vec![0.0; 16];
+        stratified_sample_1d(&mut samples, &mut rng, true);
+        assert!(is_stratified(&samples));
+        stratified_sample_1d(&mut samples, &mut rng, false);
+        assert_eq!(samples[3], 3.5 / 16.0);
+
+        let mut samples = vec![Point2f::default(); 12];
+        stratified_sample_2d(&mut samples, 4, 3, &mut rng, true);
+        for (i, p) in samples.iter().enumerate() {
+            assert_eq!(((p.x * 4.0) as usize, (p.y * 3.0) as usize), (i % 4, i / 4));
+        }
+
+        let mut values: Vec<usize> = (0..20).collect();
+        shuffle(&mut values, 2, &mut rng);
+        // pairs stay together
+        assert!(values.chunks(2).all(|c| c[0] % 2 == 0 && c[1] == c[0] + 1));
+        values.sort_unstable();
+        assert_eq!(values, (0..20).collect::<Vec<usize>>());
+
+        let mut values = vec![0.0; 3 * 10];
+        latin_hypercube(&mut values, 3, &mut rng);
+        for d in 0..3 {
+            let dimension: Vec<f32> = values.iter().skip(d).step_by(3).copied().collect();
+            assert!(is_stratified(&dimension));
+        }
+    }
+
+    #[test]
+    fn check_independent_sampler() {
+        let mut sampler = IndependentSampler::new(8, 0);
+        let a = pixel_samples(&mut sampler, Point2i::new(3, 4), 3, 2);
+        assert_eq!(a.len(), 8);
+        assert_eq!(sampler.current_sample_number(), 8);
+
+        // samples only depend on the pixel, sample number and seed
+        pixel_samples(&mut sampler, Point2i::new(5, 5), 1, 1);
+        assert_eq!(pixel_samples(&mut sampler, Point2i::new(3, 4), 3, 2), a);
+        sampler.start_pixel(Point2i::new(3, 4));
+        assert!(sampler.set_sample_number(5));
+        assert_eq!(sampler.get_1d(), a[5].0[0]);
+        assert_ne!(pixel_samples(&mut sampler, Point2i::new(4, 3), 3, 2), a);
+        let mut other = sampler.clone_with_seed(1);
+        assert_eq!(other.samples_per_pixel(), 8);
+        assert_ne!(pixel_samples(other.as_mut(), Point2i::new(3, 4), 3, 2), a);
+        // every bit of the seed counts
+        let mut high = sampler.clone_with_seed(1 << 48);
+        assert_ne!(pixel_samples(high.as_mut(), Point2i::new(3, 4), 3, 2), a);
+
+        let mut sum = 0.0;
+        let mut n = 0;
+        for p in Bounds2i::new(Point2i::new(0, 0), Point2i::new(16, 16)).points() {
+            for (v1, v2) in pixel_samples(&mut sampler, p, 2, 2) {
+                let values = v1.iter().copied().chain(v2.iter().flat_map(|p| vec![p.x, p.y]));
+                for v in values {
+                    assert!((0.0..1.0).contains(&v));
+                    sum += v;
+                    n += 1;
+                }
+            }
+        }
+        assert!((sum / n as f32 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn check_stratified_sampler() {
+        let mut sampler = StratifiedSampler::new(4, 3, true, 2, 0);
+        assert_eq!(sampler.samples_per_pixel(), 12);
+        // two stratified dimensions of each kind, then padding
+        let samples = pixel_samples(&mut sampler, Point2i::new(1, 2), 4, 4);
+        assert_eq!(samples.len(), 12);
+        for d in 0..4 {
+            let values: Vec<f32> = samples.iter().map(|s| s.0[d]).collect();
+            assert!(is_stratified(&values));
+        }
+        for d in 0..2 {
+            let mut cells: Vec<(usize, usize)> = samples
+                .iter()
+                .map(|s| ((s.1[d].x * 4.0) as usize, (s.1[d].y * 3.0) as usize))
+                .collect();
+            cells.sort_unstable();
+            cells.dedup();
+            assert_eq!(cells.len(), 12);
+        }
+        for d in 2..4 {
+            assert!(is_stratified(&samples.iter().map(|s| s.1[d].x).collect::<Vec<f32>>()));
+            assert!(is_stratified(&samples.iter().map(|s| s.1[d].y).collect::<Vec<f32>>()));
+        }
+        // dimensions are shuffled against each other
+        assert!(samples.windows(2).any(|w| (w[0].0[0] < w[1].0[0]) != (w[0].0[1] < w[1].0[1])));
+
+        assert_eq!(pixel_samples(&mut sampler, Point2i::new(1, 2), 4, 4), samples);
+        let mut other = sampler.clone_with_seed(5);
+        assert_ne!(pixel_samples(other.as_mut(), Point2i::new(1, 2), 4, 4), samples);
+        let mut high = sampler.clone_with_seed(1 << 40);
+        assert_ne!(pixel_samples(high.as_mut(), Point2i::new(1, 2), 4, 4), samples);
+
+        let mut sampler = StratifiedSampler::new(2, 2, false, 1, 0);
+        sampler.start_pixel(Point2i::new(0, 0));
+        let mut p_film = Vec::new();
+        loop {
+            let sample = sampler.get_camera_sample(Point2i::new(10, 20));
+            p_film.push((sample.p_film.x, sample.p_film.y));
+            assert!((0.0..1.0).contains(&sample.time));
+            if !sampler.start_next_sample() {
+                break;
+            }
+        }
+        p_film.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(p_film, vec![(10.25, 20.25), (10.25, 20.75), (10.75, 20.25), (10.75, 20.75)]);
+    }
+
+    #[test]
+    fn check_radical_inverse() {
+        assert_eq!((PRIMES[0], PRIMES[1], PRIMES[999]), (2, 3, 7919));
+        assert_eq!(PRIME_SUMS[3], 2 + 3 + 5);
+        assert_eq!(radical_inverse(0, 0b1011), 0.8125);
+        assert_eq!(radical_inverse(0, 0), 0.0);
+        assert!((radical_inverse(1, 5) - 7.0 / 9.0).abs() < 1e-6);
+        assert!((radical_inverse(2, 7) - 2.0 / 5.0 - 1.0 / 25.0).abs() < 1e-6);
+        // index 5 is 12 in base 3, its radical inverse 0.21 the digits 21
+        assert_eq!(inverse_radical_inverse(3, 7, 2), 5);
+        assert_eq!(multiplicative_inverse(8, 27), 17);
+        assert_eq!(multiplicative_inverse(27, 8), 3);
+
+        let mut rng = Rng::new(3);
+        let perms = compute_radical_inverse_permutations(&mut rng);
+        let perm = &perms[PRIME_SUMS[2] as usize..(PRIME_SUMS[2] + 5) as usize];
+        let mut sorted = perm.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![0, 1, 2, 3, 4]);
+        let identity: Vec<u16> = (0..5).collect();
+        for a in 0..100 {
+            assert!((scrambled_radical_inverse(2, a, &identity) - radical_inverse(2, a)).abs() < 1e-6);
+        }
+
+        for &(l, p) in &[(5, 7), (16, 1234), (1000, 99)] {
+            let mut values: Vec<u32> = (0..l).map(|i| permutation_element(i, l, p)).collect();
+            values.sort_unstable();
+            assert_eq!(values, (0..l).collect::<Vec<u32>>());
+        }
+
+        // scrambled points still fall one into each interval of b^k points
+        let scrambled: Vec<f32> = (0..125).map(|a| scrambled_radical_inverse(2, a, &[1, 3, 0, 4, 2])).collect();
+        assert!(is_stratified(&scrambled));
+        for (base_index, base) in PRIMES.iter().enumerate().take(3) {
+            let n = base.pow(3);
+            let owen: Vec<f32> = (0..n).map(|a| owen_scrambled_radical_inverse(base_index, a, 12345)).collect();
+            assert!(is_stratified(&owen));
+            let other: Vec<f32> = (0..n).map(|a| owen_scrambled_radical_inverse(base_index, a, 54321)).collect();
+            assert_ne!(owen, other);
+        }
+    }
+
+    #[test]
+    fn check_halton_sampler() {
+        let bounds = Bounds2i::new(Point2i::new(-2, -2), Point2i::new(30, 20));
+        for &randomization in &[HaltonRandomization::None, HaltonRandomization::PermuteDigits, HaltonRandomization::Owen] {
+            let mut sampler = HaltonSampler::new(16, bounds, randomization, 0);
+            let mut indices = Vec::new();
+            for p in bounds.points() {
+                sampler.start_pixel(p);
+                let mut x = Vec::new();
+                loop {
+                    indices.push(sampler.halton_index());
+                    let sample = sampler.get_camera_sample(p);
+                    // the sequence points belonging to the pixel
+                    assert_eq!((sample.p_film.x.floor() as i16, sample.p_film.y.floor() as i16), (p.x, p.y));
+                    x.push(sample.p_film.x - p.x as f32);
+                    assert!((0.0..1.0).contains(&sample.time));
+                    assert!((0.0..1.0).contains(&sample.p_lens.x) && (0.0..1.0).contains(&sample.p_lens.y));
+                    if !sampler.start_next_sample() {
+                        break;
+                    }
+                }
+                assert!(is_stratified(&x));
+            }
+            // every pixel gets its own points of the sequence
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), 16 * bounds.area() as usize);
+
+            let a = pixel_samples(&mut sampler, Point2i::new(3, 3), 5, 5);
+            assert_eq!(pixel_samples(&mut sampler, Point2i::new(3, 3), 5, 5), a);
+            let mut other = sampler.clone_with_seed(9);
+            let b = pixel_samples(other.as_mut(), Point2i::new(3, 3), 5, 5);
+            assert_eq!(randomization == HaltonRandomization::None, a == b);
+        }
+
+        // the 1D dimensions of a pixel's samples are well stratified too
+        let mut sampler = HaltonSampler::new(25, bounds, HaltonRandomization::Owen, 0);
+        let samples = pixel_samples(&mut sampler, Point2i::new(7, 1), 3, 0);
+        for d in 0..3 {
+            let values: Vec<f32> = samples.iter().map(|s| s.0[d]).collect();
+            let mean = values.iter().sum::<f32>() / values.len() as f32;
+            assert!((mean - 0.5).abs() < 0.1);
+        }
+    }
+
+    // true if n = 2^k points put one point into every elementary interval
+    // of [0, 1)^2 with area 1/n
+    fn is_02_net(points: &[Point2f]) -> bool {
+        let n = points.len();
+        assert!(n.is_power_of_two());
+        let k = n.trailing_zeros();
+        (0..=k).all(|a| {
+            let (nx, ny) = (1usize << a, 1usize << (k - a));
+            let mut hit = vec![false; n];
+            points.iter().all(|p| {
+                let cell = (p.y * ny as f32) as usize * nx + (p.x * nx as f32) as usize;
+                !std::mem::replace(&mut hit[cell], true)
+            })
+        })
+    }
+
+    #[test]
+    fn check_sobol_sequence() {
+        for a in 0..1000u64 {
+            assert_eq!(sobol_sample_bits(a, 0), (a as u32).reverse_bits());
+        }
+        // the first columns of dimensions built from Joe and Kuo's direction
+        // numbers
+        let columns = |d: usize| &SOBOL_MATRICES_32[d * SOBOL_MATRIX_SIZE..d * SOBOL_MATRIX_SIZE + 8];
+        assert_eq!(columns(1), &[0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000, 0xff000000]);
+        assert_eq!(columns(2), &[0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0x8e000000, 0xc5000000]);
+        assert_eq!(columns(3), &[0x80000000, 0xc0000000, 0x20000000, 0x50000000, 0xf8000000, 0x74000000, 0xa2000000, 0x93000000]);
+        assert_eq!(columns(4), &[0x80000000, 0x40000000, 0x20000000, 0xb0000000, 0xf8000000, 0xdc000000, 0x7a000000, 0x9d000000]);
+        assert_eq!(columns(5), &[0x80000000, 0x40000000, 0x60000000, 0x30000000, 0xc8000000, 0x24000000, 0x56000000, 0xfb000000]);
+        // every dimension is a (0, 1)-sequence
+        for d in 0..N_SOBOL_DIMENSIONS {
+            let values: Vec<f32> = (0..64).map(|a| sobol_sample(a, d, SobolScrambler::None)).collect();
+            assert!(is_stratified(&values), "dimension {}", d);
+        }
+
+        // the first two dimensions are a (0, 2)-sequence, also when scrambled
+        let scramblers = [
+            SobolScrambler::None,
+            SobolScrambler::BinaryPermute(0x1234_5678),
+            SobolScrambler::FastOwen(0x9abc_def0),
+            SobolScrambler::Owen(0x0f0f_1234),
+        ];
+        for scrambler in scramblers.iter() {
+            for k in 0..9 {
+                let n = 1u64 << k;
+                for block in 0..3 {
+                    let points: Vec<Point2f> = (block * n..(block + 1) * n)
+                        .map(|a| Point2f::new(sobol_sample(a, 0, *scrambler), sobol_sample(a, 1, *scrambler)))
+                        .collect();
+                    assert!(is_02_net(&points), "{:?} {} {}", scrambler, k, block);
+                }
+            }
+        }
+        let a: Vec<f32> = (0..16).map(|a| sobol_sample(a, 5, SobolScrambler::Owen(1))).collect();
+        let b: Vec<f32> = (0..16).map(|a| sobol_sample(a, 5, SobolScrambler::Owen(2))).collect();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn check_sobol_sampler() {
+        let bounds = Bounds2i::new(Point2i::new(-3, -1), Point2i::new(10, 6));
+        for &randomization in &[SobolRandomization::None, SobolRandomization::PermuteDigits, SobolRandomization::Owen] {
+            let mut sampler = SobolSampler::new(8, bounds, randomization, 0);
+            let mut indices = Vec::new();
+            for p in bounds.points() {
+                sampler.start_pixel(p);
+                let mut in_pixel = Vec::new();
+                loop {
+                    indices.push(sampler.sobol_index());
+                    let sample = sampler.get_camera_sample(p);
+                    assert_eq!((sample.p_film.x.floor() as i16, sample.p_film.y.floor() as i16), (p.x, p.y));
+                    in_pixel.push(Point2f::new(sample.p_film.x - p.x as f32, sample.p_film.y - p.y as f32));
+                    assert!((0.0..1.0).contains(&sample.time));
+                    if !sampler.start_next_sample() {
+                        break;
+                    }
+                }
+                // the samples of a pixel are stratified inside it
+                assert!(is_02_net(&in_pixel));
+            }
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), 8 * bounds.area() as usize);
+
+            let a = pixel_samples(&mut sampler, Point2i::new(2, 2), 3, 3);
+            assert_eq!(pixel_samples(&mut sampler, Point2i::new(2, 2), 3, 3), a);
+            let mut other = sampler.clone_with_seed(4);
+            let b = pixel_samples(other.as_mut(), Point2i::new(2, 2), 3, 3);
+            assert_eq!(randomization == SobolRandomization::None, a == b);
+        }
+    }
+
+    #[test]
+    fn check_padded_sobol_sampler() {
+        assert_eq!(PaddedSobolSampler::new(12, SobolRandomization::None, 0).samples_per_pixel(), 16);
+        for &randomization in &[
+            SobolRandomization::None,
+            SobolRandomization::PermuteDigits,
+            SobolRandomization::FastOwen,
+            SobolRandomization::Owen,
+        ] {
+            let mut sampler = PaddedSobolSampler::new(32, randomization, 7);
+            for p in &[Point2i::new(0, 0), Point2i::new(5, -2)] {
+                let samples = pixel_samples(&mut sampler, *p, 3, 4);
+                assert_eq!(samples.len(), 32);
+                for d in 0..3 {
+                    assert!(is_stratified(&samples.iter().map(|s| s.0[d]).collect::<Vec<f32>>()));
+                }
+                for d in 0..4 {
+                    assert!(is_02_net(&samples.iter().map(|s| s.1[d]).collect::<Vec<Point2f>>()));
+                }
+                // dimensions are decorrelated by shuffling
+                assert_ne!(
+                    samples.iter().map(|s| s.1[0]).collect::<Vec<Point2f>>(),
+                    samples.iter().map(|s| s.1[1]).collect::<Vec<Point2f>>()
+                );
+            }
+            let a = pixel_samples(&mut sampler, Point2i::new(1, 1), 2, 2);
+            assert_ne!(a, pixel_samples(&mut sampler, Point2i::new(1, 2), 2, 2));
+            let mut other = sampler.clone_with_seed(8);
+            assert_ne!(a, pixel_samples(other.as_mut(), Point2i::new(1, 1), 2, 2));
+        }
+    }
+
+    #[test]
+    fn check_pmj02_sequence() {
+        let mut rng = Rng::new(3);
+        for &blue_noise in &[false, true] {
+            let points = generate_pmj02(256, blue_noise, &mut rng);
+            assert_eq!(points.len(), 256);
+            // every power of two prefix is a (0, 2)-net
+            for k in 0..=8 {
+                assert!(is_02_net(&points[..1 << k]), "{} {}", blue_noise, k);
+            }
+        }
+        assert_ne!(generate_pmj02(16, false, &mut rng), generate_pmj02(16, false, &mut rng));
+
+        // best candidate placement spreads the points out
+        let min_distance = |points: &[Point2f]| {
+            let mut d = f32::INFINITY;
+            for (i, p) in points.iter().enumerate() {
+                for q in points[..i].iter() {
+                    d = d.min(Point2f::distance(*p, *q));
+                }
+            }
+            d
+        };
+        let n = 16;
+        let (mut white, mut blue) = (0.0, 0.0);
+        for _ in 0..n {
+            white += min_distance(&generate_pmj02(64, false, &mut rng));
+            blue += min_distance(&generate_pmj02(64, true, &mut rng));
+        }
+        assert!(blue > white);
+    }
+
+    #[test]
+    fn check_pmj02_sampler() {
+        let mut sampler = Pmj02Sampler::new(48, 5);
+        assert_eq!(sampler.samples_per_pixel(), 64);
+        assert_eq!(sampler.sets().len(), N_PMJ02_SETS);
+        for p in &[Point2i::new(0, 0), Point2i::new(-4, 9)] {
+            let samples = pixel_samples(&mut sampler, *p, 3, 7);
+            assert_eq!(samples.len(), 64);
+            for d in 0..3 {
+                assert!(is_stratified(&samples.iter().map(|s| s.0[d]).collect::<Vec<f32>>()));
+            }
+            // scrambling keeps every set a (0, 2)-net, also past the number
+            // of sets
+            for d in 0..7 {
+                assert!(is_02_net(&samples.iter().map(|s| s.1[d]).collect::<Vec<Point2f>>()));
+            }
+        }
+        let a = pixel_samples(&mut sampler, Point2i::new(1, 1), 2, 2);
+        assert_eq!(a, pixel_samples(&mut sampler, Point2i::new(1, 1), 2, 2));
+        assert_ne!(a, pixel_samples(&mut sampler, Point2i::new(2, 1), 2, 2));
+        let mut other = sampler.clone_with_seed(6);
+        assert_eq!(other.samples_per_pixel(), 64);
+        assert_ne!(a, pixel_samples(other.as_mut(), Point2i::new(1, 1), 2, 2));
+    }
+
+    #[test]
+    fn check_zsobol_sampler() {
+        assert_eq!(encode_morton2(0b101, 0b011), 0b011011);
+        let bounds = Bounds2i::new(Point2i::new(-2, 3), Point2i::new(6, 11));
+        for &spp in &[4, 8] {
+            let mut sampler = ZSobolSampler::new(spp, bounds, SobolRandomization::FastOwen, 1);
+            let mut indices = Vec::new();
+            let samples: Vec<Vec<(Vec<f32>, Vec<Point2f>)>> = bounds
+                .points()
+                .map(|p| {
+                    sampler.start_pixel(p);
+                    loop {
+                        indices.push(sampler.sample_index());
+                        if !sampler.start_next_sample() {
+                            break;
+                        }
+                    }
+                    pixel_samples(&mut sampler, p, 0, 3)
+                })
+                .collect();
+            // the pixels take disjoint parts of the sequence
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), spp * bounds.area() as usize);
+
+            for d in 0..3 {
+                for s in samples.iter() {
+                    assert!(is_02_net(&s.iter().map(|s| s.1[d]).collect::<Vec<Point2f>>()));
+                }
+                // together the pixels of aligned 2x2 blocks are a (0, 2)-net
+                for by in 0..4 {
+                    for bx in 0..4 {
+                        let mut points = Vec::new();
+                        for (y, x) in [(0, 0), (0, 1), (1, 0), (1, 1)].iter() {
+                            let s = &samples[(2 * by + y) * 8 + 2 * bx + x];
+                            points.extend(s.iter().map(|s| s.1[d]));
+                        }
+                        assert!(is_02_net(&points), "{} {} {} {}", spp, d, bx, by);
+                    }
+                }
+            }
+            let p = Point2i::new(0, 4);
+            let a = pixel_samples(&mut sampler, p, 2, 2);
+            assert_eq!(a, pixel_samples(&mut sampler, p, 2, 2));
+            let mut other = sampler.clone_with_seed(2);
+            assert_ne!(a, pixel_samples(other.as_mut(), p, 2, 2));
+        }
+    }
+}
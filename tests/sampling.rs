@@ -0,0 +1,299 @@
+mod sampling_tests {
+    use rust_my_pbrt::core::geometry::*;
+    use rust_my_pbrt::core::lowdiscrepancy::*;
+    use rust_my_pbrt::core::pbrt::*;
+    use rust_my_pbrt::core::sampling::*;
+
+    const N: u64 = 4096;
+
+    // scrambled (0, 2)-sequence points, well stratified and never on the edges
+    fn samples() -> impl Iterator<Item = Point2f> {
+        (0..N).map(|a| {
+            Point2f::new(
+                sobol_sample(a, 0, SobolScrambler::FastOwen(0x1234_5678)),
+                sobol_sample(a, 1, SobolScrambler::FastOwen(0x9abc_def0)),
+            )
+        })
+    }
+
+    // Monte Carlo estimate of the integral of f with sample values and
+    // densities from sample
+    fn integrate<T>(sample: impl Fn(&Point2f) -> Option<(T, f32)>, f: impl Fn(&T) -> f32) -> f32 {
+        samples()
+            .filter_map(|u| sample(&u))
+            .map(|(x, pdf)| if pdf > 0.0 { f(&x) / pdf } else { 0.0 })
+            .sum::<f32>()
+            / N as f32
+    }
+
+    fn assert_close(a: f32, b: f32, tolerance: f32) {
+        assert!((a - b).abs() <= tolerance * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    fn check_inverse(sample: impl Fn(&Point2f) -> Point2f, invert: impl Fn(&Point2f) -> Point2f) {
+        for u in samples().step_by(7) {
+            let v = invert(&sample(&u));
+            assert!((u.x - v.x).abs() < 2e-3 && (u.y - v.y).abs() < 2e-3, "{:?} {:?}", u, v);
+        }
+    }
+
+    fn to_point2f(w: &Vector3f) -> Point2f {
+        Point2f::new(w.x, w.y)
+    }
+
+    #[test]
+    fn check_sample_disk() {
+        let disk = integrate(|u| Some((concentric_sample_disk(u), concentric_disk_pdf())), |p| p.x * p.x);
+        assert_close(disk, PI / 4.0, 0.01);
+        assert!(samples().all(|u| Point2f::distance(concentric_sample_disk(&u), Point2f::default()) <= 1.0));
+        check_inverse(concentric_sample_disk, invert_concentric_sample_disk);
+    }
+
+    #[test]
+    fn check_sample_hemisphere() {
+        let pdf = |w: &Vector3f| Some((*w, uniform_hemisphere_pdf()));
+        assert!(samples().all(|u| uniform_sample_hemisphere(&u).z >= 0.0));
+        assert_close(integrate(|u| pdf(&uniform_sample_hemisphere(u)), |w| w.z), PI, 0.01);
+        assert_close(integrate(|u| pdf(&uniform_sample_hemisphere(u)), |_| 1.0), 2.0 * PI, 1e-4);
+        check_inverse(
+            |u| to_point2f(&uniform_sample_hemisphere(u)),
+            |p| {
+                let z = (1.0 - p.x * p.x - p.y * p.y).max(0.0).sqrt();
+                invert_uniform_hemisphere_sample(&Vector3f::new(p.x, p.y, z))
+            },
+        );
+
+        let cosine = |u: &Point2f| {
+            let w = cosine_sample_hemisphere(u);
+            Some((w, cosine_hemisphere_pdf(w.z)))
+        };
+        assert_close(integrate(cosine, |w| w.z * w.z), 2.0 * PI / 3.0, 0.01);
+        assert_close(integrate(cosine, |w| w.x * w.x * w.z), PI / 4.0, 0.01);
+        for u in samples().step_by(7) {
+            let w = cosine_sample_hemisphere(&u);
+            assert_close(w.length(), 1.0, 1e-4);
+            let v = invert_cosine_hemisphere_sample(&w);
+            assert!((u.x - v.x).abs() < 2e-3 && (u.y - v.y).abs() < 2e-3);
+        }
+    }
+
+    #[test]
+    fn check_sample_sphere() {
+        let sphere = |u: &Point2f| Some((uniform_sample_sphere(u), uniform_sphere_pdf()));
+        assert_close(integrate(sphere, |_| 1.0), 4.0 * PI, 1e-4);
+        assert_close(integrate(sphere, |w| w.z * w.z), 4.0 * PI / 3.0, 0.01);
+        assert_close(integrate(sphere, |w| w.x.max(0.0)), PI, 0.01);
+        for u in samples().step_by(7) {
+            let w = uniform_sample_sphere(&u);
+            assert_close(w.length(), 1.0, 1e-4);
+            let v = invert_uniform_sphere_sample(&w);
+            assert!((u.x - v.x).abs() < 2e-3 && (u.y - v.y).abs() < 2e-3);
+        }
+    }
+
+    #[test]
+    fn check_sample_cone() {
+        for &cos_theta_max in &[0.9, 0.3, -0.5] {
+            let cone = |u: &Point2f| Some((uniform_sample_cone(u, cos_theta_max), uniform_cone_pdf(cos_theta_max)));
+            assert!(samples().all(|u| uniform_sample_cone(&u, cos_theta_max).z >= cos_theta_max - 1e-5));
+            let expected = PI * (1.0 - cos_theta_max * cos_theta_max);
+            assert_close(integrate(cone, |w| w.z), expected, 0.01);
+            for u in samples().step_by(7) {
+                let v = invert_uniform_cone_sample(&uniform_sample_cone(&u, cos_theta_max), cos_theta_max);
+                assert!((u.x - v.x).abs() < 2e-3 && (u.y - v.y).abs() < 2e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn check_sample_triangle() {
+        let p = [Point3f::new(1.0, 0.0, 0.0), Point3f::new(3.0, 1.0, 0.0), Point3f::new(0.0, 2.0, 1.0)];
+        let point = |b: &Point2f| p[2] + (p[0] - p[2]) * b.x + (p[1] - p[2]) * b.y;
+        let triangle = |u: &Point2f| Some((point(&uniform_sample_triangle(u)), uniform_triangle_pdf(&p[0], &p[1], &p[2])));
+        let area = 1.0 / uniform_triangle_pdf(&p[0], &p[1], &p[2]);
+        assert_close(area, 0.5 * Vector3f::cross(&(p[1] - p[0]), &(p[2] - p[0])).length(), 1e-5);
+        // the mean of the points is the centroid
+        assert_close(integrate(triangle, |q| q.x), area * 4.0 / 3.0, 0.01);
+        assert_close(integrate(triangle, |q| q.z), area / 3.0, 0.01);
+        check_inverse(uniform_sample_triangle, invert_uniform_triangle_sample);
+    }
+
+    // point where the ray from o along w meets the plane of the triangle,
+    // with its barycentrics
+    fn hit_triangle(v: &[Point3f; 3], o: &Point3f, w: &Vector3f) -> Option<(Point3f, f32, f32)> {
+        let n = Vector3f::cross(&(v[1] - v[0]), &(v[2] - v[0]));
+        let t = Vector3f::dot(&n, &(v[0] - *o)) / Vector3f::dot(&n, w);
+        if t <= 0.0 {
+            return None;
+        }
+        let q = *o + *w * t;
+        let area = n.length();
+        let b1 = Vector3f::dot(&Vector3f::cross(&(q - v[0]), &(v[2] - v[0])), &n) / (area * area);
+        let b2 = Vector3f::dot(&Vector3f::cross(&(v[1] - v[0]), &(q - v[0])), &n) / (area * area);
+        Some((q, b1, b2))
+    }
+
+    #[test]
+    fn check_sample_spherical_triangle() {
+        let v = [Point3f::new(-1.0, -0.5, 2.0), Point3f::new(1.5, 0.0, 3.0), Point3f::new(0.0, 2.0, 1.5)];
+        let o = Point3f::new(0.2, 0.1, -0.5);
+        let n = Vector3f::normalize(&Vector3f::cross(&(v[1] - v[0]), &(v[2] - v[0])));
+        let f = |w: &Vector3f| 1.0 + w.x * w.x + w.y;
+
+        let solid_angle = spherical_triangle_area(&v, &o);
+        assert!(solid_angle > 0.0);
+        assert_close(spherical_triangle_pdf(&v, &o), 1.0 / solid_angle, 1e-5);
+        let sampled = integrate(|u| sample_spherical_triangle(&v, &o, u), f);
+        // the same integral sampled by area, converted to solid angle
+        let by_area = integrate(
+            |u| {
+                let b = uniform_sample_triangle(u);
+                let q = v[2] + (v[0] - v[2]) * b.x + (v[1] - v[2]) * b.y;
+                let d = q - o;
+                let cos = Vector3f::abs_dot(&n, &Vector3f::normalize(&d));
+                let pdf = uniform_triangle_pdf(&v[0], &v[1], &v[2]) * d.length_squared() / cos;
+                Some((Vector3f::normalize(&d), pdf))
+            },
+            f,
+        );
+        assert_close(sampled, by_area, 0.01);
+        assert_close(integrate(|u| sample_spherical_triangle(&v, &o, u), |_| 1.0), solid_angle, 1e-4);
+
+        for u in samples().step_by(7) {
+            let (w, _) = sample_spherical_triangle(&v, &o, &u).unwrap();
+            assert_close(w.length(), 1.0, 1e-4);
+            let (_, b1, b2) = hit_triangle(&v, &o, &w).unwrap();
+            assert!(b1 >= -1e-4 && b2 >= -1e-4 && b1 + b2 <= 1.0 + 1e-4);
+            let inverse = invert_spherical_triangle_sample(&v, &o, &w);
+            assert!((u.x - inverse.x).abs() < 2e-3 && (u.y - inverse.y).abs() < 2e-3, "{:?} {:?}", u, inverse);
+        }
+        // degenerate as seen from a point in its plane
+        assert!(sample_spherical_triangle(&v, &v[0], &Point2f::new(0.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn check_sample_spherical_rectangle() {
+        let s = Point3f::new(-1.0, 0.5, 2.0);
+        let ex = Vector3f::new(2.0, 0.0, 1.0);
+        let ey = Vector3f::new(0.0, 1.5, 0.0);
+        let n = Vector3f::normalize(&Vector3f::cross(&ex, &ey));
+        let area = ex.length() * ey.length();
+        let f = |p: &Point3f| 1.0 + p.x * p.x + p.y;
+        for o in &[Point3f::new(0.0, 0.0, 0.0), Point3f::new(0.5, 1.0, 4.0), Point3f::new(3.0, -1.0, 1.0)] {
+            let sampled = integrate(|u| sample_spherical_rectangle(o, &s, &ex, &ey, u), f);
+            let by_area = integrate(
+                |u| {
+                    let q = s + ex * u.x + ey * u.y;
+                    let d = q - *o;
+                    let cos = Vector3f::abs_dot(&n, &Vector3f::normalize(&d));
+                    Some((q, d.length_squared() / (cos * area)))
+                },
+                f,
+            );
+            assert_close(sampled, by_area, 0.01);
+            let solid_angle = integrate(|u| sample_spherical_rectangle(o, &s, &ex, &ey, u), |_| 1.0);
+            assert_close(spherical_rectangle_pdf(o, &s, &ex, &ey), 1.0 / solid_angle, 1e-4);
+
+            for u in samples().step_by(7) {
+                let (p, _) = sample_spherical_rectangle(o, &s, &ex, &ey, &u).unwrap();
+                // the point lies on the rectangle
+                let d = p - s;
+                let (x, y) = (Vector3f::dot(&d, &ex) / ex.length_squared(), Vector3f::dot(&d, &ey) / ey.length_squared());
+                assert!(Vector3f::dot(&d, &n).abs() < 1e-4);
+                assert!((-1e-4..=1.0 + 1e-4).contains(&x) && (-1e-4..=1.0 + 1e-4).contains(&y));
+                let inverse = invert_spherical_rectangle_sample(o, &s, &ex, &ey, &p);
+                assert!((u.x - inverse.x).abs() < 2e-3 && (u.y - inverse.y).abs() < 2e-3, "{:?} {:?}", u, inverse);
+            }
+        }
+    }
+
+    #[test]
+    fn check_distribution_1d() {
+        let f = [0.0, 1.0, 3.0, 0.0, 4.0];
+        let d = Distribution1D::new(&f, -1.0, 4.0);
+        assert_eq!(d.count(), 5);
+        assert_close(d.integral(), 8.0, 1e-6);
+        assert_eq!(d.discrete_pdf(2), 3.0 / 8.0);
+        let (x, pdf, offset) = d.sample_continuous(0.0);
+        // pieces with zero probability are skipped
+        assert_eq!((x, pdf, offset), (0.0, 1.0 / 8.0, 1));
+        assert_eq!(d.pdf(2.5), 0.0);
+        assert_eq!(d.pdf(3.5), 0.5);
+
+        let mut counts = [0; 5];
+        for u in samples() {
+            let (x, pdf, offset) = d.sample_continuous(u.x);
+            assert!((-1.0..4.0).contains(&x));
+            assert_eq!(offset, (x + 1.0) as usize);
+            assert_eq!(pdf, d.pdf(x));
+            assert_close(d.invert(x).unwrap(), u.x, 1e-4);
+
+            let (i, pmf, u_remapped) = d.sample_discrete(u.x);
+            assert_eq!(pmf, f[i] / 8.0);
+            assert!((0.0..1.0).contains(&u_remapped));
+            counts[i] += 1;
+        }
+        for (c, v) in counts.iter().zip(f.iter()) {
+            assert_close(*c as f32 / N as f32, v / 8.0, 1e-3);
+        }
+        assert_eq!(d.invert(5.0), None);
+
+        // a zero function is sampled uniformly
+        let zero = Distribution1D::new(&[0.0; 4], 0.0, 1.0);
+        assert_eq!(zero.sample_continuous(0.3).0, 0.3);
+        assert_eq!(zero.sample_discrete(0.6).0, 2);
+    }
+
+    #[test]
+    fn check_distribution_2d() {
+        let (nu, nv) = (4, 3);
+        let func: Vec<f32> = (0..nu * nv).map(|i| ((i * 7) % 5) as f32).collect();
+        let domain = Bounds2f::new(Point2f::new(-1.0, 2.0), Point2f::new(3.0, 3.5));
+        let d = Distribution2D::new(&func, nu, nv, &domain);
+        let cell_area = domain.area() / (nu * nv) as f32;
+        assert_close(d.integral(), func.iter().sum::<f32>() * cell_area, 1e-5);
+
+        let value = |p: &Point2f| {
+            let iu = ((p.x - domain.p_min.x) / (domain.p_max.x - domain.p_min.x) * nu as f32) as usize;
+            let iv = ((p.y - domain.p_min.y) / (domain.p_max.y - domain.p_min.y) * nv as f32) as usize;
+            func[iv.min(nv - 1) * nu + iu.min(nu - 1)]
+        };
+        // the estimate of the integral has no variance with importance sampling
+        let estimate = integrate(|u| Some(d.sample_continuous(u)), value);
+        assert_close(estimate, d.integral(), 1e-4);
+        // cells with zero value are never sampled
+        let support = func.iter().filter(|&&f| f > 0.0).count() as f32 * cell_area;
+        assert_close(integrate(|u| Some(d.sample_continuous(u)), |_| 1.0), support, 0.01);
+
+        for u in samples().step_by(7) {
+            let (p, pdf) = d.sample_continuous(&u);
+            assert!(Bounds2f::inside(&p, &domain));
+            assert_close(pdf, d.pdf(&p), 1e-5);
+            assert_close(pdf, value(&p) / d.integral(), 1e-5);
+            let inverse = d.invert(&p).unwrap();
+            assert!((u.x - inverse.x).abs() < 1e-3 && (u.y - inverse.y).abs() < 1e-3, "{:?} {:?}", u, inverse);
+        }
+        assert_eq!(d.pdf(&Point2f::new(-2.0, 3.0)), 0.0);
+    }
+
+    #[test]
+    fn check_alias_table() {
+        let weights = [0.5, 3.0, 0.0, 1.25, 2.0, 0.25, 7.0];
+        let sum: f32 = weights.iter().sum();
+        let table = AliasTable::new(&weights);
+        assert_eq!(table.size(), weights.len());
+        let mut counts = [0; 7];
+        for u in samples() {
+            let (i, pmf, u_remapped) = table.sample(u.x);
+            assert_close(pmf, weights[i] / sum, 1e-6);
+            assert!((0.0..1.0).contains(&u_remapped));
+            counts[i] += 1;
+        }
+        for (i, (c, w)) in counts.iter().zip(weights.iter()).enumerate() {
+            assert_close(table.pmf(i), w / sum, 1e-6);
+            assert_close(*c as f32 / N as f32, w / sum, 2e-3);
+        }
+        assert_eq!(counts[2], 0);
+        assert_eq!(AliasTable::new(&[2.0]).sample(0.7), (0, 1.0, 0.7));
+    }
+}
@@ -0,0 +1,307 @@
+mod spectrum_tests {
+    use rust_my_pbrt::core::spectrum::*;
+
+    fn assert_close(a: f32, b: f32, tolerance: f32) {
+        assert!((a - b).abs() <= tolerance, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn check_spectrum_arithmetic() {
+        let a = RGBSpectrum::from_rgb(&[1.0, 2.0, 4.0]);
+        let b = RGBSpectrum::from_rgb(&[0.5, 0.0, 2.0]);
+        assert_eq!((a + b).c, [1.5, 2.0, 6.0]);
+        assert_eq!((a - b).c, [0.5, 2.0, 2.0]);
+        assert_eq!((a * b).c, [0.5, 0.0, 8.0]);
+        assert_eq!((2.0 * a).c, (a * 2.0).c);
+        assert_eq!((a / 2.0).c, [0.5, 1.0, 2.0]);
+        // division by a zero sample gives zero
+        assert_eq!((a / b).c, [2.0, 0.0, 2.0]);
+        assert_eq!((-a)[2], -4.0);
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= 3.0;
+        c /= 3.0;
+        assert_eq!(c, a);
+        c[1] = 7.0;
+        assert_eq!(c.c, [1.0, 7.0, 4.0]);
+
+        assert!(RGBSpectrum::default().is_black());
+        assert!(!b.is_black());
+        assert_eq!(RGBSpectrum::sqrt(&a).c, [1.0, 2.0_f32.sqrt(), 2.0]);
+        assert_eq!(RGBSpectrum::pow(&a, 2.0).c, [1.0, 4.0, 16.0]);
+        assert_eq!(RGBSpectrum::exp(&RGBSpectrum::new(0.0)), RGBSpectrum::new(1.0));
+        assert_eq!(RGBSpectrum::clamp(&a, 1.5, 3.0).c, [1.5, 2.0, 3.0]);
+        assert_eq!(RGBSpectrum::lerp(0.5, &a, &b).c, [0.75, 1.0, 3.0]);
+        assert_eq!(a.max_component_value(), 4.0);
+        assert!(RGBSpectrum::new(f32::NAN).has_nans());
+
+        let s = SampledSpectrum::new(2.0) * SampledSpectrum::new(1.5);
+        assert!(s.c.iter().all(|&v| v == 3.0));
+        assert_eq!(s.average(), 3.0);
+    }
+
+    #[test]
+    fn check_cie_tables() {
+        assert_eq!(CIE_X.len(), N_CIE_SAMPLES);
+        assert_eq!(CIE_LAMBDA_START + (N_CIE_SAMPLES - 1) as f32, CIE_LAMBDA_END);
+        assert_close(CIE_Y.iter().sum::<f32>(), CIE_Y_INTEGRAL, 1e-3);
+        // y peaks at 555nm, z in the blue and x in the red
+        let peak = |t: &[f32]| CIE_LAMBDA_START + t.iter().enumerate().fold(0, |m, (i, &v)| if v > t[m] { i } else { m }) as f32;
+        assert!((550.0..=560.0).contains(&peak(&CIE_Y)));
+        assert!((440.0..=450.0).contains(&peak(&CIE_Z)));
+        assert!((595.0..=605.0).contains(&peak(&CIE_X)));
+        // a few tabulated values
+        let at = |t: &[f32], lambda: usize| t[lambda - CIE_LAMBDA_START as usize];
+        assert_eq!(at(&CIE_Y, 555), 1.0);
+        assert_eq!(at(&CIE_X, 600), 1.0622);
+        assert_eq!(at(&CIE_Z, 445), 1.7826);
+        assert_eq!(at(&CIE_X, 500), 0.0049);
+        assert_eq!(at(&CIE_Y, 400), 0.000396);
+        assert_eq!(at(&CIE_Z, 700), 0.0);
+        // the integrals of x, y and z are about the same
+        assert_close(CIE_X.iter().sum::<f32>() / CIE_Y_INTEGRAL, 1.0, 1e-3);
+        assert_close(CIE_Z.iter().sum::<f32>() / CIE_Y_INTEGRAL, 1.0, 1e-3);
+    }
+
+    #[test]
+    fn check_spectrum_xyz() {
+        // equal energy white has chromaticity (1/3, 1/3)
+        let white = SampledSpectrum::new(1.0);
+        let xyz = white.xyz();
+        let sum = xyz[0] + xyz[1] + xyz[2];
+        assert_close(xyz[0] / sum, 1.0 / 3.0, 0.01);
+        assert_close(xyz[1] / sum, 1.0 / 3.0, 0.01);
+        // almost all of y is inside the sampled range
+        assert_close(white.y(), 1.0, 0.03);
+
+        // a narrow band around 450nm is blue, around 610nm red
+        let band = |center: f32| {
+            let lambda = [center - 10.0, center - 9.0, center + 9.0, center + 10.0];
+            SampledSpectrum::from_sampled(&lambda, &[0.0, 1.0, 1.0, 0.0])
+        };
+        let blue = band(450.0).rgb();
+        assert!(blue[2] > blue[0] && blue[2] > blue[1]);
+        let red = band(610.0).rgb();
+        assert!(red[0] > red[1] && red[0] > red[2]);
+        assert_eq!(band(450.0).to_rgb_spectrum().c, blue);
+
+        // rgb to xyz and back
+        let rgb = [0.2, 0.7, 0.4];
+        let s = RGBSpectrum::from_xyz(&RGBSpectrum::from_rgb(&rgb).xyz());
+        for (a, b) in s.c.iter().zip(rgb.iter()) {
+            assert_close(*a, *b, 1e-4);
+        }
+        assert_close(RGBSpectrum::new(1.0).y(), 1.0, 1e-4);
+        assert_close(s.y(), s.xyz()[1], 1e-6);
+    }
+
+    #[test]
+    fn check_average_spectrum_samples() {
+        let lambda = [400.0, 500.0, 600.0];
+        let values = [1.0, 3.0, 2.0];
+        assert_eq!(average_spectrum_samples(&lambda, &values, 350.0, 390.0), 1.0);
+        assert_eq!(average_spectrum_samples(&lambda, &values, 650.0, 700.0), 2.0);
+        assert_close(average_spectrum_samples(&lambda, &values, 400.0, 500.0), 2.0, 1e-5);
+        assert_close(average_spectrum_samples(&lambda, &values, 450.0, 550.0), 2.625, 1e-5);
+        // constant past the ends
+        assert_close(average_spectrum_samples(&lambda, &values, 300.0, 400.0), 1.0, 1e-5);
+        assert_close(average_spectrum_samples(&lambda, &values, 550.0, 650.0), 2.125, 1e-5);
+
+        let s = SampledSpectrum::from_sampled(&lambda, &values);
+        assert_close(s[0], 1.05, 1e-4);
+        assert_close(s[N_SPECTRAL_SAMPLES - 1], 2.0, 1e-5);
+    }
+
+    #[test]
+    fn check_visible_wavelengths() {
+        // the density integrates to one over the range it covers
+        let n = 4700;
+        let dl = (LAMBDA_MAX - LAMBDA_MIN) / n as f32;
+        let sum: f32 = (0..n).map(|i| visible_wavelengths_pdf(LAMBDA_MIN + (i as f32 + 0.5) * dl) * dl).sum();
+        assert_close(sum, 1.0, 1e-3);
+        assert_eq!(visible_wavelengths_pdf(LAMBDA_MIN - 1.0), 0.0);
+        assert_close(sample_visible_wavelengths(0.0), LAMBDA_MIN, 0.5);
+        assert_close(sample_visible_wavelengths(1.0), LAMBDA_MAX, 0.5);
+        // sampling inverts the cumulative density
+        for &u in [0.2, 0.5, 0.7].iter() {
+            let l = sample_visible_wavelengths(u);
+            let m = ((l - LAMBDA_MIN) / dl) as usize;
+            let cdf: f32 = (0..m).map(|i| visible_wavelengths_pdf(LAMBDA_MIN + (i as f32 + 0.5) * dl) * dl).sum();
+            assert_close(cdf, u, 2e-3);
+        }
+
+        // the wavelengths of a path are stratified in u
+        let lambda = SampledWavelengths::sample_visible(0.1);
+        for i in 0..N_SPECTRUM_SAMPLES {
+            let u = 0.1 + i as f32 / N_SPECTRUM_SAMPLES as f32;
+            assert_eq!(lambda.lambda(i), sample_visible_wavelengths(u));
+            assert_eq!(lambda.pdf()[i], visible_wavelengths_pdf(lambda.lambda(i)));
+        }
+        let uniform = SampledWavelengths::sample_uniform(0.9, 400.0, 700.0);
+        assert_close(uniform.lambda(0), 670.0, 1e-3);
+        assert_close(uniform.lambda(1), 445.0, 1e-3);
+        assert_eq!(uniform.pdf()[3], 1.0 / 300.0);
+    }
+
+    #[test]
+    fn check_hero_wavelength_estimate() {
+        // the estimate of xyz of a spectrum converges to its xyz, also with
+        // only the hero wavelength left
+        let s = SampledSpectrum::from_sampled(&[400.0, 550.0, 700.0], &[0.2, 1.0, 0.5]);
+        let n = 4096;
+        let mut full = [0.0; 3];
+        let mut terminated = [0.0; 3];
+        for i in 0..n {
+            let mut lambda = SampledWavelengths::sample_visible((i as f32 + 0.5) / n as f32);
+            assert!(!lambda.secondary_terminated());
+            let xyz = s.sample(&lambda).xyz(&lambda);
+            lambda.terminate_secondary();
+            assert!(lambda.secondary_terminated());
+            let xyz_hero = s.sample(&lambda).xyz(&lambda);
+            for c in 0..3 {
+                full[c] += xyz[c] / n as f32;
+                terminated[c] += xyz_hero[c] / n as f32;
+            }
+        }
+        let expected = s.xyz();
+        for c in 0..3 {
+            assert_close(full[c], expected[c], 0.02 * expected[c]);
+            assert_close(terminated[c], expected[c], 0.02 * expected[c]);
+        }
+        let lambda = SampledWavelengths::sample_visible(0.3);
+        assert_close(s.sample(&lambda).y(&lambda), s.sample(&lambda).xyz(&lambda)[1], 1e-6);
+    }
+
+    // xyz of a spectrum over the whole CIE range
+    fn spectrum_xyz(f: impl Fn(f32) -> f32) -> [f32; 3] {
+        let mut xyz = [0.0; 3];
+        for i in 0..N_CIE_SAMPLES {
+            let l = CIE_LAMBDA_START + i as f32;
+            let v = f(l);
+            xyz[0] += CIE_X[i] * v / CIE_Y_INTEGRAL;
+            xyz[1] += CIE_Y[i] * v / CIE_Y_INTEGRAL;
+            xyz[2] += CIE_Z[i] * v / CIE_Y_INTEGRAL;
+        }
+        xyz
+    }
+
+    #[test]
+    fn check_color_spaces() {
+        // the matrices of srgb match the fixed ones
+        let srgb = RGBColorSpace::srgb();
+        let rgb = [0.2, 0.7, 0.4];
+        for (a, b) in srgb.xyz(&rgb).iter().zip(rgb_to_xyz(&rgb).iter()) {
+            assert_close(*a, *b, 5e-3);
+        }
+        for cs in [RGBColorSpace::srgb(), RGBColorSpace::aces_cg(), RGBColorSpace::rec2020()].iter() {
+            assert_eq!(RGBColorSpace::by_name(cs.name()).unwrap().name(), cs.name());
+            // white maps to the white point with luminance 1
+            let white = cs.xyz(&[1.0, 1.0, 1.0]);
+            assert_close(white[1], 1.0, 1e-5);
+            let xy = xyz_to_xy(&white);
+            assert_close(xy[0], cs.white_point()[0], 1e-5);
+            assert_close(xy[1], cs.white_point()[1], 1e-5);
+            for (i, p) in cs.primaries().iter().enumerate() {
+                let mut rgb = [0.0; 3];
+                rgb[i] = 1.0;
+                let xy = xyz_to_xy(&cs.xyz(&rgb));
+                assert_close(xy[0], p[0], 1e-5);
+                assert_close(xy[1], p[1], 1e-5);
+            }
+            let back = cs.rgb(&cs.xyz(&rgb));
+            for (a, b) in back.iter().zip(rgb.iter()) {
+                assert_close(*a, *b, 1e-5);
+            }
+            // the illuminant has the white point and luminance 1
+            let xyz = spectrum_xyz(|l| cs.illuminant().value(l));
+            assert_close(xyz[1], 1.0, 1e-4);
+            let xy = xyz_to_xy(&xyz);
+            assert_close(xy[0], cs.white_point()[0], 2e-4);
+            assert_close(xy[1], cs.white_point()[1], 2e-4);
+        }
+        // srgb uses the tabulated D65, which daylight of its chromaticity
+        // reproduces up to the rounding in the table
+        let d65 = RGBColorSpace::srgb().illuminant();
+        assert_close(d65.value(460.0) / d65.value(560.0), 1.17812, 1e-5);
+        assert_close(d65.value(555.0) / d65.value(560.0), 1.02023, 1e-5);
+        assert_eq!(d65.value(300.0), 0.0);
+        let daylight = Illuminant::daylight(&[0.3127, 0.329]);
+        for l in (400..=700).step_by(10) {
+            assert_close(daylight.value(l as f32) / d65.value(l as f32), 1.0, 5e-3);
+        }
+        assert!(RGBColorSpace::by_name("xyz").is_none());
+
+        // bradford adaptation maps white to white
+        let d65 = RGBColorSpace::srgb().white_point();
+        let d60 = RGBColorSpace::aces_cg().white_point();
+        let m = bradford_adaptation(&d65, &d60);
+        let xy = xyz_to_xy(&m.mul_vec(&xy_to_xyz(&d65, 1.0)));
+        assert_close(xy[0], d60[0], 1e-5);
+        assert_close(xy[1], d60[1], 1e-5);
+        let identity = bradford_adaptation(&d65, &d65);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_close(identity.m[i][j], if i == j { 1.0 } else { 0.0 }, 1e-5);
+            }
+        }
+
+        // white stays white between spaces, and a round trip is the identity
+        let to_aces = RGBColorSpace::conversion(RGBColorSpace::srgb(), RGBColorSpace::aces_cg());
+        for v in to_aces.mul_vec(&[1.0, 1.0, 1.0]).iter() {
+            assert_close(*v, 1.0, 1e-4);
+        }
+        let round_trip = RGBColorSpace::conversion(RGBColorSpace::aces_cg(), RGBColorSpace::srgb()) * to_aces;
+        for (a, b) in round_trip.mul_vec(&rgb).iter().zip(rgb.iter()) {
+            assert_close(*a, *b, 1e-4);
+        }
+        // rec2020 has the same white, the conversion needs no adaptation
+        let to_rec2020 = RGBColorSpace::conversion(RGBColorSpace::srgb(), RGBColorSpace::rec2020());
+        let xyz = RGBColorSpace::rec2020().xyz(&to_rec2020.mul_vec(&rgb));
+        for (a, b) in xyz.iter().zip(srgb.xyz(&rgb).iter()) {
+            assert_close(*a, *b, 1e-4);
+        }
+    }
+
+    #[test]
+    fn check_rgb_to_spectrum() {
+        let cs = RGBColorSpace::srgb();
+        let illuminant = |l: f32| cs.illuminant().value(l);
+        let colors = [[0.0, 0.0, 0.0], [0.5, 0.5, 0.5], [0.8, 0.2, 0.1], [0.1, 0.6, 0.3], [0.25, 0.3, 0.9], [0.95, 0.9, 0.05], [0.01, 0.02, 0.015]];
+        for rgb in colors.iter() {
+            // a reflectance under the illuminant of the space has its rgb
+            let albedo = RGBAlbedoSpectrum::new(cs, rgb);
+            let xyz = spectrum_xyz(|l| albedo.value(l) * illuminant(l));
+            for (a, b) in cs.rgb(&xyz).iter().zip(rgb.iter()) {
+                assert_close(*a, *b, 5e-3);
+            }
+            assert!(albedo.max_value() <= 1.0);
+            let lambda = SampledWavelengths::sample_visible(0.4);
+            assert_eq!(albedo.sample(&lambda)[2], albedo.value(lambda.lambda(2)));
+
+            // unbounded spectra scale up reflectances, illuminants emit it
+            let scaled = [4.0 * rgb[0], 4.0 * rgb[1], 4.0 * rgb[2]];
+            let unbounded = RGBUnboundedSpectrum::new(cs, &scaled);
+            let xyz = spectrum_xyz(|l| unbounded.value(l) * illuminant(l));
+            for (a, b) in cs.rgb(&xyz).iter().zip(scaled.iter()) {
+                assert_close(*a, *b, 2e-2);
+            }
+            let light = RGBIlluminantSpectrum::new(cs, &scaled);
+            let xyz = spectrum_xyz(|l| light.value(l));
+            for (a, b) in cs.rgb(&xyz).iter().zip(scaled.iter()) {
+                assert_close(*a, *b, 2e-2);
+            }
+        }
+        // white light is the illuminant
+        let white = RGBIlluminantSpectrum::new(cs, &[1.0, 1.0, 1.0]);
+        assert_close(white.value(550.0), cs.illuminant().value(550.0), 1e-5);
+        assert!(RGBUnboundedSpectrum::new(cs, &[0.0; 3]).sampled().is_black());
+
+        // bluish rgb gives a decreasing spectrum, grey a constant one
+        let p = cs.sigmoid_polynomial(&[0.3, 0.45, 0.7]);
+        assert!(p.evaluate(450.0) > p.evaluate(650.0));
+        let flat = cs.sigmoid_polynomial(&[0.5, 0.5, 0.5]);
+        assert_close(flat.evaluate(400.0), 0.5, 1e-6);
+        assert_close(flat.max_value(), 0.5, 1e-6);
+    }
+}
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+# writes src/core/lowdiscrepancy/sobolmatrices.rs from Joe and Kuo's direction
+# numbers, https://web.maths.unsw.edu.au/~fkuo/sobol/new-joe-kuo-6.21201
+#
+#   python3 tools/sobolmatrices.py new-joe-kuo-6.21201 > src/core/lowdiscrepancy/sobolmatrices.rs
+
+import sys
+
+N_SOBOL_DIMENSIONS = 1024
+SOBOL_MATRIX_SIZE = 52
+
+
+def read_direction_numbers(name):
+    # lines of d, s, a, m_1 .. m_s after the header, d starting at 2
+    dims = []
+    with open(name) as f:
+        for line in f.readlines()[1:]:
+            if not line.strip():
+                continue
+            d, s, a, *m = map(int, line.split())
+            if d != len(dims) + 2 or len(m) != s:
+                sys.exit("malformed line for dimension %d" % d)
+            dims.append((s, a, m))
+            if len(dims) == N_SOBOL_DIMENSIONS - 1:
+                return dims
+    sys.exit("%s has only %d dimensions" % (name, len(dims) + 1))
+
+
+def matrix(s, a, m):
+    # direction numbers with 64 bits of precision, the top 32 are kept
+    v = [m[j] << (63 - j) for j in range(min(s, SOBOL_MATRIX_SIZE))]
+    for j in range(s, SOBOL_MATRIX_SIZE):
+        x = v[j - s] ^ (v[j - s] >> s)
+        for k in range(1, s):
+            if (a >> (s - 1 - k)) & 1:
+                x ^= v[j - k]
+        v.append(x)
+    return [x >> 32 for x in v]
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: sobolmatrices.py new-joe-kuo-6.21201")
+    # dimension 0 is the van der Corput sequence
+    matrices = [[1 << (31 - j) if j < 32 else 0 for j in range(SOBOL_MATRIX_SIZE)]]
+    matrices += [matrix(s, a, m) for s, a, m in read_direction_numbers(sys.argv[1])]
+
+    print("// generator matrices of the first 1024 Sobol dimensions, SOBOL_MATRIX_SIZE")
+    print("// columns each with the most significant bit first. dimension 0 is the van")
+    print("// der Corput sequence, the others use the primitive polynomials and initial")
+    print("// direction numbers of Joe and Kuo's new-joe-kuo-6.21201. generated by")
+    print("// tools/sobolmatrices.py.")
+    print()
+    print("pub const N_SOBOL_DIMENSIONS: usize = %d;" % N_SOBOL_DIMENSIONS)
+    print("pub const SOBOL_MATRIX_SIZE: usize = %d;" % SOBOL_MATRIX_SIZE)
+    print()
+    print("#[rustfmt::skip]")
+    print("pub static SOBOL_MATRICES_32: [u32; N_SOBOL_DIMENSIONS * SOBOL_MATRIX_SIZE] = [")
+    for c in matrices:
+        for i in range(0, SOBOL_MATRIX_SIZE, 8):
+            print("    " + " ".join("0x%08x," % x for x in c[i:i + 8]))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()